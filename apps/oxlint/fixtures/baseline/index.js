@@ -0,0 +1,3 @@
+debugger;
+
+debugger;
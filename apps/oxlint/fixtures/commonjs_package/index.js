@@ -0,0 +1,3 @@
+import fs from "fs";
+
+module.exports = fs.readFileSync;
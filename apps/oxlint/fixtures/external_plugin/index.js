@@ -0,0 +1 @@
+legacy();
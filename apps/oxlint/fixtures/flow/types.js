@@ -0,0 +1,4 @@
+#!/usr/bin/env node
+// @flow
+type Props = {| name: string |};
+debugger;
@@ -0,0 +1,3 @@
+debugger;
+console.log("foo");
+console.info("bar");
@@ -0,0 +1,3 @@
+describe("foo", () => {
+  foo();
+});
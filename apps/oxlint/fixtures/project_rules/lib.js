@@ -0,0 +1,3 @@
+export function used() {}
+
+export function unused() {}
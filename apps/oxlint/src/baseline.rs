@@ -0,0 +1,59 @@
+//! Reading and writing baseline files.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "files": {
+//!     "src/index.js": [{ "rule": "eslint(no-debugger)", "fingerprint": "6b0ba2c8d4bd5d6f" }]
+//!   }
+//! }
+//! ```
+
+use std::{fs, path::Path};
+
+use oxc_diagnostics::{Baseline, BaselineEntry};
+use serde_json::{json, Map, Value};
+
+/// Written by `--generate-baseline` when no `--baseline` is given.
+pub const DEFAULT_PATH: &str = "oxlint-baseline.json";
+
+const VERSION: u64 = 1;
+
+/// Read the baseline file at `path`. File paths in it are relative to `root`.
+pub fn read(path: &Path, root: &Path) -> Result<Baseline, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read baseline {}: {err}", path.display()))?;
+    let invalid = |reason: &str| format!("Invalid baseline {}: {reason}", path.display());
+    let json = serde_json::from_str::<Value>(&text).map_err(|err| invalid(&err.to_string()))?;
+    if json.get("version").and_then(Value::as_u64) != Some(VERSION) {
+        return Err(invalid(&format!("expected version {VERSION}")));
+    }
+
+    let mut baseline = Baseline::new(root);
+    let files =
+        json.get("files").and_then(Value::as_object).ok_or_else(|| invalid("missing files"))?;
+    for (file, entries) in files {
+        for entry in entries.as_array().into_iter().flatten() {
+            let field = |key| entry.get(key).and_then(Value::as_str).map(ToString::to_string);
+            let (Some(rule), Some(fingerprint)) = (field("rule"), field("fingerprint")) else {
+                return Err(invalid(&format!("malformed entry for {file}")));
+            };
+            baseline.insert(BaselineEntry { file: file.clone(), rule, fingerprint });
+        }
+    }
+    Ok(baseline)
+}
+
+/// Write `baseline` to `path`, with entries grouped by file.
+pub fn write(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    let mut files = Map::new();
+    for BaselineEntry { file, rule, fingerprint } in baseline.entries() {
+        if let Value::Array(entries) = files.entry(file).or_insert_with(|| json!([])) {
+            entries.push(json!({ "rule": rule, "fingerprint": fingerprint }));
+        }
+    }
+    let json = json!({ "version": VERSION, "files": files });
+    let text = serde_json::to_string_pretty(&json).unwrap();
+    fs::write(path, text + "\n")
+        .map_err(|err| format!("Failed to write baseline {}: {err}", path.display()))
+}
@@ -30,6 +30,17 @@ pub struct IgnoreOptions {
     /// Follow symbolic links. Oxlint ignores symbolic links by default.
     #[bpaf(switch, hide_usage)]
     pub symlinks: bool,
+
+    /// Only lint files changed since the current branch forked from REF,
+    /// including uncommitted and untracked files. With the import plugin,
+    /// files that import them are linted too
+    #[bpaf(argument("REF"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint files staged for commit, using their staged contents rather
+    /// than the contents of the working tree. Useful in pre-commit hooks
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
 }
 
 #[cfg(test)]
@@ -63,6 +74,18 @@ mod ignore_options {
         assert!(options.no_ignore);
     }
 
+    #[test]
+    fn changed_since() {
+        let options = get_ignore_options("--changed-since main foo.js");
+        assert_eq!(options.changed_since.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn staged() {
+        let options = get_ignore_options("--staged");
+        assert!(options.staged);
+    }
+
     #[test]
     fn single_ignore_pattern() {
         let options = get_ignore_options("--ignore-pattern ./test foo.js");
@@ -18,6 +18,11 @@ pub struct LintCommand {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many, hide_usage)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// Enable the rules in the `nursery` category, which are still under development and off by default.
+    /// Same as `-W nursery` before the other filters
+    #[bpaf(switch, hide_usage)]
+    pub nursery: bool,
+
     #[bpaf(external)]
     pub enable_plugins: EnablePlugins,
 
@@ -65,7 +70,8 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
-    /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
+    /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin.
+    /// Defaults to the nearest `tsconfig.json` or `jsconfig.json`
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
 }
@@ -170,28 +176,63 @@ pub struct WarningOptions {
     pub deny_warnings: bool,
 
     /// Specify a warning threshold,
-    /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
+    /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project.
+    /// The exit code is 0 when linting passes, 1 when it fails, and 2 when the linter could not run
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Only report problems that are not in this baseline file.
+    /// Problems are matched by file, rule and fingerprint, so a baseline keeps working as code changes
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all current problems in a baseline file instead of reporting them.
+    /// The file is written to the `--baseline` path, or `oxlint-baseline.json`
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, compact, json, json-with-fixes, unix, checkstyle, github, html)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Disable colors in the default output format
+    #[bpaf(switch, hide_usage)]
+    pub no_color: bool,
+
+    /// Wrap the default output format at this many columns instead of the terminal width
+    #[bpaf(argument("INT"), hide_usage)]
+    pub width: Option<usize>,
+
+    /// Maximum number of problems to report for a single file.
+    /// Remaining problems are still counted, and summarized in a single line by the default output format
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_diagnostics_per_file: Option<usize>,
+
+    /// Maximum number of problems a single rule may report for a single file.
+    /// Remaining problems are still counted, and summarized in a single line by the default output format
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_diagnostics_per_rule: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
+    /// One `file:line:column rule message` line per diagnostic
+    Compact,
     /// GitHub Check Annotation
     /// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-a-notice-message>
     Github,
     Json,
+    /// JSON, with the edit that fixes each diagnostic. Fixes are reported instead of applied
+    JsonWithFixes,
     Unix,
     Checkstyle,
+    /// A standalone HTML page, with problems grouped by rule and by file. Fixes are reported instead of applied
+    Html,
 }
 
 impl FromStr for OutputFormat {
@@ -200,10 +241,13 @@ impl FromStr for OutputFormat {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "json" => Ok(Self::Json),
+            "json-with-fixes" => Ok(Self::JsonWithFixes),
             "default" => Ok(Self::Default),
+            "compact" => Ok(Self::Compact),
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
             "github" => Ok(Self::Github),
+            "html" => Ok(Self::Html),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -544,6 +588,34 @@ mod lint_options {
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn max_diagnostics() {
+        let options =
+            get_lint_options("--max-diagnostics-per-file 20 --max-diagnostics-per-rule 5");
+        assert_eq!(options.output_options.max_diagnostics_per_file, Some(20));
+        assert_eq!(options.output_options.max_diagnostics_per_rule, Some(5));
+    }
+
+    #[test]
+    fn compact_format() {
+        let options = get_lint_options("-f compact --no-color --width 120");
+        assert_eq!(options.output_options.format, OutputFormat::Compact);
+        assert!(options.output_options.no_color);
+        assert_eq!(options.output_options.width, Some(120));
+    }
+
+    #[test]
+    fn json_with_fixes_format() {
+        let options = get_lint_options("-f json-with-fixes");
+        assert_eq!(options.output_options.format, OutputFormat::JsonWithFixes);
+    }
+
+    #[test]
+    fn html_format() {
+        let options = get_lint_options("-f html");
+        assert_eq!(options.output_options.format, OutputFormat::Html);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
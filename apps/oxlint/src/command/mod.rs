@@ -7,7 +7,7 @@ use bpaf::Bpaf;
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    lint::{lint_command, EnablePlugins, LintCommand, OutputFormat, OutputOptions, WarningOptions},
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -28,8 +28,35 @@ pub struct MiscOptions {
 
     /// This option outputs the configuration to be used.
     /// When present, no linting is performed and only config-related options are valid.
+    /// When a single file is passed, the overrides matching it are merged into the printed rules
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Check the configuration file passed with `-c` for unknown rules and malformed rule options instead of linting.
+    /// Exits with a non-zero status code if any problem is found
+    #[bpaf(switch, hide_usage)]
+    pub validate_config: bool,
+
+    /// Print an oxlint configuration converted from an ESLint configuration instead of linting.
+    /// Takes an `.eslintrc` file, or a flat config (`eslint.config.js`) exported as JSON.
+    /// Rules oxlint does not implement are listed on stderr
+    #[bpaf(argument("./.eslintrc.json"), hide_usage)]
+    pub migrate: Option<PathBuf>,
+
+    /// Print the documentation of a rule and how it is configured instead of linting.
+    /// Takes a rule name such as `no-debugger`, `react/jsx-key`, or `eslint-plugin-react(jsx-key)` as shown in diagnostics
+    #[bpaf(argument("RULE"), hide_usage)]
+    pub explain: Option<String>,
+
+    /// Print the ESTree AST of each file as JSON instead of linting it.
+    /// Nodes have `range` and `loc`, and offsets count UTF-16 code units, as in ESLint
+    #[bpaf(switch, hide_usage)]
+    pub print_ast: bool,
+
+    /// Print the time spent in the slowest rules.
+    /// With the default format a table is printed to stdout, otherwise the timings of every rule are printed to stderr as JSON
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -1,54 +1,174 @@
-use std::path::Path;
+//! Selecting files by their git history, for `--changed-since` and `--staged`.
 
-use git2::Repository;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
-use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use rustc_hash::{FxHashMap, FxHashSet};
 
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
+/// Get the files under `cwd` that changed since the branch forked from `base`:
+/// files changed by commits since the merge base of `base` and `HEAD`,
+/// uncommitted changes, and untracked files that are not ignored. Deleted files
+/// are left out.
+///
+/// Paths are canonicalized, so they can be compared with other canonical paths.
+pub fn changed_files(cwd: &Path, base: &str) -> Result<FxHashSet<PathBuf>, String> {
+    // git would take it for an option
+    if base.starts_with('-') {
+        return Err(format!("Invalid git ref `{base}`: refs cannot start with `-`"));
+    }
+    let merge_base = git(cwd, &["merge-base", base, "HEAD"])?;
+    let changed = git(
+        cwd,
+        &["diff", "-z", "--name-only", "--relative", "--diff-filter=d", merge_base.trim(), "--"],
+    )?;
+    let untracked = git(cwd, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+    Ok(changed
+        .split('\0')
+        .chain(untracked.split('\0'))
+        .filter(|file| !file.is_empty())
+        .filter_map(|file| fs::canonicalize(cwd.join(file)).ok())
+        .collect())
 }
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
+/// Get the staged contents of the files under `cwd` that are staged for
+/// commit, read from the git index rather than the working tree. Deleted files
+/// and files that are not valid UTF-8 are left out.
+///
+/// Paths are canonicalized, so they can be compared with other canonical paths.
+pub fn staged_files(cwd: &Path) -> Result<FxHashMap<PathBuf, String>, String> {
+    let staged =
+        git(cwd, &["diff", "-z", "--cached", "--name-only", "--relative", "--diff-filter=d"])?;
+    let mut files = FxHashMap::default();
+    for file in staged.split('\0').filter(|file| !file.is_empty()) {
+        let Ok(path) = fs::canonicalize(cwd.join(file)) else { continue };
+        // `:./<file>` is the blob in the index, with a path relative to `cwd`
+        let contents = git_bytes(cwd, &["show", &format!(":./{file}")])?;
+        if let Ok(contents) = String::from_utf8(contents) {
+            files.insert(path, contents);
+        }
     }
+    Ok(files)
+}
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
-            return Err(OxcDiagnostic::warning("No repository found"))
-                .with_help("Ensure target path(s) belong to a Git repository");
-        }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(OxcDiagnostic::warning("Uncommitted changes")
-                            .with_help("Commit any changes before linting"));
-                    }
-                }
-                Ok(repo)
-            }
-            err => err,
-        }
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    git_bytes(cwd, args).map(|stdout| String::from_utf8_lossy(&stdout).into_owned())
+}
+
+fn git_bytes(cwd: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("`git {}` failed: {}", args.join(" "), stderr.trim()));
     }
+    Ok(output.stdout)
+}
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(OxcDiagnostic::warning("Multiple repositories found")
-                    .with_help("Ensure all paths belong to a single repository"));
-            }
-        }
-        Ok(first_repo)
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, process::Command};
+
+    use super::{changed_files, staged_files};
+
+    fn run_git(cwd: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(cwd)
+            .status()
+            .unwrap();
+        assert!(status.success(), "`git {}` failed", args.join(" "));
     }
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+    #[test]
+    fn test_changed_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        run_git(dir, &["init", "--quiet"]);
+        fs::write(dir.join("old.js"), "debugger;").unwrap();
+        fs::write(dir.join("deleted.js"), "debugger;").unwrap();
+        fs::write(dir.join(".gitignore"), "ignored.js").unwrap();
+        run_git(dir, &["add", "."]);
+        run_git(dir, &["commit", "--quiet", "-m", "base"]);
+        run_git(dir, &["tag", "base"]);
+
+        assert!(changed_files(dir, "base").unwrap().is_empty());
+
+        fs::write(dir.join("committed.js"), "debugger;").unwrap();
+        run_git(dir, &["add", "committed.js"]);
+        run_git(dir, &["commit", "--quiet", "-m", "change"]);
+        fs::write(dir.join("old.js"), "debugger; debugger;").unwrap();
+        fs::write(dir.join("untracked.js"), "debugger;").unwrap();
+        fs::write(dir.join("ignored.js"), "debugger;").unwrap();
+        fs::remove_file(dir.join("deleted.js")).unwrap();
+
+        let mut changed = changed_files(dir, "base")
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        changed.sort();
+        assert_eq!(changed, ["committed.js", "old.js", "untracked.js"]);
+
+        let err = changed_files(dir, "not-a-ref").unwrap_err();
+        assert!(err.contains("git merge-base not-a-ref HEAD"));
+        let err = changed_files(dir, "--output=out.txt").unwrap_err();
+        assert!(err.contains("cannot start with `-`"));
+        assert!(!dir.join("out.txt").exists());
+    }
+
+    #[test]
+    fn test_staged_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        run_git(dir, &["init", "--quiet"]);
+        fs::create_dir(dir.join("src")).unwrap();
+        fs::write(dir.join("src/modified.js"), "let a = 1;").unwrap();
+        fs::write(dir.join("src/deleted.js"), "debugger;").unwrap();
+        fs::write(dir.join("unchanged.js"), "debugger;").unwrap();
+        run_git(dir, &["add", "."]);
+        run_git(dir, &["commit", "--quiet", "-m", "base"]);
+
+        assert!(staged_files(dir).unwrap().is_empty());
+
+        // The staged contents are linted, not the working tree.
+        fs::write(dir.join("src/modified.js"), "let a = 2;").unwrap();
+        run_git(dir, &["add", "src/modified.js"]);
+        fs::write(dir.join("src/modified.js"), "let a = 3;").unwrap();
+        fs::write(dir.join("src/added.js"), "debugger;").unwrap();
+        fs::write(dir.join("src/binary.js"), [0xff, 0xfe]).unwrap();
+        run_git(dir, &["add", "src/added.js", "src/binary.js"]);
+        run_git(dir, &["rm", "--quiet", "src/deleted.js"]);
+        fs::write(dir.join("unstaged.js"), "debugger;").unwrap();
+
+        let staged = |cwd: &Path| {
+            let mut files = staged_files(cwd)
+                .unwrap()
+                .into_iter()
+                .map(|(path, contents)| {
+                    (path.file_name().unwrap().to_string_lossy().into_owned(), contents)
+                })
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        };
+        let expected = [
+            ("added.js".to_string(), "debugger;".to_string()),
+            ("modified.js".to_string(), "let a = 2;".to_string()),
+        ];
+        assert_eq!(staged(dir), expected);
+        // Paths are relative to `cwd`, and files outside of it are left out.
+        assert_eq!(staged(&dir.join("src")), expected);
+        fs::create_dir(dir.join("other")).unwrap();
+        assert!(staged(&dir.join("other")).is_empty());
+
+        let not_a_repo = tempfile::tempdir().unwrap();
+        assert!(staged_files(not_a_repo.path()).is_err());
     }
 }
@@ -1,4 +1,6 @@
+mod baseline;
 mod command;
+mod git;
 mod lint;
 mod result;
 mod runner;
@@ -1,18 +1,31 @@
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    env, fs,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, Error, GraphicalReportHandler, OxcDiagnostic};
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    Baseline, DiagnosticService, Error, GraphicalReportHandler, GraphicalTheme, NamedSource,
+    OxcDiagnostic,
+};
 use oxc_linter::{
-    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintFilter, LintService,
-    LintServiceOptions, Linter, LinterBuilder, LinterBuilderError, Oxlintrc,
+    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, FixKind, InvalidFilterKind, LintFilter,
+    LintService, LintServiceOptions, Linter, LinterBuilder, LinterBuilderError, Oxlintrc,
 };
-use oxc_span::VALID_EXTENSIONS;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
+    baseline,
     cli::{
-        CliRunResult, LintCommand, LintResult, MiscOptions, OutputFormat, OutputOptions, Runner,
-        WarningOptions,
+        CliRunResult, EnablePlugins, LintCommand, LintResult, MiscOptions, OutputFormat,
+        OutputOptions, Runner, WarningOptions,
     },
+    git,
     walk::{Extensions, Walk},
 };
 
@@ -36,7 +49,8 @@ impl Runner for LintRunner {
 
         let LintCommand {
             paths,
-            filter,
+            mut filter,
+            nursery,
             basic_options,
             warning_options,
             ignore_options,
@@ -47,9 +61,26 @@ impl Runner for LintRunner {
             ..
         } = self.options;
 
+        if misc_options.validate_config {
+            return Self::validate_config(basic_options.config.as_deref(), &enable_plugins);
+        }
+        if let Some(eslint_config) = &misc_options.migrate {
+            return Self::migrate(eslint_config);
+        }
+        if ignore_options.staged && !fix_options.fix_kind().is_none() {
+            return CliRunResult::InvalidOptions {
+                message: "--staged cannot be used with --fix, because fixes would be written to the working tree instead of the staged files.".to_string(),
+            };
+        }
+
         let mut paths = paths;
         let provided_path_count = paths.len();
         let now = Instant::now();
+        // Overrides are resolved for a single file only.
+        let print_config_path = match paths.as_slice() {
+            [path] if misc_options.print_config && path.is_file() => Some(path.clone()),
+            _ => None,
+        };
 
         // The ignore crate whitelists explicit paths, but priority
         // should be given to the ignore file. Many users lint
@@ -81,69 +112,118 @@ impl Runner for LintRunner {
             }
         }
 
+        if misc_options.print_ast {
+            let paths = Walk::new(&paths, &ignore_options)
+                .with_extensions(Extensions(VALID_EXTENSIONS.to_vec()))
+                .paths();
+            return Self::print_ast(&paths);
+        }
+
+        if nursery {
+            filter.insert(0, (AllowWarnDeny::Warn, "nursery".to_string()));
+        }
         let filter = match Self::get_filters(filter) {
             Ok(filter) => filter,
             Err(e) => return e,
         };
 
+        if let Some(rule_name) = &misc_options.explain {
+            let mut oxlintrc = match Self::load_config(basic_options.config.as_deref()) {
+                Ok(oxlintrc) => oxlintrc,
+                Err(e) => return e,
+            };
+            enable_plugins.apply_overrides(&mut oxlintrc.plugins);
+            let builder = match Self::linter_builder(oxlintrc.clone()) {
+                Ok(builder) => builder.with_filters(filter),
+                Err(e) => return e,
+            };
+            return match builder.explain_rule(&oxlintrc, rule_name) {
+                Some(explanation) => CliRunResult::ExplainResult { explanation },
+                None => CliRunResult::InvalidOptions {
+                    message: format!(
+                        "Unknown rule `{rule_name}`. Run `oxlint --rules` to list all rules."
+                    ),
+                },
+            };
+        }
+
         let extensions = VALID_EXTENSIONS
             .iter()
             .chain(LINT_PARTIAL_LOADER_EXT.iter())
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
+        let mut paths =
             Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
 
-        let number_of_files = paths.len();
-
         let cwd = std::env::current_dir().unwrap();
 
-        let mut oxlintrc = if let Some(config_path) = basic_options.config.as_ref() {
-            match Oxlintrc::from_file(config_path) {
-                Ok(config) => config,
-                Err(diagnostic) => {
-                    let handler = GraphicalReportHandler::new();
-                    let mut err = String::new();
-                    handler.render_report(&mut err, &diagnostic).unwrap();
-                    return CliRunResult::InvalidOptions {
-                        message: format!("Failed to parse configuration file.\n{err}"),
-                    };
-                }
-            }
-        } else {
-            Oxlintrc::default()
+        // Files that import changed files are linted too, when the module graph is built.
+        let changed_paths = match &ignore_options.changed_since {
+            Some(base) => match git::changed_files(&cwd, base) {
+                Ok(changed) => Some(
+                    paths
+                        .iter()
+                        .filter(|path| {
+                            fs::canonicalize(path).is_ok_and(|path| changed.contains(&path))
+                        })
+                        .cloned()
+                        .collect::<FxHashSet<_>>(),
+                ),
+                Err(message) => return CliRunResult::InvalidOptions { message },
+            },
+            None => None,
         };
 
+        let mut file_contents = FxHashMap::default();
+        if ignore_options.staged {
+            let mut staged = match git::staged_files(&cwd) {
+                Ok(staged) => staged,
+                Err(message) => return CliRunResult::InvalidOptions { message },
+            };
+            paths.retain(|path| {
+                let contents = fs::canonicalize(path).ok().and_then(|path| staged.remove(&path));
+                contents.map(|contents| file_contents.insert(path.clone(), contents)).is_some()
+            });
+        }
+
+        let mut oxlintrc = match Self::load_config(basic_options.config.as_deref()) {
+            Ok(oxlintrc) => oxlintrc,
+            Err(e) => return e,
+        };
         enable_plugins.apply_overrides(&mut oxlintrc.plugins);
 
         let oxlintrc_for_print =
             if misc_options.print_config { Some(oxlintrc.clone()) } else { None };
-        let builder = LinterBuilder::from_oxlintrc(false, oxlintrc);
-        // Gracefully report any linter builder errors as CLI errors
-        let builder = match builder {
+        let builder = match Self::linter_builder(oxlintrc) {
             Ok(builder) => builder,
-            Err(err) => match err {
-                LinterBuilderError::UnknownRules { rules } => {
-                    let rules = rules.iter().map(|r| r.full_name()).collect::<Vec<_>>().join("\n");
-                    let error = Error::from(OxcDiagnostic::warn(format!(
-                        "The following rules do not match the currently supported rules:\n{rules}"
-                    )));
-                    return CliRunResult::LintError { error: format!("{error:?}") };
-                }
-            },
+            Err(e) => return e,
         };
-        let builder = builder.with_filters(filter).with_fix(fix_options.fix_kind());
+        let report_fixes =
+            matches!(output_options.format, OutputFormat::JsonWithFixes | OutputFormat::Html);
+        let fix_kind = match fix_options.fix_kind() {
+            // Report safe fixes unless other kinds were asked for.
+            kind if report_fixes && kind.is_none() => FixKind::SafeFix,
+            kind => kind,
+        };
+        let builder = builder.with_filters(filter).with_fix(fix_kind);
 
         if let Some(basic_config_file) = oxlintrc_for_print {
-            return CliRunResult::PrintConfigResult {
-                config_file: builder.resolve_final_config_file(basic_config_file),
+            let config_file = match print_config_path {
+                Some(path) => builder.resolve_config_file_for_path(basic_config_file, &path),
+                None => builder.resolve_final_config_file(basic_config_file),
             };
+            return CliRunResult::PrintConfigResult { config_file };
         }
 
-        let mut options =
-            LintServiceOptions::new(cwd, paths).with_cross_module(builder.plugins().has_import());
-        let linter = builder.build();
+        let mut options = LintServiceOptions::new(cwd, paths)
+            .with_cross_module(builder.plugins().has_import())
+            .with_report_fixes(report_fixes)
+            .with_file_contents(file_contents);
+        if let Some(changed_paths) = changed_paths {
+            options = options.with_changed_files(changed_paths);
+        }
+        let linter = builder.build().with_timing(misc_options.timing);
 
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
@@ -159,9 +239,23 @@ impl Runner for LintRunner {
             }
         }
 
+        let baseline = match &warning_options.baseline {
+            Some(path) if !warning_options.generate_baseline => {
+                match baseline::read(path, options.cwd()) {
+                    Ok(baseline) => Some(baseline),
+                    Err(message) => return CliRunResult::InvalidOptions { message },
+                }
+            }
+            _ => None,
+        };
+        let recorded_baseline =
+            warning_options.generate_baseline.then(|| Baseline::new(options.cwd()));
+
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
-            Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
+            Self::get_diagnostic_service(&warning_options, &output_options, &misc_options)
+                .with_baseline(baseline)
+                .with_baseline_recording(recorded_baseline);
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -173,12 +267,23 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if let Some(baseline) = diagnostic_service.take_recorded_baseline() {
+            let path =
+                warning_options.baseline.unwrap_or_else(|| PathBuf::from(baseline::DEFAULT_PATH));
+            if let Err(error) = baseline::write(&path, &baseline) {
+                return CliRunResult::LintError { error };
+            }
+            return CliRunResult::BaselineResult { path, number_of_problems: baseline.len() };
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
-            number_of_files,
+            number_of_files: lint_service.number_of_files(),
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
+            rule_counts: diagnostic_service.rule_counts(),
+            rule_timings: lint_service.linter().rule_timings().unwrap_or_default(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
             print_summary: matches!(output_options.format, OutputFormat::Default),
@@ -194,19 +299,166 @@ impl LintRunner {
     ) -> DiagnosticService {
         let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_silent(misc_options.silent)
-            .with_max_warnings(warning_options.max_warnings);
+            // Problems are recorded instead of reported.
+            .with_silent(misc_options.silent || warning_options.generate_baseline)
+            .with_max_warnings(warning_options.max_warnings)
+            .with_max_diagnostics_per_file(output_options.max_diagnostics_per_file)
+            .with_max_diagnostics_per_rule(output_options.max_diagnostics_per_rule);
 
         match output_options.format {
-            OutputFormat::Default => {}
+            OutputFormat::Default => {
+                if output_options.no_color || output_options.width.is_some() {
+                    let mut handler = GraphicalReportHandler::new();
+                    if output_options.no_color {
+                        handler = handler.with_theme(GraphicalTheme::unicode_nocolor());
+                    }
+                    if let Some(width) = output_options.width {
+                        handler = handler.with_width(width);
+                    }
+                    diagnostic_service.set_graphical_reporter(handler);
+                }
+            }
+            OutputFormat::Compact => diagnostic_service.set_compact_reporter(),
             OutputFormat::Json => diagnostic_service.set_json_reporter(),
+            OutputFormat::JsonWithFixes => diagnostic_service.set_json_with_fixes_reporter(),
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            OutputFormat::Html => diagnostic_service.set_html_reporter(),
         }
         diagnostic_service
     }
 
+    /// Parse `paths` and print their ESTree ASTs, one after another.
+    fn print_ast(paths: &[Box<Path>]) -> CliRunResult {
+        let mut asts = Vec::with_capacity(paths.len());
+        for path in paths {
+            let source_text = match fs::read_to_string(path) {
+                Ok(source_text) => source_text,
+                Err(err) => {
+                    return CliRunResult::LintError {
+                        error: format!("Failed to read {}: {err}", path.display()),
+                    };
+                }
+            };
+            let source_type = SourceType::from_path(path).unwrap_or_default();
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            if let Some(error) = ret.errors.into_iter().next() {
+                let source = NamedSource::new(path.to_string_lossy(), source_text.clone());
+                let error = error.with_source_code(source);
+                return CliRunResult::LintError { error: format!("{error:?}") };
+            }
+            asts.push(serde_json::to_string_pretty(&ret.program.to_estree()).unwrap());
+        }
+        CliRunResult::PrintAstResult { ast: asts.join("\n") }
+    }
+
+    fn validate_config(config_path: Option<&Path>, enable_plugins: &EnablePlugins) -> CliRunResult {
+        let Some(config_path) = config_path else {
+            return CliRunResult::InvalidOptions {
+                message: "--validate-config requires a configuration file passed with -c."
+                    .to_string(),
+            };
+        };
+        let source_text = match fs::read_to_string(config_path) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to read {}: {err}", config_path.display()),
+                };
+            }
+        };
+
+        let diagnostics = oxc_linter::validate_config(config_path, &source_text, |plugins| {
+            enable_plugins.apply_overrides(plugins);
+        });
+        let handler = GraphicalReportHandler::new();
+        let mut report = String::new();
+        for diagnostic in &diagnostics {
+            let source = NamedSource::new(config_path.to_string_lossy(), source_text.clone());
+            let error = diagnostic.clone().with_source_code(source);
+            handler.render_report(&mut report, error.as_ref()).unwrap();
+        }
+        CliRunResult::ValidateConfigResult { report, number_of_errors: diagnostics.len() }
+    }
+
+    fn migrate(eslint_config: &Path) -> CliRunResult {
+        let mut source_text = match fs::read_to_string(eslint_config) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to read {}: {err}", eslint_config.display()),
+                };
+            }
+        };
+        let json = json_strip_comments::strip(&mut source_text)
+            .map_err(|err| err.to_string())
+            .and_then(|()| serde_json::from_str(&source_text).map_err(|err| err.to_string()));
+        let migration = match json
+            .map_err(|err| OxcDiagnostic::error(format!("Invalid JSON: {err}")))
+            .and_then(|json| oxc_linter::migrate_eslint_config(&json))
+        {
+            Ok(migration) => migration,
+            Err(diagnostic) => {
+                let error = Error::from(diagnostic.with_help(
+                    "Only JSON is supported. Export a JavaScript flat config to JSON first",
+                ));
+                return CliRunResult::LintError {
+                    error: format!("Failed to migrate {}: {error:?}", eslint_config.display()),
+                };
+            }
+        };
+        CliRunResult::MigrateResult {
+            config: serde_json::to_string_pretty(&migration.oxlintrc).unwrap(),
+            unsupported_rules: migration.unsupported_rules,
+            warnings: migration.warnings,
+        }
+    }
+
+    fn load_config(config_path: Option<&Path>) -> Result<Oxlintrc, CliRunResult> {
+        let Some(config_path) = config_path else {
+            return Ok(Oxlintrc::default());
+        };
+        Oxlintrc::from_file(config_path).map_err(|diagnostic| {
+            let handler = GraphicalReportHandler::new();
+            let mut err = String::new();
+            handler.render_report(&mut err, &diagnostic).unwrap();
+            CliRunResult::InvalidOptions {
+                message: format!("Failed to parse configuration file.\n{err}"),
+            }
+        })
+    }
+
+    // Gracefully report any linter builder errors as CLI errors
+    fn linter_builder(oxlintrc: Oxlintrc) -> Result<LinterBuilder, CliRunResult> {
+        LinterBuilder::from_oxlintrc(false, oxlintrc).map_err(|err| match err {
+            LinterBuilderError::UnknownRules { rules } => {
+                let rules = rules.iter().map(|r| r.full_name()).collect::<Vec<_>>().join("\n");
+                let error = Error::from(OxcDiagnostic::warn(format!(
+                    "The following rules do not match the currently supported rules:\n{rules}"
+                )));
+                CliRunResult::LintError { error: format!("{error:?}") }
+            }
+            LinterBuilderError::InvalidRuleOptions { rules } => {
+                let errors = rules
+                    .iter()
+                    .map(|(rule, message)| format!("Invalid options for rule `{rule}`: {message}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let error = Error::from(OxcDiagnostic::error(errors));
+                CliRunResult::LintError { error: format!("{error:?}") }
+            }
+            LinterBuilderError::ExternalPlugin { path, message } => {
+                let error = Error::from(OxcDiagnostic::error(format!(
+                    "Failed to load plugin {}: {message}",
+                    path.display()
+                )));
+                CliRunResult::LintError { error: format!("{error:?}") }
+            }
+        })
+    }
+
     // moved into a separate function for readability, but it's only ever used
     // in one place.
     fn get_filters(
@@ -312,6 +564,35 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn rule_counts() {
+        let args = &["fixtures/linter/debugger.js", "fixtures/linter/nan.js"];
+        let result = test(args);
+        let rule_counts = result
+            .rule_counts
+            .iter()
+            .map(|count| (count.rule.as_str(), count.warnings, count.errors))
+            .collect::<Vec<_>>();
+        assert_eq!(rule_counts, [("eslint(no-debugger)", 1, 0), ("eslint(use-isnan)", 1, 0)]);
+    }
+
+    #[test]
+    fn timing() {
+        let args = &["fixtures/linter/debugger.js"];
+        assert!(test(args).rule_timings.is_empty());
+        let args = &["--timing", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.rule_timings.len(), result.number_of_rules);
+    }
+
+    #[test]
+    fn max_warnings() {
+        let args = &["--max-warnings", "1", "fixtures/linter/debugger.js"];
+        assert!(!test(args).max_warnings_exceeded);
+        let args = &["--max-warnings", "0", "fixtures/linter/debugger.js"];
+        assert!(test(args).max_warnings_exceeded);
+    }
+
     #[test]
     fn wrong_extension() {
         let args = &["foo.asdf"];
@@ -402,6 +683,79 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn external_plugin_not_found() {
+        let args = &["-c", "fixtures/external_plugin/.oxlintrc.json", "fixtures/external_plugin"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        let CliRunResult::LintError { error } = LintRunner::new(options).run() else {
+            panic!("expected a lint error");
+        };
+        assert!(error.contains("Failed to load plugin"), "{error}");
+        assert!(error.contains("does-not-exist.so"), "{error}");
+    }
+
+    #[test]
+    fn invalid_rule_options() {
+        let args = &[
+            "-c",
+            "fixtures/invalid_rule_options/.oxlintrc.json",
+            "fixtures/invalid_rule_options",
+        ];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        let CliRunResult::LintError { error } = LintRunner::new(options).run() else {
+            panic!("expected a lint error");
+        };
+        assert!(error.contains("Invalid options for rule `no-restricted-syntax`"), "{error}");
+    }
+
+    #[test]
+    fn overrides() {
+        let args = &["-c", "fixtures/overrides/.oxlintrc.json", "fixtures/overrides/index.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+
+        let args = &["-c", "fixtures/overrides/.oxlintrc.json", "fixtures/overrides/index.test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn overrides_relative_to_config() {
+        // no paths: the current working directory is linted
+        let args = &["-A", "all", "-c", "fixtures/overrides_relative/.oxlintrc.json"];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 1);
+
+        let args = &[
+            "-A",
+            "all",
+            "-c",
+            "fixtures/overrides_relative/.oxlintrc.json",
+            "fixtures/overrides_relative",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn overrides_env() {
+        let args =
+            &["-c", "fixtures/overrides_env/.oxlintrc.json", "fixtures/overrides_env/index.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 2);
+
+        let args = &[
+            "-c",
+            "fixtures/overrides_env/.oxlintrc.json",
+            "fixtures/overrides_env/index.test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn eslintrc_no_undef() {
         let args = &[
@@ -494,7 +848,7 @@ mod test {
         ];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_warnings, 2);
         assert_eq!(result.number_of_errors, 0);
     }
 
@@ -523,6 +877,25 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn lint_flow_files() {
+        // `types.js` uses Flow syntax and is skipped, `index.js` is linted.
+        let args = &["fixtures/flow"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_commonjs_package() {
+        // `.js` files are still parsed as modules in packages with `"type": "commonjs"`
+        let args = &["-A", "all", "fixtures/commonjs_package"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn lint_empty_vue_file() {
         let args = &["fixtures/vue/empty.vue"];
@@ -546,10 +919,25 @@ mod test {
         let args = &["fixtures/svelte/debugger.svelte"];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_warnings, 1);
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn nursery() {
+        let args = &["--nursery", "fixtures/svelte/debugger.svelte"];
+        assert!(test(args).number_of_warnings > 1);
+
+        // Nursery rules can be enabled by name, e.g. `no-unused-vars`.
+        let args = &["-W", "no-unused-vars", "fixtures/svelte/debugger.svelte"];
+        assert_eq!(test(args).number_of_warnings, 2);
+
+        let args = &["-W", "all", "fixtures/svelte/debugger.svelte"];
+        let rules = test(args).number_of_rules;
+        let args = &["-W", "all", "--nursery", "fixtures/svelte/debugger.svelte"];
+        assert!(test(args).number_of_rules > rules);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -598,6 +986,21 @@ mod test {
         assert_eq!(result.number_of_errors, 1);
     }
 
+    #[test]
+    fn test_project_rules() {
+        let config = "fixtures/project_rules/.oxlintrc.json";
+        let args = &["--import-plugin", "-c", config, "fixtures/project_rules"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_errors, 1);
+
+        // `lib.js` is only visited to resolve imports, so it is not reported
+        let args = &["--import-plugin", "-c", config, "fixtures/project_rules/index.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn test_fix() {
         use std::fs;
@@ -655,4 +1058,153 @@ mod test {
             std::fs::read_to_string("fixtures/print_config/ban_rules/expect.json").unwrap();
         assert_eq!(config, expect_json.trim());
     }
+
+    #[test]
+    fn test_print_config_for_path() {
+        let args = &[
+            "-c",
+            "fixtures/overrides/.oxlintrc.json",
+            "-A",
+            "all",
+            "--print-config",
+            "fixtures/overrides/index.test.js",
+        ];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::PrintConfigResult { config_file: config } = ret else {
+            panic!("Expected PrintConfigResult, got {ret:?}")
+        };
+
+        let config: serde_json::Value = serde_json::from_str(&config).unwrap();
+        assert_eq!(config["rules"], serde_json::json!({ "no-console": "warn" }));
+        assert!(config.get("overrides").is_none());
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let args = &["-c", "fixtures/overrides/.oxlintrc.json", "--validate-config"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::ValidateConfigResult { number_of_errors: 0, .. } = ret else {
+            panic!("Expected a valid config, got {ret:?}")
+        };
+
+        let args = &["-c", "fixtures/validate_config/.oxlintrc.json", "--validate-config"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::ValidateConfigResult { report, number_of_errors } = ret else {
+            panic!("Expected ValidateConfigResult, got {ret:?}")
+        };
+        assert_eq!(number_of_errors, 2);
+        assert!(report.contains("Unknown rule `no-debuger`"), "{report}");
+        assert!(report.contains("invalid type: string \"error\", expected a sequence"), "{report}");
+        assert!(report.contains("fixtures/validate_config/.oxlintrc.json:3:5"), "{report}");
+
+        let args = &["--validate-config"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        assert!(matches!(ret, CliRunResult::InvalidOptions { .. }), "{ret:?}");
+    }
+
+    #[test]
+    fn test_explain() {
+        let args = &["-c", "fixtures/overrides/.oxlintrc.json", "--explain", "no-debugger"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::ExplainResult { explanation } = ret else {
+            panic!("Expected ExplainResult, got {ret:?}")
+        };
+        assert!(explanation.starts_with("# eslint/no-debugger\n"), "{explanation}");
+        assert!(explanation.contains("### What it does"), "{explanation}");
+        assert!(explanation.contains("Severity: deny\n"), "{explanation}");
+        assert!(explanation.contains("Severity for **/*.test.js: allow\n"), "{explanation}");
+
+        // Rules turned on or off on the command line are taken into account.
+        let args = &["-A", "all", "--explain", "eslint(no-debugger)"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::ExplainResult { explanation } = ret else {
+            panic!("Expected ExplainResult, got {ret:?}")
+        };
+        assert!(explanation.contains("Severity: allow\n"), "{explanation}");
+
+        let message = test_invalid_options(&["--explain", "no-debuger"]);
+        assert_eq!(message, "Unknown rule `no-debuger`. Run `oxlint --rules` to list all rules.");
+    }
+
+    #[test]
+    fn test_migrate() {
+        let args = &["--migrate", "fixtures/migrate/.eslintrc.json"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::MigrateResult { config, unsupported_rules, warnings } = ret else {
+            panic!("Expected MigrateResult, got {ret:?}")
+        };
+        assert_eq!(unsupported_rules, vec!["no-restricted-properties"]);
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        // The migrated config lints like the ESLint config.
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        let oxlintrc = temp_dir.path().join(".oxlintrc.json");
+        std::fs::write(&oxlintrc, config).unwrap();
+        let args = &["-c", oxlintrc.to_str().unwrap(), "fixtures/migrate/index.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_changed_since() {
+        let message = test_invalid_options(&["--changed-since", "not-a-ref", "fixtures/linter"]);
+        assert!(message.contains("git merge-base not-a-ref HEAD"));
+
+        let message = test_invalid_options(&["--changed-since=--output=x", "fixtures/linter"]);
+        assert!(message.contains("refs cannot start with `-`"));
+    }
+
+    #[test]
+    fn test_staged() {
+        let message = test_invalid_options(&["--staged", "--fix", "fixtures/linter"]);
+        assert!(message.contains("--staged cannot be used with --fix"));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        let baseline_path = temp_dir.path().join("baseline.json");
+        let baseline = baseline_path.to_str().unwrap();
+
+        let args = ["--generate-baseline", "--baseline", baseline, "fixtures/baseline/index.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::BaselineResult { number_of_problems, .. } = ret else {
+            panic!("Expected BaselineResult, got {ret:?}")
+        };
+        assert_eq!(number_of_problems, 2);
+
+        let result = test(&["--baseline", baseline, "fixtures/baseline/index.js"]);
+        assert_eq!(result.number_of_warnings, 0);
+        // Problems in other files are new.
+        let result = test(&["--baseline", baseline, "fixtures/linter/debugger.js"]);
+        assert_eq!(result.number_of_warnings, 1);
+
+        let options = lint_command().run_inner(&["--baseline", "does-not-exist.json"]).unwrap();
+        let ret = LintRunner::new(options).run();
+        assert!(matches!(ret, CliRunResult::InvalidOptions { .. }), "{ret:?}");
+    }
+
+    #[test]
+    fn test_print_ast() {
+        let args = &["--print-ast", "fixtures/linter/debugger.js"];
+        let options = lint_command().run_inner(args).unwrap();
+        let ret = LintRunner::new(options).run();
+        let CliRunResult::PrintAstResult { ast } = ret else {
+            panic!("Expected PrintAstResult, got {ret:?}")
+        };
+
+        let ast: serde_json::Value = serde_json::from_str(&ast).unwrap();
+        assert_eq!(ast["type"], "Program");
+        assert_eq!(ast["body"][0]["type"], "DebuggerStatement");
+        assert_eq!(ast["body"][0]["range"], serde_json::json!([0, 9]));
+    }
 }
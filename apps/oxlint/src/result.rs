@@ -4,6 +4,9 @@ use std::{
     time::Duration,
 };
 
+use oxc_diagnostics::RuleCount;
+use oxc_linter::RuleTiming;
+
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
@@ -22,6 +25,29 @@ pub enum CliRunResult {
     PrintConfigResult {
         config_file: String,
     },
+    PrintAstResult {
+        ast: String,
+    },
+    /// The documentation and configuration of a rule printed by `--explain`.
+    ExplainResult {
+        explanation: String,
+    },
+    /// A baseline written by `--generate-baseline`.
+    BaselineResult {
+        path: PathBuf,
+        number_of_problems: usize,
+    },
+    /// An oxlint configuration converted by `--migrate`.
+    MigrateResult {
+        config: String,
+        unsupported_rules: Vec<String>,
+        warnings: Vec<String>,
+    },
+    /// Problems found by `--validate-config`, rendered as a report.
+    ValidateConfigResult {
+        report: String,
+        number_of_errors: usize,
+    },
 }
 
 /// A summary of a complete linter run.
@@ -37,6 +63,10 @@ pub struct LintResult {
     pub number_of_warnings: usize,
     /// The number of errors that were found.
     pub number_of_errors: usize,
+    /// The number of warnings and errors found by each rule, most frequent first.
+    pub rule_counts: Vec<RuleCount>,
+    /// Time spent in each rule, slowest first. Empty unless `--timing` was passed.
+    pub rule_timings: Vec<RuleTiming>,
     /// Whether or not the maximum number of warnings was exceeded.
     pub max_warnings_exceeded: bool,
     /// Whether or not warnings should be treated as errors (from `--deny-warnings` for example)
@@ -45,22 +75,32 @@ pub struct LintResult {
     pub print_summary: bool,
 }
 
+impl CliRunResult {
+    /// No problems were found, or only warnings below the `--max-warnings` threshold.
+    pub const EXIT_SUCCESS: u8 = 0;
+    /// Errors were found, or warnings exceeded `--max-warnings` or were denied with
+    /// `--deny-warnings`.
+    pub const EXIT_LINT_FAILURE: u8 = 1;
+    /// The linter could not run, e.g. because of invalid options or a missing path.
+    pub const EXIT_TOOL_FAILURE: u8 = 2;
+}
+
 impl Termination for CliRunResult {
     #[allow(clippy::print_stdout, clippy::print_stderr)]
     fn report(self) -> ExitCode {
         match self {
-            Self::None => ExitCode::from(0),
+            Self::None => ExitCode::from(Self::EXIT_SUCCESS),
             Self::InvalidOptions { message } => {
                 println!("Invalid Options: {message}");
-                ExitCode::from(1)
+                ExitCode::from(Self::EXIT_TOOL_FAILURE)
             }
             Self::PathNotFound { paths } => {
                 println!("Path {paths:?} does not exist.");
-                ExitCode::from(1)
+                ExitCode::from(Self::EXIT_TOOL_FAILURE)
             }
             Self::LintError { error } => {
                 eprintln!("Error: {error}");
-                ExitCode::from(1)
+                ExitCode::from(Self::EXIT_TOOL_FAILURE)
             }
             Self::LintResult(LintResult {
                 duration,
@@ -68,6 +108,8 @@ impl Termination for CliRunResult {
                 number_of_files,
                 number_of_warnings,
                 number_of_errors,
+                rule_counts,
+                rule_timings,
                 max_warnings_exceeded,
                 deny_warnings,
                 print_summary,
@@ -78,6 +120,7 @@ impl Termination for CliRunResult {
 
                     if number_of_diagnostics > 0 {
                         println!();
+                        Self::print_rule_counts(&rule_counts);
                     }
 
                     let time = Self::get_execution_time(&duration);
@@ -90,29 +133,155 @@ impl Termination for CliRunResult {
                         println!(
                             "Exceeded maximum number of warnings. Found {number_of_warnings}."
                         );
-                        return ExitCode::from(1);
+                    } else {
+                        println!(
+                            "Found {} and {}.",
+                            pluralize(number_of_warnings, "warning"),
+                            pluralize(number_of_errors, "error")
+                        );
                     }
+                }
 
-                    println!(
-                        "Found {number_of_warnings} warning{} and {number_of_errors} error{}.",
-                        if number_of_warnings == 1 { "" } else { "s" },
-                        if number_of_errors == 1 { "" } else { "s" }
-                    );
+                if !rule_timings.is_empty() {
+                    // Keep stdout parseable for machine-readable formats.
+                    if print_summary {
+                        println!("\n{}", Self::format_rule_timings(&rule_timings));
+                    } else {
+                        eprintln!("{}", Self::format_rule_timings_json(&rule_timings));
+                    }
                 }
 
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
-                ExitCode::from(exit_code)
+                let failed = max_warnings_exceeded
+                    || (number_of_warnings > 0 && deny_warnings)
+                    || number_of_errors > 0;
+                ExitCode::from(if failed { Self::EXIT_LINT_FAILURE } else { Self::EXIT_SUCCESS })
             }
             Self::PrintConfigResult { config_file } => {
                 println!("{config_file}");
-                ExitCode::from(0)
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::PrintAstResult { ast } => {
+                println!("{ast}");
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::ExplainResult { explanation } => {
+                print!("{explanation}");
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::BaselineResult { path, number_of_problems } => {
+                println!(
+                    "Wrote {} to {}.",
+                    pluralize(number_of_problems, "problem"),
+                    path.display()
+                );
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::MigrateResult { config, unsupported_rules, warnings } => {
+                println!("{config}");
+                // Keep stdout a valid config so it can be redirected into a file.
+                for warning in warnings {
+                    eprintln!("Warning: {warning}");
+                }
+                if !unsupported_rules.is_empty() {
+                    eprintln!(
+                        "The following rules are not supported by oxlint:\n{}",
+                        unsupported_rules.join("\n")
+                    );
+                }
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::ValidateConfigResult { number_of_errors: 0, .. } => {
+                println!("Configuration file is valid.");
+                ExitCode::from(Self::EXIT_SUCCESS)
+            }
+            Self::ValidateConfigResult { report, number_of_errors } => {
+                println!("{report}");
+                println!(
+                    "Found {} in the configuration file.",
+                    pluralize(number_of_errors, "problem")
+                );
+                ExitCode::from(Self::EXIT_LINT_FAILURE)
             }
         }
     }
 }
 
 impl CliRunResult {
+    #[allow(clippy::print_stdout)]
+    fn print_rule_counts(rule_counts: &[RuleCount]) {
+        let Some(width) = rule_counts.iter().map(|count| count.rule.len()).max() else {
+            return;
+        };
+        for RuleCount { rule, warnings, errors } in rule_counts {
+            let counts = match (*warnings, *errors) {
+                (warnings, 0) => pluralize(warnings, "warning"),
+                (0, errors) => pluralize(errors, "error"),
+                (warnings, errors) => {
+                    format!("{}, {}", pluralize(warnings, "warning"), pluralize(errors, "error"))
+                }
+            };
+            println!("  {rule:<width$}  {counts}");
+        }
+        println!();
+    }
+
+    /// Number of rules shown by `--timing`.
+    const TIMING_RULES: usize = 10;
+
+    /// Format the slowest rules as a table, like ESLint's `TIMING` output.
+    fn format_rule_timings(rule_timings: &[RuleTiming]) -> String {
+        let total = rule_timings.iter().map(|timing| timing.duration).sum::<Duration>();
+        let rows = rule_timings
+            .iter()
+            .take(Self::TIMING_RULES)
+            .map(|timing| {
+                let name = format!("{}/{}", timing.plugin_name, timing.rule_name);
+                let ms = format!("{:.3}", timing.duration.as_secs_f64() * 1000.0);
+                let relative = if total.is_zero() {
+                    0.0
+                } else {
+                    timing.duration.as_secs_f64() / total.as_secs_f64() * 100.0
+                };
+                (name, ms, format!("{relative:.1}%"), timing.files)
+            })
+            .collect::<Vec<_>>();
+        let name_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0).max(4);
+        let ms_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0).max(9);
+        let mut table = format!(
+            "{:<name_width$} | {:>ms_width$} | Relative | Files\n{}-|-{}-|----------|------\n",
+            "Rule",
+            "Time (ms)",
+            "-".repeat(name_width),
+            "-".repeat(ms_width)
+        );
+        for (name, ms, relative, files) in rows {
+            table.push_str(&format!(
+                "{name:<name_width$} | {ms:>ms_width$} | {relative:>8} | {files:>5}\n"
+            ));
+        }
+        table
+    }
+
+    /// Format the timings of every rule that ran as a JSON object, slowest first:
+    /// `{"rules":[{"rule":"eslint/no-debugger","timeMs":0.042,"files":3}]}`
+    fn format_rule_timings_json(rule_timings: &[RuleTiming]) -> String {
+        let rules = rule_timings
+            .iter()
+            .filter(|timing| timing.files > 0 || !timing.duration.is_zero())
+            .map(|timing| {
+                format!(
+                    r#"{{"rule":"{}/{}","timeMs":{:.3},"files":{}}}"#,
+                    timing.plugin_name,
+                    timing.rule_name,
+                    timing.duration.as_secs_f64() * 1000.0,
+                    timing.files
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(r#"{{"rules":[{rules}]}}"#)
+    }
+
     fn get_execution_time(duration: &Duration) -> String {
         let ms = duration.as_millis();
         if ms < 1000 {
@@ -122,3 +291,8 @@ impl CliRunResult {
         }
     }
 }
+
+fn pluralize(count: usize, noun: &str) -> String {
+    let s = if count == 1 { "" } else { "s" };
+    format!("{count} {noun}{s}")
+}
@@ -153,6 +153,8 @@ mod test {
             ignore_path: OsString::from(".gitignore"),
             ignore_pattern: vec![],
             symlinks: false,
+            changed_since: None,
+            staged: false,
         };
 
         let mut paths = Walk::new(&fixtures, &ignore_options)
@@ -0,0 +1,34 @@
+use std::process::Command;
+
+use serde_json::Value;
+
+/// Run the `oxlint` binary from `apps/oxlint` and parse its stdout as JSON.
+fn lint_json(args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_oxlint"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    serde_json::from_str(&stdout).unwrap_or_else(|err| panic!("{err} in:\n{stdout}"))
+}
+
+#[test]
+fn json() {
+    let output = lint_json(&["-f", "json", "fixtures/linter/debugger.js"]);
+    let [diagnostic] = output.as_array().unwrap().as_slice() else {
+        panic!("expected one diagnostic in {output}");
+    };
+    assert_eq!(diagnostic["code"], "eslint(no-debugger)");
+    assert_eq!(diagnostic["filename"], "fixtures/linter/debugger.js");
+    assert_eq!(diagnostic["category"], "correctness");
+    assert_eq!(diagnostic["labels"][0]["span"]["offset"], 0);
+    assert!(diagnostic.get("fix").is_none());
+
+    let output = lint_json(&["-f", "json-with-fixes", "fixtures/linter/debugger.js"]);
+    assert_eq!(output[0]["fix"]["span"]["length"], 9);
+    assert_eq!(output[0]["fix"]["content"], "");
+
+    let output = lint_json(&["-f", "json", "-A", "all", "fixtures/linter/debugger.js"]);
+    assert_eq!(output, Value::Array(vec![]));
+}
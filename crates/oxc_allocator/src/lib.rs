@@ -51,12 +51,14 @@ mod address;
 mod boxed;
 mod clone_in;
 mod convert;
+mod pool;
 mod vec;
 
 pub use address::{Address, GetAddress};
 pub use boxed::Box;
 pub use clone_in::CloneIn;
 pub use convert::{FromIn, IntoIn};
+pub use pool::{AllocatorGuard, AllocatorPool};
 pub use vec::Vec;
 
 /// A bump-allocated memory arena based on [bumpalo].
@@ -0,0 +1,98 @@
+use std::{mem::ManuallyDrop, ops::Deref, sync::Mutex};
+
+use crate::Allocator;
+
+/// A thread-safe pool of [`Allocator`]s, for reusing arenas across many short-lived
+/// jobs, such as linting each file in a project.
+///
+/// An allocator is reset when it is returned to the pool, so its memory can be
+/// reused by the next job without releasing it to the system and allocating it again.
+///
+/// ## Example
+/// ```
+/// use oxc_allocator::AllocatorPool;
+///
+/// let pool = AllocatorPool::new(4);
+/// let allocator = pool.get();
+/// let x = allocator.alloc(1);
+/// // `allocator` is reset and returned to the pool when dropped.
+/// ```
+#[derive(Default)]
+pub struct AllocatorPool {
+    allocators: Mutex<Vec<Allocator>>,
+}
+
+impl AllocatorPool {
+    /// Create a pool with `size` allocators, usually the number of threads that use it.
+    ///
+    /// The pool grows as needed if more than `size` allocators are in use at once.
+    pub fn new(size: usize) -> Self {
+        let allocators = std::iter::repeat_with(Allocator::default).take(size).collect();
+        Self { allocators: Mutex::new(allocators) }
+    }
+
+    /// Take an allocator from the pool, or create a new one if the pool is empty.
+    ///
+    /// The allocator is returned to the pool when the [`AllocatorGuard`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread panicked while holding the lock on the pool.
+    pub fn get(&self) -> AllocatorGuard<'_> {
+        let allocator = self.allocators.lock().unwrap().pop().unwrap_or_default();
+        AllocatorGuard { allocator: ManuallyDrop::new(allocator), pool: self }
+    }
+
+    fn add(&self, mut allocator: Allocator) {
+        allocator.reset();
+        self.allocators.lock().unwrap().push(allocator);
+    }
+}
+
+/// An [`Allocator`] borrowed from an [`AllocatorPool`]. Resets the allocator and
+/// returns it to the pool when dropped.
+pub struct AllocatorGuard<'p> {
+    allocator: ManuallyDrop<Allocator>,
+    pool: &'p AllocatorPool,
+}
+
+impl Deref for AllocatorGuard<'_> {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.allocator
+    }
+}
+
+impl Drop for AllocatorGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `self.allocator` is not used again after being taken, as `self` is being dropped.
+        let allocator = unsafe { ManuallyDrop::take(&mut self.allocator) };
+        self.pool.add(allocator);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AllocatorPool;
+
+    #[test]
+    fn reuses_allocators() {
+        let pool = AllocatorPool::new(1);
+        let first = {
+            let allocator = pool.get();
+            std::ptr::from_ref(allocator.alloc(1u64))
+        };
+        assert_eq!(pool.allocators.lock().unwrap().len(), 1);
+
+        // The allocator is reset, so the same memory is handed out again.
+        let allocator = pool.get();
+        assert_eq!(std::ptr::from_ref(allocator.alloc(2u64)), first);
+        assert!(pool.allocators.lock().unwrap().is_empty());
+
+        // The pool grows when all of its allocators are in use.
+        let another = pool.get();
+        drop((allocator, another));
+        assert_eq!(pool.allocators.lock().unwrap().len(), 2);
+    }
+}
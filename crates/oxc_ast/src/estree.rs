@@ -0,0 +1,662 @@
+//! Conversion of the AST to [ESTree] shaped JSON.
+//!
+//! The `Serialize` implementations of AST nodes follow ESTree for most nodes, but keep oxc's own
+//! shape where the two differ, e.g. for literals, member expressions, function parameters and
+//! assignment targets. [`Program::to_estree`] rewrites those nodes into the shape ESTree tools
+//! expect, using [typescript-eslint]'s definitions for TypeScript and JSX nodes, and gives every
+//! node a `range` and `loc` like ESLint's parser does.
+//!
+//! [ESTree]: <https://github.com/estree/estree>
+//! [typescript-eslint]: <https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/ast-spec>
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    ast::{CommentKind, Program},
+    AstKind,
+};
+
+/// Largest integer a JavaScript number can hold exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+impl<'a> Program<'a> {
+    /// Convert to [ESTree](https://github.com/estree/estree) compatible JSON.
+    ///
+    /// Every node has `start` and `end` offsets, a `[start, end]` `range` and a `loc` with 1-based
+    /// lines and 0-based columns. Offsets and columns count UTF-16 code units, as in JavaScript
+    /// strings. Comments are listed in the `comments` field of the program.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the AST can't be serialized, which only happens if it is malformed.
+    pub fn to_estree(&self) -> Value {
+        let converter = EstreeConverter::new(self.source_text);
+        let mut program = converter.convert(serde_json::to_value(self).unwrap());
+        if let Value::Object(program) = &mut program {
+            let comments = self
+                .comments
+                .iter()
+                .map(|comment| {
+                    let span = comment.real_span();
+                    json!({
+                        "type": match comment.kind {
+                            CommentKind::Line => "Line",
+                            CommentKind::Block => "Block",
+                        },
+                        "start": span.start,
+                        "end": span.end,
+                        "value": comment.span.source_text(self.source_text),
+                    })
+                })
+                .collect();
+            program.insert("comments".to_string(), Value::Array(comments));
+        }
+        converter.add_locations(&mut program);
+        program
+    }
+
+    /// Convert to [ESTree](https://github.com/estree/estree) compatible JSON text.
+    /// See [`Program::to_estree`].
+    pub fn to_estree_json(&self) -> String {
+        self.to_estree().to_string()
+    }
+}
+
+impl AstKind<'_> {
+    /// Convert the node to [ESTree](https://github.com/estree/estree) compatible JSON, like
+    /// [`Program::to_estree`] does for a whole program.
+    ///
+    /// Nodes keep their UTF-8 `start` and `end` offsets and have no `range` or `loc`. Nodes which
+    /// have no ESTree equivalent, such as [`AstKind::Argument`], are converted to their contents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node can't be serialized, which only happens if it is malformed.
+    pub fn to_estree(&self, source_text: &str) -> Value {
+        macro_rules! serialize {
+            ($($variant:ident),* $(,)?) => {
+                match self {
+                    $(Self::$variant(node) => serde_json::to_value(node),)*
+                }
+            };
+        }
+        let value = serialize!(
+            BooleanLiteral,
+            NullLiteral,
+            NumericLiteral,
+            BigIntLiteral,
+            RegExpLiteral,
+            StringLiteral,
+            Program,
+            IdentifierName,
+            IdentifierReference,
+            BindingIdentifier,
+            LabelIdentifier,
+            ThisExpression,
+            ArrayExpression,
+            ArrayExpressionElement,
+            Elision,
+            ObjectExpression,
+            ObjectProperty,
+            PropertyKey,
+            TemplateLiteral,
+            TaggedTemplateExpression,
+            MemberExpression,
+            CallExpression,
+            NewExpression,
+            MetaProperty,
+            SpreadElement,
+            Argument,
+            UpdateExpression,
+            UnaryExpression,
+            BinaryExpression,
+            PrivateInExpression,
+            LogicalExpression,
+            ConditionalExpression,
+            AssignmentExpression,
+            AssignmentTarget,
+            SimpleAssignmentTarget,
+            AssignmentTargetPattern,
+            ArrayAssignmentTarget,
+            ObjectAssignmentTarget,
+            AssignmentTargetWithDefault,
+            SequenceExpression,
+            Super,
+            AwaitExpression,
+            ChainExpression,
+            ParenthesizedExpression,
+            Directive,
+            Hashbang,
+            BlockStatement,
+            VariableDeclaration,
+            VariableDeclarator,
+            EmptyStatement,
+            ExpressionStatement,
+            IfStatement,
+            DoWhileStatement,
+            WhileStatement,
+            ForStatement,
+            ForStatementInit,
+            ForInStatement,
+            ForOfStatement,
+            ContinueStatement,
+            BreakStatement,
+            ReturnStatement,
+            WithStatement,
+            SwitchStatement,
+            SwitchCase,
+            LabeledStatement,
+            ThrowStatement,
+            TryStatement,
+            CatchClause,
+            CatchParameter,
+            DebuggerStatement,
+            AssignmentPattern,
+            ObjectPattern,
+            ArrayPattern,
+            BindingRestElement,
+            Function,
+            FormalParameters,
+            FormalParameter,
+            FunctionBody,
+            ArrowFunctionExpression,
+            YieldExpression,
+            Class,
+            ClassBody,
+            MethodDefinition,
+            PropertyDefinition,
+            PrivateIdentifier,
+            StaticBlock,
+            ModuleDeclaration,
+            ImportExpression,
+            ImportDeclaration,
+            ImportSpecifier,
+            ImportDefaultSpecifier,
+            ImportNamespaceSpecifier,
+            ExportNamedDeclaration,
+            ExportDefaultDeclaration,
+            ExportAllDeclaration,
+            ExportSpecifier,
+            TSThisParameter,
+            TSEnumDeclaration,
+            TSEnumMember,
+            TSTypeAnnotation,
+            TSLiteralType,
+            TSConditionalType,
+            TSUnionType,
+            TSIntersectionType,
+            TSParenthesizedType,
+            TSIndexedAccessType,
+            TSNamedTupleMember,
+            TSAnyKeyword,
+            TSStringKeyword,
+            TSBooleanKeyword,
+            TSNumberKeyword,
+            TSNeverKeyword,
+            TSIntrinsicKeyword,
+            TSUnknownKeyword,
+            TSNullKeyword,
+            TSUndefinedKeyword,
+            TSVoidKeyword,
+            TSSymbolKeyword,
+            TSThisType,
+            TSObjectKeyword,
+            TSBigIntKeyword,
+            TSTypeReference,
+            TSTypeName,
+            TSQualifiedName,
+            TSTypeParameterInstantiation,
+            TSTypeParameter,
+            TSTypeParameterDeclaration,
+            TSTypeAliasDeclaration,
+            TSClassImplements,
+            TSInterfaceDeclaration,
+            TSPropertySignature,
+            TSMethodSignature,
+            TSConstructSignatureDeclaration,
+            TSInterfaceHeritage,
+            TSModuleDeclaration,
+            TSModuleBlock,
+            TSTypeLiteral,
+            TSInferType,
+            TSTypeQuery,
+            TSImportType,
+            TSMappedType,
+            TSTemplateLiteralType,
+            TSAsExpression,
+            TSSatisfiesExpression,
+            TSTypeAssertion,
+            TSImportEqualsDeclaration,
+            TSModuleReference,
+            TSExternalModuleReference,
+            TSNonNullExpression,
+            Decorator,
+            TSExportAssignment,
+            TSInstantiationExpression,
+            JSXElement,
+            JSXOpeningElement,
+            JSXClosingElement,
+            JSXFragment,
+            JSXElementName,
+            JSXNamespacedName,
+            JSXMemberExpression,
+            JSXMemberExpressionObject,
+            JSXExpressionContainer,
+            JSXAttributeItem,
+            JSXSpreadAttribute,
+            JSXIdentifier,
+            JSXText
+        );
+        EstreeConverter::without_locations(source_text).convert(value.unwrap())
+    }
+}
+
+struct EstreeConverter<'s> {
+    source_text: &'s str,
+    /// UTF-16 offset of each UTF-8 offset, or `None` if the source text is ASCII.
+    utf16_offsets: Option<Vec<u32>>,
+    /// UTF-16 offset of the start of each line.
+    line_starts: Vec<u32>,
+}
+
+impl<'s> EstreeConverter<'s> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &'s str) -> Self {
+        let utf16_offsets = (!source_text.is_ascii()).then(|| {
+            let mut offsets = Vec::with_capacity(source_text.len() + 1);
+            let mut offset = 0;
+            for c in source_text.chars() {
+                offsets.extend(std::iter::repeat(offset).take(c.len_utf8()));
+                offset += c.len_utf16() as u32;
+            }
+            offsets.push(offset);
+            offsets
+        });
+
+        let mut line_starts = vec![0];
+        let mut offset = 0;
+        let mut chars = source_text.chars().peekable();
+        while let Some(c) = chars.next() {
+            offset += c.len_utf16() as u32;
+            match c {
+                '\r' => {
+                    if chars.next_if_eq(&'\n').is_some() {
+                        offset += 1;
+                    }
+                    line_starts.push(offset);
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => line_starts.push(offset),
+                _ => {}
+            }
+        }
+
+        Self { source_text, utf16_offsets, line_starts }
+    }
+
+    /// A converter for [`EstreeConverter::convert`] only, which doesn't need the offset tables.
+    fn without_locations(source_text: &'s str) -> Self {
+        Self { source_text, utf16_offsets: None, line_starts: vec![] }
+    }
+
+    /// Convert nodes bottom up, so each node sees its children in their final shape.
+    fn convert(&self, value: Value) -> Value {
+        match value {
+            Value::Array(elements) => {
+                Value::Array(elements.into_iter().map(|element| self.convert(element)).collect())
+            }
+            Value::Object(node) => {
+                let node =
+                    node.into_iter().map(|(key, value)| (key, self.convert(value))).collect();
+                self.convert_node(node)
+            }
+            // `f64`s are written as `1.0`, where JavaScript would write `1`.
+            #[allow(clippy::cast_possible_truncation)]
+            Value::Number(number) => match number.as_f64() {
+                Some(float)
+                    if number.is_f64()
+                        && float.fract() == 0.0
+                        && float.abs() <= MAX_SAFE_INTEGER =>
+                {
+                    Value::from(float as i64)
+                }
+                _ => Value::Number(number),
+            },
+            value => value,
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn convert_node(&self, mut node: Map<String, Value>) -> Value {
+        let Some(Value::String(kind)) = node.get("type") else {
+            return Value::Object(node);
+        };
+        let kind = kind.clone();
+
+        // `this` parameters are the first parameter in ESTree.
+        if let Some(Value::Object(this_param)) = node.remove("thisParam") {
+            if let Some(Value::Array(params)) = node.get_mut("params") {
+                params.insert(0, Value::Object(this_param));
+            }
+        }
+        // typescript-eslint renamed type arguments, and keeps the old name for compatibility.
+        for (old, new) in
+            [("typeParameters", "typeArguments"), ("superTypeParameters", "superTypeArguments")]
+        {
+            if node.get(old).and_then(|value| value.get("type"))
+                == Some(&Value::from("TSTypeParameterInstantiation"))
+            {
+                let type_arguments = field(&node, old).clone();
+                node.insert(new.to_string(), type_arguments);
+            }
+        }
+
+        match kind.as_str() {
+            "Program" => {
+                let module_kind =
+                    node.get("sourceType").and_then(|source_type| source_type.get("moduleKind"));
+                let source_type =
+                    if module_kind == Some(&Value::from("script")) { "script" } else { "module" };
+                node.insert("sourceType".to_string(), source_type.into());
+                node.remove("hashbang");
+                let directives = take(&mut node, "directives");
+                let body = take(&mut node, "body");
+                node.insert("body".to_string(), concat(directives, body));
+            }
+            "Directive" => set_type(&mut node, "ExpressionStatement"),
+            "FunctionBody" => {
+                set_type(&mut node, "BlockStatement");
+                let directives = take(&mut node, "directives");
+                let statements = take(&mut node, "statements");
+                node.insert("body".to_string(), concat(directives, statements));
+            }
+            "FormalParameters" => return take(&mut node, "items"),
+            "FormalParameter" => {
+                let mut pattern = take(&mut node, "pattern");
+                let decorators = take(&mut node, "decorators");
+                let is_parameter_property = !field(&node, "accessibility").is_null()
+                    || field(&node, "readonly") == true
+                    || field(&node, "override") == true;
+                if is_parameter_property {
+                    let fields = [
+                        ("accessibility", take(&mut node, "accessibility")),
+                        ("readonly", take(&mut node, "readonly")),
+                        ("override", take(&mut node, "override")),
+                        ("static", false.into()),
+                        ("decorators", decorators),
+                        ("parameter", pattern),
+                    ];
+                    return new_node("TSParameterProperty", &node, fields);
+                }
+                if decorators.as_array().is_some_and(|decorators| !decorators.is_empty()) {
+                    if let Value::Object(pattern) = &mut pattern {
+                        pattern.insert("decorators".to_string(), decorators);
+                    }
+                }
+                return pattern;
+            }
+            "TSThisParameter" => {
+                set_type(&mut node, "Identifier");
+                node.insert("name".to_string(), "this".into());
+                node.insert("optional".to_string(), false.into());
+            }
+            "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression" => {
+                node.insert("expression".to_string(), false.into());
+            }
+            "ArrowFunctionExpression" => {
+                node.insert("id".to_string(), Value::Null);
+                node.insert("generator".to_string(), false.into());
+                // Expression bodies are serialized as a block with a single expression statement.
+                if field(&node, "expression") == true {
+                    let expression = node
+                        .get_mut("body")
+                        .and_then(|body| body.get_mut("body"))
+                        .and_then(|body| body.get_mut(0))
+                        .and_then(|statement| statement.get_mut("expression"))
+                        .map(Value::take);
+                    if let Some(expression) = expression {
+                        node.insert("body".to_string(), expression);
+                    }
+                }
+            }
+            "BooleanLiteral" | "NullLiteral" | "NumericLiteral" | "StringLiteral" => {
+                self.literal(&mut node);
+            }
+            "BigIntLiteral" => {
+                self.literal(&mut node);
+                let raw = field(&node, "raw").as_str().unwrap_or_default();
+                let bigint =
+                    raw.trim_end_matches('n').chars().filter(|&c| c != '_').collect::<String>();
+                node.insert("bigint".to_string(), bigint.into());
+            }
+            "RegExpLiteral" => {
+                self.literal(&mut node);
+                node.insert("value".to_string(), Value::Null);
+                let raw = field(&node, "raw").as_str().unwrap_or_default();
+                let pattern = raw.rfind('/').and_then(|end| raw.get(1..end)).unwrap_or_default();
+                let flags = node.get("regex").and_then(|regex| regex.get("flags")).cloned();
+                node.insert(
+                    "regex".to_string(),
+                    json!({ "pattern": pattern, "flags": flags.unwrap_or_else(|| "".into()) }),
+                );
+            }
+            "StaticMemberExpression" | "ComputedMemberExpression" | "PrivateFieldExpression" => {
+                let property = ["property", "expression", "field"]
+                    .into_iter()
+                    .find_map(|key| node.remove(key))
+                    .unwrap_or_default();
+                let fields = [
+                    ("object", take(&mut node, "object")),
+                    ("property", property),
+                    ("computed", (kind == "ComputedMemberExpression").into()),
+                    ("optional", take(&mut node, "optional")),
+                ];
+                return new_node("MemberExpression", &node, fields);
+            }
+            "ParenthesizedExpression" | "CatchParameter" => {
+                let key = if kind == "CatchParameter" { "pattern" } else { "expression" };
+                return take(&mut node, key);
+            }
+            "ObjectProperty" => {
+                set_type(&mut node, "Property");
+                node.remove("init");
+            }
+            "BindingProperty" => {
+                set_type(&mut node, "Property");
+                node.insert("kind".to_string(), "init".into());
+                node.insert("method".to_string(), false.into());
+            }
+            "ArrayAssignmentTarget" => set_type(&mut node, "ArrayPattern"),
+            "ObjectAssignmentTarget" => set_type(&mut node, "ObjectPattern"),
+            "AssignmentTargetWithDefault" => {
+                let left = take(&mut node, "binding");
+                let right = take(&mut node, "init");
+                return new_node("AssignmentPattern", &node, [("left", left), ("right", right)]);
+            }
+            "AssignmentTargetPropertyIdentifier" => {
+                let binding = take(&mut node, "binding");
+                let init = take(&mut node, "init");
+                let value = if init.is_null() {
+                    binding.clone()
+                } else {
+                    new_node(
+                        "AssignmentPattern",
+                        &node,
+                        [("left", binding.clone()), ("right", init)],
+                    )
+                };
+                return property(&node, binding, value, true, false);
+            }
+            "AssignmentTargetPropertyProperty" => {
+                let computed = self.slice(&node).starts_with('[');
+                let key = take(&mut node, "name");
+                let value = take(&mut node, "binding");
+                return property(&node, key, value, false, computed);
+            }
+            "ImportExpression" => {
+                let options = match take(&mut node, "arguments") {
+                    Value::Array(mut arguments) if !arguments.is_empty() => {
+                        arguments.swap_remove(0)
+                    }
+                    _ => Value::Null,
+                };
+                node.insert("options".to_string(), options);
+            }
+            "ImportDeclaration" | "ExportNamedDeclaration" | "ExportAllDeclaration" => {
+                let attributes = match take(&mut node, "withClause") {
+                    Value::Object(mut with_clause) => take(&mut with_clause, "withEntries"),
+                    _ => Value::Array(vec![]),
+                };
+                node.insert("attributes".to_string(), attributes);
+            }
+            "ExportDefaultDeclaration" => {
+                node.remove("exported");
+            }
+            "PrivateInExpression" => set_type(&mut node, "BinaryExpression"),
+            "JSXText" => {
+                let raw = self.slice(&node).to_string();
+                node.insert("raw".to_string(), raw.into());
+            }
+            "TSMappedType" => {
+                for key in ["optional", "readonly"] {
+                    let modifier = match node.get(key).and_then(Value::as_str) {
+                        Some("true") => true.into(),
+                        Some("none") => false.into(),
+                        _ => continue,
+                    };
+                    node.insert(key.to_string(), modifier);
+                }
+            }
+            _ => {}
+        }
+        Value::Object(node)
+    }
+
+    /// Source text of `node`, using its UTF-8 offsets.
+    fn slice(&self, node: &Map<String, Value>) -> &'s str {
+        let offset = |key: &str| {
+            node.get(key).and_then(Value::as_u64).and_then(|offset| usize::try_from(offset).ok())
+        };
+        match (offset("start"), offset("end")) {
+            (Some(start), Some(end)) => self.source_text.get(start..end).unwrap_or_default(),
+            _ => "",
+        }
+    }
+
+    fn literal(&self, node: &mut Map<String, Value>) {
+        set_type(node, "Literal");
+        let raw = self.slice(node).to_string();
+        node.insert("raw".to_string(), raw.into());
+        node.entry("value").or_insert(Value::Null);
+    }
+
+    /// Convert UTF-8 `start` and `end` offsets to UTF-16, and add `range` and `loc`.
+    fn add_locations(&self, value: &mut Value) {
+        match value {
+            Value::Array(elements) => {
+                for element in elements {
+                    self.add_locations(element);
+                }
+            }
+            Value::Object(node) => {
+                let span = (
+                    node.get("start").and_then(Value::as_u64),
+                    node.get("end").and_then(Value::as_u64),
+                );
+                if let (true, (Some(start), Some(end))) = (node.contains_key("type"), span) {
+                    let (start, end) = (self.utf16_offset(start), self.utf16_offset(end));
+                    node.insert("start".to_string(), start.into());
+                    node.insert("end".to_string(), end.into());
+                    node.insert("range".to_string(), json!([start, end]));
+                    node.insert(
+                        "loc".to_string(),
+                        json!({ "start": self.position(start), "end": self.position(end) }),
+                    );
+                }
+                for (key, child) in node.iter_mut() {
+                    if key != "loc" && key != "range" {
+                        self.add_locations(child);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn utf16_offset(&self, offset: u64) -> u32 {
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX).min(self.source_text.len());
+        match &self.utf16_offsets {
+            Some(offsets) => offsets[offset],
+            None => offset as u32,
+        }
+    }
+
+    fn position(&self, offset: u32) -> Value {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        json!({ "line": line, "column": offset - self.line_starts[line - 1] })
+    }
+}
+
+fn take(node: &mut Map<String, Value>, key: &str) -> Value {
+    node.remove(key).unwrap_or_default()
+}
+
+fn set_type(node: &mut Map<String, Value>, kind: &str) {
+    node.insert("type".to_string(), kind.into());
+}
+
+fn concat(first: Value, second: Value) -> Value {
+    match (first, second) {
+        (Value::Array(mut first), Value::Array(second)) => {
+            first.extend(second);
+            Value::Array(first)
+        }
+        (Value::Array(first), _) => Value::Array(first),
+        (_, second) => second,
+    }
+}
+
+fn property(
+    node: &Map<String, Value>,
+    key: Value,
+    value: Value,
+    shorthand: bool,
+    computed: bool,
+) -> Value {
+    new_node(
+        "Property",
+        node,
+        [
+            ("key", key),
+            ("value", value),
+            ("kind", "init".into()),
+            ("method", false.into()),
+            ("shorthand", shorthand.into()),
+            ("computed", computed.into()),
+        ],
+    )
+}
+
+/// A node of type `kind` with the same span as `like`.
+fn new_node<const N: usize>(
+    kind: &str,
+    like: &Map<String, Value>,
+    fields: [(&str, Value); N],
+) -> Value {
+    let mut node = Map::with_capacity(N + 3);
+    node.insert("type".to_string(), kind.into());
+    node.insert("start".to_string(), field(like, "start").clone());
+    node.insert("end".to_string(), field(like, "end").clone());
+    for (key, value) in fields {
+        node.insert(key.to_string(), value);
+    }
+    Value::Object(node)
+}
+
+/// Field `key` of `node`, or `null` if it has none.
+fn field<'n>(node: &'n Map<String, Value>, key: &str) -> &'n Value {
+    node.get(key).unwrap_or(&Value::Null)
+}
@@ -8,7 +8,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::ast::*;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AstType {
     BooleanLiteral,
     NullLiteral,
@@ -177,6 +177,11 @@ pub enum AstType {
     JSXText,
 }
 
+impl AstType {
+    /// Number of [`AstType`] variants. `ty as usize` is always less than this.
+    pub const COUNT: usize = 165;
+}
+
 /// Untyped AST Node Kind
 #[derive(Debug, Clone, Copy)]
 pub enum AstKind<'a> {
@@ -521,6 +526,178 @@ impl<'a> GetSpan for AstKind<'a> {
 }
 
 impl<'a> AstKind<'a> {
+    /// The type of this node, without the reference to it.
+    #[inline]
+    pub fn ty(&self) -> AstType {
+        match self {
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::NumericLiteral(_) => AstType::NumericLiteral,
+            Self::BigIntLiteral(_) => AstType::BigIntLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::Program(_) => AstType::Program,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrayExpressionElement(_) => AstType::ArrayExpressionElement,
+            Self::Elision(_) => AstType::Elision,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ObjectProperty(_) => AstType::ObjectProperty,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::Argument(_) => AstType::Argument,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::PrivateInExpression(_) => AstType::PrivateInExpression,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetPattern(_) => AstType::AssignmentTargetPattern,
+            Self::ArrayAssignmentTarget(_) => AstType::ArrayAssignmentTarget,
+            Self::ObjectAssignmentTarget(_) => AstType::ObjectAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::Super(_) => AstType::Super,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::ChainExpression(_) => AstType::ChainExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::Directive(_) => AstType::Directive,
+            Self::Hashbang(_) => AstType::Hashbang,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::CatchParameter(_) => AstType::CatchParameter,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::BindingRestElement(_) => AstType::BindingRestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::ArrowFunctionExpression(_) => AstType::ArrowFunctionExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::Class(_) => AstType::Class,
+            Self::ClassBody(_) => AstType::ClassBody,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::ImportExpression(_) => AstType::ImportExpression,
+            Self::ImportDeclaration(_) => AstType::ImportDeclaration,
+            Self::ImportSpecifier(_) => AstType::ImportSpecifier,
+            Self::ImportDefaultSpecifier(_) => AstType::ImportDefaultSpecifier,
+            Self::ImportNamespaceSpecifier(_) => AstType::ImportNamespaceSpecifier,
+            Self::ExportNamedDeclaration(_) => AstType::ExportNamedDeclaration,
+            Self::ExportDefaultDeclaration(_) => AstType::ExportDefaultDeclaration,
+            Self::ExportAllDeclaration(_) => AstType::ExportAllDeclaration,
+            Self::ExportSpecifier(_) => AstType::ExportSpecifier,
+            Self::TSThisParameter(_) => AstType::TSThisParameter,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSConditionalType(_) => AstType::TSConditionalType,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSParenthesizedType(_) => AstType::TSParenthesizedType,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSNamedTupleMember(_) => AstType::TSNamedTupleMember,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSStringKeyword(_) => AstType::TSStringKeyword,
+            Self::TSBooleanKeyword(_) => AstType::TSBooleanKeyword,
+            Self::TSNumberKeyword(_) => AstType::TSNumberKeyword,
+            Self::TSNeverKeyword(_) => AstType::TSNeverKeyword,
+            Self::TSIntrinsicKeyword(_) => AstType::TSIntrinsicKeyword,
+            Self::TSUnknownKeyword(_) => AstType::TSUnknownKeyword,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSUndefinedKeyword(_) => AstType::TSUndefinedKeyword,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSSymbolKeyword(_) => AstType::TSSymbolKeyword,
+            Self::TSThisType(_) => AstType::TSThisType,
+            Self::TSObjectKeyword(_) => AstType::TSObjectKeyword,
+            Self::TSBigIntKeyword(_) => AstType::TSBigIntKeyword,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSTypeName(_) => AstType::TSTypeName,
+            Self::TSQualifiedName(_) => AstType::TSQualifiedName,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSClassImplements(_) => AstType::TSClassImplements,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSConstructSignatureDeclaration(_) => AstType::TSConstructSignatureDeclaration,
+            Self::TSInterfaceHeritage(_) => AstType::TSInterfaceHeritage,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSInferType(_) => AstType::TSInferType,
+            Self::TSTypeQuery(_) => AstType::TSTypeQuery,
+            Self::TSImportType(_) => AstType::TSImportType,
+            Self::TSMappedType(_) => AstType::TSMappedType,
+            Self::TSTemplateLiteralType(_) => AstType::TSTemplateLiteralType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSSatisfiesExpression(_) => AstType::TSSatisfiesExpression,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSModuleReference(_) => AstType::TSModuleReference,
+            Self::TSExternalModuleReference(_) => AstType::TSExternalModuleReference,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::TSExportAssignment(_) => AstType::TSExportAssignment,
+            Self::TSInstantiationExpression(_) => AstType::TSInstantiationExpression,
+            Self::JSXElement(_) => AstType::JSXElement,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXClosingElement(_) => AstType::JSXClosingElement,
+            Self::JSXFragment(_) => AstType::JSXFragment,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::JSXNamespacedName(_) => AstType::JSXNamespacedName,
+            Self::JSXMemberExpression(_) => AstType::JSXMemberExpression,
+            Self::JSXMemberExpressionObject(_) => AstType::JSXMemberExpressionObject,
+            Self::JSXExpressionContainer(_) => AstType::JSXExpressionContainer,
+            Self::JSXAttributeItem(_) => AstType::JSXAttributeItem,
+            Self::JSXSpreadAttribute(_) => AstType::JSXSpreadAttribute,
+            Self::JSXIdentifier(_) => AstType::JSXIdentifier,
+            Self::JSXText(_) => AstType::JSXText,
+        }
+    }
+
     #[inline]
     pub fn as_boolean_literal(&self) -> Option<&'a BooleanLiteral> {
         if let Self::BooleanLiteral(v) = self {
@@ -2010,3 +2187,1166 @@ impl<'a> AstKind<'a> {
         }
     }
 }
+
+/// AST node types with a corresponding [`AstKind`] variant.
+///
+/// Allows looking up nodes by their type, e.g.
+/// `CallExpression::from_ast_kind(kind)` is equivalent to `kind.as_call_expression()`.
+pub trait FromAstKind<'a>: Sized {
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self>;
+}
+
+impl<'a> FromAstKind<'a> for BooleanLiteral {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_boolean_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for NullLiteral {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_null_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for NumericLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_numeric_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BigIntLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_big_int_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for RegExpLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_reg_exp_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for StringLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_string_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Program<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_program()
+    }
+}
+
+impl<'a> FromAstKind<'a> for IdentifierName<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_identifier_name()
+    }
+}
+
+impl<'a> FromAstKind<'a> for IdentifierReference<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_identifier_reference()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BindingIdentifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_binding_identifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for LabelIdentifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_label_identifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ThisExpression {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_this_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ArrayExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_array_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ArrayExpressionElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_array_expression_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Elision {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_elision()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ObjectExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_object_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ObjectProperty<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_object_property()
+    }
+}
+
+impl<'a> FromAstKind<'a> for PropertyKey<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_property_key()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TemplateLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_template_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TaggedTemplateExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_tagged_template_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for MemberExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_member_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for CallExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_call_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for NewExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_new_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for MetaProperty<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_meta_property()
+    }
+}
+
+impl<'a> FromAstKind<'a> for SpreadElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_spread_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Argument<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_argument()
+    }
+}
+
+impl<'a> FromAstKind<'a> for UpdateExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_update_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for UnaryExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_unary_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BinaryExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_binary_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for PrivateInExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_private_in_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for LogicalExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_logical_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ConditionalExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_conditional_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AssignmentExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_assignment_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AssignmentTarget<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_assignment_target()
+    }
+}
+
+impl<'a> FromAstKind<'a> for SimpleAssignmentTarget<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_simple_assignment_target()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AssignmentTargetPattern<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_assignment_target_pattern()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ArrayAssignmentTarget<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_array_assignment_target()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ObjectAssignmentTarget<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_object_assignment_target()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AssignmentTargetWithDefault<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_assignment_target_with_default()
+    }
+}
+
+impl<'a> FromAstKind<'a> for SequenceExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_sequence_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Super {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_super()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AwaitExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_await_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ChainExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_chain_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ParenthesizedExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_parenthesized_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Directive<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_directive()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Hashbang<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_hashbang()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BlockStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_block_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for VariableDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_variable_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for VariableDeclarator<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_variable_declarator()
+    }
+}
+
+impl<'a> FromAstKind<'a> for EmptyStatement {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_empty_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ExpressionStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_expression_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for IfStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_if_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for DoWhileStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_do_while_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for WhileStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_while_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ForStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_for_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ForStatementInit<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_for_statement_init()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ForInStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_for_in_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ForOfStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_for_of_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ContinueStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_continue_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BreakStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_break_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ReturnStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_return_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for WithStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_with_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for SwitchStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_switch_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for SwitchCase<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_switch_case()
+    }
+}
+
+impl<'a> FromAstKind<'a> for LabeledStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_labeled_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ThrowStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_throw_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TryStatement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_try_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for CatchClause<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_catch_clause()
+    }
+}
+
+impl<'a> FromAstKind<'a> for CatchParameter<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_catch_parameter()
+    }
+}
+
+impl<'a> FromAstKind<'a> for DebuggerStatement {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_debugger_statement()
+    }
+}
+
+impl<'a> FromAstKind<'a> for AssignmentPattern<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_assignment_pattern()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ObjectPattern<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_object_pattern()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ArrayPattern<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_array_pattern()
+    }
+}
+
+impl<'a> FromAstKind<'a> for BindingRestElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_binding_rest_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Function<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_function()
+    }
+}
+
+impl<'a> FromAstKind<'a> for FormalParameters<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_formal_parameters()
+    }
+}
+
+impl<'a> FromAstKind<'a> for FormalParameter<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_formal_parameter()
+    }
+}
+
+impl<'a> FromAstKind<'a> for FunctionBody<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_function_body()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ArrowFunctionExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_arrow_function_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for YieldExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_yield_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Class<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_class()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ClassBody<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_class_body()
+    }
+}
+
+impl<'a> FromAstKind<'a> for MethodDefinition<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_method_definition()
+    }
+}
+
+impl<'a> FromAstKind<'a> for PropertyDefinition<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_property_definition()
+    }
+}
+
+impl<'a> FromAstKind<'a> for PrivateIdentifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_private_identifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for StaticBlock<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_static_block()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ModuleDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_module_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ImportExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_import_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ImportDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_import_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ImportSpecifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_import_specifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ImportDefaultSpecifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_import_default_specifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ImportNamespaceSpecifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_import_namespace_specifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ExportNamedDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_export_named_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ExportDefaultDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_export_default_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ExportAllDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_export_all_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for ExportSpecifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_export_specifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSThisParameter<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_this_parameter()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSEnumDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_enum_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSEnumMember<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_enum_member()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeAnnotation<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_annotation()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSLiteralType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_literal_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSConditionalType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_conditional_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSUnionType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_union_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSIntersectionType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_intersection_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSParenthesizedType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_parenthesized_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSIndexedAccessType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_indexed_access_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSNamedTupleMember<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_named_tuple_member()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSAnyKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_any_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSStringKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_string_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSBooleanKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_boolean_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSNumberKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_number_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSNeverKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_never_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSIntrinsicKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_intrinsic_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSUnknownKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_unknown_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSNullKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_null_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSUndefinedKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_undefined_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSVoidKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_void_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSSymbolKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_symbol_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSThisType {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_this_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSObjectKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_object_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSBigIntKeyword {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_big_int_keyword()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeReference<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_reference()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeName<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_name()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSQualifiedName<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_qualified_name()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeParameterInstantiation<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_parameter_instantiation()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeParameter<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_parameter()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeParameterDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_parameter_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeAliasDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_alias_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSClassImplements<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_class_implements()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSInterfaceDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_interface_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSPropertySignature<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_property_signature()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSMethodSignature<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_method_signature()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSConstructSignatureDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_construct_signature_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSInterfaceHeritage<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_interface_heritage()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSModuleDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_module_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSModuleBlock<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_module_block()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeLiteral<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_literal()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSInferType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_infer_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeQuery<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_query()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSImportType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_import_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSMappedType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_mapped_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTemplateLiteralType<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_template_literal_type()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSAsExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_as_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSSatisfiesExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_satisfies_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSTypeAssertion<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_type_assertion()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSImportEqualsDeclaration<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_import_equals_declaration()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSModuleReference<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_module_reference()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSExternalModuleReference<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_external_module_reference()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSNonNullExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_non_null_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for Decorator<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_decorator()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSExportAssignment<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_export_assignment()
+    }
+}
+
+impl<'a> FromAstKind<'a> for TSInstantiationExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_ts_instantiation_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXOpeningElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_opening_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXClosingElement<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_closing_element()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXFragment<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_fragment()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXElementName<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_element_name()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXNamespacedName<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_namespaced_name()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXMemberExpression<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_member_expression()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXMemberExpressionObject<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_member_expression_object()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXExpressionContainer<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_expression_container()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXAttributeItem<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_attribute_item()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXSpreadAttribute<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_spread_attribute()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXIdentifier<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_identifier()
+    }
+}
+
+impl<'a> FromAstKind<'a> for JSXText<'a> {
+    #[inline]
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+        kind.as_jsx_text()
+    }
+}
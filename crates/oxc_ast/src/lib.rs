@@ -22,7 +22,8 @@
 //! You can obtain an AST by parsing source code with a [`Parser`] from [`oxc_parser`].
 //!
 //! ## Cargo Features
-//! * `"serde"` enables support for serde serialization
+//! * `"serialize"` enables support for serde serialization, and conversion to [estree] JSON
+//!   with `Program::to_estree`
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -44,6 +45,8 @@
 #![allow(clippy::self_named_module_files)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "serialize")]
+mod estree;
 #[cfg(feature = "serialize")]
 mod serialize;
 
@@ -83,7 +86,7 @@ pub use crate::{
     ast::comment::{Comment, CommentKind, CommentPosition},
     ast_builder::AstBuilder,
     ast_builder_impl::NONE,
-    ast_kind::{AstKind, AstType},
+    ast_kind::{AstKind, AstType, FromAstKind},
     trivia::{comments_range, has_comments_between, CommentsRange},
     visit::{Visit, VisitMut},
 };
@@ -0,0 +1,46 @@
+//! Tests for the conversion of the AST to ESTree JSON.
+#![cfg(feature = "serialize")]
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn estree() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_typescript(true);
+    let source = "'use strict';\nlet s = '😀', o = { a: b.c };\n[x = 1, ...y] = z; // done\nfunction f(this: T, private p) {}";
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let program = ret.program.to_estree();
+    assert_eq!(program["sourceType"], "module");
+
+    let body = &program["body"];
+    assert_eq!(body[0]["type"], "ExpressionStatement");
+    assert_eq!(body[0]["directive"], "use strict");
+    let declarations = &body[1]["declarations"];
+    assert_eq!(declarations[0]["init"]["type"], "Literal");
+    assert_eq!(declarations[0]["init"]["raw"], "'😀'");
+    // Offsets count UTF-16 code units.
+    assert_eq!(declarations[0]["init"]["range"], serde_json::json!([22, 26]));
+    let property = &declarations[1]["init"]["properties"][0];
+    assert_eq!(property["type"], "Property");
+    assert_eq!(property["value"]["type"], "MemberExpression");
+    assert_eq!(property["value"]["computed"], false);
+    assert_eq!(
+        property["value"]["loc"],
+        serde_json::json!({ "start": { "line": 2, "column": 23 }, "end": { "line": 2, "column": 26 } })
+    );
+
+    let left = &body[2]["expression"]["left"];
+    assert_eq!(left["type"], "ArrayPattern");
+    assert_eq!(left["elements"][0]["type"], "AssignmentPattern");
+    assert_eq!(left["elements"][1]["type"], "RestElement");
+
+    let params = &body[3]["params"];
+    assert_eq!(params[0]["name"], "this");
+    assert_eq!(params[1]["type"], "TSParameterProperty");
+    assert_eq!(params[1]["parameter"]["name"], "p");
+
+    assert_eq!(program["comments"][0]["type"], "Line");
+    assert_eq!(program["comments"][0]["value"], " done");
+}
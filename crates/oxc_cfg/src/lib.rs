@@ -220,6 +220,83 @@ impl ControlFlowGraph {
         }
     }
 
+    /// Returns `true` if every code path starting at `from` ends in a `return`
+    /// or `throw`. This is usually called with the block of a function's
+    /// entry point, to check if the function always returns a value.
+    ///
+    /// `return;` only counts as returning if `allow_implicit_undefined` is
+    /// `true`. Falling off the end of the function never does.
+    pub fn always_returns(&self, from: BlockNodeId, allow_implicit_undefined: bool) -> bool {
+        let graph = &self.graph;
+        set_depth_first_search(graph, Some(from), |event| match event {
+            // We only need to check paths that are normal, jump or through a `finally` block.
+            DfsEvent::TreeEdge(a, b) => {
+                if graph.edges_connecting(a, b).any(|e| {
+                    matches!(
+                        e.weight(),
+                        EdgeType::Normal
+                            | EdgeType::Jump
+                            | EdgeType::Finalize
+                            | EdgeType::Join
+                            | EdgeType::Error(ErrorEdgeKind::Explicit)
+                    )
+                }) {
+                    Control::Continue
+                } else {
+                    Control::Prune
+                }
+            }
+            DfsEvent::Discover(basic_block_id, _) => {
+                let return_instruction =
+                    self.basic_block(basic_block_id).instructions().iter().find(|it| {
+                        match it.kind {
+                            // Throws are classified as returning.
+                            InstructionKind::Return(_) | InstructionKind::Throw => true,
+                            // Ignore irrelevant elements.
+                            InstructionKind::Break(_)
+                            | InstructionKind::Continue(_)
+                            | InstructionKind::Iteration(_)
+                            | InstructionKind::Unreachable
+                            | InstructionKind::Condition
+                            | InstructionKind::Statement => false,
+                        }
+                    });
+
+                let does_return = return_instruction.is_some_and(|ret| {
+                    !matches! { ret.kind,
+                    InstructionKind::Return(ReturnInstructionKind::ImplicitUndefined)
+                        if !allow_implicit_undefined
+                    }
+                });
+
+                // Continue walking this branch if control can flow out of it, as we
+                // haven't seen anything that will signify to us that this path of the
+                // program will definitely return or throw. A `finally` block entered by a
+                // `return` only delays it, so that path returns.
+                if graph.edges_directed(basic_block_id, Direction::Outgoing).any(|e| {
+                    match e.weight() {
+                        EdgeType::Jump
+                        | EdgeType::Normal
+                        | EdgeType::Backedge
+                        | EdgeType::Join
+                        | EdgeType::Error(ErrorEdgeKind::Explicit) => true,
+                        EdgeType::Finalize => !does_return,
+                        _ => false,
+                    }
+                }) {
+                    Control::Continue
+                } else if does_return {
+                    Control::Prune
+                } else {
+                    Control::Break(())
+                }
+            }
+            _ => Control::Continue,
+        })
+        .break_value()
+        .is_none()
+    }
+
     pub fn is_cyclic(&self, node: BlockNodeId) -> bool {
         set_depth_first_search(&self.graph, Some(node), |event| match event {
             DfsEvent::BackEdge(_, id) if id == node => Err(()),
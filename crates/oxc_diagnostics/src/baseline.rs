@@ -0,0 +1,202 @@
+use std::{
+    hash::{Hash, Hasher},
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::{primary_label, service::source_text, Error};
+
+/// Diagnostics that already exist in a code base. A
+/// [`DiagnosticService`](crate::DiagnosticService) with a baseline only reports new
+/// diagnostics, which allows adopting rules without fixing every existing problem first.
+///
+/// Diagnostics are identified by their file, rule and [fingerprint](BaselineEntry::fingerprint),
+/// which does not change when code is added or removed elsewhere in the file. A file can have
+/// several diagnostics with the same fingerprint; each entry of the baseline covers one of them.
+#[derive(Debug, Default, Clone)]
+pub struct Baseline {
+    /// Directory that file paths are relative to.
+    root: PathBuf,
+    /// Number of known diagnostics for each entry.
+    entries: FxHashMap<BaselineEntry, usize>,
+}
+
+/// A diagnostic recorded in a [`Baseline`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BaselineEntry {
+    /// Path of the file, relative to the root of the baseline, with `/` separators.
+    pub file: String,
+    /// The diagnostic code of the rule, e.g. `eslint(no-debugger)`.
+    pub rule: String,
+    pub fingerprint: String,
+}
+
+impl Baseline {
+    /// An empty baseline for files in `root`.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into(), entries: FxHashMap::default() }
+    }
+
+    pub fn insert(&mut self, entry: BaselineEntry) {
+        *self.entries.entry(entry).or_default() += 1;
+    }
+
+    /// Number of diagnostics in the baseline.
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// All entries, sorted by file, rule and fingerprint. An entry is repeated for each
+    /// diagnostic it covers.
+    pub fn entries(&self) -> Vec<BaselineEntry> {
+        let mut entries = self
+            .entries
+            .iter()
+            .flat_map(|(entry, count)| std::iter::repeat(entry).take(*count).cloned())
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+        entries
+    }
+
+    /// The entry for `diagnostic`, reported in the file at `path`, or [`None`] if it was not
+    /// reported by a rule.
+    pub fn entry(&self, path: &Path, diagnostic: &Error) -> Option<BaselineEntry> {
+        let rule = diagnostic.code()?.to_string();
+        let path = path.strip_prefix(&self.root).unwrap_or(path);
+        let file = path
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        Some(BaselineEntry { file, rule, fingerprint: BaselineEntry::fingerprint(diagnostic) })
+    }
+
+    /// Remove one occurrence of the entry for `diagnostic`. Returns `true` if the baseline
+    /// contained it, i.e. the diagnostic is not new.
+    pub(crate) fn remove(&mut self, path: &Path, diagnostic: &Error) -> bool {
+        let Some(entry) = self.entry(path, diagnostic) else {
+            return false;
+        };
+        match self.entries.get_mut(&entry) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                true
+            }
+            Some(_) => {
+                self.entries.remove(&entry);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl BaselineEntry {
+    /// Identifies a diagnostic within a file by its content rather than its offset, so it
+    /// survives edits to other lines: a hash of the rule, the source lines of the primary span,
+    /// and the position of the span within those lines. Whitespace is ignored, so reformatting
+    /// the lines doesn't change the fingerprint either.
+    ///
+    /// Falls back to the offset of the span if the diagnostic was not wrapped by
+    /// [`DiagnosticService::wrap_diagnostics`](crate::DiagnosticService::wrap_diagnostics).
+    pub fn fingerprint(diagnostic: &Error) -> String {
+        let mut hasher = FxHasher::default();
+        diagnostic.code().map(|code| code.to_string()).hash(&mut hasher);
+        if let Some(label) = primary_label(diagnostic.as_ref()) {
+            let (start, end) = (label.offset(), label.offset() + label.len());
+            match source_text(diagnostic).and_then(|text| {
+                Some((text.get(..start)?, text.get(start..end)?, text.get(end..)?))
+            }) {
+                Some((before, span, after)) => {
+                    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                    let line_end = after.find('\n').unwrap_or(after.len());
+                    hash_words(&before[line_start..], &mut hasher);
+                    hash_words(span, &mut hasher);
+                    hash_words(&after[..line_end], &mut hasher);
+                }
+                None => (start, end).hash(&mut hasher),
+            }
+        }
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// Hash `text` with whitespace ignored, but keeping words apart. Each part is terminated, so
+/// moving a word between the parts changes the hash.
+fn hash_words(text: &str, hasher: &mut FxHasher) {
+    for word in text.split_whitespace() {
+        word.hash(hasher);
+    }
+    0xffu8.hash(hasher);
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use super::{Baseline, BaselineEntry};
+    use crate::{DiagnosticService, Error, LabeledSpan, NamedSource, OxcDiagnostic};
+
+    fn debugger(offset: usize) -> Error {
+        let source = Arc::new(NamedSource::new("test.js", "debugger; debugger;".to_string()));
+        Error::new(
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(LabeledSpan::underline(offset..offset + 9)),
+        )
+        .with_source_code(source)
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut baseline = Baseline::new("/project");
+        let entry = baseline.entry(Path::new("/project/./src/test.js"), &debugger(0)).unwrap();
+        assert_eq!(entry.file, "src/test.js");
+        assert_eq!(entry.rule, "eslint(no-debugger)");
+        assert_eq!(entry.fingerprint, BaselineEntry::fingerprint(&debugger(0)));
+        assert!(baseline
+            .entry(Path::new("test.js"), &Error::new(OxcDiagnostic::error("x")))
+            .is_none());
+
+        baseline.insert(entry.clone());
+        baseline.insert(entry);
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline.entries().len(), 2);
+
+        let path = Path::new("src/test.js");
+        assert!(!baseline.remove(path, &debugger(10)));
+        assert!(baseline.remove(path, &debugger(0)));
+        assert!(baseline.remove(path, &debugger(0)));
+        assert!(!baseline.remove(path, &debugger(0)));
+        assert!(baseline.is_empty());
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let fingerprint = |source_text: &str| {
+            let offset = source_text.find("debugger").unwrap();
+            let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(LabeledSpan::underline(offset..offset + 8));
+            let (_, errors) =
+                DiagnosticService::wrap_diagnostics("test.js", source_text, vec![diagnostic]);
+            BaselineEntry::fingerprint(&errors[0])
+        };
+
+        let original = fingerprint("function foo() {\n  if (x) debugger;\n}\n");
+        // Lines added above, and the line reformatted.
+        assert_eq!(
+            original,
+            fingerprint("import x from 'x';\n\nfunction foo() {\n    if (x)   debugger;\n}\n")
+        );
+        // The line itself changed.
+        assert_ne!(original, fingerprint("function foo() {\n  if (y) debugger;\n}\n"));
+        assert_ne!(original, fingerprint("function foo() {\n  if (x) debugger; foo();\n}\n"));
+    }
+}
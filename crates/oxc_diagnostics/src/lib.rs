@@ -48,16 +48,20 @@
 //! service.run();
 //! ```
 
+mod baseline;
 mod reporter;
 mod service;
 
 use std::{
     borrow::Cow,
     fmt::{self, Display},
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
 };
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    baseline::{Baseline, BaselineEntry},
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple, RuleCount},
+};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
@@ -122,6 +126,21 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Category of the lint rule that reported this diagnostic, e.g. `correctness`.
+    pub category: Option<Cow<'static, str>>,
+    pub fix: Option<OxcFix>,
+}
+
+/// An edit that resolves a diagnostic: the source text in `span` is replaced with `content`.
+///
+/// Attached to diagnostics so that reporters can expose fixes to editors and other tools,
+/// without the tool re-running the linter in fix mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OxcFix {
+    /// Byte range of the source text to replace.
+    pub span: Range<usize>,
+    /// The replacement text. Empty for deletions.
+    pub content: String,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -178,6 +197,8 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                category: None,
+                fix: None,
             }),
         }
     }
@@ -192,6 +213,8 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                category: None,
+                fix: None,
             }),
         }
     }
@@ -288,6 +311,9 @@ impl OxcDiagnostic {
 
     /// Add multiple labels covering problematic portions of source code.
     ///
+    /// The first label is the one reporters anchor the diagnostic to, unless another is marked
+    /// as primary with [`Span::primary_label`]. Other labels are shown as related locations.
+    ///
     /// Existing labels will be removed. Use [`OxcDiagnostic::and_labels`] to append labels
     /// instead.
     ///
@@ -299,6 +325,7 @@ impl OxcDiagnostic {
     ///
     /// [`oxc_span::Span`]: https://docs.rs/oxc_span/latest/oxc_span/struct.Span.html
     /// [`label`]: https://docs.rs/oxc_span/latest/oxc_span/struct.Span.html#method.label
+    /// [`Span::primary_label`]: https://docs.rs/oxc_span/latest/oxc_span/struct.Span.html#method.primary_label
     pub fn with_labels<L: Into<LabeledSpan>, T: IntoIterator<Item = L>>(
         mut self,
         labels: T,
@@ -326,12 +353,44 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Attach an edit that replaces the source text in `span` with `content`.
+    pub fn with_fix<T: Into<String>>(mut self, span: Range<usize>, content: T) -> Self {
+        self.inner.fix = Some(OxcFix { span, content: content.into() });
+        self
+    }
+
+    /// Shift all labels, and the fix if there is one, forward by `offset` bytes, e.g. to map
+    /// spans in a `<script>` block back to the file it is embedded in.
+    pub fn with_label_offset(mut self, offset: usize) -> Self {
+        if let Some(fix) = &mut self.inner.fix {
+            fix.span = fix.span.start + offset..fix.span.end + offset;
+        }
+        if let Some(labels) = &mut self.inner.labels {
+            for label in labels.iter_mut() {
+                let text = label.label().map(ToString::to_string);
+                let span = (label.offset() + offset, label.len());
+                *label = if label.primary() {
+                    LabeledSpan::new_primary_with_span(text, span)
+                } else {
+                    LabeledSpan::new_with_span(text, span)
+                };
+            }
+        }
+        self
+    }
+
     /// Add a URL that provides more information about this diagnostic.
     pub fn with_url<S: Into<Cow<'static, str>>>(mut self, url: S) -> Self {
         self.inner.url = Some(url.into());
         self
     }
 
+    /// Set the category of the lint rule reporting this diagnostic, e.g. `correctness`.
+    pub fn with_category<S: Into<Cow<'static, str>>>(mut self, category: S) -> Self {
+        self.inner.category = Some(category.into());
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
@@ -339,3 +398,16 @@ impl OxcDiagnostic {
         Error::from(self).with_source_code(code)
     }
 }
+
+/// The label a diagnostic is anchored to: the one marked as primary, or the first one.
+///
+/// Reporters that show a single position per diagnostic (e.g. JSON or the
+/// language server) should use this instead of the first label.
+pub fn primary_label(diagnostic: &dyn Diagnostic) -> Option<LabeledSpan> {
+    let mut labels = diagnostic.labels()?;
+    let first = labels.next()?;
+    if first.primary() {
+        return Some(first);
+    }
+    labels.find(LabeledSpan::primary).or(Some(first))
+}
@@ -0,0 +1,70 @@
+use std::io::{BufWriter, Stdout, Write};
+
+use super::{writer, DiagnosticReporter, Info};
+use crate::{Error, Severity};
+
+/// Reports each diagnostic on a single line, as `file:line:column rule message`.
+///
+/// Meant for grep-based tooling and editor problem matchers.
+pub struct CompactReporter {
+    writer: BufWriter<Stdout>,
+}
+
+impl Default for CompactReporter {
+    fn default() -> Self {
+        Self { writer: writer() }
+    }
+}
+
+impl DiagnosticReporter for CompactReporter {
+    fn finish(&mut self) {
+        self.writer.flush().unwrap();
+    }
+
+    fn render_diagnostics(&mut self, s: &[u8]) {
+        self.writer.write_all(s).unwrap();
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        Some(format_compact(&error))
+    }
+}
+
+fn format_compact(diagnostic: &Error) -> String {
+    let Info { line, column, filename, message, severity, rule_id } = Info::new(diagnostic);
+    // Diagnostics without a rule, such as parse errors, show their severity instead.
+    let rule_id = diagnostic.code().map(|code| code.to_string()).or(rule_id).unwrap_or_else(|| {
+        match severity {
+            Severity::Error => "error",
+            _ => "warning",
+        }
+        .to_string()
+    });
+    format!("{filename}:{line}:{column} {rule_id} {message}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_compact;
+    use crate::{DiagnosticService, LabeledSpan, OxcDiagnostic};
+
+    #[test]
+    fn test_format_compact() {
+        let (_, errors) = DiagnosticService::wrap_diagnostics(
+            "src/main.js",
+            "let a = 1;\ndebugger;\n",
+            vec![
+                OxcDiagnostic::warn("`debugger` statement is not allowed")
+                    .with_error_code("eslint", "no-debugger")
+                    .with_label(LabeledSpan::underline(11..20)),
+                OxcDiagnostic::error("Unexpected token").with_label(LabeledSpan::underline(4..5)),
+            ],
+        );
+        let output = errors.iter().map(format_compact).collect::<String>();
+        assert_eq!(
+            output,
+            "src/main.js:2:1 eslint(no-debugger) `debugger` statement is not allowed\n\
+             src/main.js:1:5 error Unexpected token\n"
+        );
+    }
+}
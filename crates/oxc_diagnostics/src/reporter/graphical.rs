@@ -13,7 +13,13 @@ pub struct GraphicalReporter {
 
 impl Default for GraphicalReporter {
     fn default() -> Self {
-        Self { handler: GraphicalReportHandler::new(), writer: writer() }
+        Self::new(GraphicalReportHandler::new())
+    }
+}
+
+impl GraphicalReporter {
+    pub fn new(handler: GraphicalReportHandler) -> Self {
+        Self { handler, writer: writer() }
     }
 }
 
@@ -51,4 +57,8 @@ impl DiagnosticReporter for GraphicalReporter {
         self.handler.render_report(&mut output, error.as_ref()).unwrap();
         Some(output)
     }
+
+    fn is_human_readable(&self) -> bool {
+        true
+    }
 }
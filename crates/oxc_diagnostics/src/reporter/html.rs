@@ -0,0 +1,253 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use super::{DiagnosticReporter, Info};
+use crate::{
+    primary_label,
+    service::{as_oxc_diagnostic, source_text},
+    Error, Severity,
+};
+
+/// Renders a standalone HTML page with a summary of the problems found per
+/// rule, followed by each file's problems with a code frame.
+///
+/// Like [`JsonReporter`](super::JsonReporter), this reporter waits until all
+/// diagnostics have been reported before writing the page to the output stream.
+#[derive(Default)]
+pub struct HtmlReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for HtmlReporter {
+    #[allow(clippy::print_stdout)]
+    fn finish(&mut self) {
+        print!("{}", format_html(&self.diagnostics));
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+struct Problem {
+    info: Info,
+    message: String,
+    rule: Option<String>,
+    fixable: bool,
+    frame: Option<String>,
+}
+
+#[derive(Default)]
+struct RuleSummary {
+    errors: usize,
+    warnings: usize,
+    fixable: usize,
+}
+
+fn format_html(diagnostics: &[Error]) -> String {
+    let mut files = BTreeMap::<String, Vec<Problem>>::new();
+    let mut rules = BTreeMap::<String, RuleSummary>::new();
+    let (mut errors, mut warnings, mut fixable) = (0, 0, 0);
+    for diagnostic in diagnostics {
+        let info = Info::new(diagnostic);
+        let rule = diagnostic.code().map(|code| code.to_string()).or_else(|| info.rule_id.clone());
+        let is_fixable = as_oxc_diagnostic(diagnostic).is_some_and(|d| d.fix.is_some());
+        let is_error = matches!(info.severity, Severity::Error);
+        if is_error {
+            errors += 1;
+        } else {
+            warnings += 1;
+        }
+        fixable += usize::from(is_fixable);
+        if let Some(rule) = &rule {
+            let summary = rules.entry(rule.clone()).or_default();
+            if is_error {
+                summary.errors += 1;
+            } else {
+                summary.warnings += 1;
+            }
+            summary.fixable += usize::from(is_fixable);
+        }
+        let frame = code_frame(diagnostic);
+        files.entry(info.filename.clone()).or_default().push(Problem {
+            info,
+            message: diagnostic.to_string(),
+            rule,
+            fixable: is_fixable,
+            frame,
+        });
+    }
+
+    let mut html = String::from(HEADER);
+    let plural = |n: usize, word: &str| format!("{n} {word}{}", if n == 1 { "" } else { "s" });
+    writeln!(
+        html,
+        "<p class=\"summary\">{} ({}, {}) in {}, {} fixable</p>",
+        plural(errors + warnings, "problem"),
+        plural(errors, "error"),
+        plural(warnings, "warning"),
+        plural(files.len(), "file"),
+        fixable,
+    )
+    .unwrap();
+
+    // Rules with the most problems first.
+    let mut rules = rules.into_iter().collect::<Vec<_>>();
+    rules.sort_by_key(|(_, summary)| std::cmp::Reverse(summary.errors + summary.warnings));
+    html.push_str("<h2>Rules</h2>\n<table>\n");
+    html.push_str("<tr><th>Rule</th><th>Errors</th><th>Warnings</th><th>Fixable</th></tr>\n");
+    for (rule, RuleSummary { errors, warnings, fixable }) in &rules {
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{errors}</td><td>{warnings}</td><td>{fixable}</td></tr>",
+            escape(rule)
+        )
+        .unwrap();
+    }
+    html.push_str("</table>\n<h2>Files</h2>\n");
+
+    for (filename, problems) in &files {
+        writeln!(
+            html,
+            "<details open>\n<summary>{} <span class=\"count\">{}</span></summary>",
+            escape(filename),
+            plural(problems.len(), "problem")
+        )
+        .unwrap();
+        for Problem { info, message, rule, fixable, frame } in problems {
+            let severity = match info.severity {
+                Severity::Error => "error",
+                _ => "warning",
+            };
+            write!(
+                html,
+                "<div class=\"problem {severity}\"><span class=\"location\">{}:{}</span> <span class=\"severity\">{severity}</span> {}",
+                info.line,
+                info.column,
+                escape(message),
+            )
+            .unwrap();
+            if let Some(rule) = rule {
+                write!(html, " <span class=\"rule\">{}</span>", escape(rule)).unwrap();
+            }
+            if *fixable {
+                html.push_str(" <span class=\"fixable\">fixable</span>");
+            }
+            if let Some(frame) = frame {
+                write!(html, "\n<pre>{frame}</pre>").unwrap();
+            }
+            html.push_str("</div>\n");
+        }
+        html.push_str("</details>\n");
+    }
+
+    html.push_str(FOOTER);
+    html
+}
+
+/// The lines of source text covered by the diagnostic's primary label, with the
+/// label highlighted.
+fn code_frame(diagnostic: &Error) -> Option<String> {
+    let label = primary_label(diagnostic.as_ref())?;
+    let source = source_text(diagnostic)?;
+    let start = label.offset();
+    let end = start + label.len();
+    let line_start = source.get(..start)?.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source.get(end..)?.find('\n').map_or(source.len(), |i| end + i);
+    let first_line = source[..line_start].matches('\n').count() + 1;
+
+    let mut frame = String::new();
+    let mut offset = line_start;
+    for (i, line) in source[line_start..line_end].split('\n').enumerate() {
+        let next = offset + line.len();
+        let (mark_start, mark_end) = (start.clamp(offset, next), end.clamp(offset, next));
+        writeln!(
+            frame,
+            "<span class=\"line\">{:>4}</span> {}<mark>{}</mark>{}",
+            first_line + i,
+            escape(&source[offset..mark_start]),
+            escape(&source[mark_start..mark_end]),
+            escape(&source[mark_end..next]),
+        )
+        .unwrap();
+        offset = next + 1;
+    }
+    Some(frame)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const HEADER: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Oxlint Report</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2em; color: #1f2328; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 0.25em 0.75em; text-align: left; }
+summary { cursor: pointer; font-weight: bold; margin-top: 1em; }
+.count { color: #656d76; font-weight: normal; }
+.problem { margin: 0.5em 0 0.5em 1em; }
+.location, .rule { font-family: monospace; color: #656d76; }
+.severity { font-weight: bold; }
+.error .severity { color: #cf222e; }
+.warning .severity { color: #9a6700; }
+.fixable { background: #dafbe1; border-radius: 0.5em; padding: 0 0.5em; }
+pre { background: #f6f8fa; padding: 0.5em; overflow-x: auto; }
+.line { color: #656d76; user-select: none; }
+mark { background: #ffebe9; text-decoration: underline wavy #cf222e; }
+</style>
+</head>
+<body>
+<h1>Oxlint Report</h1>
+"#;
+
+const FOOTER: &str = "</body>\n</html>\n";
+
+#[cfg(test)]
+mod test {
+    use super::format_html;
+    use crate::{DiagnosticService, LabeledSpan, OxcDiagnostic};
+
+    #[test]
+    fn test_format_html() {
+        let (_, errors) = DiagnosticService::wrap_diagnostics(
+            "src/<main>.js",
+            "let a = 1;\ndebugger;\n",
+            vec![
+                OxcDiagnostic::warn("`debugger` statement is not allowed")
+                    .with_error_code("eslint", "no-debugger")
+                    .with_label(LabeledSpan::underline(11..20))
+                    .with_fix(11..20, ""),
+                OxcDiagnostic::error("'a' is assigned a value but never used")
+                    .with_error_code("eslint", "no-unused-vars")
+                    .with_label(LabeledSpan::underline(4..5)),
+            ],
+        );
+        let html = format_html(&errors);
+        assert!(html.contains("2 problems (1 error, 1 warning) in 1 file, 1 fixable"));
+        assert!(html.contains("<td>eslint(no-debugger)</td><td>0</td><td>1</td><td>1</td>"));
+        assert!(html.contains("<td>eslint(no-unused-vars)</td><td>1</td><td>0</td><td>0</td>"));
+        assert!(html.contains("<summary>src/&lt;main&gt;.js"));
+        assert!(html.contains("<span class=\"line\">   2</span> <mark>debugger;</mark>"));
+        assert!(html.contains("<span class=\"line\">   1</span> let <mark>a</mark> = 1;"));
+        assert!(html.contains("&#39;a&#39; is assigned a value but never used"));
+        assert_eq!(html.matches("class=\"fixable\"").count(), 1);
+    }
+}
@@ -1,7 +1,9 @@
 use miette::JSONReportHandler;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
 use super::DiagnosticReporter;
-use crate::Error;
+use crate::{service::as_oxc_diagnostic, Error, OxcFix};
 
 /// Renders reports as a JSON array of objects.
 ///
@@ -10,13 +12,25 @@ use crate::Error;
 #[derive(Default)]
 pub struct JsonReporter {
     diagnostics: Vec<Error>,
+    /// Include each diagnostic's fix, if it has one, as a `fix` property.
+    with_fixes: bool,
+}
+
+impl JsonReporter {
+    /// A reporter that also includes fixes, as
+    /// `"fix": { "span": { "offset": 0, "length": 9 }, "content": "" }`.
+    pub fn with_fixes() -> Self {
+        Self { diagnostics: vec![], with_fixes: true }
+    }
 }
 
 impl DiagnosticReporter for JsonReporter {
     // NOTE: this output does not conform to eslint json format yet
     // https://eslint.org/docs/latest/use/formatters/#json
+    #[allow(clippy::print_stdout)]
     fn finish(&mut self) {
-        format_json(&mut self.diagnostics);
+        println!("{}", format_json(&self.diagnostics, self.with_fixes));
+        self.diagnostics.clear();
     }
 
     fn render_diagnostics(&mut self, _s: &[u8]) {}
@@ -27,18 +41,100 @@ impl DiagnosticReporter for JsonReporter {
     }
 }
 
+/// A diagnostic as rendered by miette, with the fields miette doesn't know about.
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    #[serde(flatten)]
+    report: Map<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<JsonFix<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFix<'a> {
+    span: JsonSpan,
+    content: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    offset: usize,
+    length: usize,
+}
+
 /// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-json>
-#[allow(clippy::print_stdout)]
-fn format_json(diagnostics: &mut Vec<Error>) {
+fn format_json(diagnostics: &[Error], with_fixes: bool) -> String {
     let handler = JSONReportHandler::new();
     let messages = diagnostics
-        .drain(..)
+        .iter()
         .map(|error| {
-            let mut output = String::from("\t");
-            handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
+            let mut report = String::new();
+            handler.render_report(&mut report, error.as_ref()).unwrap();
+            let report = match serde_json::from_str(&report) {
+                Ok(Value::Object(report)) => report,
+                _ => Map::from_iter([("message".to_string(), Value::from(error.to_string()))]),
+            };
+            let diagnostic = as_oxc_diagnostic(error);
+            let category = diagnostic.and_then(|diagnostic| diagnostic.category.as_deref());
+            let fix =
+                diagnostic.and_then(|diagnostic| diagnostic.fix.as_ref()).filter(|_| with_fixes);
+            let fix = fix.map(|OxcFix { span, content }| JsonFix {
+                span: JsonSpan { offset: span.start, length: span.len() },
+                content,
+            });
+            let json = JsonDiagnostic { report, category, fix };
+            format!("\t{}", serde_json::to_string(&json).unwrap())
         })
         .collect::<Vec<_>>()
         .join(",\n");
-    println!("[\n{messages}\n]");
+    format!("[\n{messages}\n]")
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::format_json;
+    use crate::{DiagnosticService, LabeledSpan, OxcDiagnostic};
+
+    #[test]
+    fn test_format_json() {
+        let (_, errors) = DiagnosticService::wrap_diagnostics(
+            "src/main.js",
+            "let a = 1;\ndebugger;\n",
+            vec![
+                OxcDiagnostic::warn("`debugger` statement is \"not\" allowed")
+                    .with_error_code("eslint", "no-debugger")
+                    .with_category("correctness")
+                    .with_label(LabeledSpan::underline(11..20))
+                    .with_fix(11..20, "\t// removed\n"),
+                OxcDiagnostic::error("'a' is assigned a value but never used")
+                    .with_label(LabeledSpan::underline(4..5)),
+            ],
+        );
+
+        let output: Value = serde_json::from_str(&format_json(&errors, true)).unwrap();
+        let [debugger, unused] = output.as_array().unwrap().as_slice() else {
+            panic!("expected two diagnostics");
+        };
+        assert_eq!(debugger["message"], "`debugger` statement is \"not\" allowed");
+        assert_eq!(debugger["code"], "eslint(no-debugger)");
+        assert_eq!(debugger["filename"], "src/main.js");
+        assert_eq!(debugger["category"], "correctness");
+        assert_eq!(
+            debugger["fix"],
+            json!({ "span": { "offset": 11, "length": 9 }, "content": "\t// removed\n" })
+        );
+        assert_eq!(unused["severity"], "error");
+        assert!(unused.get("category").is_none());
+        assert!(unused.get("fix").is_none());
+
+        let output: Value = serde_json::from_str(&format_json(&errors, false)).unwrap();
+        assert!(output[0].get("fix").is_none());
+        assert_eq!(output[0]["category"], "correctness");
+
+        assert_eq!(format_json(&[], false), "[\n\n]");
+    }
 }
@@ -1,18 +1,20 @@
 //! [Reporters](DiagnosticReporter) for rendering and writing diagnostics.
 
 mod checkstyle;
+mod compact;
 mod github;
 mod graphical;
+mod html;
 mod json;
 mod unix;
 
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter, compact::CompactReporter, github::GithubReporter,
+    graphical::GraphicalReporter, html::HtmlReporter, json::JsonReporter, unix::UnixReporter,
 };
-use crate::{Error, Severity};
+use crate::{primary_label, Error, Severity};
 
 /// stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
 /// See `https://github.com/rust-lang/rust/issues/60673`.
@@ -88,6 +90,15 @@ pub trait DiagnosticReporter {
     /// Reporters should not use this method to write diagnostics to their output stream. That
     /// should be done in [`render_diagnostics`](DiagnosticReporter::render_diagnostics).
     fn render_error(&mut self, error: Error) -> Option<String>;
+
+    /// Whether this reporter's output is meant to be read by people rather than tools. Only
+    /// these reporters get notes like "2 more problems" for diagnostics hidden by
+    /// [`with_max_diagnostics_per_file`], which are not problems of their own.
+    ///
+    /// [`with_max_diagnostics_per_file`]: crate::DiagnosticService::with_max_diagnostics_per_file
+    fn is_human_readable(&self) -> bool {
+        false
+    }
 }
 
 struct Info {
@@ -107,25 +118,23 @@ impl Info {
         let mut message = String::new();
         let mut severity = Severity::Warning;
         let mut rule_id = None;
-        if let Some(mut labels) = diagnostic.labels() {
+        if let Some(label) = primary_label(diagnostic.as_ref()) {
             if let Some(source) = diagnostic.source_code() {
-                if let Some(label) = labels.next() {
-                    if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
-                        line = span_content.line() + 1;
-                        column = span_content.column() + 1;
-                        if let Some(name) = span_content.name() {
-                            filename = name.to_string();
-                        };
-                        if matches!(diagnostic.severity(), Some(Severity::Error)) {
-                            severity = Severity::Error;
-                        }
-                        let msg = diagnostic.to_string();
-                        // Our messages usually comes with `eslint(rule): message`
-                        (rule_id, message) = msg.split_once(':').map_or_else(
-                            || (None, msg.to_string()),
-                            |(id, msg)| (Some(id.to_string()), msg.trim().to_string()),
-                        );
+                if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
+                    line = span_content.line() + 1;
+                    column = span_content.column() + 1;
+                    if let Some(name) = span_content.name() {
+                        filename = name.to_string();
+                    };
+                    if matches!(diagnostic.severity(), Some(Severity::Error)) {
+                        severity = Severity::Error;
                     }
+                    let msg = diagnostic.to_string();
+                    // Our messages usually comes with `eslint(rule): message`
+                    (rule_id, message) = msg.split_once(':').map_or_else(
+                        || (None, msg.to_string()),
+                        |(id, msg)| (Some(id.to_string()), msg.trim().to_string()),
+                    );
                 }
             }
         }
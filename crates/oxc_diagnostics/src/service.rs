@@ -1,21 +1,36 @@
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
+    fmt,
     path::{Path, PathBuf},
     sync::{mpsc, Arc},
 };
 
+use miette::{Diagnostic, SourceCode};
+use rustc_hash::{FxHashMap, FxHashSet};
+
 use crate::{
+    baseline::Baseline,
+    primary_label,
     reporter::{
-        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        CheckstyleReporter, CompactReporter, DiagnosticReporter, GithubReporter, GraphicalReporter,
+        HtmlReporter, JsonReporter, UnixReporter,
     },
-    Error, NamedSource, OxcDiagnostic, Severity,
+    Error, GraphicalReportHandler, LabeledSpan, NamedSource, OxcDiagnostic, Severity,
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// Number of warnings and errors reported by a single rule.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RuleCount {
+    /// The diagnostic code of the rule, e.g. `eslint(no-debugger)`.
+    pub rule: String,
+    pub warnings: usize,
+    pub errors: usize,
+}
+
 /// Listens for diagnostics sent over a [channel](DiagnosticSender) by some job, and
 /// formats/reports them to the user.
 ///
@@ -61,12 +76,30 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Maximum number of diagnostics to report for a single file. Any remaining
+    /// diagnostics are replaced by a single "N more problems" summary.
+    max_diagnostics_per_file: Option<usize>,
+
+    /// Maximum number of diagnostics a single rule may report for a single file. Any remaining
+    /// diagnostics from that rule are replaced by a single "N more problems" summary.
+    max_diagnostics_per_rule: Option<usize>,
+
     /// Total number of warnings received
     warnings_count: Cell<usize>,
 
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Number of warnings and errors received for each diagnostic code
+    rule_counts: RefCell<FxHashMap<String, RuleCount>>,
+
+    /// Diagnostics that are not reported. Each diagnostic in the baseline hides one
+    /// received diagnostic.
+    baseline: RefCell<Option<Baseline>>,
+
+    /// Records every diagnostic received, for generating a baseline.
+    recorded_baseline: RefCell<Option<Baseline>>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -90,8 +123,13 @@ impl DiagnosticService {
             quiet: false,
             silent: false,
             max_warnings: None,
+            max_diagnostics_per_file: None,
+            max_diagnostics_per_rule: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            rule_counts: RefCell::default(),
+            baseline: RefCell::default(),
+            recorded_baseline: RefCell::default(),
             sender,
             receiver,
         }
@@ -102,6 +140,12 @@ impl DiagnosticService {
         self.reporter = Box::<JsonReporter>::default();
     }
 
+    /// Configure this service to format reports as a JSON array of objects, including the edit
+    /// that fixes each diagnostic, if there is one.
+    pub fn set_json_with_fixes_reporter(&mut self) {
+        self.reporter = Box::new(JsonReporter::with_fixes());
+    }
+
     pub fn set_unix_reporter(&mut self) {
         self.reporter = Box::<UnixReporter>::default();
     }
@@ -110,6 +154,24 @@ impl DiagnosticService {
         self.reporter = Box::<CheckstyleReporter>::default();
     }
 
+    /// Configure this service to report each diagnostic on a single
+    /// `file:line:column rule message` line.
+    pub fn set_compact_reporter(&mut self) {
+        self.reporter = Box::<CompactReporter>::default();
+    }
+
+    /// Configure this service to pretty-print reports using a custom `handler`, e.g. to change
+    /// the [theme](crate::GraphicalTheme) or the output width.
+    pub fn set_graphical_reporter(&mut self, handler: GraphicalReportHandler) {
+        self.reporter = Box::new(GraphicalReporter::new(handler));
+    }
+
+    /// Configure this service to render a standalone HTML page, grouping
+    /// problems by rule and by file.
+    pub fn set_html_reporter(&mut self) {
+        self.reporter = Box::<HtmlReporter>::default();
+    }
+
     /// Configure this service to formats reports using [GitHub Actions
     /// annotations](https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions#setting-an-error-message).
     pub fn set_github_reporter(&mut self) {
@@ -152,6 +214,68 @@ impl DiagnosticService {
         self
     }
 
+    /// Limit the number of diagnostics reported for each file. Diagnostics past the limit are
+    /// not rendered, but still count towards [`warnings_count`] and [`errors_count`].
+    ///
+    /// [`warnings_count`]: DiagnosticService::warnings_count
+    /// [`errors_count`]: DiagnosticService::errors_count
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_max_diagnostics_per_file(mut self, max_diagnostics: Option<usize>) -> Self {
+        self.max_diagnostics_per_file = max_diagnostics;
+        self
+    }
+
+    /// Limit the number of diagnostics each rule reports for each file. This keeps a single
+    /// misbehaving rule from flooding the output. Like the [per-file limit], hidden diagnostics
+    /// still count towards [`warnings_count`] and [`errors_count`].
+    ///
+    /// [per-file limit]: DiagnosticService::with_max_diagnostics_per_file
+    /// [`warnings_count`]: DiagnosticService::warnings_count
+    /// [`errors_count`]: DiagnosticService::errors_count
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_max_diagnostics_per_rule(mut self, max_diagnostics: Option<usize>) -> Self {
+        self.max_diagnostics_per_rule = max_diagnostics;
+        self
+    }
+
+    /// Only report diagnostics that are not in `baseline`. Diagnostics in the baseline are not
+    /// counted towards [`warnings_count`] and [`errors_count`].
+    ///
+    /// [`warnings_count`]: DiagnosticService::warnings_count
+    /// [`errors_count`]: DiagnosticService::errors_count
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = RefCell::new(baseline);
+        self
+    }
+
+    /// Add every diagnostic received to `baseline`, before any are hidden by a [baseline] or
+    /// the [quiet] mode. Use [`take_recorded_baseline`] to get it when the service is done.
+    ///
+    /// [baseline]: DiagnosticService::with_baseline
+    /// [quiet]: DiagnosticService::with_quiet
+    /// [`take_recorded_baseline`]: DiagnosticService::take_recorded_baseline
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_baseline_recording(mut self, baseline: Option<Baseline>) -> Self {
+        self.recorded_baseline = RefCell::new(baseline);
+        self
+    }
+
+    /// The baseline passed to [`with_baseline_recording`], with the diagnostics received so far.
+    ///
+    /// [`with_baseline_recording`]: DiagnosticService::with_baseline_recording
+    pub fn take_recorded_baseline(&self) -> Option<Baseline> {
+        self.recorded_baseline.take()
+    }
+
     /// Channel for sending [diagnostic messages] to the service.
     ///
     /// The service will only start processing diagnostics after [`run`](DiagnosticService::run)
@@ -172,6 +296,17 @@ impl DiagnosticService {
         self.errors_count.get()
     }
 
+    /// Get the number of warnings and errors received for each rule, sorted by the total
+    /// number of diagnostics in descending order. Diagnostics without a code, such as
+    /// parse errors, are not included.
+    pub fn rule_counts(&self) -> Vec<RuleCount> {
+        let mut counts = self.rule_counts.borrow().values().cloned().collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| {
+            (b.errors + b.warnings).cmp(&(a.errors + a.warnings)).then_with(|| a.rule.cmp(&b.rule))
+        });
+        counts
+    }
+
     /// Check if the max warning threshold, as set by
     /// [`with_max_warnings`](DiagnosticService::with_max_warnings), has been exceeded.
     pub fn max_warnings_exceeded(&self) -> bool {
@@ -190,41 +325,129 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| {
+                Error::new(FileDiagnostic { diagnostic, source: Arc::clone(&source) })
+            })
             .collect();
         (path.to_path_buf(), diagnostics)
     }
 
-    /// # Panics
+    /// Prepare the diagnostics for the file at `path` for reporting:
     ///
-    /// * When the writer fails to write
-    pub fn run(&mut self) {
-        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
-            for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
-                let is_warning = severity == Some(Severity::Warning);
-                let is_error = severity == Some(Severity::Error) || severity.is_none();
-                if is_warning || is_error {
-                    if is_warning {
-                        let warnings_count = self.warnings_count() + 1;
-                        self.warnings_count.set(warnings_count);
-                    }
-                    if is_error {
-                        let errors_count = self.errors_count() + 1;
-                        self.errors_count.set(errors_count);
+    /// 1. Duplicate reports from the same rule, with the same message, on the same span are merged.
+    ///    They are [recorded] for a baseline, and the ones in the [baseline] are dropped.
+    /// 2. Diagnostics are sorted by their position in the file. The sort is stable, so
+    ///    diagnostics at the same position keep the order they were reported in.
+    /// 3. Warnings and errors are counted, both in total and per rule, and warnings are dropped in [quiet] mode.
+    /// 4. The remaining diagnostics are capped to the [per-rule limit], then to the
+    ///    [per-file limit].
+    ///
+    /// [recorded]: DiagnosticService::with_baseline_recording
+    /// [baseline]: DiagnosticService::with_baseline
+    /// [quiet]: DiagnosticService::with_quiet
+    /// [per-rule limit]: DiagnosticService::with_max_diagnostics_per_rule
+    /// [per-file limit]: DiagnosticService::with_max_diagnostics_per_file
+    fn prepare_diagnostics(&self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error> {
+        let mut seen = FxHashSet::default();
+        let mut diagnostics = diagnostics
+            .into_iter()
+            .filter(|diagnostic| seen.insert(DiagnosticKey::new(diagnostic)))
+            .collect::<Vec<_>>();
+        if let Some(recorded) = self.recorded_baseline.borrow_mut().as_mut() {
+            for diagnostic in &diagnostics {
+                if let Some(entry) = recorded.entry(path, diagnostic) {
+                    recorded.insert(entry);
+                }
+            }
+        }
+        if let Some(baseline) = self.baseline.borrow_mut().as_mut() {
+            diagnostics.retain(|diagnostic| !baseline.remove(path, diagnostic));
+        }
+        diagnostics.sort_by_cached_key(primary_offset);
+
+        let mut rule_counts = self.rule_counts.borrow_mut();
+        diagnostics.retain(|diagnostic| {
+            let severity = diagnostic.severity();
+            let mut rule_count = diagnostic.code().map(|code| {
+                let rule = code.to_string();
+                rule_counts
+                    .entry(rule.clone())
+                    .or_insert_with(|| RuleCount { rule, ..RuleCount::default() })
+            });
+            match severity {
+                Some(Severity::Warning) => {
+                    self.warnings_count.set(self.warnings_count() + 1);
+                    if let Some(rule_count) = rule_count.as_mut() {
+                        rule_count.warnings += 1;
                     }
                     // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
                     // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
-                        continue;
+                    !self.quiet
+                }
+                Some(Severity::Error) | None => {
+                    self.errors_count.set(self.errors_count() + 1);
+                    if let Some(rule_count) = rule_count.as_mut() {
+                        rule_count.errors += 1;
                     }
+                    true
                 }
+                Some(Severity::Advice) => true,
+            }
+        });
+        drop(rule_counts);
 
-                if self.silent {
-                    continue;
+        let mut hidden_per_rule: Vec<(String, usize)> = vec![];
+        if let Some(max) = self.max_diagnostics_per_rule {
+            let mut reported = FxHashMap::<String, usize>::default();
+            diagnostics.retain(|diagnostic| {
+                let Some(code) = diagnostic.code().map(|code| code.to_string()) else {
+                    return true;
+                };
+                let count = reported.entry(code.clone()).or_default();
+                *count += 1;
+                if *count <= max {
+                    return true;
+                }
+                match hidden_per_rule.iter_mut().find(|(rule, _)| *rule == code) {
+                    Some((_, hidden)) => *hidden += 1,
+                    None => hidden_per_rule.push((code, 1)),
                 }
+                false
+            });
+        }
+
+        let mut hidden_in_file = 0;
+        if let Some(max) = self.max_diagnostics_per_file {
+            if diagnostics.len() > max {
+                hidden_in_file = diagnostics.len() - max;
+                diagnostics.truncate(max);
+            }
+        }
 
+        if self.reporter.is_human_readable() {
+            for (rule, hidden) in hidden_per_rule {
+                diagnostics.push(suppressed_note(hidden, &format!(" {rule}")));
+            }
+            if hidden_in_file > 0 {
+                diagnostics.push(suppressed_note(hidden_in_file, ""));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn run(&mut self) {
+        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+            let diagnostics = self.prepare_diagnostics(&path, diagnostics);
+            if self.silent {
+                continue;
+            }
+
+            let mut output = String::new();
+            for diagnostic in diagnostics {
                 if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
                     // Skip large output and print only once.
                     // Setting to 1200 because graphical output may contain ansi escape codes and other decorations.
@@ -246,3 +469,265 @@ impl DiagnosticService {
         self.reporter.finish();
     }
 }
+
+/// A diagnostic together with the source text of the file it was reported in.
+///
+/// Unlike [`OxcDiagnostic::with_source_code`], this keeps the [`OxcDiagnostic`] reachable from
+/// the resulting [`Error`], so reporters can read data miette does not know about, like fixes.
+#[derive(Debug)]
+struct FileDiagnostic {
+    diagnostic: OxcDiagnostic,
+    source: Arc<NamedSource<String>>,
+}
+
+impl fmt::Display for FileDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for FileDiagnostic {}
+
+impl Diagnostic for FileDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+}
+
+/// The [`OxcDiagnostic`] behind an [`Error`], for reading its structured fields.
+pub(crate) fn as_oxc_diagnostic(error: &Error) -> Option<&OxcDiagnostic> {
+    match error.downcast_ref::<FileDiagnostic>() {
+        Some(file_diagnostic) => Some(&file_diagnostic.diagnostic),
+        None => error.downcast_ref::<OxcDiagnostic>(),
+    }
+}
+
+/// The source text of the file an [`Error`] was reported in, if it was wrapped by
+/// [`DiagnosticService::wrap_diagnostics`].
+pub(crate) fn source_text(error: &Error) -> Option<&str> {
+    error
+        .downcast_ref::<FileDiagnostic>()
+        .map(|file_diagnostic| file_diagnostic.source.inner().as_str())
+}
+
+/// Summary shown in place of diagnostics hidden by a per-file or per-rule limit.
+fn suppressed_note(hidden: usize, rule: &str) -> Error {
+    let problems = if hidden == 1 { "problem" } else { "problems" };
+    Error::new(
+        OxcDiagnostic::warn(format!("{hidden} more{rule} {problems}"))
+            .with_severity(Severity::Advice),
+    )
+}
+
+/// Identifies duplicate reports: the same rule reported with the same message and labels.
+#[derive(PartialEq, Eq, Hash)]
+struct DiagnosticKey {
+    code: Option<String>,
+    message: String,
+    /// Offset, length and text of every label.
+    labels: Vec<(usize, usize, Option<String>)>,
+}
+
+impl DiagnosticKey {
+    fn new(diagnostic: &Error) -> Self {
+        let code = diagnostic.code().map(|code| code.to_string());
+        let message = diagnostic.to_string();
+        let labels = diagnostic
+            .labels()
+            .into_iter()
+            .flatten()
+            .map(|label| (label.offset(), label.len(), label.label().map(ToString::to_string)))
+            .collect();
+        Self { code, message, labels }
+    }
+}
+
+/// Byte offset of a diagnostic's primary label. Diagnostics without labels are sorted first.
+fn primary_offset(diagnostic: &Error) -> usize {
+    primary_label(diagnostic.as_ref()).map_or(0, |label| label.offset())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{ops::Range, path::Path, sync::Arc};
+
+    use super::as_oxc_diagnostic;
+    use crate::{
+        primary_label, Baseline, DiagnosticService, Error, LabeledSpan, NamedSource, OxcDiagnostic,
+        OxcFix, RuleCount,
+    };
+
+    #[test]
+    fn test_prepare_diagnostics() {
+        let source = Arc::new(NamedSource::new("test.js", "debugger; debugger; foo;".to_string()));
+        let report = |code: &'static str, span: Range<usize>| {
+            Error::new(
+                OxcDiagnostic::warn(format!("{code} at {}", span.start))
+                    .with_error_code("eslint", code)
+                    .with_label(LabeledSpan::underline(span)),
+            )
+            .with_source_code(Arc::clone(&source))
+        };
+        let diagnostics = || {
+            vec![
+                report("no-undef", 20..23),
+                report("no-debugger", 10..19),
+                report("no-debugger", 0..9),
+                report("no-debugger", 10..19),
+            ]
+        };
+
+        let service = DiagnosticService::default();
+        let prepared = service.prepare_diagnostics(Path::new("test.js"), diagnostics());
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["no-debugger at 0", "no-debugger at 10", "no-undef at 20"]);
+        assert_eq!(service.warnings_count(), 3);
+        let rule_count =
+            |rule: &str, warnings| RuleCount { rule: rule.into(), warnings, errors: 0 };
+        assert_eq!(
+            service.rule_counts(),
+            [rule_count("eslint(no-debugger)", 2), rule_count("eslint(no-undef)", 1)]
+        );
+
+        let service = DiagnosticService::default().with_max_diagnostics_per_file(Some(1));
+        let prepared = service.prepare_diagnostics(Path::new("test.js"), diagnostics());
+        assert_eq!(prepared.len(), 2);
+        assert_eq!(prepared[1].to_string(), "2 more problems");
+        assert_eq!(service.warnings_count(), 3);
+
+        // Machine-readable output only has actual problems.
+        let mut service = DiagnosticService::default().with_max_diagnostics_per_file(Some(1));
+        service.set_json_reporter();
+        let prepared = service.prepare_diagnostics(Path::new("test.js"), diagnostics());
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["no-debugger at 0"]);
+
+        let service = DiagnosticService::default().with_max_diagnostics_per_rule(Some(1));
+        let prepared = service.prepare_diagnostics(Path::new("test.js"), diagnostics());
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["no-debugger at 0", "no-undef at 20", "1 more eslint(no-debugger) problem"]
+        );
+        assert_eq!(service.warnings_count(), 3);
+
+        let path = Path::new("test.js");
+        let mut baseline = Baseline::new("");
+        baseline.insert(baseline.entry(path, &report("no-debugger", 0..9)).unwrap());
+        let service = DiagnosticService::default()
+            .with_baseline(Some(baseline))
+            .with_baseline_recording(Some(Baseline::new("")));
+        let prepared = service.prepare_diagnostics(path, diagnostics());
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["no-debugger at 10", "no-undef at 20"]);
+        assert_eq!(service.warnings_count(), 2);
+        assert_eq!(service.take_recorded_baseline().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_sorted_by_primary_label() {
+        let source = Arc::new(NamedSource::new("test.js", "let a = 1; a = 2; b;".to_string()));
+        let diagnostics = vec![
+            Error::new(OxcDiagnostic::warn("b").with_label(LabeledSpan::underline(18..19)))
+                .with_source_code(Arc::clone(&source)),
+            // Declared at 4, but anchored to the write at 11.
+            Error::new(OxcDiagnostic::warn("a").with_labels([
+                LabeledSpan::at(4..5, "declared here"),
+                LabeledSpan::new_primary_with_span(Some("assigned here".into()), 11..12),
+            ]))
+            .with_source_code(Arc::clone(&source)),
+        ];
+        assert_eq!(primary_label(diagnostics[1].as_ref()).unwrap().offset(), 11);
+        assert_eq!(primary_label(diagnostics[0].as_ref()).unwrap().offset(), 18);
+
+        let service = DiagnosticService::default();
+        let prepared = service.prepare_diagnostics(Path::new("test.js"), diagnostics);
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_dedup_keeps_distinct_messages() {
+        let source = Arc::new(NamedSource::new("test.js", "foo;".to_string()));
+        let report = |message: &'static str| {
+            Error::new(
+                OxcDiagnostic::warn(message)
+                    .with_error_code("eslint", "no-undef")
+                    .with_label(LabeledSpan::underline(0..3)),
+            )
+            .with_source_code(Arc::clone(&source))
+        };
+        let service = DiagnosticService::default();
+        let prepared = service
+            .prepare_diagnostics(Path::new("test.js"), vec![report("a"), report("b"), report("a")]);
+        let messages = prepared.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_dedup_keeps_distinct_labels() {
+        let source =
+            Arc::new(NamedSource::new("test.js", "case 1: case 1.0: case 0x1:".to_string()));
+        let report = |duplicate: Range<usize>| {
+            Error::new(
+                OxcDiagnostic::warn("Duplicate case label")
+                    .with_error_code("eslint", "no-duplicate-case")
+                    .with_labels([
+                        LabeledSpan::at(5..6, "first"),
+                        LabeledSpan::new_with_span(Some("duplicate".into()), duplicate),
+                    ]),
+            )
+            .with_source_code(Arc::clone(&source))
+        };
+        let service = DiagnosticService::default();
+        let prepared = service.prepare_diagnostics(
+            Path::new("test.js"),
+            vec![report(13..16), report(23..26), report(13..16)],
+        );
+        assert_eq!(prepared.len(), 2);
+        assert_eq!(service.warnings_count(), 2);
+    }
+
+    #[test]
+    fn test_wrapped_diagnostic_keeps_fix() {
+        let diagnostic = OxcDiagnostic::warn("unexpected debugger")
+            .with_label(LabeledSpan::underline(0..9))
+            .with_category("correctness")
+            .with_fix(0..9, "")
+            .with_label_offset(8);
+        let (_, errors) = DiagnosticService::wrap_diagnostics(
+            "test.vue",
+            "<script>debugger;</script>",
+            vec![diagnostic, OxcDiagnostic::warn("no fix")],
+        );
+        let diagnostic_fix = |error| as_oxc_diagnostic(error).and_then(|d| d.fix.as_ref());
+        assert_eq!(
+            diagnostic_fix(&errors[0]),
+            Some(&OxcFix { span: 8..17, content: String::new() })
+        );
+        assert_eq!(diagnostic_fix(&errors[1]), None);
+        assert_eq!(as_oxc_diagnostic(&errors[0]).unwrap().category.as_deref(), Some("correctness"));
+        assert_eq!(errors[0].labels().unwrap().next().unwrap().offset(), 8);
+        assert!(errors[0].source_code().is_some());
+    }
+}
@@ -40,6 +40,7 @@ struct LabeledSpanWithPosition {
     pub start_pos: Position,
     pub end_pos: Position,
     pub message: Option<String>,
+    pub primary: bool,
 }
 
 impl ErrorWithPosition {
@@ -61,6 +62,7 @@ impl ErrorWithPosition {
                 )
                 .unwrap_or_default(),
                 message: labeled_span.label().map(ToString::to_string),
+                primary: labeled_span.primary(),
             })
             .collect();
 
@@ -96,32 +98,44 @@ impl ErrorWithPosition {
                 })
                 .collect(),
         );
-        let range = related_information.as_ref().map_or(
-            Range { start: self.start_pos, end: self.end_pos },
-            |infos: &Vec<DiagnosticRelatedInformation>| {
-                let mut ret_range = Range {
-                    start: Position { line: u32::MAX, character: u32::MAX },
-                    end: Position { line: u32::MAX, character: u32::MAX },
-                };
-                for info in infos {
-                    if cmp_range(&ret_range, &info.location.range) == std::cmp::Ordering::Greater {
-                        ret_range = info.location.range;
+        // Anchor to the label marked as primary, falling back to the earliest one.
+        let primary = self.labels_with_pos.iter().find(|labeled_span| labeled_span.primary);
+        let range = if let Some(primary) = primary {
+            Range { start: primary.start_pos, end: primary.end_pos }
+        } else {
+            related_information.as_ref().map_or(
+                Range { start: self.start_pos, end: self.end_pos },
+                |infos: &Vec<DiagnosticRelatedInformation>| {
+                    let mut ret_range = Range {
+                        start: Position { line: u32::MAX, character: u32::MAX },
+                        end: Position { line: u32::MAX, character: u32::MAX },
+                    };
+                    for info in infos {
+                        if cmp_range(&ret_range, &info.location.range)
+                            == std::cmp::Ordering::Greater
+                        {
+                            ret_range = info.location.range;
+                        }
                     }
-                }
-                ret_range
-            },
-        );
+                    ret_range
+                },
+            )
+        };
         let code = self.miette_err.code().map(|item| item.to_string());
-        let code_description = code.as_ref().and_then(|code| {
-            let (scope, number) = parse_diagnostic_code(code)?;
-            Some(CodeDescription {
-                href: Url::from_str(&format!(
+        // Prefer the docs URL the linter attached over one guessed from the code.
+        let code_description = self
+            .miette_err
+            .url()
+            .and_then(|url| Url::from_str(&url.to_string()).ok())
+            .or_else(|| {
+                let (scope, number) = parse_diagnostic_code(code.as_ref()?)?;
+                Url::from_str(&format!(
                     "{LINT_DOC_LINK_PREFIX}/{}/{number}",
                     scope.strip_prefix("eslint-plugin-").unwrap_or(scope).cow_replace("-", "_")
                 ))
-                .ok()?,
+                .ok()
             })
-        });
+            .map(|href| CodeDescription { href });
         let message = self.miette_err.help().map_or_else(
             || self.miette_err.to_string(),
             |help| format!("{}\nhelp: {}", self.miette_err, help),
@@ -275,6 +289,7 @@ impl IsolatedLintHandler {
             let semantic_ret = SemanticBuilder::new()
                 .with_cfg(true)
                 .with_check_syntax_error(true)
+                .with_definition_files(true)
                 .build(&ret.program);
 
             if !semantic_ret.errors.is_empty() {
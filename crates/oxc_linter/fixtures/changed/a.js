@@ -0,0 +1,4 @@
+import { b } from './b';
+
+debugger;
+b();
@@ -0,0 +1,4 @@
+import { c } from './c';
+
+debugger;
+export const b = c;
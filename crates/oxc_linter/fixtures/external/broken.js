@@ -0,0 +1 @@
+throw new Error('plugin failed to load');
@@ -0,0 +1,46 @@
+//! An `acme` plugin with a `no-legacy` rule, implementing the C ABI described
+//! on `oxc_linter::DylibPlugin`.
+//!
+//! To stay free of dependencies it doesn't parse requests, and reacts to
+//! substrings instead:
+//! * `legacy(` reports `acme/no-legacy` at `0..6`
+//! * `crash` returns an error
+//! * `silent` returns no response at all
+
+use std::ffi::{c_char, CStr, CString};
+
+#[no_mangle]
+pub extern "C" fn oxlint_plugin_abi_version() -> u32 {
+    1
+}
+
+#[no_mangle]
+pub extern "C" fn oxlint_plugin_manifest() -> *const c_char {
+    b"{\"name\":\"acme\",\"rules\":[\"no-legacy\",\"other\"]}\0".as_ptr().cast()
+}
+
+/// # Safety
+///
+/// `request` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn oxlint_plugin_lint(request: *const c_char) -> *mut c_char {
+    let request = CStr::from_ptr(request).to_string_lossy();
+    let response = if request.contains("silent") {
+        return std::ptr::null_mut();
+    } else if request.contains("crash") {
+        r#"{"error":"boom"}"#
+    } else if request.contains("legacy(") {
+        r#"{"diagnostics":[{"rule":"no-legacy","message":"Do not use legacy","start":0,"end":6}]}"#
+    } else {
+        r#"{"diagnostics":[]}"#
+    };
+    CString::new(response).unwrap().into_raw()
+}
+
+/// # Safety
+///
+/// `response` must have been returned by `oxlint_plugin_lint`.
+#[no_mangle]
+pub unsafe extern "C" fn oxlint_plugin_free(response: *mut c_char) {
+    drop(CString::from_raw(response));
+}
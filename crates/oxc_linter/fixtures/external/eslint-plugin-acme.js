@@ -0,0 +1,57 @@
+'use strict';
+
+module.exports = {
+  meta: { name: 'eslint-plugin-acme' },
+  rules: {
+    'no-foo-calls': {
+      meta: { messages: { noFoo: 'Do not call foo with {{ count }} argument(s).' } },
+      create(context) {
+        return {
+          CallExpression(node) {
+            if (node.callee.type === 'Identifier' && node.callee.name === 'foo' && node.arguments[0]?.type === 'Literal') {
+              context.report({ node, messageId: 'noFoo', data: { count: node.arguments.length } });
+            }
+          },
+        };
+      },
+    },
+    'max-declarators': {
+      create(context) {
+        const max = context.options[0]?.max ?? 3;
+        return {
+          VariableDeclaration(node) {
+            if (node.declarations.length > max) {
+              context.report({
+                node,
+                message: `Declares ${node.declarations.length} variables, at most ${max} allowed.`,
+              });
+            }
+          },
+        };
+      },
+    },
+    'max-params': {
+      create(context) {
+        return {
+          'FunctionDeclaration, ArrowFunctionExpression'(node) {
+            if (node.params.length > 1) {
+              context.report({ node: node.params[1], message: 'Too many parameters.' });
+            }
+          },
+        };
+      },
+    },
+    // Exits on `exit()`, to test that oxlint restarts node.
+    'no-exit': {
+      create(context) {
+        return {
+          CallExpression(node) {
+            if (node.callee.type !== 'Identifier') return;
+            if (node.callee.name === 'exit') process.exit(1);
+            context.report({ node, message: 'Called ' + node.callee.name + '.' });
+          },
+        };
+      },
+    },
+  },
+};
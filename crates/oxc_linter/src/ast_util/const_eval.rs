@@ -0,0 +1,282 @@
+//! Static evaluation of expressions, shared by rules that need to know what an
+//! expression evaluates to without running it.
+
+use std::borrow::Cow;
+
+use oxc_ast::ast::{Expression, IdentifierReference, TemplateLiteral, UnaryExpression};
+use oxc_ecmascript::{
+    constant_evaluation::{ConstantEvaluation, ConstantValue},
+    side_effects::MayHaveSideEffects,
+    ToBigInt, ToJsString, ToNumber,
+};
+use oxc_semantic::Semantic;
+use oxc_span::Span;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+/// Folds expressions built from literals to the [`ConstantValue`] they evaluate to.
+///
+/// Supports literals, template literals without substitutions, `undefined`, `NaN` and
+/// `Infinity` when they aren't shadowed, arithmetic, comparisons, logical and unary
+/// operators, and `typeof`.
+///
+/// ```ignore
+/// let value = ConstEval::new(ctx.semantic()).eval(expr);
+/// // `60 * 60` evaluates to `ConstantValue::Number(3600.0)`
+/// // `typeof (() => {})` evaluates to `ConstantValue::String("function")`
+/// ```
+pub struct ConstEval<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+}
+
+impl<'s, 'a> ConstEval<'s, 'a> {
+    pub fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic }
+    }
+
+    /// Evaluate `expr`, if its value is known statically and evaluating it has no side
+    /// effects.
+    pub fn eval(&self, expr: &Expression<'a>) -> Option<ConstantValue<'a>> {
+        self.eval_expression(expr)
+    }
+
+    fn eval_typeof(&self, expr: &UnaryExpression<'a>) -> Option<ConstantValue<'a>> {
+        let type_name = match expr.argument.without_parentheses() {
+            Expression::ArrowFunctionExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ClassExpression(_) => "function",
+            argument => match self.eval_expression(argument) {
+                Some(ConstantValue::Number(_)) => "number",
+                Some(ConstantValue::BigInt(_)) => "bigint",
+                Some(ConstantValue::String(_)) => "string",
+                Some(ConstantValue::Boolean(_)) => "boolean",
+                Some(ConstantValue::Undefined) => "undefined",
+                Some(ConstantValue::Null) => "object",
+                None => return self.eval_unary_expression(expr),
+            },
+        };
+        Some(ConstantValue::String(Cow::Borrowed(type_name)))
+    }
+}
+
+impl<'a> ConstantEvaluation<'a> for ConstEval<'_, 'a> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.semantic.is_reference_to_global_variable(ident)
+    }
+
+    fn eval_to_number(&self, expr: &Expression<'a>) -> Option<f64> {
+        self.eval_expression(expr)?.to_number()
+    }
+
+    // The default implementation only knows the types of literals, e.g. not of `` `a` `` or
+    // `(1)`, so operands are folded here before applying the operator.
+    fn eval_expression(&self, expr: &Expression<'a>) -> Option<ConstantValue<'a>> {
+        match expr {
+            Expression::ParenthesizedExpression(paren) => self.eval_expression(&paren.expression),
+            Expression::TemplateLiteral(lit) => eval_template_literal(lit),
+            Expression::BinaryExpression(e) if e.operator == BinaryOperator::Addition => {
+                match (self.eval_expression(&e.left)?, self.eval_expression(&e.right)?) {
+                    (left @ ConstantValue::String(_), right)
+                    | (left, right @ ConstantValue::String(_)) => {
+                        Some(ConstantValue::String(left.to_js_string()? + right.to_js_string()?))
+                    }
+                    (left, right) => {
+                        Some(ConstantValue::Number(left.to_number()? + right.to_number()?))
+                    }
+                }
+            }
+            Expression::BinaryExpression(e) => self.eval_binary_expression(e),
+            Expression::LogicalExpression(e) => self.eval_logical_expression(e),
+            Expression::UnaryExpression(e) => match e.operator {
+                UnaryOperator::Typeof => self.eval_typeof(e),
+                UnaryOperator::Void => {
+                    (!e.argument.may_have_side_effects()).then_some(ConstantValue::Undefined)
+                }
+                UnaryOperator::UnaryNegation => match self.eval_expression(&e.argument)? {
+                    ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(-n)),
+                    value => value.to_number().map(|n| ConstantValue::Number(-n)),
+                },
+                _ => self.eval_unary_expression(e),
+            },
+            Expression::Identifier(ident) => self.resolve_binding(ident),
+            Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+            Expression::NullLiteral(_) => Some(ConstantValue::Null),
+            Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+            Expression::BigIntLiteral(lit) => lit.to_big_int().map(ConstantValue::BigInt),
+            Expression::StringLiteral(lit) => {
+                Some(ConstantValue::String(Cow::Borrowed(lit.value.as_str())))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn eval_template_literal<'a>(lit: &TemplateLiteral<'a>) -> Option<ConstantValue<'a>> {
+    if !lit.expressions.is_empty() {
+        return None;
+    }
+    lit.to_js_string().map(ConstantValue::String)
+}
+
+/// Returns `true` if the call or `new` expression at `span` is annotated with
+/// `/*#__PURE__*/` or `/*@__PURE__*/`, marking it as free of side effects.
+///
+/// The annotation must directly precede the expression. Only whitespace, other
+/// comments and opening parentheses may be between them.
+///
+/// <https://rollupjs.org/configuration-options/#pure>
+pub fn has_pure_annotation(span: Span, semantic: &Semantic) -> bool {
+    let source_text = semantic.source_text();
+    let mut end = span.start;
+    for comment in semantic.comments_range(..span.start).rev() {
+        let between = Span::new(comment.real_span_end(), end).source_text(source_text);
+        if !between.chars().all(|c| c.is_whitespace() || c == '(') {
+            return false;
+        }
+        let text = comment.span.source_text(source_text);
+        if comment.is_block() && (text.contains("@__PURE__") || text.contains("#__PURE__")) {
+            return true;
+        }
+        end = comment.real_span_start();
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::Expression, AstKind};
+    use oxc_ecmascript::constant_evaluation::ConstantValue;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::{has_pure_annotation, ConstEval};
+
+    /// Evaluate the initializer of each variable declared in `source_text`.
+    fn eval_all(source_text: &str) -> Vec<Option<String>> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let eval = ConstEval::new(&semantic);
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::VariableDeclarator(decl) => decl.init.as_ref(),
+                _ => None,
+            })
+            .map(|init| {
+                eval.eval(init).map(|value| match value {
+                    ConstantValue::Number(n) => n.to_string(),
+                    ConstantValue::BigInt(n) => format!("{n}n"),
+                    ConstantValue::String(s) => format!("{s:?}"),
+                    ConstantValue::Boolean(b) => b.to_string(),
+                    ConstantValue::Undefined => "undefined".to_string(),
+                    ConstantValue::Null => "null".to_string(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_eval() {
+        let values = eval_all(
+            "
+            const a = 1, b = 'str', c = `tpl`, d = null, e = 10n, f = !0;
+            const g = 60 * 60, h = (1 + 2) * 3, i = -(4), j = 2 ** 3 % 5;
+            const k = 'a' + 1, l = `a` + `b`, m = 1 + '', n = 1 < 2;
+            const o = typeof 1, p = typeof `x`, q = typeof (() => {}), r = typeof class {};
+            const s = typeof null, t = typeof undefined, u = void 0, v = typeof (1 + 1);
+            const w = true && 'yes', x = NaN, y = Infinity;
+            ",
+        );
+        let expected = [
+            "1",
+            "\"str\"",
+            "\"tpl\"",
+            "null",
+            "10n",
+            "true", //
+            "3600",
+            "9",
+            "-4",
+            "3", //
+            "\"a1\"",
+            "\"ab\"",
+            "\"1\"",
+            "true", //
+            "\"number\"",
+            "\"string\"",
+            "\"function\"",
+            "\"function\"", //
+            "\"object\"",
+            "\"undefined\"",
+            "undefined",
+            "\"number\"", //
+            "\"yes\"",
+            "NaN",
+            "inf",
+        ];
+        assert_eq!(values, expected.map(|value| Some(value.to_string())));
+    }
+
+    #[test]
+    fn test_eval_unknown() {
+        let values = eval_all(
+            "
+            const undefined = 1;
+            const a = foo, b = `a${foo}`, c = foo + 1, d = typeof foo, e = f(), g = [] + 1;
+            const h = undefined, i = typeof undefined;
+            ",
+        );
+        let expected = [Some("1"), None, None, None, None, None, None, None, None];
+        assert_eq!(values, expected.map(|value| value.map(str::to_string)));
+    }
+
+    #[test]
+    fn test_has_pure_annotation() {
+        let source_text = "
+            /*#__PURE__*/ a();
+            /* @__PURE__ */ new B();
+            /*@__PURE__*/ (c());
+            /*#__PURE__*/ /* other */ d();
+            // #__PURE__
+            e();
+            /*#__PURE__*/ x, f();
+            g(/*#__PURE__*/);
+            h();
+        ";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let annotated = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::CallExpression(call) => {
+                    Some((call.callee_name()?, has_pure_annotation(call.span, &semantic)))
+                }
+                AstKind::NewExpression(new) => match &new.callee {
+                    Expression::Identifier(ident) => {
+                        Some((ident.name.as_str(), has_pure_annotation(new.span(), &semantic)))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            annotated,
+            [
+                ("a", true),
+                ("B", true),
+                ("c", true),
+                ("d", true),
+                ("e", false),
+                ("f", false),
+                ("g", false),
+                ("h", false),
+            ]
+        );
+    }
+}
@@ -1,7 +1,9 @@
+pub mod const_eval;
+
 use oxc_ast::{ast::BindingIdentifier, AstKind};
 use oxc_ecmascript::ToBoolean;
 use oxc_semantic::{AstNode, IsGlobalReference, NodeId, Semantic, SymbolId};
-use oxc_span::{GetSpan, Span};
+use oxc_span::{cmp::ContentEq, GetSpan, Span};
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
 
 use oxc_ast::ast::*;
@@ -278,8 +280,7 @@ pub fn get_declaration_of_variable<'a, 'b>(
     semantic: &'b Semantic<'a>,
 ) -> Option<&'b AstNode<'a>> {
     let symbol_id = get_symbol_id_of_variable(ident, semantic)?;
-    let symbol_table = semantic.symbols();
-    Some(semantic.nodes().get_node(symbol_table.get_declaration(symbol_id)))
+    Some(semantic.symbol_declaration(symbol_id))
 }
 
 pub fn get_symbol_id_of_variable(
@@ -456,3 +457,43 @@ pub fn leftmost_identifier_reference<'a, 'b: 'a>(
         _ => Err(expr),
     }
 }
+
+/// Whether `a` and `b` are the same, for finding duplicates such as repeated `case` labels.
+///
+/// Literals are compared by value, so `1` and `0x1`, or `'a'` and `` `a` ``, are the same, but
+/// `1` and `'1'` are not. Other expressions are compared structurally, ignoring parentheses.
+pub fn is_same_key(a: &Expression, b: &Expression) -> bool {
+    let (a, b) = (a.without_parentheses(), b.without_parentheses());
+    match (LiteralKey::new(a), LiteralKey::new(b)) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => a.content_eq(b),
+        _ => false,
+    }
+}
+
+/// The value of a literal. Comparing numbers as `f64` follows `===`: `NaN` is never equal,
+/// and `0` equals `-0`.
+#[derive(Debug, PartialEq)]
+enum LiteralKey<'a> {
+    String(&'a str),
+    Number(f64),
+    BigInt(&'a str),
+    Boolean(bool),
+    Null,
+}
+
+impl<'a> LiteralKey<'a> {
+    fn new(expr: &Expression<'a>) -> Option<Self> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(Self::String(lit.value.as_str())),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                lit.quasi().map(|quasi| Self::String(quasi.as_str()))
+            }
+            Expression::NumericLiteral(lit) => Some(Self::Number(lit.value)),
+            Expression::BigIntLiteral(lit) => Some(Self::BigInt(lit.raw.as_str())),
+            Expression::BooleanLiteral(lit) => Some(Self::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(Self::Null),
+            _ => None,
+        }
+    }
+}
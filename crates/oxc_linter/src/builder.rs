@@ -1,16 +1,23 @@
 use std::{
+    borrow::Cow,
     cell::{Ref, RefCell},
-    fmt,
+    fmt::{self, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use oxc_span::CompactStr;
 use rustc_hash::FxHashSet;
 
 use crate::{
-    config::{ESLintRule, LintPlugins, OxlintRules},
+    config::{
+        find_rule, BasePath, ESLintRule, LintPlugins, OverrideResolver, OxlintOverrides,
+        OxlintRules,
+    },
+    external::{self, ConfiguredExternalPlugin, ExternalPlugin},
     rules::RULES,
     AllowWarnDeny, FixKind, FrameworkFlags, LintConfig, LintFilter, LintFilterKind, LintOptions,
-    Linter, Oxlintrc, RuleCategory, RuleEnum, RuleWithSeverity,
+    Linter, Oxlintrc, RuleCategory, RuleEnum, RuleWithSeverity, TypeInfoProvider,
 };
 
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
@@ -18,6 +25,11 @@ pub struct LinterBuilder {
     pub(super) rules: FxHashSet<RuleWithSeverity>,
     options: LintOptions,
     config: LintConfig,
+    overrides: OxlintOverrides,
+    /// The directory patterns of `overrides` are relative to.
+    base_path: BasePath,
+    external_plugins: Vec<ConfiguredExternalPlugin>,
+    type_info_provider: Option<Arc<dyn TypeInfoProvider>>,
     cache: RulesCache,
 }
 
@@ -38,7 +50,16 @@ impl LinterBuilder {
         let rules = FxHashSet::default();
         let cache = RulesCache::new(config.plugins);
 
-        Self { rules, options, config, cache }
+        Self {
+            rules,
+            options,
+            config,
+            overrides: OxlintOverrides::default(),
+            base_path: BasePath::default(),
+            external_plugins: vec![],
+            type_info_provider: None,
+            cache,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -56,6 +77,10 @@ impl LinterBuilder {
                 .collect(),
             options,
             config,
+            overrides: OxlintOverrides::default(),
+            base_path: BasePath::default(),
+            external_plugins: vec![],
+            type_info_provider: None,
             cache,
         }
     }
@@ -80,36 +105,126 @@ impl LinterBuilder {
     /// Will return a [`LinterBuilderError::UnknownRules`] if there are unknown rules in the
     /// config. This can happen if the plugin for a rule is not enabled, or the rule name doesn't
     /// match any recognized rules.
+    ///
+    /// Will return a [`LinterBuilderError::InvalidRuleOptions`] if rules are configured with
+    /// invalid options, e.g. a malformed selector.
+    ///
+    /// Will return a [`LinterBuilderError::ExternalPlugin`] if an external plugin listed in
+    /// `plugins` can't be loaded.
     pub fn from_oxlintrc(
         start_empty: bool,
         oxlintrc: Oxlintrc,
+    ) -> Result<Self, LinterBuilderError> {
+        let external_plugins = oxlintrc
+            .external_plugins
+            .iter()
+            .map(|path| {
+                external::load(path).map_err(|message| LinterBuilderError::ExternalPlugin {
+                    path: path.clone(),
+                    message,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_oxlintrc_with_external_plugins(start_empty, oxlintrc, external_plugins)
+    }
+
+    /// Like [`LinterBuilder::from_oxlintrc`], but with external plugins that are already
+    /// loaded instead of those listed in the config. Rules of these plugins can be configured
+    /// in the top-level `rules` of the config as `<plugin name>/<rule name>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return a [`LinterBuilderError::UnknownRules`] if there are unknown rules in the
+    /// config, or a [`LinterBuilderError::InvalidRuleOptions`] if rules are configured with
+    /// invalid options.
+    pub fn from_oxlintrc_with_external_plugins(
+        start_empty: bool,
+        oxlintrc: Oxlintrc,
+        external_plugins: Vec<Arc<dyn ExternalPlugin>>,
     ) -> Result<Self, LinterBuilderError> {
         // TODO: monorepo config merging, plugin-based extends, etc.
-        let Oxlintrc { plugins, settings, env, globals, categories, rules: mut oxlintrc_rules } =
-            oxlintrc;
+        let Oxlintrc {
+            plugins,
+            settings,
+            env,
+            globals,
+            categories,
+            rules: mut oxlintrc_rules,
+            mut overrides,
+            external_plugins: _,
+            path,
+        } = oxlintrc;
 
         let config = LintConfig { plugins, settings, env, globals };
         let options = LintOptions::default();
         let rules =
             if start_empty { FxHashSet::default() } else { Self::warn_correctness(plugins) };
         let cache = RulesCache::new(config.plugins);
-        let mut builder = Self { rules, options, config, cache };
+        let mut builder = Self {
+            rules,
+            options,
+            config,
+            overrides: OxlintOverrides::default(),
+            base_path: BasePath::new(&path),
+            external_plugins: external_plugins
+                .into_iter()
+                .map(ConfiguredExternalPlugin::new)
+                .collect(),
+            type_info_provider: None,
+            cache,
+        };
 
         if !categories.is_empty() {
             builder = builder.with_filters(categories.filters());
         }
 
-        {
+        let mut unknown_rules = {
             let all_rules = builder.cache.borrow();
             oxlintrc_rules.override_rules(&mut builder.rules, all_rules.as_slice());
-        }
+            let mut unknown_rules = std::mem::take(&mut oxlintrc_rules.unknown_rules);
+            unknown_rules.retain(|rule| {
+                let Some(plugin) =
+                    builder.external_plugins.iter_mut().find(|plugin| plugin.provides(rule))
+                else {
+                    return true;
+                };
+                plugin.configure(rule.rule_name.clone(), rule.severity, rule.config.clone());
+                false
+            });
+            // Overrides are applied per file while linting. Check their rules
+            // exist up front so that mistakes are reported even if no file matches.
+            for r#override in overrides.iter_mut() {
+                let mut rules = builder.rules.clone();
+                r#override.rules.override_rules(&mut rules, all_rules.as_slice());
+                let mut override_unknown_rules =
+                    std::mem::take(&mut r#override.rules.unknown_rules);
+                override_unknown_rules.retain(|rule| {
+                    !builder.external_plugins.iter().any(|plugin| plugin.provides(rule))
+                });
+                unknown_rules.append(&mut override_unknown_rules);
+            }
+            unknown_rules
+        };
 
-        if !oxlintrc_rules.unknown_rules.is_empty() {
+        if !unknown_rules.is_empty() {
             return Err(LinterBuilderError::UnknownRules {
-                rules: std::mem::take(&mut oxlintrc_rules.unknown_rules),
+                rules: std::mem::take(&mut unknown_rules),
             });
         }
 
+        let invalid_options = {
+            let all_rules = builder.cache.borrow();
+            let mut invalid_options = oxlintrc_rules.invalid_options(all_rules.as_slice());
+            for r#override in overrides.iter() {
+                invalid_options.extend(r#override.rules.invalid_options(all_rules.as_slice()));
+            }
+            invalid_options
+        };
+        if !invalid_options.is_empty() {
+            return Err(LinterBuilderError::InvalidRuleOptions { rules: invalid_options });
+        }
+        builder.overrides = overrides;
+
         Ok(builder)
     }
 
@@ -131,6 +246,14 @@ impl LinterBuilder {
         self
     }
 
+    /// Use `provider` for type information in type-aware rules, see
+    /// [`Linter::with_type_info_provider`].
+    #[inline]
+    pub fn with_type_info_provider(mut self, provider: Arc<dyn TypeInfoProvider>) -> Self {
+        self.type_info_provider = Some(provider);
+        self
+    }
+
     /// Configure what linter plugins are enabled.
     ///
     /// Turning on a plugin will not automatically enable any of its rules. You must do this
@@ -249,7 +372,23 @@ impl LinterBuilder {
             self.rules.into_iter().collect::<Vec<_>>()
         };
         rules.sort_unstable_by_key(|r| r.id());
-        Linter::new(rules, self.options, self.config)
+        let config = Arc::new(self.config);
+        let overrides = (!self.overrides.is_empty()).then(|| {
+            OverrideResolver::new(
+                self.overrides,
+                self.base_path,
+                self.cache.borrow().clone(),
+                self.external_plugins.clone(),
+                Arc::clone(&config),
+            )
+        });
+        let external_plugins =
+            self.external_plugins.into_iter().filter(|plugin| !plugin.rules().is_empty()).collect();
+        let linter = Linter::new(rules, self.options, config, overrides, external_plugins);
+        match self.type_info_provider {
+            Some(provider) => linter.with_type_info_provider(provider),
+            None => linter,
+        }
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -269,17 +408,63 @@ impl LinterBuilder {
     /// # Panics
     /// This function will panic if the `oxlintrc` is not valid JSON.
     pub fn resolve_final_config_file(&self, oxlintrc: Oxlintrc) -> String {
+        self.resolve_config(oxlintrc, None)
+    }
+
+    /// Like [`LinterBuilder::resolve_final_config_file`], but with the
+    /// overrides that match `path` applied on top of the top-level rules.
+    ///
+    /// # Panics
+    /// This function will panic if the `oxlintrc` is not valid JSON.
+    pub fn resolve_config_file_for_path(&self, oxlintrc: Oxlintrc, path: &Path) -> String {
+        self.resolve_config(oxlintrc, Some(path))
+    }
+
+    fn resolve_config(&self, oxlintrc: Oxlintrc, path: Option<&Path>) -> String {
         let mut oxlintrc = oxlintrc;
         let previous_rules = std::mem::take(&mut oxlintrc.rules);
 
-        let rule_name_to_rule = previous_rules
+        let mut rule_name_to_rule = previous_rules
             .rules
             .into_iter()
             .map(|r| (get_name(&r.plugin_name, &r.rule_name), r))
             .collect::<rustc_hash::FxHashMap<_, _>>();
 
-        let new_rules = self
-            .rules
+        let mut rules = Cow::Borrowed(&self.rules);
+        let mut external_plugins = Cow::Borrowed(&self.external_plugins);
+        if let Some(path) = path {
+            let all_rules = self.cache.borrow();
+            let path = self.base_path.relative(path);
+            for r#override in
+                self.overrides.iter().filter(|r#override| r#override.files.is_match(&path))
+            {
+                let mut override_rules = r#override.rules.clone();
+                override_rules.override_rules(rules.to_mut(), all_rules.as_slice());
+                for rule in std::mem::take(&mut override_rules.unknown_rules) {
+                    if let Some(plugin) =
+                        external_plugins.to_mut().iter_mut().find(|plugin| plugin.provides(&rule))
+                    {
+                        plugin.configure(rule.rule_name, rule.severity, rule.config);
+                    }
+                }
+                rule_name_to_rule.extend(
+                    override_rules
+                        .rules
+                        .into_iter()
+                        .map(|r| (get_name(&r.plugin_name, &r.rule_name), r)),
+                );
+                if let Some(env) = &r#override.env {
+                    oxlintrc.env.override_env(env);
+                }
+                if let Some(globals) = &r#override.globals {
+                    oxlintrc.globals.override_globals(globals);
+                }
+            }
+            // The matching overrides are merged into `rules`, `env` and `globals`.
+            oxlintrc.overrides = OxlintOverrides::default();
+        }
+
+        let new_rules = rules
             .iter()
             .map(|r: &RuleWithSeverity| {
                 return ESLintRule {
@@ -291,11 +476,67 @@ impl LinterBuilder {
                         .and_then(|r| r.config.clone()),
                 };
             })
+            .chain(external_plugins.iter().flat_map(|plugin| {
+                plugin.rules().iter().map(|rule| ESLintRule {
+                    plugin_name: plugin.name().to_string(),
+                    rule_name: rule.name.clone(),
+                    severity: rule.severity,
+                    config: (!rule.options.is_null()).then(|| rule.options.clone()),
+                })
+            }))
             .collect();
 
         oxlintrc.rules = OxlintRules::new(new_rules);
         serde_json::to_string_pretty(&oxlintrc).unwrap()
     }
+
+    /// Explain the rule named `name`, e.g. `no-debugger`, `react/jsx-key` or
+    /// `eslint-plugin-react(jsx-key)` as it is reported in diagnostics.
+    ///
+    /// The explanation starts with the rule's documentation, followed by its
+    /// severity in this builder and its options and overrides in `oxlintrc`.
+    /// Returns `None` if there is no such rule.
+    ///
+    /// # Panics
+    /// This function will panic if the rule options in `oxlintrc` are not valid JSON.
+    pub fn explain_rule(&self, oxlintrc: &Oxlintrc, name: &str) -> Option<String> {
+        let rule = find_rule(name)?;
+        let (plugin_name, rule_name) = (rule.plugin_name(), rule.name());
+        let is_rule = |r: &&ESLintRule| r.plugin_name == plugin_name && r.rule_name == rule_name;
+        let options = |r: &ESLintRule| {
+            r.config.as_ref().filter(|config| !config.is_null()).map_or(String::new(), |config| {
+                format!(" with options {}", serde_json::to_string(config).unwrap())
+            })
+        };
+
+        let mut s = String::new();
+        writeln!(s, "# {plugin_name}/{rule_name}\n").unwrap();
+        writeln!(s, "Category: {}", rule.category().as_str()).unwrap();
+        writeln!(s, "Fix: {}", rule.fix().description()).unwrap();
+        writeln!(s, "Docs: {}\n", rule.docs_url()).unwrap();
+        if let Some(documentation) = rule.documentation() {
+            writeln!(s, "{}\n", documentation.trim()).unwrap();
+        }
+
+        s.push_str("## Configuration\n\n");
+        let severity = self
+            .rules
+            .iter()
+            .find(|r| r.plugin_name() == plugin_name && r.name() == rule_name)
+            .map_or(AllowWarnDeny::Allow, |r| r.severity);
+        let configured = oxlintrc.rules.rules.iter().find(is_rule).map(options).unwrap_or_default();
+        writeln!(s, "Severity: {severity}{configured}").unwrap();
+        if !self.plugins().contains(LintPlugins::from(plugin_name)) {
+            writeln!(s, "The `{plugin_name}` plugin is not enabled.").unwrap();
+        }
+        for r#override in self.overrides.iter() {
+            if let Some(r) = r#override.rules.rules.iter().find(is_rule) {
+                let files = r#override.files.patterns().join(", ");
+                writeln!(s, "Severity for {files}: {}{}", r.severity, options(r)).unwrap();
+            }
+        }
+        Some(s)
+    }
 }
 
 fn get_name(plugin_name: &str, rule_name: &str) -> CompactStr {
@@ -330,6 +571,11 @@ impl fmt::Debug for LinterBuilder {
 pub enum LinterBuilderError {
     /// There were unknown rules that could not be matched to any known plugins/rules.
     UnknownRules { rules: Vec<ESLintRule> },
+    /// Rules were configured with invalid options. Holds the full name of each
+    /// rule with an error message.
+    InvalidRuleOptions { rules: Vec<(String, String)> },
+    /// An external plugin listed in the config could not be loaded.
+    ExternalPlugin { path: PathBuf, message: String },
 }
 
 impl std::fmt::Display for LinterBuilderError {
@@ -342,6 +588,18 @@ impl std::fmt::Display for LinterBuilderError {
                 }
                 Ok(())
             }
+            LinterBuilderError::InvalidRuleOptions { rules } => {
+                for (i, (rule, message)) in rules.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "invalid options for rule {rule}: {message}")?;
+                }
+                Ok(())
+            }
+            LinterBuilderError::ExternalPlugin { path, message } => {
+                write!(f, "failed to load plugin {}: {message}", path.display())
+            }
         }
     }
 }
@@ -432,6 +690,8 @@ impl RulesCache {
 
 #[cfg(test)]
 mod test {
+    use std::path::Path;
+
     use super::*;
 
     #[test]
@@ -677,4 +937,195 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_nursery() {
+        let has_nursery_rules = |builder: &LinterBuilder| {
+            builder.rules.iter().any(|rule| rule.category() == RuleCategory::Nursery)
+        };
+        assert!(!has_nursery_rules(&LinterBuilder::default()));
+        let builder =
+            LinterBuilder::default()
+                .with_filters([LintFilter::new(AllowWarnDeny::Warn, "all").unwrap()]);
+        assert!(!has_nursery_rules(&builder));
+
+        // Nursery rules are enabled by name, or with the category.
+        for config in [
+            serde_json::json!({ "rules": { "no-unused-vars": "warn" } }),
+            serde_json::json!({ "categories": { "nursery": "warn" } }),
+        ] {
+            let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+            let builder = LinterBuilder::from_oxlintrc(false, oxlintrc).unwrap();
+            assert!(has_nursery_rules(&builder));
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": { "no-console": "warn" },
+            "overrides": [
+                { "files": ["**/*.test.js"], "rules": { "no-console": "off", "no-debugger": "error" } },
+                { "files": ["**/fixtures/**"], "rules": { "no-debugger": "off" } }
+            ]
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc).unwrap().build();
+        let resolver = linter.overrides.as_ref().unwrap();
+        let rule_names = |path: &str| {
+            resolver.resolve(&linter.rules, Path::new(path)).map(|resolved| {
+                resolved
+                    .rules
+                    .iter()
+                    .map(|rule| format!("{}:{}", rule.name(), rule.severity.as_str()))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(rule_names("src/index.js"), None);
+        assert_eq!(rule_names("src/index.test.js"), Some(vec!["no-debugger:deny".to_string()]));
+        // Later overrides take precedence.
+        assert_eq!(rule_names("fixtures/index.test.js"), Some(vec![]));
+        assert_eq!(rule_names("fixtures/index.js"), Some(vec!["no-console:warn".to_string()]));
+    }
+
+    #[test]
+    fn test_overrides_env_and_globals() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "env": { "browser": true },
+            "globals": { "foo": "readonly" },
+            "overrides": [
+                { "files": ["**/*.test.js"], "env": { "jest": true }, "globals": { "foo": "off" } },
+                { "files": ["scripts/**"], "env": { "browser": false, "node": true } },
+                { "files": ["**/fixtures/**"], "rules": { "no-debugger": "off" } }
+            ]
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc).unwrap().build();
+        let resolver = linter.overrides.as_ref().unwrap();
+        let config = |path: &str| {
+            resolver.resolve(&linter.rules, Path::new(path)).map(|resolved| resolved.config)
+        };
+
+        let test = config("src/index.test.js").unwrap();
+        assert!(test.env.contains("browser") && test.env.contains("jest"));
+        assert!(!test.globals.is_enabled("foo"));
+        let script = config("scripts/build.js").unwrap();
+        assert!(!script.env.contains("browser") && script.env.contains("node"));
+        assert!(script.globals.is_enabled("foo"));
+        // Overrides without `env` or `globals` share the linter's configuration.
+        assert!(Arc::ptr_eq(&config("fixtures/index.js").unwrap(), &linter.config));
+        assert!(!linter.config.env.contains("jest"));
+    }
+
+    #[test]
+    fn test_explain_rule() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": { "no-console": ["error", { "allow": ["info"] }] },
+            "overrides": [{ "files": ["**/*.test.js"], "rules": { "no-console": "off" } }]
+        }))
+        .unwrap();
+        let builder = LinterBuilder::from_oxlintrc(false, oxlintrc.clone()).unwrap();
+        let explain = |name: &str| builder.explain_rule(&oxlintrc, name);
+
+        let explanation = explain("no-console").unwrap();
+        assert!(explanation.starts_with("# eslint/no-console\n"), "{explanation}");
+        assert!(explanation.contains("### What it does"), "{explanation}");
+        assert!(
+            explanation.contains("Severity: deny with options [{\"allow\":[\"info\"]}]\n"),
+            "{explanation}"
+        );
+        assert!(explanation.contains("Severity for **/*.test.js: allow\n"), "{explanation}");
+        assert_eq!(explain("eslint/no-console"), Some(explanation.clone()));
+        assert_eq!(explain("eslint(no-console)"), Some(explanation));
+
+        // On by default, but its plugin is not.
+        let explanation = explain("eslint-plugin-jest(valid-expect)").unwrap();
+        assert!(explanation.starts_with("# jest/valid-expect\n"), "{explanation}");
+        assert!(explanation.contains("Severity: allow\nThe `jest` plugin is not enabled."));
+        assert_eq!(explain("jest/valid-expect"), Some(explanation));
+        assert_eq!(explain("no-such-rule"), None);
+    }
+
+    #[test]
+    fn test_resolve_config_file_for_path() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": { "no-console": "warn" },
+            "overrides": [
+                {
+                    "files": ["**/*.test.js"],
+                    "env": { "jest": true },
+                    "rules": { "no-console": "off", "no-debugger": "error" }
+                }
+            ]
+        }))
+        .unwrap();
+        let builder = LinterBuilder::from_oxlintrc(true, oxlintrc.clone()).unwrap();
+        let rules = |path: &str| {
+            let config: serde_json::Value = serde_json::from_str(
+                &builder.resolve_config_file_for_path(oxlintrc.clone(), Path::new(path)),
+            )
+            .unwrap();
+            assert!(config.get("overrides").is_none());
+            (config["rules"].clone(), config["env"].clone())
+        };
+
+        assert_eq!(
+            rules("src/index.js"),
+            (serde_json::json!({ "no-console": "warn" }), serde_json::json!({ "builtin": true }))
+        );
+        assert_eq!(
+            rules("src/index.test.js"),
+            (
+                serde_json::json!({ "no-debugger": "deny" }),
+                serde_json::json!({ "builtin": true, "jest": true })
+            )
+        );
+    }
+
+    #[test]
+    fn test_overrides_unknown_rules() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "overrides": [{ "files": ["**/*.tsx"], "rules": { "react/no-such-rule": "error" } }]
+        }))
+        .unwrap();
+        let Err(LinterBuilderError::UnknownRules { rules }) =
+            LinterBuilder::from_oxlintrc(true, oxlintrc)
+        else {
+            panic!("expected unknown rules error");
+        };
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].rule_name, "no-such-rule");
+    }
+
+    #[test]
+    fn test_invalid_rule_options() {
+        let build = |config: serde_json::Value| {
+            let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+            LinterBuilder::from_oxlintrc(true, oxlintrc)
+        };
+        let invalid_options = |config: serde_json::Value| match build(config) {
+            Err(LinterBuilderError::InvalidRuleOptions { rules }) => rules,
+            _ => panic!("expected invalid rule options error"),
+        };
+
+        let rules = invalid_options(serde_json::json!({
+            "rules": { "no-restricted-syntax": ["error", "WithStatement", "CallExpression["] }
+        }));
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].0, "no-restricted-syntax");
+        let rules = invalid_options(serde_json::json!({
+            "overrides": [{
+                "files": ["*.js"],
+                "rules": { "no-restricted-syntax": ["warn", { "selector": ":has(" }] }
+            }]
+        }));
+        assert_eq!(rules[0].0, "no-restricted-syntax");
+
+        // Options of disabled rules are not used.
+        assert!(build(serde_json::json!({
+            "rules": { "no-restricted-syntax": ["off", "CallExpression["] }
+        }))
+        .is_ok());
+    }
 }
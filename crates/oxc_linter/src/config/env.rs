@@ -10,6 +10,9 @@ use serde::{Deserialize, Serialize};
 /// list of
 /// environments](https://eslint.org/docs/v8.x/use/configure/language-options#specifying-environments)
 /// for what environments are available and what each one provides.
+///
+/// Files can enable more environments for themselves with a
+/// `/* oxlint-env jest */` or `/* eslint-env jest */` comment.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct OxlintEnv(FxHashMap<String, bool>);
@@ -27,6 +30,11 @@ impl OxlintEnv {
         // Filter out false values
         self.0.iter().filter_map(|(k, v)| (*v).then_some(k.as_str()))
     }
+
+    /// Turn environments of `env` on or off, leaving the others alone.
+    pub(crate) fn override_env(&mut self, env: &Self) {
+        self.0.extend(env.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
 }
 
 impl FromIterator<String> for OxlintEnv {
@@ -29,6 +29,9 @@ use serde::{de::Visitor, Deserialize, Serialize};
 ///
 /// You may also use `"readable"` or `false` to represent `"readonly"`, and
 /// `"writeable"` or `true` to represent `"writable"`.
+///
+/// Files can declare globals for themselves with a `/* globals foo, bar: writable */`
+/// comment. Globals without a value are read-only.
 // <https://eslint.org/docs/v8.x/use/configure/language-options#using-configuration-files-1>
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone)]
 pub struct OxlintGlobals(FxHashMap<String, GlobalValue>);
@@ -40,6 +43,17 @@ impl OxlintGlobals {
     {
         self.0.get(name).is_some_and(|value| *value != GlobalValue::Off)
     }
+
+    /// Add, change or disable the globals of `globals`, leaving the others alone.
+    pub(crate) fn override_globals(&mut self, globals: &Self) {
+        self.0.extend(globals.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
+}
+
+impl FromIterator<(String, GlobalValue)> for OxlintGlobals {
+    fn from_iter<T: IntoIterator<Item = (String, GlobalValue)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use oxc_ast::Comment;
+
+use super::{globals::GlobalValue, LintConfig, OxlintEnv, OxlintGlobals};
+
+/// Add the environments and globals declared by comments in a file to `config`.
+///
+/// Like ESLint, block comments declare environments with `/* eslint-env node, jest */` (or
+/// `oxlint-env`) and globals with `/* global foo, bar: writable */` (or `globals`). Globals
+/// without a value are read-only, and `true` and `false` stand for `writable` and `readonly`.
+pub(crate) fn apply_inline_config(
+    config: Arc<LintConfig>,
+    source_text: &str,
+    comments: &[Comment],
+) -> Arc<LintConfig> {
+    let mut env = vec![];
+    let mut globals = vec![];
+    for comment in comments.iter().filter(|comment| comment.is_block()) {
+        let text = comment.span.source_text(source_text).trim_start();
+        if let Some(text) = text.strip_prefix("globals").or_else(|| text.strip_prefix("global")) {
+            if text.starts_with(char::is_whitespace) {
+                globals.extend(parse_list(text).into_iter().map(|(name, value)| {
+                    let value = match value.as_deref() {
+                        Some("true") => GlobalValue::Writeable,
+                        Some(value) => {
+                            GlobalValue::try_from(value).unwrap_or(GlobalValue::Readonly)
+                        }
+                        None => GlobalValue::Readonly,
+                    };
+                    (name, value)
+                }));
+            }
+        } else if let Some(text) =
+            text.strip_prefix("eslint-env").or_else(|| text.strip_prefix("oxlint-env"))
+        {
+            if text.starts_with(char::is_whitespace) {
+                env.extend(parse_list(text).into_iter().map(|(name, _)| name));
+            }
+        }
+    }
+
+    if env.is_empty() && globals.is_empty() {
+        return config;
+    }
+    let mut config = LintConfig::clone(&config);
+    config.env.override_env(&env.into_iter().collect::<OxlintEnv>());
+    config.globals.override_globals(&globals.into_iter().collect::<OxlintGlobals>());
+    Arc::new(config)
+}
+
+/// Parse `a, b:false c` into names with optional values.
+///
+/// As in ESLint, items are separated by commas or whitespace, and whitespace around `:` and
+/// `,` is ignored, so `a : false` is `a:false`.
+fn parse_list(text: &str) -> Vec<(String, Option<String>)> {
+    let mut normalized = String::with_capacity(text.len());
+    let mut after_separator = false;
+    for c in text.chars() {
+        if matches!(c, ':' | ',') {
+            normalized.truncate(normalized.trim_end().len());
+            normalized.push(c);
+            after_separator = true;
+        } else if !(after_separator && c.is_whitespace()) {
+            normalized.push(c);
+            after_separator = false;
+        }
+    }
+    normalized
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(|item| match item.split_once(':') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (item.to_string(), None),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{apply_inline_config, LintConfig};
+
+    fn inline_config(source_text: &str) -> Arc<LintConfig> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        apply_inline_config(Arc::default(), source_text, &ret.program.comments)
+    }
+
+    #[test]
+    fn test_env() {
+        let config = inline_config("/* eslint-env node, jest */ /*oxlint-env browser*/");
+        assert!(config.env.contains("builtin"));
+        assert!(config.env.contains("node"));
+        assert!(config.env.contains("jest"));
+        assert!(config.env.contains("browser"));
+
+        let config = inline_config("// eslint-env node\n/* eslint-envnode */");
+        assert!(!config.env.contains("node"));
+    }
+
+    #[test]
+    fn test_globals() {
+        let config = inline_config(
+            "/* global a, b: writable c:false */ /*globals d:true, e : off f:readonly*/ /* globalg */",
+        );
+        assert!(config.globals.is_enabled("a"));
+        assert!(config.globals.is_enabled("b"));
+        assert!(config.globals.is_enabled("c"));
+        assert!(config.globals.is_enabled("d"));
+        assert!(!config.globals.is_enabled("e"));
+        assert!(config.globals.is_enabled("f"));
+        assert!(!config.globals.is_enabled("globalg"));
+        assert!(!config.globals.is_enabled("g"));
+    }
+
+    #[test]
+    fn test_no_comments() {
+        let config = Arc::new(LintConfig::default());
+        let allocator = Allocator::default();
+        let source_text = "/* a comment */ foo;";
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let applied = apply_inline_config(Arc::clone(&config), source_text, &ret.program.comments);
+        assert!(Arc::ptr_eq(&config, &applied));
+    }
+}
@@ -0,0 +1,417 @@
+use oxc_diagnostics::OxcDiagnostic;
+use serde_json::{Map, Value};
+
+use super::{
+    plugins::LintPlugins,
+    rules::{find_rule, parse_rule_value},
+};
+use crate::{AllowWarnDeny, RuleEnum};
+
+/// An oxlint configuration migrated from an ESLint configuration by
+/// [`migrate_eslint_config`].
+#[derive(Debug, Default)]
+pub struct EslintConfigMigration {
+    /// The migrated configuration, in the format of `.oxlintrc.json`.
+    pub oxlintrc: Value,
+    /// Enabled ESLint rules that oxlint does not implement.
+    pub unsupported_rules: Vec<String>,
+    /// Parts of the ESLint configuration that could not be migrated.
+    pub warnings: Vec<String>,
+}
+
+/// Convert an ESLint configuration into an oxlint configuration.
+///
+/// `eslint_config` is either a legacy `.eslintrc` object, or a flat config
+/// (`eslint.config.js`) exported as JSON: an array of config objects.
+///
+/// Rules oxlint implements are renamed to their oxlint names and keep their
+/// options. The plugins they belong to are enabled. `typescript-eslint`
+/// extension rules, e.g. `@typescript-eslint/no-unused-vars`, are mapped to
+/// the corresponding ESLint rule.
+///
+/// # Errors
+///
+/// Returns an error if `eslint_config` is neither an object nor an array.
+pub fn migrate_eslint_config(
+    eslint_config: &Value,
+) -> Result<EslintConfigMigration, OxcDiagnostic> {
+    let mut migration = Migration::default();
+    match eslint_config {
+        Value::Object(config) => migration.eslintrc(config),
+        Value::Array(configs) => {
+            for (index, config) in configs.iter().enumerate() {
+                let Some(config) = config.as_object() else {
+                    migration.warn(format!("Skipped config #{index}, which is not an object"));
+                    continue;
+                };
+                migration.flat_config(index, config);
+            }
+        }
+        _ => {
+            return Err(OxcDiagnostic::error(
+                "Expected an ESLint configuration object, or an array of flat config objects",
+            ));
+        }
+    }
+    Ok(migration.finish())
+}
+
+#[derive(Default)]
+struct Migration {
+    plugins: LintPlugins,
+    env: Map<String, Value>,
+    globals: Map<String, Value>,
+    settings: Map<String, Value>,
+    rules: Map<String, Value>,
+    overrides: Vec<Value>,
+    unsupported_rules: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Migration {
+    fn warn(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+
+    fn finish(self) -> EslintConfigMigration {
+        let mut oxlintrc = Map::new();
+        oxlintrc.insert("plugins".to_string(), serde_json::to_value(self.plugins).unwrap());
+        for (key, map) in
+            [("env", self.env), ("globals", self.globals), ("settings", self.settings)]
+        {
+            if !map.is_empty() {
+                oxlintrc.insert(key.to_string(), Value::Object(map));
+            }
+        }
+        oxlintrc.insert("rules".to_string(), Value::Object(self.rules));
+        if !self.overrides.is_empty() {
+            oxlintrc.insert("overrides".to_string(), Value::Array(self.overrides));
+        }
+        EslintConfigMigration {
+            oxlintrc: Value::Object(oxlintrc),
+            unsupported_rules: self.unsupported_rules,
+            warnings: self.warnings,
+        }
+    }
+
+    /// A legacy `.eslintrc` configuration.
+    fn eslintrc(&mut self, config: &Map<String, Value>) {
+        for (key, value) in config {
+            match key.as_str() {
+                "rules" => self.rules(value, None),
+                "plugins" => self.plugins(value),
+                "env" => merge(&mut self.env, value),
+                "globals" => merge(&mut self.globals, value),
+                "settings" => merge(&mut self.settings, value),
+                // Flat configs printed with `eslint --print-config`.
+                "languageOptions" => {
+                    if let Some(globals) = value.get("globals") {
+                        merge(&mut self.globals, globals);
+                    }
+                }
+                "overrides" => {
+                    for (index, r#override) in value.as_array().into_iter().flatten().enumerate() {
+                        self.eslintrc_override(index, r#override);
+                    }
+                }
+                "extends" => self.warn(
+                    "`extends` is not supported. Rules of shared configs have to be added to `rules`"
+                        .to_string(),
+                ),
+                "ignorePatterns" => self.warn(
+                    "`ignorePatterns` is not supported. Use an `.eslintignore` file instead"
+                        .to_string(),
+                ),
+                "root" | "parser" | "parserOptions" | "$schema" => {}
+                _ => self.warn(format!("`{key}` is not supported")),
+            }
+        }
+    }
+
+    fn eslintrc_override(&mut self, index: usize, r#override: &Value) {
+        let files = match r#override.get("files") {
+            Some(Value::String(pattern)) => vec![eslintrc_pattern(pattern)],
+            Some(Value::Array(patterns)) => {
+                patterns.iter().filter_map(Value::as_str).map(eslintrc_pattern).collect()
+            }
+            _ => vec![],
+        };
+
+        let keys = r#override.as_object().into_iter().flatten().map(|(key, _)| key.as_str());
+        for key in keys.filter(|key| !matches!(*key, "files" | "rules" | "env" | "globals")) {
+            self.warn(format!("`overrides[{index}].{key}` is not supported"));
+        }
+        self.r#override(
+            files,
+            r#override.get("rules"),
+            r#override.get("env"),
+            r#override.get("globals"),
+        );
+    }
+
+    /// An object of a flat config. Objects with `files` only apply to some
+    /// files, and become overrides.
+    fn flat_config(&mut self, index: usize, config: &Map<String, Value>) {
+        if let Some(files) = config.get("files") {
+            let files = files
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|pattern| {
+                    let pattern = pattern.as_str().map(ToString::to_string);
+                    if pattern.is_none() {
+                        self.warn(format!(
+                            "Skipped a nested pattern in `files` of config #{index}"
+                        ));
+                    }
+                    pattern
+                })
+                .collect();
+            for key in config.keys().filter(|key| {
+                !matches!(key.as_str(), "files" | "rules" | "plugins" | "name" | "languageOptions")
+            }) {
+                self.warn(format!(
+                    "`{key}` of config #{index} is not supported for a subset of files"
+                ));
+            }
+            if let Some(plugins) = config.get("plugins") {
+                self.plugins(plugins);
+            }
+            let globals = config.get("languageOptions").and_then(|options| options.get("globals"));
+            self.r#override(files, config.get("rules"), None, globals);
+            return;
+        }
+
+        for (key, value) in config {
+            match key.as_str() {
+                "rules" => self.rules(value, None),
+                "plugins" => self.plugins(value),
+                "settings" => merge(&mut self.settings, value),
+                "languageOptions" => {
+                    if let Some(globals) = value.get("globals") {
+                        merge(&mut self.globals, globals);
+                    }
+                }
+                "ignores" => self.warn(format!(
+                    "`ignores` of config #{index} is not supported. Use an `.eslintignore` file instead"
+                )),
+                "name" | "linterOptions" => {}
+                _ => self.warn(format!("`{key}` of config #{index} is not supported")),
+            }
+        }
+    }
+
+    fn r#override(
+        &mut self,
+        files: Vec<String>,
+        rules: Option<&Value>,
+        env: Option<&Value>,
+        globals: Option<&Value>,
+    ) {
+        let mut override_rules = Map::new();
+        if let Some(rules) = rules {
+            self.rules(rules, Some(&mut override_rules));
+        }
+        let mut r#override = Map::new();
+        r#override.insert("files".to_string(), files.into());
+        for (key, value) in [("env", env), ("globals", globals)] {
+            if let Some(value @ Value::Object(_)) = value {
+                r#override.insert(key.to_string(), value.clone());
+            }
+        }
+        r#override.insert("rules".to_string(), Value::Object(override_rules));
+        self.overrides.push(Value::Object(r#override));
+    }
+
+    /// Migrate `rules` into `target`, or the top-level rules.
+    fn rules(&mut self, rules: &Value, mut target: Option<&mut Map<String, Value>>) {
+        for (key, value) in rules.as_object().into_iter().flatten() {
+            let Ok((severity, options)) = parse_rule_value(value) else {
+                self.warn(format!("Skipped rule `{key}` with an invalid configuration: {value}"));
+                continue;
+            };
+            let Some(rule) = find_rule(key) else {
+                // Disabling a rule oxlint doesn't have is a no-op.
+                if !severity.is_allow() {
+                    self.unsupported_rules.push(key.clone());
+                }
+                continue;
+            };
+            if !severity.is_allow() {
+                self.plugins |= LintPlugins::from(rule.plugin_name());
+            }
+
+            let mut config = vec![Value::from(match severity {
+                AllowWarnDeny::Allow => "off",
+                AllowWarnDeny::Warn => "warn",
+                AllowWarnDeny::Deny => "error",
+            })];
+            if let Some(Value::Array(options)) = options {
+                config.extend(options);
+            }
+            let config = if config.len() == 1 { config.remove(0) } else { Value::Array(config) };
+
+            let rules = match target.as_deref_mut() {
+                Some(target) => target,
+                None => &mut self.rules,
+            };
+            // The base rule of an extension rule is usually turned off, e.g.
+            // `"no-unused-vars": "off"` next to `"@typescript-eslint/no-unused-vars": "error"`.
+            let name = oxlint_name(rule);
+            if severity.is_allow() && rules.contains_key(&name) {
+                continue;
+            }
+            rules.insert(name, config);
+        }
+    }
+
+    fn plugins(&mut self, plugins: &Value) {
+        let names: Vec<&str> = match plugins {
+            Value::Array(plugins) => plugins
+                .iter()
+                .filter_map(Value::as_str)
+                // `eslint --print-config` lists plugins as `name:package`.
+                .map(|plugin| plugin.split_once(':').map_or(plugin, |(name, _)| name))
+                .collect(),
+            // Flat configs map names to plugin objects.
+            Value::Object(plugins) => plugins.keys().map(String::as_str).collect(),
+            _ => vec![],
+        };
+        for name in names {
+            let plugin = match name {
+                "@next/next" | "@next" | "next" => LintPlugins::NEXTJS,
+                name => LintPlugins::from(name),
+            };
+            if plugin.is_empty() {
+                self.warn(format!("Plugin `{name}` is not supported"));
+            }
+            self.plugins |= plugin;
+        }
+    }
+}
+
+fn oxlint_name(rule: &RuleEnum) -> String {
+    match <&str>::from(LintPlugins::from(rule.plugin_name())) {
+        "" => rule.name().to_string(),
+        plugin => format!("{plugin}/{}", rule.name()),
+    }
+}
+
+/// ESLint matches patterns without a slash against file names in any
+/// directory, oxlint against whole paths.
+fn eslintrc_pattern(pattern: &str) -> String {
+    if pattern.contains('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+/// Add the entries of `source`, if it is an object, to `target`.
+fn merge(target: &mut Map<String, Value>, source: &Value) {
+    if let Some(source) = source.as_object() {
+        target.extend(source.iter().map(|(key, value)| (key.clone(), value.clone())));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::migrate_eslint_config;
+
+    #[test]
+    fn test_eslintrc() {
+        let migration = migrate_eslint_config(&json!({
+            "root": true,
+            "extends": ["eslint:recommended"],
+            "plugins": ["jest", "@typescript-eslint", "eslint-comments"],
+            "env": { "browser": true },
+            "globals": { "jQuery": "readonly" },
+            "rules": {
+                "no-console": [2, { "allow": ["warn"] }],
+                "no-unused-vars": "off",
+                "@typescript-eslint/no-unused-vars": ["error", { "args": "none" }],
+                "jsx-a11y/alt-text": "warn",
+                "no-restricted-properties": "error",
+                "no-with": "off"
+            },
+            "overrides": [
+                { "files": "*.test.js", "env": { "jest": true }, "rules": { "no-console": "off" } }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            migration.oxlintrc,
+            json!({
+                "plugins": ["react", "unicorn", "typescript", "oxc", "jest", "jsx-a11y"],
+                "env": { "browser": true },
+                "globals": { "jQuery": "readonly" },
+                "rules": {
+                    "no-console": ["error", { "allow": ["warn"] }],
+                    "no-unused-vars": ["error", { "args": "none" }],
+                    "jsx-a11y/alt-text": "warn",
+                    "no-with": "off",
+                },
+                "overrides": [{
+                    "files": ["**/*.test.js"],
+                    "env": { "jest": true },
+                    "rules": { "no-console": "off" }
+                }]
+            })
+        );
+        assert_eq!(migration.unsupported_rules, vec!["no-restricted-properties"]);
+        assert_eq!(
+            migration.warnings,
+            vec![
+                "`extends` is not supported. Rules of shared configs have to be added to `rules`",
+                "Plugin `eslint-comments` is not supported",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flat_config() {
+        let migration = migrate_eslint_config(&json!([
+            { "ignores": ["dist/**"] },
+            {
+                "plugins": { "react": {}, "react-hooks": {} },
+                "languageOptions": { "ecmaVersion": 2022, "globals": { "window": "readonly" } },
+                "rules": { "eqeqeq": ["warn", "smart"], "react/jsx-key": "error" }
+            },
+            {
+                "files": ["**/*.spec.ts"],
+                "languageOptions": { "globals": { "vi": "readonly" } },
+                "rules": { "vitest/no-focused-tests": "error" }
+            }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            migration.oxlintrc,
+            json!({
+                "plugins": ["react", "unicorn", "typescript", "oxc", "jest"],
+                "globals": { "window": "readonly" },
+                "rules": { "eqeqeq": ["warn", "smart"], "react/jsx-key": "error" },
+                "overrides": [
+                    {
+                        "files": ["**/*.spec.ts"],
+                        "globals": { "vi": "readonly" },
+                        "rules": { "jest/no-focused-tests": "error" }
+                    }
+                ]
+            })
+        );
+        assert!(migration.unsupported_rules.is_empty());
+        assert_eq!(
+            migration.warnings,
+            vec!["`ignores` of config #0 is not supported. Use an `.eslintignore` file instead"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(migrate_eslint_config(&json!("eslint:recommended")).is_err());
+    }
+}
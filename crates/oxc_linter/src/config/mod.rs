@@ -1,22 +1,34 @@
 mod categories;
 mod env;
 mod globals;
+mod inline_config;
+mod migrate;
+mod overrides;
 mod oxlintrc;
 mod plugins;
 mod rules;
 mod settings;
+mod validate;
 
 pub use self::{
     env::OxlintEnv,
     globals::OxlintGlobals,
+    migrate::{migrate_eslint_config, EslintConfigMigration},
+    overrides::{GlobSet, OxlintOverride, OxlintOverrides},
     oxlintrc::Oxlintrc,
     plugins::LintPlugins,
     rules::ESLintRule,
     rules::OxlintRules,
     settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
+    validate::validate_config,
+};
+pub(crate) use self::{
+    inline_config::apply_inline_config,
+    overrides::{BasePath, OverrideResolver},
+    rules::find_rule,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct LintConfig {
     pub(crate) plugins: LintPlugins,
     pub(crate) settings: OxlintSettings,
@@ -0,0 +1,315 @@
+use std::{
+    borrow::Cow,
+    env,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{rules::OxlintRules, LintConfig, OxlintEnv, OxlintGlobals};
+use crate::{external::ConfiguredExternalPlugin, rules::RuleEnum, RuleWithSeverity};
+
+/// Configure rules and globals for a subset of files, e.g. only enabling test
+/// rules and globals on test files.
+///
+/// Overrides are applied in order, on top of the top-level `rules`, `env` and
+/// `globals`. When several overrides match a file, later ones take precedence.
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "overrides": [
+///     {
+///       "files": ["**/*.{jsx,tsx}"],
+///       "rules": { "react/jsx-key": "error" }
+///     },
+///     {
+///       "files": ["**/*.test.*", "**/*.spec.*"],
+///       "env": { "jest": true },
+///       "rules": { "jest/no-disabled-tests": "error", "no-console": "off" }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct OxlintOverrides(Vec<OxlintOverride>);
+
+impl OxlintOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &OxlintOverride> + '_ {
+        self.0.iter()
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut OxlintOverride> + '_ {
+        self.0.iter_mut()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[non_exhaustive]
+pub struct OxlintOverride {
+    /// Glob patterns of the files this override applies to, e.g. `**/*.test.ts`.
+    ///
+    /// Patterns are matched against file paths relative to the directory of
+    /// the configuration file, as in ESLint. Use a leading `**/` to match files
+    /// in any directory.
+    pub files: GlobSet,
+    /// Rules to configure for matching files, in the same format as the
+    /// top-level `rules`.
+    #[serde(default)]
+    pub rules: OxlintRules,
+    /// Environments to turn on or off for matching files. Environments that
+    /// are not listed keep their top-level setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<OxlintEnv>,
+    /// Globals to add, change or turn off for matching files, in the same
+    /// format as the top-level `globals`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub globals: Option<OxlintGlobals>,
+}
+
+/// A set of glob patterns, e.g. `["**/*.test.ts", "src/**"]`.
+#[derive(Debug, Clone)]
+pub struct GlobSet {
+    patterns: Vec<String>,
+    set: globset::GlobSet,
+}
+
+impl GlobSet {
+    /// # Errors
+    ///
+    /// Returns an error if any of the patterns is not a valid glob.
+    pub fn new<S: Into<String>, I: IntoIterator<Item = S>>(
+        patterns: I,
+    ) -> Result<Self, globset::Error> {
+        let patterns = patterns.into_iter().map(Into::into).collect::<Vec<String>>();
+        let mut builder = globset::GlobSetBuilder::new();
+        for pattern in &patterns {
+            builder.add(globset::Glob::new(pattern)?);
+        }
+        Ok(Self { set: builder.build()?, patterns })
+    }
+
+    /// The glob patterns, as they were written in the config.
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Returns `true` if any of the patterns match `path`.
+    pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.set.is_match(path)
+    }
+}
+
+impl<'de> Deserialize<'de> for GlobSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let patterns = Vec::<String>::deserialize(deserializer)?;
+        Self::new(patterns).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for GlobSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.patterns.serialize(serializer)
+    }
+}
+
+impl JsonSchema for GlobSet {
+    fn schema_name() -> String {
+        "GlobSet".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("GlobSet")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<Vec<String>>()
+    }
+}
+
+/// The directory override patterns are relative to: that of the configuration
+/// file, or the current working directory for configurations not loaded from a
+/// file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BasePath {
+    cwd: PathBuf,
+    dir: PathBuf,
+}
+
+impl BasePath {
+    /// `config_path` is the path of the configuration file, or empty.
+    pub fn new(config_path: &Path) -> Self {
+        let cwd = env::current_dir().unwrap_or_default();
+        let dir = cwd.join(config_path.parent().unwrap_or_else(|| Path::new("")));
+        Self { cwd, dir }
+    }
+
+    /// `path` relative to the base directory, to match override patterns
+    /// against. Relative paths are resolved from the current working directory
+    /// first. Paths outside of the base directory are returned as is.
+    pub fn relative<'p>(&self, path: &'p Path) -> Cow<'p, Path> {
+        match self.cwd.join(path).strip_prefix(&self.dir) {
+            Ok(relative) => Cow::Owned(relative.to_path_buf()),
+            Err(_) => Cow::Borrowed(path),
+        }
+    }
+}
+
+/// Resolves the rules to run on a file, and the configuration to run them
+/// with: the linter's base rules and configuration, with every override
+/// matching the file's path applied on top.
+///
+/// Rule sets are resolved once per combination of matching overrides and
+/// shared between all files with that combination.
+#[derive(Debug)]
+pub(crate) struct OverrideResolver {
+    overrides: OxlintOverrides,
+    base_path: BasePath,
+    /// Rules of all enabled plugins, which overrides may turn on.
+    all_rules: Vec<RuleEnum>,
+    /// External plugins with the rules the top-level config turned on for them.
+    external_plugins: Vec<ConfiguredExternalPlugin>,
+    /// The linter's configuration, which overrides may change the globals of.
+    config: Arc<LintConfig>,
+    /// Resolved rule sets, keyed by the indices of the overrides that matched.
+    cache: Mutex<FxHashMap<Vec<usize>, ResolvedOverrides>>,
+}
+
+/// Rules and configuration for files matching a combination of overrides.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedOverrides {
+    pub rules: Arc<[RuleWithSeverity]>,
+    pub config: Arc<LintConfig>,
+    /// External plugins with at least one rule turned on.
+    pub external_plugins: Arc<[ConfiguredExternalPlugin]>,
+}
+
+impl OverrideResolver {
+    pub fn new(
+        overrides: OxlintOverrides,
+        base_path: BasePath,
+        all_rules: Vec<RuleEnum>,
+        external_plugins: Vec<ConfiguredExternalPlugin>,
+        config: Arc<LintConfig>,
+    ) -> Self {
+        Self { overrides, base_path, all_rules, external_plugins, config, cache: Mutex::default() }
+    }
+
+    /// Rules and configuration for the file at `path`, or [`None`] if no
+    /// override matches it and `base` applies as is.
+    pub fn resolve(&self, base: &[RuleWithSeverity], path: &Path) -> Option<ResolvedOverrides> {
+        let path = self.base_path.relative(path);
+        let matched = self
+            .overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, r#override)| r#override.files.is_match(&path))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        if matched.is_empty() {
+            return None;
+        }
+
+        let mut cache = self.cache.lock().unwrap();
+        let resolved = cache.entry(matched).or_insert_with_key(|matched| {
+            let mut rules = base.iter().cloned().collect::<FxHashSet<_>>();
+            let mut external_plugins = self.external_plugins.clone();
+            let mut config = Cow::Borrowed(self.config.as_ref());
+            for index in matched {
+                let r#override = &self.overrides.0[*index];
+                let mut override_rules = r#override.rules.clone();
+                override_rules.override_rules(&mut rules, &self.all_rules);
+                // The builder checked that rules which aren't built in are external.
+                for rule in override_rules.unknown_rules {
+                    if let Some(plugin) =
+                        external_plugins.iter_mut().find(|plugin| plugin.provides(&rule))
+                    {
+                        plugin.configure(rule.rule_name, rule.severity, rule.config);
+                    }
+                }
+                if let Some(env) = &r#override.env {
+                    config.to_mut().env.override_env(env);
+                }
+                if let Some(globals) = &r#override.globals {
+                    config.to_mut().globals.override_globals(globals);
+                }
+            }
+            let mut rules = rules.into_iter().collect::<Vec<_>>();
+            rules.sort_unstable_by_key(|rule| rule.id());
+            let config = match config {
+                Cow::Borrowed(_) => Arc::clone(&self.config),
+                Cow::Owned(config) => Arc::new(config),
+            };
+            external_plugins.retain(|plugin| !plugin.rules().is_empty());
+            ResolvedOverrides {
+                rules: rules.into(),
+                config,
+                external_plugins: external_plugins.into(),
+            }
+        });
+        Some(resolved.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{BasePath, OxlintOverrides};
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides: OxlintOverrides = serde_json::from_value(json!([
+            { "files": ["**/*.{jsx,tsx}"], "rules": { "react/jsx-key": "error" } },
+            { "files": ["**/*.test.*"] }
+        ]))
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+
+        let overrides = overrides.iter().collect::<Vec<_>>();
+        assert!(overrides[0].files.is_match("src/App.tsx"));
+        assert!(overrides[0].files.is_match("/home/me/project/src/App.jsx"));
+        assert!(!overrides[0].files.is_match("src/app.ts"));
+        assert!(!overrides[0].rules.is_empty());
+        assert!(overrides[1].files.is_match("src/foo.test.ts"));
+        assert!(overrides[1].rules.is_empty());
+
+        let serialized = serde_json::to_value(overrides[1]).unwrap();
+        assert_eq!(serialized["files"], json!(["**/*.test.*"]));
+    }
+
+    #[test]
+    fn test_base_path() {
+        let cwd = std::env::current_dir().unwrap();
+        let base_path = BasePath::new(Path::new("fixtures/.oxlintrc.json"));
+        assert_eq!(base_path.relative(&cwd.join("fixtures/src/a.js")), Path::new("src/a.js"));
+        assert_eq!(base_path.relative(Path::new("fixtures/src/a.js")), Path::new("src/a.js"));
+        assert_eq!(base_path.relative(Path::new("/elsewhere/a.js")), Path::new("/elsewhere/a.js"));
+
+        let base_path = BasePath::new(Path::new(""));
+        assert_eq!(base_path.relative(&cwd.join("src/a.js")), Path::new("src/a.js"));
+        assert_eq!(base_path.relative(Path::new("src/a.js")), Path::new("src/a.js"));
+    }
+
+    #[test]
+    fn test_parse_invalid_glob() {
+        let err = serde_json::from_value::<OxlintOverrides>(json!([{ "files": ["src/{a,b"] }]))
+            .unwrap_err();
+        assert!(err.to_string().contains("src/{a,b"), "{err}");
+    }
+}
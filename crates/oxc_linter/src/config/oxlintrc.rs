@@ -1,12 +1,12 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use oxc_diagnostics::OxcDiagnostic;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals, plugins::LintPlugins,
-    rules::OxlintRules, settings::OxlintSettings,
+    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals,
+    overrides::OxlintOverrides, plugins::LintPlugins, rules::OxlintRules, settings::OxlintSettings,
 };
 
 use crate::utils::read_to_string;
@@ -49,6 +49,12 @@ use crate::utils::read_to_string;
 #[serde(default)]
 #[non_exhaustive]
 pub struct Oxlintrc {
+    /// Plugins to enable, e.g. `["import", "jest"]`.
+    ///
+    /// Paths load external plugins: shared libraries, e.g.
+    /// `"./plugins/libacme_rules.so"`, or ESLint plugins written in JavaScript,
+    /// e.g. `"./eslint-plugin-acme.js"`. Relative paths are resolved from the
+    /// directory of the configuration file.
     pub plugins: LintPlugins,
     pub categories: OxlintCategories,
     /// Example
@@ -74,6 +80,16 @@ pub struct Oxlintrc {
     pub env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub globals: OxlintGlobals,
+    /// Add, remove, or reconfigure rules for specific files.
+    #[serde(skip_serializing_if = "OxlintOverrides::is_empty")]
+    pub overrides: OxlintOverrides,
+    /// Paths of external plugins listed in `plugins`.
+    #[serde(skip)]
+    pub external_plugins: Vec<PathBuf>,
+    /// Path of the file this configuration was loaded from, empty if it
+    /// wasn't. Patterns of `overrides` are relative to its directory.
+    #[serde(skip)]
+    pub path: PathBuf,
 }
 
 impl Oxlintrc {
@@ -105,14 +121,37 @@ impl Oxlintrc {
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
+        let mut config = Self::deserialize(&json).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
 
+        if let Some(plugins) = json.get("plugins").and_then(serde_json::Value::as_array) {
+            let dir = path.parent().unwrap_or_else(|| Path::new(""));
+            config.external_plugins = plugins
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .filter(|plugin| is_external_plugin(plugin))
+                .map(|plugin| dir.join(plugin))
+                .collect();
+        }
+        config.path = path.to_path_buf();
+
         Ok(config)
     }
 }
 
+/// Returns `true` if an entry of `plugins` is the path of an external plugin
+/// rather than the name of a built-in one.
+pub(super) fn is_external_plugin(plugin: &str) -> bool {
+    let path = Path::new(plugin);
+    plugin.starts_with('.')
+        || path.is_absolute()
+        || path
+            .extension()
+            .and_then(std::ffi::OsStr::to_str)
+            .is_some_and(|ext| matches!(ext, "so" | "dylib" | "dll" | "js" | "cjs" | "mjs"))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -126,6 +165,7 @@ mod test {
         assert!(config.rules.is_empty());
         assert_eq!(config.settings, OxlintSettings::default());
         assert_eq!(config.env, OxlintEnv::default());
+        assert!(config.overrides.is_empty());
     }
 
     #[test]
@@ -151,6 +191,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_oxlintrc_external_plugins() {
+        assert!(is_external_plugin("./plugins/libacme.so"));
+        assert!(is_external_plugin("../acme.dll"));
+        assert!(is_external_plugin("libacme.dylib"));
+        assert!(is_external_plugin("eslint-plugin-acme.js"));
+        assert!(!is_external_plugin("import"));
+        assert!(!is_external_plugin("@typescript-eslint"));
+        assert!(!is_external_plugin("jsx-a11y"));
+
+        let config: Oxlintrc =
+            serde_json::from_str(r#"{ "plugins": ["import", "./libacme.so"] }"#).unwrap();
+        assert_eq!(config.plugins, LintPlugins::default().union(LintPlugins::IMPORT));
+    }
+
     #[test]
     fn test_oxlintrc_de_plugins_new() {
         let config: Oxlintrc = serde_json::from_str(r#"{ "plugins": ["import"] }"#).unwrap();
@@ -10,6 +10,7 @@ use serde::{
 };
 
 use crate::{
+    context::plugin_name_to_prefix,
     rules::{RuleEnum, RULES},
     utils::is_jest_rule_adapted_to_vitest,
     AllowWarnDeny, RuleWithSeverity,
@@ -40,6 +41,33 @@ impl OxlintRules {
     pub fn is_empty(&self) -> bool {
         self.rules.is_empty()
     }
+
+    /// Problems in the options of enabled rules, as the full name of the rule
+    /// and an error message. See [`Rule::validate_configuration`].
+    ///
+    /// [`Rule::validate_configuration`]: crate::rule::Rule::validate_configuration
+    pub(crate) fn invalid_options(&self, all_rules: &[RuleEnum]) -> Vec<(String, String)> {
+        let mut invalid_options = vec![];
+        for rule_config in &self.rules {
+            let Some(config) = rule_config.config.as_ref() else { continue };
+            if !rule_config.severity.is_warn_deny() {
+                continue;
+            }
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule_config.rule_name, &rule_config.plugin_name);
+            let Some(rule) =
+                all_rules.iter().find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+            else {
+                continue;
+            };
+            invalid_options.extend(
+                rule.validate_json(config)
+                    .into_iter()
+                    .map(|(_, message)| (rule_config.full_name().into_owned(), message)),
+            );
+        }
+        invalid_options
+    }
 }
 
 /// A fully qualified rule name, e.g. `eslint/no-console` or `react/rule-of-hooks`.
@@ -167,7 +195,7 @@ impl OxlintRules {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+pub(super) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -270,7 +298,28 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+/// Find a rule by the name it is configured with in oxlint or ESLint, e.g.
+/// `no-debugger`, `react/jsx-key` or `@typescript-eslint/no-unused-vars`, or by
+/// the name it is reported with in diagnostics, e.g. `eslint-plugin-react(jsx-key)`.
+pub(crate) fn find_rule(name: &str) -> Option<&'static RuleEnum> {
+    if let Some((prefix, rule_name)) = name.strip_suffix(')').and_then(|n| n.split_once('(')) {
+        return RULES.iter().find(|rule| {
+            rule.name() == rule_name && plugin_name_to_prefix(rule.plugin_name()) == prefix
+        });
+    }
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    RULES.iter().find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name).or_else(
+        || {
+            // Extension rules of typescript-eslint replace ESLint rules.
+            (plugin_name == "typescript").then(|| {
+                RULES.iter().find(|rule| rule.name() == rule_name && rule.plugin_name() == "eslint")
+            })?
+        },
+    )
+}
+
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return (
             RULES
@@ -298,7 +347,7 @@ fn parse_rule_key(name: &str) -> (String, String) {
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
-fn parse_rule_value(
+pub(super) fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
     match value {
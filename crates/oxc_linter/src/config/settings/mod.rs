@@ -68,7 +68,10 @@ mod test {
                     "Hyperlink",
                     {"name": "MyLink", "linkAttribute": "to"},
                     {"name": "Link", "linkAttribute": ["to", "href"]},
-                ]
+                ],
+                "pragma": "Preact",
+                "createClass": "createClass",
+                "fragment": "Frag"
             }
         }))
         .unwrap();
@@ -93,6 +96,9 @@ mod test {
             as_attrs(["to", "href"])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(settings.react.pragma(), "Preact");
+        assert_eq!(settings.react.create_class(), "createClass");
+        assert_eq!(settings.react.fragment(), "Frag");
     }
 
     #[test]
@@ -100,5 +106,8 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert_eq!(settings.react.pragma(), "React");
+        assert_eq!(settings.react.create_class(), "createReactClass");
+        assert_eq!(settings.react.fragment(), "Fragment");
     }
 }
@@ -15,6 +15,19 @@ pub struct ReactPluginSettings {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+
+    /// Name of the object that holds React's API, e.g. `Preact` for `Preact.createElement`.
+    /// Defaults to `React`.
+    pragma: Option<CompactStr>,
+
+    /// Name of the function that creates legacy class components. Defaults to
+    /// `createReactClass`.
+    #[serde(rename = "createClass")]
+    create_class: Option<CompactStr>,
+
+    /// Name of the fragment component, e.g. `Fragment` for `<React.Fragment>`.
+    /// Defaults to `Fragment`.
+    fragment: Option<CompactStr>,
     // TODO: More properties should be added
 }
 
@@ -27,6 +40,18 @@ impl ReactPluginSettings {
     pub fn get_link_component_attrs(&self, name: &str) -> Option<ComponentAttrs<'_>> {
         get_component_attrs_by_name(&self.link_components, name)
     }
+
+    pub fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React")
+    }
+
+    pub fn create_class(&self) -> &str {
+        self.create_class.as_deref().unwrap_or("createReactClass")
+    }
+
+    pub fn fragment(&self) -> &str {
+        self.fragment.as_deref().unwrap_or("Fragment")
+    }
 }
 
 // Deserialize helper types
@@ -0,0 +1,525 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{
+    oxlintrc::is_external_plugin,
+    plugins::LintPlugins,
+    rules::{parse_rule_key, parse_rule_value, transform_rule_and_plugin_name},
+    Oxlintrc,
+};
+use crate::{rules::RULES, RuleEnum};
+
+/// Check a config file for problems that otherwise surface as a vague parse
+/// error, or not at all:
+///
+/// * Invalid JSON, and values of the wrong type.
+/// * Rules that don't exist, or belong to a plugin that is not enabled.
+/// * Rule options that the rule can't deserialize. Only rules that declare a
+///   type for their options are checked.
+/// * Rule options the rule would ignore, such as invalid selectors.
+/// * External plugins that don't exist. Plugins are not loaded, so the names
+///   of their rules are not checked.
+///
+/// `source_text` is the content of the file at `path`. Diagnostics are labeled
+/// with spans into it. `resolve_plugins` turns plugins on or off on top of the
+/// config's `plugins`, e.g. with the CLI's `--jest-plugin`, so rules are
+/// checked against the plugins they run with.
+pub fn validate_config<F: FnOnce(&mut LintPlugins)>(
+    path: &Path,
+    source_text: &str,
+    resolve_plugins: F,
+) -> Vec<OxcDiagnostic> {
+    // Comments are replaced with whitespace, so offsets stay the same.
+    let mut text = source_text.to_string();
+    if let Err(err) = json_strip_comments::strip(&mut text) {
+        return vec![OxcDiagnostic::error(format!("Failed to parse jsonc file: {err}"))];
+    }
+    let json = match serde_json::from_str::<Value>(&text) {
+        Ok(json) => json,
+        Err(err) => {
+            let offset = line_column_offset(&text, err.line(), err.column());
+            return vec![OxcDiagnostic::error(format!("Invalid JSON: {err}"))
+                .with_label(Span::new(offset, offset))];
+        }
+    };
+
+    let mut validator = Validator {
+        locations: JsonLocations::new(&text),
+        plugins: LintPlugins::default(),
+        has_external_plugins: false,
+        diagnostics: vec![],
+    };
+    validator.plugins(path, &json);
+    resolve_plugins(&mut validator.plugins);
+    if let Some(rules) = json.get("rules") {
+        validator.rules("/rules", rules);
+    }
+    if let Some(overrides) = json.get("overrides").and_then(Value::as_array) {
+        for (index, r#override) in overrides.iter().enumerate() {
+            validator.files(&format!("/overrides/{index}/files"), r#override.get("files"));
+            if let Some(rules) = r#override.get("rules") {
+                validator.rules(&format!("/overrides/{index}/rules"), rules);
+            }
+        }
+    }
+
+    // Anything else, e.g. unknown categories or malformed `env`.
+    if validator.diagnostics.is_empty() {
+        if let Err(err) = Oxlintrc::deserialize(&json) {
+            validator.diagnostics.push(OxcDiagnostic::error(format!("Invalid config: {err}")));
+        }
+    }
+    validator.diagnostics
+}
+
+struct Validator {
+    locations: JsonLocations,
+    plugins: LintPlugins,
+    /// Whether `plugins` lists external plugins, which may provide rules of
+    /// any other plugin name.
+    has_external_plugins: bool,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl Validator {
+    fn report(&mut self, message: String, span: Span, help: Option<String>) {
+        let mut diagnostic = OxcDiagnostic::error(message).with_label(span);
+        if let Some(help) = help {
+            diagnostic = diagnostic.with_help(help);
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn plugins(&mut self, path: &Path, json: &Value) {
+        let Some(plugins) = json.get("plugins") else {
+            return;
+        };
+        match LintPlugins::deserialize(plugins) {
+            Ok(plugins) => self.plugins = plugins,
+            Err(err) => {
+                let span = self.locations.value("/plugins");
+                self.report(format!("Invalid plugins: {err}"), span, None);
+                return;
+            }
+        }
+
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for (index, plugin) in plugins.as_array().into_iter().flatten().enumerate() {
+            let Some(plugin) = plugin.as_str().filter(|plugin| is_external_plugin(plugin)) else {
+                continue;
+            };
+            self.has_external_plugins = true;
+            let plugin_path = dir.join(plugin);
+            if !plugin_path.is_file() {
+                let span = self.locations.value(&format!("/plugins/{index}"));
+                self.report(
+                    format!("Plugin `{plugin}` not found"),
+                    span,
+                    Some(format!(
+                        "Plugins are resolved relative to the config file, looked for {}",
+                        plugin_path.display()
+                    )),
+                );
+            }
+        }
+    }
+
+    fn files(&mut self, pointer: &str, files: Option<&Value>) {
+        for (index, pattern) in files.and_then(Value::as_array).into_iter().flatten().enumerate() {
+            let Some(pattern) = pattern.as_str() else { continue };
+            if let Err(err) = globset::Glob::new(pattern) {
+                let span = self.locations.value(&format!("{pointer}/{index}"));
+                self.report(format!("Invalid glob pattern: {}", err.kind()), span, None);
+            }
+        }
+    }
+
+    fn rules(&mut self, pointer: &str, rules: &Value) {
+        let Some(rules) = rules.as_object() else {
+            let span = self.locations.value(pointer);
+            self.report("`rules` must be an object".to_string(), span, None);
+            return;
+        };
+        for (key, value) in rules {
+            let pointer = format!("{pointer}/{}", escape_pointer(key));
+            let Ok((_, options)) = parse_rule_value(value) else {
+                let span = self.locations.value(&pointer);
+                self.report(
+                    format!("Invalid configuration for rule `{key}`"),
+                    span,
+                    Some("Expected a severity (\"off\", \"warn\", \"error\", 0, 1 or 2), or an array of a severity followed by the rule's options".to_string()),
+                );
+                continue;
+            };
+
+            let (plugin_name, rule_name) = parse_rule_key(key);
+            let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+            let span = self.locations.key(&pointer);
+
+            if let Some(rule) = RULES
+                .iter()
+                .find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+            {
+                let plugin = LintPlugins::from(plugin_name);
+                if !self.plugins.contains(plugin) {
+                    let plugin = <&str>::from(plugin);
+                    self.report(
+                        format!(
+                            "Rule `{key}` belongs to the `{plugin}` plugin, which is not enabled"
+                        ),
+                        span,
+                        Some(format!("Add \"{plugin}\" to `plugins`")),
+                    );
+                } else if let Some(options) = options {
+                    self.options(key, rule, &options, &pointer);
+                }
+                continue;
+            }
+
+            let is_builtin_plugin = RULES.iter().any(|rule| rule.plugin_name() == plugin_name);
+            if self.has_external_plugins && !is_builtin_plugin {
+                continue;
+            }
+
+            let suggestion = RULES
+                .iter()
+                .find(|rule| rule.name() == rule_name)
+                .map(|rule| format!("Did you mean `{}`?", full_name(rule)));
+            self.report(format!("Unknown rule `{key}`"), span, suggestion);
+        }
+    }
+
+    /// Check the options of `rule`. ESLint passes the options after the
+    /// severity as an array, and rules deserialize the first element.
+    fn options(&mut self, key: &str, rule: &RuleEnum, options: &Value, pointer: &str) {
+        for (index, message) in rule.validate_json(options) {
+            let span = self.locations.value(&format!("{pointer}/{}", index + 1));
+            self.report(format!("Invalid options for rule `{key}`: {message}"), span, None);
+        }
+        if let Some(Err(message)) = options.get(0).map(|options| rule.check_options(options)) {
+            let span = self.locations.value(&format!("{pointer}/1"));
+            self.report(format!("Invalid options for rule `{key}`: {message}"), span, None);
+        }
+    }
+}
+
+/// Name of `rule` as it is written in config files.
+fn full_name(rule: &RuleEnum) -> String {
+    match rule.plugin_name() {
+        "eslint" => rule.name().to_string(),
+        plugin => format!("{plugin}/{}", rule.name()),
+    }
+}
+
+/// Escape `key` for use in a JSON pointer.
+fn escape_pointer(key: &str) -> String {
+    let mut escaped = String::with_capacity(key.len());
+    for c in key.chars() {
+        match c {
+            '~' => escaped.push_str("~0"),
+            '/' => escaped.push_str("~1"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Offset of a 1-based line and column, as reported by `serde_json`.
+#[allow(clippy::cast_possible_truncation)]
+fn line_column_offset(text: &str, line: usize, column: usize) -> u32 {
+    let line_start: usize =
+        text.split_inclusive('\n').take(line.saturating_sub(1)).map(str::len).sum();
+    (line_start + column.saturating_sub(1)).min(text.len()) as u32
+}
+
+/// Spans of the keys and values in a JSON document, by JSON pointer.
+struct JsonLocations {
+    /// Span of the key, if the value is in an object, and span of the value.
+    spans: FxHashMap<String, (Option<Span>, Span)>,
+}
+
+impl JsonLocations {
+    /// `text` must be valid JSON.
+    fn new(text: &str) -> Self {
+        let mut scanner = JsonScanner { text, pos: 0, spans: FxHashMap::default() };
+        scanner.value(String::new(), None);
+        Self { spans: scanner.spans }
+    }
+
+    /// Span of the key of the value at `pointer`, or of the value if it has no
+    /// key.
+    fn key(&self, pointer: &str) -> Span {
+        self.spans.get(pointer).map_or(Span::default(), |(key, value)| key.unwrap_or(*value))
+    }
+
+    fn value(&self, pointer: &str) -> Span {
+        self.spans.get(pointer).map_or(Span::default(), |(_, value)| *value)
+    }
+}
+
+struct JsonScanner<'t> {
+    text: &'t str,
+    pos: usize,
+    spans: FxHashMap<String, (Option<Span>, Span)>,
+}
+
+impl<'t> JsonScanner<'t> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip `byte` if it is next.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.pos += 1;
+        }
+        matches
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize) -> Span {
+        Span::new(start as u32, self.pos as u32)
+    }
+
+    fn value(&mut self, pointer: String, key: Option<Span>) {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek() {
+            Some(b'{') => {
+                self.pos += 1;
+                while !self.eat(b'}') && self.peek().is_some() {
+                    let key_start = self.pos;
+                    let name = self.string();
+                    let key = self.span(key_start);
+                    self.eat(b':');
+                    self.value(format!("{pointer}/{}", escape_pointer(&name)), Some(key));
+                    self.eat(b',');
+                }
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let mut index = 0;
+                while !self.eat(b']') && self.peek().is_some() {
+                    self.value(format!("{pointer}/{index}"), None);
+                    index += 1;
+                    self.eat(b',');
+                }
+            }
+            Some(b'"') => {
+                self.string();
+            }
+            _ => {
+                while self.peek().is_some_and(|byte| {
+                    !matches!(byte, b',' | b'}' | b']') && !byte.is_ascii_whitespace()
+                }) {
+                    self.pos += 1;
+                }
+            }
+        }
+        let span = self.span(start);
+        self.spans.insert(pointer, (key, span));
+    }
+
+    /// Read a string starting at the current position.
+    fn string(&mut self) -> String {
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(byte) = self.peek() {
+            self.pos += if byte == b'\\' { 2 } else { 1 };
+            if byte == b'"' {
+                break;
+            }
+        }
+        let end = self.pos.min(self.text.len());
+        serde_json::from_str(self.text.get(start..end).unwrap_or_default()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_span::Span;
+
+    use super::{validate_config, JsonLocations};
+    use crate::LintPlugins;
+
+    fn validate(source_text: &str) -> Vec<(String, String)> {
+        validate_with_plugins(source_text, |_| {})
+    }
+
+    fn validate_with_plugins<F: FnOnce(&mut LintPlugins)>(
+        source_text: &str,
+        resolve_plugins: F,
+    ) -> Vec<(String, String)> {
+        validate_config(Path::new("fixtures/.oxlintrc.json"), source_text, resolve_plugins)
+            .into_iter()
+            .map(|diagnostic| {
+                let span = diagnostic.labels.as_ref().and_then(|labels| labels.first()).map_or(
+                    Span::default(),
+                    |label| {
+                        #[allow(clippy::cast_possible_truncation)]
+                        let start = label.offset() as u32;
+                        #[allow(clippy::cast_possible_truncation)]
+                        Span::new(start, start + label.len() as u32)
+                    },
+                );
+                (diagnostic.message.to_string(), span.source_text(source_text).to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_json_locations() {
+        let text = r#"{ "a": [1, { "b/c": "d" }], "e": null }"#;
+        let locations = JsonLocations::new(text);
+        let source = |span: Span| span.source_text(text);
+        assert_eq!(source(locations.value("")), text);
+        assert_eq!(source(locations.key("/a")), r#""a""#);
+        assert_eq!(source(locations.value("/a/0")), "1");
+        assert_eq!(source(locations.key("/a/1/b~1c")), r#""b/c""#);
+        assert_eq!(source(locations.value("/a/1/b~1c")), r#""d""#);
+        assert_eq!(source(locations.value("/e")), "null");
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let config = r#"{
+            // comments are allowed
+            "plugins": ["jest"],
+            "rules": { "no-console": ["error", { "allow": ["warn"] }], "jest/no-disabled-tests": "warn" },
+            "overrides": [{ "files": ["*.test.js"], "rules": { "eqeqeq": "off" } }]
+        }"#;
+        assert_eq!(validate(config), vec![]);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = r#"{
+            "rules": {
+                "no-consol": "error",
+                "console": "error",
+                "jest/no-disabled-tests": "warn",
+                "eqeqeq": "errr",
+                "no-console": ["error", { "allow": "warn" }],
+                "typescript/member-ordering": ["error", { "default": 1 }]
+            },
+            "overrides": [{ "files": ["[a"], "rules": { "acme/foo": "off" } }]
+        }"#;
+        assert_eq!(
+            validate(config),
+            vec![
+                ("Unknown rule `no-consol`".to_string(), r#""no-consol""#.to_string()),
+                ("Unknown rule `console`".to_string(), r#""console""#.to_string()),
+                (
+                    "Rule `jest/no-disabled-tests` belongs to the `jest` plugin, which is not enabled"
+                        .to_string(),
+                    r#""jest/no-disabled-tests""#.to_string()
+                ),
+                ("Invalid configuration for rule `eqeqeq`".to_string(), r#""errr""#.to_string()),
+                (
+                    "Invalid options for rule `no-console`: invalid type: string \"warn\", expected a sequence"
+                        .to_string(),
+                    r#"{ "allow": "warn" }"#.to_string()
+                ),
+                (
+                    "Invalid options for rule `typescript/member-ordering`: expected an array of member types, \"never\", or an object with `memberTypes` and `order`"
+                        .to_string(),
+                    r#"{ "default": 1 }"#.to_string()
+                ),
+                (
+                    "Invalid glob pattern: unclosed character class; missing ']'".to_string(),
+                    r#""[a""#.to_string()
+                ),
+                ("Unknown rule `acme/foo`".to_string(), r#""acme/foo""#.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolved_plugins() {
+        let config = r#"{ "rules": { "jest/no-disabled-tests": "warn" } }"#;
+        assert_eq!(validate(config).len(), 1);
+        assert_eq!(
+            validate_with_plugins(config, |plugins| plugins.set(LintPlugins::JEST, true)),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_external_plugins() {
+        // Plugins aren't loaded, so their rules can't be checked.
+        let config = r#"{
+            "plugins": ["./external/eslint-plugin-acme.js", "./external/missing.so"],
+            "rules": { "acme/no-foo-calls": "error", "eslint/no-such-rule": "error" },
+            "overrides": [{ "files": ["*.js"], "rules": { "acme/max-declarators": "off" } }]
+        }"#;
+        assert_eq!(
+            validate(config),
+            vec![
+                (
+                    "Plugin `./external/missing.so` not found".to_string(),
+                    r#""./external/missing.so""#.to_string()
+                ),
+                (
+                    "Unknown rule `eslint/no-such-rule`".to_string(),
+                    r#""eslint/no-such-rule""#.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_selector() {
+        let config = r#"{
+            "rules": {
+                "no-restricted-syntax": [
+                    "error",
+                    "WithStatement",
+                    "[",
+                    { "selector": "Program > :first-child", "message": "no" }
+                ]
+            }
+        }"#;
+        assert_eq!(
+            validate(config),
+            vec![
+                (
+                    "Invalid options for rule `no-restricted-syntax`: Unexpected end of selector `[`"
+                        .to_string(),
+                    r#""[""#.to_string()
+                ),
+                (
+                    "Invalid options for rule `no-restricted-syntax`: Unexpected `:` at offset 10 of selector `Program > :first-child`"
+                        .to_string(),
+                    r#"{ "selector": "Program > :first-child", "message": "no" }"#.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let errors = validate(
+            "{\n  \"rules\": {\n    \"eqeqeq\": \"error\"\n    \"no-debugger\": \"error\"\n  }\n}",
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].0.starts_with("Invalid JSON: expected `,` or `}` at line 4"),
+            "{errors:?}"
+        );
+    }
+}
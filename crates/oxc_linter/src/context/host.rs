@@ -1,17 +1,25 @@
 use oxc_semantic::Semantic;
 use oxc_span::SourceType;
-use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
+use rustc_hash::FxHashMap;
+use std::{
+    any::{Any, TypeId},
+    cell::{OnceCell, RefCell},
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 
 use crate::{
-    config::{LintConfig, LintPlugins},
+    config::{apply_inline_config, LintConfig, LintPlugins},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{FixKind, Message},
     frameworks,
     options::LintOptions,
+    type_info::{FileTypeInfo, TypeInfoProvider},
     utils, FrameworkFlags, RuleWithSeverity,
 };
 
-use super::{plugin_name_to_prefix, LintContext};
+use super::{plugin_name_to_prefix, LintContext, ModuleKind};
 
 /// Stores shared information about a file being linted.
 ///
@@ -53,12 +61,24 @@ pub(crate) struct ContextHost<'a> {
     /// Path to the file being linted.
     pub(super) file_path: Box<Path>,
     /// Global linter configuration, such as globals to include and the target
-    /// environments, and other settings.
+    /// environments, and other settings. Includes environments and globals declared
+    /// by comments like `/* eslint-env node */` in the file.
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// Analyses shared between rules, keyed by their type. See
+    /// [`LintContext::get_or_compute`].
+    pub(super) analyses: RefCell<FxHashMap<TypeId, Rc<dyn Any>>>,
+    /// Whether the file is a CommonJS or an ES module. Defaults to what its
+    /// [`SourceType`] says.
+    module_kind: ModuleKind,
+    /// Source of type information, if the linter has one.
+    type_info_provider: Option<Arc<dyn TypeInfoProvider>>,
+    /// Type information for the file, requested from the provider on first
+    /// use. See [`LintContext::type_info`].
+    type_info: OnceCell<Option<Box<dyn FileTypeInfo>>>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -82,8 +102,10 @@ impl<'a> ContextHost<'a> {
         let disable_directives =
             DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
 
+        let config = apply_inline_config(config, semantic.source_text(), semantic.comments());
         let file_path = file_path.as_ref().to_path_buf().into_boxed_path();
         let plugins = config.plugins;
+        let module_kind = ModuleKind::from(semantic.source_type());
 
         Self {
             semantic,
@@ -94,16 +116,49 @@ impl<'a> ContextHost<'a> {
             config,
             frameworks: options.framework_hints,
             plugins,
+            analyses: RefCell::default(),
+            module_kind,
+            type_info_provider: None,
+            type_info: OnceCell::new(),
         }
         .sniff_for_frameworks()
     }
 
+    /// Set whether the file is a CommonJS or an ES module.
+    #[inline]
+    pub fn with_module_kind(mut self, module_kind: ModuleKind) -> Self {
+        self.module_kind = module_kind;
+        self
+    }
+
+    /// Set the source of type information for this file.
+    #[inline]
+    pub fn with_type_info_provider(mut self, provider: Option<Arc<dyn TypeInfoProvider>>) -> Self {
+        self.type_info_provider = provider;
+        self
+    }
+
+    /// Type information for the file, if a provider is set and has any.
+    pub(super) fn type_info(&self) -> Option<&dyn FileTypeInfo> {
+        self.type_info
+            .get_or_init(|| {
+                self.type_info_provider.as_ref().and_then(|provider| {
+                    provider.file(&self.file_path, self.semantic.source_text())
+                })
+            })
+            .as_deref()
+    }
+
     /// Set the linter configuration for this context.
     #[inline]
     #[allow(dead_code)] // will be used in up-stack PR
     pub fn with_config(mut self, config: &Arc<LintConfig>) -> Self {
         let plugins = config.plugins;
-        self.config = Arc::clone(config);
+        self.config = apply_inline_config(
+            Arc::clone(config),
+            self.semantic.source_text(),
+            self.semantic.comments(),
+        );
 
         if self.plugins != plugins {
             self.plugins = plugins;
@@ -128,6 +183,12 @@ impl<'a> ContextHost<'a> {
         &self.file_path
     }
 
+    /// Rules disabled by comment directives like `eslint-disable` in the file.
+    #[inline]
+    pub fn disable_directives(&self) -> &DisableDirectives<'a> {
+        &self.disable_directives
+    }
+
     /// The source type of the file being linted, e.g. JavaScript, TypeScript,
     /// CJS, ESM, etc.
     #[inline]
@@ -135,10 +196,22 @@ impl<'a> ContextHost<'a> {
         self.semantic.source_type()
     }
 
+    /// Whether the file being linted is a CommonJS or an ES module.
+    #[inline]
+    pub fn module_kind(&self) -> ModuleKind {
+        self.module_kind
+    }
+
+    /// Whether the file being linted is a TypeScript declaration file, e.g. `index.d.ts`.
+    #[inline]
+    pub fn is_declaration_file(&self) -> bool {
+        self.source_type().is_typescript_definition()
+    }
+
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
-    pub(super) fn push_diagnostic(&self, diagnostic: Message<'a>) {
+    pub(crate) fn push_diagnostic(&self, diagnostic: Message<'a>) {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
@@ -159,6 +232,7 @@ impl<'a> ContextHost<'a> {
         LintContext {
             parent: self,
             current_rule_name: rule_name,
+            current_rule_category: rule.category(),
             current_plugin_name: plugin_name,
             current_plugin_prefix: plugin_name_to_prefix(plugin_name),
             #[cfg(debug_assertions)]
@@ -173,6 +247,7 @@ impl<'a> ContextHost<'a> {
         LintContext {
             parent: Rc::clone(&self),
             current_rule_name: "",
+            current_rule_category: crate::RuleCategory::Correctness,
             current_plugin_name: "eslint",
             current_plugin_prefix: "eslint",
             #[cfg(debug_assertions)]
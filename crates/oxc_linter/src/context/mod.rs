@@ -1,12 +1,19 @@
 #![allow(rustdoc::private_intra_doc_links)] // useful for intellisense
 mod host;
+mod project;
 
-use std::{ops::Deref, path::Path, rc::Rc};
+use std::{
+    any::{Any, TypeId},
+    ops::Deref,
+    path::Path,
+    rc::Rc,
+};
 
+use oxc_ast::{ast::Program, AstKind, Comment};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
-use oxc_span::{GetSpan, Span};
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{GetSpan, SourceType, Span};
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
@@ -14,10 +21,14 @@ use crate::{
     disable_directives::DisableDirectives,
     fixer::{FixKind, Message, RuleFix, RuleFixer},
     javascript_globals::GLOBALS,
-    AllowWarnDeny, FrameworkFlags, OxlintEnv, OxlintGlobals, OxlintSettings,
+    rule::rule_docs_url,
+    type_info::FileTypeInfo,
+    AllowWarnDeny, FrameworkFlags, OxlintEnv, OxlintGlobals, OxlintSettings, RuleCategory,
+    Selector,
 };
 
 pub(crate) use host::ContextHost;
+pub use project::ProjectContext;
 
 #[derive(Clone)]
 #[must_use]
@@ -36,6 +47,8 @@ pub struct LintContext<'a> {
     current_plugin_prefix: &'static str,
     /// Kebab-cased name of the current rule being linted. Example: `no-unused-vars`, `no-undef`.
     current_rule_name: &'static str,
+    /// Category of the current rule, e.g. [`RuleCategory::Correctness`].
+    current_rule_category: RuleCategory,
     /// Capabilities of the current rule to fix issues. Indicates whether:
     /// - Rule cannot be auto-fixed [`RuleFixMeta::None`]
     /// - Rule needs an auto-fix to be written still [`RuleFixMeta::FixPending`]
@@ -55,6 +68,21 @@ pub struct LintContext<'a> {
     severity: Severity,
 }
 
+/// A per-file analysis that can be shared between rules with
+/// [`LintContext::get_or_compute`].
+///
+/// Analyses outlive the rule that computed them, so they cannot borrow from
+/// the AST. Store [`NodeId`]s or [`SymbolId`]s instead.
+///
+/// [`NodeId`]: oxc_semantic::NodeId
+/// [`SymbolId`]: oxc_semantic::SymbolId
+pub trait FileAnalysis: Sized + 'static {
+    /// Analyze the file being linted. Diagnostics reported here are attributed
+    /// to whichever rule happened to request the analysis first, so this
+    /// should not report any.
+    fn compute(ctx: &LintContext<'_>) -> Self;
+}
+
 impl<'a> Deref for LintContext<'a> {
     type Target = Semantic<'a>;
 
@@ -65,9 +93,6 @@ impl<'a> Deref for LintContext<'a> {
 }
 
 impl<'a> LintContext<'a> {
-    /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
-    const WEBSITE_BASE_URL: &'static str = "https://oxc.rs/docs/guide/usage/linter/rules";
-
     /// Set the plugin name for the current rule.
     pub fn with_plugin_name(mut self, plugin: &'static str) -> Self {
         self.current_plugin_name = plugin;
@@ -105,6 +130,21 @@ impl<'a> LintContext<'a> {
         &self.parent.semantic
     }
 
+    /// The [`Program`] being linted.
+    ///
+    /// Useful in [`Rule::run_once`], which is not given a node to start from.
+    ///
+    /// [`Rule::run_once`]: crate::rule::Rule::run_once
+    ///
+    /// # Panics
+    ///
+    /// If the [`Semantic`] this context was created from was built without a root node.
+    pub fn program(&self) -> &'a Program<'a> {
+        let root = self.nodes().root_node().expect("Semantic always has a Program root node");
+        let AstKind::Program(program) = root.kind() else { unreachable!() };
+        program
+    }
+
     /// Get the control flow graph for the current program.
     #[inline]
     pub fn cfg(&self) -> &ControlFlowGraph {
@@ -125,12 +165,132 @@ impl<'a> LintContext<'a> {
         span.source_text(self.semantic().source_text())
     }
 
+    /// Get an analysis of the file being linted, computing it if no other rule
+    /// has requested it yet.
+    ///
+    /// Analyses are computed at most once per file and shared between all
+    /// rules, so expensive work (e.g. finding all React components) is not
+    /// repeated for every rule that needs it.
+    pub fn get_or_compute<T: FileAnalysis>(&self) -> Rc<T> {
+        let key = TypeId::of::<T>();
+        let cached = self.parent.analyses.borrow().get(&key).map(Rc::clone);
+        if let Some(analysis) = cached.and_then(|analysis| analysis.downcast::<T>().ok()) {
+            return analysis;
+        }
+        // The cache is not borrowed while computing, so analyses may depend on other analyses.
+        let analysis = Rc::new(T::compute(self));
+        self.parent.analyses.borrow_mut().insert(key, Rc::clone(&analysis) as Rc<dyn Any>);
+        analysis
+    }
+
+    /// Type information for the file being linted, if the linter has a
+    /// [`TypeInfoProvider`] that knows about it.
+    ///
+    /// Rules must not depend on it, and should fall back to syntactic checks
+    /// when it is [`None`].
+    ///
+    /// [`TypeInfoProvider`]: crate::TypeInfoProvider
+    #[inline]
+    pub fn type_info(&self) -> Option<&dyn FileTypeInfo> {
+        self.parent.type_info()
+    }
+
+    /// Comments in the file being linted, sorted by position.
+    ///
+    /// Note that a [`Comment`]'s span does not include its `//` or `/* */`
+    /// delimiters. Use [`Comment::real_span`] to get the full span.
+    #[inline]
+    pub fn comments(&self) -> &[Comment] {
+        self.semantic().comments()
+    }
+
+    /// Find the comment covering `span`, if `span` is inside a comment.
+    /// Delimiters count as part of the comment.
+    pub fn comment_at(&self, span: Span) -> Option<&Comment> {
+        let comments = self.comments();
+        let index = comments.partition_point(|comment| comment.real_span_start() <= span.start);
+        let comment = comments.get(index.checked_sub(1)?)?;
+        (span.end <= comment.real_span_end()).then_some(comment)
+    }
+
+    /// Get the text of a comment, without its `//` or `/* */` delimiters.
+    pub fn comment_text(&self, comment: &Comment) -> &'a str {
+        self.source_range(comment.span)
+    }
+
+    /// Find the closest character before `span` that is not whitespace or part
+    /// of a comment, e.g. the `,` or `(` preceding an expression, and get its
+    /// [`Span`].
+    ///
+    /// Use [`LintContext::source_range`] to get the character itself.
+    pub fn find_token_before(&self, span: Span) -> Option<Span> {
+        let mut end = span.start;
+        loop {
+            let (index, c) = self.source_text()[..end as usize]
+                .char_indices()
+                .rev()
+                .find(|(_, c)| !c.is_whitespace())?;
+            let token = char_span(index, c);
+            match self.comment_at(token) {
+                Some(comment) => end = comment.real_span_start(),
+                None => return Some(token),
+            }
+        }
+    }
+
+    /// Find the closest character after `span` that is not whitespace or part
+    /// of a comment, e.g. the `,` or `;` following an expression, and get its
+    /// [`Span`].
+    pub fn find_token_after(&self, span: Span) -> Option<Span> {
+        let mut start = span.end as usize;
+        loop {
+            let (index, c) =
+                self.source_text()[start..].char_indices().find(|(_, c)| !c.is_whitespace())?;
+            let token = char_span(start + index, c);
+            match self.comment_at(token) {
+                Some(comment) => start = comment.real_span_end() as usize,
+                None => return Some(token),
+            }
+        }
+    }
+
+    /// Find the nodes matching an AST selector, e.g. `CallExpression[callee.name='useEffect']`,
+    /// in the order they appear in the AST. See [`Selector`] for the supported syntax.
+    ///
+    /// The selector is parsed on every call. Rules running a selector on every file should parse
+    /// it once with [`Selector::parse`], and use [`Selector::query`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `selector` is invalid or unsupported.
+    pub fn query(&self, selector: &str) -> Result<Vec<&AstNode<'a>>, OxcDiagnostic> {
+        let selector = Selector::parse(selector)?;
+        Ok(selector.query(self.semantic()).collect())
+    }
+
     /// Path to the file currently being linted.
     #[inline]
     pub fn file_path(&self) -> &Path {
         &self.parent.file_path
     }
 
+    /// Whether the file currently being linted is a CommonJS or an ES module. Unlike
+    /// [`SourceType::is_script`], this takes the `"type"` of the file's package into
+    /// account.
+    ///
+    /// [`SourceType::is_script`]: oxc_span::SourceType::is_script
+    #[inline]
+    pub fn module_kind(&self) -> ModuleKind {
+        self.parent.module_kind()
+    }
+
+    /// Whether the file currently being linted is a TypeScript declaration file, e.g.
+    /// `index.d.ts`.
+    #[inline]
+    pub fn is_declaration_file(&self) -> bool {
+        self.parent.is_declaration_file()
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -182,12 +342,8 @@ impl<'a> LintContext<'a> {
         message.error = message
             .error
             .with_error_code(self.current_plugin_prefix, self.current_rule_name)
-            .with_url(format!(
-                "{}/{}/{}.html",
-                Self::WEBSITE_BASE_URL,
-                self.current_plugin_name,
-                self.current_rule_name
-            ));
+            .with_url(rule_docs_url(self.current_plugin_name, self.current_rule_name))
+            .with_category(self.current_rule_category.as_str());
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
@@ -319,6 +475,26 @@ impl<'a> LintContext<'a> {
     }
 }
 
+/// Whether a file is a CommonJS or an ES module, see [`LintContext::module_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+    /// An ES module, which loads other modules with `import`.
+    Module,
+    /// A CommonJS module, which loads other modules with `require`, e.g. a `.cjs` file
+    /// or a `.js` file in a package with `"type": "commonjs"`.
+    CommonJs,
+}
+
+impl From<&SourceType> for ModuleKind {
+    fn from(source_type: &SourceType) -> Self {
+        if source_type.is_script() {
+            Self::CommonJs
+        } else {
+            Self::Module
+        }
+    }
+}
+
 /// Gets the prefixed plugin name, given the short plugin name.
 ///
 /// Example:
@@ -327,7 +503,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub(crate) fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
@@ -348,3 +524,130 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "node" => "eslint-plugin-node",
     "security" => "oxc-security",
 };
+
+#[allow(clippy::cast_possible_truncation)]
+fn char_span(offset: usize, c: char) -> Span {
+    Span::sized(offset as u32, c.len_utf8() as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::{Semantic, SemanticBuilder};
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::{FileAnalysis, LintContext};
+    use crate::{options::LintOptions, ContextHost};
+
+    fn build_semantic<'a>(allocator: &'a Allocator, source_text: &'a str) -> Rc<Semantic<'a>> {
+        let parser_ret = Parser::new(allocator, source_text, SourceType::default()).parse();
+        // The semantic borrows from the program, e.g. its comments, so it must outlive this function.
+        let program = allocator.alloc(parser_ret.program);
+        Rc::new(SemanticBuilder::new().with_cfg(true).build(program).semantic)
+    }
+
+    #[test]
+    fn test_get_or_compute() {
+        thread_local! {
+            static COMPUTE_COUNT: Cell<usize> = const { Cell::new(0) };
+        }
+
+        struct SymbolCount(usize);
+
+        impl FileAnalysis for SymbolCount {
+            fn compute(ctx: &LintContext<'_>) -> Self {
+                COMPUTE_COUNT.set(COMPUTE_COUNT.get() + 1);
+                Self(ctx.symbols().len())
+            }
+        }
+
+        let allocator = Allocator::default();
+        let semantic = build_semantic(&allocator, "let a, b;");
+        let host =
+            Rc::new(ContextHost::new("foo.js", semantic, LintOptions::default(), Arc::default()));
+
+        // analyses are shared between rules
+        let first = Rc::clone(&host).spawn_for_test();
+        let second = host.spawn_for_test();
+        assert_eq!(first.get_or_compute::<SymbolCount>().0, 2);
+        assert_eq!(second.get_or_compute::<SymbolCount>().0, 2);
+        assert_eq!(COMPUTE_COUNT.get(), 1);
+    }
+
+    #[test]
+    fn test_comment_at() {
+        let allocator = Allocator::default();
+        let source_text = "let a; // line\n/* block */ let b;";
+        let semantic = build_semantic(&allocator, source_text);
+        let ctx =
+            Rc::new(ContextHost::new("foo.js", semantic, LintOptions::default(), Arc::default()))
+                .spawn_for_test();
+
+        assert_eq!(ctx.comments().len(), 2);
+
+        let line = ctx.comment_at(Span::new(9, 13)).unwrap();
+        assert_eq!(ctx.comment_text(line), " line");
+        assert_eq!(ctx.comment_at(Span::new(7, 14)).map(|c| c.span), Some(line.span));
+
+        let block = ctx.comment_at(Span::new(15, 26)).unwrap();
+        assert_eq!(ctx.comment_text(block), " block ");
+
+        assert!(ctx.comment_at(Span::new(0, 6)).is_none());
+        assert!(ctx.comment_at(Span::new(27, 30)).is_none());
+        // spans extending past the end of a comment are not inside it
+        assert!(ctx.comment_at(Span::new(9, 16)).is_none());
+    }
+
+    #[test]
+    fn test_find_token() {
+        let allocator = Allocator::default();
+        let source_text = "foo(a, /* b */ b /* c */ ,\n  // d\n  c);";
+        let semantic = build_semantic(&allocator, source_text);
+        let ctx =
+            Rc::new(ContextHost::new("foo.js", semantic, LintOptions::default(), Arc::default()))
+                .spawn_for_test();
+        let find = |needle: &str| {
+            let start = u32::try_from(source_text.rfind(needle).unwrap()).unwrap();
+            Span::sized(start, 1)
+        };
+        let text = |span: Option<Span>| span.map(|span| ctx.source_range(span));
+
+        assert_eq!(text(ctx.find_token_before(find("a"))), Some("("));
+        assert_eq!(text(ctx.find_token_after(find("a"))), Some(","));
+        // comments are skipped
+        assert_eq!(text(ctx.find_token_before(find("b"))), Some(","));
+        assert_eq!(text(ctx.find_token_after(find("b"))), Some(","));
+        assert_eq!(ctx.find_token_after(find("b")), Some(find(",")));
+        assert_eq!(text(ctx.find_token_before(find("c"))), Some(","));
+        assert_eq!(text(ctx.find_token_after(find("c"))), Some(")"));
+
+        assert!(ctx.find_token_before(Span::sized(0, 3)).is_none());
+        assert!(ctx.find_token_after(find(";")).is_none());
+    }
+
+    #[test]
+    fn test_query() {
+        let allocator = Allocator::default();
+        let source_text = "useEffect(() => {}); useState(0); foo.useEffect();";
+        let semantic = build_semantic(&allocator, source_text);
+        let ctx =
+            Rc::new(ContextHost::new("foo.js", semantic, LintOptions::default(), Arc::default()))
+                .spawn_for_test();
+        let query = |selector: &str| {
+            ctx.query(selector)
+                .unwrap()
+                .into_iter()
+                .map(|node| ctx.source_range(node.kind().span()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(query("CallExpression[callee.name='useEffect']"), ["useEffect(() => {})"]);
+        // nodes are returned in source order
+        assert_eq!(query("CallExpression > Identifier"), ["useEffect", "useState"]);
+        assert!(query("WithStatement").is_empty());
+        assert!(ctx.query("CallExpression[").is_err());
+    }
+}
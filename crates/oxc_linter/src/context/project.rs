@@ -0,0 +1,65 @@
+use std::{cell::RefCell, path::Path};
+
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+
+use crate::{rule::rule_docs_url, service::ModuleGraph, RuleCategory, RuleWithSeverity};
+
+use super::plugin_name_to_prefix;
+
+/// Context for rules that need to see the whole project at once, such as
+/// `import/no-cycle` or detecting unused exports.
+///
+/// Project rules run after every file has been linted, and only when the
+/// linter is run with cross-module analysis enabled. Unlike [`LintContext`],
+/// there is no AST available; rules work on the [`ModuleGraph`] instead and
+/// report diagnostics against a specific file. Disable directives are not
+/// respected for these diagnostics.
+///
+/// [`LintContext`]: crate::LintContext
+pub struct ProjectContext<'g> {
+    graph: &'g ModuleGraph,
+    diagnostics: &'g RefCell<Vec<(Box<Path>, OxcDiagnostic)>>,
+    current_plugin_name: &'static str,
+    current_rule_name: &'static str,
+    current_rule_category: RuleCategory,
+    severity: Severity,
+}
+
+impl<'g> ProjectContext<'g> {
+    pub(crate) fn new(
+        graph: &'g ModuleGraph,
+        diagnostics: &'g RefCell<Vec<(Box<Path>, OxcDiagnostic)>>,
+        rule: &RuleWithSeverity,
+    ) -> Self {
+        Self {
+            graph,
+            diagnostics,
+            current_plugin_name: rule.plugin_name(),
+            current_rule_name: rule.name(),
+            current_rule_category: rule.category(),
+            severity: rule.severity.into(),
+        }
+    }
+
+    /// Module records of all visited files.
+    pub fn graph(&self) -> &'g ModuleGraph {
+        self.graph
+    }
+
+    /// Report a lint rule violation in the file at `path`. Labels are
+    /// relative to that file's source text.
+    ///
+    /// Diagnostics for files that are not being linted (see
+    /// [`ModuleGraph::is_linted`]) are discarded.
+    pub fn diagnostic(&self, path: &Path, diagnostic: OxcDiagnostic) {
+        let diagnostic = diagnostic
+            .with_error_code(
+                plugin_name_to_prefix(self.current_plugin_name),
+                self.current_rule_name,
+            )
+            .with_url(rule_docs_url(self.current_plugin_name, self.current_rule_name))
+            .with_category(self.current_rule_category.as_str())
+            .with_severity(self.severity);
+        self.diagnostics.borrow_mut().push((path.into(), diagnostic));
+    }
+}
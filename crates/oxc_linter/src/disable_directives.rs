@@ -28,7 +28,7 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
-    pub fn contains(&self, rule_name: &'static str, span: Span) -> bool {
+    pub fn contains(&self, rule_name: &str, span: Span) -> bool {
         self.intervals.find(span.start, span.end).any(|interval| {
             interval.val == DisabledRule::All
                 // Our rule name currently does not contain the prefix.
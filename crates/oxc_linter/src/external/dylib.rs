@@ -0,0 +1,149 @@
+use std::{
+    ffi::{c_char, CStr, CString},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    ExternalDiagnostic, ExternalFile, ExternalPlugin, LintResult, Manifest, PluginResponse,
+};
+
+/// Version of the C ABI shared library plugins are expected to implement.
+/// Bumped whenever the exported functions or their JSON payloads change in
+/// an incompatible way.
+pub const EXTERNAL_PLUGIN_ABI_VERSION: u32 = 1;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type ManifestFn = unsafe extern "C" fn() -> *const c_char;
+type LintFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+
+/// An [`ExternalPlugin`] loaded from a shared library (`.so`, `.dylib` or `.dll`).
+///
+/// The library must export these functions with the C calling convention.
+/// All strings are NUL-terminated UTF-8 JSON.
+///
+/// * `uint32_t oxlint_plugin_abi_version(void)` returns
+///   [`EXTERNAL_PLUGIN_ABI_VERSION`].
+/// * `const char *oxlint_plugin_manifest(void)` returns
+///   `{ "name": "acme", "rules": ["no-legacy-api"] }`. The string must stay
+///   valid for as long as the library is loaded.
+/// * `char *oxlint_plugin_lint(const char *request)` lints one file. The
+///   request is a serialized [`ExternalFile`]:
+///   `{ "path": "src/a.js", "sourceText": "...", "rules": [{ "name": "no-legacy-api", "options": null }] }`.
+///   It returns either `{ "diagnostics": [{ "rule": "no-legacy-api", "message": "...", "start": 0, "end": 3 }] }`
+///   or `{ "error": "..." }`. Diagnostics may also have a `help` message.
+/// * `void oxlint_plugin_free(char *response)` frees a string returned by
+///   `oxlint_plugin_lint`.
+///
+/// `oxlint_plugin_lint` is called from several threads at once. See
+/// `fixtures/external/dylib_plugin` for a minimal plugin.
+pub struct DylibPlugin {
+    path: PathBuf,
+    name: String,
+    rules: Vec<String>,
+    lint: LintFn,
+    free: FreeFn,
+    /// Keeps `lint` and `free` valid, so must outlive them.
+    _library: libloading::Library,
+}
+
+impl DylibPlugin {
+    /// Load the plugin at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if the library can't be loaded, doesn't export the
+    /// plugin functions, was built for another ABI version or has an invalid
+    /// manifest.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        // SAFETY: Loading a library runs its initializers. Plugins are code the
+        // user opted into running by listing them in their config.
+        let library = unsafe { libloading::Library::new(path) }.map_err(|err| err.to_string())?;
+
+        // SAFETY: The symbols are declared with the signatures documented above.
+        // The copied function pointers are only used while `library` is alive.
+        let (abi_version, manifest, lint, free) = unsafe {
+            (
+                *library
+                    .get::<AbiVersionFn>(b"oxlint_plugin_abi_version\0")
+                    .map_err(|err| err.to_string())?,
+                *library
+                    .get::<ManifestFn>(b"oxlint_plugin_manifest\0")
+                    .map_err(|err| err.to_string())?,
+                *library.get::<LintFn>(b"oxlint_plugin_lint\0").map_err(|err| err.to_string())?,
+                *library.get::<FreeFn>(b"oxlint_plugin_free\0").map_err(|err| err.to_string())?,
+            )
+        };
+
+        // SAFETY: Takes no arguments, see above.
+        let version = unsafe { abi_version() };
+        if version != EXTERNAL_PLUGIN_ABI_VERSION {
+            return Err(format!(
+                "plugin was built for ABI version {version}, expected {EXTERNAL_PLUGIN_ABI_VERSION}"
+            ));
+        }
+
+        // SAFETY: Takes no arguments, see above.
+        let manifest = unsafe { manifest() };
+        if manifest.is_null() {
+            return Err("plugin returned no manifest".to_string());
+        }
+        // SAFETY: The manifest is a NUL-terminated string that lives as long as the library.
+        let manifest = unsafe { CStr::from_ptr(manifest) };
+        let manifest = manifest.to_str().map_err(|err| err.to_string())?;
+        let Manifest { name, rules } =
+            serde_json::from_str(manifest).map_err(|err| format!("invalid manifest: {err}"))?;
+
+        Ok(Self { path: path.to_path_buf(), name, rules, lint, free, _library: library })
+    }
+
+    /// Path the plugin was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ExternalPlugin for DylibPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn rules(&self) -> &[String] {
+        &self.rules
+    }
+
+    fn lint(&self, file: &ExternalFile<'_>) -> Result<Vec<ExternalDiagnostic>, String> {
+        let request = serde_json::to_string(file).map_err(|err| err.to_string())?;
+        let request = CString::new(request).map_err(|err| err.to_string())?;
+
+        // SAFETY: `request` is a NUL-terminated string that outlives the call.
+        let response = unsafe { (self.lint)(request.as_ptr()) };
+        if response.is_null() {
+            return Err("plugin returned no response".to_string());
+        }
+        // SAFETY: `response` is a NUL-terminated string owned by the plugin until it is freed below.
+        let response_text = unsafe { CStr::from_ptr(response) };
+        let parsed = response_text.to_str().map_err(|err| err.to_string()).and_then(|response| {
+            serde_json::from_str::<PluginResponse<LintResult>>(response)
+                .map_err(|err| format!("invalid response: {err}"))
+        });
+        // SAFETY: `response` was returned by `oxlint_plugin_lint` and is not used afterwards.
+        unsafe { (self.free)(response) };
+
+        match parsed? {
+            PluginResponse::Ok(LintResult { diagnostics }) => Ok(diagnostics),
+            PluginResponse::Error { error } => Err(error),
+        }
+    }
+}
+
+impl fmt::Debug for DylibPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DylibPlugin")
+            .field("path", &self.path)
+            .field("name", &self.name)
+            .field("rules", &self.rules)
+            .finish_non_exhaustive()
+    }
+}
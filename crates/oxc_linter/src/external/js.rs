@@ -0,0 +1,295 @@
+use std::{
+    env, fmt,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::Mutex,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    ExternalDiagnostic, ExternalFile, ExternalPlugin, LintResult, Manifest, PluginResponse,
+};
+
+/// Runs the plugin's rules in Node.js. Kept in a separate file so it can be
+/// edited and linted as JavaScript.
+const JS_HOST: &str = include_str!("js_host.js");
+
+/// An [`ExternalPlugin`] running ESLint-format rules from a JS plugin
+/// (`.js`, `.cjs` or `.mjs`) in a Node.js subprocess.
+///
+/// This lets teams keep bespoke ESLint rules running while migrating to
+/// oxlint. The plugin module is imported as is, so it must export an ESLint
+/// plugin object: `{ meta: { name }, rules: { [name]: rule } }`. Its name is
+/// `meta.name` without the `eslint-plugin-` prefix, or the file name if
+/// there is no `meta.name`.
+///
+/// Only a subset of the ESLint rule API is available:
+/// * Listeners for node types, including `:exit` listeners. Selectors and
+///   code path events are ignored.
+/// * `context.report` with a `node` or `loc`, `message` or `messageId` and
+///   `data`. Fixes and suggestions are ignored.
+/// * `context.options`, `context.filename` and `context.sourceCode` with its
+///   `text`, `ast`, `lines`, `getText`, `getAllComments`, `getLocFromIndex`
+///   and `getIndexFromLoc`. There is no scope analysis, and no tokens.
+///
+/// Rules see the AST converted by [`Program::to_estree`], with TypeScript and
+/// JSX nodes shaped as in typescript-eslint. Every node has a `range`, `loc`
+/// and `parent`, and comments are available from `sourceCode.getAllComments`.
+///
+/// [`Program::to_estree`]: oxc_ast::ast::Program::to_estree
+///
+/// Node.js is started from `PATH`, or from the `OXLINT_NODE` environment
+/// variable if it is set. Each process lints one file at a time, so files
+/// linted in parallel are sent to a pool of processes, which grows up to
+/// the number of threads linting. A process that exits, e.g. because a rule
+/// called `process.exit`, is replaced.
+pub struct JsPlugin {
+    path: PathBuf,
+    name: String,
+    rules: Vec<String>,
+    /// Processes that are not linting a file.
+    idle_hosts: Mutex<Vec<JsHost>>,
+}
+
+/// A request to lint a file, sent to the JS host as a single line of JSON.
+#[derive(Serialize)]
+struct JsRequest<'a> {
+    #[serde(flatten)]
+    file: &'a ExternalFile<'a>,
+    ast: serde_json::Value,
+}
+
+impl JsPlugin {
+    /// Start a Node.js process and load the plugin at `path` in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if Node.js can't be started or the plugin can't be
+    /// imported.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let path = path.canonicalize().map_err(|err| err.to_string())?;
+        let (host, Manifest { name, rules }) = JsHost::start(&path)?;
+        Ok(Self { path, name, rules, idle_hosts: Mutex::new(vec![host]) })
+    }
+
+    /// Path the plugin was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn start_host_and_request(
+        &self,
+        request: &str,
+    ) -> Result<(JsHost, PluginResponse<LintResult>), String> {
+        let (mut host, _) = JsHost::start(&self.path)?;
+        let response = host.request(request)?;
+        Ok((host, response))
+    }
+}
+
+impl ExternalPlugin for JsPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn rules(&self) -> &[String] {
+        &self.rules
+    }
+
+    fn lint(&self, file: &ExternalFile<'_>) -> Result<Vec<ExternalDiagnostic>, String> {
+        let Some(ast) = file.program else {
+            return Err("no AST available".to_string());
+        };
+        let request = JsRequest { file, ast: ast.to_estree() };
+        let request = serde_json::to_string(&request).map_err(|err| err.to_string())?;
+        let idle_host = self.idle_hosts.lock().unwrap().pop();
+        let (mut host, response) = match idle_host {
+            Some(mut host) => match host.request(&request) {
+                Ok(response) => (host, response),
+                // The process exited while linting a previous file: retry on a new one.
+                Err(_) => self.start_host_and_request(&request)?,
+            },
+            None => self.start_host_and_request(&request)?,
+        };
+        if host.is_running() {
+            self.idle_hosts.lock().unwrap().push(host);
+        }
+        match response {
+            PluginResponse::Ok(LintResult { diagnostics }) => Ok(diagnostics),
+            PluginResponse::Error { error } => Err(error),
+        }
+    }
+}
+
+impl fmt::Debug for JsPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsPlugin")
+            .field("path", &self.path)
+            .field("name", &self.name)
+            .field("rules", &self.rules)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A Node.js process running [`JS_HOST`].
+struct JsHost {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl JsHost {
+    /// Start a Node.js process running the plugin at `path`, and wait for its manifest.
+    fn start(path: &Path) -> Result<(Self, Manifest), String> {
+        let node = env::var_os("OXLINT_NODE").unwrap_or_else(|| "node".into());
+        let mut child = Command::new(&node)
+            .arg("-e")
+            .arg(JS_HOST)
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to start {}: {err}", node.to_string_lossy()))?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err("failed to connect to node".to_string());
+        };
+        let mut host = Self { child, stdin, stdout: BufReader::new(stdout) };
+        match host.receive()? {
+            PluginResponse::Ok(manifest) => Ok((host, manifest)),
+            PluginResponse::Error { error } => Err(error),
+        }
+    }
+
+    fn request<T: DeserializeOwned>(&mut self, line: &str) -> Result<PluginResponse<T>, String> {
+        self.send(line)?;
+        self.receive()
+    }
+
+    fn is_running(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    fn send(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.stdin, "{line}")
+            .and_then(|()| self.stdin.flush())
+            .map_err(|err| format!("failed to send request to node: {err}"))
+    }
+
+    fn receive<T: DeserializeOwned>(&mut self) -> Result<PluginResponse<T>, String> {
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => Err("node exited unexpectedly".to_string()),
+            Ok(_) => serde_json::from_str(&line).map_err(|err| format!("invalid response: {err}")),
+            Err(err) => Err(format!("failed to read response from node: {err}")),
+        }
+    }
+}
+
+impl Drop for JsHost {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, process::Command, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::JsPlugin;
+    use crate::{
+        AllowWarnDeny, ExternalFile, ExternalPlugin, ExternalRuleConfig, LinterBuilder, Oxlintrc,
+    };
+
+    #[test]
+    fn test_js_plugin() {
+        if Command::new("node").arg("--version").output().is_err() {
+            return;
+        }
+        let path = Path::new("fixtures/external/eslint-plugin-acme.js");
+        let plugin = JsPlugin::load(path).unwrap();
+        assert_eq!(plugin.name(), "acme");
+        assert_eq!(plugin.rules(), ["no-foo-calls", "max-declarators", "max-params", "no-exit"]);
+
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": {
+                "acme/no-foo-calls": "error",
+                "acme/max-declarators": ["warn", { "max": 1 }],
+                "acme/max-params": "warn"
+            }
+        }))
+        .unwrap();
+        let plugin: Arc<dyn ExternalPlugin> = Arc::new(plugin);
+        let linter =
+            LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, vec![plugin])
+                .unwrap()
+                .build();
+
+        let source_text = "const s = 'ü';\nfoo(1);\nlet a, b = 'ü';\nfoo(2); // eslint-disable-line acme/no-foo-calls\nconst f = (x, { y }) => x;";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let mut messages = linter
+            .run(Path::new("test.mjs"), Rc::new(semantic))
+            .into_iter()
+            .map(|message| {
+                let span = message.span();
+                format!("{}: {}", span.source_text(source_text), message.error.message)
+            })
+            .collect::<Vec<_>>();
+        messages.sort_unstable();
+        assert_eq!(
+            messages,
+            [
+                "foo(1): Do not call foo with 1 argument(s).",
+                "let a, b = 'ü';: Declares 2 variables, at most 1 allowed.",
+                "{ y }: Too many parameters.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_js_plugin_restarts_node() {
+        if Command::new("node").arg("--version").output().is_err() {
+            return;
+        }
+        let path = Path::new("fixtures/external/eslint-plugin-acme.js");
+        let plugin = JsPlugin::load(path).unwrap();
+        let rules = [ExternalRuleConfig {
+            name: "no-exit".to_string(),
+            options: serde_json::Value::Null,
+            severity: AllowWarnDeny::Deny,
+        }];
+        let lint = |source_text: &str| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+            let file = ExternalFile {
+                path: Path::new("test.mjs"),
+                source_text,
+                rules: &rules,
+                program: Some(&ret.program),
+            };
+            plugin.lint(&file).map(|diagnostics| diagnostics.len())
+        };
+
+        assert_eq!(lint("exit();"), Err("node exited unexpectedly".to_string()));
+        assert_eq!(lint("stay();"), Ok(1));
+        assert_eq!(lint("stay();"), Ok(1));
+    }
+
+    #[test]
+    fn test_js_plugin_load_error() {
+        if Command::new("node").arg("--version").output().is_err() {
+            return;
+        }
+        let err = JsPlugin::load(Path::new("fixtures/external/broken.js")).unwrap_err();
+        assert!(err.contains("plugin failed to load"), "{err}");
+    }
+}
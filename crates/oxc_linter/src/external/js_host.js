@@ -0,0 +1,217 @@
+// Runs ESLint-format rules from a JS plugin on ASTs sent by oxlint.
+//
+// Started as `node -e <this script> <plugin path>`. Writes a manifest line,
+// `{ "name": ..., "rules": [...] }`, then answers each request line from
+// stdin with one response line. See `external/js.rs` for the protocol.
+'use strict';
+
+const { pathToFileURL } = require('node:url');
+const path = require('node:path');
+const readline = require('node:readline');
+
+// Rules may log. Keep stdout for responses.
+const stdout = process.stdout;
+console.log = console.info = console.debug = console.warn = console.error;
+
+function send(message) {
+  stdout.write(JSON.stringify(message) + '\n');
+}
+
+function errorMessage(err) {
+  return String((err && err.stack) || err);
+}
+
+function pluginName(plugin, file) {
+  const name = (plugin.meta && plugin.meta.name) || path.basename(file, path.extname(file));
+  if (name.startsWith('@')) {
+    return name.replace(/\/eslint-plugin(-.*)?$/, (_, rest) => (rest ? '/' + rest.slice(1) : ''));
+  }
+  return name.replace(/^eslint-plugin-/, '');
+}
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string';
+}
+
+function forEachChild(node, callback) {
+  for (const key of Object.keys(node)) {
+    if (key === 'parent' || key === 'loc' || key === 'range' || key === 'comments') continue;
+    const value = node[key];
+    if (Array.isArray(value)) {
+      for (const child of value) if (isNode(child)) callback(child);
+    } else if (isNode(value)) {
+      callback(value);
+    }
+  }
+}
+
+// Nodes have UTF-16 offsets like ESLint's, but oxlint expects UTF-8 byte
+// offsets in diagnostics.
+function utf8Offsets(text) {
+  const byteLength = Buffer.byteLength(text);
+  if (byteLength === text.length) return (offset) => offset;
+  const utf8 = new Uint32Array(text.length + 1);
+  let byte = 0;
+  for (let index = 0; index < text.length; index++) {
+    const codePoint = text.codePointAt(index);
+    const size = codePoint < 0x80 ? 1 : codePoint < 0x800 ? 2 : codePoint < 0x10000 ? 3 : 4;
+    utf8[index] = byte;
+    if (size === 4) utf8[++index] = byte;
+    byte += size;
+  }
+  utf8[text.length] = byteLength;
+  return (offset) => utf8[offset];
+}
+
+function lineStarts(text) {
+  const starts = [0];
+  for (const match of text.matchAll(/\r\n|[\r\n\u2028\u2029]/g)) {
+    starts.push(match.index + match[0].length);
+  }
+  return starts;
+}
+
+function createSourceCode(text, ast) {
+  const starts = lineStarts(text);
+  const lines = text.split(/\r\n|[\r\n\u2028\u2029]/);
+
+  function getLocFromIndex(index) {
+    let low = 0;
+    let high = starts.length - 1;
+    while (low < high) {
+      const mid = (low + high + 1) >> 1;
+      if (starts[mid] <= index) low = mid;
+      else high = mid - 1;
+    }
+    return { line: low + 1, column: index - starts[low] };
+  }
+
+  return {
+    text,
+    ast,
+    lines,
+    hasBOM: false,
+    getText(node, before = 0, after = 0) {
+      if (!node) return text;
+      return text.slice(Math.max(node.range[0] - before, 0), node.range[1] + after);
+    },
+    getLines: () => lines,
+    getAllComments: () => ast.comments || [],
+    getLocFromIndex,
+    getIndexFromLoc: (loc) => starts[loc.line - 1] + loc.column,
+  };
+}
+
+function setParents(node, parent) {
+  node.parent = parent;
+  forEachChild(node, (child) => setParents(child, node));
+}
+
+function walk(node, listeners) {
+  const enter = listeners.get(node.type);
+  if (enter) for (const listener of enter) listener(node);
+  forEachChild(node, (child) => walk(child, listeners));
+  const exit = listeners.get(node.type + ':exit');
+  if (exit) for (const listener of exit) listener(node);
+}
+
+function lint(rules, request) {
+  const { path: filename, sourceText: text, rules: configured, ast } = request;
+  const toUtf8 = utf8Offsets(text);
+  const sourceCode = createSourceCode(text, ast);
+  setParents(ast, null);
+
+  const diagnostics = [];
+  const listeners = new Map();
+  for (const { name, options } of configured) {
+    const rule = rules[name];
+    if (!rule) continue;
+    const create = typeof rule === 'function' ? rule : rule.create;
+    const messages = (rule.meta && rule.meta.messages) || {};
+
+    const report = (descriptor, ...rest) => {
+      // Legacy signature: report(node, [loc], message, data)
+      if (isNode(descriptor)) {
+        const [locOrMessage, ...others] = rest;
+        descriptor = typeof locOrMessage === 'string'
+          ? { node: descriptor, message: locOrMessage, data: others[0] }
+          : { node: descriptor, loc: locOrMessage, message: others[0], data: others[1] };
+      }
+      let message = descriptor.messageId ? messages[descriptor.messageId] : descriptor.message;
+      if (message === undefined) {
+        throw new Error(`Rule "${name}" reported unknown messageId "${descriptor.messageId}"`);
+      }
+      if (descriptor.data) {
+        message = message.replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (match, key) =>
+          key in descriptor.data ? String(descriptor.data[key]) : match);
+      }
+      let start;
+      let end;
+      if (descriptor.loc) {
+        const loc = descriptor.loc.start ? descriptor.loc : { start: descriptor.loc };
+        start = sourceCode.getIndexFromLoc(loc.start);
+        end = loc.end ? sourceCode.getIndexFromLoc(loc.end) : start;
+      } else {
+        [start, end] = descriptor.node.range;
+      }
+      diagnostics.push({ rule: name, message, start: toUtf8(start), end: toUtf8(end) });
+    };
+
+    const context = {
+      id: name,
+      options: Array.isArray(options) ? options : [],
+      settings: {},
+      parserOptions: {},
+      languageOptions: {},
+      filename,
+      physicalFilename: filename,
+      cwd: process.cwd(),
+      sourceCode,
+      getFilename: () => filename,
+      getPhysicalFilename: () => filename,
+      getCwd: () => process.cwd(),
+      getSourceCode: () => sourceCode,
+      report,
+    };
+
+    const handlers = create(context) || {};
+    for (const [selector, handler] of Object.entries(handlers)) {
+      // Only plain node types are supported, not esquery selectors or code path events.
+      for (const key of selector.split(',').map((part) => part.trim())) {
+        if (!/^[A-Z][A-Za-z]*(:exit)?$/.test(key)) continue;
+        if (!listeners.has(key)) listeners.set(key, []);
+        listeners.get(key).push(handler);
+      }
+    }
+  }
+
+  walk(ast, listeners);
+  return diagnostics;
+}
+
+async function main() {
+  const file = process.argv[1];
+  let plugin;
+  try {
+    const imported = await import(pathToFileURL(file).href);
+    plugin = imported.default || imported;
+  } catch (err) {
+    send({ error: errorMessage(err) });
+    return;
+  }
+  const rules = plugin.rules || {};
+  send({ name: pluginName(plugin, file), rules: Object.keys(rules) });
+
+  const lines = readline.createInterface({ input: process.stdin, crlfDelay: Infinity });
+  for await (const line of lines) {
+    let response;
+    try {
+      response = { diagnostics: lint(rules, JSON.parse(line)) };
+    } catch (err) {
+      response = { error: errorMessage(err) };
+    }
+    send(response);
+  }
+}
+
+main();
@@ -0,0 +1,475 @@
+//! Lint rules implemented outside of this crate.
+//!
+//! External plugins are listed in the `plugins` array of the config by path,
+//! next to built-in plugin names. Their rules are configured like any other
+//! rule, prefixed with the plugin's name:
+//!
+//! ```json
+//! {
+//!   "plugins": ["import", "./plugins/libacme_rules.so"],
+//!   "rules": { "acme/no-legacy-api": "error" }
+//! }
+//! ```
+//!
+//! Plugins get the path and source text of each file along with their
+//! configured rules, and report diagnostics as byte offsets into that source
+//! text. Shared libraries are loaded as a [`DylibPlugin`], and ESLint plugins
+//! written in JavaScript as a `JsPlugin` with the `js_plugins` feature.
+
+mod dylib;
+#[cfg(feature = "js_plugins")]
+mod js;
+
+use std::{ffi::OsStr, fmt, path::Path, sync::Arc};
+
+use oxc_ast::{ast::Program, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+
+pub use self::dylib::{DylibPlugin, EXTERNAL_PLUGIN_ABI_VERSION};
+#[cfg(feature = "js_plugins")]
+pub use self::js::JsPlugin;
+use crate::{config::ESLintRule, context::ContextHost, fixer::Message, AllowWarnDeny};
+
+/// A set of lint rules implemented outside of this crate.
+///
+/// Plugins loaded from shared libraries are wrapped in a [`DylibPlugin`].
+/// Other implementations can be passed to
+/// [`LinterBuilder::from_oxlintrc_with_external_plugins`].
+///
+/// Files are linted in parallel, so implementations must be safe to call
+/// from several threads at once.
+///
+/// [`LinterBuilder::from_oxlintrc_with_external_plugins`]: crate::LinterBuilder::from_oxlintrc_with_external_plugins
+pub trait ExternalPlugin: fmt::Debug + Send + Sync {
+    /// Name of the plugin, which prefixes its rules in the config, e.g.
+    /// `acme` for `acme/no-legacy-api`.
+    fn name(&self) -> &str;
+
+    /// Names of the rules this plugin provides, without the plugin prefix.
+    fn rules(&self) -> &[String];
+
+    /// Lint a single file with the rules configured for this plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if the plugin failed to lint the file. It is reported
+    /// as an error on that file.
+    fn lint(&self, file: &ExternalFile<'_>) -> Result<Vec<ExternalDiagnostic>, String>;
+}
+
+/// A file to lint with an [`ExternalPlugin`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalFile<'a> {
+    pub path: &'a Path,
+    pub source_text: &'a str,
+    /// Rules of the plugin that are turned on. Never empty.
+    pub rules: &'a [ExternalRuleConfig],
+    /// AST of the file, for in-process plugins. Not sent to shared libraries.
+    #[serde(skip)]
+    pub program: Option<&'a Program<'a>>,
+}
+
+/// A rule of an [`ExternalPlugin`] that is turned on in the config.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalRuleConfig {
+    /// Name of the rule, without the plugin prefix.
+    pub name: String,
+    /// Options after the severity, e.g. `[{ "max": 3 }]` for
+    /// `"acme/max-things": ["error", { "max": 3 }]`, or `null` if there are none.
+    pub options: serde_json::Value,
+    #[serde(skip)]
+    pub(crate) severity: AllowWarnDeny,
+}
+
+/// A problem reported by an [`ExternalPlugin`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExternalDiagnostic {
+    /// Name of the rule that reported the problem, without the plugin prefix.
+    pub rule: String,
+    pub message: String,
+    /// Byte offset into the source text where the problem starts.
+    pub start: u32,
+    /// Byte offset into the source text where the problem ends.
+    pub end: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+/// Name and rules of a plugin, sent by plugins that are not in-process.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    name: String,
+    rules: Vec<String>,
+}
+
+/// Diagnostics for a file, sent by plugins that are not in-process.
+#[derive(Debug, Deserialize)]
+struct LintResult {
+    diagnostics: Vec<ExternalDiagnostic>,
+}
+
+/// A message from a plugin that is not in-process.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PluginResponse<T> {
+    Error { error: String },
+    Ok(T),
+}
+
+/// Load the external plugin at `path`, as a `JsPlugin` if it is a
+/// JavaScript file and as a [`DylibPlugin`] otherwise.
+pub(crate) fn load(path: &Path) -> Result<Arc<dyn ExternalPlugin>, String> {
+    match path.extension().and_then(OsStr::to_str) {
+        #[cfg(feature = "js_plugins")]
+        Some("js" | "cjs" | "mjs") => Ok(Arc::new(js::JsPlugin::load(path)?)),
+        #[cfg(not(feature = "js_plugins"))]
+        Some("js" | "cjs" | "mjs") => {
+            Err("JavaScript plugins need oxc_linter's `js_plugins` feature".to_string())
+        }
+        _ => Ok(Arc::new(DylibPlugin::load(path)?)),
+    }
+}
+
+/// An [`ExternalPlugin`] with the rules the config turned on for it.
+#[derive(Debug, Clone)]
+pub(crate) struct ConfiguredExternalPlugin {
+    plugin: Arc<dyn ExternalPlugin>,
+    rules: Vec<ExternalRuleConfig>,
+}
+
+impl ConfiguredExternalPlugin {
+    pub fn new(plugin: Arc<dyn ExternalPlugin>) -> Self {
+        Self { plugin, rules: vec![] }
+    }
+
+    pub fn name(&self) -> &str {
+        self.plugin.name()
+    }
+
+    /// Whether `rule` is one of this plugin's rules.
+    pub fn provides(&self, rule: &ESLintRule) -> bool {
+        self.name() == rule.plugin_name
+            && self.plugin.rules().iter().any(|name| *name == rule.rule_name)
+    }
+
+    pub fn rules(&self) -> &[ExternalRuleConfig] {
+        &self.rules
+    }
+
+    pub fn configure(
+        &mut self,
+        name: String,
+        severity: AllowWarnDeny,
+        options: Option<serde_json::Value>,
+    ) {
+        self.rules.retain(|rule| rule.name != name);
+        if severity.is_warn_deny() {
+            let options = options.unwrap_or_default();
+            self.rules.push(ExternalRuleConfig { name, options, severity });
+        }
+    }
+
+    /// Run the plugin on the file in `ctx_host`, respecting disable directives.
+    pub fn run(&self, ctx_host: &ContextHost<'_>) {
+        let source_text = ctx_host.semantic().source_text();
+        let program = ctx_host.semantic().nodes().root_node().and_then(|node| match node.kind() {
+            AstKind::Program(program) => Some(program),
+            _ => None,
+        });
+        let file = ExternalFile {
+            path: ctx_host.file_path(),
+            source_text,
+            rules: self.rules.as_slice(),
+            program,
+        };
+        let name = self.plugin.name();
+
+        let diagnostics = match self.plugin.lint(&file) {
+            Ok(diagnostics) => diagnostics,
+            Err(err) => {
+                let error = OxcDiagnostic::error(format!(
+                    "External plugin `{name}` failed to lint this file: {err}"
+                ));
+                ctx_host.push_diagnostic(Message::new(error, None));
+                return;
+            }
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let len = source_text.len() as u32;
+        for diagnostic in diagnostics {
+            let Some(rule) = self.rules.iter().find(|rule| rule.name == diagnostic.rule) else {
+                continue;
+            };
+            // Widen offsets inside a multi-byte character to the whole character.
+            let start = floor_char_boundary(source_text, diagnostic.start.min(len));
+            let end = ceil_char_boundary(source_text, diagnostic.end.clamp(start, len));
+            let span = Span::new(start, end);
+            if ctx_host.disable_directives().contains(&rule.name, span) {
+                continue;
+            }
+            let mut error = OxcDiagnostic::warn(diagnostic.message)
+                .with_label(span)
+                .with_error_code(name.to_string(), rule.name.clone())
+                .with_severity(rule.severity.into());
+            if let Some(help) = diagnostic.help {
+                error = error.with_help(help);
+            }
+            ctx_host.push_diagnostic(Message::new(error, None));
+        }
+    }
+}
+
+fn floor_char_boundary(source_text: &str, mut offset: u32) -> u32 {
+    while !source_text.is_char_boundary(offset as usize) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn ceil_char_boundary(source_text: &str, mut offset: u32) -> u32 {
+    while !source_text.is_char_boundary(offset as usize) {
+        offset += 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        process::Command,
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+    use serde_json::json;
+
+    use super::{DylibPlugin, ExternalDiagnostic, ExternalFile, ExternalPlugin};
+    use crate::{Linter, LinterBuilder, LinterBuilderError, Oxlintrc};
+
+    /// Reports every call to `legacy` in the source text.
+    #[derive(Debug)]
+    struct AcmePlugin {
+        rules: Vec<String>,
+    }
+
+    impl ExternalPlugin for AcmePlugin {
+        fn name(&self) -> &str {
+            "acme"
+        }
+
+        fn rules(&self) -> &[String] {
+            &self.rules
+        }
+
+        fn lint(&self, file: &ExternalFile<'_>) -> Result<Vec<ExternalDiagnostic>, String> {
+            assert_eq!(file.rules.len(), 1);
+            assert_eq!(file.rules[0].options, json!([{ "ignore": "x" }]));
+            if file.source_text.contains("crash") {
+                return Err("boom".to_string());
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let mut diagnostics = file
+                .source_text
+                .match_indices("legacy(")
+                .map(|(start, text)| ExternalDiagnostic {
+                    rule: "no-legacy".to_string(),
+                    message: "Do not use legacy".to_string(),
+                    start: start as u32,
+                    end: (start + text.len()) as u32,
+                    help: None,
+                })
+                .collect::<Vec<_>>();
+            // Offsets inside the multi-byte `€`
+            #[allow(clippy::cast_possible_truncation)]
+            if let Some(start) = file.source_text.find('€') {
+                diagnostics.push(ExternalDiagnostic {
+                    rule: "no-legacy".to_string(),
+                    message: "Do not use €".to_string(),
+                    start: start as u32 + 1,
+                    end: start as u32 + 2,
+                    help: None,
+                });
+            }
+            Ok(diagnostics)
+        }
+    }
+
+    fn acme() -> Vec<Arc<dyn ExternalPlugin>> {
+        vec![Arc::new(AcmePlugin { rules: vec!["no-legacy".to_string(), "other".to_string()] })]
+    }
+
+    fn lint(config: serde_json::Value, source_text: &str) -> Vec<String> {
+        let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+        let linter = LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, acme())
+            .unwrap()
+            .build();
+        run(&linter, "test.mjs", source_text)
+    }
+
+    fn run(linter: &Linter, path: &str, source_text: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        linter
+            .run(Path::new(path), Rc::new(semantic))
+            .into_iter()
+            .map(|message| {
+                let code = message.error.code.to_string();
+                format!("{code} {}..{}", message.span().start, message.span().end)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_external_rules() {
+        let config = json!({ "rules": { "acme/no-legacy": ["error", { "ignore": "x" }] } });
+        assert_eq!(
+            lint(config.clone(), "legacy(); // eslint-disable-line acme/no-legacy\nlegacy();"),
+            vec!["acme(no-legacy) 48..55".to_string()]
+        );
+        assert_eq!(lint(config.clone(), "crash();"), vec![" 0..0".to_string()]);
+        assert_eq!(lint(config.clone(), "a = '€';"), vec!["acme(no-legacy) 5..8".to_string()]);
+
+        // Plugins with no rules turned on are not run.
+        assert!(lint(json!({ "rules": { "acme/no-legacy": "off" } }), "legacy();").is_empty());
+    }
+
+    #[test]
+    fn test_external_rules_in_overrides() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(json!({
+            "rules": { "acme/no-legacy": ["error", { "ignore": "x" }] },
+            "overrides": [
+                { "files": ["legacy/**"], "rules": { "acme/no-legacy": "off" } },
+                { "files": ["*.test.mjs"], "rules": { "acme/other": "off" } },
+            ]
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, acme())
+            .unwrap()
+            .build()
+            .with_timing(true);
+        assert_eq!(run(&linter, "src/a.mjs", "legacy();"), vec!["acme(no-legacy) 0..7"]);
+        assert!(run(&linter, "legacy/a.mjs", "legacy();").is_empty());
+        assert_eq!(run(&linter, "src/a.test.mjs", "legacy();"), vec!["acme(no-legacy) 0..7"]);
+
+        // The plugin didn't run on `legacy/a.mjs`, where all its rules are off.
+        let timings = linter.rule_timings().unwrap();
+        let timing = timings.iter().find(|timing| timing.plugin_name == "acme").unwrap();
+        assert_eq!(timing.rule_name, "*");
+        assert_eq!(timing.files, 2);
+
+        // Overrides may also turn external rules on.
+        let oxlintrc: Oxlintrc = serde_json::from_value(json!({
+            "overrides": [
+                { "files": ["src/**"], "rules": { "acme/no-legacy": ["warn", { "ignore": "x" }] } },
+            ]
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, acme())
+            .unwrap()
+            .build();
+        assert!(run(&linter, "a.mjs", "legacy();").is_empty());
+        assert_eq!(run(&linter, "src/a.mjs", "legacy();"), vec!["acme(no-legacy) 0..7"]);
+
+        let oxlintrc: Oxlintrc = serde_json::from_value(json!({
+            "overrides": [{ "files": ["src/**"], "rules": { "acme/no-such-rule": "warn" } }]
+        }))
+        .unwrap();
+        assert!(matches!(
+            LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, acme()),
+            Err(LinterBuilderError::UnknownRules { .. })
+        ));
+    }
+
+    #[test]
+    fn test_external_unknown_rules() {
+        let oxlintrc: Oxlintrc =
+            serde_json::from_value(json!({ "rules": { "acme/no-such-rule": "error" } })).unwrap();
+        let Err(LinterBuilderError::UnknownRules { rules }) =
+            LinterBuilder::from_oxlintrc_with_external_plugins(true, oxlintrc, acme())
+        else {
+            panic!("expected unknown rules error");
+        };
+        assert_eq!(rules[0].full_name(), "acme/no-such-rule");
+    }
+
+    #[test]
+    fn test_load_missing_dylib() {
+        let err = DylibPlugin::load(Path::new("./does-not-exist.so")).unwrap_err();
+        assert!(!err.is_empty());
+
+        let mut oxlintrc = Oxlintrc::default();
+        oxlintrc.external_plugins.push("./does-not-exist.so".into());
+        let Err(LinterBuilderError::ExternalPlugin { path, .. }) =
+            LinterBuilder::from_oxlintrc(true, oxlintrc)
+        else {
+            panic!("expected external plugin error");
+        };
+        assert_eq!(path, Path::new("./does-not-exist.so"));
+    }
+
+    /// Build the shared library in `fixtures/external/dylib_plugin` and return its path.
+    fn build_dylib_plugin() -> PathBuf {
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/external/dylib_plugin");
+        let target_dir = std::env::temp_dir().join("oxc_linter_dylib_plugin");
+        let status = Command::new(env!("CARGO"))
+            .arg("build")
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(fixture.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to build {}", fixture.display());
+        let file_name = format!(
+            "{}acme_dylib_plugin{}",
+            std::env::consts::DLL_PREFIX,
+            std::env::consts::DLL_SUFFIX
+        );
+        target_dir.join("debug").join(file_name)
+    }
+
+    #[test]
+    fn test_dylib_plugin() {
+        let plugin = DylibPlugin::load(&build_dylib_plugin()).unwrap();
+        assert_eq!(plugin.name(), "acme");
+        assert_eq!(plugin.rules(), ["no-legacy", "other"]);
+
+        let oxlintrc: Oxlintrc =
+            serde_json::from_value(json!({ "rules": { "acme/no-legacy": "error" } })).unwrap();
+        let linter = LinterBuilder::from_oxlintrc_with_external_plugins(
+            true,
+            oxlintrc,
+            vec![Arc::new(plugin)],
+        )
+        .unwrap()
+        .build();
+        assert_eq!(run(&linter, "a.mjs", "legacy();"), vec!["acme(no-legacy) 0..6"]);
+        assert!(run(&linter, "a.mjs", "modern();").is_empty());
+
+        // Errors and missing responses are reported on the file.
+        let messages = |source_text| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+            let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+            linter
+                .run(Path::new("a.mjs"), Rc::new(semantic))
+                .into_iter()
+                .map(|message| message.error.message.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(messages("crash();").len(), 1);
+        assert!(messages("crash();")[0].contains("boom"));
+        assert!(messages("silent();")[0].contains("plugin returned no response"));
+    }
+}
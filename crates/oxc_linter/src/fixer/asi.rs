@@ -0,0 +1,347 @@
+//! Checks that a fix doesn't change how automatic semicolon insertion (ASI)
+//! splits the code around it into statements.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, Program, Statement},
+    visit::walk,
+    Visit,
+};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::FxHashSet;
+
+use super::Fix;
+
+/// How a fix changes the code around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AsiHazard {
+    /// The fixed code doesn't parse, e.g. `if (a) { b() } c()` becoming
+    /// `if (a) b() c()`.
+    SyntaxError,
+    /// The fixed code is split into statements differently.
+    ChangedStatements,
+}
+
+/// Find out whether applying `fix` to `program` changes how the code around
+/// it is split into statements. The statements next to the fix are parsed
+/// again with and without it, and the fix is an ASI hazard if
+///
+/// 1. the fixed code doesn't parse,
+/// 2. a statement before, after or around the fix no longer begins or ends in
+///    the same place, e.g. `if (a) { b() }\n(c)` becoming `if (a) b()\n(c)`,
+///    which calls `b()(c)`, or
+/// 3. an argument-less `return`, `break` or `continue` is now followed by
+///    code which wasn't a statement of its own, e.g. `return (\n  a\n)`
+///    becoming `return \n  a\n`, which returns `undefined`.
+///
+/// When the statements next to the fix don't parse on their own, e.g. because
+/// they use `yield`, the whole fixed source text is parsed instead.
+pub(super) fn find_asi_hazard(program: &Program, fix: &Fix) -> Option<AsiHazard> {
+    let source_text = program.source_text;
+    let (start, end) = (fix.span.start as usize, fix.span.end as usize);
+    if source_text.get(..start).is_none() || source_text.get(end..).is_none() {
+        return Some(AsiHazard::SyntaxError);
+    }
+
+    let allocator = Allocator::default();
+    let (range, original) = surrounding_statements(program, fix.span)
+        .and_then(|range| {
+            let original = parse(&allocator, range.source_text(source_text), program.source_type)?;
+            Some((range, Statements::collect(original)))
+        })
+        .unwrap_or_else(|| {
+            #[allow(clippy::cast_possible_truncation)]
+            let range = Span::new(0, source_text.len() as u32);
+            (range, Statements::collect(program))
+        });
+
+    let fixed_text = format!(
+        "{}{}{}",
+        &source_text[range.start as usize..start],
+        fix.content,
+        &source_text[end..range.end as usize]
+    );
+    let Some(fixed) = parse(&allocator, &fixed_text, program.source_type) else {
+        return Some(AsiHazard::SyntaxError);
+    };
+    let fixed = Statements::collect(fixed);
+
+    let edit = Edit::new(
+        Span::new(fix.span.start - range.start, fix.span.end - range.start),
+        fix.content.len(),
+    );
+    let mut moved = original.spans.iter().filter_map(|&span| edit.map_statement(span));
+    let original_starts = original.spans.iter().map(|span| span.start).collect::<FxHashSet<_>>();
+    let changed = moved.any(|span| !fixed.spans.contains(&span))
+        || fixed.after_bare_exit.iter().any(|&start| {
+            edit.to_original(start).map_or(true, |start| !original_starts.contains(&start))
+        });
+    changed.then_some(AsiHazard::ChangedStatements)
+}
+
+/// Parse `source_text`, or [`None`] if it has errors. `return` is allowed
+/// outside of functions, since the text may be taken from a function body.
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Option<&'a Program<'a>> {
+    let options = ParseOptions { allow_return_outside_function: true, ..ParseOptions::default() };
+    let ret = Parser::new(allocator, source_text, source_type).with_options(options).parse();
+    if ret.panicked || !ret.errors.is_empty() {
+        return None;
+    }
+    Some(allocator.alloc(ret.program))
+}
+
+/// The statements before, around and after `span`, in the innermost list of
+/// statements which contains it. Statements in a list can't be joined with
+/// code outside of it, which ends with a `}` or a `case`.
+fn surrounding_statements(program: &Program, span: Span) -> Option<Span> {
+    let mut finder = SurroundingStatements { span, range: None };
+    finder.visit_program(program);
+    finder.range
+}
+
+struct SurroundingStatements {
+    span: Span,
+    range: Option<Span>,
+}
+
+impl SurroundingStatements {
+    fn overlaps(&self, span: Span) -> bool {
+        span.start <= self.span.end && self.span.start <= span.end
+    }
+}
+
+impl<'a> Visit<'a> for SurroundingStatements {
+    fn visit_statements(&mut self, it: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        let (Some(first), Some(last)) = (it.first(), it.last()) else {
+            return;
+        };
+        // Statements are sorted, so only those from `after` to `before` overlap `span`.
+        let after = it.partition_point(|stmt| stmt.span().end <= self.span.start);
+        let before = it.partition_point(|stmt| stmt.span().start < self.span.end);
+        if first.span().start <= self.span.start && self.span.end <= last.span().end {
+            let start = it[after.saturating_sub(1).min(it.len() - 1)].span().start;
+            let end = it[before.min(it.len() - 1)].span().end;
+            self.range = Some(Span::new(start, end));
+        }
+        for stmt in it.iter().take(before).skip(after) {
+            self.visit_statement(stmt);
+        }
+    }
+
+    // Nested lists of statements can only be inside of nodes overlapping `span`.
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if self.overlaps(it.span()) {
+            walk::walk_statement(self, it);
+        }
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if self.overlaps(it.span()) {
+            walk::walk_expression(self, it);
+        }
+    }
+}
+
+/// Maps positions in the original source text to the fixed one, and back.
+struct Edit {
+    start: u32,
+    original_end: u32,
+    fixed_end: u32,
+}
+
+impl Edit {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(span: Span, content_len: usize) -> Self {
+        Self {
+            start: span.start,
+            original_end: span.end,
+            fixed_end: span.start + content_len as u32,
+        }
+    }
+
+    /// Where a statement of the original source text must be in the fixed
+    /// one, or [`None`] if it overlaps with the edit.
+    fn map_statement(&self, span: Span) -> Option<Span> {
+        if span.end <= self.start {
+            Some(span)
+        } else if span.start >= self.original_end {
+            let offset = |pos: u32| pos - self.original_end + self.fixed_end;
+            Some(Span::new(offset(span.start), offset(span.end)))
+        } else if span.start < self.start && span.end > self.original_end {
+            Some(Span::new(span.start, span.end - self.original_end + self.fixed_end))
+        } else {
+            None
+        }
+    }
+
+    /// The position in the original source text for a position in the
+    /// fixed one, or [`None`] if it is inside the inserted text.
+    fn to_original(&self, pos: u32) -> Option<u32> {
+        if pos <= self.start {
+            Some(pos)
+        } else if pos >= self.fixed_end {
+            Some(pos - self.fixed_end + self.original_end)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Default)]
+struct Statements<'s> {
+    source_text: &'s str,
+    spans: FxHashSet<Span>,
+    /// Starts of statements following an argument-less `return`, `break` or
+    /// `continue` which is ended by ASI.
+    after_bare_exit: Vec<u32>,
+}
+
+impl<'s> Statements<'s> {
+    fn collect(program: &Program<'s>) -> Self {
+        let mut statements = Self { source_text: program.source_text, ..Self::default() };
+        statements.visit_program(program);
+        statements
+    }
+
+    fn ends_with_bare_exit(&self, statement: &Statement) -> bool {
+        let is_bare = match statement {
+            Statement::ReturnStatement(stmt) => stmt.argument.is_none(),
+            Statement::BreakStatement(stmt) => stmt.label.is_none(),
+            Statement::ContinueStatement(stmt) => stmt.label.is_none(),
+            _ => return last_body(statement).is_some_and(|body| self.ends_with_bare_exit(body)),
+        };
+        is_bare && !statement.span().source_text(self.source_text).ends_with(';')
+    }
+}
+
+/// The nested statement `statement` ends with, e.g. the `else` branch of an
+/// `if` statement.
+fn last_body<'s, 'a>(statement: &'s Statement<'a>) -> Option<&'s Statement<'a>> {
+    match statement {
+        Statement::IfStatement(stmt) => Some(stmt.alternate.as_ref().unwrap_or(&stmt.consequent)),
+        Statement::ForStatement(stmt) => Some(&stmt.body),
+        Statement::ForInStatement(stmt) => Some(&stmt.body),
+        Statement::ForOfStatement(stmt) => Some(&stmt.body),
+        Statement::WhileStatement(stmt) => Some(&stmt.body),
+        Statement::LabeledStatement(stmt) => Some(&stmt.body),
+        Statement::WithStatement(stmt) => Some(&stmt.body),
+        _ => None,
+    }
+}
+
+impl<'a> Visit<'a> for Statements<'_> {
+    fn visit_statements(&mut self, it: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        for pair in it.windows(2) {
+            if self.ends_with_bare_exit(&pair[0]) {
+                self.after_bare_exit.push(pair[1].span().start);
+            }
+        }
+        walk::walk_statements(self, it);
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        self.spans.insert(it.span());
+        walk::walk_statement(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{SourceType, Span};
+
+    use super::{find_asi_hazard, AsiHazard};
+    use crate::fixer::Fix;
+
+    /// Replace the first occurrence of `target` in `source_text` with `replacement`.
+    fn hazard(source_text: &str, target: &str, replacement: &str) -> Option<AsiHazard> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+        let start = u32::try_from(source_text.find(target).unwrap()).unwrap();
+        let end = start + u32::try_from(target.len()).unwrap();
+        find_asi_hazard(&program, &Fix::new(replacement, Span::new(start, end)))
+    }
+
+    #[test]
+    fn test_find_asi_hazard() {
+        // safe
+        assert_eq!(None, hazard("if (a) { b() }", "{ b() }", " b() "));
+        assert_eq!(None, hazard("if (a) { b() }\nc()", "{ b() }", " b() "));
+        assert_eq!(None, hazard("if (a) { b++ }\nc", "{ b++ }", " b++ "));
+        assert_eq!(None, hazard("if (a) { b() }\nelse c()", "{ b() }", " b() "));
+        assert_eq!(None, hazard("if (a) { for (;;) {} } c()", "{ for (;;) {} }", " for (;;) {} "));
+        assert_eq!(None, hazard("if (a) b()\n(c)", "b()\n(c)", "{b()\n(c)}"));
+        assert_eq!(
+            None,
+            hazard("function f() { if (x) { return a } else { b() } }", " else { b() }", "\nb()")
+        );
+        assert_eq!(
+            None,
+            hazard("function f() { if (a) { return }\nb() }", "{ return }", " return ")
+        );
+        assert_eq!(None, hazard("function f() { return (a) }", "(a)", "a"));
+        assert_eq!(None, hazard("let a = 1;\n[b] = c", "let", "const"));
+        assert_eq!(
+            None,
+            hazard("function f() {\n  a();\n  if (b) { c() }\n  d()\n}\n(e)", "{ c() }", " c() ")
+        );
+
+        // syntax errors
+        assert_eq!(Some(AsiHazard::SyntaxError), hazard("if (a) { b() } c()", "{ b() }", " b() "));
+        assert_eq!(
+            Some(AsiHazard::SyntaxError),
+            hazard("if (a) { b() } else c()", "{ b() }", " b() ")
+        );
+        assert_eq!(
+            Some(AsiHazard::SyntaxError),
+            hazard("do { b() } while (a)", "{ b() }", " b() ")
+        );
+        // the next line continues the statement
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("if (a) { b() }\n(c)", "{ b() }", " b() ")
+        );
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("if (a) { b() }\n[c]", "{ b() }", " b() ")
+        );
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("if (a) { b() }\n`c`", "{ b() }", " b() ")
+        );
+        assert_eq!(Some(AsiHazard::ChangedStatements), hazard("let a = 1;\n[b] = c", ";", ""));
+        assert_eq!(Some(AsiHazard::ChangedStatements), hazard("a;\n(b)", "a;", "a"));
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("class A { m() { if (a) { b() }\n[c] } }", "{ b() }", " b() ")
+        );
+        // a line break after `return`
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("function f() { return (\n  a\n) }", "(\n  a\n)", "\n  a\n")
+        );
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("function f() { return (\n  a\n); }", "(\n  a\n)", "\n  a\n")
+        );
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("for (;;) { break /* comment */ }", " /* comment */ ", "\na")
+        );
+        // statements which don't parse on their own
+        assert_eq!(
+            Some(AsiHazard::ChangedStatements),
+            hazard("function* f() { if (a) { yield b }\n(c) }", "{ yield b }", " yield b ")
+        );
+        assert_eq!(
+            None,
+            hazard("function* f() { if (a) { yield b }\nc() }", "{ yield b }", " yield b ")
+        );
+    }
+}
@@ -220,6 +220,19 @@ impl<'a> RuleFix<'a> {
         self
     }
 
+    /// Merge the fixes of this [`RuleFix`] into a single [`Fix`].
+    pub(super) fn normalize(self, source_text: &str) -> Self {
+        let fix = CompositeFix::Single(self.fix.normalize_fixes(source_text));
+        Self { fix, ..self }
+    }
+
+    /// Turn this fix into a suggestion. Dangerous fixes stay dangerous.
+    pub(super) fn into_suggestion(mut self) -> Self {
+        self.kind.remove(FixKind::Fix);
+        self.kind.insert(FixKind::Suggestion);
+        self
+    }
+
     #[inline]
     pub fn with_message<S: Into<Cow<'a, str>>>(mut self, message: S) -> Self {
         self.message = Some(message.into());
@@ -1,3 +1,4 @@
+mod asi;
 mod fix;
 
 use std::borrow::Cow;
@@ -8,6 +9,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
+use asi::AsiHazard;
 
 /// Produces [`RuleFix`] instances. Inspired by ESLint's [`RuleFixer`].
 ///
@@ -179,6 +181,32 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         self.new_fix(CompositeFix::Single(fix), message)
     }
 
+    /// Downgrade `fix` to a suggestion if applying it would change how the
+    /// code around it is split into statements by automatic semicolon
+    /// insertion. For example, removing the braces of `if (a) { b() }` is
+    /// safe, but not when the next line is `(c)`, which would then call
+    /// `b()(c)`. Fixes which produce code that doesn't parse, like
+    /// `if (a) b() c()`, are dropped.
+    ///
+    /// The statements next to the fix are parsed again, so only use this for
+    /// fixes which rewrite code at statement boundaries, when checking for
+    /// hazards by hand is impractical. Check the tokens around the fix first
+    /// where possible, since this runs for every diagnostic.
+    pub fn check_asi(&self, fix: RuleFix<'a>) -> RuleFix<'a> {
+        if fix.is_empty() || !fix.kind().contains(FixKind::Fix) {
+            return fix;
+        }
+        let fix = fix.normalize(self.source_text());
+        let CompositeFix::Single(edit) = &*fix else {
+            return fix;
+        };
+        match asi::find_asi_hazard(self.ctx.program(), edit) {
+            Some(AsiHazard::SyntaxError) => self.noop(),
+            Some(AsiHazard::ChangedStatements) => fix.into_suggestion(),
+            None => fix,
+        }
+    }
+
     #[allow(clippy::unused_self)]
     #[must_use]
     pub fn codegen(self) -> CodeGenerator<'a> {
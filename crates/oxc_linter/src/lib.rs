@@ -8,6 +8,7 @@ mod builder;
 mod config;
 mod context;
 mod disable_directives;
+mod external;
 mod fixer;
 mod frameworks;
 mod globals;
@@ -15,35 +16,61 @@ mod javascript_globals;
 mod options;
 mod rule;
 mod rules;
+mod selector;
 mod service;
+mod timing;
+mod type_info;
 mod utils;
 
 pub mod loader;
 pub mod table;
 
-use std::{io::Write, path::Path, rc::Rc, sync::Arc};
+use std::{
+    cell::{OnceCell, RefCell},
+    io::Write,
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+    time::Instant,
+};
 
 use config::LintConfig;
 use context::ContextHost;
 use options::LintOptions;
+use oxc_ast::AstType;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::{AstNode, Semantic};
 use utils::iter_possible_jest_call_node;
 
+#[cfg(feature = "js_plugins")]
+pub use crate::external::JsPlugin;
 pub use crate::{
     builder::{LinterBuilder, LinterBuilderError},
-    config::{ESLintRule, LintPlugins, Oxlintrc},
-    context::LintContext,
+    config::{
+        migrate_eslint_config, validate_config, ESLintRule, EslintConfigMigration, GlobSet,
+        LintPlugins, OxlintOverride, OxlintOverrides, Oxlintrc,
+    },
+    context::{FileAnalysis, LintContext, ModuleKind, ProjectContext},
+    external::{
+        DylibPlugin, ExternalDiagnostic, ExternalFile, ExternalPlugin, ExternalRuleConfig,
+        EXTERNAL_PLUGIN_ABI_VERSION,
+    },
     fixer::FixKind,
     frameworks::FrameworkFlags,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    selector::Selector,
+    service::{LintService, LintServiceOptions, ModuleGraph},
+    timing::RuleTiming,
+    type_info::{FileTypeInfo, TypeFacts, TypeInfoProvider},
 };
 use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    config::{OverrideResolver, OxlintEnv, OxlintGlobals, OxlintSettings},
+    external::ConfiguredExternalPlugin,
     fixer::{Fixer, Message},
     rules::RuleEnum,
     table::RuleTable,
+    timing::RuleTimings,
 };
 
 #[cfg(target_pointer_width = "64")]
@@ -60,6 +87,13 @@ pub struct Linter {
     rules: Vec<RuleWithSeverity>,
     options: LintOptions,
     config: Arc<LintConfig>,
+    /// Rules for files matching `overrides` in the config, if there are any.
+    overrides: Option<OverrideResolver>,
+    /// Plugins providing rules implemented outside of this crate.
+    external_plugins: Vec<ConfiguredExternalPlugin>,
+    timings: Option<Arc<RuleTimings>>,
+    /// Source of type information for type-aware rules.
+    type_info_provider: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 impl Default for Linter {
@@ -72,9 +106,19 @@ impl Linter {
     pub(crate) fn new(
         rules: Vec<RuleWithSeverity>,
         options: LintOptions,
-        config: LintConfig,
+        config: Arc<LintConfig>,
+        overrides: Option<OverrideResolver>,
+        external_plugins: Vec<ConfiguredExternalPlugin>,
     ) -> Self {
-        Self { rules, options, config: Arc::new(config) }
+        Self {
+            rules,
+            options,
+            config,
+            overrides,
+            external_plugins,
+            timings: None,
+            type_info_provider: None,
+        }
     }
 
     #[cfg(test)]
@@ -100,12 +144,38 @@ impl Linter {
         self
     }
 
+    /// Measure the time spent in each rule, see [`Linter::rule_timings`].
+    ///
+    /// Rules are run one after another over the whole file when timing is
+    /// enabled, which is slower for very large files.
+    #[must_use]
+    pub fn with_timing(mut self, yes: bool) -> Self {
+        self.timings = yes.then(|| Arc::new(RuleTimings::new(&self.rules)));
+        self
+    }
+
+    /// Use `provider` for type information in type-aware rules, see
+    /// [`LintContext::type_info`]. Without one, these rules only use
+    /// syntactic checks.
+    #[must_use]
+    pub fn with_type_info_provider(mut self, provider: Arc<dyn TypeInfoProvider>) -> Self {
+        self.type_info_provider = Some(provider);
+        self
+    }
+
+    /// Time spent in each rule across all files linted so far, slowest first.
+    /// Returns [`None`] unless timing was enabled with [`Linter::with_timing`].
+    pub fn rule_timings(&self) -> Option<Vec<RuleTiming>> {
+        self.timings.as_ref().map(|timings| timings.get())
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
 
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
+            + self.external_plugins.iter().map(|plugin| plugin.rules().len()).sum::<usize>()
     }
 
     #[cfg(test)]
@@ -114,11 +184,29 @@ impl Linter {
     }
 
     pub fn run<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> Vec<Message<'a>> {
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, self.options, Arc::clone(&self.config)));
+        let module_kind = ModuleKind::from(semantic.source_type());
+        self.run_with_module_kind(path, semantic, module_kind)
+    }
 
-        let rules = self
-            .rules
+    /// Like [`Linter::run`], for a file whose [`ModuleKind`] is not known from its
+    /// extension alone, e.g. a `.js` file in a package with `"type": "commonjs"`.
+    pub fn run_with_module_kind<'a>(
+        &self,
+        path: &Path,
+        semantic: Rc<Semantic<'a>>,
+        module_kind: ModuleKind,
+    ) -> Vec<Message<'a>> {
+        let overridden = self.overrides.as_ref().and_then(|o| o.resolve(&self.rules, path));
+        let config = overridden.as_ref().map_or(&self.config, |overridden| &overridden.config);
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, self.options, Arc::clone(config))
+                .with_module_kind(module_kind)
+                .with_type_info_provider(self.type_info_provider.clone()),
+        );
+
+        let rules = overridden
+            .as_ref()
+            .map_or(&self.rules[..], |overridden| &overridden.rules)
             .iter()
             .filter(|rule| rule.should_run(&ctx_host))
             .map(|rule| (rule, Rc::clone(&ctx_host).spawn(rule)));
@@ -128,6 +216,68 @@ impl Linter {
         let should_run_on_jest_node =
             self.config.plugins.has_test() && ctx_host.frameworks().is_test();
 
+        // Nodes bucketed by type, built on first use by a rule with `node_types`.
+        let nodes_by_type = OnceCell::new();
+
+        let run_rule = |rule: &RuleWithSeverity, ctx: &LintContext<'a>| {
+            rule.run_once(ctx);
+
+            for symbol in semantic.symbols().symbol_ids() {
+                rule.run_on_symbol(symbol, ctx);
+            }
+
+            match rule.node_types() {
+                None => {
+                    for node in semantic.nodes() {
+                        rule.run(node, ctx);
+                    }
+                }
+                Some(&[]) => {}
+                Some(&[ty]) => {
+                    let buckets = nodes_by_type.get_or_init(|| bucket_nodes_by_type(semantic));
+                    for node in &buckets[ty as usize] {
+                        rule.run(node, ctx);
+                    }
+                }
+                Some(types) => {
+                    let buckets = nodes_by_type.get_or_init(|| bucket_nodes_by_type(semantic));
+                    // Keep traversal order, which rules may rely on.
+                    let mut nodes =
+                        types.iter().flat_map(|ty| &buckets[*ty as usize]).collect::<Vec<_>>();
+                    nodes.sort_unstable_by_key(|node| node.id());
+                    for node in nodes {
+                        rule.run(node, ctx);
+                    }
+                }
+            }
+
+            if should_run_on_jest_node {
+                for jest_node in iter_possible_jest_call_node(semantic) {
+                    rule.run_on_jest_node(&jest_node, ctx);
+                }
+            }
+        };
+
+        let external_plugins = overridden
+            .as_ref()
+            .map_or(&self.external_plugins[..], |overridden| &overridden.external_plugins);
+        for plugin in external_plugins {
+            let start = Instant::now();
+            plugin.run(&ctx_host);
+            if let Some(timings) = &self.timings {
+                timings.add_external(plugin.name(), start.elapsed());
+            }
+        }
+
+        if let Some(timings) = &self.timings {
+            for (rule, ref ctx) in rules {
+                let start = Instant::now();
+                run_rule(rule, ctx);
+                timings.add(rule, start.elapsed());
+            }
+            return ctx_host.take_diagnostics();
+        }
+
         // IMPORTANT: We have two branches here for performance reasons:
         //
         // 1) Branch where we iterate over each node, then each rule
@@ -161,8 +311,11 @@ impl Linter {
             }
 
             for node in semantic.nodes() {
+                let ty = node.kind().ty();
                 for (rule, ctx) in &rules {
-                    rule.run(node, ctx);
+                    if rule.node_types().map_or(true, |types| types.contains(&ty)) {
+                        rule.run(node, ctx);
+                    }
                 }
             }
 
@@ -175,27 +328,31 @@ impl Linter {
             }
         } else {
             for (rule, ref ctx) in rules {
-                rule.run_once(ctx);
-
-                for symbol in semantic.symbols().symbol_ids() {
-                    rule.run_on_symbol(symbol, ctx);
-                }
-
-                for node in semantic.nodes() {
-                    rule.run(node, ctx);
-                }
-
-                if should_run_on_jest_node {
-                    for jest_node in iter_possible_jest_call_node(semantic) {
-                        rule.run_on_jest_node(&jest_node, ctx);
-                    }
-                }
+                run_rule(rule, ctx);
             }
         }
 
         ctx_host.take_diagnostics()
     }
 
+    /// Run project rules over the module graph built during a cross-module
+    /// lint run. Returns diagnostics paired with the file they belong to.
+    ///
+    /// Project rules see the whole graph at once, so `overrides` in the config
+    /// do not apply to them.
+    pub fn run_on_project(&self, graph: &ModuleGraph) -> Vec<(Box<Path>, OxcDiagnostic)> {
+        let diagnostics = RefCell::default();
+        for rule in &self.rules {
+            let ctx = ProjectContext::new(graph, &diagnostics, rule);
+            let start = Instant::now();
+            rule.run_on_project(&ctx);
+            if let Some(timings) = &self.timings {
+                timings.add_duration(rule, start.elapsed());
+            }
+        }
+        diagnostics.into_inner()
+    }
+
     /// # Panics
     pub fn print_rules<W: Write>(writer: &mut W) {
         let table = RuleTable::new();
@@ -207,9 +364,30 @@ impl Linter {
     }
 }
 
+/// Group the nodes of a file by [`AstType`], each bucket in traversal order.
+fn bucket_nodes_by_type<'s, 'a>(semantic: &'s Semantic<'a>) -> Vec<Vec<&'s AstNode<'a>>> {
+    let mut buckets = vec![vec![]; AstType::COUNT];
+    for node in semantic.nodes() {
+        buckets[node.kind().ty() as usize].push(node);
+    }
+    buckets
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Linter, Oxlintrc};
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstType;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{
+        bucket_nodes_by_type,
+        rules::{RuleEnum, RULES},
+        Linter, Oxlintrc,
+    };
 
     #[test]
     fn print_rules() {
@@ -218,6 +396,49 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn test_node_types() {
+        let node_types = |name: &str| {
+            RULES.iter().find(|rule| rule.name() == name).and_then(RuleEnum::node_types)
+        };
+        assert_eq!(node_types("no-debugger"), Some(&[AstType::DebuggerStatement][..]));
+        assert_eq!(node_types("no-unused-vars"), None);
+        // Only implements `run_once`
+        assert_eq!(node_types("no-unresolved"), Some(&[][..]));
+
+        let allocator = Allocator::default();
+        let source_text = "debugger; if (a) { debugger; } function f() { debugger; }";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let buckets = bucket_nodes_by_type(&semantic);
+
+        assert_eq!(buckets.iter().map(Vec::len).sum::<usize>(), semantic.nodes().len());
+        let debuggers = &buckets[AstType::DebuggerStatement as usize];
+        assert_eq!(debuggers.len(), 3);
+        assert!(debuggers.windows(2).all(|pair| pair[0].id() < pair[1].id()));
+        assert!(buckets[AstType::IfStatement as usize]
+            .iter()
+            .all(|node| node.kind().ty() == AstType::IfStatement));
+    }
+
+    #[test]
+    fn test_diagnostic_metadata() {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, "debugger;", SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let messages = Linter::default().run(Path::new("test.mjs"), Rc::new(semantic));
+
+        assert_eq!(messages.len(), 1);
+        let diagnostic = &messages[0].error;
+        assert_eq!(diagnostic.code.scope.as_deref(), Some("eslint"));
+        assert_eq!(diagnostic.code.number.as_deref(), Some("no-debugger"));
+        assert_eq!(diagnostic.category.as_deref(), Some("correctness"));
+        assert_eq!(
+            diagnostic.url.as_deref(),
+            Some("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html")
+        );
+    }
+
     #[test]
     fn test_schema_json() {
         use std::fs;
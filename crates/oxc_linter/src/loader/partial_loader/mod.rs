@@ -25,6 +25,34 @@ impl PartialLoader {
     }
 }
 
+/// Value of the `lang` attribute of a `<script>` tag, given the text between
+/// `<script` and the closing `>`. e.g. `ts` for `<script setup lang="ts">`.
+fn script_lang(attributes: &str) -> Option<&str> {
+    let mut rest = attributes;
+    while let Some(index) = rest.find("lang") {
+        let is_attribute_name = rest[..index].ends_with(|c: char| c.is_ascii_whitespace());
+        let after = rest[index + "lang".len()..].trim_start();
+        rest = &rest[index + "lang".len()..];
+        if !is_attribute_name {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next(),
+            _ => value.split(|c: char| c.is_ascii_whitespace() || c == '>').next(),
+        };
+        return value;
+    }
+    None
+}
+
+/// Whether a `lang` attribute value means the script is TypeScript.
+fn is_typescript_lang(lang: Option<&str>) -> bool {
+    matches!(lang, Some("ts" | "tsx" | "typescript"))
+}
+
 /// Find closing angle for situations where there is another `>` in between.
 /// e.g. `<script generic="T extends Record<string, string>">`
 fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize> {
@@ -45,3 +73,19 @@ fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize>
     }
     None
 }
+
+#[cfg(test)]
+mod test {
+    use super::script_lang;
+
+    #[test]
+    fn test_script_lang() {
+        assert_eq!(script_lang(r#" lang="ts""#), Some("ts"));
+        assert_eq!(script_lang(" setup lang='tsx'"), Some("tsx"));
+        assert_eq!(script_lang(" lang = ts setup"), Some("ts"));
+        assert_eq!(script_lang(r#" generic="T extends Foo" lang="ts""#), Some("ts"));
+        assert_eq!(script_lang(r#" data-lang="ts""#), None);
+        assert_eq!(script_lang(r#" language="ts""#), None);
+        assert_eq!(script_lang(" setup"), None);
+    }
+}
@@ -1,7 +1,7 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{find_script_closing_angle, SCRIPT_END, SCRIPT_START};
+use super::{find_script_closing_angle, is_typescript_lang, script_lang, SCRIPT_END, SCRIPT_START};
 use crate::loader::JavaScriptSource;
 
 pub struct SveltePartialLoader<'a> {
@@ -14,32 +14,45 @@ impl<'a> SveltePartialLoader<'a> {
     }
 
     pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
-        self.parse_script().map_or_else(Vec::new, |source| vec![source])
+        self.parse_scripts()
     }
 
-    fn parse_script(&self) -> Option<JavaScriptSource<'a>> {
+    /// Each *.svelte file can contain at most
+    ///  * one instance `<script>` block.
+    ///  * one `<script context="module">` (or `<script module>`) block.
+    /// <https://svelte.dev/docs/svelte-components#script-context-module>
+    fn parse_scripts(&self) -> Vec<JavaScriptSource<'a>> {
+        let mut pointer = 0;
+        let Some(result1) = self.parse_script(&mut pointer) else {
+            return vec![];
+        };
+        let Some(result2) = self.parse_script(&mut pointer) else {
+            return vec![result1];
+        };
+        vec![result1, result2]
+    }
+
+    fn parse_script(&self, pointer: &mut usize) -> Option<JavaScriptSource<'a>> {
         let script_start_finder = Finder::new(SCRIPT_START);
         let script_end_finder = Finder::new(SCRIPT_END);
 
-        let mut pointer = 0;
-
         // find opening "<script"
-        let offset = script_start_finder.find(self.source_text[pointer..].as_bytes())?;
-        pointer += offset + SCRIPT_START.len();
+        let offset = script_start_finder.find(self.source_text[*pointer..].as_bytes())?;
+        *pointer += offset + SCRIPT_START.len();
 
         // find closing ">"
-        let offset = find_script_closing_angle(self.source_text, pointer)?;
+        let offset = find_script_closing_angle(self.source_text, *pointer)?;
 
-        // get lang="ts" attribute
-        let content = &self.source_text[pointer..pointer + offset];
-        let is_ts = content.contains("ts");
+        // get ts from the `lang` attribute
+        let is_ts = is_typescript_lang(script_lang(&self.source_text[*pointer..*pointer + offset]));
 
-        pointer += offset + 1;
-        let js_start = pointer;
+        *pointer += offset + 1;
+        let js_start = *pointer;
 
         // find "</script>"
-        let offset = script_end_finder.find(self.source_text[pointer..].as_bytes())?;
-        let js_end = pointer + offset;
+        let offset = script_end_finder.find(self.source_text[*pointer..].as_bytes())?;
+        let js_end = *pointer + offset;
+        *pointer += offset + SCRIPT_END.len();
 
         let source_text = &self.source_text[js_start..js_end];
         let source_type = SourceType::mjs().with_typescript(is_ts);
@@ -84,4 +97,24 @@ mod test {
         let result = parse_svelte(source_text);
         assert_eq!(result.source_text.trim(), r#"console.log("hi");"#);
     }
+
+    #[test]
+    fn test_parse_svelte_module_script() {
+        let source_text = r#"
+        <script context="module" lang="ts">
+          export const prerender = true;
+        </script>
+        <script>
+          let count = 0;
+        </script>
+        <button on:click={() => count++}>{count}</button>
+        "#;
+
+        let sources = SveltePartialLoader::new(source_text).parse();
+        assert_eq!(sources.len(), 2);
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[0].source_text.trim(), "export const prerender = true;");
+        assert!(!sources[1].source_type.is_typescript());
+        assert_eq!(sources[1].source_text.trim(), "let count = 0;");
+    }
 }
@@ -1,7 +1,10 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{find_script_closing_angle, JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{
+    find_script_closing_angle, is_typescript_lang, script_lang, JavaScriptSource, SCRIPT_END,
+    SCRIPT_START,
+};
 
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
@@ -42,10 +45,10 @@ impl<'a> VuePartialLoader<'a> {
         // find closing ">"
         let offset = find_script_closing_angle(self.source_text, *pointer)?;
 
-        // get ts and jsx attribute
-        let content = &self.source_text[*pointer..*pointer + offset];
-        let is_ts = content.contains("ts");
-        let is_jsx = content.contains("tsx") || content.contains("jsx");
+        // get ts and jsx from the `lang` attribute
+        let lang = script_lang(&self.source_text[*pointer..*pointer + offset]);
+        let is_ts = is_typescript_lang(lang);
+        let is_jsx = matches!(lang, Some("tsx" | "jsx"));
 
         *pointer += offset + 1;
         let js_start = *pointer;
@@ -5,12 +5,13 @@ use std::{
     ops::Deref,
 };
 
+use oxc_ast::AstType;
 use oxc_semantic::SymbolId;
-use schemars::JsonSchema;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::{ContextHost, LintContext, ProjectContext},
     utils::PossibleJestNode,
     AllowWarnDeny, AstNode, FixKind, RuleEnum,
 };
@@ -21,7 +22,28 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Find problems in eslint json configuration that [`from_configuration`]
+    /// would skip over, such as malformed patterns. Returns the index of each
+    /// offending option with an error message.
+    ///
+    /// Building a [`Linter`](crate::Linter) from a config file fails on these
+    /// problems, and `oxlint --validate-config` reports them.
+    ///
+    /// [`from_configuration`]: Rule::from_configuration
+    fn validate_configuration(_value: &serde_json::Value) -> Vec<(usize, String)> {
+        vec![]
+    }
+
     /// Visit each AST Node
+    ///
+    /// Rules may implement any combination of [`run`], [`run_on_symbol`] and
+    /// [`run_once`]. Pick the one that visits the fewest items: e.g. a rule that
+    /// only checks declared variables should use [`run_on_symbol`] instead of
+    /// matching on every node.
+    ///
+    /// [`run`]: Rule::run
+    /// [`run_on_symbol`]: Rule::run_on_symbol
+    /// [`run_once`]: Rule::run_once
     #[expect(unused_variables)]
     #[inline]
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {}
@@ -31,7 +53,14 @@ pub trait Rule: Sized + Default + fmt::Debug {
     #[inline]
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {}
 
-    /// Run only once. Useful for inspecting scopes and trivias etc.
+    /// Run only once per file, after semantic analysis and before any other
+    /// visit. Useful for inspecting scopes, the module record, trivias etc.
+    ///
+    /// Use [`LintContext::program`] to get the root of the AST. Rules that only
+    /// implement this should declare `node_types = []` so [`run`] is never
+    /// dispatched for them.
+    ///
+    /// [`run`]: Rule::run
     #[expect(unused_variables)]
     #[inline]
     fn run_once(&self, ctx: &LintContext) {}
@@ -48,6 +77,15 @@ pub trait Rule: Sized + Default + fmt::Debug {
     ) {
     }
 
+    /// Run once on the whole project, after every file has been linted.
+    ///
+    /// Only called when cross-module analysis is enabled (e.g. by the import
+    /// plugin). Use this for rules that need to see how files relate to each
+    /// other, such as finding exports that are never imported.
+    #[expect(unused_variables)]
+    #[inline]
+    fn run_on_project(&self, ctx: &ProjectContext<'_>) {}
+
     /// Check if a rule should be run at all.
     ///
     /// You usually do not need to implement this function. If you do, use it to
@@ -70,9 +108,53 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// AST node types [`Rule::run`] is interested in. When set, `run` is only
+    /// called with nodes of these types; `None` means every node, and an empty
+    /// list means `run` is never called.
+    ///
+    /// Set with `node_types = [DebuggerStatement, ..]` in [`declare_oxc_lint!`].
+    ///
+    /// [`declare_oxc_lint!`]: oxc_macros::declare_oxc_lint
+    const NODE_TYPES: Option<&'static [AstType]> = None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
+
+    /// JSON schema for this rule's options, if it accepts any.
+    ///
+    /// Set with `config = MyRuleConfig` in [`declare_oxc_lint!`].
+    ///
+    /// [`declare_oxc_lint!`]: oxc_macros::declare_oxc_lint
+    #[expect(unused_variables)]
+    fn schema(gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
+
+    /// Check the rule's options, the first element after the severity, by
+    /// deserializing them into the type of its options.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserialization error.
+    ///
+    /// Set with `config = MyRuleConfig` in [`declare_oxc_lint!`], which must
+    /// also implement [`Deserialize`].
+    ///
+    /// [`declare_oxc_lint!`]: oxc_macros::declare_oxc_lint
+    #[expect(unused_variables)]
+    fn check_options(options: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Base URL for rule documentation pages.
+pub(crate) const RULE_DOCS_BASE_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
+
+/// URL of the documentation page for a rule, e.g.
+/// `https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-console.html`.
+pub(crate) fn rule_docs_url(plugin_name: &str, rule_name: &str) -> String {
+    format!("{RULE_DOCS_BASE_URL}/{plugin_name}/{rule_name}.html")
 }
 
 /// Rule categories defined by rust-clippy
@@ -96,6 +178,10 @@ pub enum RuleCategory {
     /// Lints should be considered on a case-by-case basis before enabling.
     Restriction,
     /// New lints that are still under development
+    ///
+    /// Nursery rules are off by default, and `all` does not include them. They run when enabled
+    /// with `--nursery`, `-W nursery`, the `nursery` category of the configuration file, or by name.
+    /// Once a rule is stable, it is promoted by moving it to another category.
     Nursery,
 }
 
@@ -304,6 +390,7 @@ impl RuleWithSeverity {
 #[cfg(test)]
 mod test {
     use markdown::{to_html_with_options, Options};
+    use schemars::gen::SchemaGenerator;
 
     use super::RuleCategory;
     use crate::rules::RULES;
@@ -325,6 +412,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_rule_metadata() {
+        let no_console = RULES.iter().find(|rule| rule.name() == "no-console").unwrap();
+        assert_eq!(
+            no_console.docs_url(),
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-console.html"
+        );
+        assert!(no_console.fix().has_fix());
+
+        let mut gen = SchemaGenerator::default();
+        assert!(no_console.schema(&mut gen).is_some());
+        let schema = serde_json::to_value(gen.definitions()).unwrap();
+        assert!(schema["NoConsoleConfig"]["properties"]["allow"].is_object(), "{schema}");
+
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        assert!(no_debugger.schema(&mut gen).is_none());
+    }
+
     #[test]
     fn test_deserialize_rule_category() {
         let tests = [
@@ -8,7 +8,6 @@
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
     // pub mod no_deprecated;
-    // pub mod no_unused_modules;
     pub mod default;
     pub mod export;
     pub mod max_dependencies;
@@ -23,12 +22,17 @@ mod import {
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
 }
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod brace_style;
+    pub mod complexity;
     pub mod constructor_super;
+    pub mod curly;
     pub mod default_case;
     pub mod default_case_last;
     pub mod default_param_last;
@@ -60,6 +64,7 @@ mod eslint {
     pub mod no_debugger;
     pub mod no_delete_var;
     pub mod no_div_regex;
+    pub mod no_dupe_args;
     pub mod no_dupe_class_members;
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
@@ -84,6 +89,7 @@ mod eslint {
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
     pub mod no_label_var;
+    pub mod no_loop_func;
     pub mod no_loss_of_precision;
     pub mod no_magic_numbers;
     pub mod no_multi_str;
@@ -94,16 +100,21 @@ mod eslint {
     pub mod no_nonoctal_decimal_escape;
     pub mod no_obj_calls;
     pub mod no_plusplus;
+    pub mod no_promise_executor_return;
     pub mod no_proto;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
+    pub mod no_return_await;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
@@ -121,6 +132,7 @@ mod eslint {
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
     pub mod no_useless_catch;
+    pub mod no_useless_computed_key;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
     pub mod no_useless_escape;
@@ -128,9 +140,11 @@ mod eslint {
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod object_shorthand;
     pub mod prefer_exponentiation_operator;
     pub mod prefer_numeric_literals;
     pub mod prefer_object_has_own;
+    pub mod prefer_regex_literals;
     pub mod radix;
     pub mod require_await;
     pub mod require_yield;
@@ -153,6 +167,7 @@ mod typescript {
     pub mod consistent_type_definitions;
     pub mod consistent_type_imports;
     pub mod explicit_function_return_type;
+    pub mod member_ordering;
     pub mod no_confusing_non_null_assertion;
     pub mod no_duplicate_enum_values;
     pub mod no_dynamic_delete;
@@ -160,12 +175,14 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
     pub mod no_non_null_asserted_optional_chain;
     pub mod no_non_null_assertion;
+    pub mod no_require_imports;
     pub mod no_this_alias;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
@@ -236,6 +253,7 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod hook_use_state;
     pub mod iframe_missing_sandbox;
     pub mod jsx_boolean_value;
     pub mod jsx_curly_brace_presence;
@@ -246,6 +264,7 @@ mod react {
     pub mod jsx_no_undef;
     pub mod jsx_no_useless_fragment;
     pub mod jsx_props_no_spread_multi;
+    pub mod no_array_index_key;
     pub mod no_children_prop;
     pub mod no_danger;
     pub mod no_danger_with_children;
@@ -254,9 +273,11 @@ mod react {
     pub mod no_is_mounted;
     pub mod no_render_return_value;
     pub mod no_set_state;
+    pub mod no_set_state_in_effect_loop;
     pub mod no_string_refs;
     pub mod no_unescaped_entities;
     pub mod no_unknown_property;
+    pub mod no_unstable_nested_components;
     pub mod prefer_es6_class;
     pub mod react_in_jsx_scope;
     pub mod require_render_return;
@@ -498,15 +519,18 @@ mod vitest {
 }
 
 mod node {
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
     pub mod no_new_require;
 }
 
 oxc_macros::declare_all_lint_rules! {
     // import::no_deprecated,
-    // import::no_unused_modules,
     eslint::array_callback_return,
+    eslint::brace_style,
+    eslint::complexity,
     eslint::constructor_super,
+    eslint::curly,
     eslint::default_case,
     eslint::default_case_last,
     eslint::default_param_last,
@@ -538,6 +562,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_debugger,
     eslint::no_delete_var,
     eslint::no_div_regex,
+    eslint::no_dupe_args,
     eslint::no_dupe_class_members,
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
@@ -562,6 +587,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_irregular_whitespace,
     eslint::no_iterator,
     eslint::no_label_var,
+    eslint::no_loop_func,
     eslint::no_loss_of_precision,
     eslint::no_magic_numbers,
     eslint::no_multi_str,
@@ -573,15 +599,20 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_obj_calls,
     eslint::no_plusplus,
     eslint::no_proto,
+    eslint::no_promise_executor_return,
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_restricted_globals,
     eslint::no_return_assign,
+    eslint::no_return_await,
     eslint::no_script_url,
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_template_curly_in_string,
@@ -599,6 +630,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unused_private_class_members,
     eslint::no_unused_vars,
     eslint::no_useless_catch,
+    eslint::no_useless_computed_key,
     eslint::no_useless_concat,
     eslint::no_useless_constructor,
     eslint::no_useless_escape,
@@ -606,9 +638,11 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_var,
     eslint::no_void,
     eslint::no_with,
+    eslint::object_shorthand,
     eslint::prefer_exponentiation_operator,
     eslint::prefer_numeric_literals,
     eslint::prefer_object_has_own,
+    eslint::prefer_regex_literals,
     eslint::radix,
     eslint::require_await,
     eslint::require_yield,
@@ -633,6 +667,8 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
+    import::no_unresolved,
+    import::no_unused_modules,
     import::no_webpack_loader_syntax,
     jest::consistent_test_it,
     jest::expect_expect,
@@ -747,6 +783,7 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_title_in_document_head,
     nextjs::no_typos,
     nextjs::no_unwanted_polyfillio,
+    node::no_deprecated_api,
     node::no_exports_assign,
     node::no_new_require,
     oxc::approx_constant,
@@ -785,6 +822,7 @@ oxc_macros::declare_all_lint_rules! {
     promise::valid_params,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::hook_use_state,
     react::iframe_missing_sandbox,
     react::jsx_boolean_value,
     react::jsx_curly_brace_presence,
@@ -795,6 +833,7 @@ oxc_macros::declare_all_lint_rules! {
     react::jsx_no_undef,
     react::jsx_no_useless_fragment,
     react::jsx_props_no_spread_multi,
+    react::no_array_index_key,
     react::no_children_prop,
     react::no_danger,
     react::no_danger_with_children,
@@ -803,9 +842,11 @@ oxc_macros::declare_all_lint_rules! {
     react::no_is_mounted,
     react::no_render_return_value,
     react::no_set_state,
+    react::no_set_state_in_effect_loop,
     react::no_string_refs,
     react::no_unescaped_entities,
     react::no_unknown_property,
+    react::no_unstable_nested_components,
     react::prefer_es6_class,
     react::react_in_jsx_scope,
     react::require_render_return,
@@ -828,6 +869,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::consistent_type_definitions,
     typescript::consistent_type_imports,
     typescript::explicit_function_return_type,
+    typescript::member_ordering,
     typescript::no_confusing_non_null_assertion,
     typescript::no_duplicate_enum_values,
     typescript::no_dynamic_delete,
@@ -835,12 +877,14 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
     typescript::no_namespace,
     typescript::no_non_null_asserted_nullish_coalescing,
     typescript::no_non_null_asserted_optional_chain,
     typescript::no_non_null_assertion,
+    typescript::no_require_imports,
     typescript::no_this_alias,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
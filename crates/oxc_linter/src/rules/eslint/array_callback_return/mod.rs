@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 use phf::phf_set;
 use serde_json::Value;
 
-use self::return_checker::{check_function_body, StatementReturnStatus};
+use self::return_checker::check_function_body;
 use crate::{
     ast_util::{get_enclosing_function, is_nth_argument, outermost_paren},
     context::LintContext,
@@ -94,40 +94,24 @@ impl Rule for ArrayCallbackReturn {
         };
 
         // Filter on target methods on Arrays
-        if let Some(array_method) = get_array_method_name(node, ctx) {
-            let return_status = if always_explicit_return {
-                StatementReturnStatus::AlwaysExplicit
-            } else {
-                check_function_body(function_body)
-            };
-
-            match (array_method, self.check_for_each, self.allow_implicit_return) {
-                ("forEach", false, _) => (),
-                ("forEach", true, _) => {
-                    if return_status.may_return_explicit() {
-                        ctx.diagnostic(expect_no_return(
-                            &full_array_method_name(array_method),
-                            function_body.span,
-                        ));
-                    }
-                }
-                (_, _, true) => {
-                    if !return_status.must_return() {
-                        ctx.diagnostic(expect_return(
-                            &full_array_method_name(array_method),
-                            function_body.span,
-                        ));
-                    }
-                }
-                (_, _, false) => {
-                    if !return_status.must_return() || return_status.may_return_implicit() {
-                        ctx.diagnostic(expect_return(
-                            &full_array_method_name(array_method),
-                            function_body.span,
-                        ));
-                    }
-                }
+        let Some(array_method) = get_array_method_name(node, ctx) else { return };
+        if array_method == "forEach" {
+            if self.check_for_each
+                && (always_explicit_return
+                    || check_function_body(function_body).may_return_explicit())
+            {
+                ctx.diagnostic(expect_no_return(
+                    &full_array_method_name(array_method),
+                    function_body.span,
+                ));
             }
+        } else if !always_explicit_return
+            && !ctx.cfg().always_returns(node.cfg_id(), self.allow_implicit_return)
+        {
+            ctx.diagnostic(expect_return(
+                &full_array_method_name(array_method),
+                function_body.span,
+            ));
         }
     }
 }
@@ -295,6 +279,7 @@ fn test() {
             None,
         ),
         ("foo.every(function() { try { bar(); } finally { return true; } })", None),
+        ("foo.map(function() { try { return bar(); } finally { baz(); } })", None),
         (
             "Array.from(x, function() { return; })",
             Some(serde_json::json!([{"allowImplicit": true}])),
@@ -578,6 +563,7 @@ fn test() {
         ("Array?.from([], () => { console.log('hello') })", None),
         ("(Array?.from)([], () => { console.log('hello') })", None),
         ("foo?.filter((function() { return () => { console.log('hello') } })?.())", None),
+        ("foo.map(function() { try { if (a) return bar(); } finally { baz(); } })", None),
     ];
 
     Tester::new(ArrayCallbackReturn::NAME, pass, fail).test_and_snapshot();
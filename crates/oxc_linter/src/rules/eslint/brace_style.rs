@@ -0,0 +1,420 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_line_terminator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn next_line_open_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Opening curly brace does not appear on the same line as controlling statement.",
+    )
+    .with_label(span)
+}
+
+fn same_line_open_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Opening curly brace appears on the same line as controlling statement.")
+        .with_label(span)
+}
+
+fn block_same_line_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Statement inside of curly braces should be on next line.").with_label(span)
+}
+
+fn next_line_close_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Closing curly brace does not appear on the same line as the subsequent block.",
+    )
+    .with_label(span)
+}
+
+fn single_line_close_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Closing curly brace should be on the same line as opening curly brace or on the line after the previous block.")
+        .with_label(span)
+}
+
+fn same_line_close_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Closing curly brace appears on the same line as the subsequent block.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct BraceStyle {
+    style: Style,
+    allow_single_line: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    /// "one true brace style"
+    #[default]
+    OneTbs,
+    Stroustrup,
+    Allman,
+}
+
+impl Style {
+    fn from(raw: &str) -> Option<Self> {
+        match raw {
+            "1tbs" => Some(Self::OneTbs),
+            "stroustrup" => Some(Self::Stroustrup),
+            "allman" => Some(Self::Allman),
+            _ => None,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent brace style for blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Brace style is closely related to indent style, and mixing styles in a
+    /// codebase makes it harder to read.
+    ///
+    /// ### Options
+    ///
+    /// The first option is one of:
+    ///
+    /// - `"1tbs"` _(default)_: the opening brace of a block is on the same line
+    ///   as its statement, and `else`, `catch` and `finally` are on the same
+    ///   line as the preceding closing brace.
+    /// - `"stroustrup"`: like `"1tbs"`, but `else`, `catch` and `finally` are
+    ///   on the line after the preceding closing brace.
+    /// - `"allman"`: opening braces are on their own line.
+    ///
+    /// The second one is an object with `allowSingleLine` (default `false`),
+    /// which allows the opening and closing braces of a block to be on the
+    /// same line.
+    ///
+    /// Line breaks with a comment between the braces and their statement
+    /// are reported, but not fixed.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function foo()
+    /// {
+    ///   return true;
+    /// }
+    ///
+    /// if (foo) {
+    ///   bar();
+    /// }
+    /// else {
+    ///   baz();
+    /// }
+    ///
+    /// if (foo) { bar(); }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function foo() {
+    ///   return true;
+    /// }
+    ///
+    /// if (foo) {
+    ///   bar();
+    /// } else {
+    ///   baz();
+    /// }
+    /// ```
+    BraceStyle,
+    style,
+    conditional_fix
+);
+
+impl Rule for BraceStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let style = value
+            .get(0)
+            .and_then(serde_json::Value::as_str)
+            .and_then(Style::from)
+            .unwrap_or_default();
+        let allow_single_line = value
+            .get(1)
+            .and_then(|options| options.get("allowSingleLine"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { style, allow_single_line }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BlockStatement(block) => {
+                // Blocks in statement lists are not attached to a statement.
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(
+                        AstKind::Program(_)
+                            | AstKind::BlockStatement(_)
+                            | AstKind::StaticBlock(_)
+                            | AstKind::SwitchCase(_)
+                            | AstKind::FunctionBody(_)
+                    )
+                ) {
+                    return;
+                }
+                self.validate_curly_pair(opening_curly(block.span), closing_curly(block.span), ctx);
+            }
+            AstKind::FunctionBody(body) => {
+                if let Some(AstKind::ArrowFunctionExpression(arrow)) =
+                    ctx.nodes().parent_kind(node.id())
+                {
+                    if arrow.expression {
+                        return;
+                    }
+                }
+                self.validate_curly_pair(opening_curly(body.span), closing_curly(body.span), ctx);
+            }
+            AstKind::ClassBody(body) => {
+                self.validate_curly_pair(opening_curly(body.span), closing_curly(body.span), ctx);
+            }
+            AstKind::StaticBlock(block) => {
+                let Some(opening) = ctx.find_token_after(Span::sized(block.span.start, 6)) else {
+                    return;
+                };
+                self.validate_curly_pair(opening, closing_curly(block.span), ctx);
+            }
+            AstKind::SwitchStatement(switch) => {
+                let closing = closing_curly(switch.span);
+                let first = switch.cases.first().map_or(closing, GetSpan::span);
+                let Some(opening) = ctx.find_token_before(first) else {
+                    return;
+                };
+                self.validate_curly_pair(opening, closing, ctx);
+            }
+            AstKind::IfStatement(if_stmt) => {
+                if let (Statement::BlockStatement(block), Some(_)) =
+                    (&if_stmt.consequent, &if_stmt.alternate)
+                {
+                    self.validate_curly_before_keyword(closing_curly(block.span), ctx);
+                }
+            }
+            AstKind::TryStatement(try_stmt) => {
+                self.validate_curly_before_keyword(closing_curly(try_stmt.block.span), ctx);
+                if let (Some(handler), Some(_)) = (&try_stmt.handler, &try_stmt.finalizer) {
+                    self.validate_curly_before_keyword(closing_curly(handler.body.span), ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl BraceStyle {
+    fn validate_curly_pair(&self, opening: Span, closing: Span, ctx: &LintContext) {
+        let single_line_exception = self.allow_single_line && is_same_line(opening, closing, ctx);
+
+        if let Some(token_before) = ctx.find_token_before(opening) {
+            let same_line = is_same_line(token_before, opening, ctx);
+            if self.style != Style::Allman && !same_line {
+                report_with_newline_removed(
+                    next_line_open_diagnostic(opening),
+                    token_before,
+                    opening,
+                    ctx,
+                );
+            }
+            if self.style == Style::Allman && same_line && !single_line_exception {
+                ctx.diagnostic_with_fix(same_line_open_diagnostic(opening), |fixer| {
+                    fixer
+                        .insert_text_before_range(opening, "\n")
+                        .with_message("Insert a line break")
+                });
+            }
+        }
+
+        if let Some(token_after) = ctx.find_token_after(opening) {
+            if token_after != closing
+                && !single_line_exception
+                && is_same_line(opening, token_after, ctx)
+            {
+                ctx.diagnostic_with_fix(block_same_line_diagnostic(opening), |fixer| {
+                    fixer.insert_text_after_range(opening, "\n").with_message("Insert a line break")
+                });
+            }
+        }
+
+        if let Some(token_before) = ctx.find_token_before(closing) {
+            if token_before != opening
+                && !single_line_exception
+                && is_same_line(token_before, closing, ctx)
+            {
+                ctx.diagnostic_with_fix(single_line_close_diagnostic(closing), |fixer| {
+                    fixer
+                        .insert_text_before_range(closing, "\n")
+                        .with_message("Insert a line break")
+                });
+            }
+        }
+    }
+
+    /// Check the closing brace before an `else`, `catch` or `finally`.
+    fn validate_curly_before_keyword(&self, curly: Span, ctx: &LintContext) {
+        let Some(keyword) = ctx.find_token_after(curly) else {
+            return;
+        };
+        let same_line = is_same_line(curly, keyword, ctx);
+        if self.style == Style::OneTbs && !same_line {
+            report_with_newline_removed(next_line_close_diagnostic(curly), curly, keyword, ctx);
+        }
+        if self.style != Style::OneTbs && same_line {
+            ctx.diagnostic_with_fix(same_line_close_diagnostic(curly), |fixer| {
+                fixer.insert_text_after_range(curly, "\n").with_message("Insert a line break")
+            });
+        }
+    }
+}
+
+/// Report `diagnostic`, with a fix joining `first` and `second` on a single
+/// line unless there is a comment between them.
+fn report_with_newline_removed(
+    diagnostic: OxcDiagnostic,
+    first: Span,
+    second: Span,
+    ctx: &LintContext,
+) {
+    let between = Span::new(first.end, second.start);
+    if !ctx.source_range(between).trim().is_empty() {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        fixer.replace(between, " ").with_message("Remove the line break")
+    });
+}
+
+fn opening_curly(span: Span) -> Span {
+    Span::sized(span.start, 1)
+}
+
+fn closing_curly(span: Span) -> Span {
+    Span::new(span.end - 1, span.end)
+}
+
+fn is_same_line(first: Span, second: Span, ctx: &LintContext) -> bool {
+    first.end >= second.start
+        || !ctx.source_range(Span::new(first.end, second.start)).chars().any(is_line_terminator)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function f() {\n  if (true)\n    return {x: 1}\n  else {\n    var y = 2\n    return y\n  }\n}", None),
+        ("if (tag === 1) glyph.id = pbfTag.readSVarint();\nelse if (tag === 2) glyph.bitmap = pbf.readBytes();", None),
+        ("function foo () {\n  return;\n}", None),
+        ("function a(b,\nc,\nd) { }", None),
+        ("!function foo () {\n  return;\n}", None),
+        ("!function a(b,\nc,\nd) { }", None),
+        ("if (foo) {\n  bar();\n}", None),
+        ("if (a) {\n  b();\n} else {\n  c();\n}", None),
+        ("while (foo) {\n  bar();\n}", None),
+        ("for (;;) {\n  bar();\n}", None),
+        ("with (foo) {\n  bar();\n}", None),
+        ("switch (foo) {\n  case 'bar': break;\n}", None),
+        ("try {\n  bar();\n} catch (e) {\n  baz();\n}", None),
+        ("try {\n  bar();\n} catch (e) {\n  baz();\n} finally {\n  qux();\n}", None),
+        ("do {\n  bar();\n} while (true)", None),
+        ("for (foo in bar) {\n  baz();\n}", None),
+        ("if (a &&\n    b &&\n    c) {\n  }", None),
+        ("switch(0) {\n}", None),
+        ("class Foo {\n}", None),
+        ("(class {\n})", None),
+        ("class\nFoo {\n}", None),
+        ("class Foo {\n  bar() {\n  }\n}", None),
+        ("class Foo {\n  static {\n    bar();\n  }\n}", None),
+        ("class Foo {\n  static {}\n}", None),
+        ("const foo = () => bar;", None),
+        ("if (foo) {\n}\n{\n  bar();\n}", None),
+        ("if (foo) {\n  bar();\n}\nelse {\n  baz();\n}", Some(json!(["stroustrup"]))),
+        ("try {\n  bar();\n}\ncatch (e) {\n  baz();\n}\nfinally {\n  qux();\n}", Some(json!(["stroustrup"]))),
+        ("function foo()\n{\n  return;\n}", Some(json!(["allman"]))),
+        ("if (foo)\n{\n  bar();\n}\nelse\n{\n  baz();\n}", Some(json!(["allman"]))),
+        ("class Foo\n{\n  static\n  {\n    bar();\n  }\n}", Some(json!(["allman"]))),
+        ("switch (foo)\n{\n  case 'bar': break;\n}", Some(json!(["allman"]))),
+        ("function foo() { return; }", Some(json!(["1tbs", { "allowSingleLine": true }]))),
+        ("if (foo) { bar(); } else { baz(); }", Some(json!(["1tbs", { "allowSingleLine": true }]))),
+        ("try { bar(); } catch (e) { baz(); }", Some(json!(["1tbs", { "allowSingleLine": true }]))),
+        ("if (foo) { bar(); }\nelse { baz(); }", Some(json!(["stroustrup", { "allowSingleLine": true }]))),
+        ("function foo() { return; }", Some(json!(["allman", { "allowSingleLine": true }]))),
+        ("if (foo) {}", None),
+        ("class Foo {}", None),
+    ];
+
+    let fail = vec![
+        ("function foo()\n{\n  return;\n}", None),
+        ("!function foo()\n{\n  return;\n}", None),
+        ("if (foo)\n{\n  bar();\n}", None),
+        ("if (a) {\n  b();\n}\nelse {\n  c();\n}", None),
+        ("while (foo)\n{\n  bar();\n}", None),
+        ("for (;;)\n{\n  bar();\n}", None),
+        ("switch (foo)\n{\n  case 'bar': break;\n}", None),
+        ("try\n{\n  bar();\n}\ncatch (e)\n{\n  baz();\n}", None),
+        ("class Foo\n{\n}", None),
+        ("class Foo {\n  static\n  {\n    bar();\n  }\n}", None),
+        ("if (foo) { bar(); }", None),
+        ("function foo() { return; }", None),
+        ("if (foo) {\n  bar(); }", None),
+        ("if (foo) /* comment */\n{\n  bar();\n}", None),
+        ("if (foo) {\n  bar();\n} else {\n  baz();\n}", Some(json!(["stroustrup"]))),
+        (
+            "try {\n  bar();\n} catch (e) {\n  baz();\n} finally {\n  qux();\n}",
+            Some(json!(["stroustrup"])),
+        ),
+        ("function foo() {\n  return;\n}", Some(json!(["allman"]))),
+        ("if (foo) {\n  bar();\n}\nelse {\n  baz();\n}", Some(json!(["allman"]))),
+        ("if (foo)\n{\n  bar();\n} else\n{\n  baz();\n}", Some(json!(["allman"]))),
+        ("if (foo) {\n  bar(); }", Some(json!(["1tbs", { "allowSingleLine": true }]))),
+        (
+            "if (foo) { bar(); } else\n{ baz(); }",
+            Some(json!(["1tbs", { "allowSingleLine": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("function foo()\n{\n  return;\n}", "function foo() {\n  return;\n}", None),
+        ("if (foo)\n{\n  bar();\n}", "if (foo) {\n  bar();\n}", None),
+        ("if (a) {\n  b();\n}\nelse {\n  c();\n}", "if (a) {\n  b();\n} else {\n  c();\n}", None),
+        ("class Foo\n{\n}", "class Foo {\n}", None),
+        (
+            "class Foo {\n  static\n  {\n    bar();\n  }\n}",
+            "class Foo {\n  static {\n    bar();\n  }\n}",
+            None,
+        ),
+        ("if (foo) { bar(); }", "if (foo) {\n bar(); \n}", None),
+        ("switch (foo)\n{ case 'bar': break; }", "switch (foo) {\n case 'bar': break; \n}", None),
+        // comments are not moved
+        ("if (foo) /* comment */\n{\n  bar();\n}", "if (foo) /* comment */\n{\n  bar();\n}", None),
+        (
+            "if (a) {\n  b();\n} // comment\nelse {\n  c();\n}",
+            "if (a) {\n  b();\n} // comment\nelse {\n  c();\n}",
+            None,
+        ),
+        (
+            "if (foo) {\n  bar();\n} else {\n  baz();\n}",
+            "if (foo) {\n  bar();\n}\n else {\n  baz();\n}",
+            Some(json!(["stroustrup"])),
+        ),
+        (
+            "function foo() {\n  return;\n}",
+            "function foo() \n{\n  return;\n}",
+            Some(json!(["allman"])),
+        ),
+        (
+            "if (foo) {\n  bar(); }",
+            "if (foo) {\n  bar(); \n}",
+            Some(json!(["1tbs", { "allowSingleLine": true }])),
+        ),
+    ];
+
+    Tester::new(BraceStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
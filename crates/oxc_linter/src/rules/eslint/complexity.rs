@@ -0,0 +1,321 @@
+use std::borrow::Cow;
+
+use oxc_ast::{ast::SwitchCase, AstKind};
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn complexity_diagnostic(name: &str, complexity: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{name} has a complexity of {complexity}."))
+        .with_help(format!("Maximum allowed is {max}."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Complexity(Box<ComplexityConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ComplexityConfig {
+    max: usize,
+}
+
+impl std::ops::Deref for Complexity {
+    type Target = ComplexityConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum cyclomatic complexity allowed in a program.
+    ///
+    /// The complexity of a function is one plus the number of its branches in
+    /// the control flow graph: conditions, loops, logical operators, `case`s
+    /// with a test and `catch` clauses.
+    ///
+    /// ### Why is this bad?
+    /// Cyclomatic complexity measures the number of linearly independent paths
+    /// through a function. Functions with a high complexity are hard to read,
+    /// test and maintain, and are usually better split into smaller functions.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /* complexity: ["error", 2] */
+    /// function a(x) {
+    ///     if (true) {
+    ///         return x;
+    ///     } else if (false) {
+    ///         return x + 1;
+    ///     } else {
+    ///         return 4;
+    ///     }
+    /// }
+    /// ```
+    Complexity,
+    pedantic
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        if let Some(max) = config
+            .and_then(Value::as_number)
+            .and_then(serde_json::Number::as_u64)
+            .and_then(|v| usize::try_from(v).ok())
+        {
+            Self(Box::new(ComplexityConfig { max }))
+        } else {
+            let max = config
+                .and_then(|config| config.get("max").or_else(|| config.get("maximum")))
+                .and_then(Value::as_number)
+                .and_then(serde_json::Number::as_u64)
+                .map_or(20, |v| usize::try_from(v).unwrap_or(20));
+
+            Self(Box::new(ComplexityConfig { max }))
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let switch_dispatches = switch_dispatch_blocks(ctx);
+
+        for node in ctx.nodes() {
+            let (name, span) = match node.kind() {
+                AstKind::Function(function) => {
+                    if function.body.is_none() {
+                        continue;
+                    }
+                    let method = match ctx.nodes().parent_kind(node.id()) {
+                        Some(AstKind::MethodDefinition(method)) => {
+                            Some((method.span, method.key.static_name()))
+                        }
+                        Some(AstKind::ObjectProperty(property)) if property.method => {
+                            Some((property.span, property.key.static_name()))
+                        }
+                        _ => None,
+                    };
+                    match (method, &function.id) {
+                        (Some((span, key)), _) => (
+                            key.map_or(Cow::Borrowed("Method"), |key| {
+                                Cow::Owned(format!("Method '{key}'"))
+                            }),
+                            Span::new(span.start, function.params.span.start),
+                        ),
+                        (None, Some(id)) => (
+                            Cow::Owned(format!("Function '{}'", id.name)),
+                            Span::new(function.span.start, function.params.span.start),
+                        ),
+                        (None, None) => (
+                            Cow::Borrowed("Function"),
+                            Span::new(function.span.start, function.params.span.start),
+                        ),
+                    }
+                }
+                AstKind::ArrowFunctionExpression(function) => {
+                    (Cow::Borrowed("Arrow function"), function.params.span)
+                }
+                _ => continue,
+            };
+
+            let complexity = cyclomatic_complexity(ctx.cfg(), node.cfg_id(), &switch_dispatches);
+            if complexity > self.max {
+                ctx.diagnostic(complexity_diagnostic(&name, complexity, self.max, span));
+            }
+        }
+    }
+}
+
+/// Blocks that dispatch a `switch` with a `default` case to its cases.
+///
+/// The CFG connects them to every case, but only the cases with a test are decisions.
+fn switch_dispatch_blocks(ctx: &LintContext) -> FxHashSet<BlockNodeId> {
+    let graph = ctx.cfg().graph();
+    ctx.nodes()
+        .iter()
+        .filter_map(|node| {
+            let AstKind::SwitchCase(case) = node.kind() else { return None };
+            let Some(AstKind::SwitchStatement(switch)) = ctx.nodes().parent_kind(node.id()) else {
+                return None;
+            };
+            if switch.cases[0].span != case.span
+                || !switch.cases.iter().any(SwitchCase::is_default_case)
+            {
+                return None;
+            }
+            graph.neighbors_directed(node.cfg_id(), Direction::Incoming).next()
+        })
+        .collect()
+}
+
+/// Counts the decision points of the function starting at `entry`, which are the blocks with
+/// more than one successor and the `catch` clauses, without descending into nested functions.
+fn cyclomatic_complexity(
+    cfg: &ControlFlowGraph,
+    entry: BlockNodeId,
+    switch_dispatches: &FxHashSet<BlockNodeId>,
+) -> usize {
+    let graph = cfg.graph();
+    let mut complexity = 1;
+    let mut catch_clauses = FxHashSet::default();
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![entry];
+    while let Some(block) = stack.pop() {
+        if !visited.insert(block) {
+            continue;
+        }
+        let mut successors = 0;
+        for edge in graph.edges_directed(block, Direction::Outgoing) {
+            match edge.weight() {
+                EdgeType::Jump | EdgeType::Normal | EdgeType::Backedge => {
+                    successors += 1;
+                    stack.push(edge.target());
+                }
+                EdgeType::Error(ErrorEdgeKind::Explicit) => {
+                    catch_clauses.insert(edge.target());
+                    stack.push(edge.target());
+                }
+                _ => {}
+            }
+        }
+        if successors > 1 && !switch_dispatches.contains(&block) {
+            complexity += 1;
+        }
+    }
+    complexity + catch_clauses.len()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(serde_json::json!([2]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}",
+            Some(serde_json::json!([2])),
+        ),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}",
+            Some(serde_json::json!([4])),
+        ),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(serde_json::json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(serde_json::json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(serde_json::json!([3]))),
+        ("function a(x) {return x || 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x && 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x ?? 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x ||= 4;}", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){default: 1; case 1: 2; case 2: 3;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break;}}",
+            Some(serde_json::json!([3])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(serde_json::json!([2]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(serde_json::json!([2]))),
+        ("if (foo) { bar(); }", Some(serde_json::json!([0]))),
+        (
+            "function a(x) {if (x) {} function b(y) {if (y) {} if (y) {}}}",
+            Some(serde_json::json!([3])),
+        ),
+        ("var func = function () {}", Some(serde_json::json!([{ "max": 1 }]))),
+        ("var func = () => x ? 1 : 2", Some(serde_json::json!([{ "maximum": 2 }]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(serde_json::json!([0]))),
+        ("var func = function () {}", Some(serde_json::json!([0]))),
+        ("var obj = { a(x) {} }", Some(serde_json::json!([0]))),
+        ("class Test { a(x) {} }", Some(serde_json::json!([0]))),
+        ("var a = (x) => {if (true) {return x;}}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}",
+            Some(serde_json::json!([1])),
+        ),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(serde_json::json!([1]))),
+        ("function a(obj) {for(var i of obj) {obj[i] = 3;}}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}",
+            Some(serde_json::json!([3])),
+        ),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(serde_json::json!([1]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(serde_json::json!([1]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(serde_json::json!([2]))),
+        ("function a(x) {return x || 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x && 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x ?? 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x &&= 4;}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: if(x == 'foo') {5;};}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break;}}",
+            Some(serde_json::json!([2])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(serde_json::json!([1]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {(function() {while(true){'foo';}})(); (function() {while(true){'bar';}})();}",
+            Some(serde_json::json!([1])),
+        ),
+        (
+            "function a(x) {(function() {while(true){'foo';}})(); (function() {'bar';})();}",
+            Some(serde_json::json!([1])),
+        ),
+        ("var obj = { a(x) { return x ? 0 : 1; } };", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {} if (x) {}}",
+            None,
+        ),
+    ];
+
+    Tester::new(Complexity::NAME, pass, fail).test_and_snapshot();
+}
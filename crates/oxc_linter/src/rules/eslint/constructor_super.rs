@@ -1,44 +1,336 @@
+use oxc_ast::{
+    ast::{AssignmentOperator, Expression, LogicalOperator, MethodDefinitionKind},
+    AstKind,
+};
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind, InstructionKind, ReturnInstructionKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
 
-// use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-// #[derive(Debug, Error, Diagnostic)]
-// #[error("Expected to call 'super()'.")]
-// #[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
-// struct ConstructorSuperDiagnostic(#[label] pub Span);
+fn missing_all_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected to call 'super()'.")
+        .with_help("Ensure 'super()' is called from constructor")
+        .with_label(span)
+}
+
+fn missing_some_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Lacked a call of 'super()' in some code paths.")
+        .with_help("Ensure 'super()' is called in every code path of the constructor")
+        .with_label(span)
+}
 
-// #[derive(Debug, Error, Diagnostic)]
-// #[error("Unexpected 'super()' because 'super' is not a constructor.")]
-// #[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
-// struct SuperNotConstructorDiagnostic(
-//     #[label("unexpected 'super()'")] pub Span,
-//     #[label("because this is not a constructor")] pub Span,
-// );
+fn duplicate_super_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected duplicate 'super()'.")
+        .with_help("'super()' can only be called once per code path")
+        .with_label(span)
+}
+
+fn super_not_constructor_diagnostic(span: Span, super_class_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected 'super()' because 'super' is not a constructor.")
+        .with_help("Do not call 'super()' from constructor.")
+        .with_labels([
+            span.label("unexpected 'super()'"),
+            super_class_span.label("because this is not a constructor"),
+        ])
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct ConstructorSuper;
 
 declare_oxc_lint!(
     /// ### What it does
-    /// Require 'super()' calls in constructors.
+    ///
+    /// Require `super()` calls in constructors of derived classes, and disallow
+    /// them everywhere else.
     ///
     /// ### Why is this bad?
     ///
+    /// Constructors of derived classes must call `super()` before returning,
+    /// otherwise a `ReferenceError` is thrown. Calling `super()` twice, or when
+    /// the class extends something that isn't a constructor, throws as well.
     ///
     /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// class A extends B {
+    ///     constructor() {}
+    /// }
+    ///
+    /// class C extends D {
+    ///     constructor() {
+    ///         if (a) super();
+    ///     }
+    /// }
+    ///
+    /// class E extends null {
+    ///     constructor() {
+    ///         super();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
     /// ```javascript
     /// class A extends B {
-    ///   constructor() {}
+    ///     constructor() {
+    ///         super();
+    ///     }
+    /// }
+    ///
+    /// class C extends D {
+    ///     constructor() {
+    ///         if (a) super(); else super();
+    ///     }
     /// }
     /// ```
     ConstructorSuper,
-    nursery // This rule should be implemented with CFG, the current implementation has a lot of
-            // false positives.
+    correctness
 );
 
+/// Whether `super()` has been called on some or every path reaching a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SuperState {
+    some: bool,
+    every: bool,
+}
+
+impl SuperState {
+    const CALLED: Self = Self { some: true, every: true };
+    const NOT_CALLED: Self = Self { some: false, every: false };
+
+    fn join(self, other: Self) -> Self {
+        Self { some: self.some || other.some, every: self.every && other.every }
+    }
+}
+
 impl Rule for ConstructorSuper {
-    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+
+        // first pass -> collect the `super()` calls of every constructor
+        let mut constructors = Vec::new();
+        let mut super_calls = FxHashMap::<NodeId, Vec<&AstNode>>::default();
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::Function(function) if function.body.is_some() => {
+                    let Some(AstKind::MethodDefinition(method)) = nodes.parent_kind(node.id())
+                    else {
+                        continue;
+                    };
+                    if method.kind == MethodDefinitionKind::Constructor {
+                        constructors.push((node, method));
+                    }
+                }
+                AstKind::CallExpression(call) if matches!(call.callee, Expression::Super(_)) => {
+                    let function = nodes.ancestors(node.id()).skip(1).find(|&id| {
+                        matches!(
+                            nodes.kind(id),
+                            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                        )
+                    });
+                    if let Some(function) = function {
+                        super_calls.entry(function).or_default().push(node);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // second pass -> walk the cfg of every constructor of a derived class
+        for (function, method) in constructors {
+            let Some(class) =
+                nodes.ancestors(function.id()).find_map(|id| nodes.kind(id).as_class())
+            else {
+                continue;
+            };
+            if class.declare {
+                continue;
+            }
+            let Some(super_class) = &class.super_class else {
+                continue;
+            };
+            let calls = super_calls.get(&function.id()).map_or(&[][..], Vec::as_slice);
+
+            if !is_possible_constructor(super_class) {
+                for call in calls {
+                    ctx.diagnostic(super_not_constructor_diagnostic(
+                        call.kind().span(),
+                        super_class.span(),
+                    ));
+                }
+            }
+
+            let mut calls_by_block = FxHashMap::<BlockNodeId, Vec<Span>>::default();
+            for call in calls {
+                calls_by_block.entry(call.cfg_id()).or_default().push(call.kind().span());
+            }
+            for spans in calls_by_block.values_mut() {
+                spans.sort_unstable_by_key(|span| span.start);
+            }
+
+            let cfg = ctx.cfg();
+            let states = analyze(cfg, function.cfg_id(), &calls_by_block);
+
+            let mut final_states = Vec::new();
+            for (&block_id, &state) in &states {
+                let spans = calls_by_block.get(&block_id).map_or(&[][..], Vec::as_slice);
+                let mut some = state.some;
+                for span in spans {
+                    if some && is_possible_constructor(super_class) {
+                        ctx.diagnostic(duplicate_super_diagnostic(*span));
+                    }
+                    some = true;
+                }
+                if is_exit_block(cfg, block_id) {
+                    final_states.push(transfer(cfg, block_id, state, &calls_by_block));
+                }
+            }
+
+            if final_states.iter().all(|state| state.every) {
+                continue;
+            }
+            let span = method.key.span();
+            if final_states.iter().any(|state| state.some) {
+                ctx.diagnostic(missing_some_diagnostic(span));
+            } else {
+                ctx.diagnostic(missing_all_diagnostic(span));
+            }
+        }
+    }
+}
+
+/// Computes the [`SuperState`] at the start of every block reachable from `entry`.
+fn analyze(
+    cfg: &ControlFlowGraph,
+    entry: BlockNodeId,
+    calls_by_block: &FxHashMap<BlockNodeId, Vec<Span>>,
+) -> FxHashMap<BlockNodeId, SuperState> {
+    let mut states = FxHashMap::default();
+    states.insert(entry, SuperState::NOT_CALLED);
+    let mut worklist = vec![entry];
+
+    while let Some(block_id) = worklist.pop() {
+        let state = states[&block_id];
+        let exit_state = transfer(cfg, block_id, state, calls_by_block);
+        for edge in cfg.graph.edges_directed(block_id, Direction::Outgoing) {
+            let next_state = match edge.weight() {
+                EdgeType::Jump
+                | EdgeType::Normal
+                | EdgeType::Backedge
+                | EdgeType::Join
+                | EdgeType::Finalize => exit_state,
+                // anything in the block can throw before `super()` is reached.
+                EdgeType::Error(ErrorEdgeKind::Explicit) => state,
+                EdgeType::Error(ErrorEdgeKind::Implicit)
+                | EdgeType::NewFunction
+                | EdgeType::Unreachable => continue,
+            };
+            let target = edge.target();
+            if cfg.basic_block(target).is_unreachable() {
+                continue;
+            }
+            let joined = states.get(&target).map_or(next_state, |it| it.join(next_state));
+            if states.get(&target) != Some(&joined) {
+                states.insert(target, joined);
+                worklist.push(target);
+            }
+        }
+    }
+
+    states
+}
+
+/// The [`SuperState`] at the end of `block_id`, given the one at its start.
+/// Returning a value is a substitute for calling `super()`.
+fn transfer(
+    cfg: &ControlFlowGraph,
+    block_id: BlockNodeId,
+    state: SuperState,
+    calls_by_block: &FxHashMap<BlockNodeId, Vec<Span>>,
+) -> SuperState {
+    let returns_value = cfg.basic_block(block_id).instructions().iter().any(|it| {
+        matches!(it.kind, InstructionKind::Return(ReturnInstructionKind::NotImplicitUndefined))
+    });
+    if returns_value || calls_by_block.contains_key(&block_id) {
+        SuperState::CALLED
+    } else {
+        state
+    }
+}
+
+/// Whether the constructor returns at the end of `block_id`, either with a `return`
+/// or by falling off its end. Paths that end with a `throw` don't count.
+fn is_exit_block(cfg: &ControlFlowGraph, block_id: BlockNodeId) -> bool {
+    let instructions = cfg.basic_block(block_id).instructions();
+    if instructions.iter().any(|it| matches!(it.kind, InstructionKind::Return(_))) {
+        return true;
+    }
+    if instructions.iter().any(|it| matches!(it.kind, InstructionKind::Throw)) {
+        return false;
+    }
+    !cfg.graph.edges_directed(block_id, Direction::Outgoing).any(|edge| {
+        matches!(
+            edge.weight(),
+            EdgeType::Jump
+                | EdgeType::Normal
+                | EdgeType::Backedge
+                | EdgeType::Join
+                | EdgeType::Finalize
+        ) && !cfg.basic_block(edge.target()).is_unreachable()
+    })
+}
+
+/// Whether `expr`, used as a super class, could evaluate to a constructor.
+fn is_possible_constructor(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::ClassExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ThisExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::PrivateFieldExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::MetaProperty(_) => true,
+        Expression::Identifier(ident) => ident.name != "undefined",
+        Expression::AssignmentExpression(assign) => match assign.operator {
+            AssignmentOperator::Assign | AssignmentOperator::LogicalAnd => {
+                is_possible_constructor(&assign.right)
+            }
+            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalNullish => {
+                assign.left.get_expression().map_or(true, is_possible_constructor)
+                    || is_possible_constructor(&assign.right)
+            }
+            _ => false,
+        },
+        Expression::LogicalExpression(logical) => {
+            if logical.operator == LogicalOperator::And {
+                is_possible_constructor(&logical.right)
+            } else {
+                is_possible_constructor(&logical.left) || is_possible_constructor(&logical.right)
+            }
+        }
+        Expression::ConditionalExpression(conditional) => {
+            is_possible_constructor(&conditional.alternate)
+                || is_possible_constructor(&conditional.consequent)
+        }
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.last().is_some_and(is_possible_constructor)
+        }
+        _ => false,
+    }
 }
 
 #[test]
@@ -46,12 +338,16 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        // non derived classes.
         ("class A { }", None),
         ("class A { constructor() { } }", None),
+        // inherit from non constructors.
+        // those are valid if we don't define the constructor.
         ("class A extends null { }", None),
-        ("class A extends B { constructor() { super(); } }", None),
+        // derived classes.
         ("class A extends B { }", None),
         ("class A extends B { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { if (true) { super(); } else { super(); } } }", None),
         ("class A extends (class B {}) { constructor() { super(); } }", None),
         ("class A extends (B = C) { constructor() { super(); } }", None),
         ("class A extends (B &&= C) { constructor() { super(); } }", None),
@@ -61,14 +357,173 @@ fn test() {
         ("class A extends (B ??= 5) { constructor() { super(); } }", None),
         ("class A extends (B || C) { constructor() { super(); } }", None),
         ("class A extends (5 && B) { constructor() { super(); } }", None),
+        // A future improvement could detect the left side as statically falsy
+        ("class A extends (false && B) { constructor() { super(); } }", None),
+        ("class A extends (B || 5) { constructor() { super(); } }", None),
+        ("class A extends (B ?? 5) { constructor() { super(); } }", None),
+        ("class A extends (a ? B : C) { constructor() { super(); } }", None),
+        ("class A extends (B, C) { constructor() { super(); } }", None),
+        ("class A extends B.C { constructor() { super(); } }", None),
+        ("class A extends B() { constructor() { super(); } }", None),
+        // nested.
+        ("class A { constructor() { class B extends C { constructor() { super(); } } } }", None),
+        (
+            "class A extends B { constructor() { super(); class C extends D { constructor() { super(); } } } }",
+            None,
+        ),
+        (
+            "class A extends B { constructor() { super(); class C { constructor() { } } } }",
+            None,
+        ),
+        // multi code path.
+        ("class A extends B { constructor() { a ? super() : super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); else super(); } }", None),
+        (
+            "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
+            None,
+        ),
+        ("class A extends B { constructor() { try {} finally { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) throw Error(); super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); return; } super(); } }", None),
+        // returning value is a substitute of 'super()'.
+        ("class A extends B { constructor() { if (true) return a; super(); } }", None),
+        ("class A extends null { constructor() { return a; } }", None),
+        ("class A { constructor() { return a; } }", None),
+        // https://github.com/eslint/eslint/issues/5261
+        ("class A extends B { constructor(a) { super(); for (const b of a) { this.a(); } } }", None),
+        ("class A extends B { constructor(a) { for (const b of a) { foo(b); } super(); } }", None),
+        // https://github.com/eslint/eslint/issues/5319
+        ("class Foo extends Object { constructor(method) { super(); this.method = method || function() {}; } }", None),
+        // https://github.com/eslint/eslint/issues/5394
+        (
+            "class A extends Object {
+                constructor() {
+                    super();
+                    for (let i = 0; i < 0; i++);
+                }
+            }",
+            None,
+        ),
+        (
+            "class A extends Object {
+                constructor() {
+                    super();
+                    for (; i < 0; i++);
+                }
+            }",
+            None,
+        ),
+        (
+            "class A extends Object {
+                constructor() {
+                    super();
+                    for (let i = 0;; i++) {
+                        if (foo) break;
+                    }
+                }
+            }",
+            None,
+        ),
+        (
+            "class A extends Object {
+                constructor() {
+                    super();
+                    for (let i = 0; i < 0;);
+                }
+            }",
+            None,
+        ),
+        (
+            "class A extends Object {
+                constructor() {
+                    super();
+                    for (let i = 0;;) {
+                        if (foo) break;
+                    }
+                }
+            }",
+            None,
+        ),
+        // https://github.com/eslint/eslint/issues/8848
+        (
+            "
+            class A extends B {
+                constructor(props) {
+                    super(props);
+
+                    try {
+                        let arr = [];
+                        for (let a of arr) {
+                        }
+                    } catch (err) {
+                    }
+                }
+            }
+            ",
+            None,
+        ),
+        // Optional chaining
+        ("class A extends obj?.prop { constructor() { super(); } }", None),
+        // TypeScript overloads and ambient classes.
+        ("class A extends B { constructor(a: string); constructor(a: any) { super(); } }", None),
+        ("declare class A extends B { constructor(); }", None),
     ];
 
     let fail = vec![
-        // ("class A extends B { constructor() {} }", None),
-        // ("class A extends null { constructor() { super(); } }", None),
-        // ("class A extends null { constructor() { } }", None),
-        // ("class A extends 100 { constructor() { super(); } }", None),
-        // ("class A extends 'test' { constructor() { super(); } }", None),
+        // inherit from non constructors.
+        ("class A extends null { constructor() { super(); } }", None),
+        ("class A extends null { constructor() { } }", None),
+        ("class A extends 100 { constructor() { super(); } }", None),
+        ("class A extends 'test' { constructor() { super(); } }", None),
+        ("class A extends (B = 5) { constructor() { super(); } }", None),
+        ("class A extends (B && 5) { constructor() { super(); } }", None),
+        ("class A extends (B &&= 5) { constructor() { super(); } }", None),
+        ("class A extends (B += C) { constructor() { super(); } }", None),
+        ("class A extends (B -= C) { constructor() { super(); } }", None),
+        ("class A extends (B **= C) { constructor() { super(); } }", None),
+        ("class A extends (B |= C) { constructor() { super(); } }", None),
+        ("class A extends (B &= C) { constructor() { super(); } }", None),
+        ("class A extends undefined { constructor() { super(); } }", None),
+        // derived classes.
+        ("class A extends B { constructor() { } }", None),
+        ("class A extends B { constructor() { for (var a of b) super.foo(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var c = class extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var c = () => super(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { var C = class extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { super(); class C extends D { constructor() { } } } }", None),
+        ("class A extends B { constructor() { super(); var C = class extends D { constructor() { } } } }", None),
+        // lacked in some code path.
+        ("class A extends B { constructor() { if (a) super(); } }", None),
+        ("class A extends B { constructor() { if (a); else super(); } }", None),
+        ("class A extends B { constructor() { a && super(); } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: super(); } } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: break; default: super(); } } }", None),
+        ("class A extends B { constructor() { try { super(); } catch (err) {} } }", None),
+        ("class A extends B { constructor() { try { a; } catch (err) { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) return; super(); } }", None),
+        // duplicate.
+        ("class A extends B { constructor() { super(); super(); } }", None),
+        ("class A extends B { constructor() { super() || super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); super(); } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: super(); default: super(); } } }", None),
+        ("class A extends B { constructor(a) { while (a) super(); } }", None),
+        // ignores `super()` on unreachable paths.
+        ("class A extends B { constructor() { return; super(); } }", None),
+        // https://github.com/eslint/eslint/issues/8248
+        (
+            "class Foo extends Bar {
+                constructor() {
+                    for (a in b) for (c in d);
+                }
+            }",
+            None,
+        ),
+        ("class C extends D { constructor() { do { something(); } while (foo); } }", None),
+        ("class C extends D { constructor() { for (let i = 1;;i++) { if (bar) { break; } } } }", None),
+        ("class C extends D { constructor() { do { super(); } while (foo); } }", None),
+        ("class C extends D { constructor() { while (foo) { if (bar) { super(); break; } } } }", None),
     ];
 
     Tester::new(ConstructorSuper::NAME, pass, fail).test_and_snapshot();
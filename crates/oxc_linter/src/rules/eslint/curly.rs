@@ -0,0 +1,603 @@
+use oxc_ast::{
+    ast::{BlockStatement, IfStatement, Statement, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::{is_identifier_part, is_line_terminator};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn missing_curly_diagnostic(keyword: &str, condition: bool, span: Span) -> OxcDiagnostic {
+    let after = if condition { format!("'{keyword}' condition") } else { format!("'{keyword}'") };
+    OxcDiagnostic::warn(format!("Expected {{ after {after}.")).with_label(span)
+}
+
+fn unexpected_curly_diagnostic(keyword: &str, condition: bool, span: Span) -> OxcDiagnostic {
+    let after = if condition { format!("'{keyword}' condition") } else { format!("'{keyword}'") };
+    OxcDiagnostic::warn(format!("Unnecessary {{ after {after}.")).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Curly {
+    option: CurlyOption,
+    consistent: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CurlyOption {
+    #[default]
+    All,
+    Multi,
+    MultiLine,
+    MultiOrNest,
+}
+
+impl CurlyOption {
+    fn from(raw: &str) -> Option<Self> {
+        match raw {
+            "all" => Some(Self::All),
+            "multi" => Some(Self::Multi),
+            "multi-line" => Some(Self::MultiLine),
+            "multi-or-nest" => Some(Self::MultiOrNest),
+            _ => None,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent brace style for all control statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript allows the omission of curly braces when a block contains
+    /// only one statement. Omitting them can make code harder to read, and
+    /// adding a second statement to a brace-less body later is a common
+    /// source of bugs.
+    ///
+    /// ### Options
+    ///
+    /// The first option is one of:
+    ///
+    /// - `"all"` _(default)_: always require braces.
+    /// - `"multi"`: disallow braces around bodies with a single statement.
+    /// - `"multi-line"`: allow brace-less bodies only when they fit on the
+    ///   same line as the statement.
+    /// - `"multi-or-nest"`: require braces when the body spans several lines,
+    ///   and disallow them when it fits on a single line.
+    ///
+    /// With a second `"consistent"` option, all branches of an `if`/`else`
+    /// chain must either use braces or not.
+    ///
+    /// Braces are never removed when they are needed, e.g. around `let`
+    /// declarations or a nested `if` followed by an `else`. Removing braces is
+    /// only suggested when it would change how semicolons are inserted
+    /// automatically, e.g. in `if (foo) { bar() }` followed by a line starting
+    /// with `(`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// if (foo) foo++;
+    ///
+    /// while (bar)
+    ///     baz();
+    ///
+    /// if (foo) {
+    ///     baz();
+    /// } else qux();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// if (foo) {
+    ///     foo++;
+    /// }
+    ///
+    /// while (bar) {
+    ///     baz();
+    /// }
+    ///
+    /// if (foo) {
+    ///     baz();
+    /// } else {
+    ///     qux();
+    /// }
+    /// ```
+    Curly,
+    style,
+    conditional_fix_suggestion
+);
+
+/// The body of a control statement, and whether it should be wrapped in braces.
+struct Check<'s, 'a> {
+    keyword: &'static str,
+    condition: bool,
+    body: &'s Statement<'a>,
+    expected: Option<bool>,
+}
+
+impl Check<'_, '_> {
+    fn actual(&self) -> bool {
+        matches!(self.body, Statement::BlockStatement(_))
+    }
+}
+
+impl Rule for Curly {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let option = value
+            .get(0)
+            .and_then(serde_json::Value::as_str)
+            .and_then(CurlyOption::from)
+            .unwrap_or_default();
+        let consistent = value.get(1).and_then(serde_json::Value::as_str) == Some("consistent");
+        Self { option, consistent }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let checks = match node.kind() {
+            AstKind::IfStatement(if_stmt) => {
+                // `else if` branches are checked with the `if` starting the chain
+                if let Some(AstKind::IfStatement(parent)) = ctx.nodes().parent_kind(node.id()) {
+                    if parent.alternate.as_ref().is_some_and(|alt| alt.span() == if_stmt.span) {
+                        return;
+                    }
+                }
+                self.if_checks(if_stmt, ctx)
+            }
+            AstKind::WhileStatement(stmt) => vec![self.check("while", true, &stmt.body, ctx)],
+            AstKind::DoWhileStatement(stmt) => vec![self.check("do", false, &stmt.body, ctx)],
+            AstKind::ForStatement(stmt) => vec![self.check("for", true, &stmt.body, ctx)],
+            AstKind::ForInStatement(stmt) => vec![self.check("for-in", false, &stmt.body, ctx)],
+            AstKind::ForOfStatement(stmt) => vec![self.check("for-of", false, &stmt.body, ctx)],
+            _ => return,
+        };
+
+        for check in checks {
+            report(&check, ctx);
+        }
+    }
+}
+
+impl Curly {
+    fn if_checks<'s, 'a>(
+        &self,
+        if_stmt: &'s IfStatement<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Vec<Check<'s, 'a>> {
+        let mut checks = vec![];
+        let mut current = if_stmt;
+        loop {
+            checks.push(self.check("if", true, &current.consequent, ctx));
+            match &current.alternate {
+                Some(Statement::IfStatement(alternate)) => current = alternate,
+                Some(alternate) => {
+                    checks.push(self.check("else", false, alternate, ctx));
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        if self.consistent {
+            let expected =
+                checks.iter().any(|check| check.expected.unwrap_or_else(|| check.actual()));
+            for check in &mut checks {
+                check.expected = Some(expected);
+            }
+        }
+        checks
+    }
+
+    fn check<'s, 'a>(
+        &self,
+        keyword: &'static str,
+        condition: bool,
+        body: &'s Statement<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Check<'s, 'a> {
+        Check { keyword, condition, body, expected: self.expected(body, ctx) }
+    }
+
+    /// `Some(true)` if `body` must be wrapped in braces, `Some(false)` if it
+    /// must not be, and `None` if both are allowed.
+    fn expected(&self, body: &Statement, ctx: &LintContext) -> Option<bool> {
+        if let Statement::BlockStatement(block) = body {
+            if block.body.len() != 1 || are_braces_necessary(block, ctx) {
+                return Some(true);
+            }
+        }
+
+        match self.option {
+            CurlyOption::All => Some(true),
+            CurlyOption::Multi => Some(false),
+            CurlyOption::MultiLine => (!is_collapsed_one_liner(body, ctx)).then_some(true),
+            CurlyOption::MultiOrNest => match body {
+                Statement::BlockStatement(block) if is_one_liner(&block.body[0], ctx) => {
+                    let statement_start = block.body[0].span().start;
+                    let has_leading_comment = ctx.comments().iter().any(|comment| {
+                        block.span.start < comment.span.start && comment.span.end <= statement_start
+                    });
+                    Some(has_leading_comment)
+                }
+                _ => (!is_one_liner(body, ctx)).then_some(true),
+            },
+        }
+    }
+}
+
+fn report(check: &Check, ctx: &LintContext) {
+    let Some(expected) = check.expected else {
+        return;
+    };
+    if expected == check.actual() {
+        return;
+    }
+
+    let span = check.body.span();
+    if expected {
+        ctx.diagnostic_with_fix(
+            missing_curly_diagnostic(check.keyword, check.condition, span),
+            |fixer| fixer.replace(span, format!("{{{}}}", ctx.source_range(span))),
+        );
+        return;
+    }
+
+    let Statement::BlockStatement(block) = check.body else {
+        return;
+    };
+    let diagnostic = unexpected_curly_diagnostic(check.keyword, check.condition, span);
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let body = ctx.source_range(Span::new(block.span.start + 1, block.span.end - 1));
+        // `do{foo()}\nwhile (x)` becomes `do foo()\nwhile (x)`, not `dofoo()`
+        let follows_keyword = ctx.source_text()[..block.span.start as usize]
+            .chars()
+            .next_back()
+            .is_some_and(is_identifier_part);
+        let fix = if follows_keyword && body.chars().next().is_some_and(is_identifier_part) {
+            fixer.replace(span, format!(" {body}"))
+        } else {
+            fixer.replace(span, body)
+        };
+        // e.g. `if (a) { b() } c()` or `if (a) { b() }\n(c)`
+        if may_join_next_statement(block, ctx) {
+            fixer.check_asi(fix)
+        } else {
+            fix
+        }
+    });
+}
+
+/// Whether removing the braces of `block` may join its last statement with
+/// the code after it: when that code is on the same line, or starts with a
+/// token which can continue an expression. Only then is the fix checked with
+/// the slower `RuleFixer::check_asi`.
+fn may_join_next_statement(block: &BlockStatement, ctx: &LintContext) -> bool {
+    let closing = Span::new(block.span.end - 1, block.span.end);
+    let Some(token_after) = ctx.find_token_after(closing) else {
+        return false;
+    };
+    !has_line_break(block.span.end, token_after.start, ctx)
+        || matches!(ctx.source_range(token_after), "(" | "[" | "`" | "+" | "-" | "/")
+}
+
+/// Whether removing the braces of `block` would change its meaning, e.g.
+/// `if (a) { if (b) foo(); } else bar();` or `if (a) { let b; }`.
+fn are_braces_necessary(block: &BlockStatement, ctx: &LintContext) -> bool {
+    let statement = &block.body[0];
+    is_lexical_declaration(statement)
+        || (has_unsafe_if(statement) && is_followed_by_else_keyword(block, ctx))
+}
+
+fn is_lexical_declaration(statement: &Statement) -> bool {
+    match statement {
+        Statement::VariableDeclaration(decl) => decl.kind != VariableDeclarationKind::Var,
+        Statement::FunctionDeclaration(_) | Statement::ClassDeclaration(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether `statement` ends with an `if` without an `else`, which would take
+/// over an `else` following it.
+fn has_unsafe_if(statement: &Statement) -> bool {
+    match statement {
+        Statement::IfStatement(stmt) => stmt.alternate.as_ref().map_or(true, has_unsafe_if),
+        Statement::ForStatement(stmt) => has_unsafe_if(&stmt.body),
+        Statement::ForInStatement(stmt) => has_unsafe_if(&stmt.body),
+        Statement::ForOfStatement(stmt) => has_unsafe_if(&stmt.body),
+        Statement::LabeledStatement(stmt) => has_unsafe_if(&stmt.body),
+        Statement::WithStatement(stmt) => has_unsafe_if(&stmt.body),
+        Statement::WhileStatement(stmt) => has_unsafe_if(&stmt.body),
+        _ => false,
+    }
+}
+
+fn is_followed_by_else_keyword(block: &BlockStatement, ctx: &LintContext) -> bool {
+    ctx.find_token_after(block.span).is_some_and(|token| {
+        let rest = &ctx.source_text()[token.start as usize..];
+        rest.strip_prefix("else")
+            .is_some_and(|after| !after.chars().next().is_some_and(is_identifier_part))
+    })
+}
+
+/// Whether `statement` fits on a single line, ignoring a trailing semicolon.
+fn is_one_liner(statement: &Statement, ctx: &LintContext) -> bool {
+    if matches!(statement, Statement::EmptyStatement(_)) {
+        return true;
+    }
+    let span = statement.span();
+    !has_line_break(span.start, end_excluding_semicolon(span, ctx), ctx)
+}
+
+/// Whether `body` is on the same line as the token preceding it, e.g. the
+/// `)` of an `if` condition.
+fn is_collapsed_one_liner(body: &Statement, ctx: &LintContext) -> bool {
+    let span = body.span();
+    let Some(before) = ctx.find_token_before(span) else {
+        return true;
+    };
+    !has_line_break(before.start, end_excluding_semicolon(span, ctx), ctx)
+}
+
+fn end_excluding_semicolon(span: Span, ctx: &LintContext) -> u32 {
+    let last = Span::new(span.end - 1, span.end);
+    if ctx.source_range(last) != ";" {
+        return span.end;
+    }
+    ctx.find_token_before(last).map_or(span.start, |token| token.end.max(span.start))
+}
+
+fn has_line_break(start: u32, end: u32, ctx: &LintContext) -> bool {
+    start < end && ctx.source_range(Span::new(start, end)).chars().any(is_line_terminator)
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, FixKind};
+    use serde_json::json;
+
+    let pass = vec![
+        ("if (foo) { bar() }", None),
+        ("if (foo) { bar() } else if (foo2) { baz() }", None),
+        ("while (foo) { bar() }", None),
+        ("do { bar(); } while (foo)", None),
+        ("for (;foo;) { bar() }", None),
+        ("for (var foo in bar) { console.log(foo) }", None),
+        ("for (var foo of bar) { console.log(foo) }", None),
+        ("for (;foo;) bar()", Some(json!(["multi"]))),
+        ("if (foo) bar()", Some(json!(["multi"]))),
+        ("if (a) { b; c; }", Some(json!(["multi"]))),
+        ("if (a) {}", Some(json!(["multi"]))),
+        ("while (foo) bar()", Some(json!(["multi"]))),
+        ("do bar(); while (foo)", Some(json!(["multi"]))),
+        ("if (foo) { const bar = 'baz'; }", Some(json!(["multi"]))),
+        ("if (foo) { let bar; } else baz()", Some(json!(["multi"]))),
+        ("while (foo) { function bar() {} }", Some(json!(["multi"]))),
+        ("for (;;) { class Foo {} }", Some(json!(["multi"]))),
+        ("if (a) { if (b) foo(); } else bar();", Some(json!(["multi"]))),
+        ("if (a) { while (cond) if (b) foo(); } else bar();", Some(json!(["multi"]))),
+        ("if (a) { if (b) foo(); else if (c) bar(); } else baz();", Some(json!(["multi"]))),
+        ("if (a) { b(); c() } else if (d) { e() }", Some(json!(["multi", "consistent"]))),
+        ("if (foo) bar(); else baz();", Some(json!(["multi", "consistent"]))),
+        ("if (a) { b; c; } else { d(); }", Some(json!(["multi", "consistent"]))),
+        ("if (foo) bar()", Some(json!(["multi-line"]))),
+        ("if (foo) bar() \n", Some(json!(["multi-line"]))),
+        ("if (foo) bar(); else baz()", Some(json!(["multi-line"]))),
+        ("if (foo) bar(); else if (baz) qux()", Some(json!(["multi-line"]))),
+        ("do bar(); while (foo)", Some(json!(["multi-line"]))),
+        ("if (foo) { \n bar(); \n }", Some(json!(["multi-line"]))),
+        ("for (var foo in bar) console.log(foo)", Some(json!(["multi-line"]))),
+        (
+            "for (var foo in bar) { \n console.log(1); \n console.log(2) \n }",
+            Some(json!(["multi-line"])),
+        ),
+        ("if (foo) \n bar()", Some(json!(["multi-or-nest"]))),
+        (
+            "if (foo) { \n quz = { \n bar: baz, \n qux: foo \n }; \n }",
+            Some(json!(["multi-or-nest"])),
+        ),
+        (
+            "while (true) { \n if (foo) \n doSomething(); \n else \n doSomethingElse(); \n }",
+            Some(json!(["multi-or-nest"])),
+        ),
+        ("if (foo) { \n // comment \n bar(); \n }", Some(json!(["multi-or-nest"]))),
+        ("for (var foo of bar) console.log(foo)", Some(json!(["multi-or-nest"]))),
+        ("if (foo) \n quz = true;", Some(json!(["multi-or-nest", "consistent"]))),
+    ];
+
+    let fail = vec![
+        ("if (foo) bar()", None),
+        ("if (foo) \n bar()", None),
+        ("if (foo) { bar() } else baz()", None),
+        ("if (foo) { bar() } else if (faa) baz()", None),
+        ("while (foo) bar()", None),
+        ("do bar(); while (foo)", None),
+        ("for (;foo;) bar()", None),
+        ("for (var foo in bar) console.log(foo)", None),
+        ("for (var foo of bar) console.log(foo)", None),
+        ("if (foo) { bar() }", Some(json!(["multi"]))),
+        ("if (foo) { bar() } else if (faa) { baz() }", Some(json!(["multi"]))),
+        ("while (foo) { bar() }", Some(json!(["multi"]))),
+        ("do { bar() } while (foo)", Some(json!(["multi"]))),
+        ("for (;foo;) { bar() }", Some(json!(["multi"]))),
+        ("if (foo) { var bar = 'baz'; }", Some(json!(["multi"]))),
+        ("if (a) { if (b) foo(); }", Some(json!(["multi"]))),
+        ("if (a) { if (b) foo(); else bar(); } else baz();", Some(json!(["multi"]))),
+        ("if (foo) { bar() } baz()", Some(json!(["multi"]))),
+        ("if (foo) { bar() }\n(baz)", Some(json!(["multi"]))),
+        ("if (foo) { bar++ }\n+baz", Some(json!(["multi"]))),
+        ("if (foo) \n bar()", Some(json!(["multi-line"]))),
+        ("if (foo) bar(); else \n baz()", Some(json!(["multi-line"]))),
+        ("do \n bar(); \n while (foo)", Some(json!(["multi-line"]))),
+        ("for (var foo in bar) \n console.log(foo)", Some(json!(["multi-line"]))),
+        ("if (foo) \n quz = { \n bar: baz, \n qux: foo \n };", Some(json!(["multi-or-nest"]))),
+        ("if (foo) { var bar = 'baz'; }", Some(json!(["multi-or-nest"]))),
+        ("while (foo) { \n bar(); \n }", Some(json!(["multi-or-nest"]))),
+        ("for (var foo of bar) { console.log(foo) }", Some(json!(["multi-or-nest"]))),
+        ("if (foo) bar(); else { baz(); qux(); }", Some(json!(["multi", "consistent"]))),
+        ("if (foo) { bar(); } else baz();", Some(json!(["multi-line", "consistent"]))),
+        ("if (a) { b(); } else if (c) d(); else e();", Some(json!(["multi", "consistent"]))),
+        ("if (a) { b() } else if (c) { d() } else { e() }", Some(json!(["multi", "consistent"]))),
+    ];
+
+    let fix = vec![
+        ("if (foo) bar()", "if (foo) {bar()}", None, FixKind::Fix),
+        ("if (foo) \n bar()", "if (foo) \n {bar()}", None, FixKind::Fix),
+        ("if (foo) bar(); // comment", "if (foo) {bar();} // comment", None, FixKind::Fix),
+        ("if (foo) { bar() } else baz()", "if (foo) { bar() } else {baz()}", None, FixKind::Fix),
+        ("while (foo) bar()", "while (foo) {bar()}", None, FixKind::Fix),
+        ("do bar(); while (foo)", "do {bar();} while (foo)", None, FixKind::Fix),
+        (
+            "for (var foo of bar) console.log(foo)",
+            "for (var foo of bar) {console.log(foo)}",
+            None,
+            FixKind::Fix,
+        ),
+        ("if (foo) { bar() }", "if (foo)  bar() ", Some(json!(["multi"])), FixKind::Fix),
+        ("if (foo) { bar(); }", "if (foo)  bar(); ", Some(json!(["multi"])), FixKind::Fix),
+        (
+            "if (foo) { /* comment */ bar(); }",
+            "if (foo)  /* comment */ bar(); ",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) { // comment\n bar(); }",
+            "if (foo)  // comment\n bar(); ",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) {bar()} else {baz()}",
+            "if (foo) {bar()} else baz()",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) { bar(); } else {baz()}",
+            "if (foo)  bar();  else baz()",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        ("do{bar()}\nwhile (foo)", "do bar()\nwhile (foo)", Some(json!(["multi"])), FixKind::Fix),
+        (
+            "if (foo) { bar() }\nbaz()",
+            "if (foo)  bar() \nbaz()",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        ("if (foo) { bar++ }\nbaz", "if (foo)  bar++ \nbaz", Some(json!(["multi"])), FixKind::Fix),
+        (
+            "if (foo) { for (;;) {} } baz()",
+            "if (foo)  for (;;) {}  baz()",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "function f() { if (foo) { return }\nbar() }",
+            "function f() { if (foo)  return \nbar() }",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        // removing the braces would not parse, so no fix is offered
+        (
+            "if (foo) { bar() } baz()",
+            "if (foo) { bar() } baz()",
+            Some(json!(["multi"])),
+            FixKind::All,
+        ),
+        (
+            "if (foo) { bar() } else baz()",
+            "if (foo) { bar() } else baz()",
+            Some(json!(["multi"])),
+            FixKind::All,
+        ),
+        (
+            "do { bar() } while (foo)",
+            "do { bar() } while (foo)",
+            Some(json!(["multi"])),
+            FixKind::All,
+        ),
+        // removing the braces would join the next line to the statement, so it is only suggested
+        (
+            "if (foo) { bar() }\n(baz)",
+            "if (foo) { bar() }\n(baz)",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) { bar() }\n(baz)",
+            "if (foo)  bar() \n(baz)",
+            Some(json!(["multi"])),
+            FixKind::Suggestion,
+        ),
+        (
+            "if (foo) { bar() }\n[baz]",
+            "if (foo) { bar() }\n[baz]",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) { bar() }\n`baz`",
+            "if (foo) { bar() }\n`baz`",
+            Some(json!(["multi"])),
+            FixKind::Fix,
+        ),
+        ("if (foo) \n bar()", "if (foo) \n {bar()}", Some(json!(["multi-line"])), FixKind::Fix),
+        (
+            "while (foo) { \n bar(); \n }",
+            "while (foo)  \n bar(); \n ",
+            Some(json!(["multi-or-nest"])),
+            FixKind::Fix,
+        ),
+        (
+            "if (foo) bar(); else { baz(); qux(); }",
+            "if (foo) {bar();} else { baz(); qux(); }",
+            Some(json!(["multi", "consistent"])),
+            FixKind::Fix,
+        ),
+    ];
+
+    Tester::new(Curly::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
+
+#[test]
+fn test_large_input() {
+    use std::{
+        path::Path,
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use crate::{fixer::Fixer, FixKind, LinterBuilder, Oxlintrc};
+
+    // Half of the fixes are checked for ASI hazards, which must not parse the whole file again.
+    // Those are only suggested, so safe fixes leave them in place.
+    let source_text = "if (foo) { bar() }\n(baz)\nif (foo) { bar() }\nbaz()\n".repeat(2500);
+    let config = json!({ "rules": { "curly": ["error", "multi"] } });
+    let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+    let linter =
+        LinterBuilder::from_oxlintrc(true, oxlintrc).unwrap().with_fix(FixKind::SafeFix).build();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::mjs()).parse();
+    let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+
+    let start = Instant::now();
+    let messages = linter.run(Path::new("test.mjs"), Rc::new(semantic));
+    let elapsed = start.elapsed();
+
+    assert_eq!(messages.len(), 5000);
+    let fixed = Fixer::new(&source_text, messages).fix().fixed_code;
+    assert_eq!(fixed, "if (foo) { bar() }\n(baz)\nif (foo)  bar() \nbaz()\n".repeat(2500));
+    assert!(elapsed < Duration::from_secs(10), "linting took {elapsed:?}");
+}
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     DefaultCaseLast,
-    style
+    style,
+    node_types = [SwitchStatement]
 );
 
 impl Rule for DefaultCaseLast {
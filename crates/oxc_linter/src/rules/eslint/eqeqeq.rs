@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     Eqeqeq,
     pedantic,
-    conditional_fix
+    conditional_fix,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for Eqeqeq {
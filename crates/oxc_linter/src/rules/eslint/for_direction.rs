@@ -78,7 +78,8 @@ declare_oxc_lint!(
     /// ```
     ForDirection,
     correctness,
-    fix_dangerous
+    fix_dangerous,
+    node_types = [ForStatement]
 );
 
 impl Rule for ForDirection {
@@ -142,7 +142,8 @@ declare_oxc_lint!(
     /// ```
     FuncNames,
     style,
-    conditional_fix_suggestion
+    conditional_fix_suggestion,
+    node_types = []
 );
 
 /// Determines whether the current FunctionExpression node is a get, set, or
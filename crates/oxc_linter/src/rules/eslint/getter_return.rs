@@ -5,13 +5,6 @@ use oxc_ast::{
     },
     AstKind,
 };
-use oxc_cfg::{
-    graph::{
-        visit::{set_depth_first_search, Control, DfsEvent},
-        Direction,
-    },
-    EdgeType, ErrorEdgeKind, InstructionKind, ReturnInstructionKind,
-};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -208,9 +201,6 @@ impl GetterReturn {
             return;
         }
 
-        let cfg = ctx.cfg();
-
-        let graph = cfg.graph();
         let definitely_returns_in_all_codepaths = 'returns: {
             // The expression is the equivalent of return.
             // Therefore, if a function is an expression, it always returns its value.
@@ -233,72 +223,7 @@ impl GetterReturn {
                     }
                 }
             }
-            let output = set_depth_first_search(graph, Some(node.cfg_id()), |event| {
-                match event {
-                    // We only need to check paths that are normal or jump.
-                    DfsEvent::TreeEdge(a, b) => {
-                        let edges = graph.edges_connecting(a, b).collect::<Vec<_>>();
-                        if edges.iter().any(|e| {
-                            matches!(
-                                e.weight(),
-                                EdgeType::Normal
-                                    | EdgeType::Jump
-                                    | EdgeType::Error(ErrorEdgeKind::Explicit)
-                            )
-                        }) {
-                            Control::Continue
-                        } else {
-                            Control::Prune
-                        }
-                    }
-                    DfsEvent::Discover(basic_block_id, _) => {
-                        let return_instruction =
-                            cfg.basic_block(basic_block_id).instructions().iter().find(|it| {
-                                match it.kind {
-                                    // Throws are classified as returning.
-                                    InstructionKind::Return(_) | InstructionKind::Throw => true,
-                                    // Ignore irrelevant elements.
-                                    InstructionKind::Break(_)
-                                    | InstructionKind::Continue(_)
-                                    | InstructionKind::Iteration(_)
-                                    | InstructionKind::Unreachable
-                                    | InstructionKind::Condition
-                                    | InstructionKind::Statement => false,
-                                }
-                            });
-
-                        let does_return = return_instruction.is_some_and(|ret| {
-                            !matches! { ret.kind,
-                            InstructionKind::Return(ReturnInstructionKind::ImplicitUndefined)
-                                if !self.allow_implicit
-                            }
-                        });
-
-                        // Return true as the second argument to signify we should
-                        // continue walking this branch, as we haven't seen anything
-                        // that will signify to us that this path of the program will
-                        // definitely return or throw.
-                        if graph.edges_directed(basic_block_id, Direction::Outgoing).any(|e| {
-                            matches!(
-                                e.weight(),
-                                EdgeType::Jump
-                                    | EdgeType::Normal
-                                    | EdgeType::Backedge
-                                    | EdgeType::Error(ErrorEdgeKind::Explicit)
-                            )
-                        }) {
-                            Control::Continue
-                        } else if does_return {
-                            Control::Prune
-                        } else {
-                            Control::Break(())
-                        }
-                    }
-                    _ => Control::Continue,
-                }
-            });
-
-            output.break_value().is_none()
+            ctx.cfg().always_returns(node.cfg_id(), self.allow_implicit)
         };
 
         if !definitely_returns_in_all_codepaths {
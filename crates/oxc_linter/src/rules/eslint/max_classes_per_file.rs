@@ -53,6 +53,7 @@ declare_oxc_lint!(
     /// ```
     MaxClassesPerFile,
     pedantic,
+    node_types = []
 );
 
 impl Rule for MaxClassesPerFile {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// file, most people would agree it should not be in the thousands.
     /// Recommendations usually range from 100 to 500 lines.
     MaxLines,
-    pedantic
+    pedantic,
+    node_types = []
 );
 
 impl Rule for MaxLines {
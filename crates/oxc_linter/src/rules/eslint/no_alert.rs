@@ -50,6 +50,7 @@ declare_oxc_lint!(
     /// ```
     NoAlert,
     restriction,
+    node_types = [CallExpression]
 );
 
 const GLOBAL_THIS: &str = "globalThis";
@@ -1,7 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -42,7 +42,7 @@ declare_oxc_lint!(
     /// ```
     NoArrayConstructor,
     pedantic,
-    pending
+    fix
 );
 
 impl Rule for NoArrayConstructor {
@@ -70,9 +70,48 @@ impl Rule for NoArrayConstructor {
             && type_parameters.is_none()
             && !optional
         {
-            ctx.diagnostic(no_array_constructor_diagnostic(span));
+            let diagnostic = no_array_constructor_diagnostic(span);
+            // `Array` may be shadowed by something which isn't equivalent to a literal
+            let Expression::Identifier(ident) = callee.get_inner_expression() else {
+                ctx.diagnostic(diagnostic);
+                return;
+            };
+            if !ctx.semantic().is_reference_to_global_variable(ident) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                let after_callee =
+                    Span::new(callee.span().end, span.end).source_text(ctx.source_text());
+                let elements = after_callee
+                    .find('(')
+                    .map_or("", |start| &after_callee[start + 1..after_callee.len() - 1]);
+                let prefix = if needs_leading_semicolon(node, ctx) { ";" } else { "" };
+                fixer.replace(span, format!("{prefix}[{elements}]"))
+            });
+        }
+    }
+}
+
+/// Whether replacing `node` with an array literal would make it continue the previous
+/// statement, e.g. `foo\nArray(a, b)` becoming `foo\n[a, b]`.
+fn needs_leading_semicolon(node: &AstNode, ctx: &LintContext) -> bool {
+    let start = node.kind().span().start;
+    for ancestor in ctx.nodes().ancestors(node.id()).skip(1) {
+        let kind = ctx.nodes().kind(ancestor);
+        if kind.span().start != start {
+            return false;
+        }
+        if matches!(kind, AstKind::ExpressionStatement(_)) {
+            break;
         }
     }
+    ctx.source_range(Span::new(0, start))
+        .trim_end()
+        .chars()
+        .next_back()
+        .is_some_and(|c| !matches!(c, ';' | '{'))
 }
 
 #[test]
@@ -114,7 +153,24 @@ fn test() {
         ("new Array(0, 1, 2)", None),
         ("Array(x, y)", None),
         ("Array(0, 1, 2)", None),
+        ("foo\nArray(a, b)", None),
+        ("let Array; new Array(a, b)", None),
+    ];
+
+    let fix = vec![
+        ("new Array()", "[]"),
+        ("new Array", "[]"),
+        ("Array();", "[];"),
+        ("new Array(x, y)", "[x, y]"),
+        ("Array(0, 1, 2)", "[0, 1, 2]"),
+        ("new Array(\n    a, // first\n    b,\n)", "[\n    a, // first\n    b,\n]"),
+        ("const a = Array(...b, c);", "const a = [...b, c];"),
+        ("foo\nArray(a, b)", "foo\n;[a, b]"),
+        ("foo;\nArray(a, b).forEach(bar)", "foo;\n[a, b].forEach(bar)"),
+        ("foo\nnew Array(a, b).forEach(bar)", "foo\n;[a, b].forEach(bar)"),
+        ("{ Array(a, b) }", "{ [a, b] }"),
+        ("let Array; new Array(a, b)", "let Array; new Array(a, b)"),
     ];
 
-    Tester::new(NoArrayConstructor::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayConstructor::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
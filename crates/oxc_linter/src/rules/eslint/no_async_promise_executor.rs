@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// - If an async executor function throws an error, the error will be lost and won’t cause the newly-constructed `Promise` to reject.This could make it difficult to debug and handle some errors.
     /// - If a Promise executor function is using `await`, this is usually a sign that it is not actually necessary to use the `new Promise` constructor, or the scope of the `new Promise` constructor can be reduced.
     NoAsyncPromiseExecutor,
-    correctness
+    correctness,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoAsyncPromiseExecutor {
@@ -50,7 +51,10 @@ impl Rule for NoAsyncPromiseExecutor {
         let AstKind::NewExpression(new_expression) = node.kind() else {
             return;
         };
-        if !new_expression.callee.is_specific_id("Promise") {
+        let Expression::Identifier(ident) = &new_expression.callee else {
+            return;
+        };
+        if ident.name != "Promise" || !ctx.semantic().is_reference_to_global_variable(ident) {
             return;
         }
         let Some(expression) = new_expression.arguments.first().and_then(Argument::as_expression)
@@ -77,6 +81,8 @@ fn test() {
         ("new Promise((resolve, reject) => {})", None),
         ("new Promise((resolve, reject) => {}, async function unrelated() {})", None),
         ("new Foo(async (resolve, reject) => {})", None),
+        ("class Promise {} new Promise(async (resolve, reject) => {})", None),
+        ("function foo(Promise) { new Promise(async (resolve, reject) => {}) }", None),
     ];
 
     let fail = vec![
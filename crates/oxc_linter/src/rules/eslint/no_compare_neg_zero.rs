@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// ```
     NoCompareNegZero,
     correctness,
-    conditional_fix_suggestion
+    conditional_fix_suggestion,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for NoCompareNegZero {
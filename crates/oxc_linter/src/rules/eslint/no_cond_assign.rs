@@ -75,7 +75,13 @@ impl Rule for NoCondAssign {
                 }
             }
             AstKind::ConditionalExpression(expr) => {
-                self.check_expression(ctx, expr.test.get_inner_expression());
+                // Unlike the test of a statement, the test of a conditional expression isn't
+                // already wrapped in parentheses, so one pair doesn't count.
+                let test = match &expr.test {
+                    Expression::ParenthesizedExpression(paren) => &paren.expression,
+                    test => test,
+                };
+                self.check_expression(ctx, test);
             }
             AstKind::AssignmentExpression(expr) if self.config == NoCondAssignConfig::Always => {
                 let mut spans = vec![];
@@ -94,15 +100,9 @@ impl Rule for NoCondAssign {
                             if let Some(test) = &for_stmt.test {
                                 spans.push(test.span());
                             }
-                            if let Some(update) = &for_stmt.update {
-                                spans.push(update.span());
-                            }
-                            if let Some(update) = &for_stmt.update {
-                                spans.push(update.span());
-                            }
                         }
                         AstKind::ConditionalExpression(cond_expr) => {
-                            spans.push(cond_expr.span());
+                            spans.push(cond_expr.test.span());
                         }
                         AstKind::Function(_)
                         | AstKind::ArrowFunctionExpression(_)
@@ -225,6 +225,9 @@ fn test() {
         ("for (; (typeof l === 'undefined' ? (l = 0) : l); i++) { }", None),
         ("for (x = 0;x<10;x++) { x = 0 }", None),
         ("for (x = 0;x<10;(x = x + 1)) { x = 0 }", None),
+        ("for (;x;x = x.next) { }", Some(serde_json::json!(["always"]))),
+        ("var b = x ? (y = 1) : (y = 2);", Some(serde_json::json!(["always"]))),
+        ("var b = ((x = 0)) ? 1 : 0;", None),
     ];
 
     let fail = vec![
@@ -254,6 +257,7 @@ fn test() {
         ("var x; var b = (x = 0) ? 1 : 0;", None),
         ("var x; var b = x && (y = 0) ? 1 : 0;", Some(serde_json::json!(["always"]))),
         ("(((3496.29)).bkufyydt = 2e308) ? foo : bar;", None),
+        ("var b = ((x = 0)) ? 1 : 0;", Some(serde_json::json!(["always"]))),
     ];
 
     Tester::new(NoCondAssign::NAME, pass, fail).test_and_snapshot();
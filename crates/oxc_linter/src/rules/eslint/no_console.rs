@@ -1,7 +1,12 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{Expression, ExpressionStatement},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, Span};
+use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -12,7 +17,8 @@ fn no_console_diagnostic(span: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoConsole(Box<NoConsoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoConsoleConfig {
     /// A list of methods allowed to be used.
     ///
@@ -46,8 +52,20 @@ declare_oxc_lint!(
     /// ```javascript
     /// console.log('here');
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// This rule accepts an object with an `allow` array of `console` methods
+    /// that may still be called:
+    ///
+    /// ```json
+    /// { "no-console": ["error", { "allow": ["warn", "error"] }] }
+    /// ```
     NoConsole,
-    restriction
+    restriction,
+    conditional_suggestion,
+    config = NoConsoleConfig,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoConsole {
@@ -79,13 +97,69 @@ impl Rule for NoConsole {
             && ident.name == "console"
             && !self.allow.iter().any(|s| mem.static_property_name().is_some_and(|f| f == s))
         {
-            if let Some(mem) = mem.static_property_info() {
-                ctx.diagnostic(no_console_diagnostic(mem.0));
+            let Some((span, _)) = mem.static_property_info() else {
+                return;
+            };
+            let diagnostic = no_console_diagnostic(span);
+            match get_expression_statement(node, ctx) {
+                Some((statement, is_sole_body)) => {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        // `if (foo) console.log()` needs an empty block to stay valid
+                        if is_sole_body {
+                            fixer
+                                .replace(statement.span, "{}")
+                                .with_message("Remove the console call")
+                        } else {
+                            fixer.delete(&statement.span).with_message("Remove the console call")
+                        }
+                    });
+                }
+                None => ctx.diagnostic(diagnostic),
             }
         }
     }
 }
 
+/// Returns the expression statement if the call is the entire statement, along
+/// with whether that statement is the sole body of a control-flow statement.
+///
+/// The body of an arrow function like `() => console.log()` is an expression
+/// statement too, but it can't be removed.
+fn get_expression_statement<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a ExpressionStatement<'a>, bool)> {
+    let mut parents = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .filter(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+    let AstKind::ExpressionStatement(statement) = parents.next()?.kind() else {
+        return None;
+    };
+    if statement.expression.without_parentheses().span() != node.kind().span() {
+        return None;
+    }
+    let parent = parents.next()?.kind();
+    if matches!(parent, AstKind::FunctionBody(_))
+        && matches!(parents.next()?.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+    {
+        return None;
+    }
+    let is_sole_body = matches!(
+        parent,
+        AstKind::IfStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::DoWhileStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::LabeledStatement(_)
+            | AstKind::WithStatement(_)
+    );
+    Some((statement, is_sole_body))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -120,5 +194,20 @@ fn test() {
         ("console.warn(foo)", Some(serde_json::json!([{ "allow": ["info", "log"] }]))),
     ];
 
-    Tester::new(NoConsole::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("console.log(foo)", "", None),
+        ("foo(); console.log(foo); bar();", "foo();  bar();", None),
+        ("(console.log(foo))", "", None),
+        ("if (foo) console.log(foo)", "if (foo) {}", None),
+        ("function f() { console.error(foo); }", "function f() {  }", None),
+        ("const f = () => { console.log(1); };", "const f = () => {  };", None),
+        ("console.log(foo)", "", Some(serde_json::json!([{ "allow": ["info"] }]))),
+        // not the entire statement
+        ("const x = console.log(foo)", "const x = console.log(foo)", None),
+        ("foo(console.log(foo))", "foo(console.log(foo))", None),
+        ("const f = () => console.log(1);", "const f = () => console.log(1);", None),
+        ("const f = () => (console.log(1));", "const f = () => (console.log(1));", None),
+    ];
+
+    Tester::new(NoConsole::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,16 +1,13 @@
 use itertools::Itertools as _;
-use oxc_allocator::Allocator;
-use oxc_ast::{ast::Argument, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_regular_expression::{
     ast::{CapturingGroup, Character, Pattern},
     visit::{walk, Visit},
-    ConstructorParser, Options,
 };
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::run_on_regex_node, AstNode};
 
 fn no_control_regex_diagnostic(count: usize, regex: &str, span: Span) -> OxcDiagnostic {
     debug_assert!(count > 0);
@@ -71,78 +68,15 @@ declare_oxc_lint!(
 
 impl Rule for NoControlRegex {
     fn run<'a>(&self, node: &AstNode<'a>, context: &LintContext<'a>) {
-        match node.kind() {
-            // regex literal
-            AstKind::RegExpLiteral(reg) => {
-                let Some(pattern) = reg.regex.pattern.as_pattern() else {
-                    return;
-                };
-
-                check_pattern(context, pattern, reg.span);
-            }
-
-            // new RegExp()
-            AstKind::NewExpression(expr) if expr.callee.is_specific_id("RegExp") => {
-                // note: improvements required for strings used via identifier references
-                // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
-                match (&expr.arguments.first(), &expr.arguments.get(1)) {
-                    (
-                        Some(Argument::StringLiteral(pattern)),
-                        Some(Argument::StringLiteral(flags)),
-                    ) => {
-                        parse_and_check_regex(context, pattern.span, Some(flags.span));
-                    }
-                    (Some(Argument::StringLiteral(pattern)), _) => {
-                        parse_and_check_regex(context, pattern.span, None);
-                    }
-                    _ => {}
-                }
-            }
-
-            // RegExp()
-            AstKind::CallExpression(expr) if expr.callee.is_specific_id("RegExp") => {
-                // note: improvements required for strings used via identifier references
-                // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
-                match (&expr.arguments.first(), &expr.arguments.get(1)) {
-                    (
-                        Some(Argument::StringLiteral(pattern)),
-                        Some(Argument::StringLiteral(flags)),
-                    ) => {
-                        parse_and_check_regex(context, pattern.span, Some(flags.span));
-                    }
-                    (Some(Argument::StringLiteral(pattern)), _) => {
-                        parse_and_check_regex(context, pattern.span, None);
-                    }
-                    _ => {}
-                }
-            }
-
-            _ => {}
-        };
+        // note: improvements required for strings used via identifier references
+        run_on_regex_node(node, context, |pattern, span| {
+            check_pattern(context, pattern, span);
+        });
     }
 }
 
-fn parse_and_check_regex(ctx: &LintContext, pattern_span: Span, flags_span: Option<Span>) {
-    let allocator = Allocator::default();
-
-    let flags_text = flags_span.map(|span| span.source_text(ctx.source_text()));
-    let parser = ConstructorParser::new(
-        &allocator,
-        pattern_span.source_text(ctx.source_text()),
-        flags_text,
-        Options {
-            pattern_span_offset: pattern_span.start,
-            flags_span_offset: flags_span.map_or(0, |span| span.start),
-        },
-    );
-    let Ok(pattern) = parser.parse() else {
-        return;
-    };
-    check_pattern(ctx, &pattern, pattern_span);
-}
-
 fn check_pattern(context: &LintContext, pattern: &Pattern, span: Span) {
-    let mut finder = ControlCharacterFinder::default();
+    let mut finder = ControlCharacterFinder::new(context.source_text());
     finder.visit_pattern(pattern);
 
     if !finder.control_chars.is_empty() {
@@ -152,13 +86,25 @@ fn check_pattern(context: &LintContext, pattern: &Pattern, span: Span) {
     }
 }
 
-#[derive(Default)]
-struct ControlCharacterFinder {
+struct ControlCharacterFinder<'s> {
+    source_text: &'s str,
     control_chars: Vec<Character>,
     num_capture_groups: u32,
 }
 
-impl<'a> Visit<'a> for ControlCharacterFinder {
+impl<'s> ControlCharacterFinder<'s> {
+    fn new(source_text: &'s str) -> Self {
+        Self { source_text, control_chars: vec![], num_capture_groups: 0 }
+    }
+
+    /// `\xhh` and `\uhhhh` escapes, which may be written as `\\xhh` in a string pattern.
+    fn is_hex_or_unicode_escape(&self, ch: &Character) -> bool {
+        let raw = ch.span.source_text(self.source_text);
+        raw.starts_with('\\') && raw.trim_start_matches('\\').starts_with(['x', 'u'])
+    }
+}
+
+impl<'a> Visit<'a> for ControlCharacterFinder<'_> {
     fn visit_pattern(&mut self, it: &Pattern<'a>) {
         walk::walk_pattern(self, it);
         // \1, \2, etc. are sometimes valid "control" characters as they can be
@@ -183,16 +129,11 @@ impl<'a> Visit<'a> for ControlCharacterFinder {
     }
 
     fn visit_character(&mut self, ch: &Character) {
-        // Control characters are in the range 0x00 to 0x1F
-        if ch.value <= 0x1F &&
-            // tab
-            ch.value != 0x09 &&
-            // line feed
-            ch.value != 0x0A &&
-            // carriage return
-            ch.value != 0x0D
+        // Control characters are in the range 0x00 to 0x1F. Tabs, line feeds and carriage
+        // returns are only reported when written as a hex or unicode escape, e.g. `\x0a`.
+        if ch.value <= 0x1F
+            && (!matches!(ch.value, 0x09 | 0x0A | 0x0D) || self.is_hex_or_unicode_escape(ch))
         {
-            // TODO: check if starts with \x or \u when char spans work correctly
             self.control_chars.push(*ch);
         }
     }
@@ -342,13 +283,13 @@ mod tests {
                 r"new RegExp('\\u{1F}', 'u')",
                 r"new RegExp('\\u{1F}', 'ugi')",
                 // https://github.com/oxc-project/oxc/issues/6136
-                // TODO: uncomment when char spans work correctly
-                // r"/\u{0a}/u",
-                // r"/\x0a/u",
-                // r"/\u{0d}/u",
-                // r"/\x0d/u",
-                // r"/\u{09}/u",
-                // r"/\x09/u",
+                r"/\u{0a}/u",
+                r"/\x0a/u",
+                r"/\u{0d}/u",
+                r"/\x0d/u",
+                r"/\u{09}/u",
+                r"/\x09/u",
+                r"new RegExp('\\x0a')",
             ],
         )
         .test_and_snapshot();
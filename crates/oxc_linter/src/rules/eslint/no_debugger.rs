@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// ```
     NoDebugger,
     correctness,
-    fix
+    fix,
+    node_types = [DebuggerStatement]
 );
 
 impl Rule for NoDebugger {
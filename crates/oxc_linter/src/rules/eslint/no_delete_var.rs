@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// delete x;
     /// ```
     NoDeleteVar,
-    correctness
+    correctness,
+    node_types = [UnaryExpression]
 );
 
 impl Rule for NoDeleteVar {
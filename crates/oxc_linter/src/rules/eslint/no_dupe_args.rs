@@ -0,0 +1,112 @@
+use oxc_ast::{ast::FormalParameterKind, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::IsSimpleParameterList;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_dupe_args_diagnostic(first: Span, second: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate parameter '{name}'"))
+        .with_help("Rename or remove the duplicated parameter")
+        .with_labels([
+            first.label("Parameter is first defined here"),
+            second.label("and duplicated here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeArgs;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate parameter names in function definitions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If more than one parameter has the same name, the last one shadows the
+    /// others, so the arguments passed for them can only be read through
+    /// `arguments`. This is most likely a typo.
+    ///
+    /// Duplicate parameters are a syntax error in strict mode, in arrow functions
+    /// and methods, and in functions with default, rest or destructured parameters,
+    /// so this rule only applies to other functions in scripts.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function foo(a, b, a) {
+    ///     console.log("value of the second a:", a);
+    /// }
+    ///
+    /// var bar = function (a, b, a) {
+    ///     console.log("value of the second a:", a);
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function foo(a, b, c) {
+    ///     console.log(a, b, c);
+    /// }
+    /// ```
+    NoDupeArgs,
+    correctness,
+    node_types = [FormalParameters]
+);
+
+impl Rule for NoDupeArgs {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::FormalParameters(params) = node.kind() else {
+            return;
+        };
+        // Duplicates anywhere else are syntax errors, which are already reported.
+        if params.kind != FormalParameterKind::FormalParameter
+            || !params.is_simple_parameter_list()
+            || params.items.len() <= 1
+        {
+            return;
+        }
+        let mut seen = FxHashMap::default();
+        for param in &params.items {
+            let Some(ident) = param.pattern.get_binding_identifier() else {
+                continue;
+            };
+            if let Some(first) = seen.get(ident.name.as_str()) {
+                ctx.diagnostic(no_dupe_args_diagnostic(*first, ident.span, &ident.name));
+            } else {
+                seen.insert(ident.name.as_str(), ident.span);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function a(a, b, c){}",
+        "var a = function(a, b, c){}",
+        "function a(a, b, c, d){} function b(a, b, c){}",
+        "function a(a) { function b(a) {} }",
+        "var a = function(a) { var a; }",
+        "function a(a, A){}",
+    ];
+
+    let fail = vec![
+        "function a(a, b, b) {}",
+        "function a(a, a, a) {}",
+        "function a(a, b, a) {}",
+        "function a(a, b, a, b) {}",
+        "var a = function(a, b, b) {}",
+        "var a = function(a, a, a) {}",
+        "var a = function(a, b, a) {}",
+        "var a = function(a, b, a, b) {}",
+    ];
+
+    Tester::new(NoDupeArgs::NAME, pass, fail).change_rule_path_extension("cjs").test_and_snapshot();
+}
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// a.foo() // Uncaught TypeError: a.foo is not a function
     /// ```
     NoDupeClassMembers,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for NoDupeClassMembers {
@@ -62,7 +62,8 @@ declare_oxc_lint!(
     /// };
     /// ```
     NoDupeKeys,
-    correctness
+    correctness,
+    node_types = [ObjectExpression]
 );
 
 impl Rule for NoDupeKeys {
@@ -79,8 +80,9 @@ impl Rule for NoDupeKeys {
             let ObjectPropertyKind::ObjectProperty(prop) = prop else {
                 continue;
             };
+            // Computed keys that aren't literals can't be compared.
             let Some(name) = prop.key.static_name() else {
-                return;
+                continue;
             };
             if let Some((prev_kind, prev_span)) = map.insert(name, (prop.kind, prop.key.span())) {
                 if prev_kind == PropertyKind::Init
@@ -147,6 +149,7 @@ fn test() {
         ("var x = { a: 1, set a(value) {} };", None),
         ("var x = { a: 1, b: { a: 2 }, get b() {} };", None),
         ("var x = ({ '/(?<zero>0)/': 1, [/(?<zero>0)/]: 2 })", None),
+        ("var x = { [a]: 1, b: 1, b: 2 };", None),
     ];
 
     Tester::new(NoDupeKeys::NAME, pass, fail).test_and_snapshot();
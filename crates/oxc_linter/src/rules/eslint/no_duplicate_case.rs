@@ -1,9 +1,9 @@
 use oxc_ast::ast::Expression;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{cmp::ContentEq, GetSpan, Span};
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_same_key, context::LintContext, rule::Rule, AstNode};
 
 fn no_duplicate_case_diagnostic(first: Span, second: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Duplicate case label")
@@ -84,7 +84,7 @@ impl Rule for NoDuplicateCase {
         let mut previous_tests: Vec<&Expression<'_>> = vec![];
         for test in ss.cases.iter().filter_map(|c| c.test.as_ref()) {
             let test = test.without_parentheses();
-            if let Some(prev) = previous_tests.iter().find(|t| t.content_eq(test)) {
+            if let Some(prev) = previous_tests.iter().find(|t| is_same_key(t, test)) {
                 ctx.diagnostic(no_duplicate_case_diagnostic(prev.span(), test.span()));
             } else {
                 previous_tests.push(test);
@@ -110,6 +110,7 @@ fn test() {
         "var a = [1,2]; switch(a.toString()){case ([1,2]).toString():break; case ([1]).toString():break; default:break;}",
         "switch(a) { case a: break; } switch(a) { case a: break; }",
         "switch(a) { case toString: break; }",
+        "switch (a) { case 1: break; case 2: break; case '1': break; case `2`: break; }",
     ];
 
     let fail = vec![
@@ -131,6 +132,8 @@ fn test() {
         "var a = 1, p = {p: {p1: 1, p2: 1}}; switch (a) {case p.p.p1: break; case p. p // comment\n .p1: break; case p .p\n/* comment */\n.p1: break; default: break;}",
         "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(a + 1).p1: break; case f(a+1).p1: break; default: break;}",
         "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(\na + 1 // comment\n).p1: break; case f(a+1)\n.p1: break; default: break;}",
+        "switch (a) { case 1: break; case 0x1: break; case 1.0: break; }",
+        "switch (a) { case 'a': break; case `a`: break; case \"a\": break; }",
     ];
 
     Tester::new(NoDuplicateCase::NAME, pass, fail).test_and_snapshot();
@@ -4,6 +4,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{ScopeId, ScopeTree};
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_line_terminator;
 
 #[derive(Debug, Default, Clone)]
 pub struct NoElseReturn {
@@ -197,7 +198,20 @@ fn is_safe_from_name_collisions(
                 return false;
             }
 
-            true
+            // `var` declarations inside the parent block are hoisted out of it,
+            // e.g. `{ if (a) { return } else { let x } var x }`
+            let parent_span = ctx.nodes().kind(scopes.get_node_id(parent_scope_id)).span();
+            let Some(var_scope_id) =
+                scopes.ancestors(parent_scope_id).find(|id| scopes.get_flags(*id).is_var())
+            else {
+                return true;
+            };
+            let var_bindings = scopes.get_bindings(var_scope_id);
+            !bindings.keys().any(|name| {
+                var_bindings.get(name).is_some_and(|symbol_id| {
+                    parent_span.contains_inclusive(ctx.symbols().get_span(*symbol_id))
+                })
+            })
         }
         Statement::FunctionDeclaration(_) => false,
         _ => true,
@@ -232,6 +246,11 @@ fn no_else_return_diagnostic_fix(
             let (Some(start), Some(end)) = (first_stmt_start, last_stmt_end) else {
                 return fixer.noop();
             };
+            // Without the braces, code following the block may continue its
+            // last statement, e.g. `else { baz() } qaz()`
+            if is_unsafe_to_unwrap(ctx, block.span) {
+                return fixer.noop();
+            }
             Span::new(start, end)
         } else {
             else_content_span
@@ -257,6 +276,29 @@ fn no_else_return_diagnostic_fix(
     });
 }
 
+/// Whether the statements of `block` would join the code following it once its
+/// braces are removed, following ESLint: the last statement isn't ended with a
+/// `;`, and the next token is either on the same line or could continue an
+/// expression.
+fn is_unsafe_to_unwrap(ctx: &LintContext, block: Span) -> bool {
+    let closing_brace = Span::new(block.end - 1, block.end);
+    let Some(last_token) = ctx.find_token_before(closing_brace) else {
+        return false;
+    };
+    if ctx.source_range(last_token) == ";" {
+        return false;
+    }
+    let Some(next_token) = ctx.find_token_after(block) else {
+        return false;
+    };
+    let next = ctx.source_range(next_token);
+    let on_same_line = !ctx
+        .source_range(Span::new(last_token.end, next_token.start))
+        .chars()
+        .any(is_line_terminator);
+    next.starts_with(['(', '[', '/', '+', '`', '-']) || (on_same_line && next != "}")
+}
+
 #[allow(clippy::cast_possible_truncation)]
 fn left_offset_for_whitespace(ctx: &LintContext, position: u32) -> u32 {
     if position == 0 {
@@ -545,6 +587,8 @@ fn test() {
 			else { baz() } 
 			qaz() }", "function foo17() { if (foo) return bar\n baz() 
 			qaz() }", None),
+("function foo15() { if (foo) return bar; else { baz() } qaz() }", "function foo15() { if (foo) return bar; else { baz() } qaz() }", None),
+("function foo() { if (bar) { if (baz) { return true; } else { let a; } var a; } }", "function foo() { if (bar) { if (baz) { return true; } else { let a; } var a; } }", None),
 ("function foo19() { if (true) { return x; } else if (false) { return y; } }", "function foo19() { if (true) { return x; } if (false) { return y; } }", Some(serde_json::json!([{ "allowElseIf": false }]))),
 ("function foo20() {if (x) { return true; } else if (y) { notAReturn() } else { notAReturn(); } }", "function foo20() {if (x) { return true; } if (y) { notAReturn() } else { notAReturn(); } }", Some(serde_json::json!([{ "allowElseIf": false }]))),
 ("function foo21() { var x = true; if (x) { return x; } else if (x === false) { return false; } }", "function foo21() { var x = true; if (x) { return x; } if (x === false) { return false; } }", Some(serde_json::json!([{ "allowElseIf": false }]))),
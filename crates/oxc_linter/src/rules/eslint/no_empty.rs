@@ -1,9 +1,18 @@
-use oxc_ast::{ast::BlockStatement, AstKind};
+use oxc_ast::{
+    ast::{BlockStatement, Expression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+    AstNode,
+};
 
 fn no_empty_diagnostic(stmt_kind: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected empty block statements")
@@ -30,6 +39,13 @@ declare_oxc_lint!(
     ///
     /// }
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowEmptyCatch`: allow empty `catch` clauses. Defaults to `false`.
+    /// ```json
+    /// { "no-empty": ["error", { "allowEmptyCatch": true }] }
+    /// ```
     NoEmpty,
     restriction,
     suggestion
@@ -58,36 +74,19 @@ impl Rule for NoEmpty {
                     return;
                 }
                 ctx.diagnostic_with_suggestion(no_empty_diagnostic("block", block.span), |fixer| {
-                    if let Some(parent) = parent {
-                        if let AstKind::TryStatement(try_stmt) = parent {
-                            if let Some(try_block_stmt) = &try_stmt.finalizer {
-                                if try_block_stmt.span == block.span {
-                                    return if let Some(finally_kw_start) =
-                                        find_finally_start(ctx, block)
-                                    {
-                                        fixer.delete_range(Span::new(
-                                            finally_kw_start,
-                                            block.span.end,
-                                        ))
-                                    } else {
-                                        fixer.noop()
-                                    };
-                                }
-                            }
-                        }
-                        if matches!(parent, AstKind::CatchClause(_)) {
-                            return fixer.noop();
-                        }
-                        fixer.delete(&parent)
-                    } else {
-                        fixer.noop()
-                    }
+                    remove_empty_block(node, block, ctx, fixer)
                 });
             }
             AstKind::SwitchStatement(switch) if switch.cases.is_empty() => {
                 ctx.diagnostic_with_suggestion(
                     no_empty_diagnostic("switch", switch.span),
-                    |fixer| fixer.delete(switch),
+                    |fixer| {
+                        if is_pure(&switch.discriminant) && is_in_statement_list(node, ctx) {
+                            fixer.delete(switch)
+                        } else {
+                            fixer.noop()
+                        }
+                    },
                 );
             }
             _ => {}
@@ -95,6 +94,92 @@ impl Rule for NoEmpty {
     }
 }
 
+/// Remove an empty block, or the statement it belongs to, if that doesn't change what the code
+/// does. Empty loop bodies and `catch` clauses are kept, since removing them would.
+fn remove_empty_block<'a>(
+    node: &AstNode<'a>,
+    block: &BlockStatement<'a>,
+    ctx: &LintContext<'a>,
+    fixer: RuleFixer<'_, 'a>,
+) -> RuleFix<'a> {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return fixer.noop();
+    };
+    match parent.kind() {
+        // `{}`
+        AstKind::Program(_)
+        | AstKind::BlockStatement(_)
+        | AstKind::FunctionBody(_)
+        | AstKind::StaticBlock(_)
+        | AstKind::SwitchCase(_) => fixer.delete(block),
+        // `try { foo() } catch (ex) {} finally {}`
+        AstKind::TryStatement(try_stmt)
+            if try_stmt
+                .finalizer
+                .as_ref()
+                .is_some_and(|finalizer| finalizer.span == block.span) =>
+        {
+            // Without a `catch` clause, the `try` would be left without a handler.
+            match find_finally_start(ctx, block) {
+                Some(finally_kw_start) if try_stmt.handler.is_some() => {
+                    fixer.delete_range(Span::new(finally_kw_start, block.span.end))
+                }
+                _ => fixer.noop(),
+            }
+        }
+        // `try {} catch (ex) {}`: nothing can throw, so the `catch` clause never runs.
+        AstKind::TryStatement(try_stmt)
+            if try_stmt.finalizer.as_ref().map_or(true, |finalizer| finalizer.body.is_empty())
+                && is_in_statement_list(parent, ctx) =>
+        {
+            fixer.delete(try_stmt)
+        }
+        // `if (foo) { bar() } else {}`
+        AstKind::IfStatement(if_stmt)
+            if if_stmt
+                .alternate
+                .as_ref()
+                .is_some_and(|alternate| alternate.span() == block.span) =>
+        {
+            fixer.delete_range(Span::new(if_stmt.consequent.span().end, block.span.end))
+        }
+        // `if (foo) {}`
+        AstKind::IfStatement(if_stmt)
+            if if_stmt.alternate.is_none()
+                && is_pure(&if_stmt.test)
+                && is_in_statement_list(parent, ctx) =>
+        {
+            fixer.delete(if_stmt)
+        }
+        _ => fixer.noop(),
+    }
+}
+
+/// Whether `node` is a statement in a list of statements, which it can be removed from.
+fn is_in_statement_list(node: &AstNode, ctx: &LintContext) -> bool {
+    matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(
+            AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+        )
+    )
+}
+
+/// Whether evaluating `expr` has no side effects, other than a possible `ReferenceError`.
+fn is_pure(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+        Expression::UnaryExpression(unary) => {
+            unary.operator != UnaryOperator::Delete && is_pure(&unary.argument)
+        }
+        expr => expr.is_literal(),
+    }
+}
+
 fn find_finally_start(ctx: &LintContext, finally_clause: &BlockStatement) -> Option<u32> {
     let src = ctx.source_text();
     let finally_start = finally_clause.span.start as usize - 1;
@@ -170,6 +255,14 @@ fn test() {
         ("try { foo(); } catch (ex) {} finally {}", Some(json!([ { "allowEmptyCatch": true }]))),
         ("try {} catch (ex) {} finally {}", Some(json!([ { "allowEmptyCatch": true }]))),
         ("try { foo(); } catch (ex) {} finally {}", None),
+        ("if (foo) { bar() } else {}", None),
+        ("if (foo()) {}", None),
+        ("if (foo) {} else { bar() }", None),
+        ("if (foo) bar(); else if (baz) {}", None),
+        ("function foo() { bar(); {} }", None),
+        ("try { foo() } finally {}", None),
+        ("try {} finally { foo() }", None),
+        ("switch (foo()) {}", None),
     ];
 
     let fix = vec![
@@ -182,8 +275,9 @@ fn test() {
         // we can't fix this because removing the `catch` block would change the semantics of the code
         ("try { foo() } catch (ex) {}", "try { foo() } catch (ex) {}", None),
         ("if (foo) {}", "", None),
-        ("while (foo) {}", "", None),
-        ("for (;foo;) {}", "", None),
+        // removing a loop would change the semantics of the code if it never ends
+        ("while (foo) {}", "while (foo) {}", None),
+        ("for (;foo;) {}", "for (;foo;) {}", None),
         ("switch(foo) {}", "", None),
         ("switch (foo) { /* empty */ }", "", None),
         ("try {} catch (ex) {}", "", Some(json!([ { "allowEmptyCatch": true }]))),
@@ -194,6 +288,14 @@ fn test() {
         ),
         ("try {} catch (ex) {} finally {}", "", Some(json!([ { "allowEmptyCatch": true }]))),
         ("try { foo(); } catch (ex) {} finally {}", "try { foo(); } catch (ex) {} ", None),
+        ("if (foo) { bar() } else {}", "if (foo) { bar() }", None),
+        ("if (foo()) {}", "if (foo()) {}", None),
+        ("if (foo) {} else { bar() }", "if (foo) {} else { bar() }", None),
+        ("if (foo) bar(); else if (baz) {}", "if (foo) bar(); else if (baz) {}", None),
+        ("function foo() { bar(); {} }", "function foo() { bar();  }", None),
+        ("try { foo() } finally {}", "try { foo() } finally {}", None),
+        ("try {} finally { foo() }", "try {} finally { foo() }", None),
+        ("switch (foo()) {}", "switch (foo()) {}", None),
     ];
 
     Tester::new(NoEmpty::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
@@ -58,6 +58,7 @@ declare_oxc_lint!(
     /// ```
     NoEmptyFunction,
     restriction,
+    node_types = [FunctionBody]
 );
 
 impl Rule for NoEmptyFunction {
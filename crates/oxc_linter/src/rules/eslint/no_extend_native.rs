@@ -66,6 +66,7 @@ declare_oxc_lint!(
     /// ```
     NoExtendNative,
     suspicious,
+    node_types = []
 );
 
 impl Rule for NoExtendNative {
@@ -245,7 +245,8 @@ declare_oxc_lint!(
     NoFallthrough,
     // TODO: add options section to docs
     pedantic, // Fall through code are still incorrect.
-    pending // TODO: add a dangerous suggestion for this rule.
+    pending, // TODO: add a dangerous suggestion for this rule.
+    node_types = [SwitchStatement]
 );
 
 impl Rule for NoFallthrough {
@@ -378,15 +379,12 @@ impl NoFallthrough {
         case: &SwitchCase,
         fall: &SwitchCase,
     ) -> Option<Span> {
-        let semantic = ctx.semantic();
         let is_fallthrough_comment_in_range = |range: Range<u32>| {
-            let comment = semantic
+            let comment = ctx
+                .semantic()
                 .comments_range(range)
-                .map(|comment| {
-                    &semantic.source_text()[comment.span.start as usize..comment.span.end as usize]
-                })
                 .last()
-                .map(str::trim);
+                .map(|comment| ctx.comment_text(comment).trim());
 
             comment.is_some_and(|comment| self.is_comment_fall_through(comment))
         };
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// Object = null
     /// ```
     NoGlobalAssign,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for NoGlobalAssign {
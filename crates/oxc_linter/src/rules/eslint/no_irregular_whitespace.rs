@@ -1,8 +1,13 @@
+use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::identifier::{is_irregular_line_terminator, is_irregular_whitespace};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn no_irregular_whitespace_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected irregular whitespace")
@@ -11,7 +16,44 @@ fn no_irregular_whitespace_diagnostic(span: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct NoIrregularWhitespace;
+pub struct NoIrregularWhitespace(Box<NoIrregularWhitespaceConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoIrregularWhitespaceConfig {
+    /// Allow irregular whitespace in string literals.
+    skip_strings: bool,
+    /// Allow irregular whitespace in comments.
+    skip_comments: bool,
+    /// Allow irregular whitespace in regular expression literals.
+    #[serde(rename = "skipRegExps")]
+    skip_regexps: bool,
+    /// Allow irregular whitespace in template literals.
+    skip_templates: bool,
+    /// Allow irregular whitespace in JSX text.
+    #[serde(rename = "skipJSXText")]
+    skip_jsx_text: bool,
+}
+
+impl Default for NoIrregularWhitespaceConfig {
+    fn default() -> Self {
+        Self {
+            skip_strings: true,
+            skip_comments: false,
+            skip_regexps: false,
+            skip_templates: false,
+            skip_jsx_text: false,
+        }
+    }
+}
+
+impl std::ops::Deref for NoIrregularWhitespace {
+    type Target = NoIrregularWhitespaceConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -27,16 +69,82 @@ declare_oxc_lint!(
     ///     return  42;
     /// }
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// Irregular whitespace is always reported between tokens. Whether it is
+    /// reported inside strings, comments, regular expressions, templates and
+    /// JSX text is configurable; only strings are skipped by default:
+    ///
+    /// ```json
+    /// { "no-irregular-whitespace": ["error", { "skipStrings": false, "skipComments": true }] }
+    /// ```
     NoIrregularWhitespace,
-    correctness
+    correctness,
+    config = NoIrregularWhitespaceConfig,
+    node_types = [StringLiteral, TemplateLiteral, RegExpLiteral, JSXText]
 );
 
 impl Rule for NoIrregularWhitespace {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str, default: bool| {
+            config.and_then(|config| config.get(name)).and_then(Value::as_bool).unwrap_or(default)
+        };
+        Self(Box::new(NoIrregularWhitespaceConfig {
+            skip_strings: option("skipStrings", true),
+            skip_comments: option("skipComments", false),
+            skip_regexps: option("skipRegExps", false),
+            skip_templates: option("skipTemplates", false),
+            skip_jsx_text: option("skipJSXText", false),
+        }))
+    }
+
     fn run_once(&self, ctx: &LintContext) {
-        let irregular_whitespaces = ctx.semantic().irregular_whitespaces();
-        for irregular_whitespace in irregular_whitespaces {
+        // Collected by the lexer, which only sees whitespace between tokens.
+        for irregular_whitespace in ctx.semantic().irregular_whitespaces() {
+            // A byte order mark at the start of the file is not part of the code.
+            if irregular_whitespace.start == 0 && ctx.source_text().starts_with('\u{feff}') {
+                continue;
+            }
             ctx.diagnostic(no_irregular_whitespace_diagnostic(*irregular_whitespace));
         }
+        if !self.skip_comments {
+            for comment in ctx.semantic().comments() {
+                check_source_text(comment.span, ctx);
+            }
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::StringLiteral(lit) if !self.skip_strings => check_source_text(lit.span, ctx),
+            AstKind::TemplateLiteral(lit) if !self.skip_templates => {
+                for quasi in &lit.quasis {
+                    check_source_text(quasi.span, ctx);
+                }
+            }
+            AstKind::RegExpLiteral(lit) if !self.skip_regexps => check_source_text(lit.span, ctx),
+            AstKind::JSXText(text) if !self.skip_jsx_text => check_source_text(text.span, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn is_irregular(c: char) -> bool {
+    // U+180E MONGOLIAN VOWEL SEPARATOR is no longer whitespace, but is still invisible.
+    is_irregular_whitespace(c) || is_irregular_line_terminator(c) || c == '\u{180e}'
+}
+
+/// Report each irregular whitespace character in the raw source text of `span`.
+fn check_source_text(span: Span, ctx: &LintContext) {
+    let text = span.source_text(ctx.source_text());
+    for (offset, c) in text.char_indices() {
+        if is_irregular(c) {
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::sized(span.start + offset as u32, c.len_utf8() as u32);
+            ctx.diagnostic(no_irregular_whitespace_diagnostic(span));
+        }
     }
 }
 
@@ -98,8 +206,8 @@ fn test() {
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"// ᠎", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"// ﻿", Some(serde_json::json!([{ "skipComments": true }]))),
-        // (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))), lint error
-        // (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))), lint error
+        (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
+        (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
@@ -109,7 +217,7 @@ fn test() {
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
-        // (r"// ​", Some(serde_json::json!([{ "skipComments": true }]))), lint error
+        (r"// ​", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"//  ", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"// 　", Some(serde_json::json!([{ "skipComments": true }]))),
@@ -119,8 +227,8 @@ fn test() {
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/* ᠎ */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/* ﻿ */", Some(serde_json::json!([{ "skipComments": true }]))),
-        // (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))), lint error
-        // (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))), lint error
+        (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
+        (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
@@ -130,7 +238,7 @@ fn test() {
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
-        // (r"/* ​ */", Some(serde_json::json!([{ "skipComments": true }]))), lint error
+        (r"/* ​ */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
         (r"/*   */", Some(serde_json::json!([{ "skipComments": true }]))),
@@ -142,8 +250,8 @@ fn test() {
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/᠎/", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/﻿/", Some(serde_json::json!([{ "skipRegExps": true }]))),
-        // (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))), lint error
-        // (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))), lint error
+        (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
+        (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
@@ -153,7 +261,7 @@ fn test() {
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
-        // (r"/​/", Some(serde_json::json!([{ "skipRegExps": true }]))),  lint error
+        (r"/​/", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/ /", Some(serde_json::json!([{ "skipRegExps": true }]))),
         (r"/　/", Some(serde_json::json!([{ "skipRegExps": true }]))),
@@ -163,8 +271,8 @@ fn test() {
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"`᠎`", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"`﻿`", Some(serde_json::json!([{ "skipTemplates": true }]))),
-        // (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),  lint error
-        // (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),  lint error
+        (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
+        (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
@@ -174,7 +282,7 @@ fn test() {
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
-        // (r"`​`", Some(serde_json::json!([{ "skipTemplates": true }]))),  lint error
+        (r"`​`", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"` `", Some(serde_json::json!([{ "skipTemplates": true }]))),
         (r"`　`", Some(serde_json::json!([{ "skipTemplates": true }]))),
@@ -207,8 +315,8 @@ fn test() {
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div>᠎</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div>﻿</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
-        // (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),  lint error
-        // (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),  lint error
+        (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
+        (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
@@ -218,11 +326,11 @@ fn test() {
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
-        // (r"<div>​</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),  lint error
+        (r"<div>​</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div>　</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
-        // (r"﻿console.log('hello BOM');", None),
+        (r"﻿console.log('hello BOM');", None),
     ];
 
     let fail = vec![
@@ -256,53 +364,53 @@ fn test() {
 			var third 　 = 'thing';",
             None,
         ),
-        // (r"// ", None),
-        // (r"// ", None),
-        // (r"// ", None),
-        // (r"//  ", None),
-        // (r"// ᠎", None),
-        // (r"// ﻿", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"// ​", None),
-        // (r"//  ", None),
-        // (r"//  ", None),
-        // (r"// 　", None),
-        // (r"/*  */", None),
-        // (r"/*  */", None),
-        // (r"/*  */", None),
-        // (r"/*   */", None),
-        // (r"/* ᠎ */", None),
-        // (r"/* ﻿ */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/* ​ */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/*   */", None),
-        // (r"/* 　 */", None),
-        // (r"var any = /　/, other = //;", None),
-        // (r"var any = '　', other = '';", Some(serde_json::json!([{ "skipStrings": false }]))),
-        // (r"var any = `　`, other = ``;", Some(serde_json::json!([{ "skipTemplates": false }]))),
+        (r"// ", None),
+        (r"// ", None),
+        (r"// ", None),
+        (r"//  ", None),
+        (r"// ᠎", None),
+        (r"// ﻿", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"// ​", None),
+        (r"//  ", None),
+        (r"//  ", None),
+        (r"// 　", None),
+        (r"/*  */", None),
+        (r"/*  */", None),
+        (r"/*  */", None),
+        (r"/*   */", None),
+        (r"/* ᠎ */", None),
+        (r"/* ﻿ */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/* ​ */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/*   */", None),
+        (r"/* 　 */", None),
+        (r"var any = /　/, other = //;", None),
+        (r"var any = '　', other = '';", Some(serde_json::json!([{ "skipStrings": false }]))),
+        (r"var any = `　`, other = ``;", Some(serde_json::json!([{ "skipTemplates": false }]))),
         (
             r"`something ${　 10} another thing`",
             Some(serde_json::json!([{ "skipTemplates": true }])),
@@ -381,28 +489,28 @@ fn test() {
          ",
             None,
         ),
-        // (r"foo ", None),
-        // (r"<div></div>;", None),
-        // (r"<div></div>;", None),
-        // (r"<div></div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div>᠎</div>;", None),
-        // (r"<div>﻿</div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div>​</div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div> </div>;", None),
-        // (r"<div>　</div>;", None),
+        (r"foo ", None),
+        (r"<div></div>;", None),
+        (r"<div></div>;", None),
+        (r"<div></div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div>᠎</div>;", None),
+        (r"<div>﻿</div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div>​</div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div> </div>;", None),
+        (r"<div>　</div>;", None),
     ];
 
     Tester::new(NoIrregularWhitespace::NAME, pass, fail).test_and_snapshot();
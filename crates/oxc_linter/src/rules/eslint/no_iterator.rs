@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// ```
     NoIterator,
     restriction,
-    pending // TODO: suggestion
+    pending, // TODO: suggestion
+    node_types = [MemberExpression]
 );
 
 impl Rule for NoIterator {
@@ -56,6 +56,7 @@ declare_oxc_lint!(
     /// ```
     NoLabelVar,
     style,
+    node_types = [LabeledStatement]
 );
 
 impl Rule for NoLabelVar {
@@ -0,0 +1,333 @@
+use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode};
+
+fn no_loop_func_diagnostic(names: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Function declared in a loop contains unsafe references to variable(s) {names}."
+    ))
+    .with_help("Move the function out of the loop, or capture the current values with `let` or `const` declared in the loop.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoopFunc;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow function declarations that contain unsafe references inside loop statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function created in a loop closes over the variables of the loop, not over their
+    /// values in the iteration it was created in. If such a variable is modified by the
+    /// loop, e.g. a `var` counter, every function sees its final value when it's called.
+    ///
+    /// Variables declared with `let` or `const` in the loop are safe, since every iteration
+    /// has its own binding, and so are variables that are never modified after the loop
+    /// starts. Immediately invoked functions are also safe.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// for (var i = 10; i; i--) {
+    ///     (function() { return i; });
+    /// }
+    ///
+    /// let foo = 0;
+    /// for (let i = 0; i < 10; ++i) {
+    ///     // `foo` is modified in the loop.
+    ///     setTimeout(() => console.log(foo));
+    ///     foo += 1;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// for (let i = 10; i; i--) {
+    ///     var a = function() { return i; };
+    ///     a();
+    /// }
+    ///
+    /// var foo = 100;
+    /// for (let i = 10; i; i--) {
+    ///     // `foo` is not modified after the loop starts.
+    ///     var b = function() { return foo; };
+    /// }
+    /// ```
+    NoLoopFunc,
+    suspicious,
+    node_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for NoLoopFunc {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (scope_id, span) = match node.kind() {
+            AstKind::Function(func) => (func.scope_id.get(), func.span),
+            AstKind::ArrowFunctionExpression(arrow) => (arrow.scope_id.get(), arrow.span),
+            _ => return,
+        };
+        let Some(scope_id) = scope_id else { return };
+        if is_skipped_iife(node, ctx) {
+            return;
+        }
+        let Some(loop_node) = get_containing_loop_node(node, ctx) else {
+            return;
+        };
+
+        // References from inside the function to variables declared outside of it.
+        let symbols = ctx.symbols();
+        let mut names = vec![];
+        for reference in &symbols.references {
+            let Some(symbol_id) = reference.symbol_id() else { continue };
+            if !span.contains_inclusive(ctx.nodes().kind(reference.node_id()).span())
+                || ctx.scopes().ancestors(symbols.get_scope_id(symbol_id)).any(|id| id == scope_id)
+            {
+                continue;
+            }
+            let name = symbols.get_name(symbol_id);
+            if !names.contains(&name) && !is_safe(loop_node, symbol_id, ctx) {
+                names.push(name);
+            }
+        }
+
+        if !names.is_empty() {
+            let names = names.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+            ctx.diagnostic(no_loop_func_diagnostic(&names.join(", "), span));
+        }
+    }
+}
+
+/// The loop that `node` is created in, if any. Functions in the `init` of a `for` loop,
+/// or in the object a `for-in` or `for-of` loop iterates over, are only created once.
+fn get_containing_loop_node<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let mut current = node;
+    while let Some(parent) = ctx.nodes().parent_node(current.id()) {
+        match parent.kind() {
+            AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => return Some(parent),
+            AstKind::ForStatement(_) => {
+                if !matches!(current.kind(), AstKind::ForStatementInit(_)) {
+                    return Some(parent);
+                }
+            }
+            AstKind::ForInStatement(stmt) => {
+                if stmt.right.span() != current.kind().span() {
+                    return Some(parent);
+                }
+            }
+            AstKind::ForOfStatement(stmt) => {
+                if stmt.right.span() != current.kind().span() {
+                    return Some(parent);
+                }
+            }
+            // Nested functions are checked on their own, unless they are immediately invoked.
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                if !is_skipped_iife(parent, ctx) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+        current = parent;
+    }
+    None
+}
+
+/// The outermost loop that contains `node` and starts after `excluded` ends.
+fn get_top_loop_node<'a, 'b>(
+    node: &'b AstNode<'a>,
+    excluded: Option<Span>,
+    ctx: &'b LintContext<'a>,
+) -> &'b AstNode<'a> {
+    let border = excluded.map_or(0, |span| span.end);
+    let mut top = node;
+    let mut containing = Some(node);
+    while let Some(loop_node) = containing.filter(|node| node.kind().span().start >= border) {
+        top = loop_node;
+        containing = get_containing_loop_node(loop_node, ctx);
+    }
+    top
+}
+
+/// Whether the variable can't change while the loop runs, so a function created in the loop
+/// always sees the value it had when the function was created.
+fn is_safe<'a>(loop_node: &AstNode<'a>, symbol_id: SymbolId, ctx: &LintContext<'a>) -> bool {
+    let declaration =
+        ctx.nodes().parent_node(ctx.symbols().get_declaration(symbol_id)).and_then(|parent| {
+            match parent.kind() {
+                AstKind::VariableDeclaration(decl) => Some(decl),
+                _ => None,
+            }
+        });
+    let kind = declaration.map(|decl| decl.kind);
+    if kind == Some(VariableDeclarationKind::Const) {
+        return true;
+    }
+
+    // Every iteration has its own binding of a `let` declared in the loop.
+    let loop_span = loop_node.kind().span();
+    let let_declaration =
+        declaration.filter(|decl| decl.kind == VariableDeclarationKind::Let).map(|decl| decl.span);
+    if let_declaration.is_some_and(|span| span.start > loop_span.start && span.end < loop_span.end)
+    {
+        return true;
+    }
+
+    // Writes after the start of the outermost loop may change the variable.
+    let border = get_top_loop_node(loop_node, let_declaration, ctx).kind().span().start;
+    let symbol_scope = get_variable_scope(ctx.symbols().get_scope_id(symbol_id), ctx);
+    // Declarations with an initializer are writes too.
+    let declarator_id = ctx.symbols().get_declaration(symbol_id);
+    let is_initialized = match ctx.nodes().kind(declarator_id) {
+        AstKind::VariableDeclarator(declarator) => {
+            declarator.init.is_some()
+                || matches!(
+                    ctx.nodes().iter_parents(declarator_id).nth(2).map(AstNode::kind),
+                    Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+                )
+        }
+        _ => false,
+    };
+    if is_initialized && ctx.symbols().get_span(symbol_id).start >= border {
+        return false;
+    }
+    ctx.symbols().get_resolved_references(symbol_id).all(|reference| {
+        if !reference.is_write() {
+            return true;
+        }
+        let node = ctx.nodes().get_node(reference.node_id());
+        get_variable_scope(node.scope_id(), ctx) == symbol_scope
+            && node.kind().span().start < border
+    })
+}
+
+/// The closest function, class static block, or program scope.
+fn get_variable_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    ctx.scopes()
+        .ancestors(scope_id)
+        .find(|id| ctx.scopes().get_flags(*id).is_var())
+        .unwrap_or(scope_id)
+}
+
+/// Immediately invoked functions that aren't async or generators run before the loop
+/// continues, so they are safe. Named function expressions are only skipped if the name is
+/// not referenced, since the function could be called again.
+fn is_skipped_iife<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let (span, is_referenced) = match node.kind() {
+        AstKind::Function(func) => {
+            if func.r#async || func.generator {
+                return false;
+            }
+            let is_referenced =
+                func.id.as_ref().and_then(|id| id.symbol_id.get()).is_some_and(|symbol_id| {
+                    !ctx.symbols().get_resolved_reference_ids(symbol_id).is_empty()
+                });
+            (func.span, is_referenced)
+        }
+        AstKind::ArrowFunctionExpression(arrow) => {
+            if arrow.r#async {
+                return false;
+            }
+            (arrow.span, false)
+        }
+        _ => return false,
+    };
+    !is_referenced
+        && outermost_paren_parent(node, ctx).is_some_and(|parent| match parent.kind() {
+            AstKind::CallExpression(call) => call.callee.without_parentheses().span() == span,
+            _ => false,
+        })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "string = 'function a() {}';",
+        "for (var i=0; i<l; i++) { } var a = function() { i; };",
+        "for (var i=0, a=function() { i; }; i<l; i++) { }",
+        "for (var x in xs.filter(function(x) { return x != upper; })) { }",
+        "for (var x of xs.filter(function(x) { return x != upper; })) { }",
+        "for (var i=0; i<l; i++) { (function() {}) }",
+        "for (var i in {}) { (function() {}) }",
+        "for (var i of {}) { (function() {}) }",
+        "for (let i=0; i<l; i++) { (function() { i; }) }",
+        "for (let i in {}) { i = 7; (function() { i; }) }",
+        "for (const i of {}) { (function() { i; }) }",
+        "for (let i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) {  } }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "let a = 0; for (let i in {}) { (function() { a; }); }",
+        "let a = 0; for (let i of {}) { (function() { a; }); }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); }",
+        "let a = 0; for (let i in {}) { function foo() { (function() { a; }); } }",
+        "let a = 0; for (let i of {}) { (() => { (function() { a; }); }); }",
+        "var a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "var a = 0; for (let i in {}) { (function() { a; }); }",
+        "var a = 0; for (let i of {}) { (function() { a; }); }",
+        "let result = {};
+        for (const score in scores) {
+            const letters = scores[score];
+            letters.split('').forEach(letter => {
+                result[letter] = score;
+            });
+        }
+        result.__default = 6;",
+        "while (true) {
+            (function() { a; });
+        }
+        let a;",
+        "while(i) { (function() { i; }) }",
+        "do { (function() { i; }) } while (i)",
+        "var i; while(i) { (function() { i; }) }",
+        "var i; do { (function() { i; }) } while (i)",
+        "for (var i=0; i<l; i++) { (function() { undefinedVar; }) }",
+        // Immediately invoked functions.
+        "for (var i = 0; i < 10; i++) { (function() { i; })(); }",
+        "for (var i = 0; i < 10; i++) { (() => { i; })(); }",
+        "for (var i = 0; i < 10; i++) { (function a() { i; })(); }",
+        "for (var i = 0; i < 10; i++) { (function() { (function() { i; })(); })(); }",
+    ];
+
+    let fail = vec![
+        "for (var i=0; i<l; i++) { (function() { i; }) }",
+        "for (var i=0; i<l; i++) { for (var j=0; j<m; j++) { (function() { i+j; }) } }",
+        "for (var i in {}) { (function() { i; }) }",
+        "for (var i of {}) { (function() { i; }) }",
+        "for (var i=0; i < l; i++) { (() => { i; }) }",
+        "for (var i=0; i < l; i++) { var a = function() { i; } }",
+        "for (var i=0; i < l; i++) { function a() { i; }; a(); }",
+        "var i = 0; while (i < 10) { (function() { i; }); i++; }",
+        "var i = 0; do { (function() { i; }); } while (i++ < 10)",
+        "while (i) { var a = i; (function() { a; }); }",
+        "let a; for (let i=0; i<l; i++) { a = 1; (function() { a; });}",
+        "let a; for (let i in {}) { (function() { a; }); a = 1; }",
+        "let a; for (let i of {}) { (function() { a; }); } a = 1; ",
+        "let a; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); a = 1; }",
+        "let a; for (let i in {}) { a = 1; function foo() { (function() { a; }); } }",
+        "let a; for (let i of {}) { (() => { (function() { a; }); }); } a = 1;",
+        "for (var i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) {  } }",
+        "for (let x of xs) { let a; for (let y of ys) { a = 1; (function() { a; }); } }",
+        "for (var x of xs) { for (let y of ys) { (function() { x; }); } }",
+        "for (var x of xs) { (function() { x; }); }",
+        "var a; for (let x of xs) { a = 1; (function() { a; }); }",
+        "var a; for (let x of xs) { (function() { a; }); a = 1; }",
+        "let a; function foo() { a = 10; } for (let x of xs) { (function() { a; }); } foo();",
+        "let a; function foo() { a = 10; for (let x of xs) { (function() { a; }); } } foo();",
+        // Not immediately invoked, or may run later.
+        "for (var i = 0; i < 10; i++) { (async function() { i; })(); }",
+        "for (var i = 0; i < 10; i++) { (function a() { i; a; })(); }",
+        "for (var i = 0; i < 10; i++) { (function() { (function() { i; }); })(); }",
+    ];
+
+    Tester::new(NoLoopFunc::NAME, pass, fail).test_and_snapshot();
+}
@@ -79,12 +79,14 @@ impl TryFrom<&serde_json::Value> for NoMagicNumbersConfig {
                         .and_then(serde_json::Value::as_array)
                         .map(|v| {
                             v.iter()
-                                .map(|v| {
-                                    if v.is_number() {
-                                        NoMagicNumbersNumber::Float(v.as_f64().unwrap())
-                                    } else {
-                                        NoMagicNumbersNumber::BigInt(v.as_str().unwrap().to_owned())
+                                .filter_map(|v| match v {
+                                    serde_json::Value::Number(number) => {
+                                        number.as_f64().map(NoMagicNumbersNumber::Float)
                                     }
+                                    serde_json::Value::String(bigint) => {
+                                        Some(NoMagicNumbersNumber::BigInt(bigint.clone()))
+                                    }
+                                    _ => None,
                                 })
                                 .collect()
                         })
@@ -326,7 +328,14 @@ fn is_class_field_initial_value(parent_kind: &AstKind<'_>) -> bool {
 }
 
 fn is_detectable_object(parent_kind: &AstKind<'_>) -> bool {
-    matches!(parent_kind, AstKind::ObjectExpression(_) | AstKind::ObjectProperty(_))
+    match parent_kind {
+        AstKind::ObjectExpression(_) | AstKind::ObjectProperty(_) => true,
+        // `obj.prop = 1` is treated like an object property, `a = 1` is always reported
+        AstKind::AssignmentExpression(expression) => {
+            !matches!(expression.left, AssignmentTarget::AssignmentTargetIdentifier(_))
+        }
+        _ => false,
+    }
 }
 
 fn is_parse_int_radix(parent_parent_node: &AstNode<'_>) -> bool {
@@ -492,13 +501,6 @@ impl NoMagicNumbers {
 
                 None
             }
-            AstKind::AssignmentExpression(expression) => {
-                if matches!(expression.left, AssignmentTarget::AssignmentTargetIdentifier(_)) {
-                    return Some(NoMagicNumberReportReason::NoMagicNumber);
-                }
-
-                None
-            }
             AstKind::JSXExpressionContainer(_) => None,
             _ => Some(NoMagicNumberReportReason::NoMagicNumber),
         }
@@ -523,6 +525,7 @@ fn test() {
         ("var x = parseInt(y, -10);", None),
         ("var x = Number.parseInt(y, 10);", None),
         ("const foo = 42;", None), // { "ecmaVersion": 6 },
+        ("var colors = {}; colors.RED = 2; colors['YELLOW'] = 3;", None),
         (
             "var foo = 42;",
             Some(serde_json::json!([{                "enforceConst": false            }])),
@@ -726,6 +729,11 @@ fn test() {
             Some(serde_json::json!([{                "detectObjects": true            }])),
         ),
         ("var colors = {}; colors.RED = 2; colors.YELLOW = 3; colors.BLUE = 4 + 5;", None),
+        (
+            "var colors = {}; colors.RED = 2; colors.YELLOW = 3;",
+            Some(serde_json::json!([{                "detectObjects": true            }])),
+        ),
+        ("var foo = 1 + 2;", Some(serde_json::json!([{ "ignore": [1, null, true] }]))),
         ("function getSecondsInMinute() {return 60;}", None),
         ("function getNegativeSecondsInMinute() {return -60;}", None),
         ("var data = ['foo', 'bar', 'baz']; var third = data[3];", None),
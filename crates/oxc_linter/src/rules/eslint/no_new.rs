@@ -28,6 +28,7 @@ declare_oxc_lint!(
     /// ```
     NoNew,
     suspicious,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNew {
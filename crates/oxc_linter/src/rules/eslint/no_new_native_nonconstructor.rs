@@ -42,6 +42,7 @@ declare_oxc_lint!(
     /// ```
     NoNewNativeNonconstructor,
     correctness,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewNativeNonconstructor {
@@ -1,4 +1,7 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{Argument, Expression, NewExpression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -47,7 +50,8 @@ declare_oxc_lint!(
     /// ```
     NoNewWrappers,
     pedantic,
-    pending
+    suggestion,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewWrappers {
@@ -61,11 +65,48 @@ impl Rule for NoNewWrappers {
         if (ident.name == "String" || ident.name == "Number" || ident.name == "Boolean")
             && ctx.semantic().is_reference_to_global_variable(ident)
         {
-            ctx.diagnostic(no_new_wrappers_diagnostic(ident.name.as_str(), expr.span));
+            ctx.diagnostic_with_suggestion(
+                no_new_wrappers_diagnostic(ident.name.as_str(), expr.span),
+                |fixer| {
+                    let is_statement = matches!(
+                        ctx.nodes().parent_kind(node.id()),
+                        Some(AstKind::ExpressionStatement(_))
+                    );
+                    match literal_argument(ident.name.as_str(), expr) {
+                        // a string literal statement could turn into a directive
+                        Some(literal) if !is_statement => fixer.replace_with(&expr.span, literal),
+                        _ => {
+                            let arguments = Span::new(ident.span.end, expr.span.end)
+                                .source_text(ctx.source_text());
+                            let arguments = if arguments.trim_start().starts_with('(') {
+                                arguments
+                            } else {
+                                "()"
+                            };
+                            fixer.replace(expr.span, format!("{}{arguments}", ident.name))
+                        }
+                    }
+                },
+            );
         }
     }
 }
 
+/// The only argument of `new String('a')`, `new Number(1)` or `new Boolean(true)`,
+/// when it is a literal of the wrapped primitive type.
+fn literal_argument<'a, 'b>(name: &str, expr: &'b NewExpression<'a>) -> Option<&'b Argument<'a>> {
+    let [argument] = expr.arguments.as_slice() else {
+        return None;
+    };
+    let is_literal = match name {
+        "String" => matches!(argument, Argument::StringLiteral(_)),
+        "Number" => matches!(argument, Argument::NumericLiteral(_)),
+        "Boolean" => matches!(argument, Argument::BooleanLiteral(_)),
+        _ => false,
+    };
+    is_literal.then_some(argument)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -108,5 +149,16 @@ fn test() {
         ",
     ];
 
-    Tester::new(NoNewWrappers::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("var a = new String('hello');", "var a = 'hello';"),
+        ("var a = new Number(10);", "var a = 10;"),
+        ("var a = new Boolean(false);", "var a = false;"),
+        ("var a = new String(foo);", "var a = String(foo);"),
+        ("var a = new Number('10');", "var a = Number('10');"),
+        ("var a = new Boolean(a, b);", "var a = Boolean(a, b);"),
+        ("var a = new String;", "var a = String();"),
+        ("new String('use strict');", "String('use strict');"),
+    ];
+
+    Tester::new(NoNewWrappers::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -82,6 +82,7 @@ declare_oxc_lint!(
     // For example, `++i` and `i++` will be rewritten as `i += 1` even though they are not the same.
     // If the code depends on the order of evaluation, then this might break it.
     conditional_suggestion,
+    node_types = [UpdateExpression]
 );
 
 impl Rule for NoPlusplus {
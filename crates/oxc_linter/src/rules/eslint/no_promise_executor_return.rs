@@ -0,0 +1,275 @@
+use oxc_ast::{
+    ast::{Argument, Expression, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_promise_executor_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Return values from promise executor functions cannot be read.")
+        .with_help("Call `resolve` or `reject` instead of returning a value.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPromiseExecutorReturn {
+    /// Allow returning `void` expressions.
+    allow_void: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow returning values from Promise executor functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The executor function of `new Promise` settles the promise by calling `resolve` or
+    /// `reject`. The value returned from the executor is ignored, so returning a value is
+    /// likely a mistake, e.g. an attempt to resolve the promise with it.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         return defaultResult;
+    ///     }
+    ///     getSomething((err, result) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(result);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// new Promise((resolve, reject) => getSomething((err, data) => {}));
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         resolve(defaultResult);
+    ///         return;
+    ///     }
+    ///     getSomething((err, result) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(result);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// new Promise((resolve, reject) => {
+    ///     getSomething((err, data) => {});
+    /// });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "no-promise-executor-return": ["error", { "allowVoid": false }] }
+    /// ```
+    ///
+    /// - `allowVoid`: allow returning `void` expressions, e.g. `return void resolve(1)`.
+    NoPromiseExecutorReturn,
+    correctness,
+    suggestion,
+    node_types = [ReturnStatement, ArrowFunctionExpression]
+);
+
+impl Rule for NoPromiseExecutorReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_void: value
+                .get(0)
+                .and_then(|v| v.get("allowVoid"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                let Some(argument) = &stmt.argument else { return };
+                if self.is_allowed(argument) {
+                    return;
+                }
+                let Some(func) = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+                    matches!(
+                        parent.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                }) else {
+                    return;
+                };
+                if is_promise_executor(func, ctx) {
+                    ctx.diagnostic(no_promise_executor_return_diagnostic(stmt.span));
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                let Some(expr) = arrow.get_expression() else { return };
+                if self.is_allowed(expr) || !is_promise_executor(node, ctx) {
+                    return;
+                }
+                let body = arrow.body.span;
+                ctx.diagnostic_with_suggestion(
+                    no_promise_executor_return_diagnostic(expr.span()),
+                    |fixer| fixer.replace(body, format!("{{{}}}", fixer.source_range(body))),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoPromiseExecutorReturn {
+    fn is_allowed(&self, expr: &Expression) -> bool {
+        self.allow_void
+            && matches!(
+                expr.without_parentheses(),
+                Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::Void
+            )
+    }
+}
+
+/// Whether `node` is a function or arrow function expression passed as the executor to the
+/// global `Promise` constructor.
+fn is_promise_executor<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    if let AstKind::Function(func) = node.kind() {
+        if !func.is_expression() {
+            return false;
+        }
+    }
+    let Some(parent) = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+        !matches!(parent.kind(), AstKind::ParenthesizedExpression(_) | AstKind::Argument(_))
+    }) else {
+        return false;
+    };
+    let AstKind::NewExpression(new_expr) = parent.kind() else {
+        return false;
+    };
+    let Expression::Identifier(ident) = &new_expr.callee else {
+        return false;
+    };
+    ident.name == "Promise"
+        && ctx.semantic().is_reference_to_global_variable(ident)
+        && new_expr
+            .arguments
+            .first()
+            .and_then(Argument::as_expression)
+            .is_some_and(|arg| arg.without_parentheses().span() == node.kind().span())
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(resolve, reject) { return 1; }", None),
+        ("function Promise(resolve, reject) { return 1; }", None),
+        ("(function (resolve, reject) { return 1; })", None),
+        ("(function foo(resolve, reject) { return 1; })", None),
+        ("(function Promise(resolve, reject) { return 1; })", None),
+        ("var foo = function (resolve, reject) { return 1; }", None),
+        ("var foo = function Promise(resolve, reject) { return 1; }", None),
+        ("var Promise = function (resolve, reject) { return 1; }", None),
+        ("(resolve, reject) => { return 1; }", None),
+        ("(resolve, reject) => 1", None),
+        ("var foo = (resolve, reject) => { return 1; }", None),
+        ("var Promise = (resolve, reject) => { return 1; }", None),
+        ("var foo = (resolve, reject) => 1", None),
+        ("var Promise = (resolve, reject) => 1", None),
+        ("var foo = { bar(resolve, reject) { return 1; } }", None),
+        ("var Promise = { bar(resolve, reject) { return 1; } }", None),
+        ("var foo = { Promise(resolve, reject) { return 1; } }", None),
+        ("new foo(function (resolve, reject) { return 1; });", None),
+        ("new foo(function bar(resolve, reject) { return 1; });", None),
+        ("new foo(function Promise(resolve, reject) { return 1; });", None),
+        ("new foo((resolve, reject) => { return 1; });", None),
+        ("new foo((resolve, reject) => 1);", None),
+        ("new promise(function foo(resolve, reject) { return 1; });", None),
+        ("new Promise.foo(function foo(resolve, reject) { return 1; });", None),
+        ("new foo.Promise(function foo(resolve, reject) { return 1; });", None),
+        ("new Promise.Promise(function foo(resolve, reject) { return 1; });", None),
+        ("new Promise()(function foo(resolve, reject) { return 1; });", None),
+        ("Promise(function (resolve, reject) { return 1; });", None),
+        ("Promise((resolve, reject) => { return 1; });", None),
+        ("Promise((resolve, reject) => 1);", None),
+        ("new Promise(foo, function (resolve, reject) { return 1; });", None),
+        ("new Promise(foo, (resolve, reject) => { return 1; });", None),
+        ("new Promise(foo, (resolve, reject) => 1);", None),
+        ("const Promise = foo; new Promise(function (resolve, reject) { return 1; });", None),
+        ("function f(Promise) { new Promise((resolve, reject) => 1); }", None),
+        ("class Promise {} new Promise((resolve, reject) => { return 1; });", None),
+        ("new Promise(function (resolve, reject) {});", None),
+        ("new Promise(function (resolve, reject) { foo(); });", None),
+        ("new Promise((resolve, reject) => {});", None),
+        ("new Promise((resolve, reject) => { foo(); });", None),
+        ("new Promise(function (resolve, reject) { return; });", None),
+        ("new Promise(function (resolve, reject) { if (foo) { return; } });", None),
+        ("new Promise((resolve, reject) => { return; });", None),
+        ("new Promise((resolve, reject) => { if (foo) { return; } else { bar(); } });", None),
+        ("new Promise(function (resolve, reject) { function foo() { return 1; } });", None),
+        ("new Promise((resolve, reject) => { (function foo() { return 1; })(); });", None),
+        ("new Promise(function (resolve, reject) { () => { return 1; } });", None),
+        ("new Promise((resolve, reject) => { () => 1 });", None),
+        (
+            "function foo() { return new Promise(function (resolve, reject) { resolve(1); }) };",
+            None,
+        ),
+        ("function foo() { return new Promise((resolve, reject) => { resolve(1); }) };", None),
+        (
+            "new Promise(function (resolve, reject) { return void 0; });",
+            Some(json!([{ "allowVoid": true }])),
+        ),
+        (
+            "new Promise((resolve, reject) => { return void resolve(1); });",
+            Some(json!([{ "allowVoid": true }])),
+        ),
+        (
+            "new Promise((resolve, reject) => void resolve(1));",
+            Some(json!([{ "allowVoid": true }])),
+        ),
+        ("new Promise((resolve, reject) => (void 0));", Some(json!([{ "allowVoid": true }]))),
+    ];
+
+    let fail = vec![
+        ("new Promise(function (resolve, reject) { return 1; })", None),
+        ("new Promise((resolve, reject) => { return 1; })", None),
+        ("new Promise((resolve, reject) => 1)", None),
+        ("new Promise(function foo(resolve, reject) { return 1; })", None),
+        ("new Promise(async function (resolve, reject) { return 1; })", None),
+        ("new Promise(((resolve, reject) => { return 1; }))", None),
+        ("new Promise((resolve, reject) => (1))", None),
+        ("new Promise(r => resolve(1))", None),
+        ("new Promise(function (resolve, reject) { if (foo) { return 1; } })", None),
+        ("new Promise((resolve, reject) => { try { return 1; } catch(e) {} })", None),
+        ("new Promise(function (resolve, reject) { while (foo){ if (bar) break; else return 1; } })", None),
+        ("new Promise(function (resolve, reject) { return void 0; })", None),
+        ("new Promise((resolve, reject) => void resolve(1))", None),
+        ("new Promise((resolve, reject) => { return resolve(1); })", Some(json!([{ "allowVoid": true }]))),
+        ("new Promise((resolve, reject) => resolve(1))", Some(json!([{ "allowVoid": true }]))),
+        ("new Promise(function (resolve, reject) { (function () { new Promise(() => 1) })(); })", None),
+        ("new Promise(function () { return new Promise(function () { return 1; }); })", None),
+        ("function foo() { return new Promise(function (resolve, reject) { return 1; }) }", None),
+    ];
+
+    let fix = vec![
+        ("new Promise((resolve, reject) => 1)", "new Promise((resolve, reject) => {1})"),
+        ("new Promise(r => r(1))", "new Promise(r => {r(1)})"),
+        ("new Promise(r => (a, b))", "new Promise(r => {(a, b)})"),
+    ];
+
+    Tester::new(NoPromiseExecutorReturn::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
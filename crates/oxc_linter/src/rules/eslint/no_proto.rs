@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// ```
     NoProto,
     restriction,
-    pending
+    pending,
+    node_types = [MemberExpression]
 );
 
 impl Rule for NoProto {
@@ -1,4 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -41,7 +44,9 @@ declare_oxc_lint!(
     /// var barIsEnumerable = foo.propertyIsEnumerable("bar");
     /// ```
     NoPrototypeBuiltins,
-    pedantic
+    pedantic,
+    suggestion,
+    node_types = [CallExpression]
 );
 
 const DISALLOWED_PROPS: &[&str; 3] = &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
@@ -57,8 +62,74 @@ impl Rule for NoPrototypeBuiltins {
         let Some(prop_name) = member_expr.static_property_name() else {
             return;
         };
-        if DISALLOWED_PROPS.contains(&prop_name) {
-            ctx.diagnostic(no_prototype_builtins_diagnostic(prop_name, member_expr.span()));
+        if !DISALLOWED_PROPS.contains(&prop_name) {
+            return;
+        }
+        let diagnostic = no_prototype_builtins_diagnostic(prop_name, member_expr.span());
+
+        // `foo?.hasOwnProperty(bar)` may short-circuit, so it has to be fixed manually
+        let is_optional = expr.optional
+            || !matches!(
+                expr.callee,
+                Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_)
+            )
+            || is_after_optional(member_expr)
+            || matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ChainExpression(_)));
+        // `Object` may be shadowed
+        let is_object_global = ctx.scopes().find_binding(node.scope_id(), "Object").is_none();
+        if is_optional || !is_object_global {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            let object = member_expr.object();
+            let mut object_text = fixer.source_range(object.span()).to_string();
+            if matches!(object, Expression::SequenceExpression(_)) {
+                object_text = format!("({object_text})");
+            }
+            let after_callee = Span::new(expr.callee.span().end, expr.span.end);
+            let arguments = fixer.source_range(after_callee);
+            let arguments = arguments.find('(').map_or("", |start| &arguments[start + 1..]);
+            let delimiter = if expr.arguments.is_empty() { "" } else { ", " };
+            fixer.replace(
+                expr.span,
+                format!("Object.prototype.{prop_name}.call({object_text}{delimiter}{arguments}"),
+            )
+        });
+    }
+}
+
+/// Whether `member_expr` is part of an optional chain started before it, like `a?.b.c`.
+fn is_after_optional(member_expr: &MemberExpression) -> bool {
+    let mut object = member_expr.object();
+    loop {
+        match object {
+            Expression::StaticMemberExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                object = &member.object;
+            }
+            Expression::ComputedMemberExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                object = &member.object;
+            }
+            Expression::PrivateFieldExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                object = &member.object;
+            }
+            Expression::CallExpression(call) => {
+                if call.optional {
+                    return true;
+                }
+                object = &call.callee;
+            }
+            _ => return member_expr.optional(),
         }
     }
 }
@@ -112,5 +183,32 @@ fn test() {
         "(foo?.[`hasOwnProperty`])('bar')",
     ];
 
-    Tester::new(NoPrototypeBuiltins::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.hasOwnProperty('bar')", "Object.prototype.hasOwnProperty.call(foo, 'bar')"),
+        ("foo.isPrototypeOf('bar')", "Object.prototype.isPrototypeOf.call(foo, 'bar')"),
+        (
+            "foo.propertyIsEnumerable('bar')",
+            "Object.prototype.propertyIsEnumerable.call(foo, 'bar')",
+        ),
+        ("foo.bar.hasOwnProperty('bar')", "Object.prototype.hasOwnProperty.call(foo.bar, 'bar')"),
+        ("foo['hasOwnProperty']('bar')", "Object.prototype.hasOwnProperty.call(foo, 'bar')"),
+        ("foo[`isPrototypeOf`]('bar').baz", "Object.prototype.isPrototypeOf.call(foo, 'bar').baz"),
+        ("foo.hasOwnProperty()", "Object.prototype.hasOwnProperty.call(foo)"),
+        ("foo.hasOwnProperty( 'bar' , )", "Object.prototype.hasOwnProperty.call(foo,  'bar' , )"),
+        ("(a, b).hasOwnProperty('bar')", "Object.prototype.hasOwnProperty.call((a, b), 'bar')"),
+        ("foo.hasOwnProperty('bar')?.baz", "Object.prototype.hasOwnProperty.call(foo, 'bar')?.baz"),
+        // no suggestion for optional chains
+        ("foo?.hasOwnProperty('bar')", "foo?.hasOwnProperty('bar')"),
+        ("foo?.bar.hasOwnProperty('baz')", "foo?.bar.hasOwnProperty('baz')"),
+        ("foo.hasOwnProperty?.('bar')", "foo.hasOwnProperty?.('bar')"),
+        ("(foo?.hasOwnProperty)('bar')", "(foo?.hasOwnProperty)('bar')"),
+        ("foo?.()?.bar.hasOwnProperty('baz')", "foo?.()?.bar.hasOwnProperty('baz')"),
+        // no suggestion when `Object` is shadowed
+        (
+            "let Object = {}; foo.hasOwnProperty('bar')",
+            "let Object = {}; foo.hasOwnProperty('bar')",
+        ),
+    ];
+
+    Tester::new(NoPrototypeBuiltins::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
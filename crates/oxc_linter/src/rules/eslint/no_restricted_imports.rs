@@ -0,0 +1,620 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn with_message(diagnostic: OxcDiagnostic, message: Option<&str>) -> OxcDiagnostic {
+    match message {
+        Some(message) => diagnostic.with_help(message.to_string()),
+        None => diagnostic,
+    }
+}
+
+fn restricted_path_diagnostic(source: &str, message: Option<&str>, span: Span) -> OxcDiagnostic {
+    let diagnostic =
+        OxcDiagnostic::warn(format!("'{source}' import is restricted from being used."))
+            .with_label(span);
+    with_message(diagnostic, message)
+}
+
+fn restricted_pattern_diagnostic(source: &str, message: Option<&str>, span: Span) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "'{source}' import is restricted from being used by a pattern."
+    ))
+    .with_label(span);
+    with_message(diagnostic, message)
+}
+
+fn restricted_name_diagnostic(
+    name: &str,
+    source: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!("'{name}' import from '{source}' is restricted."))
+        .with_label(span);
+    with_message(diagnostic, message)
+}
+
+fn restricted_everything_diagnostic(
+    names: &str,
+    source: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "* import is invalid because '{names}' from '{source}' is restricted."
+    ))
+    .with_label(span);
+    with_message(diagnostic, message)
+}
+
+fn not_allowed_name_diagnostic(
+    name: &str,
+    source: &str,
+    allowed: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "'{name}' import from '{source}' is restricted because only '{allowed}' can be imported."
+    ))
+    .with_label(span);
+    with_message(diagnostic, message)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPattern>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A module that can't be imported, or only some of its exports.
+#[derive(Debug, Default, Clone)]
+struct RestrictedNames {
+    message: Option<CompactStr>,
+    /// Only these exports are restricted.
+    import_names: Option<Vec<CompactStr>>,
+    /// Only these exports are allowed.
+    allow_import_names: Option<Vec<CompactStr>>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: CompactStr,
+    names: RestrictedNames,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPattern {
+    matcher: PatternMatcher,
+    names: RestrictedNames,
+}
+
+#[derive(Debug, Clone)]
+enum PatternMatcher {
+    /// `.gitignore` style patterns. The last pattern that matches decides, and
+    /// patterns starting with `!` allow modules again.
+    Group(Vec<(GlobSet, bool)>),
+    Regex(Regex),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified modules when loaded by `import` or `export ... from`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some modules should not be used in a project, e.g. because they are deprecated,
+    /// too large for a browser bundle, or belong to another layer of the architecture.
+    /// This rule enforces such boundaries, with a message that explains the alternative.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `["error", { "paths": [{ "name": "lodash", "importNames": ["pick"] }], "patterns": ["src/server/*"] }]`:
+    /// ```js
+    /// import { pick } from 'lodash';
+    /// import * as _ from 'lodash';
+    /// import db from 'src/server/db';
+    /// export * from 'src/server/db';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```js
+    /// import { merge } from 'lodash';
+    /// import api from 'src/client/api';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// The options are either a list of module names, or an object with `paths` and
+    /// `patterns`:
+    ///
+    /// ```json
+    /// {
+    ///   "no-restricted-imports": ["error", {
+    ///     "paths": [
+    ///       "fs",
+    ///       { "name": "lodash", "importNames": ["pick"], "message": "Use `lodash/pick` instead." },
+    ///       { "name": "react", "allowImportNames": ["useState", "useEffect"] }
+    ///     ],
+    ///     "patterns": [
+    ///       "src/server/*",
+    ///       { "group": ["@/internal/*", "!@/internal/public"], "message": "Use the public API." },
+    ///       { "regex": "^\\.\\./\\.\\./", "caseSensitive": true }
+    ///     ]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// - `paths`: module names that are restricted. An entry with `importNames` only restricts
+    ///   those exports, and an entry with `allowImportNames` restricts all exports but those.
+    /// - `patterns`: `.gitignore` style patterns, or regular expressions with `regex`, that are
+    ///   matched against the module names. Matching is case insensitive unless `caseSensitive` is
+    ///   set. Objects support `message`, `importNames` and `allowImportNames` like `paths`.
+    NoRestrictedImports,
+    restriction,
+    node_types = [ImportDeclaration, ExportNamedDeclaration, ExportAllDeclaration]
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoRestrictedImportsConfig::default();
+        let entries = match value.as_array() {
+            // `["error", { "paths": [...], "patterns": [...] }]`
+            Some(entries) if entries.len() == 1 && entries[0].is_object() => {
+                let options = &entries[0];
+                if options.get("paths").is_some() || options.get("patterns").is_some() {
+                    let patterns =
+                        options.get("patterns").and_then(Value::as_array).map_or(&[][..], |v| v);
+                    // Plain patterns form a single group, so that they can be negated.
+                    let group = patterns.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+                    if !group.is_empty() {
+                        config.patterns.extend(PatternMatcher::group(group, false).map(
+                            |matcher| RestrictedPattern {
+                                matcher,
+                                names: RestrictedNames::default(),
+                            },
+                        ));
+                    }
+                    config.patterns.extend(
+                        patterns
+                            .iter()
+                            .filter(|pattern| pattern.is_object())
+                            .filter_map(RestrictedPattern::from_configuration),
+                    );
+                    options.get("paths").and_then(Value::as_array)
+                } else {
+                    Some(entries)
+                }
+            }
+            entries => entries,
+        };
+        config.paths =
+            entries.into_iter().flatten().filter_map(RestrictedPath::from_configuration).collect();
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (source, imported) = match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let imported = decl
+                    .specifiers
+                    .iter()
+                    .flatten()
+                    .map(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            (specifier.imported.name().into_compact_str(), specifier.span)
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            (CompactStr::new("default"), specifier.span)
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            (CompactStr::new("*"), specifier.span)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (&decl.source, imported)
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                let imported = decl
+                    .specifiers
+                    .iter()
+                    .map(|specifier| (specifier.local.name().into_compact_str(), specifier.span))
+                    .collect();
+                (source, imported)
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                (&decl.source, vec![(CompactStr::new("*"), decl.span)])
+            }
+            _ => return,
+        };
+
+        for path in self.paths.iter().filter(|path| path.name == source.value.as_str()) {
+            path.names.check(source, &imported, false, ctx);
+        }
+        for pattern in
+            self.patterns.iter().filter(|pattern| pattern.matcher.is_match(&source.value))
+        {
+            pattern.names.check(source, &imported, true, ctx);
+        }
+    }
+}
+
+impl RestrictedNames {
+    fn from_configuration(value: &Value) -> Self {
+        let names = |key| {
+            value
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|names| names.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+        };
+        Self {
+            message: value.get("message").and_then(Value::as_str).map(CompactStr::from),
+            import_names: names("importNames"),
+            allow_import_names: names("allowImportNames"),
+        }
+    }
+
+    /// Report the import of `source` with the `imported` names and their spans.
+    fn check(
+        &self,
+        source: &StringLiteral,
+        imported: &[(CompactStr, Span)],
+        by_pattern: bool,
+        ctx: &LintContext,
+    ) {
+        let message = self.message.as_deref();
+        if let Some(import_names) = &self.import_names {
+            for (name, span) in imported {
+                if name == "*" {
+                    let names = import_names.join(", ");
+                    ctx.diagnostic(restricted_everything_diagnostic(
+                        &names,
+                        &source.value,
+                        message,
+                        *span,
+                    ));
+                } else if import_names.contains(name) {
+                    ctx.diagnostic(restricted_name_diagnostic(name, &source.value, message, *span));
+                }
+            }
+        } else if let Some(allowed) = &self.allow_import_names {
+            for (name, span) in imported.iter().filter(|(name, _)| !allowed.contains(name)) {
+                ctx.diagnostic(not_allowed_name_diagnostic(
+                    name,
+                    &source.value,
+                    &allowed.join(", "),
+                    message,
+                    *span,
+                ));
+            }
+        } else if by_pattern {
+            ctx.diagnostic(restricted_pattern_diagnostic(&source.value, message, source.span()));
+        } else {
+            ctx.diagnostic(restricted_path_diagnostic(&source.value, message, source.span()));
+        }
+    }
+}
+
+impl RestrictedPath {
+    fn from_configuration(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(name) => {
+                Some(Self { name: name.as_str().into(), names: RestrictedNames::default() })
+            }
+            Value::Object(_) => Some(Self {
+                name: value.get("name").and_then(Value::as_str)?.into(),
+                names: RestrictedNames::from_configuration(value),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl RestrictedPattern {
+    fn from_configuration(value: &Value) -> Option<Self> {
+        let case_sensitive = value.get("caseSensitive").and_then(Value::as_bool).unwrap_or(false);
+        let matcher = if let Some(regex) = value.get("regex").and_then(Value::as_str) {
+            PatternMatcher::Regex(
+                RegexBuilder::new(regex).case_insensitive(!case_sensitive).build().ok()?,
+            )
+        } else {
+            let group = value.get("group").and_then(Value::as_array)?;
+            PatternMatcher::group(group.iter().filter_map(Value::as_str), case_sensitive)?
+        };
+        Some(Self { matcher, names: RestrictedNames::from_configuration(value) })
+    }
+}
+
+impl PatternMatcher {
+    fn group<'a, I: IntoIterator<Item = &'a str>>(
+        patterns: I,
+        case_sensitive: bool,
+    ) -> Option<Self> {
+        let mut group = vec![];
+        for pattern in patterns {
+            let (pattern, negated) = match pattern.strip_prefix('!') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, false),
+            };
+            // Like in `.gitignore`, a pattern with a slash in the beginning or middle is
+            // relative to the root, otherwise it matches at any level. A trailing slash only
+            // matches "directories", i.e. the module must be a subpath.
+            let (pattern, subpaths_only) = match pattern.strip_suffix('/') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, false),
+            };
+            let (pattern, anchored) = match pattern.strip_prefix('/') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, pattern.contains('/')),
+            };
+            let mut globs = vec![format!("{pattern}/**")];
+            if !subpaths_only {
+                globs.push(pattern.to_string());
+            }
+            if !anchored {
+                globs = globs.into_iter().flat_map(|glob| [format!("**/{glob}"), glob]).collect();
+            }
+
+            let mut builder = GlobSetBuilder::new();
+            for glob in globs {
+                let glob = GlobBuilder::new(&glob)
+                    .literal_separator(true)
+                    .case_insensitive(!case_sensitive)
+                    .build()
+                    .ok()?;
+                builder.add(glob);
+            }
+            group.push((builder.build().ok()?, negated));
+        }
+        Some(Self::Group(group))
+    }
+
+    fn is_match(&self, source: &str) -> bool {
+        match self {
+            Self::Group(group) => group
+                .iter()
+                .rev()
+                .find(|(globs, _)| globs.is_match(source))
+                .is_some_and(|(_, negated)| !negated),
+            Self::Regex(regex) => regex.is_match(source),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(json!(["osx"]))),
+        ("import fs from 'fs';", Some(json!(["crypto"]))),
+        ("import path from 'path';", Some(json!(["crypto", "stream", "os"]))),
+        ("import async from 'async';", None),
+        ("import 'foo';", Some(json!(["crypto"]))),
+        ("import 'foo/bar';", Some(json!(["foo"]))),
+        ("import withPaths from 'foo/bar';", Some(json!([{ "paths": ["foo", "bar"] }]))),
+        ("import withPatterns from 'foo/bar';", Some(json!([{ "patterns": ["foo/c*"] }]))),
+        ("import foo from 'foo';", Some(json!(["../foo"]))),
+        ("import foo from 'foo';", Some(json!([{ "paths": ["../foo"] }]))),
+        ("import foo from 'foo';", Some(json!([{ "patterns": ["../foo"] }]))),
+        ("import foo from 'foo';", Some(json!(["/foo"]))),
+        ("import relative from '../foo';", Some(json!(["foo"]))),
+        ("import relativeWithPaths from '../foo';", Some(json!([{ "paths": ["foo"] }]))),
+        ("import absolute from '/foo';", Some(json!(["foo"]))),
+        ("import absoluteWithPaths from '/foo';", Some(json!([{ "paths": ["foo"] }]))),
+        (
+            "import withPatternsAndPaths from 'foo/bar';",
+            Some(json!([{ "paths": ["foo"], "patterns": ["foo/c*"] }])),
+        ),
+        (
+            "import withGitignores from 'foo/bar';",
+            Some(json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import withPatterns from 'foo/bar';",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatternsCaseSensitive from 'foo';",
+            Some(json!([{ "patterns": [{ "group": ["FOO"], "caseSensitive": true }] }])),
+        ),
+        (
+            "import AllowedObject from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { 'AllowedObject' as bar } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { DisallowedObject as AllowedObject } from \"bar\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export { bar } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export { bar as DisallowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        ("export { bar } from \"foo\";", None),
+        ("export const foo = 1;", Some(json!(["foo"]))),
+        (
+            "import { useState } from 'react';",
+            Some(json!([{ "paths": [{ "name": "react", "allowImportNames": ["useState"] }] }])),
+        ),
+        (
+            "import withRegex from 'foo/bar';",
+            Some(json!([{ "patterns": [{ "regex": "foo/baz" }] }])),
+        ),
+        (
+            "import withRegexCaseSensitive from 'FOO/bar';",
+            Some(json!([{ "patterns": [{ "regex": "foo/bar", "caseSensitive": true }] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("import \"fs\"", Some(json!(["fs"]))),
+        ("import os from \"os\";", Some(json!(["fs", "crypto ", "stream", "os"]))),
+        ("import \"foo/bar\";", Some(json!(["foo/bar"]))),
+        ("import withPaths from \"foo/bar\";", Some(json!([{ "paths": ["foo/bar"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["foo"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["bar"] }]))),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use foo/bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/bar", "foo/baz"], "message": "some foo subimports are restricted" }] }]),
+            ),
+        ),
+        (
+            "import withPatterns from \"foo/bar\";",
+            Some(json!([{ "patterns": [{ "group": ["foo/bar"] }] }])),
+        ),
+        (
+            "import withPatternsCaseInsensitive from 'foo';",
+            Some(json!([{ "patterns": [{ "group": ["FOO"] }] }])),
+        ),
+        (
+            "import withGitignores from \"foo/bar\";",
+            Some(json!([{ "patterns": ["foo/*", "!foo/baz"] }])),
+        ),
+        ("export * from \"fs\";", Some(json!(["fs"]))),
+        ("export * as ns from \"fs\";", Some(json!(["fs"]))),
+        ("export {a} from \"fs\";", Some(json!(["fs"]))),
+        (
+            "export {foo as b} from \"fs\";",
+            Some(
+                json!([{ "paths": [{ "name": "fs", "importNames": ["foo"], "message": "Don\"t import \"foo\"." }] }]),
+            ),
+        ),
+        (
+            "export {'foo' as b} from \"fs\";",
+            Some(
+                json!([{ "paths": [{ "name": "fs", "importNames": ["foo"], "message": "Don\"t import \"foo\"." }] }]),
+            ),
+        ),
+        (
+            "export * as ns from \"fs\";",
+            Some(
+                json!([{ "paths": [{ "name": "fs", "importNames": ["foo"], "message": "Don\"t import \"foo\"." }] }]),
+            ),
+        ),
+        (
+            "import withGitignores from \"foo\";",
+            Some(json!([{ "name": "foo", "message": "Please import from 'bar' instead." }])),
+        ),
+        (
+            "import withGitignores from \"bar\";",
+            Some(
+                json!(["foo", { "name": "bar", "message": "Please import from 'baz' instead." }, "baz"]),
+            ),
+        ),
+        (
+            "import withGitignores from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "message": "Please import from 'bar' instead." }] }]),
+            ),
+        ),
+        (
+            "import DisallowedObject from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["default"], "message": "Please import the default import of 'foo' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import * as All from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"], "message": "Please import 'DisallowedObject' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import { DisallowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { DisallowedObject as AllowedObject } from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"], "message": "Please import 'DisallowedObject' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import { AllowedObject, DisallowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { DisallowedObjectOne, DisallowedObjectTwo, AllowedObject } from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObjectOne", "DisallowedObjectTwo"] }] }]),
+            ),
+        ),
+        (
+            "import { useMemo } from 'react';",
+            Some(
+                json!([{ "paths": [{ "name": "react", "allowImportNames": ["useState", "useEffect"] }] }]),
+            ),
+        ),
+        (
+            "import * as React from 'react';",
+            Some(json!([{ "paths": [{ "name": "react", "allowImportNames": ["useState"] }] }])),
+        ),
+        (
+            "import { pick } from 'lodash-es';",
+            Some(
+                json!([{ "patterns": [{ "group": ["lodash*"], "importNames": ["pick"], "message": "Use `pick` of `utils` instead." }] }]),
+            ),
+        ),
+        ("import relative from '../foo';", Some(json!(["../foo"]))),
+        ("import relative from '../../foo';", Some(json!([{ "patterns": ["../**"] }]))),
+        ("import absolute from '/foo';", Some(json!(["/foo"]))),
+        (
+            "import withRegex from 'FOO/bar';",
+            Some(json!([{ "patterns": [{ "regex": "^foo/(bar|baz)$", "message": "Use qux." }] }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,233 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    AstNode, Selector,
+};
+
+fn no_restricted_syntax_diagnostic(restriction: &RestrictedSyntax, span: Span) -> OxcDiagnostic {
+    let message = match &restriction.message {
+        Some(message) => message.to_string(),
+        None => format!("Using '{}' is not allowed.", restriction.source),
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    source: CompactStr,
+    message: Option<CompactStr>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching the configured selectors.
+    ///
+    /// Selectors are a subset of [esquery](https://github.com/estools/esquery) selectors, like in
+    /// ESLint: node types, such as `WithStatement`, or `*` for any node, attributes such as
+    /// `[async=true]`, `[callee.name="foo"]` or `[name=/^_/]`, the child (`>`) and descendant
+    /// (` `) combinators, and lists separated by `,`. Node types and attributes are those of
+    /// [ESTree](https://github.com/estree/estree). Other selectors, such as those using
+    /// pseudo-classes like `:not(...)` or `:first-child`, are not supported: they are ignored
+    /// when linting, and reported by `oxlint --validate-config`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. This rule
+    /// disallows the syntax a project chooses not to use, such as `with` statements or
+    /// `for...in` loops.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the
+    /// `"FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"` options:
+    /// ```js
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// const doSomething = function () {};
+    ///
+    /// foo in bar;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```js
+    /// me.dontMess();
+    ///
+    /// function doSomething() {};
+    ///
+    /// foo instanceof bar;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// Selectors are given as strings, or as objects with a custom `message`:
+    ///
+    /// ```json
+    /// {
+    ///   "no-restricted-syntax": [
+    ///     "error",
+    ///     "WithStatement",
+    ///     {
+    ///       "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+    ///       "message": "setTimeout must always be invoked with two arguments."
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    NoRestrictedSyntax,
+    restriction,
+);
+
+/// The selector and custom message of an option, e.g. `"WithStatement"` or
+/// `{ "selector": "WithStatement", "message": "..." }`.
+fn parse_restriction(restriction: &Value) -> Option<(&str, Option<CompactStr>)> {
+    match restriction {
+        Value::String(source) => Some((source.as_str(), None)),
+        Value::Object(object) => Some((
+            object.get("selector").and_then(Value::as_str)?,
+            object.get("message").and_then(Value::as_str).map(CompactStr::from),
+        )),
+        _ => None,
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let restrictions = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|restriction| {
+                let (source, message) = parse_restriction(restriction)?;
+                let selector = Selector::parse(source).ok()?;
+                Some(RestrictedSyntax { selector, source: CompactStr::from(source), message })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedSyntaxConfig { restrictions }))
+    }
+
+    fn validate_configuration(value: &Value) -> Vec<(usize, String)> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .filter_map(|(index, restriction)| {
+                let (source, _) = parse_restriction(restriction)?;
+                let error = Selector::parse(source).err()?;
+                Some((index, error.message.to_string()))
+            })
+            .collect()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restriction in &self.restrictions {
+            if restriction.selector.matches(node, ctx.semantic()) {
+                ctx.diagnostic(no_restricted_syntax_diagnostic(restriction, node.kind().span()));
+            }
+        }
+    }
+
+    fn should_run(&self, _ctx: &ContextHost) -> bool {
+        !self.restrictions.is_empty()
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("doSomething();", Some(json!(["FunctionExpression"]))),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property[computed=true]"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length=3]"]))),
+        ("foo(bar)", Some(json!(["CallExpression[arguments.length!=1]"]))),
+        ("foo(bar, baz)", Some(json!(["NewExpression > Identifier"]))),
+        ("var a = foo.bar;", Some(json!(["CallExpression MemberExpression"]))),
+        ("foo.bar()", Some(json!(["MemberExpression > MemberExpression"]))),
+        ("bar(foo.baz)", Some(json!(["CallExpression > MemberExpression[property.name='bar']"]))),
+        ("foo instanceof bar", Some(json!(["BinaryExpression[operator='in']"]))),
+        ("var _foo;", Some(json!(["Identifier[name=/^bar/]"]))),
+        ("var foo;", Some(json!(["Identifier[name!=/^f/]"]))),
+        ("foo(bar)", Some(json!([{ "selector": "CallExpression > Literal" }]))),
+        ("foo(bar)", Some(json!([{ "message": "no selector" }]))),
+        ("(foo)", Some(json!(["ParenthesizedExpression"]))),
+        ("foo(bar)", Some(json!(["Argument"]))),
+        ("async function foo() {}", Some(json!(["FunctionDeclaration[async=false]"]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("foo(bar, baz)", Some(json!(["CallExpression[arguments.length=2]"]))),
+        ("foo(bar, baz)", Some(json!(["CallExpression > Identifier"]))),
+        ("foo(bar(baz))", Some(json!(["CallExpression Identifier[name='baz']"]))),
+        ("foo.bar.baz", Some(json!(["MemberExpression > MemberExpression"]))),
+        ("foo(a.bar)", Some(json!(["CallExpression > MemberExpression[property.name='bar']"]))),
+        ("foo in bar", Some(json!(["BinaryExpression[operator='in']"]))),
+        ("var _foo; var _Bar;", Some(json!(["Identifier[name=/^_b/i]"]))),
+        ("var foo;", Some(json!(["Identifier[name!=/^_/]"]))),
+        ("var foo = 1 + 2;", Some(json!(["Literal[value=1], Literal[value=2]"]))),
+        ("var foo = 'bar';", Some(json!(["VariableDeclarator > Literal[raw=\"'bar'\"]"]))),
+        ("const foo = function () {};", Some(json!(["FunctionExpression"]))),
+        ("function foo() {}", Some(json!(["FunctionDeclaration > Identifier"]))),
+        ("async function foo() {}", Some(json!(["FunctionDeclaration[async=true]"]))),
+        ("class A {}", Some(json!(["ClassDeclaration"]))),
+        ("foo((bar))", Some(json!(["CallExpression > Identifier"]))),
+        ("foo(...bar)", Some(json!(["CallExpression > SpreadElement > Identifier"]))),
+        ("label: foo();", Some(json!(["LabeledStatement *"]))),
+        ("var foo = 1;", Some(json!(["[kind='var']"]))),
+        ("<div className='foo' />", Some(json!(["JSXAttribute[name.name='className']"]))),
+        (
+            "setTimeout(foo);",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+                "message": "setTimeout must always be invoked with two arguments."
+            }])),
+        ),
+        ("with (foo) {}", Some(json!(["WithStatement", ":not(Foo)"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,232 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode};
+
+fn no_return_await_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Redundant use of `await` on a return value.")
+        .with_help("Remove the redundant `await`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReturnAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary `return await`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The value returned from an `async` function is always wrapped in a `Promise`, so
+    /// awaiting a promise only to return its value is usually unnecessary. It can make the
+    /// code look like it waits for something that it doesn't have to wait for.
+    ///
+    /// `return await` is still needed inside a `try` block, or inside a `catch` block that
+    /// has a `finally` block, so that errors of the promise are handled there. Those are not
+    /// reported.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// async function foo() {
+    ///     return await bar();
+    /// }
+    ///
+    /// const baz = async () => await qux();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// async function foo() {
+    ///     return bar();
+    /// }
+    ///
+    /// async function baz() {
+    ///     try {
+    ///         return await qux();
+    ///     } catch (error) {
+    ///         handle(error);
+    ///     }
+    /// }
+    /// ```
+    NoReturnAwait,
+    pedantic,
+    suggestion,
+    node_types = [AwaitExpression]
+);
+
+impl Rule for NoReturnAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(expr) = node.kind() else { return };
+        if !is_in_tail_call_position(node, ctx) || has_error_handler(node, ctx) {
+            return;
+        }
+
+        let keyword = Span::sized(expr.span.start, 5);
+        ctx.diagnostic_with_suggestion(no_return_await_diagnostic(keyword), |fixer| {
+            fixer.replace(expr.span, fixer.source_range(expr.argument.span()).to_string())
+        });
+    }
+}
+
+/// Whether the value of `node` is returned from the function.
+fn is_in_tail_call_position<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let mut current = node;
+    while let Some(parent) = outermost_paren_parent(current, ctx) {
+        let span = current.kind().span();
+        match parent.kind() {
+            AstKind::ReturnStatement(_) => return true,
+            // The expression body of an arrow function.
+            AstKind::ExpressionStatement(_) => {
+                return ctx.nodes().iter_parents(parent.id()).nth(2).is_some_and(|node| {
+                    matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+                });
+            }
+            AstKind::ConditionalExpression(cond) => {
+                if cond.test.without_parentheses().span() == span {
+                    return false;
+                }
+            }
+            AstKind::LogicalExpression(expr) => {
+                if expr.right.without_parentheses().span() != span {
+                    return false;
+                }
+            }
+            AstKind::SequenceExpression(expr) => {
+                if expr.expressions.last().map(|expr| expr.without_parentheses().span())
+                    != Some(span)
+                {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+        current = parent;
+    }
+    false
+}
+
+/// Whether errors thrown at `node` are caught or cleaned up in the function, so that the
+/// promise has to be awaited there.
+fn has_error_handler<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let mut child = node;
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        if parent.kind().is_function_like() {
+            return false;
+        }
+        if let AstKind::TryStatement(stmt) = parent.kind() {
+            let span = child.kind().span();
+            if stmt.block.span == span
+                || (stmt.finalizer.is_some()
+                    && stmt.handler.as_ref().is_some_and(|handler| handler.span == span))
+            {
+                return true;
+            }
+        }
+        child = parent;
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "\nasync function foo() {\n\tawait bar(); return;\n}\n",
+        "\nasync function foo() {\n\tconst x = await bar(); return x;\n}\n",
+        "\nasync () => { return bar(); }\n",
+        "\nasync () => bar()\n",
+        "\nasync function foo() {\nif (a) {\n\t\tif (b) {\n\t\t\treturn bar();\n\t\t}\n\t}\n}\n",
+        "\nasync () => {\nif (a) {\n\t\tif (b) {\n\t\t\treturn bar();\n\t\t}\n\t}\n}\n",
+        "\nasync function foo() {\n\treturn (await bar() && a);\n}\n",
+        "\nasync function foo() {\n\treturn (await bar() || a);\n}\n",
+        "\nasync function foo() {\n\treturn (a && await baz() && b);\n}\n",
+        "\nasync function foo() {\n\treturn (await bar(), a);\n}\n",
+        "\nasync function foo() {\n\treturn (await baz(), await bar(), a);\n}\n",
+        "\nasync function foo() {\n\treturn (a, b, (await bar(), c));\n}\n",
+        "\nasync function foo() {\n\treturn (await bar() ? a : b);\n}\n",
+        "\nasync function foo() {\n\treturn ((a && await bar()) ? b : c);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? (await bar(), a) : b);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? (await bar() && a) : b);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? a : (await bar(), b));\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? a : (await bar() && b));\n}\n",
+        "\nasync () => (await bar(), a)\n",
+        "\nasync () => (await bar() && a)\n",
+        "\nasync () => (await bar() || a)\n",
+        "\nasync () => (a && await bar() && b)\n",
+        "\nasync () => (await baz(), await bar(), a)\n",
+        "\nasync () => (a, b, (await bar(), c))\n",
+        "\nasync () => (await bar() ? a : b)\n",
+        "\nasync () => ((a && await bar()) ? b : c)\n",
+        "\nasync () => (baz() ? (await bar(), a) : b)\n",
+        "\nasync () => (baz() ? (await bar() && a) : b)\n",
+        "\nasync () => (baz() ? a : (await bar(), b))\n",
+        "\nasync () => (baz() ? a : (await bar() && b))\n",
+        "\nasync function foo() {\n\ttry {\n\t\treturn await bar();\n\t} catch (e) {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\treturn await bar();\n\t} finally {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {}\n\tcatch (e) {\n\t\treturn await bar();\n\t} finally {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\ttry {}\n\t\tfinally {\n\t\t\treturn await bar();\n\t\t}\n\t} finally {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\ttry {}\n\t\tcatch (e) {\n\t\t\treturn await bar();\n\t\t}\n\t} finally {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\treturn (a, await bar());\n\t} catch (e) {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\treturn (qux() ? await bar() : b);\n\t} catch (e) {\n\t\tbaz();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {\n\t\treturn (a && await bar());\n\t} catch (e) {\n\t\tbaz();\n\t}\n}\n",
+    ];
+
+    let fail = vec![
+        "\nasync function foo() {\n\treturn await bar();\n}\n",
+        "\nasync function foo() {\n\treturn await(bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a, await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a, b, await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a && await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a && b && await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a || await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (a, b, (c, d, await bar()));\n}\n",
+        "\nasync function foo() {\n\treturn (a, b, (c && await bar()));\n}\n",
+        "\nasync function foo() {\n\treturn (await baz(), b, await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? await bar() : b);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? a : await bar());\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? (a, await bar()) : b);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? a : (b, await bar()));\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? (a && await bar()) : b);\n}\n",
+        "\nasync function foo() {\n\treturn (baz() ? a : (b && await bar()));\n}\n",
+        "\nasync () => { return await bar(); }\n",
+        "\nasync () => await bar()\n",
+        "\nasync () => (a, b, await bar())\n",
+        "\nasync () => (a && await bar())\n",
+        "\nasync () => (baz() ? await bar() : b)\n",
+        "\nasync () => (baz() ? a : (b, await bar()))\n",
+        "\nasync () => (baz() ? a : (b && await bar()))\n",
+        "\nasync function foo() {\nif (a) {\n\t\tif (b) {\n\t\t\treturn await bar();\n\t\t}\n\t}\n}\n",
+        "\nasync () => {\nif (a) {\n\t\tif (b) {\n\t\t\treturn await bar();\n\t\t}\n\t}\n}\n",
+        "\nasync function foo() { try {}\n\tfinally {\n\t\treturn await bar();\n\t}\n}\n",
+        "\nasync function foo() {\n\ttry {}\n\tcatch (e) {\n\t\treturn await bar();\n\t}\n}\n",
+        "\ntry {\n\tasync function foo() {\n\t\treturn await bar();\n\t}\n} catch (e) {}\n",
+        "\ntry {\n\tasync () => await bar();\n} catch (e) {}\n",
+        "\nasync function foo() {\n\ttry {}\n\tcatch (e) {\n\t\ttry {}\n\t\tcatch (e) {\n\t\t\treturn await bar();\n\t\t}\n\t}\n}\n",
+        "\nasync function foo() {\n\treturn await new Promise(resolve => {\n\t\tresolve(5);\n\t});\n}\n",
+        "\nasync () => {\n\treturn await (\n\t\tfoo()\n\t)\n};\n",
+        "\nasync function foo() {\n\treturn await // Test\n\t\t5;\n}\n",
+    ];
+
+    let fix = vec![
+        ("async function foo() { return await bar(); }", "async function foo() { return bar(); }"),
+        (
+            "async function foo() { return await(bar()); }",
+            "async function foo() { return (bar()); }",
+        ),
+        ("async () => (a, await bar())", "async () => (a, bar())"),
+        ("async () => await bar()", "async () => bar()"),
+        (
+            "async function foo() { return baz() ? await bar() : b; }",
+            "async function foo() { return baz() ? bar() : b; }",
+        ),
+    ];
+
+    Tester::new(NoReturnAwait::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// [bar, baz] = [bar, qiz];
     /// ```
     NoSelfAssign,
-    correctness
+    correctness,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for NoSelfAssign {
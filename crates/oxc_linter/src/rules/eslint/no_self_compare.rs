@@ -35,8 +35,9 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoSelfCompare,
-    pedantic // The code is not wrong if it is intended to check for NaNs, which is the majority of
-             // the case.
+    pedantic, // The code is not wrong if it is intended to check for NaNs, which is the majority of
+              // the case.
+    node_types = [BinaryExpression]
 );
 
 impl Rule for NoSelfCompare {
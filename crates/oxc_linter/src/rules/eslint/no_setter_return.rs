@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoSetterReturn,
-    correctness
+    correctness,
+    node_types = [ReturnStatement]
 );
 
 impl Rule for NoSetterReturn {
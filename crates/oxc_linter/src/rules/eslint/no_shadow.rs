@@ -0,0 +1,286 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::symbol::{SymbolFlags, SymbolId};
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_shadow_diagnostic(name: &str, span: Span, shadowed: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already declared in the upper scope."))
+        .with_help(format!("Rename the inner '{name}'."))
+        .with_labels([
+            span.primary_label(format!("'{name}' shadows a variable of an outer scope")),
+            shadowed.label(format!("'{name}' is declared here")),
+        ])
+}
+
+fn no_shadow_builtin_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already a global variable."))
+        .with_help(format!("Rename '{name}'."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadowConfig {
+    /// Also report shadowing of built-in and configured global variables.
+    builtin_globals: bool,
+    hoist: Hoist,
+    /// Names that are allowed to shadow.
+    allow: Vec<CompactStr>,
+}
+
+/// Whether to report shadowing before the outer variable is declared.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum Hoist {
+    /// Report shadowing of function declarations only.
+    #[default]
+    Functions,
+    /// Report shadowing of all declarations.
+    All,
+    /// Never report it.
+    Never,
+}
+
+impl std::ops::Deref for NoShadow {
+    type Target = NoShadowConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable declarations from shadowing variables declared in an outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shadowing makes the outer variable inaccessible in the inner scope, and a reader can
+    /// easily mistake one variable for the other.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    ///
+    /// if (true) {
+    ///     let a = 5;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var a = 3;
+    /// function b() {
+    ///     var c = 10;
+    /// }
+    ///
+    /// // The name of a function or class expression may match the variable it is assigned to.
+    /// var d = function d() {};
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "no-shadow": ["error", { "builtinGlobals": false, "hoist": "functions", "allow": [] }] }
+    /// ```
+    ///
+    /// - `builtinGlobals`: also report shadowing of global variables, such as `Object`.
+    /// - `hoist`: whether to report shadowing of variables that are declared after the
+    ///   shadowing one. `"functions"` (default) only reports function declarations, `"all"`
+    ///   reports all variables, and `"never"` reports none of them.
+    /// - `allow`: names that may be shadowed.
+    NoShadow,
+    suspicious
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoShadowConfig {
+            builtin_globals: config
+                .and_then(|config| config.get("builtinGlobals"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+            hoist: match config
+                .and_then(|config| config.get("hoist"))
+                .and_then(serde_json::Value::as_str)
+            {
+                Some("all") => Hoist::All,
+                Some("never") => Hoist::Never,
+                _ => Hoist::Functions,
+            },
+            allow: config
+                .and_then(|config| config.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter().filter_map(serde_json::Value::as_str).map(CompactStr::from).collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let scopes = ctx.scopes();
+        let name = symbols.get_name(symbol_id);
+        if !symbols.get_flags(symbol_id).intersects(SymbolFlags::Value)
+            || self.allow.iter().any(|allowed| allowed == name)
+        {
+            return;
+        }
+        let span = symbols.get_span(symbol_id);
+
+        let scope_id = symbols.get_scope_id(symbol_id);
+        let Some(parent_scope_id) = scopes.get_parent_id(scope_id) else {
+            // Top-level declarations of scripts are the global variables themselves.
+            if self.builtin_globals && ctx.source_type().is_module() && is_global(name, ctx) {
+                ctx.diagnostic(no_shadow_builtin_diagnostic(name, span));
+            }
+            return;
+        };
+
+        let Some(shadowed_id) = scopes.find_binding(parent_scope_id, name) else {
+            if self.builtin_globals && is_global(name, ctx) {
+                ctx.diagnostic(no_shadow_builtin_diagnostic(name, span));
+            }
+            return;
+        };
+        if !symbols.get_flags(shadowed_id).intersects(SymbolFlags::Value | SymbolFlags::Import)
+            || is_on_initializer(symbol_id, shadowed_id, ctx)
+        {
+            return;
+        }
+
+        let shadowed_span = symbols.get_span(shadowed_id);
+        if span.end < shadowed_span.start
+            && match self.hoist {
+                Hoist::All => false,
+                Hoist::Functions => !matches!(
+                    ctx.symbol_declaration(shadowed_id).kind(),
+                    AstKind::Function(func) if func.is_declaration()
+                ),
+                Hoist::Never => true,
+            }
+        {
+            return;
+        }
+
+        ctx.diagnostic(no_shadow_diagnostic(name, span, shadowed_span));
+    }
+}
+
+fn is_global(name: &str, ctx: &LintContext<'_>) -> bool {
+    ctx.env_contains_var(name) || ctx.globals().is_enabled(name)
+}
+
+/// Whether the symbol is the name of a function or class expression that initializes the
+/// shadowed variable, e.g. `var a = function a() {}`.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let symbols = ctx.symbols();
+    let is_expression_name = match ctx.symbol_declaration(symbol_id).kind() {
+        AstKind::Function(func) => func.is_expression(),
+        AstKind::Class(class) => class.is_expression(),
+        _ => false,
+    };
+    if !is_expression_name
+        || ctx.scopes().get_parent_id(symbols.get_scope_id(symbol_id))
+            != Some(symbols.get_scope_id(shadowed_id))
+    {
+        return false;
+    }
+
+    let declarator = ctx.symbol_declaration(shadowed_id);
+    if !matches!(declarator.kind(), AstKind::VariableDeclarator(_)) {
+        return false;
+    }
+    ctx.nodes().parent_node(declarator.id()).is_some_and(|declaration| {
+        declaration.kind().span().contains_inclusive(symbols.get_span(symbol_id))
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("{ const a = 0; } const a = 1;", None),
+        ("{ const a = 0; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { var a; } let a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { var a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", None),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } function a() {}", None),
+        ("function foo() { var a; } function a() {}", None),
+        ("var a = function() { function a() {} };", None),
+        ("var a = function() { class a{} };", None),
+        ("var a = class { constructor() { var a; } };", None),
+        ("class A { constructor() { var A; } }", None),
+        ("try {} catch (e) { function foo() { let e; } }", None),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("function foo() { var foo = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).change_rule_path_extension("cjs").test_and_snapshot();
+
+    let pass = vec![
+        ("var top = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        // Types don't shadow values.
+        ("type T = string; function foo<T>() {}", None),
+        ("interface Foo {} function bar(Foo: number) {}", None),
+        (r#"import type { Foo } from "foo"; function bar(Foo: number) {}"#, None),
+    ];
+    let fail = vec![
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        (r#"import { y } from "y"; function m(y) {}"#, None),
+        (r#"import z from "z"; { let z; }"#, None),
+    ];
+    Tester::new(NoShadow::NAME, pass, fail).test();
+}
@@ -22,6 +22,10 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// These names are either read-only properties of the global object or have a special
+    /// meaning in the language. Shadowing them is allowed, but code that relies on their usual
+    /// meaning behaves unexpectedly in the shadowing scope. Use `no-shadow` to report
+    /// shadowing of other variables.
     ///
     /// ### Example
     /// ```javascript
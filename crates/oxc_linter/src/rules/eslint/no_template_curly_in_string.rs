@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```
     NoTemplateCurlyInString,
     style,
-    pending // TODO: conditional_fix
+    pending, // TODO: conditional_fix
+    node_types = [StringLiteral]
 );
 
 impl Rule for NoTemplateCurlyInString {
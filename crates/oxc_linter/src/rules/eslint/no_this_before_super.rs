@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoThisBeforeSuper,
-    correctness
+    correctness,
+    node_types = []
 );
 
 #[derive(Default, Copy, Clone, Debug)]
@@ -67,6 +67,7 @@ declare_oxc_lint!(
     NoThrowLiteral,
     pedantic,
     conditional_suggestion,
+    node_types = [ThrowStatement]
 );
 
 const SPECIAL_IDENTIFIERS: [&str; 3] = ["undefined", "Infinity", "NaN"];
@@ -154,7 +155,7 @@ impl NoThrowLiteral {
                 let Some(symbol_id) = reference.symbol_id() else {
                     return true;
                 };
-                let decl = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+                let decl = ctx.symbol_declaration(symbol_id);
                 match decl.kind() {
                     AstKind::VariableDeclarator(decl) => {
                         if let Some(init) = &decl.init {
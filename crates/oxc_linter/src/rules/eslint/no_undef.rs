@@ -30,8 +30,13 @@ declare_oxc_lint!(
     /// var foo = someFunction();
     /// var bar = a + 1;
     /// ```
+    ///
+    /// Besides the `env` and `globals` of the configuration, globals can be declared in the
+    /// file with `/* global foo, bar:writable */` and `/* eslint-env browser */` (or
+    /// `/* oxlint-env browser */`) comments.
     NoUndef,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for NoUndef {
@@ -45,28 +50,20 @@ impl Rule for NoUndef {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let symbol_table = ctx.symbols();
-
-        for reference_id_list in ctx.scopes().root_unresolved_references_ids() {
-            for reference_id in reference_id_list {
-                let reference = symbol_table.get_reference(reference_id);
-                let name = ctx.semantic().reference_name(reference);
-
-                if ctx.env_contains_var(name) {
-                    continue;
-                }
-
-                if ctx.globals().is_enabled(name) {
-                    continue;
-                }
+        for (name, _, node) in ctx.semantic().unresolved_references() {
+            if ctx.env_contains_var(name) {
+                continue;
+            }
 
-                let node = ctx.nodes().get_node(reference.node_id());
-                if !self.type_of && has_typeof_operator(node, ctx) {
-                    continue;
-                }
+            if ctx.globals().is_enabled(name) {
+                continue;
+            }
 
-                ctx.diagnostic(no_undef_diagnostic(name, node.kind().span()));
+            if !self.type_of && has_typeof_operator(node, ctx) {
+                continue;
             }
+
+            ctx.diagnostic(no_undef_diagnostic(name, node.kind().span()));
         }
     }
 }
@@ -85,16 +82,18 @@ fn test() {
 
     let pass = vec![
         "var a = 1, b = 2; a;",
-        // "/*global b*/ function f() { b; }",
+        "/*global b*/ function f() { b; }",
         // { code: "function f() { b; }", globals: { b: false } },
-        // "/*global b a:false*/  a;  function f() { b; a; }",
+        "/*global b a:false*/  a;  function f() { b; a; }",
         "function a(){}  a();",
         "function f(b) { b; }",
         "var a; a = 1; a++;",
         "var a; function f() { a = 1; }",
-        // "/*global b:true*/ b++;",
-        // "/*eslint-env browser*/ window;",
-        // "/*eslint-env node*/ require(\"a\");",
+        "/*global b:true*/ b++;",
+        "/*eslint-env browser*/ window;",
+        "/*eslint-env node*/ require(\"a\");",
+        "/*oxlint-env jest*/ describe(\"a\", () => {});",
+        "/* globals a, b */ a; b;",
         "Object; isNaN();",
         "function evilEval(stuffToEval) { var ultimateAnswer; ultimateAnswer = 42; eval(stuffToEval); }",
         "typeof a",
@@ -122,11 +121,11 @@ fn test() {
         // "customElements;",
         // "PromiseRejectionEvent;",
         "(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }",
-        // "/*global b:false*/ function f() { b = 1; }",
+        "/*global b:false*/ function f() { b = 1; }",
         // { code: "function f() { b = 1; }", globals: { b: false } },
-        // "/*global b:false*/ function f() { b++; }",
-        // "/*global b*/ b = 1;",
-        // "/*global b:false*/ var b = 1;",
+        "/*global b:false*/ function f() { b++; }",
+        "/*global b*/ b = 1;",
+        "/*global b:false*/ var b = 1;",
         "Array = 1;",
         "class A { constructor() { new.target; } }",
         // {
@@ -184,6 +183,10 @@ fn test() {
         "class C { static { var a; } } a;",
         "toString()",
         "hasOwnProperty()",
+        "/*global b:off*/ b;",
+        "/*globalb*/ b;",
+        "// global b\nb;",
+        "/*eslint-env browser*/ require(\"a\");",
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
@@ -196,7 +199,14 @@ fn test() {
 
     Tester::new(NoUndef::NAME, pass, fail).test();
 
-    let pass = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "readonly" } })))];
+    let pass = vec![
+        ("foo", None, Some(serde_json::json!({ "globals": { "foo": "readonly" } }))),
+        (
+            "window; describe();",
+            None,
+            Some(serde_json::json!({ "env": { "browser": true, "jest": true } })),
+        ),
+    ];
     let fail = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "off" } })))];
 
     Tester::new(NoUndef::NAME, pass, fail).test();
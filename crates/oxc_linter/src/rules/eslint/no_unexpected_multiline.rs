@@ -218,12 +218,18 @@ impl Rule for NoUnexpectedMultiline {
                     let slash_span =
                         Span::sized(span.start + u32::try_from(first_slash).unwrap(), 1);
 
-                    ctx.diagnostic_with_dangerous_fix(
+                    let diagnostic =
                         no_unexpected_multiline_diagnostic(&DiagnosticKind::Division {
                             slash_span,
-                        }),
-                        |fixer| fixer.insert_text_before_range(slash_span, ";"),
-                    );
+                        });
+                    // a regex with repeated flags, e.g. `/bar/gig`, is a syntax error
+                    if has_repeated_chars(&ident_name) {
+                        ctx.diagnostic(diagnostic);
+                    } else {
+                        ctx.diagnostic_with_dangerous_fix(diagnostic, |fixer| {
+                            fixer.insert_text_before_range(slash_span, ";")
+                        });
+                    }
                 }
             }
             _ => {}
@@ -242,6 +248,10 @@ fn is_regex_flag(str: &str) -> bool {
     true
 }
 
+fn has_repeated_chars(str: &str) -> bool {
+    str.char_indices().any(|(i, c)| str[..i].contains(c))
+}
+
 /// Check if there is a newline proceeding a target character within a snippet of source text.
 /// Returns `None` if the character is not found at all or has no proceeding newline. Otherwise,
 /// returns the byte offset of the target character with respect to the start of the span.
@@ -388,7 +398,11 @@ fn test() {
     ];
 
     // TODO: add more fixer tests
-    let fix = vec![("var a = b\n(x || y).doSomething()", "var a = b\n;(x || y).doSomething()")];
+    let fix = vec![
+        ("var a = b\n(x || y).doSomething()", "var a = b\n;(x || y).doSomething()"),
+        ("let x = foo\n/bar/g.test(baz)", "let x = foo\n;/bar/g.test(baz)"),
+        ("let x = foo\n/bar/gig.test(baz)", "let x = foo\n/bar/gig.test(baz)"),
+    ];
 
     Tester::new(NoUnexpectedMultiline::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
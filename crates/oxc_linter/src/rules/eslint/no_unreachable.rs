@@ -26,7 +26,8 @@ declare_oxc_lint!(
     /// Disallow unreachable code after `return`, `throw`, `continue`, and `break` statements
     ///
     NoUnreachable,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for NoUnreachable {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// ```
     NoUnsafeNegation,
     correctness,
-    fix
+    fix,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for NoUnsafeNegation {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// ```
     NoUnusedLabels,
     correctness,
-    fix
+    fix,
+    node_types = []
 );
 
 impl Rule for NoUnusedLabels {
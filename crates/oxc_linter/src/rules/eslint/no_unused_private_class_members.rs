@@ -85,7 +85,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUnusedPrivateClassMembers,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for NoUnusedPrivateClassMembers {
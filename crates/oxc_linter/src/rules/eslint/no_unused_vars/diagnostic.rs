@@ -91,7 +91,7 @@ where
 
     OxcDiagnostic::warn(format!("{pronoun} '{name}' is assigned a value but never used.{suffix}"))
         .with_labels([
-            symbol.span().label(format!("'{name}' is declared here")),
+            symbol.span().primary_label(format!("'{name}' is declared here")),
             assign_span.label("it was last assigned here"),
         ])
         .with_help("Did you mean to use this variable?")
@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{Expression, VariableDeclarator},
     AstKind,
 };
-use oxc_semantic::{AstNode, NodeId};
+use oxc_semantic::NodeId;
 use oxc_span::CompactStr;
 
 use super::{count_whitespace_or_commas, BindingInfo, NoUnusedVars, Symbol};
@@ -21,6 +21,7 @@ impl NoUnusedVars {
     ///     (`const x = function () {}`)
     ///   * Variables initialized with an `await` expression, since these often
     ///     have side effects (`const unusedRes = await api.createUser(data)`)
+    ///   * Declarations in `for...in` and `for...of` loops (`for (const x of xs) {}`)
     ///
     /// Only a small set of `varsIgnorePattern` values are supported for
     /// renaming. Feel free to add support for more as needed.
@@ -36,13 +37,20 @@ impl NoUnusedVars {
             return fixer.noop();
         }
 
-        let Some(parent) = symbol.nodes().parent_node(decl_id).map(AstNode::kind) else {
+        let Some(parent) = symbol.nodes().parent_node(decl_id) else {
             #[cfg(debug_assertions)]
             panic!("VariableDeclarator nodes should always have a parent node");
             #[cfg(not(debug_assertions))]
             return fixer.noop();
         };
-        let (span, declarations) = match parent {
+        // `for (var x in obj)` and `for (var x of arr)` need their declaration
+        if matches!(
+            symbol.nodes().parent_kind(parent.id()),
+            Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+        ) {
+            return fixer.noop();
+        }
+        let (span, declarations) = match parent.kind() {
             AstKind::VariableDeclaration(decl) => (decl.span, &decl.declarations),
             _ => {
                 #[cfg(debug_assertions)]
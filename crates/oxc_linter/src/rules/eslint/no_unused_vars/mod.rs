@@ -187,7 +187,7 @@ declare_oxc_lint!(
     /// var global_var = 42;
     /// ```
     NoUnusedVars,
-    correctness,
+    nursery,
     dangerous_suggestion
 );
 
@@ -218,8 +218,7 @@ impl Rule for NoUnusedVars {
         // 1. declarations have side effects (they get merged together)
         // 2. vue scripts declare variables that get used in the template, which
         //    we can't detect
-        !ctx.source_type().is_typescript_definition()
-            && !ctx.file_path().extension().is_some_and(|ext| ext == "vue")
+        !ctx.is_declaration_file() && !ctx.file_path().extension().is_some_and(|ext| ext == "vue")
     }
 }
 
@@ -352,12 +351,19 @@ impl NoUnusedVars {
             return true;
         }
 
+        // JSX elements implicitly reference the JSX factory, e.g. `React` in
+        // `React.createElement` or `h` for `/** @jsx h */`.
+        if symbol.is_referenced_by_jsx() {
+            return true;
+        }
+
         // In some cases (e.g. "jsx": "react" in tsconfig.json), React imports
-        // get used in generated code. We don't have a way to detect
-        // "jsxPragmas" or whether TSX files are using "jsx": "react-jsx", so we
-        // just allow all cases.
+        // get used in generated code. We don't have a way to detect whether TSX
+        // files are using "jsx": "react-jsx" or a custom "jsxFactory", so we
+        // just allow all cases unless a `@jsxRuntime automatic` pragma says otherwise.
         if symbol.flags().contains(SymbolFlags::Import)
             && symbol.is_in_jsx()
+            && !symbol.semantic().jsx_pragma().is_automatic_runtime()
             && symbol.is_possibly_jsx_factory()
         {
             return true;
@@ -214,6 +214,17 @@ impl<'s, 'a> Symbol<'s, 'a> {
         false
     }
 
+    #[inline]
+    pub fn semantic(&self) -> &'s Semantic<'a> {
+        self.semantic
+    }
+
+    /// Is this symbol the JSX factory (or fragment) binding used by JSX in this file?
+    #[inline]
+    pub fn is_referenced_by_jsx(&self) -> bool {
+        self.semantic.is_referenced_by_jsx(self.id)
+    }
+
     #[inline]
     pub fn is_in_jsx(&self) -> bool {
         self.semantic.source_type().is_jsx()
@@ -3,7 +3,10 @@
 use serde_json::json;
 
 use super::NoUnusedVars;
-use crate::{tester::Tester, FixKind, RuleMeta as _};
+use crate::{
+    tester::{TestCase, Tester},
+    FixKind, RuleMeta as _,
+};
 
 // uncomment to only run a single test. useful for step-through debugging.
 #[test]
@@ -89,6 +92,9 @@ fn test_vars_simple() {
         ("let a = 1;", "", None, FixKind::DangerousSuggestion),
         // FIXME: b should be deleted as well.
         ("let a = 1, b = 2;", "let b = 2;", None, FixKind::DangerousSuggestion),
+        // loop variables are kept
+        ("for (var a in obj) {}", "for (var a in obj) {}", None, FixKind::DangerousSuggestion),
+        ("for (const a of arr) {}", "for (const a of arr) {}", None, FixKind::DangerousSuggestion),
         (
             "let a = 1; let b = 2; console.log(a);",
             "let a = 1;  console.log(a);",
@@ -157,6 +163,32 @@ fn test_vars_simple() {
         .test_and_snapshot();
 }
 
+#[test]
+fn test_ignore_pattern_diagnostics() {
+    let pass: Vec<TestCase> = vec![];
+    let fail = vec![
+        TestCase::from("let _a = 1; let b = 2;")
+            .with_rule_config(json!([{ "varsIgnorePattern": "^_" }]))
+            .expect_errors(vec![("Variable 'b' is declared but never used", 1, 17)]),
+        TestCase::from("let a = 1;\nlet b = 2;").expect_errors(vec![
+            ("Variable 'a' is declared but never used", 1, 5),
+            ("Variable 'b' is declared but never used", 2, 5),
+        ]),
+        TestCase::from("let _a = 1; console.log(_a);")
+            .with_rule_config(
+                json!([{ "varsIgnorePattern": "^_", "reportUsedIgnorePattern": true }]),
+            )
+            .expect_error_count(1)
+            .expect_errors(vec!["'_a' is marked as ignored but is used"]),
+        TestCase::from("function foo(a) {} foo();")
+            .with_path("foo.cjs")
+            .with_rule_config(json!([{ "args": "all" }]))
+            .expect_errors(vec![("Parameter 'a' is declared but never used", 1, 14)]),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).intentionally_allow_no_fix_tests().test();
+}
+
 #[test]
 fn test_vars_self_use() {
     let pass = vec![
@@ -898,6 +930,32 @@ fn test_react() {
             }
         }
         ",
+        // custom JSX pragmas
+        "
+        /** @jsx jsx */
+        import { jsx } from '@emotion/react';
+
+        export const Foo = () => <div />;
+        ",
+        "
+        /** @jsx Preact.h */
+        /** @jsxFrag Preact.Fragment */
+        import * as Preact from 'preact';
+
+        export const Foo = () => <></>;
+        ",
+        "
+        /** @jsx h */
+        const h = createElement;
+
+        export const Foo = () => <div />;
+        ",
+        "
+        /** @jsxRuntime classic */
+        const React = require('react');
+
+        export const Foo = () => <div />;
+        ",
     ];
 
     let fail = vec![
@@ -906,6 +964,18 @@ fn test_react() {
 
         export const Foo = () => <div />
         ",
+        // the automatic runtime does not use the factory
+        "
+        /** @jsxRuntime automatic */
+        import React from 'react';
+
+        export const Foo = () => <div />;
+        ",
+        // pragma binding is only used if the file contains JSX
+        "
+        /** @jsx jsx */
+        import { jsx } from '@emotion/react';
+        ",
     ];
 
     Tester::new(NoUnusedVars::NAME, pass, fail).intentionally_allow_no_fix_tests().test();
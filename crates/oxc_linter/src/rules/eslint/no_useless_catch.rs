@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUselessCatch,
-    correctness
+    correctness,
+    node_types = [TryStatement]
 );
 
 impl Rule for NoUselessCatch {
@@ -0,0 +1,260 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, AssignmentTargetProperty, PropertyKey},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_part;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_useless_computed_key_diagnostic(key: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessarily computed property [{key}] found."))
+        .with_help("Replace the computed property with its literal key")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUselessComputedKey(Box<NoUselessComputedKeyConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoUselessComputedKeyConfig {
+    enforce_for_class_members: bool,
+}
+
+impl std::ops::Deref for NoUselessComputedKey {
+    type Target = NoUselessComputedKeyConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoUselessComputedKey {
+    fn default() -> Self {
+        Self(Box::new(NoUselessComputedKeyConfig { enforce_for_class_members: true }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary computed property keys in objects and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It's unnecessary to use computed properties with literals such as:
+    /// ```javascript
+    /// const foo = { ["a"]: "b" };
+    /// ```
+    /// The code can be rewritten as:
+    /// ```javascript
+    /// const foo = { "a": "b" };
+    /// ```
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const a = { ['0']: 0 };
+    /// const b = { ['x']() {} };
+    /// const { ['y']: y } = obj;
+    /// class Foo { ['z'] = 1 }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const a = { '0': 0 };
+    /// const b = { x() {} };
+    /// const c = { [x]: 0 };
+    /// const d = { ['__proto__']: 1 };
+    /// class Foo { ['constructor']() {} }
+    /// ```
+    NoUselessComputedKey,
+    style,
+    fix
+);
+
+impl Rule for NoUselessComputedKey {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUselessComputedKeyConfig {
+            enforce_for_class_members: value
+                .get(0)
+                .and_then(|v| v.get("enforceForClassMembers"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectProperty(property) if property.computed => {
+                // `{ ['__proto__']: a }` defines an own property, `{ __proto__: a }` sets the prototype
+                check_key(ctx, property.span, &property.key, Some("__proto__"));
+            }
+            AstKind::ObjectPattern(pattern) => {
+                for property in &pattern.properties {
+                    if property.computed {
+                        check_key(ctx, property.span, &property.key, None);
+                    }
+                }
+            }
+            AstKind::AssignmentTarget(AssignmentTarget::ObjectAssignmentTarget(target)) => {
+                for property in &target.properties {
+                    let AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) =
+                        property
+                    else {
+                        continue;
+                    };
+                    if ctx.source_text().as_bytes()[property.span.start as usize] == b'[' {
+                        check_key(ctx, property.span, &property.name, None);
+                    }
+                }
+            }
+            AstKind::MethodDefinition(method)
+                if method.computed && self.enforce_for_class_members =>
+            {
+                let allowed = if method.r#static { "prototype" } else { "constructor" };
+                check_key(ctx, method.span, &method.key, Some(allowed));
+            }
+            AstKind::PropertyDefinition(property)
+                if property.computed && self.enforce_for_class_members =>
+            {
+                let allowed = if property.r#static { "prototype" } else { "constructor" };
+                check_key(ctx, property.span, &property.key, Some(allowed));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports the computed `key` of the member at `member_span` if it is a string or number
+/// literal other than `allowed`, whose meaning changes when it isn't computed.
+fn check_key(ctx: &LintContext, member_span: Span, key: &PropertyKey, allowed: Option<&str>) {
+    match key {
+        PropertyKey::StringLiteral(lit) if allowed != Some(lit.value.as_str()) => {}
+        PropertyKey::NumericLiteral(_) => {}
+        _ => return,
+    }
+
+    let key_span = key.span();
+    let source_text = ctx.source_text();
+    let before_key = Span::new(member_span.start, key_span.start).source_text(source_text);
+    let after_key = Span::new(key_span.end, member_span.end).source_text(source_text);
+    let (Some(left), Some(right)) = (before_key.rfind('['), after_key.find(']')) else {
+        return;
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let brackets = Span::new(member_span.start + left as u32, key_span.end + right as u32 + 1);
+    let raw = key_span.source_text(source_text);
+    let diagnostic = no_useless_computed_key_diagnostic(raw, brackets);
+
+    if ctx.semantic().has_comments_between(Span::new(member_span.start, brackets.end)) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        // `{ get[2]() {} }` must become `{ get 2() {} }`, not `{ get2() {} }`
+        let needs_space = source_text[..brackets.start as usize]
+            .chars()
+            .next_back()
+            .is_some_and(is_identifier_part)
+            && raw.chars().next().is_some_and(is_identifier_part);
+        fixer.replace(brackets, if needs_space { format!(" {raw}") } else { raw.to_string() })
+    });
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("({ 'a': 0, b(){} })", None),
+        ("({ [x]: 0 });", None),
+        ("({ a: 0, [b](){} })", None),
+        ("({ ['__proto__']: [] })", None),
+        ("var { 'a': foo, a: bar } = obj;", None),
+        ("var { [a]: b } = obj;", None),
+        ("({ [`a`]: 0 })", None),
+        ("({ [a + 'b']: 0 })", None),
+        ("class Foo { a() {} }", None),
+        ("class Foo { 'a'() {} }", None),
+        ("class Foo { [x]() {} }", None),
+        ("class Foo { ['constructor']() {} }", None),
+        ("class Foo { static ['prototype']() {} }", None),
+        ("(class { ['constructor']() {} })", None),
+        ("class Foo { 'x'() {} }", None),
+        (
+            "class Foo { ['x']() {} }",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+        ("class Foo { ['x'] = 1 }", Some(serde_json::json!([{ "enforceForClassMembers": false }]))),
+        ("class Foo { static ['prototype'] = 1 }", None),
+        ("class Foo { ['constructor'] = 1 }", None),
+        ("({ [a]: b } = obj)", None),
+    ];
+
+    let fail = vec![
+        ("({ ['0']: 0 })", None),
+        ("var { ['0']: a } = obj", None),
+        ("({ ['0+1,234']: 0 })", None),
+        ("({ [0]: 0 })", None),
+        ("var { [0]: a } = obj", None),
+        ("({ ['x']: 0 })", None),
+        ("var { ['x']: a } = obj", None),
+        ("var { ['__proto__']: a } = obj", None),
+        ("({ ['x']() {} })", None),
+        ("({ [/* this comment prevents a fix */ 'x']: 0 })", None),
+        ("({ ['x' /* this comment also prevents a fix */]: 0 })", None),
+        ("({ *['x']() {} })", None),
+        ("({ async ['x']() {} })", None),
+        ("({ get[.2]() {} })", None),
+        ("({ set[.2](value) {} })", None),
+        ("({ async[.2]() {} })", None),
+        ("({ [2]() {} })", None),
+        ("({ get [2]() {} })", None),
+        ("({ get[2]() {} })", None),
+        ("({ set[2](value) {} })", None),
+        ("({ async[2]() {} })", None),
+        ("({ get['foo']() {} })", None),
+        ("({ ['x']: y } = obj)", None),
+        ("class Foo { ['0']() {} }", None),
+        ("class Foo { ['0+1,234']() {} }", None),
+        ("class Foo { ['x']() {} }", None),
+        ("class Foo { ['constructor'] = 1 ; static ['constructor']() {} }", None),
+        ("class Foo { static ['constructor']() {} }", None),
+        ("class Foo { ['prototype']() {} }", None),
+        ("class Foo { ['x'] = 1 }", None),
+        ("class Foo { static ['x'] = 1 }", None),
+        ("(class { ['x']() {} })", None),
+        ("class Foo { get[.2]() {} }", None),
+        ("class Foo { static get[2]() {} }", None),
+    ];
+
+    let fix = vec![
+        ("({ ['0']: 0 })", "({ '0': 0 })"),
+        ("var { ['0']: a } = obj", "var { '0': a } = obj"),
+        ("({ [0]: 0 })", "({ 0: 0 })"),
+        ("({ ['x']: 0 })", "({ 'x': 0 })"),
+        ("({ ['x']() {} })", "({ 'x'() {} })"),
+        (
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+        ),
+        ("({ *['x']() {} })", "({ *'x'() {} })"),
+        ("({ async ['x']() {} })", "({ async 'x'() {} })"),
+        ("({ get[.2]() {} })", "({ get.2() {} })"),
+        ("({ get [2]() {} })", "({ get 2() {} })"),
+        ("({ get[2]() {} })", "({ get 2() {} })"),
+        ("({ async[2]() {} })", "({ async 2() {} })"),
+        ("({ get['foo']() {} })", "({ get'foo'() {} })"),
+        ("({ ['x']: y } = obj)", "({ 'x': y } = obj)"),
+        ("class Foo { ['x']() {} }", "class Foo { 'x'() {} }"),
+        ("class Foo { ['x'] = 1 }", "class Foo { 'x' = 1 }"),
+        ("class Foo { static get[2]() {} }", "class Foo { static get 2() {} }"),
+    ];
+
+    Tester::new(NoUselessComputedKey::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
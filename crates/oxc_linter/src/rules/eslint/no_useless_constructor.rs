@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{
-        Argument, BindingPattern, BindingPatternKind, BindingRestElement, CallExpression,
+        Argument, BindingPattern, BindingPatternKind, BindingRestElement, CallExpression, Class,
         Expression, FormalParameters, FunctionBody, MethodDefinition, Statement, TSAccessibility,
     },
     AstKind,
@@ -94,7 +94,8 @@ declare_oxc_lint!(
     ///```
     NoUselessConstructor,
     suspicious,
-    fix
+    fix,
+    node_types = [MethodDefinition]
 );
 
 impl Rule for NoUselessConstructor {
@@ -116,16 +117,11 @@ impl Rule for NoUselessConstructor {
             return;
         }
 
-        let class = ctx
-            .nodes()
-            .iter_parents(node.id())
-            .skip(1)
-            .find(|parent| matches!(parent.kind(), AstKind::Class(_)));
+        let class = ctx.nodes().ancestor_of_kind::<Class>(node.id());
         debug_assert!(class.is_some(), "Found a constructor outside of a class definition");
-        let Some(class_node) = class else {
+        let Some(class) = class else {
             return;
         };
-        let AstKind::Class(class) = class_node.kind() else { unreachable!() };
         if class.declare {
             return;
         }
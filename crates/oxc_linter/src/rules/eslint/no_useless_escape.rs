@@ -25,6 +25,10 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Escaping characters that don't need it in strings, template literals
+    /// and regular expressions has no effect, and makes the code harder to
+    /// read. It can also hide a mistake, such as `"\d"` written where a
+    /// regular expression string `"\\d"` was intended.
     ///
     /// ### Example
     ///
@@ -187,13 +191,14 @@ fn check_character(
         }
         if unicode_sets {
             if REGEX_CLASS_SET_RESERVED_DOUBLE_PUNCTUATOR.contains(escape_char) {
-                if let Some(prev_char) = source_text.chars().nth(span.end as usize) {
+                if let Some(prev_char) = source_text[span.end as usize..].chars().next() {
                     // Escaping is valid when it is a reserved double punctuator
                     if prev_char == escape_char {
                         return None;
                     }
                 }
-                if let Some(prev_prev_char) = source_text.chars().nth(span.start as usize - 1) {
+                if let Some(prev_prev_char) = source_text[..span.start as usize].chars().next_back()
+                {
                     if prev_prev_char == escape_char {
                         if escape_char != '^' {
                             return None;
@@ -505,6 +510,8 @@ fn test() {
         r"/[\s\-(]/",    // https://github.com/oxc-project/oxc/issues/5227
         r"/\c/",         // https://github.com/oxc-project/oxc/issues/6046
         r"/\\c/",        // https://github.com/oxc-project/oxc/issues/6046
+        r"'éééé'; /[\$$]/v",
+        r"'éééé'; /[&\&]/v",
     ];
 
     let fail = vec![
@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{AssignmentTarget, AssignmentTargetProperty, BindingPatternKind},
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty,
+        BindingPatternKind,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -60,7 +63,8 @@ declare_oxc_lint!(
     /// export { baz };
     /// ```
     NoUselessRename,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessRename {
@@ -114,7 +118,12 @@ impl Rule for NoUselessRename {
                     };
 
                     if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                        report_with_fix(
+                            ctx,
+                            no_useless_rename_diagnostic(property.span),
+                            property.span,
+                            property.value.span(),
+                        );
                     }
                 }
             }
@@ -138,9 +147,26 @@ impl Rule for NoUselessRename {
                         continue;
                     };
 
-                    if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                    if key != renamed_key {
+                        continue;
                     }
+
+                    // `({foo: (foo) = a} = obj)` can't be fixed, shorthand properties can't be parenthesized
+                    if let AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) =
+                        &property.binding
+                    {
+                        if target.span.start != target.binding.span().start {
+                            ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                            continue;
+                        }
+                    }
+
+                    report_with_fix(
+                        ctx,
+                        no_useless_rename_diagnostic(property.span),
+                        property.span,
+                        property.binding.span(),
+                    );
                 }
             }
             AstKind::ImportSpecifier(import_specifier) => {
@@ -148,7 +174,12 @@ impl Rule for NoUselessRename {
                     && import_specifier.imported.span() != import_specifier.local.span
                     && import_specifier.local.name == import_specifier.imported.name()
                 {
-                    ctx.diagnostic(no_useless_rename_diagnostic(import_specifier.local.span));
+                    report_with_fix(
+                        ctx,
+                        no_useless_rename_diagnostic(import_specifier.local.span),
+                        import_specifier.span,
+                        import_specifier.local.span,
+                    );
                 }
             }
             AstKind::ExportNamedDeclaration(export_named_decl) => {
@@ -159,7 +190,12 @@ impl Rule for NoUselessRename {
                     if specifier.local.span() != specifier.exported.span()
                         && specifier.local.name() == specifier.exported.name()
                     {
-                        ctx.diagnostic(no_useless_rename_diagnostic(specifier.local.span()));
+                        report_with_fix(
+                            ctx,
+                            no_useless_rename_diagnostic(specifier.local.span()),
+                            specifier.span,
+                            specifier.local.span(),
+                        );
                     }
                 }
             }
@@ -168,6 +204,17 @@ impl Rule for NoUselessRename {
     }
 }
 
+/// Reports the useless rename at `span`, replacing it with the `replacement` it renames to
+/// unless that would remove comments.
+fn report_with_fix(ctx: &LintContext, diagnostic: OxcDiagnostic, span: Span, replacement: Span) {
+    let comments = ctx.semantic().comments_range(span.start..span.end).count();
+    if comments > ctx.semantic().comments_range(replacement.start..replacement.end).count() {
+        ctx.diagnostic(diagnostic);
+    } else {
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace_with(&span, &replacement));
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -397,5 +444,33 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoUselessRename::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"let {foo: foo} = obj;", r"let {foo} = obj;"),
+        (r"({foo: (foo)} = obj);", r"({foo} = obj);"),
+        (r"let {\u0061: a} = obj;", r"let {a} = obj;"),
+        (r"let {foo: foo, bar: baz} = obj;", r"let {foo, bar: baz} = obj;"),
+        (r"let {foo: {bar: bar}} = obj;", r"let {foo: {bar}} = obj;"),
+        (r"let {'foo': foo} = obj;", r"let {foo} = obj;"),
+        (
+            r"let {foo: foo = 1, 'bar': bar = 1, baz: baz} = obj;",
+            r"let {foo = 1, bar = 1, baz} = obj;",
+        ),
+        (r"({foo: (foo) = a} = obj);", r"({foo: (foo) = a} = obj);"),
+        (r"function func({foo: foo}) {}", r"function func({foo}) {}"),
+        (r"({foo: foo}) => {}", r"({foo}) => {}"),
+        (r"const {foo: foo, ...stuff} = myObject;", r"const {foo, ...stuff} = myObject;"),
+        (r"import {foo as foo} from 'foo';", r"import {foo} from 'foo';"),
+        (r"import {'foo' as foo} from 'foo';", r"import {foo} from 'foo';"),
+        (r"import {a as \u0061} from 'foo';", r"import {\u0061} from 'foo';"),
+        (r"var foo = 0; export {foo as foo};", r"var foo = 0; export {foo};"),
+        (r"var foo = 0; export {foo as 'foo'};", r"var foo = 0; export {foo};"),
+        (r"export {'foo' as 'foo'} from 'bar';", r"export {'foo'} from 'bar';"),
+        (r"({/* comment */foo: foo} = {});", r"({/* comment */foo} = {});"),
+        (r"({foo: foo = /* comment */ 1} = {});", r"({foo = /* comment */ 1} = {});"),
+        (r"({foo: (foo/**/)} = {});", r"({foo: (foo/**/)} = {});"),
+        (r"import {foo as foo/* comment */} from 'foo';", r"import {foo/* comment */} from 'foo';"),
+        (r"let foo; export {foo/**/as foo};", r"let foo; export {foo/**/as foo};"),
+    ];
+
+    Tester::new(NoUselessRename::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,10 +1,13 @@
 use oxc_ast::{
-    ast::{BindingPattern, BindingPatternKind, VariableDeclarationKind},
+    ast::{
+        BindingIdentifier, BindingPattern, BindingPatternKind, VariableDeclaration,
+        VariableDeclarationKind,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -32,6 +35,10 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Using `var` in an es6 environment triggers this error
     ///
+    /// The fix replaces `var` with `const`, or with `let` if the variable is reassigned. It is
+    /// only offered when that doesn't change what the code does, e.g. not when the variable is
+    /// used outside of its block, before it is declared, or is declared more than once.
+    ///
     /// ### Example
     /// ```javascript
     /// // error
@@ -49,21 +56,163 @@ declare_oxc_lint!(
 
 impl Rule for NoVar {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        if let AstKind::VariableDeclaration(dec) = node.kind() {
-            if dec.kind == VariableDeclarationKind::Var {
-                let is_written_to = dec.declarations.iter().any(|v| is_written_to(&v.id, ctx));
-
-                ctx.diagnostic_with_fix(
-                    no_var_diagnostic(Span::new(dec.span.start, dec.span.start + 3)),
-                    |fixer| {
-                        fixer.replace(
-                            Span::new(dec.span.start, dec.span.start + 3),
-                            if is_written_to { "let" } else { "const" },
-                        )
-                    },
-                );
+        let AstKind::VariableDeclaration(dec) = node.kind() else {
+            return;
+        };
+        if dec.kind != VariableDeclarationKind::Var {
+            return;
+        }
+        // Skip `declare` in `declare var foo`.
+        #[allow(clippy::cast_possible_truncation)]
+        let start = dec.span.start + ctx.source_range(dec.span).find("var").unwrap_or(0) as u32;
+        let span = Span::new(start, start + 3);
+        let Some(is_loop_assignee) = can_fix(node, dec, ctx) else {
+            ctx.diagnostic(no_var_diagnostic(span));
+            return;
+        };
+
+        let is_written_to = dec.declarations.iter().any(|v| is_written_to(&v.id, ctx));
+        let is_initialized = dec.declarations.iter().all(|v| v.init.is_some());
+        let kind =
+            if !is_written_to && (is_initialized || is_loop_assignee) { "const" } else { "let" };
+        ctx.diagnostic_with_fix(no_var_diagnostic(span), |fixer| fixer.replace(span, kind));
+    }
+}
+
+/// Whether replacing `var` with `let` or `const` keeps the code working the same way, which
+/// needs every variable to be used only where and when a block scoped variable would exist.
+/// Returns whether the declaration is the left side of a `for-in` or `for-of` loop if it can.
+///
+/// Ported from ESLint's `canFix`.
+fn can_fix<'a>(
+    node: &AstNode<'a>,
+    dec: &VariableDeclaration<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<bool> {
+    let nodes = ctx.nodes();
+    let parent = nodes.parent_kind(node.id())?;
+    let is_loop_assignee =
+        matches!(parent, AstKind::ForInStatement(_) | AstKind::ForOfStatement(_));
+    // `if (foo) var bar = 1;` and `case 0: var bar = 1;` can't be block scoped.
+    if !is_loop_assignee
+        && !matches!(
+            parent,
+            AstKind::ForStatementInit(_)
+                | AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+        )
+    {
+        return None;
+    }
+
+    let scope_span = nodes
+        .iter_parents(node.id())
+        .find(|node| {
+            matches!(
+                node.kind(),
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::SwitchStatement(_)
+                    | AstKind::ForStatement(_)
+                    | AstKind::ForInStatement(_)
+                    | AstKind::ForOfStatement(_)
+            )
+        })?
+        .kind()
+        .span();
+    let enclosing_function = |node_id| {
+        nodes.iter_parents(node_id).find(|node| node.kind().is_function_like()).map(AstNode::id)
+    };
+    let in_loop = nodes
+        .iter_parents(node.id())
+        .take_while(|node| !node.kind().is_function_like())
+        .any(|node| {
+            matches!(
+                node.kind(),
+                AstKind::ForStatement(_)
+                    | AstKind::ForInStatement(_)
+                    | AstKind::ForOfStatement(_)
+                    | AstKind::WhileStatement(_)
+                    | AstKind::DoWhileStatement(_)
+            )
+        });
+    // A `var` keeps its value between iterations, a `let` starts out `undefined` in each.
+    if in_loop && !is_loop_assignee && dec.declarations.iter().any(|v| v.init.is_none()) {
+        return None;
+    }
+
+    let is_script = ctx.source_type().is_script();
+    let root_scope_id = ctx.scopes().root_scope_id();
+    for declarator in &dec.declarations {
+        let mut bindings = vec![];
+        collect_bindings(&declarator.id, None, &mut bindings);
+        // References in the initializer run before the variable would be initialized,
+        // unless they are in a function that is called later.
+        let init_span = declarator
+            .init
+            .as_ref()
+            .filter(|init| !init.without_parentheses().is_function())
+            .map(GetSpan::span);
+        for (ident, default_span) in bindings {
+            let symbol_id = ident.symbol_id.get()?;
+            if ident.name == "let"
+                || !ctx.symbols().get_redeclarations(symbol_id).is_empty()
+                // A global `var` is a property of the global object, a `let` isn't.
+                || (is_script && ctx.symbols().get_scope_id(symbol_id) == root_scope_id)
+            {
+                return None;
+            }
+            for reference in ctx.semantic().symbol_references(symbol_id) {
+                let span = nodes.kind(reference.node_id()).span();
+                let in_tdz = declarator.init.is_some()
+                    && (span.start < ident.span.start
+                        || default_span.is_some_and(|default| default.contains_inclusive(span))
+                        || init_span.is_some_and(|init| init.contains_inclusive(span)));
+                if !scope_span.contains_inclusive(span)
+                    || in_tdz
+                    // Each closure would capture the variable of its own iteration.
+                    || (in_loop
+                        && enclosing_function(reference.node_id()) != enclosing_function(node.id()))
+                {
+                    return None;
+                }
+            }
+        }
+    }
+    Some(is_loop_assignee)
+}
+
+/// Collect the identifiers bound by `pattern`, with the span of their default value, if any.
+fn collect_bindings<'a, 'b>(
+    pattern: &'b BindingPattern<'a>,
+    default_span: Option<Span>,
+    bindings: &mut Vec<(&'b BindingIdentifier<'a>, Option<Span>)>,
+) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => bindings.push((ident, default_span)),
+        BindingPatternKind::ObjectPattern(object_pat) => {
+            for prop in &object_pat.properties {
+                collect_bindings(&prop.value, None, bindings);
+            }
+            if let Some(rest) = &object_pat.rest {
+                collect_bindings(&rest.argument, None, bindings);
+            }
+        }
+        BindingPatternKind::ArrayPattern(array_pat) => {
+            for elem in array_pat.elements.iter().flatten() {
+                collect_bindings(elem, None, bindings);
+            }
+            if let Some(rest) = &array_pat.rest {
+                collect_bindings(&rest.argument, None, bindings);
             }
         }
+        BindingPatternKind::AssignmentPattern(assign_pat) => {
+            collect_bindings(&assign_pat.left, Some(assign_pat.right.span()), bindings);
+        }
     }
 }
 
@@ -151,12 +300,56 @@ fn test() {
     ];
 
     let fix = vec![
-        ("var foo", "const foo"),
+        ("var foo", "let foo"),
+        ("var foo = 1", "const foo = 1"),
         ("var foo; foo += 1", "let foo; foo += 1"),
         ("var foo,bar; bar = 'que'", "let foo,bar; bar = 'que'"),
         ("var { a } = {}; a = fn()", "let { a } = {}; a = fn()"),
         ("var { a } = {}; let b = a", "const { a } = {}; let b = a"),
+        ("{ var foo = 1 }", "{ const foo = 1 }"),
+        ("for (var a of b) { console.log(a); }", "for (const a of b) { console.log(a); }"),
+        (
+            "for (var i = 0; i < list.length; ++i) { foo(i) }",
+            "for (let i = 0; i < list.length; ++i) { foo(i) }",
+        ),
+        ("var foo = () => foo();", "const foo = () => foo();"),
+        ("declare var foo: string;", "declare let foo: string;"),
+        // redeclared
+        ("var a, b, c; var a;", "var a, b, c; var a;"),
+        (
+            "var i = 0; for (var i = 1; false;); console.log(i);",
+            "var i = 0; for (var i = 1; false;); console.log(i);",
+        ),
+        // used outside of the block
+        ("if (foo) { var a, b, c; } a;", "if (foo) { var a, b, c; } a;"),
+        ("for (var i = 0; i < 10; ++i) {} i;", "for (var i = 0; i < 10; ++i) {} i;"),
+        // not in a block
+        ("if (foo) var bar = 1;", "if (foo) var bar = 1;"),
+        ("switch (a) { case 0: var b = 1 }", "switch (a) { case 0: var b = 1 }"),
+        // used before it is initialized
+        ("var a = a", "var a = a"),
+        ("var {a = a} = {}", "var {a = a} = {}"),
+        ("var a = b, b = 1", "var a = b, b = 1"),
+        ("function foo() { a } var a = 1; foo()", "function foo() { a } var a = 1; foo()"),
+        ("var foo = (function () { foo(); })();", "var foo = (function () { foo(); })();"),
+        // captured or uninitialized in a loop
+        ("for (var a of b) { arr.push(() => a); }", "for (var a of b) { arr.push(() => a); }"),
+        (
+            "for (let a of b) { var c; console.log(c); c = 'hello'; }",
+            "for (let a of b) { var c; console.log(c); c = 'hello'; }",
+        ),
+        ("function foo() { var let; }", "function foo() { var let; }"),
     ];
 
     Tester::new(NoVar::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+
+    // A global `var` is a property of the global object.
+    let fix = vec![
+        ("var foo = 1", "var foo = 1"),
+        ("function foo() { var bar = 1 }", "function foo() { const bar = 1 }"),
+    ];
+    Tester::new(NoVar::NAME, vec![], vec!["var foo = 1"])
+        .change_rule_path_extension("cjs")
+        .expect_fix(fix)
+        .test();
 }
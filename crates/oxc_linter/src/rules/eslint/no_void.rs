@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     NoVoid,
     restriction,
-    pending // TODO: suggestion
+    pending, // TODO: suggestion
+    node_types = [UnaryExpression]
 );
 
 impl Rule for NoVoid {
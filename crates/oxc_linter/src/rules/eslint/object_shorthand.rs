@@ -0,0 +1,588 @@
+use oxc_ast::{
+    ast::{Expression, Function, ObjectProperty, ObjectPropertyKind, PropertyKey, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn expected_property_shorthand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected property shorthand.").with_label(span)
+}
+
+fn expected_method_shorthand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected method shorthand.").with_label(span)
+}
+
+fn expected_property_longhand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform property syntax.").with_label(span)
+}
+
+fn expected_method_longhand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform method syntax.").with_label(span)
+}
+
+fn expected_literal_method_longhand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform method syntax for string literal keys.").with_label(span)
+}
+
+fn unexpected_mix_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected mix of shorthand and non-shorthand properties.")
+        .with_label(span)
+}
+
+fn expected_all_properties_shorthanded_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected shorthand for all properties.").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthand(Box<ObjectShorthandConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthandConfig {
+    mode: Mode,
+    avoid_quotes: bool,
+    ignore_constructors: bool,
+    methods_ignore_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Always,
+    Methods,
+    Properties,
+    Never,
+    Consistent,
+    ConsistentAsNeeded,
+}
+
+impl Mode {
+    fn from(raw: &str) -> Option<Self> {
+        match raw {
+            "always" => Some(Self::Always),
+            "methods" => Some(Self::Methods),
+            "properties" => Some(Self::Properties),
+            "never" => Some(Self::Never),
+            "consistent" => Some(Self::Consistent),
+            "consistent-as-needed" => Some(Self::ConsistentAsNeeded),
+            _ => None,
+        }
+    }
+
+    fn applies_to_methods(self) -> bool {
+        matches!(self, Self::Always | Self::Methods)
+    }
+
+    fn applies_to_properties(self) -> bool {
+        matches!(self, Self::Always | Self::Properties)
+    }
+}
+
+impl std::ops::Deref for ObjectShorthand {
+    type Target = ObjectShorthandConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow method and property shorthand syntax for object literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// ES2015 added shorthand syntax for methods and properties that have the
+    /// same name as the variable they are initialized with. Mixing both styles
+    /// makes object literals harder to scan.
+    ///
+    /// ### Options
+    ///
+    /// The first option is one of `"always"` (default), `"methods"`,
+    /// `"properties"`, `"never"`, `"consistent"` or `"consistent-as-needed"`.
+    /// The second one is an object with `avoidQuotes`, `ignoreConstructors` and
+    /// `methodsIgnorePattern`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const foo = {
+    ///     w: function() {},
+    ///     x: function* () {},
+    ///     y: y,
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const foo = {
+    ///     w() {},
+    ///     *x() {},
+    ///     y,
+    /// };
+    /// ```
+    ObjectShorthand,
+    style,
+    fix
+);
+
+impl Rule for ObjectShorthand {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = value.get(0).and_then(serde_json::Value::as_str).and_then(Mode::from);
+        let options = value.get(1).or_else(|| value.get(0).filter(|v| v.is_object()));
+        let get_bool = |name: &str| {
+            options.and_then(|v| v.get(name)).and_then(serde_json::Value::as_bool).unwrap_or(false)
+        };
+
+        Self(Box::new(ObjectShorthandConfig {
+            mode: mode.unwrap_or_default(),
+            avoid_quotes: get_bool("avoidQuotes"),
+            ignore_constructors: get_bool("ignoreConstructors"),
+            methods_ignore_pattern: options
+                .and_then(|v| v.get("methodsIgnorePattern"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok()),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectExpression(object) => {
+                if matches!(self.mode, Mode::Consistent | Mode::ConsistentAsNeeded) {
+                    self.check_consistency(
+                        object.span,
+                        object.properties.iter().filter_map(|property| match property {
+                            ObjectPropertyKind::ObjectProperty(property)
+                                if property.kind == PropertyKind::Init =>
+                            {
+                                Some(&**property)
+                            }
+                            _ => None,
+                        }),
+                        ctx,
+                    );
+                }
+            }
+            AstKind::ObjectProperty(property) => self.check_property(property, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl ObjectShorthand {
+    fn check_consistency<'a, 'b>(
+        &self,
+        span: Span,
+        properties: impl Iterator<Item = &'b ObjectProperty<'a>>,
+        ctx: &LintContext<'a>,
+    ) where
+        'a: 'b,
+    {
+        let properties = properties.collect::<Vec<_>>();
+        let shorthands = properties.iter().filter(|p| p.shorthand || p.method).count();
+        if shorthands == properties.len() {
+            return;
+        }
+        if shorthands > 0 {
+            ctx.diagnostic(unexpected_mix_diagnostic(span));
+        } else if self.mode == Mode::ConsistentAsNeeded
+            && properties.iter().all(|property| is_redundant(property))
+        {
+            ctx.diagnostic(expected_all_properties_shorthanded_diagnostic(span));
+        }
+    }
+
+    fn check_property<'a>(&self, property: &ObjectProperty<'a>, ctx: &LintContext<'a>) {
+        if property.kind != PropertyKind::Init {
+            return;
+        }
+        let function = match &property.value {
+            Expression::FunctionExpression(function) => Some(&**function),
+            _ => None,
+        };
+        if property.computed && function.is_none() {
+            return;
+        }
+
+        if property.method || property.shorthand {
+            let is_string_key = matches!(property.key, PropertyKey::StringLiteral(_));
+            if property.method && (self.mode == Mode::Never || self.avoid_quotes && is_string_key) {
+                let diagnostic = if self.mode == Mode::Never {
+                    expected_method_longhand_diagnostic(property.span)
+                } else {
+                    expected_literal_method_longhand_diagnostic(property.span)
+                };
+                let Some(function) = function else { return };
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    let key_span = key_span(property, ctx);
+                    let mut header = String::from("function");
+                    if function.r#async {
+                        header.insert_str(0, "async ");
+                    }
+                    if function.generator {
+                        header.push('*');
+                    }
+                    let key = fixer.source_range(key_span);
+                    fixer.replace(
+                        Span::new(property.span.start, key_span.end),
+                        format!("{key}: {header}"),
+                    )
+                });
+            } else if self.mode == Mode::Never {
+                ctx.diagnostic_with_fix(
+                    expected_property_longhand_diagnostic(property.span),
+                    |fixer| {
+                        let key = fixer.source_range(property.key.span());
+                        fixer.replace(property.span, format!("{key}: {key}"))
+                    },
+                );
+            }
+            return;
+        }
+
+        if let Some(function) = function.filter(|function| function.id.is_none()) {
+            if !self.mode.applies_to_methods() {
+                return;
+            }
+            if self.ignore_constructors
+                && property.key.as_expression().is_none()
+                && property.key.static_name().is_some_and(|name| is_constructor(&name))
+            {
+                return;
+            }
+            if let Some(pattern) = &self.methods_ignore_pattern {
+                if property.key.static_name().is_some_and(|name| pattern.is_match(&name)) {
+                    return;
+                }
+            }
+            if self.avoid_quotes && matches!(property.key, PropertyKey::StringLiteral(_)) {
+                return;
+            }
+            Self::report_method_shorthand(property, function, ctx);
+            return;
+        }
+
+        let Expression::Identifier(value) = &property.value else {
+            return;
+        };
+        if !self.mode.applies_to_properties()
+            || property.key.static_name() != Some(value.name.as_str().into())
+        {
+            return;
+        }
+        match &property.key {
+            PropertyKey::StaticIdentifier(_) => {}
+            PropertyKey::StringLiteral(_) if !self.avoid_quotes => {}
+            _ => return,
+        }
+        let diagnostic = expected_property_shorthand_diagnostic(property.span);
+        if ctx.semantic().has_comments_between(property.span) {
+            ctx.diagnostic(diagnostic);
+        } else {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                fixer.replace_with(&property.span, &**value)
+            });
+        }
+    }
+
+    /// Reports `{ x: function() {} }`, fixing it to `{ x() {} }`.
+    fn report_method_shorthand<'a>(
+        property: &ObjectProperty<'a>,
+        function: &Function<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let diagnostic = expected_method_shorthand_diagnostic(property.span);
+        let key_span = key_span(property, ctx);
+        let params_start =
+            function.type_parameters.as_ref().map_or(function.params.span.start, |t| t.span.start);
+        if ctx.semantic().has_comments_between(Span::new(key_span.end, params_start)) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        let source_text = ctx.source_text();
+        let header = Span::new(function.span.start, params_start).source_text(source_text);
+        let Some(mut tail_start) = header.find("function").map(|i| i + "function".len()) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        if function.generator {
+            let Some(star) = header[tail_start..].find('*') else {
+                ctx.diagnostic(diagnostic);
+                return;
+            };
+            tail_start += star + 1;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let mut prefix = String::new();
+            if function.r#async {
+                prefix.push_str("async ");
+            }
+            if function.generator {
+                prefix.push('*');
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let tail = Span::new(function.span.start + tail_start as u32, function.span.end);
+            fixer.replace(
+                Span::new(key_span.start, property.span.end),
+                format!("{prefix}{}{}", fixer.source_range(key_span), fixer.source_range(tail)),
+            )
+        });
+    }
+}
+
+/// The span of the key of `property`, including the brackets of a computed key.
+fn key_span(property: &ObjectProperty, ctx: &LintContext) -> Span {
+    let key = property.key.span();
+    if !property.computed {
+        return key;
+    }
+    let start = ctx.find_token_before(key).map_or(key.start, |bracket| bracket.start);
+    let end = ctx.find_token_after(key).map_or(key.end, |bracket| bracket.end);
+    Span::new(start, end)
+}
+
+/// Whether `property` could be written with the shorthand syntax.
+fn is_redundant(property: &ObjectProperty) -> bool {
+    match &property.value {
+        Expression::FunctionExpression(function) => function.id.is_none(),
+        Expression::Identifier(value) => {
+            property.key.static_name().is_some_and(|name| name == value.name.as_str())
+        }
+        _ => false,
+    }
+}
+
+/// Whether `name` looks like a constructor, i.e. its first letter is uppercase.
+fn is_constructor(name: &str) -> bool {
+    name.chars()
+        .find(|c| !matches!(c, '_' | '$' | '0'..='9'))
+        .is_some_and(|c| c.to_uppercase().eq(std::iter::once(c)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = {y() {}}", None),
+        ("var x = {y}", None),
+        ("var x = {a: b}", None),
+        ("var x = {a: 'a'}", None),
+        ("var x = {'a': 'a'}", None),
+        ("var x = {'a': b}", None),
+        ("var x = {y(x) {}}", None),
+        ("var {x,y,z} = x", None),
+        ("var {x: {y}} = z", None),
+        ("var x = {*x() {}}", None),
+        ("var x = {x: y}", None),
+        ("var x = {x: y, y: z}", None),
+        ("var x = {x: y, y: z, z: 'z'}", None),
+        ("var x = {x() {}, y: z, l(){}}", None),
+        ("var x = {x: y, y: z, a: b}", None),
+        ("var x = {x: y, y: z, 'a': b}", None),
+        ("var x = {x: y, y() {}, z: a}", None),
+        ("var x = {[y]: y}", None),
+        ("doSomething({x: y})", None),
+        ("doSomething({'x': y})", None),
+        ("doSomething({x: 'x'})", None),
+        ("doSomething({'x': 'x'})", None),
+        ("doSomething({y() {}})", None),
+        ("doSomething({x: y, y() {}})", None),
+        ("doSomething({y() {}, z: a})", None),
+        ("!{ a: function a(){} };", None),
+        ("var x = {y: (x)=>x}", None),
+        ("doSomething({y: (x)=>x})", None),
+        ("var x = {y: (x)=>x, y: a}", None),
+        ("doSomething({x, y: (x)=>x})", None),
+        ("({ foo: x => { return; }})", None),
+        ("({ foo: (x) => { return; }})", None),
+        ("({ foo: () => { return; }})", None),
+        ("var x = {get y() {}}", None),
+        ("var x = {set y(z) {}}", None),
+        ("var x = {get y() {}, set y(z) {}}", None),
+        ("doSomething({get y() {}})", None),
+        ("doSomething({set y(z) {}})", None),
+        ("doSomething({get y() {}, set y(z) {}})", None),
+        ("var x = {[y]: y}", Some(serde_json::json!(["properties"]))),
+        (
+            "var x = {ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {_ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {$ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {__ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {_0ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {notConstructorFunction(){}, b: c}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {ConstructorFunction: function(){}, a: b}",
+            Some(serde_json::json!(["methods", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {foo: function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "^foo$" }])),
+        ),
+        (
+            "var x = {'foo': function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "^foo$" }])),
+        ),
+        (
+            "var x = {['foo']: function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "^foo$" }])),
+        ),
+        (
+            "var x = {123: function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "^123$" }])),
+        ),
+        (
+            "var x = {afoob: function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "foo" }])),
+        ),
+        (
+            "var x = {'a': function(){}}",
+            Some(serde_json::json!(["always", { "avoidQuotes": true }])),
+        ),
+        (
+            "var x = {['a']: function(){}}",
+            Some(serde_json::json!(["methods", { "avoidQuotes": true }])),
+        ),
+        ("var x = {'y': y}", Some(serde_json::json!(["properties", { "avoidQuotes": true }]))),
+        ("var x = {y: function() {}}", Some(serde_json::json!(["properties"]))),
+        ("var x = {y}", Some(serde_json::json!(["properties"]))),
+        ("var x = {y: {b}}", Some(serde_json::json!(["properties"]))),
+        ("var x = {a: n, c: d, f: g}", Some(serde_json::json!(["never"]))),
+        ("var x = {a: function(){}, b: {c: d}}", Some(serde_json::json!(["never"]))),
+        ("var x = {a: b}", Some(serde_json::json!(["methods"]))),
+        ("var x = {a() {}}", Some(serde_json::json!(["methods"]))),
+        ("var x = {a: n, c: d, f: g}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {a: function(){}, b: {c: d}}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {a, b}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {a, b, get test() { return 1; }}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {...bar}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {foo, bar, ...baz}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {a, b}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {0: 'foo'}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {'key': 'baz'}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {foo: 'foo'}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {[foo]: foo}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {foo: function foo() {}}", Some(serde_json::json!(["consistent-as-needed"]))),
+    ];
+
+    let fail = vec![
+        ("var x = {x: x}", None),
+        ("var x = {'x': x}", None),
+        ("var x = {y: y, x: x}", None),
+        ("var x = {y: z, x: x, a: b}", None),
+        ("var x = {y: z,\n x: x,\n a: b\n // comment \n}", None),
+        ("var x = {y: z,\n a: b,\n // comment \nf: function() {}}", None),
+        ("var x = {a: b,\n/* comment */\ny: y\n }", None),
+        ("var x = {\n  a: b,\n  /* comment */\n  y: y\n}", None),
+        ("var x = {y: function() {}}", None),
+        ("var x = {y: function*() {}}", None),
+        ("var x = {x: y, y: z, a: a}", None),
+        ("var x = {ConstructorFunction: function(){}, a: b}", None),
+        ("var x = {x: y, y: z, a: function(){}, b() {}}", None),
+        ("var x = {x: x, y: function() {}}", None),
+        ("doSomething({x: x})", None),
+        ("doSomething({'x': x})", None),
+        ("doSomething({a: 'a', 'x': x})", None),
+        ("doSomething({y: function() {}})", None),
+        ("doSomething({[y]: function() {}})", None),
+        ("doSomething({['y']: function() {}})", None),
+        ("({ foo: async function () {} })", None),
+        ("({ 'foo': async function() {} })", None),
+        ("({ [foo]: async function() {} })", None),
+        ("({ [foo.bar]: function*() {} })", None),
+        ("({ [foo   ]: function() {} })", None),
+        ("({ [ foo ]: async function() {} })", None),
+        ("({ foo: async function*() {} })", None),
+        ("({ foo: async function  *() {} })", None),
+        ("({ a: function<T>(x: T) { return x; } })", None),
+        ("({ a: function(this: Foo) {} })", None),
+        ("var x = {y: function() {}}", Some(serde_json::json!(["methods"]))),
+        ("var x = {x, y() {}, a: function() {}}", Some(serde_json::json!(["methods"]))),
+        ("var x = {x: x}", Some(serde_json::json!(["properties"]))),
+        ("var x = {a, b, c(){}, x: x}", Some(serde_json::json!(["properties"]))),
+        ("var x = {y() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {*y() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {y}", Some(serde_json::json!(["never"]))),
+        ("var x = {y, a: b, *x(){}}", Some(serde_json::json!(["never"]))),
+        ("var x = {y: {x}}", Some(serde_json::json!(["never"]))),
+        ("var x = {ConstructorFunction(){}, a: b}", Some(serde_json::json!(["never"]))),
+        ("var x = {notConstructorFunction(){}, b: c}", Some(serde_json::json!(["never"]))),
+        ("({ async foo() {} })", Some(serde_json::json!(["never"]))),
+        ("({ *foo() {} })", Some(serde_json::json!(["never"]))),
+        ("({ async *foo() {} })", Some(serde_json::json!(["never"]))),
+        ("({ [foo]() {} })", Some(serde_json::json!(["never"]))),
+        ("var x = {'a'(){}}", Some(serde_json::json!(["always", { "avoidQuotes": true }]))),
+        ("var x = {['a'](){}}", Some(serde_json::json!(["methods", { "avoidQuotes": true }]))),
+        (
+            "var x = {foo: function() {}}",
+            Some(serde_json::json!(["always", { "methodsIgnorePattern": "^bar$" }])),
+        ),
+        ("var x = {a: a, b}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {b, c: d, f: g}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {foo, bar: baz, ...qux}", Some(serde_json::json!(["consistent"]))),
+        ("var x = {a: a, b: b}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {a, z: function z(){}}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {foo: function() {}}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {a: a, b: b, ...baz}", Some(serde_json::json!(["consistent-as-needed"]))),
+        ("var x = {foo, bar: bar, ...qux}", Some(serde_json::json!(["consistent-as-needed"]))),
+    ];
+
+    let fix = vec![
+        ("var x = {x: x}", "var x = {x}", None),
+        ("var x = {'x': x}", "var x = {x}", None),
+        ("var x = {y: y, x: x}", "var x = {y, x}", None),
+        ("var x = {a: b,\n/* comment */\ny: y\n }", "var x = {a: b,\n/* comment */\ny\n }", None),
+        ("var x = {x: /* comment */ x}", "var x = {x: /* comment */ x}", None),
+        ("var x = {y: function() {}}", "var x = {y() {}}", None),
+        ("var x = {y: function*() {}}", "var x = {*y() {}}", None),
+        ("var x = {y: function (a, b) {}}", "var x = {y (a, b) {}}", None),
+        ("doSomething({[y]: function() {}})", "doSomething({[y]() {}})", None),
+        ("doSomething({['y']: function() {}})", "doSomething({['y']() {}})", None),
+        ("({ foo: async function () {} })", "({ async foo () {} })", None),
+        ("({ [ foo ]: async function() {} })", "({ async [ foo ]() {} })", None),
+        ("({ foo: async function  *() {} })", "({ async *foo() {} })", None),
+        ("({ a: function<T>(x: T) { return x; } })", "({ a<T>(x: T) { return x; } })", None),
+        ("({ a: /* comment */ function() {} })", "({ a: /* comment */ function() {} })", None),
+        ("var x = {y() {}}", "var x = {y: function() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {*y() {}}", "var x = {y: function*() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {y}", "var x = {y: y}", Some(serde_json::json!(["never"]))),
+        (
+            "({ async *foo() {} })",
+            "({ foo: async function*() {} })",
+            Some(serde_json::json!(["never"])),
+        ),
+        ("({ [foo]() {} })", "({ [foo]: function() {} })", Some(serde_json::json!(["never"]))),
+        (
+            "var x = {'a'(){}}",
+            "var x = {'a': function(){}}",
+            Some(serde_json::json!(["always", { "avoidQuotes": true }])),
+        ),
+    ];
+
+    Tester::new(ObjectShorthand::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -32,6 +32,7 @@ declare_oxc_lint!(
     /// ```
     PreferExponentiationOperator,
     style,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferExponentiationOperator {
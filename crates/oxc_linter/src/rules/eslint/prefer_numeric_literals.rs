@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// ```
     PreferNumericLiterals,
     style,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferNumericLiterals {
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// ```
     PreferObjectHasOwn,
     style,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferObjectHasOwn {
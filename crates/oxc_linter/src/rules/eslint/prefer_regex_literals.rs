@@ -0,0 +1,361 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, RegExpLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{LiteralParser, Options};
+use oxc_span::{Atom, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn unexpected_regexp_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use a regular expression literal instead of the `RegExp` constructor.")
+        .with_help("Regular expression literals are checked when the code is parsed.")
+        .with_label(span)
+}
+
+fn redundant_regexp_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Regular expression literal is unnecessarily wrapped within a `RegExp` constructor.",
+    )
+    .with_help("Use the regular expression literal directly.")
+    .with_label(span)
+}
+
+fn redundant_regexp_with_flags_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Use a regular expression literal with flags instead of the `RegExp` constructor.",
+    )
+    .with_help("Add the flags to the regular expression literal.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferRegexLiterals(Box<PreferRegexLiteralsConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferRegexLiteralsConfig {
+    /// Also report regular expression literals that are passed to the `RegExp`
+    /// constructor, e.g. `new RegExp(/abc/)` and `new RegExp(/abc/, "u")`.
+    disallow_redundant_wrapping: bool,
+}
+
+impl std::ops::Deref for PreferRegexLiterals {
+    type Target = PreferRegexLiteralsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the `RegExp` constructor when its pattern and flags are
+    /// static strings, which can be written as a regular expression literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Regular expression literals are shorter, don't need their backslashes
+    /// to be escaped twice, and are checked for syntax errors when the code is
+    /// parsed rather than when it runs.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// new RegExp("abc");
+    /// new RegExp("abc", "u");
+    /// RegExp("\\d+");
+    /// new RegExp(String.raw`^\d\.$`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /abc/;
+    /// /abc/u;
+    /// new RegExp(pattern);
+    /// new RegExp("abc", flags);
+    /// new RegExp(`^${prefix}`);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// With `disallowRedundantWrapping`, regular expression literals passed to
+    /// the `RegExp` constructor are reported too:
+    ///
+    /// ```json
+    /// { "prefer-regex-literals": ["error", { "disallowRedundantWrapping": true }] }
+    /// ```
+    PreferRegexLiterals,
+    style,
+    conditional_suggestion,
+    config = PreferRegexLiteralsConfig,
+    node_types = [NewExpression, CallExpression]
+);
+
+impl Rule for PreferRegexLiterals {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(PreferRegexLiteralsConfig {
+            disallow_redundant_wrapping: value
+                .get(0)
+                .and_then(|config| config.get("disallowRedundantWrapping"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, callee, arguments) = match node.kind() {
+            AstKind::NewExpression(expr) => (expr.span, &expr.callee, &expr.arguments),
+            AstKind::CallExpression(expr) => (expr.span, &expr.callee, &expr.arguments),
+            _ => return,
+        };
+        if !is_regexp_constructor(callee, ctx) {
+            return;
+        }
+        let (pattern, flags) = match arguments.as_slice() {
+            [pattern] => (pattern, None),
+            [pattern, flags] => (pattern, Some(flags)),
+            _ => return,
+        };
+        let Some(pattern) = pattern.as_expression() else {
+            return;
+        };
+        // Dynamic flags can't be written in a literal.
+        let flags = match flags {
+            Some(flags) => {
+                match flags.as_expression().and_then(|flags| static_string(flags, ctx)) {
+                    Some(flags) => Some(flags),
+                    None => return,
+                }
+            }
+            None => None,
+        };
+
+        if let Expression::RegExpLiteral(literal) = pattern.without_parentheses() {
+            if self.disallow_redundant_wrapping {
+                report_redundant_wrapping(literal, flags, span, ctx);
+            }
+            return;
+        }
+
+        let Some(pattern) = static_string(pattern, ctx) else {
+            return;
+        };
+        let pattern = to_literal_pattern(pattern);
+        let flags = flags.unwrap_or_default();
+        if is_valid_regex_literal(&pattern, flags) {
+            ctx.diagnostic_with_suggestion(unexpected_regexp_diagnostic(span), |fixer| {
+                fixer.replace(span, format!("/{pattern}/{flags}"))
+            });
+        } else {
+            ctx.diagnostic(unexpected_regexp_diagnostic(span));
+        }
+    }
+}
+
+fn report_redundant_wrapping(
+    literal: &RegExpLiteral,
+    flags: Option<&str>,
+    span: Span,
+    ctx: &LintContext,
+) {
+    let literal_text = ctx.source_range(literal.span);
+    let Some(flags) = flags else {
+        ctx.diagnostic_with_suggestion(redundant_regexp_diagnostic(span), |fixer| {
+            fixer.replace(span, literal_text.to_string())
+        });
+        return;
+    };
+    // The flags replace those of the literal, so only a literal without flags can be kept.
+    let pattern = literal.regex.pattern.source_text(ctx.source_text());
+    if literal.regex.flags.is_empty() && is_valid_regex_literal(&pattern, flags) {
+        ctx.diagnostic_with_suggestion(redundant_regexp_with_flags_diagnostic(span), |fixer| {
+            fixer.replace(span, format!("{literal_text}{flags}"))
+        });
+    } else {
+        ctx.diagnostic(redundant_regexp_with_flags_diagnostic(span));
+    }
+}
+
+/// `RegExp` or `globalThis.RegExp`, when they aren't shadowed.
+fn is_regexp_constructor(callee: &Expression, ctx: &LintContext) -> bool {
+    match callee.without_parentheses() {
+        Expression::Identifier(ident) => {
+            ident.name == "RegExp" && ctx.semantic().is_reference_to_global_variable(ident)
+        }
+        Expression::StaticMemberExpression(member) => {
+            member.property.name == "RegExp"
+                && matches!(
+                    &member.object,
+                    Expression::Identifier(object) if object.name == "globalThis"
+                        && ctx.semantic().is_reference_to_global_variable(object)
+                )
+        }
+        _ => false,
+    }
+}
+
+/// The value of a string literal, a template literal without substitutions, or a
+/// `String.raw` tagged template without substitutions.
+fn static_string<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    match expr.without_parentheses() {
+        Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasis.first()?.value.cooked.as_ref().map(Atom::as_str)
+        }
+        Expression::TaggedTemplateExpression(tagged) if tagged.quasi.expressions.is_empty() => {
+            let Expression::StaticMemberExpression(tag) = &tagged.tag else {
+                return None;
+            };
+            let Expression::Identifier(object) = &tag.object else {
+                return None;
+            };
+            (object.name == "String"
+                && tag.property.name == "raw"
+                && ctx.semantic().is_reference_to_global_variable(object))
+            .then(|| tagged.quasi.quasis.first().map(|quasi| quasi.value.raw.as_str()))?
+        }
+        _ => None,
+    }
+}
+
+/// Write the pattern of a `RegExp` constructor as the body of a regular expression literal,
+/// escaping `/` and line terminators, which would end the literal.
+fn to_literal_pattern(pattern: &str) -> String {
+    if pattern.is_empty() {
+        return "(?:)".to_string();
+    }
+    let mut literal = String::with_capacity(pattern.len());
+    let mut escaped = false;
+    for c in pattern.chars() {
+        let escape = match c {
+            '/' => "/",
+            '\n' => "n",
+            '\r' => "r",
+            '\t' => "t",
+            '\u{b}' => "v",
+            '\u{c}' => "f",
+            '\u{2028}' => "u2028",
+            '\u{2029}' => "u2029",
+            _ => {
+                literal.push(c);
+                escaped = c == '\\' && !escaped;
+                continue;
+            }
+        };
+        if !escaped {
+            literal.push('\\');
+        }
+        literal.push_str(escape);
+        escaped = false;
+    }
+    literal
+}
+
+fn is_valid_regex_literal(pattern: &str, flags: &str) -> bool {
+    let allocator = Allocator::default();
+    LiteralParser::new(&allocator, pattern, Some(flags), Options::default()).parse().is_ok()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/abc/", None),
+        ("/abc/g", None),
+        ("new RegExp(pattern)", None),
+        ("RegExp(pattern, 'g')", None),
+        ("new RegExp(f('a'))", None),
+        ("RegExp(prefix + 'a')", None),
+        ("new RegExp(String(a))", None),
+        ("new RegExp('abc', flags)", None),
+        ("new RegExp(`a${b}`)", None),
+        ("new RegExp(String.raw`a${b}`)", None),
+        ("new RegExp(...args)", None),
+        ("new RegExp('a', 'g', extra)", None),
+        ("new RegExp()", None),
+        ("RegExp()", None),
+        ("new RegExp(a.raw`a`)", None),
+        ("new RegExp(String.foo`a`)", None),
+        ("new RegExp(f`a`)", None),
+        ("new Regexp('abc')", None),
+        ("new foo.RegExp('abc')", None),
+        ("let RegExp; new RegExp('abc')", None),
+        ("function f(RegExp) { return RegExp('abc') }", None),
+        ("let String; new RegExp(String.raw`a`)", None),
+        ("let globalThis; new globalThis.RegExp('a')", None),
+        ("new RegExp(/a/)", None),
+        ("new RegExp(/a/, 'u')", None),
+        (
+            "new RegExp(/a/, flags)",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        ("new RegExp(a, 'u')", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+    ];
+
+    let fail = vec![
+        ("new RegExp('abc')", None),
+        ("RegExp('abc')", None),
+        ("new RegExp('abc', 'g')", None),
+        ("RegExp('abc', 'gi')", None),
+        ("new RegExp(`abc`)", None),
+        ("new RegExp(`abc`, `g`)", None),
+        ("new RegExp(String.raw`\\d`)", None),
+        ("new RegExp(('abc'))", None),
+        ("new RegExp('')", None),
+        ("new RegExp('a/b')", None),
+        ("new RegExp('a\\\\/b')", None),
+        ("new RegExp('\\n')", None),
+        ("new RegExp('\\\\d+', 'u')", None),
+        ("new RegExp('[')", None),
+        ("new RegExp('a', 'z')", None),
+        ("new globalThis.RegExp('abc')", None),
+        ("new RegExp('abc').test(s)", None),
+        ("new RegExp(/a/)", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("RegExp(/a/g)", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/, 'u')", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/g, 'u')", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/, 'uv')", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+    ];
+
+    let fix = vec![
+        ("new RegExp('abc')", "/abc/", None),
+        ("RegExp('abc', 'gi')", "/abc/gi", None),
+        ("new RegExp(`abc`, `g`)", "/abc/g", None),
+        ("new RegExp(String.raw`\\d`)", r"/\d/", None),
+        ("new RegExp('')", "/(?:)/", None),
+        ("new RegExp('a/b')", r"/a\/b/", None),
+        ("new RegExp('a\\\\/b')", r"/a\/b/", None),
+        ("new RegExp('\\n')", r"/\n/", None),
+        ("new RegExp('\\\\d+', 'u')", r"/\d+/u", None),
+        ("new RegExp('abc').test(s)", "/abc/.test(s)", None),
+        ("new RegExp('[')", "new RegExp('[')", None),
+        ("new RegExp('a', 'z')", "new RegExp('a', 'z')", None),
+        (
+            "new RegExp(/a/)",
+            "/a/",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/a/, 'u')",
+            "/a/u",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/a/g, 'u')",
+            "new RegExp(/a/g, 'u')",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+    ];
+
+    Tester::new(PreferRegexLiterals::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
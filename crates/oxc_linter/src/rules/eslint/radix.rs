@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// var num = parseInt("071", 10);  // 71
     /// ```
     Radix,
-    pedantic
+    pedantic,
+    node_types = [CallExpression]
 );
 
 impl Rule for Radix {
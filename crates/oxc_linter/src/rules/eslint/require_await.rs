@@ -69,6 +69,7 @@ declare_oxc_lint!(
     /// ```
     RequireAwait,
     pedantic,
+    node_types = [FunctionBody]
 );
 
 impl Rule for RequireAwait {
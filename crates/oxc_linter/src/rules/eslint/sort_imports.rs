@@ -6,10 +6,7 @@ use std::{
 
 use cow_utils::CowUtils;
 use itertools::Itertools;
-use oxc_ast::{
-    ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement},
-    AstKind,
-};
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -76,7 +73,8 @@ declare_oxc_lint!(
     /// ```
     SortImports,
     style,
-    conditional_fix
+    conditional_fix,
+    node_types = []
 );
 
 impl Rule for SortImports {
@@ -134,10 +132,7 @@ impl Rule for SortImports {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let Some(root) = ctx.nodes().root_node() else {
-            return;
-        };
-        let AstKind::Program(program) = root.kind() else { unreachable!() };
+        let program = ctx.program();
 
         let mut import_declarations = vec![];
 
@@ -2,7 +2,6 @@ use std::cmp::Ordering;
 use std::str::Chars;
 
 use cow_utils::CowUtils;
-use itertools::all;
 
 use oxc_ast::ast::ObjectPropertyKind;
 use oxc_ast::AstKind;
@@ -83,9 +82,13 @@ declare_oxc_lint!(
     ///   c: 1,
     /// };
     /// ```
+    ///
+    /// When swapping two neighbouring properties sorts an object, this is offered as a
+    /// suggestion. Other objects are only reported, as moving properties changes the order
+    /// their values are evaluated in.
     SortKeys,
     style,
-    pending
+    suggestion
 );
 
 impl Rule for SortKeys {
@@ -135,18 +138,25 @@ impl Rule for SortKeys {
                 return;
             }
 
-            let mut property_groups: Vec<Vec<String>> = vec![vec![]];
+            // Keys and the indices of their properties, split by spreads and, with
+            // `allowLineSeparatedGroups`, blank lines, as each group is sorted on its own.
+            let mut property_groups: Vec<Vec<(String, usize)>> = vec![vec![]];
 
             let source_text = ctx.semantic().source_text();
 
             for (i, prop) in dec.properties.iter().enumerate() {
                 match prop {
                     ObjectPropertyKind::SpreadProperty(_) => {
-                        property_groups.push(vec!["<ellipsis_group>".into()]);
                         property_groups.push(vec![]);
                     }
                     ObjectPropertyKind::ObjectProperty(obj) => {
                         let Some(key) = obj.key.static_name() else { continue };
+                        let key = if self.case_sensitive {
+                            key.into_owned()
+                        } else {
+                            key.cow_to_lowercase().into_owned()
+                        };
+                        property_groups.last_mut().unwrap().push((key, i));
                         if i != dec.properties.len() - 1 && self.allow_line_separated_groups {
                             let text_between = extract_text_between_spans(
                                 source_text,
@@ -154,46 +164,88 @@ impl Rule for SortKeys {
                                 dec.properties[i + 1].span(),
                             );
                             if text_between.contains("\n\n") {
-                                property_groups.last_mut().unwrap().push(key.into());
-                                property_groups.push(vec!["<linebreak_group>".into()]);
                                 property_groups.push(vec![]);
-                                continue;
                             }
                         }
-                        property_groups.last_mut().unwrap().push(key.into());
                     }
                 }
             }
 
-            if !self.case_sensitive {
-                for group in &mut property_groups {
-                    *group = group
-                        .iter()
-                        .map(|s| s.cow_to_lowercase().to_string())
-                        .collect::<Vec<String>>();
-                }
-            }
+            let unsorted_groups = property_groups
+                .iter()
+                .filter(|group| {
+                    let keys = group.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+                    self.sorted(keys.clone()) != keys
+                })
+                .collect::<Vec<_>>();
 
-            let mut sorted_property_groups = property_groups.clone();
-            for group in &mut sorted_property_groups {
-                if self.natural {
-                    natural_sort(group);
-                } else {
-                    alphanumeric_sort(group);
-                }
+            if unsorted_groups.is_empty() {
+                return;
+            }
 
-                if self.sort_order == SortOrder::Desc {
-                    group.reverse();
-                }
+            let diagnostic = sort_properties_diagnostic(node.span());
+            match unsorted_groups.as_slice() {
+                [group] => match self.adjacent_swap(group) {
+                    Some((i, j)) => {
+                        let (first, second) = (&dec.properties[i], &dec.properties[j]);
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            let (first, second) = (first.span(), second.span());
+                            let between = Span::new(first.end, second.start);
+                            fixer
+                                .replace(
+                                    Span::new(first.start, second.end),
+                                    format!(
+                                        "{}{}{}",
+                                        fixer.source_range(second),
+                                        fixer.source_range(between),
+                                        fixer.source_range(first)
+                                    ),
+                                )
+                                .with_message(format!(
+                                    "Move `{}` before `{}`",
+                                    fixer.source_range(property_key_span(&dec.properties[j])),
+                                    fixer.source_range(property_key_span(&dec.properties[i]))
+                                ))
+                        });
+                    }
+                    None => ctx.diagnostic(diagnostic),
+                },
+                _ => ctx.diagnostic(diagnostic),
             }
+        }
+    }
+}
 
-            let is_sorted =
-                all(property_groups.iter().zip(&sorted_property_groups), |(a, b)| a == b);
+impl SortKeys {
+    fn sorted(&self, mut keys: Vec<String>) -> Vec<String> {
+        if self.natural {
+            natural_sort(&mut keys);
+        } else {
+            alphanumeric_sort(&mut keys);
+        }
 
-            if !is_sorted {
-                ctx.diagnostic(sort_properties_diagnostic(node.span()));
-            }
+        if self.sort_order == SortOrder::Desc {
+            keys.reverse();
         }
+        keys
+    }
+
+    /// The indices of two neighbouring properties in `group` that, when swapped, sort it.
+    fn adjacent_swap(&self, group: &[(String, usize)]) -> Option<(usize, usize)> {
+        let keys = group.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        let sorted = self.sorted(keys.clone());
+        let i = keys.iter().zip(&sorted).position(|(key, sorted)| key != sorted)?;
+        let mut swapped = keys;
+        swapped.swap(i, i + 1);
+        let (first, second) = (group[i].1, group[i + 1].1);
+        (swapped == sorted && second == first + 1).then_some((first, second))
+    }
+}
+
+fn property_key_span(property: &ObjectPropertyKind) -> Span {
+    match property {
+        ObjectPropertyKind::ObjectProperty(property) => property.key.span(),
+        ObjectPropertyKind::SpreadProperty(spread) => spread.span,
     }
 }
 
@@ -1024,5 +1076,33 @@ fn test() {
         ), // { "ecmaVersion": 2018 }
     ];
 
-    Tester::new(SortKeys::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("var obj = {b:1, a:2}", "var obj = {a:2, b:1}", None),
+        ("var obj = {a:1, c:2, b:3, d:4}", "var obj = {a:1, b:3, c:2, d:4}", None),
+        ("var obj = {a:1, b:2}", "var obj = {b:2, a:1}", Some(serde_json::json!(["desc"]))),
+        (
+            "var obj = {B:1, a:2}",
+            "var obj = {a:2, B:1}",
+            Some(serde_json::json!(["asc", { "caseSensitive": false }])),
+        ),
+        (
+            "var obj = {a10:1, a2:2}",
+            "var obj = {a2:2, a10:1}",
+            Some(serde_json::json!(["asc", { "natural": true }])),
+        ),
+        ("var obj = {b:1, ...z, d:1, c:2}", "var obj = {b:1, ...z, c:2, d:1}", None),
+        (
+            "var obj = {\n  a: 1,\n  // comment\n  c: 2,\n  b: 3,\n}",
+            "var obj = {\n  a: 1,\n  // comment\n  b: 3,\n  c: 2,\n}",
+            None,
+        ),
+        // not sorted by a single swap
+        ("var obj = {c:1, b:2, a:3}", "var obj = {c:1, b:2, a:3}", None),
+        // more than one group is unsorted
+        ("var obj = {b:1, a:2, ...z, d:1, c:2}", "var obj = {b:1, a:2, ...z, d:1, c:2}", None),
+        // the properties to swap are not next to each other
+        ("var obj = {b:1, [f()]:2, a:3}", "var obj = {b:1, [f()]:2, a:3}", None),
+    ];
+
+    Tester::new(SortKeys::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
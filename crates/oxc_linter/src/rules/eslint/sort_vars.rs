@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     SortVars,
     pedantic,
-    pending
+    pending,
+    node_types = [VariableDeclaration]
 );
 
 impl Rule for SortVars {
@@ -44,6 +44,7 @@ declare_oxc_lint!(
     ///
     SymbolDescription,
     pedantic,
+    node_types = [CallExpression]
 );
 
 impl Rule for SymbolDescription {
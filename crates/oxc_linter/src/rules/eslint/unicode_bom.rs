@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// ```
     UnicodeBom,
     restriction,
-    fix
+    fix,
+    node_types = []
 );
 
 impl Rule for UnicodeBom {
@@ -1,11 +1,12 @@
 use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::constant_evaluation::ConstantValue;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 use phf::{phf_set, Set};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::const_eval::ConstEval, context::LintContext, rule::Rule, AstNode};
 
 fn not_string(help: Option<&'static str>, span: Span) -> OxcDiagnostic {
     let mut d =
@@ -80,16 +81,14 @@ impl Rule for ValidTypeof {
             return;
         };
 
-        if let Expression::StringLiteral(lit) = sibling {
-            if !VALID_TYPES.contains(lit.value.as_str()) {
-                ctx.diagnostic(invalid_value(None, sibling.span()));
-            }
-            return;
-        }
-
-        if let Expression::TemplateLiteral(template) = sibling {
-            if template.expressions.is_empty() {
-                if template.quasi().is_some_and(|value| !VALID_TYPES.contains(value.as_str())) {
+        if matches!(
+            sibling.without_parentheses(),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_)
+        ) {
+            // Template literals with substitutions can't be evaluated.
+            if let Some(ConstantValue::String(value)) = ConstEval::new(ctx.semantic()).eval(sibling)
+            {
+                if !VALID_TYPES.contains(value.as_ref()) {
                     ctx.diagnostic(invalid_value(None, sibling.span()));
                 }
                 return;
@@ -187,6 +186,7 @@ fn test() {
         ("typeof foo === typeof bar", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         ("typeof foo === `string`", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         ("`object` === typeof foo", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
+        ("typeof foo === ('string')", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
     ];
 
     let fail = vec![
@@ -201,6 +201,7 @@ fn test() {
         ("if (typeof bar != 'umdefined') {}", None),
         ("typeof foo == 'strnig'", None),
         ("'strnig' == typeof foo", None),
+        ("typeof foo === ('strnig')", None),
         ("if (typeof bar == 'umdefined') {}", None),
         ("if (typeof bar === `umdefined`) {}", None),
         (
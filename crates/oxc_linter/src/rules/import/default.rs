@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// import { bar } from './bar' // correct usage of named import
     /// ```
     Default,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for Default {
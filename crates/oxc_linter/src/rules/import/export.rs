@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// export * from "./export-all"; // No conflict if export-all.js also exports foo
     /// ```
     Export,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for Export {
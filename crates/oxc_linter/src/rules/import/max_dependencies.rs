@@ -69,6 +69,7 @@ declare_oxc_lint!(
     /// ```
     MaxDependencies,
     pedantic,
+    node_types = []
 );
 
 impl Rule for MaxDependencies {
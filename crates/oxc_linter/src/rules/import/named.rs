@@ -74,7 +74,8 @@ declare_oxc_lint!(
     /// import { SomeNonsenseThatDoesntExist } from 'react'
     /// ```
     Named,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for Named {
@@ -99,7 +99,8 @@ declare_oxc_lint!(
     /// foo[method](); // Valid: method refers to an exported function
     /// ```
     Namespace,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for Namespace {
@@ -7,7 +7,7 @@ use oxc_ast::{
     AstKind,
 };
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode, ModuleKind};
 
 fn no_commonjs_diagnostic(span: Span, name: &str, actual: &str) -> OxcDiagnostic {
     // See <https://oxc.rs/docs/contribute/linter/adding-rules.html#diagnostics> for details
@@ -74,6 +74,10 @@ declare_oxc_lint!(
     /// } catch (error) {}
     /// ```
     ///
+    /// `require` calls are only reported in ES modules, as CommonJS files, such as
+    /// `.cjs` files or `.js` files in a package with `"type": "commonjs"`, cannot import
+    /// modules otherwise.
+    ///
     /// ### Allow require
     ///
     /// If `allowRequire` option is set to `true`, `require` calls are valid:
@@ -205,6 +209,10 @@ impl Rule for NoCommonjs {
                 }
             }
             AstKind::CallExpression(call_expr) => {
+                if ctx.module_kind() == ModuleKind::CommonJs {
+                    return;
+                }
+
                 if self.allow_conditional_require && node.scope_id() != ctx.scopes().root_scope_id()
                 {
                     return;
@@ -332,4 +340,22 @@ fn test() {
         .change_rule_path("index.js")
         .with_import_plugin(true)
         .test_and_snapshot();
+
+    // CommonJS files, by extension or by the `type` of their package
+    let pass = vec![(r#"var x = require("x")"#, None), (r#"require("x")"#, None)];
+    let fail = vec![(r#"module.exports = { x: "y" }"#, None), (r#"exports.face = "palm""#, None)];
+    for path in ["index.cjs", "index.cts", "commonjs-package/index.js"] {
+        Tester::new(NoCommonjs::NAME, pass.clone(), fail.clone())
+            .change_rule_path(path)
+            .with_import_plugin(true)
+            .test();
+    }
+
+    // `.js` files of CommonJS packages are still parsed as modules
+    let pass = vec![(r#"import x from "x"; var y = require("y")"#, None)];
+    let fail = vec![(r#"import x from "x"; module.exports = x"#, None)];
+    Tester::new(NoCommonjs::NAME, pass, fail)
+        .change_rule_path("commonjs-package/index.js")
+        .with_import_plugin(true)
+        .test();
 }
@@ -87,7 +87,8 @@ declare_oxc_lint!(
     ///
     /// In this corrected version, `dep-b.js` no longer imports `dep-a.js`, breaking the cycle.
     NoCycle,
-    restriction
+    restriction,
+    node_types = []
 );
 
 impl Rule for NoCycle {
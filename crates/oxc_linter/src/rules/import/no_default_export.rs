@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// export const bar = 'bar';
     /// ```
     NoDefaultExport,
-    restriction
+    restriction,
+    node_types = []
 );
 
 impl Rule for NoDefaultExport {
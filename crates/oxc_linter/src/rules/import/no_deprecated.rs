@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoDeprecated,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for NoDeprecated {
@@ -72,7 +72,8 @@ declare_oxc_lint!(
     /// import type { d } from 'foo'; // `preferInline` is true
     /// ```
     NoDuplicates,
-    suspicious
+    suspicious,
+    node_types = []
 );
 
 impl Rule for NoDuplicates {
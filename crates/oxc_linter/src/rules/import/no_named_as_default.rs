@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// import foo from './foo.js';
     /// ```
     NoNamedAsDefault,
-    suspicious
+    suspicious,
+    node_types = []
 );
 
 impl Rule for NoNamedAsDefault {
@@ -62,7 +62,8 @@ declare_oxc_lint!(
     /// import { bar } from './bar'; // Correct: accessing named export directly
     /// ```
     NoNamedAsDefaultMember,
-    suspicious
+    suspicious,
+    node_types = []
 );
 fn get_symbol_id_from_ident(
     ctx: &LintContext<'_>,
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// import bar from './bar.js';  // Correct: module imports another module
     /// ```
     NoSelfImport,
-    suspicious
+    suspicious,
+    node_types = []
 );
 
 impl Rule for NoSelfImport {
@@ -0,0 +1,99 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_unresolved_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unable to resolve path to module '{specifier}'"))
+        .with_help("Check the specifier for typos, or that the package is installed")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local
+    /// filesystem. Path aliases from the nearest `tsconfig.json` (or
+    /// `jsconfig.json`) `paths` and `baseUrl` are taken into account.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module that does not exist fails at runtime or at build
+    /// time, usually because of a typo or a missing dependency.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import foo from './does-not-exist';
+    /// export { bar } from './no-such-file';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import foo from './foo';
+    /// import fs from 'node:fs';
+    /// ```
+    NoUnresolved,
+    nursery,
+    node_types = []
+);
+
+impl Rule for NoUnresolved {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        if module_record.unresolved_modules.is_empty() {
+            return;
+        }
+        let mut unresolved = module_record
+            .requested_modules
+            .iter()
+            .filter(|(specifier, _)| module_record.unresolved_modules.contains(*specifier))
+            .flat_map(|(specifier, requested_modules)| {
+                // Type-only imports may be satisfied by ambient declarations.
+                requested_modules
+                    .iter()
+                    .filter(|module| !module.is_type())
+                    .map(move |module| (module.span(), specifier))
+            })
+            .collect::<Vec<_>>();
+        unresolved.sort_unstable_by_key(|(span, _)| *span);
+        for (span, specifier) in unresolved {
+            ctx.diagnostic(no_unresolved_diagnostic(span, specifier));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import foo from './bar'",
+        "import foo from './bar.js'",
+        "import * as data from './data.json'",
+        "import { foo } from './deep/a'",
+        "export * from './bar'",
+        "import fs from 'fs'",
+        "import path from 'node:path'",
+        "import type { Foo } from './does-not-exist'",
+        "const foo = require('./does-not-exist')",
+    ];
+
+    let fail = vec![
+        "import foo from './does-not-exist'",
+        "import { a } from './bar'; import { b } from '../nope/nope'",
+        "export { foo } from './does-not-exist'",
+        "export * from './does-not-exist'",
+        "import './does-not-exist'; import './does-not-exist'",
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.ts")
+        .test_and_snapshot();
+}
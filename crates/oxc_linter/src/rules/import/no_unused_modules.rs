@@ -1,15 +1,28 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::FxHashSet;
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::{LintContext, ProjectContext},
     rule::Rule,
+    service::ModuleGraph,
 };
 
 fn no_exports_found(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("No exports found").with_label(span)
 }
 
+fn unused_export(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("exported declaration '{name}' not used within other modules"))
+        .with_label(span)
+}
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unused-modules.md>
 #[derive(Debug, Default, Clone)]
 pub struct NoUnusedModules {
@@ -21,34 +34,61 @@ declare_oxc_lint!(
     /// ### What it does
     ///
     /// Reports:
-    /// * modules without any exports
-    /// * individual exports not being statically imported or requireed from other modules in the same project
-    /// * dynamic imports are supported if argument is a literal string
+    /// * modules without any exports, with `missingExports`
+    /// * individual exports not being statically imported or re-exported from
+    ///   other modules in the same project, with `unusedExports`
+    ///
+    /// `unusedExports` needs the whole project: it only reports exports of the
+    /// files being linted, and only sees imports from the files being linted and
+    /// the modules they import. Exports only used through `import()` or
+    /// `require()` are reported.
     ///
     /// ### Why is this bad?
     ///
+    /// Exports that are never imported are dead code, and a module that
+    /// exports nothing can't be used by other modules.
+    ///
     /// ### Examples
     ///
-    /// Examples of **incorrect** code for this rule:
+    /// Examples of **incorrect** code for this rule with `{ "unusedExports": true }`:
     /// ```javascript
+    /// // a.js
+    /// import { used } from "./b";
+    ///
+    /// // b.js
+    /// export const used = 1;
+    /// export const unused = 2;
     /// ```
     ///
-    /// Examples of **correct** code for this rule:
+    /// Examples of **correct** code for this rule with `{ "unusedExports": true }`:
     /// ```javascript
+    /// // a.js
+    /// import { used } from "./b";
+    ///
+    /// // b.js
+    /// export const used = 1;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "import/no-unused-modules": ["error", { "missingExports": true, "unusedExports": true }] }
     /// ```
     NoUnusedModules,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for NoUnusedModules {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
         Self {
-            missing_exports: value
-                .get("missingExports")
+            missing_exports: obj
+                .and_then(|v| v.get("missingExports"))
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false),
-            unused_exports: value
-                .get("unusedExports")
+            unused_exports: obj
+                .and_then(|v| v.get("unusedExports"))
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false),
         }
@@ -59,10 +99,67 @@ impl Rule for NoUnusedModules {
         if self.missing_exports && module_record.local_export_entries.is_empty() {
             ctx.diagnostic(no_exports_found(Span::new(0, 0)));
         }
-        if self.unused_exports {
-            // TODO: implement unused exports
+    }
+
+    fn run_on_project(&self, ctx: &ProjectContext<'_>) {
+        if !self.unused_exports {
+            return;
+        }
+        let graph = ctx.graph();
+        for (path, record) in graph.iter().filter(|(path, _)| graph.is_linted(path)) {
+            let Some(used) = used_exports(graph, path) else {
+                continue;
+            };
+            let exports = record.local_export_entries.iter().chain(&record.indirect_export_entries);
+            for export in exports {
+                let (name, span) = match &export.export_name {
+                    ExportExportName::Name(name) => (name.name().as_str(), name.span()),
+                    ExportExportName::Default(span) => ("default", *span),
+                    ExportExportName::Null => continue,
+                };
+                if !used.contains(name) {
+                    ctx.diagnostic(path, unused_export(name, span));
+                }
+            }
+        }
+    }
+}
+
+/// Names exported by `path` that its dependents import or re-export, or `None`
+/// if they may use all of them, e.g. with `import * as ns`.
+fn used_exports(graph: &ModuleGraph, path: &Path) -> Option<FxHashSet<CompactStr>> {
+    let mut used = FxHashSet::default();
+    for importer in graph.dependents(path) {
+        let Some(record) = graph.get(importer) else {
+            continue;
+        };
+        let imports = |specifier: &str| resolves_to(record, specifier, path);
+        for import in
+            record.import_entries.iter().filter(|import| imports(import.module_request.name()))
+        {
+            match &import.import_name {
+                ImportImportName::Name(name) => used.insert(name.name().clone()),
+                ImportImportName::Default(_) => used.insert("default".into()),
+                ImportImportName::NamespaceObject => return None,
+            };
+        }
+        let re_exports = record.indirect_export_entries.iter().chain(&record.star_export_entries);
+        for export in re_exports {
+            if !export.module_request.as_ref().is_some_and(|request| imports(request.name())) {
+                continue;
+            }
+            match &export.import_name {
+                ExportImportName::Name(name) => used.insert(name.name().clone()),
+                ExportImportName::All | ExportImportName::AllButDefault => return None,
+                ExportImportName::Null => continue,
+            };
         }
     }
+    Some(used)
+}
+
+fn resolves_to(record: &ModuleRecord, specifier: &str, path: &Path) -> bool {
+    record.loaded_modules.get(specifier).is_some_and(|module| module.resolved_absolute_path == path)
 }
 
 #[test]
@@ -70,9 +167,9 @@ fn test() {
     use crate::tester::Tester;
     use serde_json::json;
 
-    let missing_exports_options = json!({
+    let missing_exports_options = json!([{
       "missingExports": true,
-    });
+    }]);
 
     let pass = vec![
         ("export default function noOptions() {}", None),
@@ -107,23 +204,4 @@ fn test() {
         .change_rule_path("missing-exports.js")
         .with_import_plugin(true)
         .test_and_snapshot();
-
-    // TODO: support unused exports
-    // let unused_exports_options = json!({
-    //   "unusedExports": true,
-    //   "src": ["./no-unused-modules/**/*.js"],
-    //   "ignoreExports": ["./no-unused-modules/*ignored*.js"],
-    // });
-
-    // let pass = vec![
-    //     ("export default function noOptions() {}", None),
-    //     ("export default () => 1", Some(unused_exports_options)),
-    // ];
-
-    // let fail = vec![];
-
-    // Tester::new(NoUnusedModules::NAME, pass, fail)
-    //     .change_rule_path("unused-exports.js")
-    //     .with_import_plugin(true)
-    //     .test_and_snapshot();
 }
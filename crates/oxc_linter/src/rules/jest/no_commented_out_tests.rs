@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoCommentedOutTests,
-    suspicious
+    suspicious,
+    node_types = []
 );
 
 impl Rule for NoCommentedOutTests {
@@ -59,11 +60,8 @@ impl Rule for NoCommentedOutTests {
             static ref RE: Regex =
             Regex::new(r#"(?mu)^\s*[xf]?(test|it|describe)(\.\w+|\[['"]\w+['"]\])?\s*\("#).unwrap();
         }
-        let comments = ctx.semantic().comments();
-        let source_text = ctx.semantic().source_text();
-        let commented_tests = comments.iter().filter_map(|comment| {
-            let text = comment.span.source_text(source_text);
-            if RE.is_match(text) {
+        let commented_tests = ctx.comments().iter().filter_map(|comment| {
+            if RE.is_match(ctx.comment_text(comment)) {
                 Some(comment.span)
             } else {
                 None
@@ -71,7 +71,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoConfusingSetTimeout,
-    style
+    style,
+    node_types = []
 );
 
 impl Rule for NoConfusingSetTimeout {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoExport,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for NoExport {
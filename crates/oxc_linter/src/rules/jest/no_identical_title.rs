@@ -67,7 +67,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoIdenticalTitle,
-    style
+    style,
+    node_types = []
 );
 
 impl Rule for NoIdenticalTitle {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// require('__mocks__');
     /// ```
     NoMocksImport,
-    style
+    style,
+    node_types = []
 );
 
 impl Rule for NoMocksImport {
@@ -139,6 +139,7 @@ declare_oxc_lint!(
     /// }
     PreferHooksInOrder,
     style,
+    node_types = []
 );
 
 impl Rule for PreferHooksInOrder {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// ```
     PreferSpyOn,
     style,
-    fix
+    fix,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for PreferSpyOn {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// /** @private */
     /// ```
     CheckAccess,
-    restriction
+    restriction,
+    node_types = []
 );
 
 const ACCESS_LEVELS: phf::Set<&'static str> = phf_set! {
@@ -57,7 +57,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     CheckPropertyNames,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for CheckPropertyNames {
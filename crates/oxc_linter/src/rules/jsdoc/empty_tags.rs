@@ -58,7 +58,8 @@ declare_oxc_lint!(
     /// /** @private */
     /// ```
     EmptyTags,
-    restriction
+    restriction,
+    node_types = []
 );
 
 const EMPTY_TAGS: phf::Set<&'static str> = phf_set! {
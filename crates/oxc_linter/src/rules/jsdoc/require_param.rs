@@ -262,11 +262,7 @@ fn collect_tags<'a>(
 ) -> Vec<(&'a str, Option<&'a str>)> {
     let mut collected = vec![];
 
-    for tag in jsdocs
-        .iter()
-        .flat_map(JSDoc::tags)
-        .filter(|tag| tag.kind.parsed() == resolved_param_tag_name)
-    {
+    for tag in jsdocs.iter().flat_map(|jsdoc| jsdoc.tags_named(resolved_param_tag_name)) {
         let (type_part, Some(name_part), _) = tag.type_name_comment() else {
             continue;
         };
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequireProperty,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for RequireProperty {
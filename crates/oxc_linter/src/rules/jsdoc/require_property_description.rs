@@ -44,7 +44,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequirePropertyDescription,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for RequirePropertyDescription {
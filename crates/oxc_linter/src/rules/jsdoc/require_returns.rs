@@ -64,6 +64,7 @@ declare_oxc_lint!(
     /// ```
     RequireReturns,
     pedantic,
+    node_types = []
 );
 
 #[derive(Debug, Clone, Deserialize)]
@@ -130,7 +130,8 @@ declare_oxc_lint!(
     /// <img src="flower.jpg" />
     /// ```
     AltText,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for AltText {
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// const Bad = <div aria-activedescendant={someID} />
     /// ```
     AriaActivedescendantHasTabindex,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for AriaActivedescendantHasTabindex {
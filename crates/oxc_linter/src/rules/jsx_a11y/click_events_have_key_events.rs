@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// <div onClick={() => void 0} onKeyDown={() => void 0} />
     /// ```
     ClickEventsHaveKeyEvents,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for ClickEventsHaveKeyEvents {
@@ -60,7 +60,8 @@ declare_oxc_lint!(
     /// <h1>Foo</h1>
     /// ```
     HeadingHasContent,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 // always including <h1> thru <h6>
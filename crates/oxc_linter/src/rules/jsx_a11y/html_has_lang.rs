@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// <html lang="en" />
     /// ```
     HtmlHasLang,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for HtmlHasLang {
@@ -57,7 +57,8 @@ declare_oxc_lint!(
     /// <iframe title={uniqueTitle} />
     /// ```
     IframeHasTitle,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for IframeHasTitle {
@@ -99,7 +99,8 @@ declare_oxc_lint!(
     /// <img src="baz" alt={`Baz taking a ${photo}`} /> // This is valid since photo is a variable name.
     /// ```
     ImgRedundantAlt,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for ImgRedundantAlt {
@@ -105,6 +105,7 @@ declare_oxc_lint!(
     /// ```
     LabelHasAssociatedControl,
     correctness,
+    node_types = [JSXElement]
 );
 
 impl Rule for LabelHasAssociatedControl {
@@ -60,7 +60,8 @@ declare_oxc_lint!(
     /// <video><track kind="captions" src="caption_file.vtt" /></video>
     /// ```
     MediaHasCaption,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for MediaHasCaption {
@@ -63,7 +63,8 @@ declare_oxc_lint!(
     /// <div onMouseOver={() => void 0} onFocus={() => void 0} />
     /// ```
     MouseEventsHaveKeyEvents,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for MouseEventsHaveKeyEvents {
@@ -39,6 +39,7 @@ declare_oxc_lint!(
     NoAccessKey,
     correctness,
     suggestion,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoAccessKey {
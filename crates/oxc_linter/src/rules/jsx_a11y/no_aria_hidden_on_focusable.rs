@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     NoAriaHiddenOnFocusable,
     correctness,
-    fix
+    fix,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoAriaHiddenOnFocusable {
@@ -64,7 +64,8 @@ declare_oxc_lint!(
     /// ```
     NoAutofocus,
     correctness,
-    fix
+    fix,
+    node_types = [JSXElement]
 );
 
 impl NoAutofocus {
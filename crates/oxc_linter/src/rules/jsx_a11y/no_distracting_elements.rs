@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// <Blink />
     /// ```
     NoDistractingElements,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoDistractingElements {
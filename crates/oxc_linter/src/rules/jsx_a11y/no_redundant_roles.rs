@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoRedundantRoles,
     correctness,
-    fix
+    fix,
+    node_types = [JSXOpeningElement]
 );
 
 static DEFAULT_ROLE_EXCEPTIONS: phf::Map<&'static str, &'static str> = phf_map! {
@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     ///
     RoleSupportsAriaProps,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 #[derive(Debug, Default, Clone)]
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// ```
     Scope,
     correctness,
-    fix
+    fix,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for Scope {
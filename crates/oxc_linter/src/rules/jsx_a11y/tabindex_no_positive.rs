@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     TabindexNoPositive,
     correctness,
-    pending
+    pending,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for TabindexNoPositive {
@@ -75,7 +75,8 @@ declare_oxc_lint!(
     /// ```
     ///
     GoogleFontDisplay,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for GoogleFontDisplay {
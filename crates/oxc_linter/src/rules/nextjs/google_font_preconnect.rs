@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     GoogleFontPreconnect,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for GoogleFontPreconnect {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NextScriptForGa,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NextScriptForGa {
@@ -25,7 +25,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoAssignModuleVariable,
-    correctness
+    correctness,
+    node_types = [VariableDeclaration]
 );
 
 impl Rule for NoAssignModuleVariable {
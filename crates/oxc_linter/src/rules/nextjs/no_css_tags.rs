@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCssTags,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoCssTags {
@@ -25,7 +25,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoImgElement,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoImgElement {
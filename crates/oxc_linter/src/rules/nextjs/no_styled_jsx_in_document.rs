@@ -31,6 +31,7 @@ declare_oxc_lint!(
     /// ```
     NoStyledJsxInDocument,
     correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoStyledJsxInDocument {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoSyncScripts,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for NoSyncScripts {
@@ -0,0 +1,511 @@
+use oxc_ast::{
+    ast::{
+        Argument, BindingPattern, BindingPatternKind, ImportDeclarationSpecifier, MemberExpression,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use phf::phf_map;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    ast_util::{is_global_require_call, outermost_paren_parent},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_deprecated_api_diagnostic(
+    name: &str,
+    deprecation: &Deprecation,
+    span: Span,
+) -> OxcDiagnostic {
+    let Deprecation { since, replaced_by } = deprecation;
+    let diagnostic = OxcDiagnostic::warn(format!("`{name}` was deprecated since v{since}."));
+    match replaced_by {
+        Some(replaced_by) => diagnostic.with_help(format!("Use {replaced_by} instead.")),
+        None => diagnostic,
+    }
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoDeprecatedApiConfig {
+    /// Deprecated module APIs that are allowed, e.g. `"fs.exists"`,
+    /// `"new buffer.Buffer()"` or a whole module like `"punycode"`.
+    ignore_module_items: Vec<CompactStr>,
+    /// Deprecated global APIs that are allowed, e.g. `"Buffer()"` or
+    /// `"process.binding"`.
+    ignore_global_items: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoDeprecatedApi {
+    type Target = NoDeprecatedApiConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows deprecated Node.js APIs, from core modules that are imported
+    /// or required, and from globals like `Buffer` and `process`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs may be removed in a future version of Node.js, and
+    /// many of them were deprecated because they are unsafe or misleading,
+    /// e.g. `new Buffer(size)` may return memory containing old data.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.exists("./foo.js", () => {});
+    ///
+    /// import { parse } from "node:url";
+    /// const buf = new Buffer(10);
+    /// const punycode = require("punycode");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.stat("./foo.js", () => {});
+    ///
+    /// const buf = Buffer.alloc(10);
+    /// // a package from npm, not the core module
+    /// const punycode = require("punycode/");
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// Deprecated APIs can be allowed with `ignoreModuleItems` and
+    /// `ignoreGlobalItems`, using the names from the diagnostics:
+    ///
+    /// ```json
+    /// { "node/no-deprecated-api": ["error", { "ignoreModuleItems": ["url.parse"], "ignoreGlobalItems": ["process.binding"] }] }
+    /// ```
+    NoDeprecatedApi,
+    pedantic,
+    config = NoDeprecatedApiConfig,
+    node_types = [ImportDeclaration, CallExpression]
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let items = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(Value::as_array)
+                .map(|items| items.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+                .unwrap_or_default()
+        };
+        Self(Box::new(NoDeprecatedApiConfig {
+            ignore_module_items: items("ignoreModuleItems"),
+            ignore_global_items: items("ignoreGlobalItems"),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let tracker =
+            Tracker { items: &DEPRECATED_GLOBAL_ITEMS, ignored: &self.ignore_global_items };
+        for (name, reference_ids) in ctx.scopes().root_unresolved_references() {
+            if !DEPRECATED_GLOBAL_ITEMS.keys().any(|item| root_name(item) == name.as_str()) {
+                continue;
+            }
+            for &reference_id in reference_ids {
+                let node =
+                    ctx.nodes().get_node(ctx.symbols().get_reference(reference_id).node_id());
+                if !tracker.check(name, node.kind().span(), ctx) {
+                    tracker.check_uses(node, name.to_string(), ctx);
+                }
+            }
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let tracker =
+            Tracker { items: &DEPRECATED_MODULE_ITEMS, ignored: &self.ignore_module_items };
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                let Some(module) = core_module_name(&decl.source.value) else {
+                    return;
+                };
+                if tracker.check(module, decl.source.span, ctx) {
+                    return;
+                }
+                for specifier in decl.specifiers.iter().flatten() {
+                    let path = match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            if specifier.import_kind.is_type() {
+                                continue;
+                            }
+                            let path = format!("{module}.{}", specifier.imported.name());
+                            if tracker.check(&path, specifier.span, ctx) {
+                                continue;
+                            }
+                            path
+                        }
+                        _ => module.to_string(),
+                    };
+                    tracker.check_binding_uses(specifier.local().symbol_id.get(), &path, ctx);
+                }
+            }
+            AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                let Some(Argument::StringLiteral(source)) = call.arguments.first() else {
+                    return;
+                };
+                let Some(module) = core_module_name(&source.value) else {
+                    return;
+                };
+                if !tracker.check(module, call.span, ctx) {
+                    tracker.check_uses(node, module.to_string(), ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The name of the core module imported by `source`, e.g. `fs` for `"fs"` and `"node:fs"`.
+///
+/// A trailing slash, as in `"punycode/"`, resolves to a package with the same name instead.
+fn core_module_name(source: &str) -> Option<&str> {
+    let module = source.strip_prefix("node:").unwrap_or(source);
+    (!module.is_empty() && !module.ends_with('/')).then_some(module)
+}
+
+/// The global an item is accessed through, e.g. `Buffer` for `new Buffer()`.
+fn root_name(item: &str) -> &str {
+    let item = item.strip_prefix("new ").unwrap_or(item);
+    item.split(['.', '(']).next().unwrap_or(item)
+}
+
+struct Tracker<'t> {
+    items: &'t phf::Map<&'static str, Deprecation>,
+    ignored: &'t [CompactStr],
+}
+
+impl Tracker<'_> {
+    /// Report `path` if it is deprecated. Returns `true` if it is.
+    fn check(&self, path: &str, span: Span, ctx: &LintContext) -> bool {
+        let Some(deprecation) = self.items.get(path) else {
+            return false;
+        };
+        if !self.ignored.iter().any(|ignored| ignored == path) {
+            ctx.diagnostic(no_deprecated_api_diagnostic(path, deprecation, span));
+        }
+        true
+    }
+
+    /// Follow property accesses, calls and destructuring of `node`, whose value is `path`,
+    /// until reaching a deprecated API.
+    fn check_uses<'a>(&self, node: &AstNode<'a>, mut path: String, ctx: &LintContext<'a>) {
+        let mut node = node;
+        loop {
+            let Some(parent) = outermost_paren_parent(node, ctx.semantic()) else {
+                return;
+            };
+            let span = node.kind().span();
+            match parent.kind() {
+                AstKind::MemberExpression(member)
+                    if member.object().without_parentheses().span() == span =>
+                {
+                    let name = match member {
+                        MemberExpression::StaticMemberExpression(member) => {
+                            member.property.name.as_str()
+                        }
+                        MemberExpression::ComputedMemberExpression(member) => {
+                            match member.static_property_name() {
+                                Some(name) => name.as_str(),
+                                None => return,
+                            }
+                        }
+                        MemberExpression::PrivateFieldExpression(_) => return,
+                    };
+                    path = format!("{path}.{name}");
+                    if self.check(&path, member.span(), ctx) {
+                        return;
+                    }
+                }
+                AstKind::CallExpression(call)
+                    if call.callee.without_parentheses().span() == span =>
+                {
+                    self.check(&format!("{path}()"), call.span, ctx);
+                    return;
+                }
+                AstKind::NewExpression(new) if new.callee.without_parentheses().span() == span => {
+                    self.check(&format!("new {path}()"), new.span, ctx);
+                    return;
+                }
+                AstKind::VariableDeclarator(decl) => {
+                    if decl
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| init.without_parentheses().span() == span)
+                    {
+                        self.check_pattern_uses(&decl.id, &path, ctx);
+                    }
+                    return;
+                }
+                _ => return,
+            }
+            node = parent;
+        }
+    }
+
+    fn check_pattern_uses(&self, pattern: &BindingPattern, path: &str, ctx: &LintContext) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.check_binding_uses(ident.symbol_id.get(), path, ctx);
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                for property in &object.properties {
+                    let Some(name) = property.key.static_name() else {
+                        continue;
+                    };
+                    let path = format!("{path}.{name}");
+                    if !self.check(&path, property.span, ctx) {
+                        self.check_pattern_uses(&property.value, &path, ctx);
+                    }
+                }
+            }
+            BindingPatternKind::AssignmentPattern(assignment) => {
+                self.check_pattern_uses(&assignment.left, path, ctx);
+            }
+            BindingPatternKind::ArrayPattern(_) => {}
+        }
+    }
+
+    fn check_binding_uses(
+        &self,
+        symbol_id: Option<oxc_semantic::SymbolId>,
+        path: &str,
+        ctx: &LintContext,
+    ) {
+        let Some(symbol_id) = symbol_id else {
+            return;
+        };
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            self.check_uses(ctx.nodes().get_node(reference.node_id()), path.to_string(), ctx);
+        }
+    }
+}
+
+struct Deprecation {
+    since: &'static str,
+    replaced_by: Option<&'static str>,
+}
+
+const fn deprecated(since: &'static str) -> Deprecation {
+    Deprecation { since, replaced_by: None }
+}
+
+const fn replaced(since: &'static str, replaced_by: &'static str) -> Deprecation {
+    Deprecation { since, replaced_by: Some(replaced_by) }
+}
+
+/// Deprecated core modules and their members, keyed by module name and property path.
+/// Calls are keyed as `path()` and `new path()`.
+///
+/// <https://nodejs.org/api/deprecations.html>
+static DEPRECATED_MODULE_ITEMS: phf::Map<&'static str, Deprecation> = phf_map! {
+    "_linklist" => deprecated("5.0.0"),
+    "_stream_wrap" => deprecated("12.0.0"),
+    "async_hooks.currentId" => replaced("8.2.0", "`async_hooks.executionAsyncId()`"),
+    "async_hooks.triggerId" => replaced("8.2.0", "`async_hooks.triggerAsyncId()`"),
+    "buffer.Buffer()" => replaced("6.0.0", "`buffer.Buffer.alloc()` or `buffer.Buffer.from()`"),
+    "new buffer.Buffer()" => replaced("6.0.0", "`buffer.Buffer.alloc()` or `buffer.Buffer.from()`"),
+    "buffer.SlowBuffer" => replaced("6.0.0", "`buffer.Buffer.allocUnsafeSlow()`"),
+    "constants" => replaced("6.3.0", "the `constants` property of each module"),
+    "crypto._toBuf" => deprecated("11.0.0"),
+    "crypto.Credentials" => replaced("0.12.0", "`tls.SecureContext`"),
+    "crypto.DEFAULT_ENCODING" => deprecated("10.0.0"),
+    "crypto.createCipher" => replaced("10.0.0", "`crypto.createCipheriv()`"),
+    "crypto.createCredentials" => replaced("0.12.0", "`tls.createSecureContext()`"),
+    "crypto.createDecipher" => replaced("10.0.0", "`crypto.createDecipheriv()`"),
+    "crypto.fips" => replaced("10.0.0", "`crypto.getFips()` and `crypto.setFips()`"),
+    "crypto.prng" => replaced("11.0.0", "`crypto.randomBytes()`"),
+    "crypto.pseudoRandomBytes" => replaced("11.0.0", "`crypto.randomBytes()`"),
+    "crypto.rng" => replaced("11.0.0", "`crypto.randomBytes()`"),
+    "domain" => deprecated("4.0.0"),
+    "events.EventEmitter.listenerCount" => replaced("4.0.0", "`events.EventEmitter#listenerCount()`"),
+    "events.listenerCount" => replaced("4.0.0", "`events.EventEmitter#listenerCount()`"),
+    "freelist" => deprecated("4.0.0"),
+    "fs.SyncWriteStream" => deprecated("4.0.0"),
+    "fs.exists" => replaced("4.0.0", "`fs.stat()` or `fs.access()`"),
+    "fs.lchmod" => deprecated("0.4.0"),
+    "fs.lchmodSync" => deprecated("0.4.0"),
+    "http.createClient" => replaced("0.10.0", "`http.request()`"),
+    "module.Module.createRequireFromPath" => replaced("12.2.0", "`module.createRequire()`"),
+    "module.Module.requireRepl" => replaced("6.0.0", "`require(\"repl\")`"),
+    "module.Module._debug" => deprecated("9.0.0"),
+    "module.createRequireFromPath" => replaced("12.2.0", "`module.createRequire()`"),
+    "module.requireRepl" => replaced("6.0.0", "`require(\"repl\")`"),
+    "module._debug" => deprecated("9.0.0"),
+    "net._setSimultaneousAccepts" => deprecated("12.0.0"),
+    "os.getNetworkInterfaces" => replaced("0.6.0", "`os.networkInterfaces()`"),
+    "os.tmpDir" => replaced("7.0.0", "`os.tmpdir()`"),
+    "path._makeLong" => replaced("9.0.0", "`path.toNamespacedPath()`"),
+    "process.EventEmitter" => replaced("0.6.0", "`require(\"events\")`"),
+    "process.assert" => replaced("10.0.0", "`require(\"assert\")`"),
+    "process.binding" => deprecated("10.12.0"),
+    "punycode" => replaced("7.0.0", "the `punycode` package from npm"),
+    "readline.codePointAt" => deprecated("4.0.0"),
+    "readline.getStringWidth" => deprecated("6.0.0"),
+    "readline.isFullWidthCodePoint" => deprecated("6.0.0"),
+    "readline.stripVTControlCharacters" => deprecated("6.0.0"),
+    "repl.REPL_MODE_MAGIC" => deprecated("8.0.0"),
+    "sys" => replaced("0.3.0", "the `util` module"),
+    "timers.enroll" => replaced("10.0.0", "`setTimeout()` or `setInterval()`"),
+    "timers.unenroll" => replaced("10.0.0", "`clearTimeout()` or `clearInterval()`"),
+    "tls.CleartextStream" => deprecated("0.10.0"),
+    "tls.CryptoStream" => replaced("0.12.0", "`tls.TLSSocket`"),
+    "tls.SecurePair" => replaced("6.0.0", "`tls.TLSSocket`"),
+    "tls.convertNPNProtocols" => deprecated("10.0.0"),
+    "tls.createSecurePair" => replaced("6.0.0", "`tls.TLSSocket`"),
+    "tls.parseCertString" => replaced("8.6.0", "`querystring.parse()`"),
+    "tty.setRawMode" => replaced("0.10.0", "`tty.ReadStream#setRawMode()`"),
+    "url.parse" => replaced("11.0.0", "the `url.URL` constructor"),
+    "url.resolve" => replaced("11.0.0", "the `url.URL` constructor"),
+    "util._extend" => replaced("6.0.0", "`Object.assign()`"),
+    "util.debug" => replaced("0.12.0", "`console.error()`"),
+    "util.error" => replaced("0.12.0", "`console.error()`"),
+    "util.isArray" => replaced("4.0.0", "`Array.isArray()`"),
+    "util.isBoolean" => deprecated("4.0.0"),
+    "util.isBuffer" => replaced("4.0.0", "`Buffer.isBuffer()`"),
+    "util.isDate" => deprecated("4.0.0"),
+    "util.isError" => deprecated("4.0.0"),
+    "util.isFunction" => deprecated("4.0.0"),
+    "util.isNull" => deprecated("4.0.0"),
+    "util.isNullOrUndefined" => deprecated("4.0.0"),
+    "util.isNumber" => deprecated("4.0.0"),
+    "util.isObject" => deprecated("4.0.0"),
+    "util.isPrimitive" => deprecated("4.0.0"),
+    "util.isRegExp" => deprecated("4.0.0"),
+    "util.isString" => deprecated("4.0.0"),
+    "util.isSymbol" => deprecated("4.0.0"),
+    "util.isUndefined" => deprecated("4.0.0"),
+    "util.log" => deprecated("6.0.0"),
+    "util.print" => replaced("0.12.0", "`console.log()`"),
+    "util.pump" => replaced("0.10.0", "`stream.Readable#pipe()`"),
+    "util.puts" => replaced("0.12.0", "`console.log()`"),
+    "vm.runInDebugContext" => deprecated("8.0.0"),
+};
+
+/// Deprecated globals and their members, keyed like [`DEPRECATED_MODULE_ITEMS`].
+static DEPRECATED_GLOBAL_ITEMS: phf::Map<&'static str, Deprecation> = phf_map! {
+    "Buffer()" => replaced("6.0.0", "`Buffer.alloc()` or `Buffer.from()`"),
+    "new Buffer()" => replaced("6.0.0", "`Buffer.alloc()` or `Buffer.from()`"),
+    "COUNTER_NET_SERVER_CONNECTION" => deprecated("11.0.0"),
+    "COUNTER_NET_SERVER_CONNECTION_CLOSE" => deprecated("11.0.0"),
+    "COUNTER_HTTP_SERVER_REQUEST" => deprecated("11.0.0"),
+    "COUNTER_HTTP_SERVER_RESPONSE" => deprecated("11.0.0"),
+    "COUNTER_HTTP_CLIENT_REQUEST" => deprecated("11.0.0"),
+    "COUNTER_HTTP_CLIENT_RESPONSE" => deprecated("11.0.0"),
+    "GLOBAL" => replaced("6.0.0", "`global`"),
+    "Intl.v8BreakIterator" => deprecated("7.0.0"),
+    "process.EventEmitter" => replaced("0.6.0", "`require(\"events\")`"),
+    "process.assert" => replaced("10.0.0", "`require(\"assert\")`"),
+    "process.binding" => deprecated("10.12.0"),
+    "require.extensions" => deprecated("0.12.0"),
+    "root" => replaced("6.0.0", "`global`"),
+};
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("require('fs').stat('a', () => {})", None),
+        ("const fs = require('fs'); fs.stat('a', () => {})", None),
+        ("const { stat } = require('node:fs')", None),
+        ("import fs from 'fs'; fs.access('a')", None),
+        ("import { stat } from 'fs'", None),
+        ("require('punycode/')", None),
+        ("import punycode from 'punycode/'", None),
+        ("require('./domain')", None),
+        ("require('fs-extra').exists('a')", None),
+        ("const fs = require('fs-extra'); fs.exists('a')", None),
+        ("function f(require) { require('fs').exists('a') }", None),
+        ("const fs = require('fs'); function f(fs) { fs.exists('a') }", None),
+        ("Buffer.alloc(10)", None),
+        ("Buffer.from('abc')", None),
+        ("let Buffer; new Buffer(10)", None),
+        ("function f(Buffer) { Buffer(10) }", None),
+        ("const buffer = require('buffer'); buffer.Buffer.alloc(10)", None),
+        ("require.resolve('a')", None),
+        ("process.env.FOO", None),
+        ("foo.root", None),
+        ("const fs = require('fs'); fs[exists]('a')", None),
+        ("import type { exists } from 'fs'", None),
+        ("fs.exists('a')", None),
+        (
+            "require('fs').exists('a')",
+            Some(serde_json::json!([{ "ignoreModuleItems": ["fs.exists"] }])),
+        ),
+        ("require('punycode')", Some(serde_json::json!([{ "ignoreModuleItems": ["punycode"] }]))),
+        ("new Buffer(10)", Some(serde_json::json!([{ "ignoreGlobalItems": ["new Buffer()"] }]))),
+    ];
+
+    let fail = vec![
+        ("require('fs').exists('a', () => {})", None),
+        ("require('node:fs').exists('a', () => {})", None),
+        ("(require('fs')).exists('a')", None),
+        ("require('fs')['exists']('a')", None),
+        ("const fs = require('fs'); fs.exists('a', () => {})", None),
+        ("const fs = require('fs'), exists = fs.exists", None),
+        ("const { exists } = require('fs')", None),
+        ("const { exists: fileExists } = require('node:fs')", None),
+        ("const { EventEmitter: { listenerCount } } = require('events')", None),
+        ("const { EventEmitter } = require('events'); EventEmitter.listenerCount(a, 'b')", None),
+        ("import fs from 'fs'; fs.exists('a')", None),
+        ("import * as fs from 'node:fs'; fs.exists('a')", None),
+        ("import { exists } from 'fs'", None),
+        ("import { parse } from 'url'", None),
+        ("import { Buffer } from 'buffer'; new Buffer(10)", None),
+        ("const { Buffer } = require('buffer'); Buffer(10)", None),
+        ("new (require('buffer').Buffer)(10)", None),
+        ("require('buffer').SlowBuffer", None),
+        ("require('punycode')", None),
+        ("import punycode from 'punycode'", None),
+        ("require('node:domain')", None),
+        ("require('util').isArray(a)", None),
+        ("require('util')._extend(a, b)", None),
+        ("const util = require('util'); if (util.isString(a)) {}", None),
+        ("require('crypto').createCipher('aes192', 'a')", None),
+        ("require('module').createRequireFromPath('a')", None),
+        ("new Buffer(10)", None),
+        ("Buffer(10)", None),
+        ("new (Buffer)(10)", None),
+        ("GLOBAL.foo", None),
+        ("root.foo", None),
+        ("process.binding('fs')", None),
+        ("require.extensions['.txt'] = () => {}", None),
+        ("Intl.v8BreakIterator", None),
+        ("COUNTER_NET_SERVER_CONNECTION(a)", None),
+        (
+            "require('fs').exists('a'); require('url').parse('a')",
+            Some(serde_json::json!([{ "ignoreModuleItems": ["fs.exists"] }])),
+        ),
+        (
+            "new Buffer(10); Buffer(10)",
+            Some(serde_json::json!([{ "ignoreGlobalItems": ["Buffer()"] }])),
+        ),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, pass, fail).test_and_snapshot();
+}
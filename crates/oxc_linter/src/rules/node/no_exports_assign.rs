@@ -73,7 +73,8 @@ declare_oxc_lint!(
     /// ```
     NoExportsAssign,
     style,
-    fix
+    fix,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for NoExportsAssign {
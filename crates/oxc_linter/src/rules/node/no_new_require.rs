@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// var appHeader = new AppHeader();
     /// ```
     NoNewRequire,
-    restriction
+    restriction,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewRequire {
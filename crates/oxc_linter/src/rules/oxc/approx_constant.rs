@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// let log10e = Math.LOG10E
     /// ```
     ApproxConstant,
-    suspicious
+    suspicious,
+    node_types = [NumericLiteral]
 );
 
 impl Rule for ApproxConstant {
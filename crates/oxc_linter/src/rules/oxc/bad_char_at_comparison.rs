@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// a.charAt(4) === '\n';
     /// ```
     BadCharAtComparison,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for BadCharAtComparison {
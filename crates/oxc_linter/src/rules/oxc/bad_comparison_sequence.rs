@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     BadComparisonSequence,
-    correctness
+    correctness,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for BadComparisonSequence {
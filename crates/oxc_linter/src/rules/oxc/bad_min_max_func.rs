@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// Math.max(1000, Math.min(0, z));
     /// ```
     BadMinMaxFunc,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for BadMinMaxFunc {
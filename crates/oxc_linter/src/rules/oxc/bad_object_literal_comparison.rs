@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// if (Array.isArray(x) && x.length === 0) { }
     /// ```
     BadObjectLiteralComparison,
-    correctness
+    correctness,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for BadObjectLiteralComparison {
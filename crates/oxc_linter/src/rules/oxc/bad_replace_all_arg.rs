@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// withSpaces.replaceAll(/\s+/g, ',');
     /// ```
     BadReplaceAllArg,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for BadReplaceAllArg {
@@ -69,7 +69,8 @@ declare_oxc_lint!(
     /// 500 <= status_code && status_code <= 600;
     /// ```
     ConstComparisons,
-    correctness
+    correctness,
+    node_types = [LogicalExpression]
 );
 
 impl Rule for ConstComparisons {
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     DoubleComparisons,
     correctness,
-    fix
+    fix,
+    node_types = [LogicalExpression]
 );
 
 #[allow(clippy::similar_names)]
@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     ErasingOp,
     correctness,
-    suggestion
+    suggestion,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for ErasingOp {
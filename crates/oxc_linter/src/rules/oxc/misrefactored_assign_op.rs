@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// ```
     MisrefactoredAssignOp,
     suspicious,
-    pending
+    pending,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for MisrefactoredAssignOp {
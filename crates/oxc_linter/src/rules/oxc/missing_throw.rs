@@ -26,7 +26,8 @@ declare_oxc_lint!(
     /// ```
     MissingThrow,
     correctness,
-    suggestion
+    suggestion,
+    node_types = [NewExpression]
 );
 
 impl Rule for MissingThrow {
@@ -215,36 +215,16 @@ fn check_loop_usage<'a>(
         return;
     };
 
-    let Some(write_reference) =
-        ctx.semantic().symbol_references(referenced_symbol_id).find(|r| r.is_write())
+    // The accumulator may be written to several times (e.g. reset before the
+    // loop), so look for the write whose assigned value contains this spread.
+    let Some(assignment_expression_right_inner_expr) =
+        ctx.semantic().symbol_references(referenced_symbol_id).filter(|r| r.is_write()).find_map(
+            |write_reference| get_reassigned_value(write_reference.node_id(), spread_span, ctx),
+        )
     else {
         return;
     };
 
-    let Some(assignment_target) = ctx.nodes().parent_node(write_reference.node_id()) else {
-        return;
-    };
-
-    let AstKind::SimpleAssignmentTarget(_) = assignment_target.kind() else { return };
-
-    let Some(assignment_expr) = ctx.nodes().parent_node(assignment_target.id()) else { return };
-    if !matches!(assignment_expr.kind(), AstKind::AssignmentTarget(_)) {
-        return;
-    }
-    let Some(assignment) = ctx.nodes().parent_node(assignment_expr.id()) else { return };
-    let AstKind::AssignmentExpression(assignment_expression) = assignment.kind() else {
-        return;
-    };
-
-    let assignment_expression_right_inner_expr = assignment_expression.right.get_inner_expression();
-    match assignment_expression_right_inner_expr {
-        Expression::ArrayExpression(array_expr)
-            if array_expr.span.contains_inclusive(spread_span) => {}
-        Expression::ObjectExpression(object_expr)
-            if object_expr.span.contains_inclusive(spread_span) => {}
-        _ => return,
-    }
-
     for parent in ctx.nodes().iter_parents(spread_node_id) {
         if let Some(loop_span) = get_loop_span(parent.kind()) {
             if !parent.kind().span().contains_inclusive(declaration.span)
@@ -273,6 +253,31 @@ fn check_loop_usage<'a>(
     }
 }
 
+/// Given the node of a write reference to the accumulator, returns the
+/// array/object literal assigned to it if that literal contains the spread.
+fn get_reassigned_value<'a>(
+    write_reference_node_id: NodeId,
+    spread_span: Span,
+    ctx: &LintContext<'a>,
+) -> Option<&'a Expression<'a>> {
+    let assignment_target = ctx.nodes().parent_node(write_reference_node_id)?;
+    let AstKind::SimpleAssignmentTarget(_) = assignment_target.kind() else { return None };
+
+    let assignment_expr = ctx.nodes().parent_node(assignment_target.id())?;
+    if !matches!(assignment_expr.kind(), AstKind::AssignmentTarget(_)) {
+        return None;
+    }
+    let assignment = ctx.nodes().parent_node(assignment_expr.id())?;
+    let AstKind::AssignmentExpression(assignment_expression) = assignment.kind() else {
+        return None;
+    };
+
+    let right = assignment_expression.right.get_inner_expression();
+    let is_literal =
+        matches!(right, Expression::ArrayExpression(_) | Expression::ObjectExpression(_));
+    (is_literal && right.span().contains_inclusive(spread_span)).then_some(right)
+}
+
 fn get_loop_span(ast_kind: AstKind) -> Option<Span> {
     match ast_kind {
         AstKind::ForStatement(ForStatement { span, .. })
@@ -461,6 +466,9 @@ fn test() {
         "let foo = {}; for (let i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; for (const i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; while (Object.keys(foo).length < 10) { foo = { ...foo, [Object.keys(foo).length]: Object.keys(foo).length }; }",
+        // accumulator written to before the loop
+        "let foo = []; foo = []; for (let i = 0; i < 10; i++) { foo = [...foo, i]; }",
+        "let foo = {}; foo = {}; for (const i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
     ];
 
     Tester::new(NoAccumulatingSpread::NAME, pass, fail).test_and_snapshot();
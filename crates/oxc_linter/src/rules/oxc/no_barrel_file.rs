@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// export { foo } from 'foo';
     /// ```
     NoBarrelFile,
-    restriction
+    restriction,
+    node_types = []
 );
 
 impl Rule for NoBarrelFile {
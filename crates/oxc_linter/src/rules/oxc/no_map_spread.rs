@@ -420,7 +420,7 @@ impl NoMapSpread {
         }
 
         if self.ignore_args {
-            let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+            let declaration = ctx.symbol_declaration(symbol_id);
             if matches!(declaration.kind(), AstKind::FormalParameter(_)) {
                 return true;
             }
@@ -695,8 +695,7 @@ where
                 }
 
                 // walk the declaration
-                let declaration_node =
-                    self.ctx.nodes().get_node(self.ctx.symbols().get_declaration(symbol_id));
+                let declaration_node = self.ctx.symbol_declaration(symbol_id);
                 self.visit_kind(declaration_node.kind());
             }
             _ => {}
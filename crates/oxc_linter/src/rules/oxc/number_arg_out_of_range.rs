@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// var s = x.toString(1);
     /// ```
     NumberArgOutOfRange,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for NumberArgOutOfRange {
@@ -254,9 +254,9 @@ fn is_function_maybe_reassigned<'a>(
 // skipping whitespace, commas, finds the next character (exclusive)
 #[allow(clippy::cast_possible_truncation)]
 fn skip_to_next_char(s: &str, start: u32) -> u32 {
-    for (i, c) in s.char_indices().skip(start as usize) {
+    for (i, c) in s[start as usize..].char_indices() {
         if !c.is_whitespace() && c != ',' {
-            return i as u32;
+            return start + i as u32;
         }
     }
 
@@ -452,6 +452,10 @@ function writeChunks(a,callac){writeChunks(m,callac)}writeChunks(i,{})",
             }
             "#,
         ),
+        (
+            "//¿\nfunction writeChunks(a,callac){writeChunks(m,callac)}writeChunks(i,{})",
+            "//¿\nfunction writeChunks(a,){writeChunks(m,)}writeChunks(i,)",
+        ),
         // Expecting no fix: function is exported
         (
             r"export function test(a) {
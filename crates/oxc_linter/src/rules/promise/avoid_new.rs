@@ -40,6 +40,7 @@ declare_oxc_lint!(
     /// ```
     AvoidNew,
     style,
+    node_types = [NewExpression]
 );
 
 impl Rule for AvoidNew {
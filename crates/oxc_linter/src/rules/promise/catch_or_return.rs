@@ -77,6 +77,7 @@ declare_oxc_lint!(
     /// ```
     CatchOrReturn,
     restriction,
+    node_types = [ExpressionStatement]
 );
 
 impl Rule for CatchOrReturn {
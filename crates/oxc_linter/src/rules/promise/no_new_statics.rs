@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// ```
     NoNewStatics,
     correctness,
-    fix
+    fix,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewStatics {
@@ -43,6 +43,7 @@ declare_oxc_lint!(
     /// ```
     NoReturnInFinally,
     nursery,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoReturnInFinally {
@@ -64,6 +64,7 @@ declare_oxc_lint!(
     /// ```
     ParamNames,
     style,
+    node_types = [NewExpression]
 );
 
 impl Rule for ParamNames {
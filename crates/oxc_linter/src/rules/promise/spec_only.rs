@@ -49,6 +49,7 @@ declare_oxc_lint!(
     /// ```
     SpecOnly,
     restriction,
+    node_types = [MemberExpression]
 );
 
 impl Rule for SpecOnly {
@@ -64,6 +64,7 @@ declare_oxc_lint!(
     /// ```
     ValidParams,
     correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for ValidParams {
@@ -0,0 +1,486 @@
+use cow_utils::CowUtils;
+use oxc_ast::{
+    ast::{ArrayPattern, BindingPattern, BindingPatternKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+    utils::{get_use_state_pattern, is_react_function_call, is_transparent},
+    AstNode,
+};
+
+fn hook_use_state_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("useState call is not destructured into value + setter pair")
+        .with_help("Destructure the state into a value and a setter named after it, e.g. `const [color, setColor] = useState()`")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HookUseState(Box<HookUseStateConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct HookUseStateConfig {
+    allow_destructured_state: bool,
+}
+
+impl std::ops::Deref for HookUseState {
+    type Target = HookUseStateConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensure destructuring and symmetric naming of `useState` hook value and
+    /// setter variables.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Naming the setter after the state it updates, as in `[color, setColor]`,
+    /// is the convention used across the React ecosystem. Following it makes
+    /// components easier to read.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// const [color, updateColor] = useState();
+    /// const colorState = useState();
+    /// const [color] = useState();
+    /// const [{ r, g, b }, setColor] = useState();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// const [color, setColor] = useState();
+    /// const [htmlContent, setHTMLContent] = React.useState();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// With `allowDestructuredState`, the state value may be destructured:
+    /// ```json
+    /// { "react/hook-use-state": ["error", { "allowDestructuredState": true }] }
+    /// ```
+    /// ```jsx
+    /// const [{ r, g, b }, setColor] = useState();
+    /// ```
+    HookUseState,
+    style,
+    suggestion
+);
+
+impl Rule for HookUseState {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(HookUseStateConfig {
+            allow_destructured_state: value
+                .get(0)
+                .and_then(|v| v.get("allowDestructuredState"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        if !is_react_function_call(call, "useState") {
+            return;
+        }
+
+        let pattern = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .find(|parent| !is_transparent(parent.kind()))
+            .and_then(|parent| match parent.kind() {
+                AstKind::VariableDeclarator(decl) => get_use_state_pattern(decl),
+                _ => None,
+            });
+        let Some(pattern) = pattern else {
+            ctx.diagnostic(hook_use_state_diagnostic(call.span));
+            return;
+        };
+
+        let value = pattern.elements.first().and_then(Option::as_ref);
+        let setter = pattern.elements.get(1).and_then(Option::as_ref);
+        if self.allow_destructured_state
+            && value.is_some_and(is_destructuring)
+            && !setter.is_some_and(is_destructuring)
+        {
+            return;
+        }
+
+        let expected = value
+            .and_then(BindingPattern::get_identifier)
+            .map(|value| expected_setter_names(&value))
+            .unwrap_or_default();
+        let setter_name = setter.and_then(BindingPattern::get_identifier);
+        if pattern.elements.len() == 2
+            && pattern.rest.is_none()
+            && setter_name.is_some_and(|name| expected.iter().any(|it| it == name.as_str()))
+        {
+            return;
+        }
+
+        let diagnostic = hook_use_state_diagnostic(pattern.span);
+        match (expected.first(), setter) {
+            (Some(new_name), Some(setter)) if can_rename(pattern, setter, new_name, node, ctx) => {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    rename_setter(setter, new_name, fixer, ctx)
+                });
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Get the setter names accepted for a state `value`, e.g. `setHtmlContent` and
+/// `setHTMLContent` for `htmlContent`.
+fn expected_setter_names(value: &str) -> Vec<String> {
+    let prefix_len = value.find(|c: char| !c.is_ascii_lowercase()).unwrap_or(value.len());
+    if prefix_len == 0 {
+        return vec![];
+    }
+    let (prefix, suffix) = value.split_at(prefix_len);
+    let mut capitalized = prefix.to_string();
+    capitalized[..1].make_ascii_uppercase();
+    vec![
+        format!("set{capitalized}{suffix}"),
+        format!("set{}{suffix}", prefix.cow_to_ascii_uppercase()),
+    ]
+}
+
+fn is_destructuring(pattern: &BindingPattern) -> bool {
+    matches!(
+        pattern.kind,
+        BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_)
+    )
+}
+
+/// The setter can be renamed if the pattern is exactly `[value, setter]` and the
+/// new name isn't already taken.
+fn can_rename(
+    pattern: &ArrayPattern,
+    setter: &BindingPattern,
+    new_name: &str,
+    node: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    pattern.elements.len() == 2
+        && pattern.rest.is_none()
+        && matches!(setter.kind, BindingPatternKind::BindingIdentifier(_))
+        && ctx.scopes().find_binding(node.scope_id(), new_name).is_none()
+}
+
+/// Rename the setter and all of its references to `new_name`.
+fn rename_setter<'a>(
+    setter: &BindingPattern<'a>,
+    new_name: &str,
+    fixer: RuleFixer<'_, 'a>,
+    ctx: &LintContext<'a>,
+) -> RuleFix<'a> {
+    let BindingPatternKind::BindingIdentifier(ident) = &setter.kind else {
+        return fixer.noop();
+    };
+    let fixer = fixer.for_multifix();
+    let mut fix = fixer.new_fix_with_capacity(1);
+    fix.push(fixer.replace(ident.span, new_name.to_string()));
+    if let Some(symbol_id) = ident.symbol_id.get() {
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            let node = ctx.nodes().get_node(reference.node_id());
+            let span = node.kind().span();
+            // `{ updateColor }` must become `{ updateColor: setColor }`
+            let replacement = match ctx.nodes().parent_kind(node.id()) {
+                Some(AstKind::ObjectProperty(prop)) if prop.shorthand => {
+                    format!("{}: {new_name}", ident.name)
+                }
+                _ => new_name.to_string(),
+            };
+            fix.push(fixer.replace(span, replacement));
+        }
+    }
+    fix.with_message(format!("Rename `{}` to `{new_name}`", ident.name))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color, setColor] = useState();
+              return [color, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import React from 'react';
+            function useColor() {
+              const [color, setColor] = React.useState();
+              return [color, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useRGB() {
+              const [rgb, setRGB] = useState();
+              return [rgb, setRGB];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useHtmlContent() {
+              const [htmlContent, setHtmlContent] = useState();
+              const [htmlString, setHTMLString] = useState();
+              return [htmlContent, setHtmlContent, htmlString, setHTMLString];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color, setColor] = (useState());
+              return [color, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [{ r, g, b }, setColor] = useState();
+              return [r, g, b, setColor];
+            }
+            ",
+            Some(serde_json::json!([{ "allowDestructuredState": true }])),
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [[r, g, b], setColor] = useState();
+              return [r, g, b, setColor];
+            }
+            ",
+            Some(serde_json::json!([{ "allowDestructuredState": true }])),
+        ),
+        (
+            r"
+            import { useState } from 'not-react';
+            const [color, updateColor] = foo.useState();
+            ",
+            None,
+        ),
+        (r"const [color, updateColor] = useColorState();", None),
+    ];
+
+    let fail = vec![
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const result = useState();
+              return result;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              return useState();
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color, updateColor] = useState();
+              return [color, updateColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import React from 'react';
+            function useColor() {
+              const [color, updateColor] = React.useState();
+              return { color, updateColor };
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color] = useState();
+              return color;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [, setColor] = useState();
+              return setColor;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color, setColor, extra] = useState();
+              return [color, setColor, extra];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [color, ...rest] = useState();
+              return [color, rest];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [Color, setColor] = useState();
+              return [Color, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [{ r, g, b }, setColor] = useState();
+              return [r, g, b, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor() {
+              const [{ r, g, b }, { setColor }] = useState();
+              return [r, g, b, setColor];
+            }
+            ",
+            Some(serde_json::json!([{ "allowDestructuredState": true }])),
+        ),
+        (
+            r"
+            import { useState } from 'react';
+            function useColor(setColor) {
+              const [color, updateColor] = useState();
+              return [color, updateColor, setColor];
+            }
+            ",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            r"
+            function useColor() {
+              const [color, updateColor] = useState();
+              return [color, updateColor];
+            }
+            ",
+            r"
+            function useColor() {
+              const [color, setColor] = useState();
+              return [color, setColor];
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function useColor() {
+              const [color, updateColor] = React.useState();
+              return { color, updateColor };
+            }
+            ",
+            r"
+            function useColor() {
+              const [color, setColor] = React.useState();
+              return { color, updateColor: setColor };
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function Foo() {
+              const [htmlContent, update] = useState();
+              return <input value={htmlContent} onChange={(e) => update(e.target.value)} />;
+            }
+            ",
+            r"
+            function Foo() {
+              const [htmlContent, setHtmlContent] = useState();
+              return <input value={htmlContent} onChange={(e) => setHtmlContent(e.target.value)} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function useColor(setColor) {
+              const [color, updateColor] = useState();
+              return [color, updateColor, setColor];
+            }
+            ",
+            r"
+            function useColor(setColor) {
+              const [color, updateColor] = useState();
+              return [color, updateColor, setColor];
+            }
+            ",
+            None,
+        ),
+    ];
+
+    Tester::new(HookUseState::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
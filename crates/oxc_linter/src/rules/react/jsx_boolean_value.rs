@@ -66,6 +66,7 @@ declare_oxc_lint!(
     JsxBooleanValue,
     style,
     fix,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for JsxBooleanValue {
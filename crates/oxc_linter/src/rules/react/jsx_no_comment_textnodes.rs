@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     JsxNoCommentTextnodes,
-    suspicious
+    suspicious,
+    node_types = [JSXText]
 );
 
 impl Rule for JsxNoCommentTextnodes {
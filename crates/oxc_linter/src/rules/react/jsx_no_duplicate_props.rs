@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// <App bar baz foo={3} />;
     /// ```
     JsxNoDuplicateProps,
-    correctness
+    correctness,
+    node_types = [JSXOpeningElement]
 );
 
 impl Rule for JsxNoDuplicateProps {
@@ -3,7 +3,7 @@ use std::ops::Deref;
 use oxc_ast::{
     ast::{
         match_expression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXExpression, StringLiteral,
+        JSXExpression, JSXOpeningElement, StringLiteral,
     },
     AstKind,
 };
@@ -13,6 +13,7 @@ use oxc_span::{CompactStr, GetSpan, Span};
 
 use crate::{
     context::{ContextHost, LintContext},
+    fixer::{RuleFix, RuleFixer},
     rule::Rule,
     AstNode,
 };
@@ -63,12 +64,14 @@ impl Default for JsxNoTargetBlank {
 }
 
 impl JsxNoTargetBlank {
-    fn diagnostic(&self, span: Span, ctx: &LintContext) {
-        if self.allow_referrer {
-            ctx.diagnostic(target_blank_without_noopener(span));
+    fn diagnostic<'a>(&self, span: Span, jsx_ele: &JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
+        let diagnostic = if self.allow_referrer {
+            target_blank_without_noopener(span)
         } else {
-            ctx.diagnostic(target_blank_without_noreferrer(span));
-        }
+            target_blank_without_noreferrer(span)
+        };
+        let rel = if self.allow_referrer { "noopener" } else { "noreferrer" };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fix_rel(jsx_ele, rel, fixer));
     }
 
     fn check_is_link(&self, tag_name: &str, ctx: &LintContext) -> bool {
@@ -122,7 +125,8 @@ declare_oxc_lint!(
     /// [`noreferrer` docs]: https://html.spec.whatwg.org/multipage/links.html#link-type-noreferrer
     /// [`noopener` docs]: https://html.spec.whatwg.org/multipage/links.html#link-type-noopener
     JsxNoTargetBlank,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for JsxNoTargetBlank {
@@ -202,13 +206,13 @@ impl Rule for JsxNoTargetBlank {
                         if (target_blank_tuple.2 && !rel_valid_tuple.2)
                             || (target_blank_tuple.3 && !rel_valid_tuple.3)
                         {
-                            self.diagnostic(span, ctx);
+                            self.diagnostic(span, jsx_ele, ctx);
                         }
                         return;
                     }
 
                     if target_blank_tuple.0 && !rel_valid_tuple.0 {
-                        self.diagnostic(span, ctx);
+                        self.diagnostic(span, jsx_ele, ctx);
                     }
                 }
             }
@@ -250,6 +254,68 @@ impl Rule for JsxNoTargetBlank {
     }
 }
 
+/// Adds `rel` to the `rel` attribute of `jsx_ele`, creating it if needed. Nothing is
+/// fixed when a spread attribute could override the result.
+fn fix_rel<'a>(
+    jsx_ele: &JSXOpeningElement<'a>,
+    rel: &str,
+    fixer: RuleFixer<'_, 'a>,
+) -> RuleFix<'a> {
+    let position = |name: &str| {
+        jsx_ele.attributes.iter().position(|attribute| match attribute {
+            JSXAttributeItem::Attribute(attribute) => attribute.is_identifier(name),
+            JSXAttributeItem::SpreadAttribute(_) => false,
+        })
+    };
+    let last_spread = jsx_ele
+        .attributes
+        .iter()
+        .rposition(|attribute| matches!(attribute, JSXAttributeItem::SpreadAttribute(_)));
+    let rel_index = position("rel");
+    if let Some(last_spread) = last_spread {
+        if rel_index.is_none() || position("target").is_some_and(|target| target < last_spread) {
+            return fixer.noop();
+        }
+    }
+
+    let Some(JSXAttributeItem::Attribute(rel_attribute)) =
+        rel_index.map(|index| &jsx_ele.attributes[index])
+    else {
+        let last = jsx_ele.attributes.last().map_or(jsx_ele.name.span(), GetSpan::span);
+        return fixer.insert_text_after_range(last, format!(" rel=\"{rel}\""));
+    };
+    let with_rel = |value: &str| {
+        let mut parts = value
+            .split_ascii_whitespace()
+            .filter(|part| !part.eq_ignore_ascii_case(rel))
+            .collect::<Vec<_>>();
+        parts.push(rel);
+        format!("\"{}\"", parts.join(" "))
+    };
+    match &rel_attribute.value {
+        None => fixer.insert_text_after_range(rel_attribute.span, format!("=\"{rel}\"")),
+        Some(JSXAttributeValue::StringLiteral(value)) => {
+            fixer.replace(value.span, with_rel(&value.value))
+        }
+        Some(JSXAttributeValue::ExpressionContainer(container)) => match &container.expression {
+            JSXExpression::StringLiteral(value) => {
+                fixer.replace(value.span, with_rel(&value.value))
+            }
+            JSXExpression::NullLiteral(_)
+            | JSXExpression::BooleanLiteral(_)
+            | JSXExpression::NumericLiteral(_)
+            | JSXExpression::BigIntLiteral(_) => {
+                fixer.replace(container.span, format!("\"{rel}\""))
+            }
+            JSXExpression::Identifier(ident) if ident.name == "undefined" => {
+                fixer.replace(container.span, format!("\"{rel}\""))
+            }
+            _ => fixer.noop(),
+        },
+        Some(_) => fixer.noop(),
+    }
+}
+
 fn check_is_external_link(link: &str) -> bool {
     link.contains("//")
 }
@@ -795,5 +861,83 @@ fn test() {
         ),
     ];
 
-    Tester::new(JsxNoTargetBlank::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            r#"<a target="_blank" href="https://example.com/1"></a>"#,
+            r#"<a target="_blank" href="https://example.com/1" rel="noreferrer"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="" href="https://example.com/2"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/2"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={0} href="https://example.com/3"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/3"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={null} href="https://example.com/4"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/4"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={undefined} href="https://example.com/5"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/5"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="noopenernoreferrer" href="https://example.com/6"></a>"#,
+            r#"<a target="_blank" rel="noopenernoreferrer noreferrer" href="https://example.com/6"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="noopener" href="https://example.com/7"></a>"#,
+            r#"<a target="_blank" rel="noopener noreferrer" href="https://example.com/7"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={"noopener"} href="https://example.com/8"></a>"#,
+            r#"<a target="_blank" rel={"noopener noreferrer"} href="https://example.com/8"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel href="https://example.com/9"></a>"#,
+            r#"<a target="_blank" rel="noreferrer" href="https://example.com/9"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" href={ dynamicLink }></a>"#,
+            r#"<a target="_blank" href={ dynamicLink } rel="noreferrer"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" href="https://example.com" {...spreadProps}></a>"#,
+            r#"<a target="_blank" href="https://example.com" {...spreadProps}></a>"#,
+            None,
+        ),
+        (
+            r#"<a href="https://example.com" {...spreadProps} target="_blank" rel="noopener"></a>"#,
+            r#"<a href="https://example.com" {...spreadProps} target="_blank" rel="noopener noreferrer"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel={a ? "noopener" : "x"} href="https://example.com"></a>"#,
+            r#"<a target="_blank" rel={a ? "noopener" : "x"} href="https://example.com"></a>"#,
+            None,
+        ),
+        (
+            r#"<a target="_blank" rel="noopenernoreferrer" href="https://example.com"></a>"#,
+            r#"<a target="_blank" rel="noopenernoreferrer noopener" href="https://example.com"></a>"#,
+            Some(serde_json::json!([{ "allowReferrer": true }])),
+        ),
+        (
+            r#"<form method="POST" action="https://example.com" target="_blank"></form>"#,
+            r#"<form method="POST" action="https://example.com" target="_blank" rel="noreferrer"></form>"#,
+            Some(serde_json::json!([{ "forms": true }])),
+        ),
+    ];
+
+    Tester::new(JsxNoTargetBlank::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -12,6 +12,7 @@ use oxc_span::Span;
 
 use crate::{
     context::{ContextHost, LintContext},
+    fixer::RuleFixer,
     rule::Rule,
     AstNode,
 };
@@ -53,7 +54,8 @@ declare_oxc_lint!(
     /// <div>foo</div>
     /// ```
     JsxNoUselessFragment,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for JsxNoUselessFragment {
@@ -70,7 +72,7 @@ impl Rule for JsxNoUselessFragment {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
-                if !is_jsx_fragment(&jsx_elem.opening_element) {
+                if !is_jsx_fragment(&jsx_elem.opening_element, ctx) {
                     return;
                 }
                 self.check_element(node, jsx_elem, ctx);
@@ -88,38 +90,97 @@ impl Rule for JsxNoUselessFragment {
 }
 
 impl JsxNoUselessFragment {
-    fn check_element(&self, node: &AstNode, elem: &JSXElement, ctx: &LintContext) {
+    fn check_element<'a>(&self, node: &AstNode<'a>, elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
         if jsx_elem_has_key_attr(elem) {
             return;
         }
 
-        if has_less_than_two_children(&elem.children)
-            && !is_fragment_with_only_text_and_is_not_child(node.id(), &elem.children, ctx)
-            && !(self.allow_expressions && is_fragment_with_single_expression(&elem.children))
+        let span = elem.opening_element.span;
+        let inner_span = elem
+            .closing_element
+            .as_ref()
+            .map(|closing| Span::new(elem.opening_element.span.end, closing.span.start));
+        self.check(node, elem.span, span, inner_span, &elem.children, ctx);
+    }
+
+    fn check_fragment<'a>(
+        &self,
+        node: &AstNode<'a>,
+        elem: &JSXFragment<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let span = elem.opening_fragment.span;
+        let inner_span =
+            Span::new(elem.opening_fragment.span.end, elem.closing_fragment.span.start);
+        self.check(node, elem.span, span, Some(inner_span), &elem.children, ctx);
+    }
+
+    /// `inner_span` is the span between the opening and closing tags, which is `None`
+    /// for self-closing elements.
+    fn check<'a>(
+        &self,
+        node: &AstNode<'a>,
+        span: Span,
+        opening_span: Span,
+        inner_span: Option<Span>,
+        children: &oxc_allocator::Vec<'a, JSXChild<'a>>,
+        ctx: &LintContext<'a>,
+    ) {
+        let replacement = can_fix(node, children, ctx).then(|| {
+            trim_like_react(inner_span.map_or("", |inner| ctx.source_range(inner))).to_string()
+        });
+        let fix = |fixer: RuleFixer<'_, 'a>| match &replacement {
+            Some(replacement) => fixer.replace(span, replacement.clone()),
+            None => fixer.noop(),
+        };
+
+        if has_less_than_two_children(children)
+            && !is_fragment_with_only_text_and_is_not_child(node.id(), children, ctx)
+            && !(self.allow_expressions && is_fragment_with_single_expression(children))
         {
-            let span = elem.opening_element.span;
-            ctx.diagnostic(needs_more_children(span));
+            ctx.diagnostic_with_fix(needs_more_children(opening_span), fix);
         }
 
         if is_child_of_html_element(node, ctx) {
-            let span = elem.opening_element.span;
-            ctx.diagnostic(child_of_html_element(span));
+            ctx.diagnostic_with_fix(child_of_html_element(opening_span), fix);
         }
     }
+}
 
-    fn check_fragment(&self, node: &AstNode, elem: &JSXFragment, ctx: &LintContext) {
-        if has_less_than_two_children(&elem.children)
-            && !is_fragment_with_only_text_and_is_not_child(node.id(), &elem.children, ctx)
-            && !(self.allow_expressions && is_fragment_with_single_expression(&elem.children))
-        {
-            let span = elem.opening_fragment.span;
-            ctx.diagnostic(needs_more_children(span));
+/// Unwrapping a fragment is only safe when its children end up in a JSX parent that
+/// accepts them. `<Eeee><>foo</></Eeee>` isn't fixed, as `Eeee` may require its children
+/// to be a single element.
+fn can_fix(
+    node: &AstNode,
+    children: &oxc_allocator::Vec<'_, JSXChild<'_>>,
+    ctx: &LintContext,
+) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::JSXElement(parent)) => is_html_element(&parent.opening_element.name),
+        Some(AstKind::JSXFragment(_)) => true,
+        _ => {
+            !children.is_empty()
+                && !children.iter().any(|child| match child {
+                    JSXChild::Text(text) => !text.value.trim().is_empty(),
+                    JSXChild::ExpressionContainer(_) => true,
+                    _ => false,
+                })
         }
+    }
+}
 
-        if is_child_of_html_element(node, ctx) {
-            let span = elem.opening_fragment.span;
-            ctx.diagnostic(child_of_html_element(span));
-        }
+/// Trims the whitespace around `text` the way React does, i.e. only when it contains a
+/// line break.
+fn trim_like_react(text: &str) -> &str {
+    let trimmed_start = text.trim_start();
+    let leading = &text[..text.len() - trimmed_start.len()];
+    let text = if leading.contains('\n') { trimmed_start } else { text };
+    let trimmed_end = text.trim_end();
+    let trailing = &text[trimmed_end.len()..];
+    if trailing.contains('\n') {
+        trimmed_end
+    } else {
+        text
     }
 }
 
@@ -169,12 +230,13 @@ fn is_html_element(elem_name: &JSXElementName) -> bool {
     ident.name.starts_with(char::is_lowercase)
 }
 
-fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
+fn is_jsx_fragment(elem: &JSXOpeningElement, ctx: &LintContext) -> bool {
+    let settings = &ctx.settings().react;
     match &elem.name {
-        JSXElementName::IdentifierReference(ident) => ident.name == "Fragment",
+        JSXElementName::IdentifierReference(ident) => ident.name == settings.fragment(),
         JSXElementName::MemberExpression(mem_expr) => {
             if let JSXMemberExpressionObject::IdentifierReference(ident) = &mem_expr.object {
-                ident.name == "React" && mem_expr.property.name == "Fragment"
+                ident.name == settings.pragma() && mem_expr.property.name == settings.fragment()
             } else {
                 false
             }
@@ -317,5 +379,61 @@ fn test() {
         (r"<><Foo>{moo}</Foo></>", None),
     ];
 
-    Tester::new(JsxNoUselessFragment::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"<></>", r"<></>", None),
+        (r"<>{}</>", r"<>{}</>", None),
+        (r"<p>moo<>foo</></p>", r"<p>moofoo</p>", None),
+        (r"<>{meow}</>", r"<>{meow}</>", None),
+        (r"<p><>{meow}</></p>", r"<p>{meow}</p>", None),
+        (r"<><div/></>", r"<div/>", None),
+        (
+            r"
+            <>
+              <div/>
+            </>
+        ",
+            r"
+            <div/>
+        ",
+            None,
+        ),
+        (r"<Fragment />", r"<Fragment />", None),
+        (
+            r"
+                <React.Fragment>
+                  <Foo />
+                </React.Fragment>
+            ",
+            r"
+                <Foo />
+            ",
+            None,
+        ),
+        (r"<Eeee><>foo</></Eeee>", r"<Eeee><>foo</></Eeee>", None),
+        (r"<div><>foo</></div>", r"<div>foo</div>", None),
+        (r#"<div><>{"a"}{"b"}</></div>"#, r#"<div>{"a"}{"b"}</div>"#, None),
+        (r#"<div><Fragment>{"a"}{"b"}</Fragment></div>"#, r#"<div>{"a"}{"b"}</div>"#, None),
+        (r#"<div>a <>{""}{""}</> a</div>"#, r#"<div>a {""}{""} a</div>"#, None),
+        (
+            r"
+            const Comp = () => (
+              <html>
+                <React.Fragment />
+              </html>
+            );
+        ",
+            r"
+            const Comp = () => (
+              <html>
+                
+              </html>
+            );
+        ",
+            None,
+        ),
+        (r"<><Foo>{moo}</Foo></>", r"<Foo>{moo}</Foo>", None),
+        (r"const a = <><Foo /></>", r"const a = <Foo />", None),
+    ];
+
+    Tester::new(JsxNoUselessFragment::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -0,0 +1,310 @@
+use oxc_ast::{
+    ast::{
+        Argument, CallExpression, Expression, IdentifierReference, JSXAttributeItem,
+        JSXAttributeValue, ObjectPropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_create_element_call,
+    AstNode,
+};
+
+fn no_array_index_key_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not use Array index in keys")
+        .with_help("Use a stable identifier of the item, such as its id, as the key instead")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayIndexKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using the index of an array iteration as the `key` of an element.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React uses keys to identify which items changed, were added or were
+    /// removed. The index of an item changes when the array is reordered or
+    /// items are inserted, so using it as a key leads to unnecessary re-renders
+    /// and issues with component state.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// things.map((thing, index) => <Hello key={index} />);
+    /// things.map((thing, index) => <Hello key={`hello-${index}`} />);
+    /// things.map((thing, index) => React.cloneElement(thing, { key: index }));
+    /// Children.map(children, (child, index) => <Hello key={index.toString()} />);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// things.map((thing) => <Hello key={thing.id} />);
+    /// things.map((thing) => React.cloneElement(thing, { key: thing.id }));
+    /// ```
+    NoArrayIndexKey,
+    perf
+);
+
+const ARRAY_ITERATOR_METHODS: [&str; 12] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "reduce",
+    "reduceRight",
+    "some",
+];
+
+impl Rule for NoArrayIndexKey {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
+                if !attr.is_identifier("key") {
+                    return;
+                }
+                let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+                    return;
+                };
+                if let Some(expr) = container.expression.as_expression() {
+                    check_key_value(expr, ctx);
+                }
+            }
+            AstKind::CallExpression(call_expr) => {
+                if !is_clone_element_call(call_expr) && !is_create_element_call(call_expr) {
+                    return;
+                }
+                let Some(Argument::ObjectExpression(props)) = call_expr.arguments.get(1) else {
+                    return;
+                };
+                for prop in &props.properties {
+                    if let ObjectPropertyKind::ObjectProperty(prop) = prop {
+                        if prop.key.is_specific_static_name("key") {
+                            check_key_value(&prop.value, ctx);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Reports `value` if it is derived from the index parameter of an iterator callback.
+fn check_key_value<'a>(value: &Expression<'a>, ctx: &LintContext<'a>) {
+    if uses_array_index(value, ctx) {
+        ctx.diagnostic(no_array_index_key_diagnostic(value.span()));
+    }
+}
+
+/// `index`, `` `item-${index}` ``, `'item' + index`, `index.toString()` or `String(index)`
+fn uses_array_index<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    let is_index = |expr: &Expression<'a>| match expr.without_parentheses() {
+        Expression::Identifier(ident) => is_array_index(ident, ctx),
+        _ => false,
+    };
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => is_array_index(ident, ctx),
+        Expression::TemplateLiteral(template) => template.expressions.iter().any(is_index),
+        Expression::BinaryExpression(binary) => {
+            uses_array_index(&binary.left, ctx) || uses_array_index(&binary.right, ctx)
+        }
+        Expression::CallExpression(call) => match &call.callee {
+            Expression::StaticMemberExpression(member) => {
+                member.property.name == "toString" && is_index(&member.object)
+            }
+            Expression::Identifier(callee) => {
+                callee.name == "String"
+                    && call
+                        .arguments
+                        .first()
+                        .and_then(Argument::as_expression)
+                        .is_some_and(is_index)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `ident` refers to the index parameter of a callback passed to an array
+/// iterator method such as `.map`, or to `Children.map`/`Children.forEach`.
+fn is_array_index(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+    else {
+        return false;
+    };
+    let declaration = ctx.symbol_declaration(symbol_id);
+    let AstKind::FormalParameter(param) = declaration.kind() else {
+        return false;
+    };
+    if !param
+        .pattern
+        .get_binding_identifier()
+        .is_some_and(|id| id.symbol_id.get() == Some(symbol_id))
+    {
+        return false;
+    }
+
+    let nodes = ctx.nodes();
+    let Some(function) = nodes.ancestors(declaration.id()).skip(1).find(|&id| {
+        matches!(nodes.kind(id), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+    }) else {
+        return false;
+    };
+    let (function_span, params) = match nodes.kind(function) {
+        AstKind::Function(function) => (function.span, &function.params),
+        AstKind::ArrowFunctionExpression(arrow) => (arrow.span, &arrow.params),
+        _ => return false,
+    };
+    let Some(param_index) = params.items.iter().position(|item| item.span == param.span) else {
+        return false;
+    };
+
+    let Some(AstKind::CallExpression(call)) =
+        nodes.ancestors(function).skip(1).map(|id| nodes.kind(id)).find(|kind| {
+            !matches!(kind, AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+        })
+    else {
+        return false;
+    };
+    let Some(member) = call.callee.get_member_expr() else {
+        return false;
+    };
+    let Some(method) = member.static_property_name() else {
+        return false;
+    };
+
+    let (callback_index, index_param) =
+        if matches!(method, "map" | "forEach") && is_children(member.object()) {
+            (1, 1)
+        } else if ARRAY_ITERATOR_METHODS.contains(&method) {
+            (0, if matches!(method, "reduce" | "reduceRight") { 2 } else { 1 })
+        } else {
+            return false;
+        };
+
+    param_index == index_param
+        && call
+            .arguments
+            .get(callback_index)
+            .and_then(Argument::as_expression)
+            .is_some_and(|callback| callback.without_parentheses().span() == function_span)
+}
+
+/// `Children` or `React.Children`
+fn is_children(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => ident.name == "Children",
+        Expression::StaticMemberExpression(member) => member.property.name == "Children",
+        _ => false,
+    }
+}
+
+/// `React.cloneElement(...)` or `cloneElement(...)`
+fn is_clone_element_call(call_expr: &CallExpression) -> bool {
+    match &call_expr.callee {
+        Expression::StaticMemberExpression(member) => member.property.name == "cloneElement",
+        Expression::Identifier(ident) => ident.name == "cloneElement",
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<Foo key="foo" />;"#, None),
+        (r"<Foo key={i} />;", None),
+        (r"<Foo key />;", None),
+        (r"<Foo key={`foo-${i}`} />;", None),
+        (r"<Foo key={'foo-' + i} />;", None),
+        (r"foo.bar((baz, i) => <Foo key={i} />)", None),
+        (r"foo.bar((bar, i) => <Foo key={`foo-${i}`} />)", None),
+        (r"foo.bar((bar, i) => <Foo key={'foo-' + i} />)", None),
+        (r"foo.map((baz) => <Foo key={baz.id} />)", None),
+        (r"foo.map((baz, i) => <Foo key={baz.id} />)", None),
+        (r"foo.map((baz, i) => <Foo key={'foo' + baz.id} />)", None),
+        (r"foo.map((baz, i) => React.cloneElement(someChild, { ...someChild.props }))", None),
+        (r"foo.map((baz, i) => cloneElement(someChild, { ...someChild.props }))", None),
+        (r"foo.map((item) => React.cloneElement(someChild, { key: item.id }))", None),
+        (r"foo.map((item, i) => <Foo key={item} />)", None),
+        (r"foo.map(function(item, i) { return <Foo key={item.id} /> })", None),
+        (r"foo.reduce((a, b) => a.concat(<Foo key={b.id} />), [])", None),
+        (r"foo.reduce((a, b, i) => a.concat(<Foo key={a} />), [])", None),
+        (r"foo.map((bar, i) => { if (bar) { const i = 0; return <Foo key={i} /> } })", None),
+        (r"foo.map((bar, i) => items.map((i) => <Foo key={i} />))", None),
+        (r"Children.forEach(this.props.children, (child) => <Foo key={child.id} />)", None),
+        (r"Children.map(this.props.children, (child, i) => <Foo key={child.id} />)", None),
+        (r"foo.map((item, i) => <Foo key={i.id} />)", None),
+        (r"foo.map((item, i) => <Foo key={String(item)} />)", None),
+        (r"foo.map((item, i) => <Foo key={item.toString()} />)", None),
+        (r"foo.map((item, i) => <Foo key={`${item.id}`} />)", None),
+        (r"foo.map((item, index) => <Foo index={index} key={item.id} />)", None),
+        (r"foo.map((item, index) => foo(item, index))", None),
+        (r"function Foo(i) { return <Foo key={i} /> }", None),
+    ];
+
+    let fail = vec![
+        (r"foo.map((bar, i) => <Foo key={i} />)", None),
+        (r"[{}, {}].map((bar, i) => <Foo key={i} />)", None),
+        (r"foo.map(function(bar, i) { return <Foo key={i} /> })", None),
+        (r"foo.map((bar, anything) => <Foo key={anything} />)", None),
+        (r"foo.map((bar, i) => <Foo key={`foo-${i}`} />)", None),
+        (r"foo.map((bar, i) => <Foo key={'foo-' + i} />)", None),
+        (r"foo.map((bar, i) => <Foo key={'foo-' + i + '-bar'} />)", None),
+        (
+            r"foo.map((baz, i) => React.cloneElement(someChild, { ...someChild.props, key: i }))",
+            None,
+        ),
+        (r"foo.map((baz, i) => cloneElement(someChild, { ...someChild.props, key: i }))", None),
+        (r"foo.map((baz, i) => React.createElement('div', { key: i }))", None),
+        (r"foo.map(function(item, i) { return React.cloneElement(someChild, { key: i }) })", None),
+        (r"foo.forEach((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.filter((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.some((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.every((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.find((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.findIndex((bar, i) => { baz.push(<Foo key={i} />); })", None),
+        (r"foo.flatMap((a, i) => <Foo key={i} />)", None),
+        (r"foo.reduce((a, b, i) => a.concat(<Foo key={i} />), [])", None),
+        (r"foo.reduceRight((a, b, i) => a.concat(<Foo key={i} />), [])", None),
+        (r"foo?.map((bar, i) => <Foo key={i} />)", None),
+        (r"foo.map(((bar, i) => <Foo key={i} />))", None),
+        (r"Children.map(this.props.children, (child, i) => <Foo key={i} />)", None),
+        (r"React.Children.map(this.props.children, (child, i) => <Foo key={i} />)", None),
+        (
+            r"Children.forEach(this.props.children, (child, i) => { baz.push(<Foo key={i} />) })",
+            None,
+        ),
+        (r"foo.map((bar, i) => <Foo key={i.toString()} />)", None),
+        (r"foo.map((bar, i) => <Foo key={String(i)} />)", None),
+        (r"foo.map((bar, i) => { const key = 'x'; return <Foo key={i} /> })", None),
+        (r"foo.map((bar, i) => items.map((item) => <Foo key={`${i}-${item.id}`} />))", None),
+    ];
+
+    Tester::new(NoArrayIndexKey::NAME, pass, fail).test_and_snapshot();
+}
@@ -6,7 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_create_element_call, AstNode};
 
 fn no_danger_with_children_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Only set one of `children` or `props.dangerouslySetInnerHTML`")
@@ -68,12 +68,8 @@ impl Rule for NoDangerWithChildren {
                 if call_expr.arguments.len() <= 1 {
                     return;
                 }
-                let Expression::StaticMemberExpression(callee) = &call_expr.callee else {
-                    return;
-                };
-
-                // Only accept calls like `.createElement(...)`
-                if callee.property.name != "createElement" {
+                // Only accept calls like `React.createElement(...)` or `createElement(...)`
+                if !is_create_element_call(call_expr) {
                     return;
                 }
 
@@ -158,6 +154,9 @@ fn test() {
         r#"React.createElement("Hello", { dangerouslySetInnerHTML: { __html: "HTML" } });"#,
         r#"React.createElement("Hello", {}, "Children");"#,
         "<Hello {...undefined}>Children</Hello>",
+        r#"<div {...{ dangerouslySetInnerHTML: { __html: "HTML" } }} />"#,
+        r#"createElement("div", { dangerouslySetInnerHTML: { __html: "HTML" } });"#,
+        r#"document.createElement("div");"#,
         r#"React.createElement("Hello", undefined, "Children")"#,
         "
         const props = {...props, scratch: {mode: 'edit'}};
@@ -233,6 +232,13 @@ fn test() {
         const props = { ...otherProps, dangerouslySetInnerHTML: { __html: "HTML" } };
         React.createElement("div", props);
         "#,
+        r#"<div {...{ dangerouslySetInnerHTML: { __html: "HTML" } }}>Children</div>"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }} {...{ children: "Children" }} />"#,
+        r#"
+        import { createElement } from "react";
+        createElement("div", { dangerouslySetInnerHTML: { __html: "HTML" } }, "Children");
+        "#,
+        r#"React["createElement"]("div", { dangerouslySetInnerHTML: { __html: "HTML" }, children: "Children" });"#,
     ];
 
     Tester::new(NoDangerWithChildren::NAME, pass, fail).test_and_snapshot();
@@ -264,12 +270,15 @@ fn has_jsx_prop(ctx: &LintContext, node: &AstNode, prop_name: &'static str) -> b
             };
             ident.name == prop_name
         }
-        JSXAttributeItem::SpreadAttribute(attr) => {
-            let Some(ident) = attr.argument.get_identifier_reference() else {
-                return false;
-            };
-            does_object_var_have_prop_name(ctx, node, ident.name.as_str(), prop_name)
-        }
+        JSXAttributeItem::SpreadAttribute(attr) => match attr.argument.without_parentheses() {
+            Expression::ObjectExpression(obj_expr) => {
+                is_object_with_prop_name(&obj_expr.properties, prop_name)
+            }
+            Expression::Identifier(ident) => {
+                does_object_var_have_prop_name(ctx, node, ident.name.as_str(), prop_name)
+            }
+            _ => false,
+        },
     })
 }
 
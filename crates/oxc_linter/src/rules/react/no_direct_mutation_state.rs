@@ -187,7 +187,7 @@ fn should_ignore_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'
             is_call_expression = true;
         }
 
-        if is_es6_component(parent) || is_es5_component(parent) {
+        if is_es6_component(parent, ctx) || is_es5_component(parent, ctx) {
             is_component = true;
         }
 
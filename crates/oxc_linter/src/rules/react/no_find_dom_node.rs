@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoFindDomNode,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoFindDomNode {
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// };
     /// ```
     NoIsMounted,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoIsMounted {
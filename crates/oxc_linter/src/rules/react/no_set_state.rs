@@ -49,6 +49,7 @@ declare_oxc_lint!(
     /// ```
     NoSetState,
     style,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoSetState {
@@ -0,0 +1,277 @@
+use oxc_ast::{
+    ast::{Argument, ArrayExpressionElement, Expression, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::get_symbol_id_of_variable,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_state_binding, is_react_function_call},
+    AstNode,
+};
+
+const SCOPE: &str = "eslint-plugin-react-hooks";
+
+fn missing_deps_diagnostic(span: Span, setter: &str, effect: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Calling {setter:?} in an effect without a dependency array may cause an infinite render loop."
+    ))
+    .with_help("The effect runs after every render. Add a dependency array, or only update the state when it changed.")
+    .with_labels([
+        span.primary_label("this state update triggers a render"),
+        effect.label("which runs the effect again"),
+    ])
+    .with_error_code_scope(SCOPE)
+}
+
+fn dependency_diagnostic(span: Span, setter: &str, state: &str, dep: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Calling {setter:?} in an effect that depends on {state:?} may cause an infinite render loop."
+    ))
+    .with_help(format!(
+        "Derive the value during render instead, or only update {state:?} when it changed."
+    ))
+    .with_labels([
+        span.primary_label(format!("this updates {state:?}")),
+        dep.label("which runs the effect again"),
+    ])
+    .with_error_code_scope(SCOPE)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSetStateInEffectLoop;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unconditionally setting state in a `useEffect` or `useLayoutEffect` that runs
+    /// again when that state changes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Setting state re-renders the component. If the effect depends on the state, or has no
+    /// dependency array, the render runs the effect again, which sets the state again. This
+    /// loops until React gives up with "Maximum update depth exceeded", or forever if the new
+    /// value is never equal to the old one.
+    ///
+    /// Only state updates before any conditional code in the effect are checked, since
+    /// guarding the update, e.g. with `if (count < 10)`, is the usual way to break the loop.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Counter() {
+    ///   const [count, setCount] = useState(0);
+    ///   useEffect(() => {
+    ///     setCount(count + 1);
+    ///   }, [count]);
+    /// }
+    ///
+    /// function Items({ item }) {
+    ///   const [items, setItems] = useState([]);
+    ///   useEffect(() => {
+    ///     setItems([...items, item]);
+    ///   });
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Counter() {
+    ///   const [count, setCount] = useState(0);
+    ///   useEffect(() => {
+    ///     if (count < 10) {
+    ///       setCount(count + 1);
+    ///     }
+    ///   }, [count]);
+    /// }
+    ///
+    /// function Items({ item }) {
+    ///   const [items, setItems] = useState([]);
+    ///   useEffect(() => {
+    ///     setItems((items) => [...items, item]);
+    ///   }, [item]);
+    /// }
+    /// ```
+    NoSetStateInEffectLoop,
+    nursery,
+    node_types = [CallExpression]
+);
+
+impl Rule for NoSetStateInEffectLoop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        if !is_react_function_call(call, "useEffect")
+            && !is_react_function_call(call, "useLayoutEffect")
+        {
+            return;
+        }
+
+        let body = match call.arguments.first() {
+            Some(Argument::ArrowFunctionExpression(arrow)) => &arrow.body,
+            Some(Argument::FunctionExpression(func)) => match &func.body {
+                Some(body) => body,
+                None => return,
+            },
+            _ => return,
+        };
+        let deps = match call.arguments.get(1) {
+            None => None,
+            Some(Argument::ArrayExpression(deps)) => Some(deps),
+            // Dependencies that aren't an array literal can't be checked.
+            Some(_) => return,
+        };
+
+        // Statements after the first one that isn't a plain expression or declaration
+        // may not run, e.g. because of an early return.
+        for statement in &body.statements {
+            let expr = match statement {
+                Statement::ExpressionStatement(stmt) => &stmt.expression,
+                Statement::VariableDeclaration(_) => continue,
+                _ => break,
+            };
+            let Expression::CallExpression(set_call) = expr.get_inner_expression() else {
+                continue;
+            };
+            let Expression::Identifier(setter) = set_call.callee.get_inner_expression() else {
+                continue;
+            };
+            let Some(binding) = get_symbol_id_of_variable(setter, ctx)
+                .and_then(|symbol_id| get_state_binding(symbol_id, ctx))
+            else {
+                continue;
+            };
+
+            let Some(deps) = deps else {
+                ctx.diagnostic(missing_deps_diagnostic(
+                    set_call.span,
+                    &setter.name,
+                    call.callee.span(),
+                ));
+                continue;
+            };
+            let Some(state) = binding.state else { continue };
+            let dep = deps.elements.iter().find_map(|element| match element {
+                ArrayExpressionElement::Identifier(ident)
+                    if get_symbol_id_of_variable(ident, ctx) == Some(state) =>
+                {
+                    Some(ident)
+                }
+                _ => None,
+            });
+            if let Some(dep) = dep {
+                ctx.diagnostic(dependency_diagnostic(
+                    set_call.span,
+                    &setter.name,
+                    &dep.name,
+                    dep.span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // Guarded update.
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                if (count < 10) {
+                    setCount(count + 1);
+                }
+            }, [count]);
+        }",
+        // Update after an early return.
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                if (count >= 10) return;
+                setCount(count + 1);
+            }, [count]);
+        }",
+        // The effect doesn't depend on the state.
+        "function Items({ item }) {
+            const [items, setItems] = useState([]);
+            useEffect(() => {
+                setItems((items) => [...items, item]);
+            }, [item]);
+        }",
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                setCount(1);
+            }, []);
+        }",
+        // Called in a callback, not in the effect itself.
+        "function Timer() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                const id = setInterval(() => setCount(count + 1), 1000);
+                return () => clearInterval(id);
+            }, [count]);
+        }",
+        // Not a state setter.
+        "function Counter({ setCount, count }) {
+            useEffect(() => {
+                setCount(count + 1);
+            }, [count]);
+        }",
+        "function Counter() {
+            const [count, setCount] = useReducer(reducer, 0);
+            useEffect(() => {
+                setCount(count + 1);
+            });
+        }",
+        // Not an effect.
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useMemo(() => {
+                setCount(count + 1);
+            }, [count]);
+        }",
+        // Dependencies that can't be checked.
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                setCount(count + 1);
+            }, deps);
+        }",
+    ];
+
+    let fail = vec![
+        "function Counter() {
+            const [count, setCount] = useState(0);
+            useEffect(() => {
+                setCount(count + 1);
+            }, [count]);
+        }",
+        "function Counter() {
+            const [count, setCount] = React.useState(0);
+            React.useLayoutEffect(function () {
+                const next = count + 1;
+                setCount(next);
+            }, [count]);
+        }",
+        "function Items({ item }) {
+            const [items, setItems] = useState([]);
+            useEffect(() => {
+                setItems([...items, item]);
+            });
+        }",
+        "function Counter() {
+            const [, setCount] = useState(0);
+            useEffect(() => setCount((count) => count + 1));
+        }",
+    ];
+
+    Tester::new(NoSetStateInEffectLoop::NAME, pass, fail).test_and_snapshot();
+}
@@ -77,7 +77,7 @@ declare_oxc_lint!(
     /// ```
     NoUnknownProperty,
     restriction,
-    pending
+    fix
 );
 
 const ATTRIBUTE_TAGS_MAP: Map<&'static str, Set<&'static str>> = phf_map! {
@@ -516,10 +516,11 @@ impl Rule for NoUnknownProperty {
                 };
                 if is_valid_data_attr(&actual_name) {
                     if self.0.require_data_lowercase && has_uppercase(&actual_name) {
-                        ctx.diagnostic(data_lowercase_required(
-                            span,
-                            &actual_name.cow_to_lowercase(),
-                        ));
+                        let lowercase = actual_name.cow_to_lowercase();
+                        ctx.diagnostic_with_fix(
+                            data_lowercase_required(span, &lowercase),
+                            |fixer| fixer.replace(span, lowercase.to_string()),
+                        );
                     }
                     return;
                 };
@@ -550,7 +551,18 @@ impl Rule for NoUnknownProperty {
                             ctx.diagnostic(unknown_prop(span));
                         },
                         |prop| {
-                            ctx.diagnostic(unknown_prop_with_standard_name(span, prop));
+                            let diagnostic = unknown_prop_with_standard_name(span, prop);
+                            // e.g. `crossOrigin` is not allowed on a `div` either
+                            let is_allowed = ATTRIBUTE_TAGS_MAP
+                                .get(normalize_attribute_case(prop))
+                                .map_or(true, |tags| tags.contains(el_type));
+                            if is_allowed {
+                                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                                    fixer.replace(span, *prop)
+                                });
+                            } else {
+                                ctx.diagnostic(diagnostic);
+                            }
                         },
                     );
             });
@@ -757,5 +769,25 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoUnknownProperty::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"<div class="bar"></div>;"#, r#"<div className="bar"></div>;"#, None),
+        (r#"<div for="bar"></div>;"#, r#"<div htmlFor="bar"></div>;"#, None),
+        (r#"<div accept-charset="bar"></div>;"#, r#"<div acceptCharset="bar"></div>;"#, None),
+        (r#"<div http-equiv="bar"></div>;"#, r#"<div httpEquiv="bar"></div>;"#, None),
+        (r#"<div accesskey="bar"></div>;"#, r#"<div accessKey="bar"></div>;"#, None),
+        (r#"<div onclick="bar"></div>;"#, r#"<div onClick="bar"></div>;"#, None),
+        (r#"<div onMousedown="bar"></div>;"#, r#"<div onMouseDown="bar"></div>;"#, None),
+        (r#"<use xlink:href="bar" />;"#, r#"<use xlinkHref="bar" />;"#, None),
+        (r#"<rect clip-path="bar" />;"#, r#"<rect clipPath="bar" />;"#, None),
+        (r"<script crossorigin nomodule />", r"<script crossOrigin noModule />", None),
+        (r"<div crossorigin />", r"<div crossorigin />", None),
+        (r#"<div abc="bar"></div>;"#, r#"<div abc="bar"></div>;"#, None),
+        (
+            r#"<div data-testID="bar" data-under_sCoRe="bar" />;"#,
+            r#"<div data-testid="bar" data-under_score="bar" />;"#,
+            Some(serde_json::json!([{ "requireDataLowercase": true }])),
+        ),
+    ];
+
+    Tester::new(NoUnknownProperty::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
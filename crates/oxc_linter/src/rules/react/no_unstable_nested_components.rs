@@ -0,0 +1,498 @@
+use std::borrow::Cow;
+
+use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        get_function_name, get_jsx_attribute_name, is_es6_component, ReactFunctionKind,
+        ReactFunctions,
+    },
+    AstNode,
+};
+
+fn nested_component_diagnostic(span: Span, parent: Option<&str>) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not define components during render")
+        .with_help(format!(
+            "Move this component definition out of {} and pass data as props.",
+            describe_parent(parent)
+        ))
+        .with_label(span)
+}
+
+fn component_as_prop_diagnostic(span: Span, parent: Option<&str>) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not define components during render")
+        .with_help(format!(
+            "Move this component definition out of {} and pass data as props. If you want to allow component creation in props, set the `allowAsProps` option to true.",
+            describe_parent(parent)
+        ))
+        .with_label(span)
+}
+
+fn describe_parent(parent: Option<&str>) -> String {
+    parent.map_or_else(
+        || "the parent component".to_string(),
+        |name| format!("the parent component `{name}`"),
+    )
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnstableNestedComponents(Box<NoUnstableNestedComponentsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnstableNestedComponentsConfig {
+    allow_as_props: bool,
+}
+
+impl std::ops::Deref for NoUnstableNestedComponents {
+    type Target = NoUnstableNestedComponentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow defining components inside the render of another component.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A component defined during render is a new component type on every
+    /// render. React can't tell it is the same component as before, so it
+    /// unmounts the previous one, destroying its DOM nodes and state, and
+    /// mounts the new one from scratch. This is slow and causes bugs, such as
+    /// inputs losing focus on every keystroke.
+    ///
+    /// Components passed as props, other than render props whose name starts
+    /// with `render`, are reported too. They can be allowed with the
+    /// `allowAsProps` option.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Parent() {
+    ///   function Child() {
+    ///     return <div />;
+    ///   }
+    ///   return <Child />;
+    /// }
+    ///
+    /// function Parent() {
+    ///   return <Table footer={() => <div />} />;
+    /// }
+    ///
+    /// class Parent extends React.Component {
+    ///   render() {
+    ///     const Child = () => <div />;
+    ///     return <Child />;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Child() {
+    ///   return <div />;
+    /// }
+    ///
+    /// function Parent() {
+    ///   return <Child />;
+    /// }
+    ///
+    /// function Parent() {
+    ///   return <Table renderFooter={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "react/no-unstable-nested-components": ["error", { "allowAsProps": true }] }
+    /// ```
+    NoUnstableNestedComponents,
+    perf
+);
+
+impl Rule for NoUnstableNestedComponents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUnstableNestedComponentsConfig {
+            allow_as_props: value
+                .get(0)
+                .and_then(|v| v.get("allowAsProps"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let react_functions = ctx.get_or_compute::<ReactFunctions>();
+        let prop_name = match node.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                if !react_functions.returns_jsx(node.id()) {
+                    return;
+                }
+                let prop_name = get_prop_name(node, ctx);
+                if prop_name.is_none()
+                    && react_functions.kind(node.id()) != Some(ReactFunctionKind::Component)
+                {
+                    return;
+                }
+                prop_name
+            }
+            AstKind::Class(_) if is_es6_component(node, ctx) => None,
+            _ => return,
+        };
+
+        if let Some(prop_name) = &prop_name {
+            // Render props are called by the component they are passed to, not rendered as components.
+            if prop_name.starts_with("render") || self.allow_as_props {
+                return;
+            }
+        }
+
+        let Some(parent_id) = find_parent_component(node, ctx, &react_functions) else {
+            return;
+        };
+        let parent_name = match ctx.nodes().kind(parent_id) {
+            AstKind::Class(class) => class.id.as_ref().map(|id| Cow::Borrowed(id.name.as_str())),
+            _ => get_function_name(ctx.nodes(), parent_id),
+        };
+
+        let span = node.kind().span();
+        if prop_name.is_some() {
+            ctx.diagnostic(component_as_prop_diagnostic(span, parent_name.as_deref()));
+        } else {
+            ctx.diagnostic(nested_component_diagnostic(span, parent_name.as_deref()));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Get the name of the prop a function is passed as, either directly as a JSX
+/// attribute or as a property of an object.
+///
+/// ```jsx
+/// <Table footer={() => <div />} />;          // footer
+/// <Table options={{ footer: () => <div /> }} />;  // footer
+/// ```
+fn get_prop_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Cow<'a, str>> {
+    let parent = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))?;
+    match parent.kind() {
+        AstKind::ObjectProperty(prop) => prop.key.static_name(),
+        AstKind::JSXExpressionContainer(_) => match ctx.nodes().parent_kind(parent.id()) {
+            Some(AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr))) => {
+                Some(get_jsx_attribute_name(&attr.name))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Find the closest function component, or class component whose `render` method,
+/// that `node` is defined in.
+fn find_parent_component(
+    node: &AstNode,
+    ctx: &LintContext,
+    react_functions: &ReactFunctions,
+) -> Option<NodeId> {
+    let mut in_render = false;
+    for ancestor in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match ancestor.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                if react_functions.kind(ancestor.id()) == Some(ReactFunctionKind::Component) =>
+            {
+                return Some(ancestor.id());
+            }
+            AstKind::MethodDefinition(method) => {
+                in_render = !method.r#static && method.key.is_specific_static_name("render");
+            }
+            AstKind::Class(_) if in_render && is_es6_component(ancestor, ctx) => {
+                return Some(ancestor.id());
+            }
+            AstKind::Class(_) => in_render = false,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <div>
+                  <OutsideDefinedFunctionComponent />
+                </div>
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return React.createElement(
+                'div',
+                null,
+                React.createElement(OutsideDefinedFunctionComponent, null)
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <SomeComponent
+                  footer={<OutsideDefinedComponent />}
+                  header={<div />}
+                />
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const MemoizedNestedComponent = React.useCallback(() => <div />, []);
+              return <SomeComponent renderFooter={MemoizedNestedComponent} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <SomeComponent renderFooter={() => <div />} />
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <SomeComponent options={{ renderFooter: () => <div /> }} />
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const thingElement = things.map((thing) => <li>{thing}</li>);
+              return <ul>{thingElement}</ul>;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const thingElement = things.map(function (thing) {
+                return <li>{thing}</li>;
+              });
+              return <ul>{thingElement}</ul>;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const handleClick = () => {};
+              const Handler = () => {};
+              return <button onClick={handleClick} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function useCustomHook() {
+              const Component = () => <div />;
+              return Component;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            class ParentComponent extends React.Component {
+              componentDidMount() {
+                const Nested = () => <div />;
+              }
+              render() {
+                return <div />;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            const Outer = () => <Table footer={() => <div />} />;
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Table options={{ Footer: () => <div /> }} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (r"<Table footer={() => <div />} />;", None),
+        (r"const columns = [{ cell: () => <div /> }];", None),
+    ];
+
+    let fail = vec![
+        (
+            r"
+            function ParentComponent() {
+              function UnstableNestedComponent() {
+                return <div />;
+              }
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const UnstableNestedComponent = () => <div />;
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            const ParentComponent = () => {
+              const UnstableNestedComponent = React.memo(() => <div />);
+              return <UnstableNestedComponent />;
+            };
+            ",
+            None,
+        ),
+        (
+            r"
+            export default () => {
+              function UnstableNestedComponent() {
+                return <div />;
+              }
+              return <UnstableNestedComponent />;
+            };
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              class UnstableNestedClassComponent extends React.Component {
+                render() {
+                  return <div />;
+                }
+              }
+              return <UnstableNestedClassComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            class ParentComponent extends React.Component {
+              render() {
+                const UnstableNestedComponent = () => <div />;
+                return <UnstableNestedComponent />;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <SomeComponent footer={() => <div />} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <SomeComponent footer={function () { return <div />; }} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Table options={{ Footer: () => <div /> }} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const columns = [{ cell() { return <div />; } }];
+              return <Table columns={columns} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <List
+                  renderItem={(item) => <Row footer={() => <div />} />}
+                />
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const UnstableNestedComponent = () => <div />;
+              return <Table footer={UnstableNestedComponent} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+    ];
+
+    Tester::new(NoUnstableNestedComponents::NAME, pass, fail).test_and_snapshot();
+}
@@ -59,13 +59,13 @@ impl Rule for PreferEs6Class {
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if matches!(self.prefer_es6_class_option, PreferES6ClassOptionType::Always) {
-            if is_es5_component(node) {
+            if is_es5_component(node, ctx) {
                 let AstKind::CallExpression(call_expr) = node.kind() else {
                     return;
                 };
                 ctx.diagnostic(expected_es6_class_diagnostic(call_expr.callee.span()));
             }
-        } else if is_es6_component(node) {
+        } else if is_es6_component(node, ctx) {
             let AstKind::Class(class_expr) = node.kind() else {
                 return;
             };
@@ -111,6 +111,7 @@ fn test() {
             Hello.displayName = 'Hello'
             ",
             None,
+            None,
         ),
         (
             r"
@@ -122,6 +123,7 @@ fn test() {
             Hello.displayName = 'Hello'
             ",
             None,
+            None,
         ),
         (
             r"
@@ -129,6 +131,7 @@ fn test() {
             module.exports = {};
             ",
             None,
+            None,
         ),
         (
             r"
@@ -139,6 +142,7 @@ fn test() {
             });
             ",
             Some(serde_json::json!(["never"])),
+            None,
         ),
         (
             r"
@@ -149,6 +153,18 @@ fn test() {
             }
             ",
             Some(serde_json::json!(["always"])),
+            None,
+        ),
+        (
+            r"
+            var Hello = createReactClass({
+              render: function() {
+                return <div>Hello {this.props.name}</div>;
+              }
+            });
+            ",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "createClass": "createClass" } } })),
         ),
     ];
 
@@ -163,6 +179,7 @@ fn test() {
             });
             ",
             None,
+            None,
         ),
         (
             r"
@@ -173,6 +190,7 @@ fn test() {
             });
             ",
             Some(serde_json::json!(["always"])),
+            None,
         ),
         (
             r"
@@ -183,6 +201,29 @@ fn test() {
             }
             ",
             Some(serde_json::json!(["never"])),
+            None,
+        ),
+        (
+            r"
+            var Hello = createClass({
+              render: function() {
+                return <div>Hello {this.props.name}</div>;
+              }
+            });
+            ",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "createClass": "createClass" } } })),
+        ),
+        (
+            r"
+            class Hello extends Preact.Component {
+              render() {
+                  return <div>Hello {this.props.name}</div>;
+              }
+            }
+            ",
+            Some(serde_json::json!(["never"])),
+            Some(serde_json::json!({ "settings": { "react": { "pragma": "Preact" } } })),
         ),
     ];
 
@@ -9,9 +9,9 @@ use crate::{
     AstNode,
 };
 
-fn react_in_jsx_scope_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("'React' must be in scope when using JSX")
-        .with_help("When using JSX, `<a />` expands to `React.createElement(\"a\")`. Therefore the `React` variable must be in scope.")
+fn react_in_jsx_scope_diagnostic(span: Span, factory: &str, binding: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{binding}' must be in scope when using JSX"))
+        .with_help(format!("When using JSX, `<a />` expands to `{factory}(\"a\")`. Therefore the `{binding}` variable must be in scope."))
         .with_label(span)
 }
 
@@ -52,14 +52,18 @@ impl Rule for ReactInJsxScope {
             AstKind::JSXFragment(v) => v.opening_fragment.span,
             _ => return,
         };
+        let pragma = ctx.jsx_pragma();
+        if pragma.is_automatic_runtime() {
+            return;
+        }
         let scope = ctx.scopes();
-        let react_name = "React";
+        let react_name = pragma.factory_binding();
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
             return;
         }
 
         if scope.find_binding(node.scope_id(), react_name).is_none() {
-            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span));
+            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span, pragma.factory(), react_name));
         }
     }
 
@@ -95,6 +99,9 @@ fn test() {
             None,
         ),
         ("var React, a = <img />;", None),
+        ("/** @jsx h */ var h; <img />;", None),
+        ("/** @jsx Preact.h */ import * as Preact from 'preact'; <img />;", None),
+        ("/** @jsxRuntime automatic */ var a = <img />;", None),
     ];
 
     let fail = vec![
@@ -103,6 +110,7 @@ fn test() {
         ("var a = <img />;", None),
         ("var a = <>fragment</>;", None),
         ("var Foo, a = <img />;", None),
+        ("/** @jsx h */ var React, a = <img />;", None),
     ];
 
     Tester::new(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
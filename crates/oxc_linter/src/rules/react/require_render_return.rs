@@ -183,7 +183,7 @@ fn is_in_es5_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>)
 
     let Some(ancestors_2) = ctx.nodes().parent_node(ancestors_1.id()) else { return false };
 
-    is_es5_component(ancestors_2)
+    is_es5_component(ancestors_2, ctx)
 }
 
 fn is_in_es6_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>) -> bool {
@@ -193,7 +193,7 @@ fn is_in_es6_component<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>)
     }
 
     let Some(grandparent) = ctx.nodes().parent_node(parent.id()) else { return false };
-    is_es6_component(grandparent)
+    is_es6_component(grandparent, ctx)
 }
 
 #[test]
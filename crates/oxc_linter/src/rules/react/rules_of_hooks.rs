@@ -15,7 +15,9 @@ use oxc_syntax::operator::AssignmentOperator;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{is_react_component_or_hook_name, is_react_function_call, is_react_hook},
+    utils::{
+        is_react_component_or_hook_name, is_react_function_call, is_react_hook, ReactFunctions,
+    },
     AstNode,
 };
 
@@ -159,7 +161,7 @@ impl Rule for RulesOfHooks {
                 if is_non_react_func_arg(nodes, parent_func.id()) =>
             {
                 // This rule doesn't apply to `use(...)`.
-                if !is_use && is_somewhere_inside_component_or_hook(nodes, parent_func.id()) {
+                if !is_use && is_somewhere_inside_component_or_hook(ctx, parent_func.id()) {
                     ctx.diagnostic(diagnostics::generic_error(span, hook_name));
                 }
                 return;
@@ -344,28 +346,9 @@ fn is_non_react_func_arg(nodes: &AstNodes, node_id: NodeId) -> bool {
     !(is_react_function_call(call, "forwardRef") || is_react_function_call(call, "memo"))
 }
 
-fn is_somewhere_inside_component_or_hook(nodes: &AstNodes, node_id: NodeId) -> bool {
-    nodes
-        .ancestors(node_id)
-        .map(|id| nodes.get_node(id))
-        .filter(|node| node.kind().is_function_like())
-        .map(|node| {
-            (
-                node.id(),
-                match node.kind() {
-                    AstKind::Function(func) => func.name().map(Cow::from),
-                    AstKind::ArrowFunctionExpression(_) => {
-                        get_declaration_identifier(nodes, node.id())
-                    }
-                    _ => unreachable!(),
-                },
-            )
-        })
-        .any(|(id, ident)| {
-            ident.is_some_and(|name| {
-                is_react_component_or_hook_name(&name) || is_memo_or_forward_ref_callback(nodes, id)
-            })
-        })
+fn is_somewhere_inside_component_or_hook(ctx: &LintContext, node_id: NodeId) -> bool {
+    let react_functions = ctx.get_or_compute::<ReactFunctions>();
+    ctx.nodes().ancestors(node_id).any(|id| react_functions.kind(id).is_some())
 }
 
 fn get_declaration_identifier<'a>(
@@ -405,18 +388,6 @@ fn is_export_default<'a>(nodes: &'a AstNodes<'a>, node_id: NodeId) -> bool {
         .is_some_and(|node| matches!(node.kind(), AstKind::ExportDefaultDeclaration(_)))
 }
 
-/// # Panics
-/// `node_id` should always point to a valid `Function`.
-fn is_memo_or_forward_ref_callback(nodes: &AstNodes, node_id: NodeId) -> bool {
-    nodes.ancestors(node_id).map(|id| nodes.get_node(id)).any(|node| {
-        if let AstKind::CallExpression(call) = node.kind() {
-            call.callee_name().is_some_and(|name| matches!(name, "forwardRef" | "memo"))
-        } else {
-            false
-        }
-    })
-}
-
 #[test]
 fn test() {
     ///  Copyright (c) Meta Platforms, Inc. and affiliates.
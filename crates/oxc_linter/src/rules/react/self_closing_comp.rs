@@ -59,7 +59,8 @@ declare_oxc_lint!(
     /// ```
     SelfClosingComp,
     style,
-    pending
+    pending,
+    node_types = [JSXElement]
 );
 
 impl Rule for SelfClosingComp {
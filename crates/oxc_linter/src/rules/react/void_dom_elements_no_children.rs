@@ -18,8 +18,7 @@ use crate::{
 };
 
 fn void_dom_elements_no_children_diagnostic(tag: &str, span: Span) -> OxcDiagnostic {
-    // TODO: use imperative phrasing
-    OxcDiagnostic::warn(format!("Void DOM element <{tag:?} /> cannot receive children."))
+    OxcDiagnostic::warn(format!("Void DOM element <{tag} /> cannot receive children."))
         .with_help("Remove this element's children or use a non-void element.")
         .with_label(span)
 }
@@ -118,25 +117,22 @@ impl Rule for VoidDomElementsNoChildren {
                     return;
                 }
 
-                if call_expr.arguments.len() < 2 {
-                    return;
-                }
-
-                let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
-                    return;
+                let has_children_prop_or_danger = match call_expr.arguments.get(1) {
+                    Some(Argument::ObjectExpression(obj_expr)) => {
+                        obj_expr.properties.iter().any(|property| match property {
+                            ObjectPropertyKind::ObjectProperty(prop) => match &prop.key {
+                                PropertyKey::StaticIdentifier(iden) => {
+                                    iden.name == "children"
+                                        || iden.name == "dangerouslySetInnerHTML"
+                                }
+                                _ => false,
+                            },
+                            ObjectPropertyKind::SpreadProperty(_) => false,
+                        })
+                    }
+                    _ => false,
                 };
 
-                let has_children_prop_or_danger =
-                    obj_expr.properties.iter().any(|property| match property {
-                        ObjectPropertyKind::ObjectProperty(prop) => match &prop.key {
-                            PropertyKey::StaticIdentifier(iden) => {
-                                iden.name == "children" || iden.name == "dangerouslySetInnerHTML"
-                            }
-                            _ => false,
-                        },
-                        ObjectPropertyKind::SpreadProperty(_) => false,
-                    });
-
                 if call_expr.arguments.get(2).is_some() || has_children_prop_or_danger {
                     ctx.diagnostic(void_dom_elements_no_children_diagnostic(
                         &element_name.value,
@@ -211,6 +207,8 @@ fn test() {
         (r"React.createElement('br', {}, 'Foo');", None),
         (r"React.createElement('br', { children: 'Foo' });", None),
         (r"React.createElement('br', { dangerouslySetInnerHTML: { __html: 'Foo' } });", None),
+        (r"React.createElement('br', undefined, 'Foo');", None),
+        (r"React.createElement('hr', props, 'Foo');", None),
         (
             r"
                 import React, {createElement} from 'react';
@@ -210,8 +210,7 @@ impl<'a> ListenerMap<'a> for ExportSpecifier<'a> {
                     }
                 }
             }
-            let symbol_table = ctx.semantic().symbols();
-            let node = ctx.nodes().get_node(symbol_table.get_declaration(symbol_id));
+            let node = ctx.symbol_declaration(symbol_id);
             node.report_effects_when_called(options);
         }
     }
@@ -481,7 +480,7 @@ impl<'a> ListenerMap<'a> for BindingIdentifier<'a> {
                     }
                 }
             }
-            let node = ctx.nodes().get_node(symbol_table.get_declaration(symbol_id));
+            let node = ctx.symbol_declaration(symbol_id);
             node.report_effects_when_called(options);
         }
     }
@@ -1122,8 +1121,7 @@ impl<'a> ListenerMap<'a> for IdentifierReference<'a> {
                         }
                     }
                 }
-                let symbol_table = options.ctx.semantic().symbols();
-                let node = options.ctx.nodes().get_node(symbol_table.get_declaration(symbol_id));
+                let node = options.ctx.symbol_declaration(symbol_id);
                 let old_val = options.called_with_new.get();
                 options.called_with_new.set(true);
                 node.report_effects_when_called(options);
@@ -1141,8 +1139,7 @@ impl<'a> ListenerMap<'a> for IdentifierReference<'a> {
                         }
                     }
                 }
-                let symbol_table = ctx.semantic().symbols();
-                let node = ctx.nodes().get_node(symbol_table.get_declaration(symbol_id));
+                let node = ctx.symbol_declaration(symbol_id);
                 node.report_effects_when_called(options);
             }
         } else {
@@ -1163,8 +1160,7 @@ impl<'a> ListenerMap<'a> for IdentifierReference<'a> {
                     }
                 }
 
-                let symbol_table = ctx.semantic().symbols();
-                let node = ctx.nodes().get_node(symbol_table.get_declaration(symbol_id));
+                let node = ctx.symbol_declaration(symbol_id);
                 node.report_effects_when_mutated(options);
             }
         } else {
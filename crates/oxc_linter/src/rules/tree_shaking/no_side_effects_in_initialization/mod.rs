@@ -1,4 +1,3 @@
-use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -188,7 +187,8 @@ declare_oxc_lint!(
     /// /*@__PURE__*/ x();
     /// ```
     NoSideEffectsInInitialization,
-    nursery
+    nursery,
+    node_types = []
 );
 
 impl Rule for NoSideEffectsInInitialization {
@@ -240,10 +240,7 @@ impl Rule for NoSideEffectsInInitialization {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let Some(root) = ctx.nodes().root_node() else {
-            return;
-        };
-        let AstKind::Program(program) = root.kind() else { unreachable!() };
+        let program = ctx.program();
         let node_listener_options = NodeListenerOptions::new(ctx)
             .with_whitelist_functions(&self.functions)
             .with_whitelist_modules(&self.modules);
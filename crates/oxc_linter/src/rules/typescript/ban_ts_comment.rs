@@ -120,7 +120,8 @@ declare_oxc_lint!(
     /// ```
     BanTsComment,
     pedantic,
-    conditional_fix
+    conditional_fix,
+    node_types = []
 );
 
 impl Rule for BanTsComment {
@@ -155,9 +156,8 @@ impl Rule for BanTsComment {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let comments = ctx.semantic().comments();
-        for comm in comments {
-            let raw = ctx.source_range(comm.span);
+        for comm in ctx.comments() {
+            let raw = ctx.comment_text(comm);
             if let Some(captures) = find_ts_comment_directive(raw, comm.is_line()) {
                 // safe to unwrap, if capture success, it can always capture one of the four directives
                 let (directive, description) = (captures.0, captures.1);
@@ -28,16 +28,16 @@ declare_oxc_lint!(
     /// ```
     BanTslintComment,
     style,
-    fix
+    fix,
+    node_types = []
 );
 
 impl Rule for BanTslintComment {
     fn run_once(&self, ctx: &LintContext) {
-        let comments = ctx.semantic().comments();
         let source_text_len = ctx.semantic().source_text().len();
 
-        for comment in comments {
-            let raw = comment.span.source_text(ctx.semantic().source_text());
+        for comment in ctx.comments() {
+            let raw = ctx.comment_text(comment);
 
             if is_tslint_comment_directive(raw) {
                 let comment_span = get_full_comment(
@@ -1,10 +1,11 @@
 use oxc_ast::{
-    ast::{ExportDefaultDeclarationKind, TSType},
+    ast::{ExportDefaultDeclarationKind, TSInterfaceDeclaration, TSModuleDeclarationName, TSType},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::symbol::SymbolFlags;
 
 use crate::{
     context::{ContextHost, LintContext},
@@ -55,9 +56,13 @@ declare_oxc_lint!(
     /// x: number;
     /// }
     /// ```
+    ///
+    /// Interfaces that take part in declaration merging, e.g. ones that are declared more
+    /// than once or augment a global or module declaration, are reported but not fixed,
+    /// since a `type` cannot be merged.
     ConsistentTypeDefinitions,
     style,
-    fix
+    conditional_fix
 );
 
 impl Rule for ConsistentTypeDefinitions {
@@ -159,6 +164,9 @@ impl Rule for ConsistentTypeDefinitions {
                             Span::new(decl.span.start, decl.span.start + 9),
                         ),
                         |fixer| {
+                            if is_merged_interface(decl, node, ctx) {
+                                return fixer.noop();
+                            }
                             fixer.replace(
                                 exp.span,
                                 format!("type {name} = {body}{extends}\nexport default {name}"),
@@ -213,6 +221,9 @@ impl Rule for ConsistentTypeDefinitions {
                         Span::new(start, start + 9),
                     ),
                     |fixer| {
+                        if is_merged_interface(decl, node, ctx) {
+                            return fixer.noop();
+                        }
                         fixer.replace(
                             Span::new(start, decl.span.end),
                             format!("type {name} = {body}{extends}"),
@@ -229,6 +240,32 @@ impl Rule for ConsistentTypeDefinitions {
     }
 }
 
+/// Whether the interface is merged with other declarations, so that it cannot be turned into a
+/// type alias.
+fn is_merged_interface<'a>(
+    decl: &TSInterfaceDeclaration<'a>,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    if let Some(symbol_id) = decl.id.symbol_id.get() {
+        let symbols = ctx.symbols();
+        if !symbols.get_redeclarations(symbol_id).is_empty()
+            || symbols.get_flags(symbol_id).contains(SymbolFlags::Class)
+        {
+            return true;
+        }
+    }
+    // `declare global {}` and `declare module "foo" {}` augment existing declarations.
+    ctx.nodes().iter_parents(node.id()).any(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::TSModuleDeclaration(module)
+                if module.kind.is_global()
+                    || matches!(module.id, TSModuleDeclarationName::StringLiteral(_))
+        )
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -359,6 +396,15 @@ fn test() {
 			      ",
             Some(serde_json::json!(["type"])),
         ),
+        (
+            "declare module 'foo' { interface Bar { x: number; } }",
+            Some(serde_json::json!(["type"])),
+        ),
+        (
+            "interface A { a: string; } interface A { b: string; }",
+            Some(serde_json::json!(["type"])),
+        ),
+        ("class Foo {} interface Foo { x: number; }", Some(serde_json::json!(["type"]))),
     ];
 
     let fix = vec![
@@ -431,12 +477,27 @@ fn test() {
                 }
               }",
             "global {
-                type Array<T> = {
+                interface Array<T> {
                   foo(x: (x: number) => T): T[];
                 }
               }",
             Some(serde_json::json!(["type"])),
         ),
+        (
+            "declare module 'foo' { interface Bar { x: number; } }",
+            "declare module 'foo' { interface Bar { x: number; } }",
+            Some(serde_json::json!(["type"])),
+        ),
+        (
+            "interface A { a: string; } interface A { b: string; }",
+            "interface A { a: string; } interface A { b: string; }",
+            Some(serde_json::json!(["type"])),
+        ),
+        (
+            "class Foo {} interface Foo { x: number; }",
+            "class Foo {} interface Foo { x: number; }",
+            Some(serde_json::json!(["type"])),
+        ),
         (
             "
 export default interface Test {
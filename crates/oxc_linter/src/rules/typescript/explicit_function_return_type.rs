@@ -105,7 +105,7 @@ declare_oxc_lint!(
     /// }
     /// ```
     ExplicitFunctionReturnType,
-    restriction,
+    pedantic,
 );
 
 fn explicit_function_return_type_diagnostic(span: Span) -> OxcDiagnostic {
@@ -178,9 +178,9 @@ impl Rule for ExplicitFunctionReturnType {
                         return;
                     }
                 } else {
-                    if self.allow_typed_function_expressions
-                        && (self.is_valid_function_expression_return_type(node, ctx)
-                            || ancestor_has_return_type(node, ctx))
+                    if self.is_valid_function_expression_return_type(node, ctx)
+                        || (self.allow_typed_function_expressions
+                            && ancestor_has_return_type(node, ctx))
                     {
                         return;
                     }
@@ -245,9 +245,9 @@ impl Rule for ExplicitFunctionReturnType {
                 if self.is_allowed_function(node, ctx) {
                     return;
                 }
-                if self.allow_typed_function_expressions
-                    && (self.is_valid_function_expression_return_type(node, ctx)
-                        || ancestor_has_return_type(node, ctx))
+                if self.is_valid_function_expression_return_type(node, ctx)
+                    || (self.allow_typed_function_expressions
+                        && ancestor_has_return_type(node, ctx))
                 {
                     return;
                 }
@@ -389,7 +389,7 @@ impl ExplicitFunctionReturnType {
         node: &AstNode<'a>,
         ctx: &LintContext<'a>,
     ) -> bool {
-        if check_typed_function_expression(node, ctx) {
+        if self.allow_typed_function_expressions && check_typed_function_expression(node, ctx) {
             return true;
         }
         self.check_allow_expressions(node, ctx)
@@ -562,7 +562,12 @@ fn is_function_argument(parent: &AstNode, callee: Option<&AstNode>) -> bool {
 }
 
 fn is_type_assertion(node: &AstNode) -> bool {
-    matches!(node.kind(), AstKind::TSAsExpression(_) | AstKind::TSTypeAssertion(_))
+    matches!(
+        node.kind(),
+        AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSTypeAssertion(_)
+    )
 }
 fn is_default_function_parameter_with_type_annotation(node: &AstNode) -> bool {
     let AstKind::AssignmentPattern(assign) = node.kind() else { return false };
@@ -711,9 +716,6 @@ fn check_return_statements<'a>(statements: &'a [Statement<'a>]) -> bool {
  * ```
  */
 fn is_property_of_object_with_type(node: &AstNode, ctx: &LintContext) -> bool {
-    if !matches!(node.kind(), AstKind::ObjectProperty(_)) {
-        return false;
-    }
     if !matches!(node.kind(), AstKind::ObjectProperty(_)) {
         return false;
     }
@@ -1550,6 +1552,20 @@ fn test() {
             None,
             None,
         ),
+        (
+            "const x = { foo: () => {} } satisfies Foo;",
+            Some(serde_json::json!([{ "allowTypedFunctionExpressions": true }])),
+            None,
+            None,
+        ),
+        (
+            "node.addEventListener('click', () => {});",
+            Some(
+                serde_json::json!([{ "allowExpressions": true, "allowTypedFunctionExpressions": false }]),
+            ),
+            None,
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -2180,6 +2196,20 @@ fn test() {
             None,
             None,
         ),
+        (
+            "const x = { foo: () => {} } satisfies Foo;",
+            Some(serde_json::json!([{ "allowTypedFunctionExpressions": false }])),
+            None,
+            None,
+        ),
+        (
+            "const foo = () => {};",
+            Some(
+                serde_json::json!([{ "allowExpressions": true, "allowTypedFunctionExpressions": false }]),
+            ),
+            None,
+            None,
+        ),
     ];
 
     Tester::new(ExplicitFunctionReturnType::NAME, pass, fail).test_and_snapshot();
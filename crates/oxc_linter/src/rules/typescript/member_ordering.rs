@@ -0,0 +1,809 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use cow_utils::CowUtils;
+use oxc_ast::{
+    ast::{
+        ClassElement, ClassType, Expression, MethodDefinitionKind, PropertyKey, TSAccessibility,
+        TSIndexSignatureName, TSMethodSignatureKind, TSSignature,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    AstNode,
+};
+
+fn incorrect_group_order_diagnostic(name: &str, rank: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Member `{name}` should be declared before all {rank} definitions."
+    ))
+    .with_label(span)
+}
+
+fn incorrect_order_diagnostic(name: &str, before: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Member `{name}` should be declared before member `{before}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MemberOrdering(Box<MemberOrderingConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MemberOrderingConfig {
+    /// The order of members in classes, interfaces and type literals without a more
+    /// specific order below.
+    default: OrderConfig,
+    /// The order of members in class declarations.
+    classes: Option<OrderConfig>,
+    /// The order of members in class expressions.
+    class_expressions: Option<OrderConfig>,
+    /// The order of members in interfaces.
+    interfaces: Option<OrderConfig>,
+    /// The order of members in type literals.
+    type_literals: Option<OrderConfig>,
+}
+
+impl Default for MemberOrderingConfig {
+    fn default() -> Self {
+        Self {
+            default: OrderConfig {
+                member_types: Some(default_member_types()),
+                order: Order::AsWritten,
+            },
+            classes: None,
+            class_expressions: None,
+            interfaces: None,
+            type_literals: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct OrderConfig {
+    /// Member groups, from first to last, e.g. `["field", "constructor", "method"]`.
+    /// Groups in a nested array share a rank and may be mixed. `None` for `"never"`,
+    /// which does not check the order of groups.
+    member_types: Option<Vec<Vec<CompactStr>>>,
+    /// How members of the same rank are sorted.
+    order: Order,
+}
+
+/// Accepts the same values as [`Rule::from_configuration`], which the derived
+/// schema only describes in part.
+impl<'de> Deserialize<'de> for OrderConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        parse_order_config(&value).ok_or_else(|| {
+            de::Error::custom(
+                "expected an array of member types, \"never\", or an object with `memberTypes` and `order`",
+            )
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum Order {
+    #[default]
+    AsWritten,
+    Alphabetically,
+    AlphabeticallyCaseInsensitive,
+    Natural,
+    NaturalCaseInsensitive,
+}
+
+impl std::ops::Deref for MemberOrdering {
+    type Target = MemberOrderingConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a consistent order of members in classes, interfaces and type literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order, e.g. fields before the constructor before methods, makes it
+    /// easier to find members and to see what a class or type contains.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   method() {}
+    ///   constructor() {}
+    ///   field = 1;
+    /// }
+    ///
+    /// interface Bar {
+    ///   method(): void;
+    ///   field: string;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   field = 1;
+    ///   constructor() {}
+    ///   method() {}
+    /// }
+    ///
+    /// interface Bar {
+    ///   field: string;
+    ///   method(): void;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `default`, `classes`, `classExpressions`, `interfaces` and `typeLiterals` each take
+    /// `"never"`, an array of member groups, or an object with `memberTypes` (`"never"` or an
+    /// array of member groups) and `order` (`"as-written"`, `"alphabetically"`,
+    /// `"alphabetically-case-insensitive"`, `"natural"` or `"natural-case-insensitive"`):
+    ///
+    /// ```json
+    /// { "typescript/member-ordering": ["error", { "default": { "memberTypes": ["signature", "field", ["get", "set"], "constructor", "method"], "order": "alphabetically" } }] }
+    /// ```
+    ///
+    /// A member group is a member type, `signature`, `call-signature`, `field`,
+    /// `static-initialization`, `constructor`, `accessor`, `get`, `set` or `method`, optionally
+    /// prefixed with an accessibility (`public`, `protected`, `private` or `#private`), a scope
+    /// (`static`, `instance` or `abstract`), or `decorated`, e.g. `private-static-field`. Members
+    /// are ranked by their most specific group in the order; members of other groups are not
+    /// checked.
+    ///
+    /// Members are only reported. When swapping a member with the one before it fixes the
+    /// order, this is offered as a suggestion.
+    MemberOrdering,
+    style,
+    suggestion,
+    config = MemberOrderingConfig,
+    node_types = [Class, TSInterfaceDeclaration, TSTypeLiteral]
+);
+
+impl Rule for MemberOrdering {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let order_config = |name: &str| config.get(name).and_then(parse_order_config);
+        Self(Box::new(MemberOrderingConfig {
+            default: order_config("default")
+                .unwrap_or_else(|| MemberOrderingConfig::default().default),
+            classes: order_config("classes"),
+            class_expressions: order_config("classExpressions"),
+            interfaces: order_config("interfaces"),
+            type_literals: order_config("typeLiterals"),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (config, members) = match node.kind() {
+            AstKind::Class(class) => {
+                let config = match class.r#type {
+                    ClassType::ClassDeclaration => self.classes.as_ref(),
+                    ClassType::ClassExpression => self.class_expressions.as_ref(),
+                };
+                let members = class.body.body.iter().map(|element| class_member(element, ctx));
+                (config, members.collect::<Vec<_>>())
+            }
+            AstKind::TSInterfaceDeclaration(decl) => (
+                self.interfaces.as_ref(),
+                decl.body.body.iter().map(|signature| signature_member(signature, ctx)).collect(),
+            ),
+            AstKind::TSTypeLiteral(literal) => (
+                self.type_literals.as_ref(),
+                literal.members.iter().map(|signature| signature_member(signature, ctx)).collect(),
+            ),
+            _ => return,
+        };
+        let config = config.unwrap_or(&self.default);
+
+        let violations = config.check(&members);
+        // Only offer to swap a member with the one before it when that fixes the whole body.
+        let swap = match violations.as_slice() {
+            [violation] if violation.index > 0 => {
+                let mut swapped = members.clone();
+                swapped.swap(violation.index - 1, violation.index);
+                config.check(&swapped).is_empty().then_some(violation.index)
+            }
+            _ => None,
+        };
+
+        for violation in violations {
+            let member = &members[violation.index];
+            let diagnostic = match &violation.kind {
+                ViolationKind::GroupOrder { rank } => {
+                    incorrect_group_order_diagnostic(&member.name, rank, member.span)
+                }
+                ViolationKind::Order { before } => {
+                    incorrect_order_diagnostic(&member.name, before, member.span)
+                }
+            };
+            if swap == Some(violation.index) {
+                let previous = &members[violation.index - 1];
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    let between = Span::new(previous.span.end, member.span.start);
+                    fixer
+                        .replace(
+                            Span::new(previous.span.start, member.span.end),
+                            format!(
+                                "{}{}{}",
+                                fixer.source_range(member.span),
+                                fixer.source_range(between),
+                                fixer.source_range(previous.span)
+                            ),
+                        )
+                        .with_message(format!("Move `{}` before `{}`", member.name, previous.name))
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn parse_order_config(value: &Value) -> Option<OrderConfig> {
+    let parse_member_types = |value: &Value| match value {
+        Value::String(never) if never == "never" => Some(None),
+        Value::Array(groups) => Some(Some(
+            groups
+                .iter()
+                .map(|group| match group {
+                    Value::Array(group) => {
+                        group.iter().filter_map(Value::as_str).map(CompactStr::from).collect()
+                    }
+                    group => group.as_str().map(CompactStr::from).into_iter().collect(),
+                })
+                .collect(),
+        )),
+        _ => None,
+    };
+    match value {
+        Value::Object(config) => Some(OrderConfig {
+            member_types: match config.get("memberTypes") {
+                Some(member_types) => parse_member_types(member_types)?,
+                None => Some(default_member_types()),
+            },
+            order: match config.get("order").and_then(Value::as_str) {
+                Some("alphabetically") => Order::Alphabetically,
+                Some("alphabetically-case-insensitive") => Order::AlphabeticallyCaseInsensitive,
+                Some("natural") => Order::Natural,
+                Some("natural-case-insensitive") => Order::NaturalCaseInsensitive,
+                _ => Order::AsWritten,
+            },
+        }),
+        value => {
+            Some(OrderConfig { member_types: parse_member_types(value)?, order: Order::AsWritten })
+        }
+    }
+}
+
+/// The default order of `@typescript-eslint/member-ordering`: signatures, fields, static
+/// blocks, constructors, accessors, getters and setters, then methods.
+fn default_member_types() -> Vec<Vec<CompactStr>> {
+    fn groups(member_type: &str) -> Vec<String> {
+        [
+            "public-static-",
+            "protected-static-",
+            "private-static-",
+            "#private-static-",
+            "public-decorated-",
+            "protected-decorated-",
+            "private-decorated-",
+            "public-instance-",
+            "protected-instance-",
+            "private-instance-",
+            "#private-instance-",
+            "public-abstract-",
+            "protected-abstract-",
+            "public-",
+            "protected-",
+            "private-",
+            "#private-",
+            "static-",
+            "instance-",
+            "abstract-",
+            "decorated-",
+            "",
+        ]
+        .iter()
+        .map(|prefix| format!("{prefix}{member_type}"))
+        .collect()
+    }
+
+    let single = |group: &str| vec![CompactStr::from(group)];
+    let mut order = vec![single("signature"), single("call-signature")];
+    order.extend(groups("field").iter().map(|group| single(group)));
+    order.push(single("static-initialization"));
+    order.extend(
+        ["public-constructor", "protected-constructor", "private-constructor", "constructor"]
+            .map(single),
+    );
+    order.extend(groups("accessor").iter().map(|group| single(group)));
+    // Getters and setters of the same kind share a rank, so pairs can be declared together.
+    order.extend(
+        groups("get")
+            .into_iter()
+            .zip(groups("set"))
+            .map(|(get, set)| vec![CompactStr::from(get), CompactStr::from(set)]),
+    );
+    order.extend(groups("method").iter().map(|group| single(group)));
+    order
+}
+
+#[derive(Debug, Clone)]
+struct Member<'a> {
+    span: Span,
+    name: Cow<'a, str>,
+    /// The member groups this member belongs to, from the most to the least specific.
+    groups: Vec<String>,
+}
+
+fn class_member<'a>(element: &ClassElement<'a>, ctx: &LintContext<'a>) -> Member<'a> {
+    let (member_type, key, accessibility, r#static, r#abstract, decorated) = match element {
+        ClassElement::StaticBlock(block) => {
+            return Member {
+                span: block.span,
+                name: Cow::Borrowed("static block"),
+                groups: vec!["static-initialization".to_string()],
+            };
+        }
+        ClassElement::TSIndexSignature(signature) => {
+            return Member {
+                span: signature.span,
+                name: index_signature_name(&signature.parameters),
+                groups: vec!["signature".to_string()],
+            };
+        }
+        ClassElement::MethodDefinition(method) => (
+            match method.kind {
+                MethodDefinitionKind::Constructor => "constructor",
+                MethodDefinitionKind::Get => "get",
+                MethodDefinitionKind::Set => "set",
+                MethodDefinitionKind::Method => "method",
+            },
+            &method.key,
+            method.accessibility,
+            method.r#static,
+            method.r#type.is_abstract(),
+            !method.decorators.is_empty(),
+        ),
+        ClassElement::PropertyDefinition(property) => (
+            match &property.value {
+                Some(
+                    Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_),
+                ) => "method",
+                _ => "field",
+            },
+            &property.key,
+            property.accessibility,
+            property.r#static,
+            property.r#type.is_abstract(),
+            !property.decorators.is_empty(),
+        ),
+        ClassElement::AccessorProperty(property) => (
+            "accessor",
+            &property.key,
+            property.accessibility,
+            property.r#static,
+            property.r#type.is_abstract(),
+            !property.decorators.is_empty(),
+        ),
+    };
+
+    let accessibility = if key.is_private_identifier() {
+        "#private"
+    } else {
+        match accessibility {
+            Some(TSAccessibility::Private) => "private",
+            Some(TSAccessibility::Protected) => "protected",
+            Some(TSAccessibility::Public) | None => "public",
+        }
+    };
+    let scope = if r#static {
+        "static"
+    } else if r#abstract {
+        "abstract"
+    } else {
+        "instance"
+    };
+
+    let mut groups = vec![];
+    if decorated && member_type != "constructor" {
+        groups.push(format!("{accessibility}-decorated-{member_type}"));
+        groups.push(format!("decorated-{member_type}"));
+    }
+    // Constructors have no scope.
+    if member_type != "constructor" {
+        groups.push(format!("{accessibility}-{scope}-{member_type}"));
+        groups.push(format!("{scope}-{member_type}"));
+    }
+    groups.push(format!("{accessibility}-{member_type}"));
+    groups.push(member_type.to_string());
+
+    let name = if member_type == "constructor" {
+        Cow::Borrowed("constructor")
+    } else {
+        key_name(key, ctx)
+    };
+    Member { span: element.span(), name, groups }
+}
+
+/// Interfaces and type literals have no modifiers, so members only belong to their type.
+fn signature_member<'a>(signature: &TSSignature<'a>, ctx: &LintContext<'a>) -> Member<'a> {
+    let (member_type, name) = match signature {
+        TSSignature::TSIndexSignature(signature) => {
+            ("signature", index_signature_name(&signature.parameters))
+        }
+        TSSignature::TSPropertySignature(property) => ("field", key_name(&property.key, ctx)),
+        TSSignature::TSCallSignatureDeclaration(_) => ("call-signature", Cow::Borrowed("call")),
+        TSSignature::TSConstructSignatureDeclaration(_) => ("constructor", Cow::Borrowed("new")),
+        TSSignature::TSMethodSignature(method) => (
+            match method.kind {
+                TSMethodSignatureKind::Method => "method",
+                TSMethodSignatureKind::Get => "get",
+                TSMethodSignatureKind::Set => "set",
+            },
+            key_name(&method.key, ctx),
+        ),
+    };
+    // The span of a signature includes its `,` or `;` separator, which should stay in place
+    // when members are swapped.
+    let span = signature.span();
+    let source = ctx.source_range(span);
+    let span = match source.strip_suffix([',', ';']) {
+        #[allow(clippy::cast_possible_truncation)]
+        Some(rest) => Span::sized(span.start, rest.trim_end().len() as u32),
+        None => span,
+    };
+    Member { span, name, groups: vec![member_type.to_string()] }
+}
+
+fn key_name<'a>(key: &PropertyKey<'a>, ctx: &LintContext<'a>) -> Cow<'a, str> {
+    match key {
+        PropertyKey::PrivateIdentifier(ident) => Cow::Owned(format!("#{}", ident.name)),
+        key => key.static_name().unwrap_or_else(|| Cow::Borrowed(ctx.source_range(key.span()))),
+    }
+}
+
+fn index_signature_name<'a>(parameters: &[TSIndexSignatureName<'a>]) -> Cow<'a, str> {
+    match parameters {
+        [parameter] => Cow::Borrowed(parameter.name.as_str()),
+        parameters => Cow::Owned(
+            parameters
+                .iter()
+                .map(|parameter| parameter.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+    }
+}
+
+struct Violation {
+    /// The index of the misplaced member.
+    index: usize,
+    kind: ViolationKind,
+}
+
+enum ViolationKind {
+    /// The member belongs before members of an earlier rank, described by `rank`.
+    GroupOrder { rank: String },
+    /// The member belongs before the member named `before` of the same rank.
+    Order { before: String },
+}
+
+impl OrderConfig {
+    /// Members are first checked to be in the order of their groups. Only when they are, the
+    /// members of each rank are checked to be sorted by name.
+    fn check(&self, members: &[Member]) -> Vec<Violation> {
+        let Some(member_types) = &self.member_types else {
+            return self.check_names(members, 0..members.len());
+        };
+
+        let mut violations = vec![];
+        let mut previous_ranks: Vec<usize> = vec![];
+        // Indices of the members of each rank, in the order they are declared.
+        let mut rank_groups: Vec<Vec<usize>> = vec![];
+        for (index, member) in members.iter().enumerate() {
+            let Some(rank) = member.groups.iter().find_map(|group| {
+                member_types.iter().position(|groups| groups.iter().any(|g| g == group))
+            }) else {
+                continue;
+            };
+            match previous_ranks.last() {
+                Some(&last) if rank < last => {
+                    let rank = lowest_rank(&previous_ranks, rank, member_types);
+                    violations.push(Violation { index, kind: ViolationKind::GroupOrder { rank } });
+                }
+                Some(&last) if rank == last => rank_groups.last_mut().unwrap().push(index),
+                _ => {
+                    previous_ranks.push(rank);
+                    rank_groups.push(vec![index]);
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            for group in rank_groups {
+                violations.extend(self.check_names(members, group));
+            }
+        }
+        violations
+    }
+
+    fn check_names(
+        &self,
+        members: &[Member],
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Vec<Violation> {
+        if self.order == Order::AsWritten {
+            return vec![];
+        }
+        let mut violations = vec![];
+        let mut previous: Option<&str> = None;
+        for index in indices {
+            let name = members[index].name.as_ref();
+            if let Some(before) = previous {
+                if self.compare(name, before) == Ordering::Less {
+                    let before = before.to_string();
+                    violations.push(Violation { index, kind: ViolationKind::Order { before } });
+                }
+            }
+            previous = Some(name);
+        }
+        violations
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self.order {
+            Order::AsWritten => Ordering::Equal,
+            Order::Alphabetically => a.cmp(b),
+            Order::AlphabeticallyCaseInsensitive => a.cow_to_lowercase().cmp(&b.cow_to_lowercase()),
+            Order::Natural => natural_cmp(a, b),
+            Order::NaturalCaseInsensitive => {
+                natural_cmp(&a.cow_to_lowercase(), &b.cow_to_lowercase())
+            }
+        }
+    }
+}
+
+/// The lowest rank above `target` that has been seen, as in "all public static field
+/// definitions".
+fn lowest_rank(ranks: &[usize], target: usize, member_types: &[Vec<CompactStr>]) -> String {
+    let lowest = ranks.iter().copied().filter(|&rank| rank > target).min().unwrap_or(target);
+    member_types[lowest]
+        .iter()
+        .map(|group| group.cow_replace('-', " ").into_owned())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Compares names with runs of digits compared by their value, so `a2` is before `a10`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(a_char), Some(b_char)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        if a_char.is_ascii_digit() && b_char.is_ascii_digit() {
+            let a_len = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+            let b_len = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+            let (a_digits, b_digits) =
+                (a[..a_len].trim_start_matches('0'), b[..b_len].trim_start_matches('0'));
+            match a_digits.len().cmp(&b_digits.len()).then_with(|| a_digits.cmp(b_digits)) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+            (a, b) = (&a[a_len..], &b[b_len..]);
+        } else {
+            match a_char.cmp(&b_char) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+            (a, b) = (&a[a_char.len_utf8()..], &b[b_char.len_utf8()..]);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+                class Foo {
+                    [key: string]: any;
+                    public static a = 1;
+                    protected static b = 2;
+                    private static c = 3;
+                    static #d = 4;
+                    public e = 5;
+                    protected f = 6;
+                    private g = 7;
+                    #h = 8;
+                    static {}
+                    constructor() {}
+                    get i() { return 1; }
+                    set i(value) {}
+                    public static j() {}
+                    k() {}
+                    l = () => {};
+                    #m() {}
+                }
+            ",
+            None,
+        ),
+        (
+            "
+                interface Foo {
+                    [key: string]: any;
+                    (): void;
+                    a: string;
+                    new (): Foo;
+                    b(): void;
+                }
+            ",
+            None,
+        ),
+        ("type Foo = { a: string; b(): void; };", None),
+        ("abstract class Foo { a = 1; abstract b: string; c() {} abstract d(): void; }", None),
+        ("class Foo { get a() { return 1; } set b(value) {} get c() { return 1; } }", None),
+        ("class Foo { @Dec() a = 1; b = 2; }", None),
+        ("class Foo { b() {} a = 1; }", Some(serde_json::json!([{ "default": "never" }]))),
+        (
+            "class Foo { constructor() {} a = 1; }",
+            Some(serde_json::json!([{ "default": ["constructor", "field"] }])),
+        ),
+        (
+            "class Foo { a() {} b = 1; constructor() {} }",
+            Some(serde_json::json!([{ "default": [["field", "method"], "constructor"] }])),
+        ),
+        // members of groups that are not in the order are not checked
+        ("class Foo { a() {} b = 1; }", Some(serde_json::json!([{ "default": ["constructor"] }]))),
+        (
+            "class Foo { private a = 1; public b = 2; }",
+            Some(serde_json::json!([{ "default": ["private-field", "public-field"] }])),
+        ),
+        (
+            "class Foo { static a() {} b() {} }",
+            Some(serde_json::json!([{ "default": ["static-method", "instance-method"] }])),
+        ),
+        (
+            "class Foo { a = 1; b = 2; c() {} d() {} }",
+            Some(serde_json::json!([{ "default": { "order": "alphabetically" } }])),
+        ),
+        (
+            "class Foo { d = 1; c() {} b = 2; a() {} }",
+            Some(
+                serde_json::json!([{ "default": { "memberTypes": "never", "order": "as-written" } }]),
+            ),
+        ),
+        (
+            "class Foo { a = 1; B = 2; c = 3; }",
+            Some(
+                serde_json::json!([{ "default": { "order": "alphabetically-case-insensitive" } }]),
+            ),
+        ),
+        (
+            "class Foo { a2 = 1; a10 = 2; }",
+            Some(
+                serde_json::json!([{ "default": { "memberTypes": "never", "order": "natural" } }]),
+            ),
+        ),
+        (
+            "interface Foo { b(): void; a: string; }",
+            Some(serde_json::json!([{ "interfaces": "never" }])),
+        ),
+        (
+            "const Foo = class { b() {} a = 1; }",
+            Some(serde_json::json!([{ "classExpressions": ["method", "field"] }])),
+        ),
+        (
+            "type Foo = { b(): void; a: string; }",
+            Some(serde_json::json!([{ "typeLiterals": ["method", "field"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("class Foo { constructor() {} a = 1; }", None),
+        ("class Foo { a() {} b = 1; }", None),
+        ("class Foo { a() {} constructor() {} b = 1; }", None),
+        ("class Foo { private a = 1; public b = 2; }", None),
+        ("class Foo { a = 1; static b = 2; }", None),
+        ("class Foo { #a = 1; b = 2; }", None),
+        ("class Foo { static {} a = 1; }", None),
+        ("class Foo { a() {} get b() { return 1; } }", None),
+        ("class Foo { a() {} b = () => {}; c = 1; }", None),
+        ("abstract class Foo { abstract a(): void; b = 1; }", None),
+        ("interface Foo { a(): void; b: string; }", None),
+        ("interface Foo { a: string; [key: string]: any; }", None),
+        ("type Foo = { new (): Foo; (): void; };", None),
+        ("const Foo = class { a() {} b = 1; }", None),
+        (
+            "class Foo { a = 1; constructor() {} }",
+            Some(serde_json::json!([{ "default": ["constructor", "field"] }])),
+        ),
+        (
+            "class Foo { a() {} constructor() {} }",
+            Some(serde_json::json!([{ "default": [["field", "constructor"], "method"] }])),
+        ),
+        (
+            "class Foo { b = 1; a = 2; }",
+            Some(serde_json::json!([{ "default": { "order": "alphabetically" } }])),
+        ),
+        (
+            "class Foo { a() {} B() {} }",
+            Some(
+                serde_json::json!([{ "default": { "memberTypes": "never", "order": "alphabetically" } }]),
+            ),
+        ),
+        (
+            "class Foo { a10 = 1; a2 = 2; }",
+            Some(
+                serde_json::json!([{ "default": { "memberTypes": "never", "order": "natural" } }]),
+            ),
+        ),
+        (
+            "interface Foo { b: string; a(): void; }",
+            Some(serde_json::json!([{ "interfaces": ["method", "field"] }])),
+        ),
+        (
+            "class Foo { b: string; a(): void {} }",
+            Some(
+                serde_json::json!([{ "interfaces": ["method", "field"], "classes": ["method", "field"] }]),
+            ),
+        ),
+    ];
+
+    let fix = vec![
+        ("class Foo { a() {} b = 1; }", "class Foo { b = 1; a() {} }", None),
+        (
+            "class Foo {\n  constructor() {}\n  // the field\n  a = 1\n}",
+            "class Foo {\n  a = 1\n  // the field\n  constructor() {}\n}",
+            None,
+        ),
+        (
+            "interface Foo { a(): void; b: string; }",
+            "interface Foo { b: string; a(): void; }",
+            None,
+        ),
+        ("type Foo = { a(): void, b: string };", "type Foo = { b: string, a(): void };", None),
+        (
+            "class Foo { b = 1; a = 2; c = 3; }",
+            "class Foo { a = 2; b = 1; c = 3; }",
+            Some(serde_json::json!([{ "default": { "order": "alphabetically" } }])),
+        ),
+        // more than one member is misplaced
+        ("class Foo { a() {} b = 1; c = 2; }", "class Foo { a() {} b = 1; c = 2; }", None),
+        // swapping with the member before does not fix the order
+        (
+            "class Foo { a() {} constructor() {} b = 1; }",
+            "class Foo { a() {} constructor() {} b = 1; }",
+            None,
+        ),
+    ];
+
+    Tester::new(MemberOrdering::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -82,7 +82,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoDuplicateEnumValues,
-    correctness
+    correctness,
+    node_types = [TSEnumDeclaration]
 );
 
 impl Rule for NoDuplicateEnumValues {
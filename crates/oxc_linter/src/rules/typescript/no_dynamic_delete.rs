@@ -25,6 +25,7 @@ declare_oxc_lint!(
     /// ```
     NoDynamicDelete,
     restriction,
+    node_types = [UnaryExpression]
 );
 
 fn no_dynamic_delete_diagnostic(span: Span) -> OxcDiagnostic {
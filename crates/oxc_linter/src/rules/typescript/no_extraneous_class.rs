@@ -59,7 +59,8 @@ declare_oxc_lint!(
     ///   abstract class Foo {}
     /// ```
     NoExtraneousClass,
-    suspicious
+    suspicious,
+    node_types = [Class]
 );
 
 fn empty_no_extraneous_class_diagnostic(span: Span) -> OxcDiagnostic {
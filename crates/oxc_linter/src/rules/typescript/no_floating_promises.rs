@@ -0,0 +1,369 @@
+use oxc_ast::{
+    ast::{CallExpression, ChainElement, Expression, IdentifierReference, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::PROMISE_STATIC_METHODS,
+    AstNode, TypeFacts,
+};
+
+fn no_floating_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.",
+    )
+    .with_help("Handle the promise, or mark it as intentionally unhandled with the `void` operator.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises(Box<NoFloatingPromisesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromisesConfig {
+    /// Allow promises that are discarded with the `void` operator.
+    ignore_void: bool,
+    /// Allow immediately invoked async functions.
+    ignore_iife: bool,
+    /// Treat calls of `.then`, `.catch` and `.finally` as promises.
+    check_thenables: bool,
+    /// Treat `new Promise()` and the static methods of `Promise` as promises.
+    check_promise_constructor: bool,
+    /// Treat calls of async functions declared in the same file as promises.
+    check_async_functions: bool,
+}
+
+impl Default for NoFloatingPromisesConfig {
+    fn default() -> Self {
+        Self {
+            ignore_void: true,
+            ignore_iife: false,
+            check_thenables: true,
+            check_promise_constructor: true,
+            check_async_functions: true,
+        }
+    }
+}
+
+impl std::ops::Deref for NoFloatingPromises {
+    type Target = NoFloatingPromisesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require promises created in statements to be handled appropriately.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise that is neither awaited nor given a rejection handler ("floating") can
+    /// fail silently, and the code after it runs before the promise has settled. This
+    /// often leads to unhandled rejections and operations that run in an unexpected order.
+    ///
+    /// ### Limitations
+    ///
+    /// Without type information, this rule only recognizes expressions that return a
+    /// promise syntactically:
+    ///
+    /// - calls of `.then()`, `.catch()` and `.finally()`
+    /// - `new Promise()` and calls of `Promise.resolve()`, `Promise.all()`, etc.
+    /// - calls of `async` functions that are declared in the same file, including
+    ///   immediately invoked ones
+    ///
+    /// Promises returned from imported functions, methods or any other call are only
+    /// reported when the linter is given a `TypeInfoProvider`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// async function fetchData() {}
+    /// fetchData();
+    ///
+    /// Promise.resolve('value').then(() => {});
+    ///
+    /// new Promise((resolve) => resolve());
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// async function fetchData() {}
+    /// await fetchData();
+    ///
+    /// Promise.resolve('value').then(() => {}, () => {});
+    ///
+    /// void new Promise((resolve) => resolve());
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///     "typescript/no-floating-promises": ["error", {
+    ///         "ignoreVoid": true,
+    ///         "ignoreIIFE": false,
+    ///         "checkThenables": true,
+    ///         "checkPromiseConstructor": true,
+    ///         "checkAsyncFunctions": true
+    ///     }]
+    /// }
+    /// ```
+    ///
+    /// - `ignoreVoid`: allow promises that are discarded with the `void` operator.
+    /// - `ignoreIIFE`: allow immediately invoked async functions.
+    /// - `checkThenables`: report calls of `.then()`, `.catch()` and `.finally()`.
+    /// - `checkPromiseConstructor`: report `new Promise()` and the static methods of
+    ///   `Promise`.
+    /// - `checkAsyncFunctions`: report calls of `async` functions declared in the same file.
+    NoFloatingPromises,
+    nursery,
+    suggestion,
+    node_types = [ExpressionStatement]
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = NoFloatingPromisesConfig::default();
+        let get_bool = |name: &str, default: bool| {
+            value
+                .get(0)
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self(Box::new(NoFloatingPromisesConfig {
+            ignore_void: get_bool("ignoreVoid", default.ignore_void),
+            ignore_iife: get_bool("ignoreIIFE", default.ignore_iife),
+            check_thenables: get_bool("checkThenables", default.check_thenables),
+            check_promise_constructor: get_bool(
+                "checkPromiseConstructor",
+                default.check_promise_constructor,
+            ),
+            check_async_functions: get_bool("checkAsyncFunctions", default.check_async_functions),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        let expr = stmt.expression.get_inner_expression();
+        if !self.is_unhandled_promise(expr, ctx) {
+            return;
+        }
+
+        let diagnostic = no_floating_promises_diagnostic(stmt.expression.span());
+        // `void` binds looser than calls, so it can be prepended to them as is.
+        if self.ignore_void
+            && matches!(expr, Expression::CallExpression(_) | Expression::NewExpression(_))
+        {
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                fixer.insert_text_before(&stmt.expression, "void ")
+            });
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+impl NoFloatingPromises {
+    fn is_unhandled_promise<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                !self.ignore_void && self.is_unhandled_promise(&unary.argument, ctx)
+            }
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.iter().any(|expr| self.is_unhandled_promise(expr, ctx))
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.is_unhandled_promise(&cond.consequent, ctx)
+                    || self.is_unhandled_promise(&cond.alternate, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_unhandled_promise(&logical.left, ctx)
+                    || self.is_unhandled_promise(&logical.right, ctx)
+            }
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => self.is_unhandled_call(call, ctx),
+                _ => false,
+            },
+            Expression::CallExpression(call) => self.is_unhandled_call(call, ctx),
+            Expression::NewExpression(new_expr) => {
+                (self.check_promise_constructor && is_global_promise(&new_expr.callee, ctx))
+                    || is_thenable(new_expr.span, ctx)
+            }
+            Expression::AwaitExpression(_) => false,
+            expr => is_thenable(expr.span(), ctx),
+        }
+    }
+
+    fn is_unhandled_call<'a>(&self, call: &CallExpression<'a>, ctx: &LintContext<'a>) -> bool {
+        let callee = call.callee.get_inner_expression();
+        if let Some(member) = callee.as_member_expression() {
+            let name = member.static_property_name();
+            if self.check_thenables {
+                match name {
+                    // The promise is handled if a rejection handler is passed.
+                    Some("then") => return call.arguments.len() < 2,
+                    Some("catch") => return call.arguments.is_empty(),
+                    Some("finally") => return self.is_unhandled_promise(member.object(), ctx),
+                    _ => {}
+                }
+            }
+            if self.check_promise_constructor
+                && name.is_some_and(|name| {
+                    name != "withResolvers" && PROMISE_STATIC_METHODS.contains(name)
+                })
+                && is_global_promise(member.object(), ctx)
+            {
+                return true;
+            }
+        } else {
+            let is_async = match callee {
+                Expression::ArrowFunctionExpression(arrow) => {
+                    if self.ignore_iife {
+                        return false;
+                    }
+                    arrow.r#async
+                }
+                Expression::FunctionExpression(func) => {
+                    if self.ignore_iife {
+                        return false;
+                    }
+                    func.r#async
+                }
+                Expression::Identifier(ident) => is_async_function_reference(ident, ctx),
+                _ => false,
+            };
+            if self.check_async_functions && is_async {
+                return true;
+            }
+        }
+        is_thenable(call.span, ctx)
+    }
+}
+
+/// Whether the type checker knows the expression at `span` to be a promise.
+fn is_thenable(span: Span, ctx: &LintContext) -> bool {
+    ctx.type_info()
+        .and_then(|type_info| type_info.expression_type(span))
+        .is_some_and(TypeFacts::is_thenable)
+}
+
+fn is_global_promise(expr: &Expression, ctx: &LintContext) -> bool {
+    matches!(
+        expr.get_inner_expression(),
+        Expression::Identifier(ident)
+            if ident.name == "Promise" && ctx.semantic().is_reference_to_global_variable(ident)
+    )
+}
+
+/// Whether `ident` refers to an `async` function declaration, or to a variable that is
+/// initialized with an `async` function and never reassigned.
+fn is_async_function_reference(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let symbols = ctx.symbols();
+    let Some(symbol_id) = ident.reference_id().and_then(|id| symbols.get_reference(id).symbol_id())
+    else {
+        return false;
+    };
+    match ctx.symbol_declaration(symbol_id).kind() {
+        AstKind::Function(func) => func.r#async && func.is_declaration(),
+        AstKind::VariableDeclarator(declarator) => {
+            let is_async = match declarator.init.as_ref().map(Expression::get_inner_expression) {
+                Some(Expression::ArrowFunctionExpression(arrow)) => arrow.r#async,
+                Some(Expression::FunctionExpression(func)) => func.r#async,
+                _ => false,
+            };
+            is_async
+                && symbols.get_resolved_references(symbol_id).all(|reference| !reference.is_write())
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function test() { await Promise.resolve('value'); }", None),
+        ("async function test() { void Promise.resolve('value'); }", None),
+        ("Promise.resolve('value').then(() => {}, () => {});", None),
+        ("Promise.resolve('value').catch(() => {});", None),
+        ("Promise.resolve('value').then(() => {}).catch(() => {});", None),
+        ("Promise.resolve('value').catch(() => {}).finally(() => {});", None),
+        ("Promise.reject(new Error('message')).then(() => {}, () => {});", None),
+        ("Promise.all([]).catch(() => {});", None),
+        ("const promise = Promise.resolve('value');", None),
+        ("function foo() { return Promise.resolve('value'); }", None),
+        ("async function test() { return Promise.resolve('value'); }", None),
+        ("const Promise = { resolve() {} }; Promise.resolve('value');", None),
+        ("function foo(Promise: any) { new Promise(() => {}); }", None),
+        ("Promise.withResolvers();", None),
+        ("function doSomething() {} doSomething();", None),
+        ("const doSomething = () => {}; doSomething();", None),
+        ("async function doSomething() {} await doSomething();", None),
+        ("async function doSomething() {} doSomething().catch(() => {});", None),
+        ("async function doSomething() {} void doSomething();", None),
+        ("let doSomething = async () => {}; doSomething = () => {}; doSomething();", None),
+        ("(function () {})();", None),
+        ("(async () => { await Promise.resolve(1); })();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("(async function () {})();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("import { fetchData } from './api'; fetchData();", None),
+        ("foo.bar();", None),
+        ("promise?.then(() => {}, () => {});", None),
+        ("cond ? Promise.resolve(1).catch(() => {}) : null;", None),
+        ("Promise.resolve(1).then(() => {});", Some(json!([{ "checkThenables": false }]))),
+        ("new Promise(() => {});", Some(json!([{ "checkPromiseConstructor": false }]))),
+        ("async function foo() {} foo();", Some(json!([{ "checkAsyncFunctions": false }]))),
+    ];
+
+    let fail = vec![
+        ("async function test() { Promise.resolve('value'); }", None),
+        ("Promise.resolve('value').then(() => {});", None),
+        ("Promise.resolve('value').catch();", None),
+        ("Promise.resolve('value').finally(() => {});", None),
+        ("Promise.resolve('value').then(() => {}).finally(() => {});", None),
+        ("Promise.all([p1, p2]);", None),
+        ("Promise.reject(new Error('message'));", None),
+        ("new Promise((resolve) => resolve());", None),
+        ("(Promise.resolve('value') as Promise<string>);", None),
+        ("promise.then(() => {});", None),
+        ("promise?.then(() => {});", None),
+        ("async function doSomething() {} doSomething();", None),
+        ("const doSomething = async () => {}; doSomething();", None),
+        ("const doSomething = async function () {}; doSomething();", None),
+        ("(async () => { await Promise.resolve(1); })();", None),
+        ("(async function () {})();", None),
+        ("cond ? Promise.resolve(1) : null;", None),
+        ("foo && Promise.resolve(1);", None),
+        ("foo(), Promise.resolve(1);", None),
+        ("void Promise.resolve('value');", Some(json!([{ "ignoreVoid": false }]))),
+    ];
+
+    let fix = vec![
+        ("Promise.resolve('value');", "void Promise.resolve('value');"),
+        (
+            "async function doSomething() {} doSomething();",
+            "async function doSomething() {} void doSomething();",
+        ),
+        ("promise.then(() => {});", "void promise.then(() => {});"),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -63,7 +63,8 @@ declare_oxc_lint!(
     /// ```
     NoImportTypeSideEffects,
     restriction,
-    fix
+    fix,
+    node_types = [ImportDeclaration]
 );
 
 impl Rule for NoImportTypeSideEffects {
@@ -18,12 +18,18 @@ fn no_namespace_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoNamespace {
     allow_declarations: bool,
     allow_definition_files: bool,
 }
 
+impl Default for NoNamespace {
+    fn default() -> Self {
+        Self { allow_declarations: false, allow_definition_files: true }
+    }
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Disallow TypeScript namespaces.
@@ -40,6 +46,15 @@ declare_oxc_lint!(
     /// declare module foo {}
     /// declare namespace foo {}
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "typescript/no-namespace": ["error", { "allowDeclarations": false, "allowDefinitionFiles": true }] }
+    /// ```
+    ///
+    /// - `allowDeclarations`: allow `declare` with custom modules and namespaces.
+    /// - `allowDefinitionFiles`: allow custom modules and namespaces in `.d.ts` files.
     NoNamespace,
     restriction
 );
@@ -56,7 +71,7 @@ impl Rule for NoNamespace {
                 .get(0)
                 .and_then(|x| x.get("allowDefinitionFiles"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
+                .unwrap_or(true),
         }
     }
 
@@ -100,7 +115,7 @@ impl Rule for NoNamespace {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        if self.allow_definition_files && ctx.source_type().is_typescript_definition() {
+        if self.allow_definition_files && ctx.is_declaration_file() {
             return false;
         }
         ctx.source_type().is_typescript()
@@ -358,4 +373,20 @@ fn test() {
     ];
 
     Tester::new(NoNamespace::NAME, pass, fail).test_and_snapshot();
+
+    let pass = vec![
+        ("declare namespace foo {}", None),
+        ("export namespace foo {}", None),
+        ("declare module foo {}", Some(serde_json::json!([{ "allowDefinitionFiles": true }]))),
+    ];
+    let fail = vec![
+        ("export namespace foo {}", Some(serde_json::json!([{ "allowDefinitionFiles": false }]))),
+        (
+            "declare module foo {}",
+            Some(
+                serde_json::json!([{ "allowDeclarations": false, "allowDefinitionFiles": false }]),
+            ),
+        ),
+    ];
+    Tester::new(NoNamespace::NAME, pass, fail).change_rule_path("test.d.ts").test();
 }
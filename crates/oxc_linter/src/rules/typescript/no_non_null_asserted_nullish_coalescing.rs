@@ -29,6 +29,7 @@ declare_oxc_lint!(
     /// ```
     NoNonNullAssertedNullishCoalescing,
     restriction,
+    node_types = [LogicalExpression]
 );
 
 fn no_non_null_asserted_nullish_coalescing_diagnostic(span: Span) -> OxcDiagnostic {
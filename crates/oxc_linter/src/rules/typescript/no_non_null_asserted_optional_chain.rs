@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// foo?.bar()!;
     /// ```
     NoNonNullAssertedOptionalChain,
-    correctness
+    correctness,
+    node_types = [TSNonNullExpression]
 );
 
 impl Rule for NoNonNullAssertedOptionalChain {
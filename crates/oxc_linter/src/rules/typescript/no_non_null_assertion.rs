@@ -27,6 +27,7 @@ declare_oxc_lint!(
     /// ```
     NoNonNullAssertion,
     restriction,
+    node_types = [TSNonNullExpression]
 );
 
 fn no_non_null_assertion_diagnostic(span: Span) -> OxcDiagnostic {
@@ -0,0 +1,390 @@
+use oxc_ast::{
+    ast::{
+        Argument, BindingIdentifier, BindingPatternKind, CallExpression, PropertyKey,
+        StringLiteral, TSModuleReference,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::Reference;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::{
+    ast_util::is_global_require_call,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    AstNode,
+};
+
+fn no_require_imports_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("A `require()` style import is forbidden.")
+        .with_help("Use an ES module `import` instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRequireImports(Box<NoRequireImportsConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRequireImportsConfig {
+    /// Regular expressions of module specifiers that may be required, e.g. `"\\.json$"`.
+    #[schemars(with = "Vec<String>")]
+    #[serde(deserialize_with = "deserialize_patterns")]
+    allow: Vec<Regex>,
+    /// Allow `import foo = require("foo")`, which TypeScript type checks like an `import`.
+    allow_as_import: bool,
+}
+
+/// Compile the patterns in `allow`, skipping invalid ones like
+/// [`Rule::from_configuration`] does. They are reported by
+/// [`Rule::validate_configuration`].
+fn deserialize_patterns<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Regex>, D::Error> {
+    let patterns = Vec::<String>::deserialize(deserializer)?;
+    Ok(patterns.iter().filter_map(|pattern| Regex::new(pattern).ok()).collect())
+}
+
+impl std::ops::Deref for NoRequireImports {
+    type Target = NoRequireImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `require()` calls and `import foo = require("foo")` in TypeScript ES modules.
+    ///
+    /// CommonJS files, such as `.cts` files, cannot import modules otherwise and
+    /// are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing `require()` with `import` in an ES module is confusing, and the
+    /// value returned by `require()` is typed as `any`, so its uses are not
+    /// type checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const lib1 = require("lib1");
+    /// const { lib2 } = require("lib2");
+    /// import lib3 = require("lib3");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// import * as lib1 from "lib1";
+    /// import { lib2 } from "lib2";
+    /// import * as lib3 from "lib3";
+    /// ```
+    ///
+    /// Top level declarations and statements such as `const { lib2 } = require("lib2")`
+    /// have a suggestion converting them to an `import`. With `allowAsImport`,
+    /// `const lib1 = require("lib1")` is converted to `import lib1 = require("lib1")`.
+    ///
+    /// ### Options
+    ///
+    /// `allow` takes regular expressions of module specifiers that may be required,
+    /// and `allowAsImport` allows `import foo = require("foo")`:
+    ///
+    /// ```json
+    /// { "typescript/no-require-imports": ["error", { "allow": ["/package\\.json$"], "allowAsImport": true }] }
+    /// ```
+    NoRequireImports,
+    restriction,
+    suggestion,
+    config = NoRequireImportsConfig,
+    node_types = [CallExpression, TSImportEqualsDeclaration]
+);
+
+impl Rule for NoRequireImports {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        Self(Box::new(NoRequireImportsConfig {
+            allow: config
+                .and_then(|config| config.get("allow"))
+                .and_then(Value::as_array)
+                .map(|allow| {
+                    allow
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(|pattern| Regex::new(pattern).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            allow_as_import: config
+                .and_then(|config| config.get("allowAsImport"))
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn validate_configuration(value: &Value) -> Vec<(usize, String)> {
+        value
+            .get(0)
+            .and_then(|config| config.get("allow"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(|pattern| {
+                let error = Regex::new(pattern).err()?;
+                Some((0, format!("invalid pattern in `allow`: {error}")))
+            })
+            .collect()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                let source = match call.arguments.first() {
+                    Some(Argument::StringLiteral(source)) => Some(source.as_ref()),
+                    _ => None,
+                };
+                if source.is_some_and(|source| self.is_allowed(source)) {
+                    return;
+                }
+                let diagnostic = no_require_imports_diagnostic(call.span);
+                match source.and_then(|source| self.import_replacement(node, call, source, ctx)) {
+                    Some((span, import)) => ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        fixer.replace(span, import)
+                    }),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+            AstKind::TSImportEqualsDeclaration(decl) => {
+                if self.allow_as_import || decl.import_kind.is_type() {
+                    return;
+                }
+                let TSModuleReference::ExternalModuleReference(reference) = &decl.module_reference
+                else {
+                    return;
+                };
+                if self.is_allowed(&reference.expression) {
+                    return;
+                }
+                // No suggestion: `import * as foo` can't call or construct a module
+                // whose `module.exports` is a function or class.
+                ctx.diagnostic(no_require_imports_diagnostic(reference.span));
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript() && ctx.source_type().is_module()
+    }
+}
+
+impl NoRequireImports {
+    fn is_allowed(&self, source: &StringLiteral) -> bool {
+        self.allow.iter().any(|regex| regex.is_match(&source.value))
+    }
+
+    /// The span of the top level statement containing `require_call`, and an `import` that
+    /// replaces it, for `require("foo")`, `const foo = require("foo")` and
+    /// `const { foo, bar: baz } = require("foo")`.
+    fn import_replacement(
+        &self,
+        node: &AstNode,
+        require_call: &CallExpression,
+        source: &StringLiteral,
+        ctx: &LintContext,
+    ) -> Option<(Span, String)> {
+        let source = source.span.source_text(ctx.source_text());
+        let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+        match parents.next()?.kind() {
+            AstKind::ExpressionStatement(stmt) => {
+                matches!(parents.next()?.kind(), AstKind::Program(_))
+                    .then(|| (stmt.span, format!("import {source};")))
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                if declarator.id.type_annotation.is_some()
+                    || declarator.definite
+                    || declarator.init.as_ref().map(GetSpan::span) != Some(require_call.span)
+                {
+                    return None;
+                }
+                let AstKind::VariableDeclaration(decl) = parents.next()?.kind() else {
+                    return None;
+                };
+                if decl.declare
+                    || decl.declarations.len() != 1
+                    || !matches!(parents.next()?.kind(), AstKind::Program(_))
+                {
+                    return None;
+                }
+                // Imports can't be reassigned.
+                let is_reassigned = |ident: &BindingIdentifier| {
+                    ident.symbol_id.get().is_some_and(|symbol_id| {
+                        ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write)
+                    })
+                };
+                let import = match &declarator.id.kind {
+                    // `import foo = require("foo")` is exactly equivalent, when it is allowed.
+                    // `import * as foo` isn't: it can't be called when `module.exports` is a
+                    // function.
+                    BindingPatternKind::BindingIdentifier(ident)
+                        if self.allow_as_import && !is_reassigned(ident) =>
+                    {
+                        format!("import {} = require({source});", ident.name)
+                    }
+                    BindingPatternKind::ObjectPattern(object) if object.rest.is_none() => {
+                        let specifiers = object
+                            .properties
+                            .iter()
+                            .map(|property| {
+                                let (PropertyKey::StaticIdentifier(key), false) =
+                                    (&property.key, property.computed)
+                                else {
+                                    return None;
+                                };
+                                let BindingPatternKind::BindingIdentifier(local) =
+                                    &property.value.kind
+                                else {
+                                    return None;
+                                };
+                                if is_reassigned(local) {
+                                    return None;
+                                }
+                                Some(if key.name == local.name {
+                                    local.name.to_string()
+                                } else {
+                                    format!("{} as {}", key.name, local.name)
+                                })
+                            })
+                            .collect::<Option<Vec<_>>>()?;
+                        format!("import {{ {} }} from {source};", specifiers.join(", "))
+                    }
+                    _ => return None,
+                };
+                Some((decl.span, import))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::{TestCase, Tester};
+
+    let pass = vec![
+        ("import { l } from 'lib';", None),
+        ("var lib3 = load?.('not_an_import');", None),
+        ("import lib2 = lib;", None),
+        ("import type lib = require('lib');", None),
+        (
+            "
+                import { createRequire } from 'module';
+                const require = createRequire(import.meta.url);
+                require('remark-preset-prettier');
+            ",
+            None,
+        ),
+        ("function f(require) { require('foo'); }", None),
+        (
+            "const pkg = require('./package.json');",
+            Some(serde_json::json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "const pkg = require('../package.json');",
+            Some(serde_json::json!([{ "allow": ["/package\\.json$", "^foo$"] }])),
+        ),
+        ("import foo = require('foo');", Some(serde_json::json!([{ "allowAsImport": true }]))),
+        ("require.resolve('foo');", None),
+    ];
+
+    let fail = vec![
+        ("var lib = require('lib');", None),
+        ("let lib2 = require('lib2');", None),
+        ("var lib5 = require('lib5'), lib6 = require('lib6');", None),
+        ("import lib8 = require('lib8');", None),
+        ("export import lib9 = require('lib9');", None),
+        ("var lib3 = load(require('not_an_import'));", None),
+        ("const { foo, bar: baz } = require('foo');", None),
+        ("require('side-effect');", None),
+        ("require?.('side-effect');", None),
+        ("function f() { return require('lazy'); }", None),
+        ("const lib = require(name);", None),
+        (
+            "const pkg = require('./package.jsonc');",
+            Some(serde_json::json!([{ "allow": ["/package\\.json$"] }])),
+        ),
+        (
+            "const pkg = require('./package.json');",
+            Some(serde_json::json!([{ "allowAsImport": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var lib = require('lib');", "var lib = require('lib');", None),
+        (
+            "const { foo, bar: baz } = require(\"foo\");",
+            "import { foo, bar as baz } from \"foo\";",
+            None,
+        ),
+        ("require('side-effect');", "import 'side-effect';", None),
+        ("import lib8 = require('lib8');", "import lib8 = require('lib8');", None),
+        (
+            "const { foo } = require('foo'); const lib = require('lib');",
+            "import { foo } from 'foo'; const lib = require('lib');",
+            None,
+        ),
+        (
+            "const lib = require('lib');",
+            "import lib = require('lib');",
+            Some(serde_json::json!([{ "allowAsImport": true }])),
+        ),
+        (
+            "let lib = require('lib'); lib = 1;",
+            "let lib = require('lib'); lib = 1;",
+            Some(serde_json::json!([{ "allowAsImport": true }])),
+        ),
+        ("let { foo } = require('foo'); foo++;", "let { foo } = require('foo'); foo++;", None),
+        ("const { [key]: foo } = require('foo');", "const { [key]: foo } = require('foo');", None),
+        (
+            "const { foo, ...rest } = require('foo');",
+            "const { foo, ...rest } = require('foo');",
+            None,
+        ),
+        ("const lib: Lib = require('lib');", "const lib: Lib = require('lib');", None),
+        ("const a = require('a'), b = 1;", "const a = require('a'), b = 1;", None),
+        ("const a = require('a').b;", "const a = require('a').b;", None),
+        (
+            "function f() { const a = require('a'); }",
+            "function f() { const a = require('a'); }",
+            None,
+        ),
+    ];
+
+    // CommonJS files are not checked
+    let cts = Some(PathBuf::from("index.cts"));
+    let pass = pass
+        .into_iter()
+        .map(TestCase::from)
+        .chain([
+            ("const lib = require('lib');", None, None, cts.clone()).into(),
+            ("import lib = require('lib');", None, None, cts).into(),
+        ])
+        .collect();
+    let fail = fail.into_iter().map(TestCase::from).collect();
+
+    Tester::new(NoRequireImports::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+
+    let config = serde_json::json!([{ "allow": ["\\.json$", "("] }]);
+    assert_eq!(NoRequireImports::validate_configuration(&config).len(), 1);
+}
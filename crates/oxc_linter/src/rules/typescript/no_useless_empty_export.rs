@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessEmptyExport,
     correctness,
-    fix
+    fix,
+    node_types = [ExportNamedDeclaration]
 );
 
 impl Rule for NoUselessEmptyExport {
@@ -1,6 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{BindingPatternKind, CallExpression, Expression, VariableDeclaration},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::Reference;
 use oxc_span::{GetSpan, Span};
 
 use crate::{
@@ -33,8 +37,12 @@ declare_oxc_lint!(
     /// const foo = require('foo');
     /// let foo = require('foo');
     /// ```
+    ///
+    /// A top level `const foo = require('foo')` can be replaced with the equivalent
+    /// `import foo = require('foo')`, which is offered as a suggestion.
     NoVarRequires,
-    restriction
+    restriction,
+    suggestion
 );
 
 impl Rule for NoVarRequires {
@@ -65,8 +73,18 @@ impl Rule for NoVarRequires {
 
             // If this is an expression statement, it means the `require()`'s return value is unused.
             // If the return value is unused, this isn't a problem.
-            if !is_expression_statement {
-                ctx.diagnostic(no_var_requires_diagnostic(node.kind().span()));
+            if is_expression_statement {
+                return;
+            }
+
+            let diagnostic = no_var_requires_diagnostic(node.kind().span());
+            if let Some((decl, name)) = import_equals_candidate(node, expr, ctx) {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    let require = fixer.source_range(expr.span);
+                    fixer.replace(decl.span, format!("import {name} = {require};"))
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
             }
         }
     }
@@ -76,6 +94,45 @@ impl Rule for NoVarRequires {
     }
 }
 
+/// The top level declaration `const foo = require('foo')` that `require_call` initializes,
+/// which can be replaced with `import foo = require('foo')`, and the name it declares.
+fn import_equals_candidate<'a, 'b>(
+    node: &AstNode<'a>,
+    require_call: &CallExpression<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<(&'b VariableDeclaration<'a>, &'a str)> {
+    if !matches!(require_call.arguments[0].as_expression(), Some(Expression::StringLiteral(_))) {
+        return None;
+    }
+    let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+    let AstKind::VariableDeclarator(declarator) = parents.next()?.kind() else {
+        return None;
+    };
+    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+        return None;
+    };
+    if declarator.id.type_annotation.is_some() || declarator.definite {
+        return None;
+    }
+    // Imports can't be reassigned.
+    let symbol_id = ident.symbol_id.get()?;
+    if ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write) {
+        return None;
+    }
+    let AstKind::VariableDeclaration(decl) = parents.next()?.kind() else {
+        return None;
+    };
+    if decl.declare || decl.declarations.len() != 1 {
+        return None;
+    }
+    // `import foo = require('foo')` is only allowed at the top level of a module or namespace.
+    matches!(
+        parents.next()?.kind(),
+        AstKind::Program(_) | AstKind::ExportNamedDeclaration(_) | AstKind::TSModuleBlock(_)
+    )
+    .then_some((decl, ident.name.as_str()))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -117,5 +174,25 @@ fn test() {
         ",
     ];
 
-    Tester::new(NoVarRequires::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("const foo = require('foo');", "import foo = require('foo');"),
+        ("var foo = require('foo')", "import foo = require('foo');"),
+        ("let foo = require(\"foo\"); foo();", "import foo = require(\"foo\"); foo();"),
+        ("export const foo = require('foo');", "export import foo = require('foo');"),
+        (
+            "namespace Foo { const foo = require('foo'); }",
+            "namespace Foo { import foo = require('foo'); }",
+        ),
+        ("const foo: Foo = require('foo');", "const foo: Foo = require('foo');"),
+        ("const { foo } = require('foo');", "const { foo } = require('foo');"),
+        ("const foo = require('foo'), bar = 1;", "const foo = require('foo'), bar = 1;"),
+        ("const foo = require(path);", "const foo = require(path);"),
+        ("let foo = require('foo'); foo = 1;", "let foo = require('foo'); foo = 1;"),
+        (
+            "function f() { const foo = require('foo'); }",
+            "function f() { const foo = require('foo'); }",
+        ),
+    ];
+
+    Tester::new(NoVarRequires::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// ```
     PreferEnumInitializers,
     pedantic,
-    pending
+    pending,
+    node_types = [TSEnumDeclaration]
 );
 
 impl Rule for PreferEnumInitializers {
@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// ```
     PreferForOf,
     style,
-    pending
+    pending,
+    node_types = [ForStatement]
 );
 
 trait SpanExt {
@@ -128,6 +128,15 @@ fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>)
                 }
 
                 match node.kind() {
+                    // `export default type Foo = ...` is not valid, so there is no fix
+                    AstKind::TSInterfaceDeclaration(_)
+                        if matches!(
+                            ctx.nodes().parent_kind(node.id()),
+                            Some(AstKind::ExportDefaultDeclaration(_))
+                        ) =>
+                    {
+                        ctx.diagnostic(prefer_function_type_diagnostic(&suggestion, decl.span));
+                    }
                     AstKind::TSInterfaceDeclaration(interface_decl) => {
                         if let Some(type_parameters) = &interface_decl.type_parameters {
                             ctx.diagnostic_with_fix(
@@ -514,6 +523,11 @@ fn test() {
 
     let fix = vec![
         ("interface Foo { (): string; }", "type Foo = () => string;", None),
+        (
+            "export default interface Foo { (): string; }",
+            "export default interface Foo { (): string; }",
+            None,
+        ),
         (
             r"
 interface Foo {
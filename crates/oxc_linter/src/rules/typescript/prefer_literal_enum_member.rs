@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferLiteralEnumMember,
-    restriction
+    restriction,
+    node_types = [TSEnumMember]
 );
 
 impl Rule for PreferLiteralEnumMember {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// ```
     PreferNamespaceKeyword,
     style,
-    fix
+    fix,
+    node_types = [TSModuleDeclaration]
 );
 
 fn is_valid_module(module: &TSModuleDeclaration) -> bool {
@@ -43,22 +43,21 @@ declare_oxc_lint!(
     /// ```
     PreferTsExpectError,
     pedantic,
-    fix
+    fix,
+    node_types = []
 );
 
 impl Rule for PreferTsExpectError {
     fn run_once(&self, ctx: &LintContext) {
-        let comments = ctx.semantic().comments();
-
-        for comment in comments {
-            let raw = comment.span.source_text(ctx.semantic().source_text());
+        for comment in ctx.comments() {
+            let raw = ctx.comment_text(comment);
 
             if !is_valid_ts_ignore_present(*comment, raw) {
                 continue;
             }
 
             if comment.is_line() {
-                let comment_span = Span::new(comment.span.start - 2, comment.span.end);
+                let comment_span = comment.real_span();
                 ctx.diagnostic_with_fix(prefer_ts_expect_error_diagnostic(comment_span), |fixer| {
                     fixer.replace(
                         comment_span,
@@ -66,7 +65,7 @@ impl Rule for PreferTsExpectError {
                     )
                 });
             } else {
-                let comment_span = Span::new(comment.span.start - 2, comment.span.end + 2);
+                let comment_span = comment.real_span();
                 ctx.diagnostic_with_fix(prefer_ts_expect_error_diagnostic(comment_span), |fixer| {
                     fixer.replace(
                         comment_span,
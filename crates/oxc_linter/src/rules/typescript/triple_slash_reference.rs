@@ -1,7 +1,4 @@
-use oxc_ast::{
-    ast::{Statement, TSModuleReference},
-    AstKind,
-};
+use oxc_ast::ast::{Statement, TSModuleReference};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -68,7 +65,8 @@ declare_oxc_lint!(
     /// globalThis.value;
     /// ```
     TripleSlashReference,
-    correctness
+    correctness,
+    node_types = []
 );
 
 impl Rule for TripleSlashReference {
@@ -104,10 +102,7 @@ impl Rule for TripleSlashReference {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let Some(root) = ctx.nodes().root_node() else {
-            return;
-        };
-        let AstKind::Program(program) = root.kind() else { unreachable!() };
+        let program = ctx.program();
 
         // We don't need to iterate over all comments since Triple-slash directives are only valid at the top of their containing file.
         // We are trying to get the first statement start potioin, falling back to the program end if statement does not exist
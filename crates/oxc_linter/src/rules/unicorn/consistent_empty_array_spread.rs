@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     ConsistentEmptyArraySpread,
     pedantic,
-    suggestion
+    suggestion,
+    node_types = [ConditionalExpression]
 );
 
 impl Rule for ConsistentEmptyArraySpread {
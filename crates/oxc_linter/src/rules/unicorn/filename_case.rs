@@ -99,7 +99,8 @@ declare_oxc_lint!(
     /// - `SomeFileName.Test.js`
     /// - `SomeFileName.TestUtils.js`
     FilenameCase,
-    style
+    style,
+    node_types = []
 );
 
 impl Rule for FilenameCase {
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// console.log(message);
     /// ```
     NoAbusiveEslintDisable,
-    restriction
+    restriction,
+    node_types = []
 );
 
 impl Rule for NoAbusiveEslintDisable {
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     NoArrayForEach,
     restriction,
-    pending
+    pending,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoArrayForEach {
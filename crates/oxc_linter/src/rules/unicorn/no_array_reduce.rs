@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// array.reduceRight(reducer, initialValue);
     /// ```
     NoArrayReduce,
-    restriction
+    restriction,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoArrayReduce {
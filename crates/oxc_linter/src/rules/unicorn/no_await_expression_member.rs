@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     NoAwaitExpressionMember,
     style,
-    pending
+    pending,
+    node_types = [MemberExpression]
 );
 
 impl Rule for NoAwaitExpressionMember {
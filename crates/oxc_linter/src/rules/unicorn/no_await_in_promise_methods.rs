@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoAwaitInPromiseMethods,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoAwaitInPromiseMethods {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoConsoleSpaces,
     style,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoConsoleSpaces {
@@ -59,7 +59,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoDocumentCookie,
-    correctness
+    correctness,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for NoDocumentCookie {
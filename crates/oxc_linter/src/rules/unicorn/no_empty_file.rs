@@ -1,4 +1,3 @@
-use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -34,6 +33,7 @@ declare_oxc_lint!(
     ///
     NoEmptyFile,
     correctness,
+    node_types = []
 );
 
 impl Rule for NoEmptyFile {
@@ -45,10 +45,7 @@ impl Rule for NoEmptyFile {
         {
             return;
         }
-        let Some(root) = ctx.nodes().root_node() else {
-            return;
-        };
-        let AstKind::Program(program) = root.kind() else { unreachable!() };
+        let program = ctx.program();
 
         if program.body.iter().any(|node| !is_empty_stmt(node)) {
             return;
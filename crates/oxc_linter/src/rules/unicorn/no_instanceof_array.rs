@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```
     NoInstanceofArray,
     pedantic,
-    fix
+    fix,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for NoInstanceofArray {
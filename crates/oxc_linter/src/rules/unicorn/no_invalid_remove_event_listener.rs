@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// el.removeEventListener('click', handler.bind(this));
     /// ```
     NoInvalidRemoveEventListener,
-    correctness
+    correctness,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoInvalidRemoveEventListener {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// ```
     NoLengthAsSliceEnd,
     restriction,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoLengthAsSliceEnd {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// if (foo && bar) baz();
     /// ```
     NoLonelyIf,
-    pedantic
+    pedantic,
+    node_types = [IfStatement]
 );
 
 impl Rule for NoLonelyIf {
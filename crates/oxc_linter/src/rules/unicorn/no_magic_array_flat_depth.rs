@@ -43,6 +43,7 @@ declare_oxc_lint!(
     /// ```
     NoMagicArrayFlatDepth,
     restriction,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoMagicArrayFlatDepth {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// ```
     NoNewArray,
     correctness,
-    pending
+    pending,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewArray {
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     NoNewBuffer,
     pedantic,
-    pending
+    pending,
+    node_types = [NewExpression]
 );
 
 impl Rule for NoNewBuffer {
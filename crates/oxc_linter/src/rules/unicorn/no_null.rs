@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// ```
     NoNull,
     style,
-    conditional_fix
+    conditional_fix,
+    node_types = [NullLiteral]
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// function foo({a = false} = {}) {}
     /// ```
     NoObjectAsDefaultParameter,
-    pedantic
+    pedantic,
+    node_types = [AssignmentPattern]
 );
 
 impl Rule for NoObjectAsDefaultParameter {
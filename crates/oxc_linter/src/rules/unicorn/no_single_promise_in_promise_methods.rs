@@ -56,7 +56,8 @@ declare_oxc_lint!(
     ///
     NoSinglePromiseInPromiseMethods,
     correctness,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoSinglePromiseInPromiseMethods {
@@ -110,7 +111,23 @@ impl Rule for NoSinglePromiseInPromiseMethods {
                 let call_span = call_expr.span;
 
                 if is_directly_in_await {
-                    fixer.replace(call_span, elem_text)
+                    // e.g. `await (yield x)`
+                    let needs_parens = matches!(
+                        first.as_expression(),
+                        Some(
+                            Expression::YieldExpression(_)
+                                | Expression::AssignmentExpression(_)
+                                | Expression::ConditionalExpression(_)
+                                | Expression::LogicalExpression(_)
+                                | Expression::BinaryExpression(_)
+                                | Expression::ArrowFunctionExpression(_)
+                        )
+                    );
+                    if needs_parens {
+                        fixer.replace(call_span, format!("({elem_text})"))
+                    } else {
+                        fixer.replace(call_span, elem_text)
+                    }
                 } else {
                     fixer.replace(call_span, format!("Promise.resolve({elem_text})"))
                 }
@@ -253,6 +270,12 @@ fn test() {
         ("await Promise.all([x]);", "await x;", None),
         ("await Promise.all([x as Promise<number>]);", "await x as Promise<number>;", None),
         ("while(true) { await Promise.all([x]); }", "while(true) { await x; }", None),
+        (
+            "async function * foo() {await Promise.all([yield promise])}",
+            "async function * foo() {await (yield promise)}",
+            None,
+        ),
+        ("await Promise.all([a || b]);", "await (a || b);", None),
         ("const foo = await Promise.all([x])", "const foo = await Promise.all([x])", None),
         ("const [foo] = await Promise.all([x])", "const [foo] = await Promise.all([x])", None),
         ("let foo; foo = await Promise.all([x])", "let foo; foo = await Promise.all([x])", None),
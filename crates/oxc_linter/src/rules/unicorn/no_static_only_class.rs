@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// ```
     NoStaticOnlyClass,
     pedantic,
-    pending
+    pending,
+    node_types = [Class]
 );
 
 impl Rule for NoStaticOnlyClass {
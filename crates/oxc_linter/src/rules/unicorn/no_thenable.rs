@@ -238,7 +238,7 @@ fn check_expression(expr: &Expression, ctx: &LintContext<'_>) -> Option<oxc_span
             let tab = ctx.semantic().symbols();
             ident.reference_id.get().and_then(|ref_id| {
                 tab.get_reference(ref_id).symbol_id().and_then(|symbol_id| {
-                    let decl = ctx.semantic().nodes().get_node(tab.get_declaration(symbol_id));
+                    let decl = ctx.symbol_declaration(symbol_id);
                     let var_decl = decl.kind().as_variable_declarator()?;
 
                     match var_decl.init {
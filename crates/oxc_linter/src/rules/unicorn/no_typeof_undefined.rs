@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// ```
     NoTypeofUndefined,
     pedantic,
-    pending
+    pending,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for NoTypeofUndefined {
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// })(getBar());
     /// ```
     NoUnreadableIife,
-    pedantic
+    pedantic,
+    node_types = [CallExpression]
 );
 
 impl Rule for NoUnreadableIife {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessFallbackInSpread,
     correctness,
-    conditional_fix
+    conditional_fix,
+    node_types = [LogicalExpression]
 );
 
 impl Rule for NoUselessFallbackInSpread {
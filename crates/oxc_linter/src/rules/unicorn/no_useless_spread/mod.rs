@@ -220,7 +220,7 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -
             // foo(...[ ])
             AstKind::Argument(_) => {
                 ctx.diagnostic_with_fix(spread_in_arguments(span), |fixer| {
-                    fix_by_removing_array_spread(fixer, array_expr, spread_elem)
+                    fix_by_spreading_arguments(fixer, array_expr, spread_elem)
                 });
                 true
             }
@@ -449,6 +449,24 @@ fn fix_by_removing_array_spread<'a, S: GetSpan>(
     fixer.replace(iterable.span(), fixer.source_range(spread.argument.span()))
 }
 
+/// Creates a fix that replaces `foo(...[a, b])` with `foo(a, b)`. Arrays with
+/// holes or without elements are left alone, since their elements can't be
+/// passed as arguments as they are.
+fn fix_by_spreading_arguments<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    array_expr: &ArrayExpression<'a>,
+    spread: &SpreadElement<'a>,
+) -> RuleFix<'a> {
+    let (Some(first), Some(last)) = (array_expr.elements.first(), array_expr.elements.last())
+    else {
+        return fixer.noop();
+    };
+    if array_expr.elements.iter().any(ArrayExpressionElement::is_elision) {
+        return fixer.noop();
+    }
+    fixer.replace_with(&spread.span, &Span::new(first.span().start, last.span().end))
+}
+
 /// Creates a fix that replaces `{...spread}` with `spread`, when `spread` is an
 /// object literal
 ///
@@ -460,7 +478,7 @@ fn fix_by_removing_object_spread<'a>(
 ) -> RuleFix<'a> {
     // get contents inside object brackets
     // e.g. `...{ a, b, }` -> ` a, b, `
-    let replacement_span = &spread.argument.span().shrink(1);
+    let replacement_span = &spread.argument.without_parentheses().span().shrink(1);
 
     // remove trailing commas to avoid syntax errors if this spread is followed
     // by another property
@@ -734,6 +752,11 @@ fn test() {
         ("const obj = {a, ...{b,c}}", "const obj = {a, b,c}"),
         ("const obj = {a, ...{b,c,}}", "const obj = {a, b,c}"),
         ("const obj = { a, ...{ b, c }, ...{ d } }", "const obj = { a,  b, c,  d }"),
+        (r"const object = {...(( {a} ))}", r"const object = {a}"),
+        // arguments
+        ("foo(...[a, b])", "foo(a, b)"),
+        (r"foo(...(( [a] )))", r"foo(a)"),
+        ("foo(...[a, , b])", "foo(...[a, , b])"),
         // iterable spread
         (r"const promise = Promise.any([...iterable])", r"const promise = Promise.any(iterable)"),
         (r"new Map([...iterable])", r"new Map(iterable)"),
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessSwitchCase,
     pedantic,
-    pending
+    pending,
+    node_types = [SwitchStatement]
 );
 
 impl Rule for NoUselessSwitchCase {
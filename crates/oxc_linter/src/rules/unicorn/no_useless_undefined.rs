@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Argument, CallExpression, Expression, VariableDeclarationKind},
+    ast::{Argument, CallExpression, Comment, Expression, VariableDeclarationKind},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -182,16 +182,16 @@ impl Rule for NoUselessUndefined {
                         ctx.diagnostic_with_fix(
                             no_useless_undefined_diagnostic(undefined_literal.span),
                             |fixer| {
-                                let delete_span = if let Some(comment) = ctx
+                                let Some(argument) = &ret_stmt.argument else {
+                                    return fixer.noop();
+                                };
+                                // keep comments between `return` and the argument
+                                let start = ctx
                                     .semantic()
-                                    .comments_range(ret_stmt.span.start..ret_stmt.span.end)
+                                    .comments_range(ret_stmt.span.start..argument.span().start)
                                     .last()
-                                {
-                                    Span::new(comment.span.end + 2, undefined_literal.span.end)
-                                } else {
-                                    Span::new(ret_stmt.span().start + 6, undefined_literal.span.end)
-                                };
-                                fixer.delete_range(delete_span)
+                                    .map_or(ret_stmt.span.start + 6, Comment::real_span_end);
+                                fixer.delete_range(Span::new(start, argument.span().end))
                             },
                         );
                     }
@@ -629,6 +629,11 @@ fn test() {
             "function foo() {return /* comment */;}",
             None,
         ),
+        (
+            "function foo() {return /* a */ (/* b */ undefined /* c */) /* d */;}",
+            "function foo() {return /* a */ /* d */;}",
+            None,
+        ),
         ("function* foo() {yield undefined;}", "function* foo() {yield;}", None),
         ("function* foo() {yield                 undefined;}", "function* foo() {yield;}", None),
         ("let a = undefined;", "let a;", None),
@@ -344,9 +344,8 @@ fn parse_number_literal(num: &str) -> ParsedNumberLiteral {
             let decimal_part =
                 num[offset..].split_once(['e', 'E']).map_or(&num[offset..], |(decimal, _)| decimal);
             offset += decimal_part.len();
-            if !decimal_part.is_empty() {
-                parsed.decimal_part = Some(decimal_part);
-            }
+            // Keep an empty decimal part, e.g. in `1..toString()`, to keep the dot.
+            parsed.decimal_part = Some(decimal_part);
         }
     }
 
@@ -629,7 +628,10 @@ fn test_number_decimal_float() {
         "const foo = 0.00000",
     ];
 
-    let fix = vec![("const foo = 9807.1234567", "const foo = 9807.123_456_7", None)];
+    let fix = vec![
+        ("const foo = 9807.1234567", "const foo = 9807.123_456_7", None),
+        ("const foo = 12345678..toString()", "const foo = 12_345_678..toString()", None),
+    ];
 
     Tester::new(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
 }
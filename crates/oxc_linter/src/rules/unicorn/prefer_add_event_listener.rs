@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// ```
     PreferAddEventListener,
     suspicious,
-    pending
+    pending,
+    node_types = [AssignmentExpression]
 );
 
 impl Rule for PreferAddEventListener {
@@ -61,7 +61,8 @@ declare_oxc_lint!(
     /// ```
     PreferArrayFlat,
     pedantic,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferArrayFlat {
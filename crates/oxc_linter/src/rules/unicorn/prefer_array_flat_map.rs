@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// ```
     PreferArrayFlatMap,
     style,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferArrayFlatMap {
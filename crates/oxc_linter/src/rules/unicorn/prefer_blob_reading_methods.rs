@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// ```
     PreferBlobReadingMethods,
     pedantic,
-    pending
+    pending,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferBlobReadingMethods {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// ```
     PreferCodePoint,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferCodePoint {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// ```
     PreferDomNodeAppend,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeAppend {
@@ -62,7 +62,8 @@ declare_oxc_lint!(
     /// ```
     PreferDomNodeDataset,
     pedantic,
-    pending
+    pending,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeDataset {
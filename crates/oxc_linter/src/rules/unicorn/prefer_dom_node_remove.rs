@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// childNode.remove();
     /// ```
     PreferDomNodeRemove,
-    pedantic
+    pedantic,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeRemove {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// class Foo extends OtherClass {}
     /// ```
     PreferEventTarget,
-    pedantic
+    pedantic,
+    node_types = [IdentifierReference]
 );
 
 impl Rule for PreferEventTarget {
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// ```
     PreferIncludes,
     style,
-    pending
+    pending,
+    node_types = [BinaryExpression]
 );
 
 impl Rule for PreferIncludes {
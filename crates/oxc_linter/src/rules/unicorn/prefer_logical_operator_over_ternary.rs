@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// ```
     PreferLogicalOperatorOverTernary,
     style,
-    pending
+    pending,
+    node_types = [ConditionalExpression]
 );
 
 impl Rule for PreferLogicalOperatorOverTernary {
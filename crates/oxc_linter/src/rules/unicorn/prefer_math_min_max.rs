@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// ```
     PreferMathMinMax,
     pedantic,
-    fix
+    fix,
+    node_types = [ConditionalExpression]
 );
 
 impl Rule for PreferMathMinMax {
@@ -62,7 +62,8 @@ declare_oxc_lint!(
     /// ```
     PreferModernDomApis,
     style,
-    pending
+    pending,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferModernDomApis {
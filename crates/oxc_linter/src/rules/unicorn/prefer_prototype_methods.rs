@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// ```
     PreferPrototypeMethods,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferPrototypeMethods {
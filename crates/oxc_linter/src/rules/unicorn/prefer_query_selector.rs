@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// ```
     PreferQuerySelector,
     pedantic,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferQuerySelector {
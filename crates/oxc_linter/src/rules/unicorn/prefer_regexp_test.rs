@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// ```
     PreferRegexpTest,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferRegexpTest {
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     PreferSetSize,
     correctness,
-    fix
+    fix,
+    node_types = [MemberExpression]
 );
 
 impl Rule for PreferSetSize {
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     PreferStringReplaceAll,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferStringReplaceAll {
@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// ```
     PreferStringStartsEndsWith,
     correctness,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferStringStartsEndsWith {
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     PreferStringTrimStartEnd,
     style,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for PreferStringTrimStartEnd {
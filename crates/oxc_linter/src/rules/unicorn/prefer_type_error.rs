@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// ```
     PreferTypeError,
     pedantic,
-    fix
+    fix,
+    node_types = [ThrowStatement]
 );
 
 impl Rule for PreferTypeError {
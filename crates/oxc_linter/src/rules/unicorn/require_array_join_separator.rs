@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// ```
     RequireArrayJoinSeparator,
     style,
-    conditional_fix
+    conditional_fix,
+    node_types = [CallExpression]
 );
 
 fn is_array_prototype_property(member_expr: &MemberExpression, property: &str) -> bool {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```
     RequireNumberToFixedDigitsArgument,
     pedantic,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for RequireNumberToFixedDigitsArgument {
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     SwitchCaseBraces,
     style,
-    fix
+    fix,
+    node_types = [SwitchStatement]
 );
 
 impl Rule for SwitchCaseBraces {
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// ```
     ThrowNewError,
     style,
-    fix
+    fix,
+    node_types = [CallExpression]
 );
 
 impl Rule for ThrowNewError {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::ast::IfStatement;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -78,12 +78,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
             JestFnKind::General(JestGeneralFnKind::Test),
         ],
     ) {
-        let if_statement_node = ctx
-            .nodes()
-            .iter_parents(node.id())
-            .find(|node| matches!(node.kind(), AstKind::IfStatement(_)))?;
-
-        let if_statement = if_statement_node.kind().as_if_statement()?;
+        let if_statement = ctx.nodes().ancestor_of_kind::<IfStatement>(node.id())?;
         ctx.diagnostic(no_conditional_tests(if_statement.span));
     }
 
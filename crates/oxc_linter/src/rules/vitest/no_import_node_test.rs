@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoImportNodeTest,
     style,
-    fix
+    fix,
+    node_types = []
 );
 
 impl Rule for NoImportNodeTest {
@@ -0,0 +1,526 @@
+//! AST selectors, a subset of [esquery]'s CSS like syntax which ESLint uses to select nodes.
+//!
+//! Supported are:
+//! * node types, e.g. `CallExpression`, and the wildcard `*`
+//! * attributes, e.g. `[async]`, `[name="foo"]`, `[callee.name!=require]` and `[value=/^foo/i]`
+//! * child (`A > B`) and descendant (`A B`) combinators
+//! * selector lists, e.g. `ForInStatement, ForOfStatement`
+//!
+//! Like in ESLint, node types and attributes are those of [ESTree], see `AstKind::to_estree`.
+//! Attributes need the `estree` feature.
+//!
+//! Rules can find the nodes matching a selector with [`LintContext::query`].
+//!
+//! [`LintContext::query`]: crate::LintContext::query
+//! [esquery]: <https://github.com/estools/esquery>
+//! [ESTree]: <https://github.com/estree/estree>
+
+// Attributes are parsed, but can't be matched without the `estree` feature.
+#![cfg_attr(not(feature = "estree"), allow(dead_code))]
+
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{ClassType, FunctionType, JSXAttributeItem},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::CompactStr;
+use regex::Regex;
+use serde_json::Value;
+
+/// A parsed selector, see the [module documentation](self) for the supported syntax.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    alternatives: Vec<ComplexSelector>,
+}
+
+/// Compound selectors joined by combinators, e.g. `A > B C`.
+#[derive(Debug, Clone)]
+struct ComplexSelector {
+    /// The right-most compound selector, which selects the node itself.
+    subject: CompoundSelector,
+    /// The compound selectors left of the subject, from right to left, with the combinator on
+    /// their right.
+    ancestors: Vec<(Combinator, CompoundSelector)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Combinator {
+    Child,
+    Descendant,
+}
+
+/// A node type and attributes, e.g. `CallExpression[callee.name="foo"]`.
+#[derive(Debug, Clone)]
+struct CompoundSelector {
+    /// `None` for `*`, or when only attributes are given.
+    node_type: Option<CompactStr>,
+    attributes: Vec<AttributeSelector>,
+}
+
+#[derive(Debug, Clone)]
+struct AttributeSelector {
+    path: Vec<CompactStr>,
+    /// `None` if the attribute only has to exist.
+    test: Option<(Operator, AttributeValue)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    Literal(CompactStr),
+    Regex(Regex),
+}
+
+impl Selector {
+    /// Parse `selector`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `selector` is invalid or uses unsupported syntax, such as
+    /// pseudo-classes.
+    pub fn parse(selector: &str) -> Result<Self, OxcDiagnostic> {
+        let mut parser = SelectorParser { source: selector, offset: 0 };
+        let mut alternatives = vec![parser.parse_complex()?];
+        while parser.eat(',') {
+            alternatives.push(parser.parse_complex()?);
+        }
+        match parser.peek() {
+            None => Ok(Self { alternatives }),
+            Some(_) => Err(parser.unexpected()),
+        }
+    }
+
+    /// Whether the selector selects `node`.
+    pub fn matches<'a>(&self, node: &AstNode<'a>, semantic: &Semantic<'a>) -> bool {
+        self.alternatives.iter().any(|selector| selector.matches(node, semantic))
+    }
+
+    /// All nodes the selector selects, in the order they appear in the AST.
+    pub fn query<'s, 'n: 's, 'a>(
+        &'s self,
+        semantic: &'n Semantic<'a>,
+    ) -> impl Iterator<Item = &'n AstNode<'a>> + 's {
+        semantic.nodes().iter().filter(|node| self.matches(node, semantic))
+    }
+}
+
+impl ComplexSelector {
+    fn matches<'a>(&self, node: &AstNode<'a>, semantic: &Semantic<'a>) -> bool {
+        self.subject.matches(node, semantic) && self.matches_ancestors(0, node, semantic)
+    }
+
+    /// Whether the ancestors of `node`, which matched the compound selector before
+    /// `self.ancestors[index]`, match the remaining compound selectors.
+    fn matches_ancestors<'a>(
+        &self,
+        index: usize,
+        node: &AstNode<'a>,
+        semantic: &Semantic<'a>,
+    ) -> bool {
+        let Some((combinator, selector)) = self.ancestors.get(index) else {
+            return true;
+        };
+        let mut parent = estree_parent(node, semantic);
+        while let Some(node) = parent {
+            if selector.matches(node, semantic) && self.matches_ancestors(index + 1, node, semantic)
+            {
+                return true;
+            }
+            if matches!(combinator, Combinator::Child) {
+                return false;
+            }
+            parent = estree_parent(node, semantic);
+        }
+        false
+    }
+}
+
+impl CompoundSelector {
+    fn matches(&self, node: &AstNode, semantic: &Semantic) -> bool {
+        let Some(node_type) = estree_type(node, semantic) else {
+            return false;
+        };
+        if self.node_type.as_ref().is_some_and(|name| !name.eq_ignore_ascii_case(&node_type)) {
+            return false;
+        }
+        if self.attributes.is_empty() {
+            return true;
+        }
+        #[cfg(feature = "estree")]
+        {
+            let value = node.kind().to_estree(semantic.source_text());
+            self.attributes.iter().all(|attribute| attribute.matches(&value))
+        }
+        // Selectors with attributes fail to parse.
+        #[cfg(not(feature = "estree"))]
+        unreachable!()
+    }
+}
+
+impl AttributeSelector {
+    fn matches(&self, node: &Value) -> bool {
+        let value = resolve(node, &self.path);
+        let Some((operator, expected)) = &self.test else {
+            return value.is_some_and(|value| !value.is_null());
+        };
+        let is_equal = match expected {
+            AttributeValue::Literal(expected) => js_string(value.as_deref()) == expected.as_str(),
+            AttributeValue::Regex(regex) => {
+                matches!(value.as_deref(), Some(Value::String(value)) if regex.is_match(value))
+            }
+        };
+        is_equal == (*operator == Operator::Equal)
+    }
+}
+
+/// The value at `path` in `value`, where `length` is the length of an array.
+fn resolve<'v>(mut value: &'v Value, path: &[CompactStr]) -> Option<Cow<'v, Value>> {
+    for (index, key) in path.iter().enumerate() {
+        value = match value {
+            Value::Object(object) => object.get(key.as_str())?,
+            Value::Array(elements) if key == "length" && index == path.len() - 1 => {
+                return Some(Cow::Owned(elements.len().into()));
+            }
+            Value::Array(elements) => elements.get(key.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(Cow::Borrowed(value))
+}
+
+/// `value` converted to a string like JavaScript does, which esquery compares attributes with.
+fn js_string(value: Option<&Value>) -> Cow<'_, str> {
+    match value {
+        None => Cow::Borrowed("undefined"),
+        Some(Value::Null) => Cow::Borrowed("null"),
+        Some(Value::Bool(value)) => Cow::Borrowed(if *value { "true" } else { "false" }),
+        Some(Value::Number(value)) => Cow::Owned(value.to_string()),
+        Some(Value::String(value)) => Cow::Borrowed(value),
+        Some(Value::Array(_) | Value::Object(_)) => Cow::Borrowed("[object Object]"),
+    }
+}
+
+/// The closest ancestor of `node` which is an ESTree node.
+fn estree_parent<'s, 'a>(
+    node: &AstNode<'a>,
+    semantic: &'s Semantic<'a>,
+) -> Option<&'s AstNode<'a>> {
+    semantic
+        .nodes()
+        .ancestors(node.id())
+        .skip(1)
+        .map(|id| semantic.nodes().get_node(id))
+        .find(|node| estree_type(node, semantic).is_some())
+}
+
+/// The ESTree type of `node`, or `None` if it has no ESTree equivalent, e.g. [`AstKind::Argument`],
+/// which only wraps an expression or spread element.
+fn estree_type(node: &AstNode, semantic: &Semantic) -> Option<Cow<'static, str>> {
+    let kind = node.kind();
+    let name = match kind {
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_)
+        | AstKind::StringLiteral(_) => "Literal",
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_)
+        | AstKind::TSThisParameter(_) => "Identifier",
+        AstKind::ObjectProperty(_) => "Property",
+        AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::Directive(_) => "ExpressionStatement",
+        // Arrow functions with an expression body have a function body with a single expression
+        // statement, where ESTree only has the expression.
+        AstKind::FunctionBody(_) if is_arrow_expression_body(node, semantic) => return None,
+        AstKind::ExpressionStatement(_)
+            if semantic
+                .nodes()
+                .parent_node(node.id())
+                .is_some_and(|parent| is_arrow_expression_body(parent, semantic)) =>
+        {
+            return None
+        }
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(_)) => "JSXAttribute",
+        AstKind::ArrayExpressionElement(_)
+        | AstKind::Elision(_)
+        | AstKind::PropertyKey(_)
+        | AstKind::Argument(_)
+        | AstKind::AssignmentTarget(_)
+        | AstKind::SimpleAssignmentTarget(_)
+        | AstKind::AssignmentTargetPattern(_)
+        | AstKind::ParenthesizedExpression(_)
+        | AstKind::Hashbang(_)
+        | AstKind::ForStatementInit(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::ModuleDeclaration(_)
+        | AstKind::TSTypeName(_)
+        | AstKind::TSModuleReference(_)
+        | AstKind::JSXElementName(_)
+        | AstKind::JSXMemberExpressionObject(_)
+        | AstKind::JSXAttributeItem(_) => return None,
+        _ => return Some(Cow::Owned(format!("{:?}", kind.ty()))),
+    };
+    Some(Cow::Borrowed(name))
+}
+
+fn is_arrow_expression_body(node: &AstNode, semantic: &Semantic) -> bool {
+    matches!(node.kind(), AstKind::FunctionBody(_))
+        && matches!(
+            semantic.nodes().parent_kind(node.id()),
+            Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+        )
+}
+
+struct SelectorParser<'s> {
+    source: &'s str,
+    offset: usize,
+}
+
+impl SelectorParser<'_> {
+    fn parse_complex(&mut self) -> Result<ComplexSelector, OxcDiagnostic> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.parse_compound()?];
+        let mut combinators = vec![];
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if has_whitespace && !matches!(self.peek(), None | Some(',')) {
+                Combinator::Descendant
+            } else {
+                break;
+            };
+            combinators.push(combinator);
+            compounds.push(self.parse_compound()?);
+        }
+        let subject = compounds.pop().unwrap();
+        let ancestors = combinators.into_iter().rev().zip(compounds.into_iter().rev()).collect();
+        Ok(ComplexSelector { subject, ancestors })
+    }
+
+    fn parse_compound(&mut self) -> Result<CompoundSelector, OxcDiagnostic> {
+        let (has_type, node_type) = if self.eat('*') {
+            (true, None)
+        } else {
+            let node_type = self.parse_identifier();
+            (node_type.is_some(), node_type)
+        };
+        let mut attributes = vec![];
+        while self.eat('[') {
+            attributes.push(self.parse_attribute()?);
+        }
+        if !has_type && attributes.is_empty() {
+            return Err(self.unexpected());
+        }
+        #[cfg(not(feature = "estree"))]
+        if !attributes.is_empty() {
+            return Err(OxcDiagnostic::error(format!(
+                "Attributes in selector `{}` need oxc_linter's `estree` feature",
+                self.source
+            )));
+        }
+        Ok(CompoundSelector { node_type, attributes })
+    }
+
+    fn parse_attribute(&mut self) -> Result<AttributeSelector, OxcDiagnostic> {
+        self.skip_whitespace();
+        let mut path = vec![self.parse_identifier().ok_or_else(|| self.unexpected())?];
+        while self.eat('.') {
+            path.push(self.parse_identifier().ok_or_else(|| self.unexpected())?);
+        }
+        self.skip_whitespace();
+        let operator = if self.eat('=') {
+            Some(Operator::Equal)
+        } else if self.source[self.offset..].starts_with("!=") {
+            self.offset += 2;
+            Some(Operator::NotEqual)
+        } else {
+            None
+        };
+        let test = match operator {
+            Some(operator) => {
+                self.skip_whitespace();
+                let value = self.parse_value()?;
+                self.skip_whitespace();
+                Some((operator, value))
+            }
+            None => None,
+        };
+        if !self.eat(']') {
+            return Err(self.unexpected());
+        }
+        Ok(AttributeSelector { path, test })
+    }
+
+    /// A quoted string, a regular expression like `/foo/i`, or an unquoted string like `foo`.
+    fn parse_value(&mut self) -> Result<AttributeValue, OxcDiagnostic> {
+        let rest = &self.source[self.offset..];
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                let mut value = String::new();
+                let mut chars = rest.char_indices().skip(1);
+                while let Some((index, c)) = chars.next() {
+                    match c {
+                        '\\' => value.extend(chars.next().map(|(_, c)| c)),
+                        c if c == quote => {
+                            self.offset += index + 1;
+                            return Ok(AttributeValue::Literal(CompactStr::from(value)));
+                        }
+                        c => value.push(c),
+                    }
+                }
+                self.offset = self.source.len();
+                Err(self.unexpected())
+            }
+            Some('/') => {
+                let mut escaped = false;
+                let end = rest.char_indices().skip(1).find_map(|(index, c)| {
+                    let is_end = c == '/' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    is_end.then_some(index)
+                });
+                let Some(end) = end else {
+                    self.offset = self.source.len();
+                    return Err(self.unexpected());
+                };
+                let pattern = &rest[1..end];
+                self.offset += end + 1;
+                let flags_end = self.source[self.offset..]
+                    .find(|c: char| !matches!(c, 'i' | 'm' | 's' | 'u'))
+                    .map_or(self.source.len(), |end| self.offset + end);
+                let flags: String =
+                    self.source[self.offset..flags_end].chars().filter(|&c| c != 'u').collect();
+                self.offset = flags_end;
+                let pattern = if flags.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{flags}){pattern}")
+                };
+                Regex::new(&pattern).map(AttributeValue::Regex).map_err(|error| {
+                    OxcDiagnostic::error(format!(
+                        "Invalid regular expression in selector `{}`: {error}",
+                        self.source
+                    ))
+                })
+            }
+            _ => {
+                let end = rest.find(|c: char| c == ']' || c.is_whitespace()).unwrap_or(rest.len());
+                if end == 0 {
+                    return Err(self.unexpected());
+                }
+                self.offset += end;
+                Ok(AttributeValue::Literal(CompactStr::from(&rest[..end])))
+            }
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Option<CompactStr> {
+        let rest = &self.source[self.offset..];
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$' | '-')))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return None;
+        }
+        self.offset += end;
+        Some(CompactStr::from(&rest[..end]))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let is_next = self.peek() == Some(c);
+        if is_next {
+            self.offset += c.len_utf8();
+        }
+        is_next
+    }
+
+    /// Skip whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.source[self.offset..];
+        let trimmed = rest.trim_start();
+        self.offset += rest.len() - trimmed.len();
+        trimmed.len() != rest.len()
+    }
+
+    fn unexpected(&self) -> OxcDiagnostic {
+        match self.peek() {
+            Some(c) => OxcDiagnostic::error(format!(
+                "Unexpected `{c}` at offset {} of selector `{}`",
+                self.offset, self.source
+            )),
+            None => OxcDiagnostic::error(format!("Unexpected end of selector `{}`", self.source)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Selector;
+
+    #[test]
+    fn test_parse() {
+        for selector in [
+            "Identifier",
+            "*",
+            "  CallExpression > MemberExpression ",
+            "FunctionDeclaration Identifier, ClassDeclaration>*",
+            "[async]",
+            "CallExpression[callee.name='require'][arguments.length=1]",
+            "Literal[ value = \"a\\\"]b\" ]",
+            "Identifier[name=/^_/i]",
+            "Identifier[name!=/a\\/b/]",
+            "Literal[value!=1]",
+        ] {
+            let result = Selector::parse(selector);
+            assert!(result.is_ok(), "{selector}: {:?}", result.err());
+        }
+
+        for selector in [
+            "",
+            "Identifier,",
+            "Identifier >",
+            ":not(Identifier)",
+            "Identifier:first-child",
+            "MemberExpression.callee",
+            "Identifier[name",
+            "Identifier[name=]",
+            "Identifier[name='a]",
+            "Identifier[name=/a]",
+            "Identifier[name=/(/]",
+            "Identifier[=a]",
+        ] {
+            assert!(Selector::parse(selector).is_err(), "{selector}");
+        }
+    }
+}
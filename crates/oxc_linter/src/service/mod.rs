@@ -1,4 +1,5 @@
 mod module_cache;
+mod module_graph;
 mod runtime;
 
 use std::{
@@ -8,9 +9,11 @@ use std::{
 
 use oxc_diagnostics::DiagnosticSender;
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::Linter;
 
+pub use module_graph::ModuleGraph;
 use runtime::Runtime;
 
 pub struct LintServiceOptions {
@@ -24,6 +27,15 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Attach fixes to the reported diagnostics instead of writing them to disk
+    report_fixes: bool,
+
+    /// Source text to lint instead of the contents on disk, for some of the paths
+    file_contents: FxHashMap<Box<Path>, String>,
+
+    /// Only lint these paths, and the paths that depend on them
+    changed_files: Option<FxHashSet<Box<Path>>>,
 }
 
 impl LintServiceOptions {
@@ -32,7 +44,15 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            report_fixes: false,
+            file_contents: FxHashMap::default(),
+            changed_files: None,
+        }
     }
 
     #[inline]
@@ -57,6 +77,38 @@ impl LintServiceOptions {
         self
     }
 
+    /// Attach fixes to the reported diagnostics instead of applying them, so
+    /// that reporters can expose them. Files are never written in this mode.
+    /// Fixes are only produced for the fix kinds enabled on the [`Linter`].
+    #[inline]
+    #[must_use]
+    pub fn with_report_fixes(mut self, report_fixes: bool) -> Self {
+        self.report_fixes = report_fixes;
+        self
+    }
+
+    /// Lint the given source text for these paths instead of reading them from
+    /// disk, e.g. the staged contents of files in a pre-commit hook. Keys must
+    /// be the same as the paths to lint. Fixes are not written for these files.
+    #[inline]
+    #[must_use]
+    pub fn with_file_contents(mut self, file_contents: FxHashMap<Box<Path>, String>) -> Self {
+        self.file_contents = file_contents;
+        self
+    }
+
+    /// Only lint the paths in `changed_files`, e.g. the files changed since a
+    /// git ref. With cross-module analysis, the paths that import them, directly
+    /// or through other modules, are linted too. All paths are visited to find
+    /// them, but the other paths are not linted. Keys must be the same as the
+    /// paths to lint.
+    #[inline]
+    #[must_use]
+    pub fn with_changed_files(mut self, changed_files: FxHashSet<Box<Path>>) -> Self {
+        self.changed_files = Some(changed_files);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -88,12 +140,21 @@ impl LintService {
         self.runtime.number_of_dependencies()
     }
 
+    /// Number of files that are linted. With
+    /// [`LintServiceOptions::with_changed_files`] and cross-module analysis,
+    /// this is only known after [`LintService::run`].
+    pub fn number_of_files(&self) -> usize {
+        self.runtime.iter_paths().count()
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
+        self.runtime.resolve_changed_files();
         self.runtime
             .iter_paths()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        self.runtime.run_project_rules(tx_error);
         tx_error.send(None).unwrap();
     }
 
@@ -123,3 +184,54 @@ impl LintService {
             .collect::<Vec<_>>()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, sync::mpsc};
+
+    use serde_json::json;
+
+    use super::{LintService, LintServiceOptions};
+    use crate::{LinterBuilder, Oxlintrc};
+
+    /// File names with problems when linting only `changed` files in `fixtures/changed`.
+    fn lint_changed(changed: &[&str], cross_module: bool) -> (Vec<String>, usize) {
+        let dir = fs::canonicalize(Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/changed"))
+            .unwrap();
+        let paths = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path().into_boxed_path())
+            .collect::<Vec<_>>();
+        let changed = changed.iter().map(|name| dir.join(name).into_boxed_path()).collect();
+
+        let config = json!({ "rules": { "no-debugger": "error" } });
+        let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc).unwrap().build();
+        let options = LintServiceOptions::new(dir, paths)
+            .with_cross_module(cross_module)
+            .with_changed_files(changed);
+        let lint_service = LintService::new(linter, options);
+        let (tx_error, rx_error) = mpsc::channel();
+        lint_service.run(&tx_error);
+
+        let mut names = rx_error
+            .try_iter()
+            .flatten()
+            .map(|(path, _)| path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        (names, lint_service.number_of_files())
+    }
+
+    #[test]
+    fn test_changed_files() {
+        assert_eq!(lint_changed(&["c.js"], false), (vec!["c.js".to_string()], 1));
+        // `clean.js` imports `c.js` too, it is linted but has no problems.
+        assert_eq!(
+            lint_changed(&["c.js"], true),
+            (vec!["a.js".to_string(), "b.js".to_string(), "c.js".to_string()], 4)
+        );
+        assert_eq!(lint_changed(&["b.js", "d.js"], true).0, ["a.js", "b.js", "d.js"]);
+        assert_eq!(lint_changed(&[], true), (vec![], 0));
+    }
+}
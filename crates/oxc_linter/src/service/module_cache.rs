@@ -51,6 +51,23 @@ impl ModuleCache {
         self.modules.len()
     }
 
+    /// Forget all modules, so that they are parsed again when visited.
+    pub(super) fn clear(&self) {
+        self.cache_state.lock().expect("Failed to lock cache state").clear();
+        self.modules.clear();
+    }
+
+    /// All modules that were parsed, skipping ignored paths.
+    pub(super) fn resolved_modules(&self) -> FxHashMap<Box<Path>, Arc<ModuleRecord>> {
+        self.modules
+            .iter()
+            .filter_map(|entry| match entry.value() {
+                ModuleState::Resolved(record) => Some((entry.key().clone(), Arc::clone(record))),
+                ModuleState::Ignored => None,
+            })
+            .collect()
+    }
+
     pub(super) fn init_cache_state(&self, path: &Path) -> bool {
         let (lock, cvar) = {
             let mut state_map = self.cache_state.lock().expect("Failed to lock cache state");
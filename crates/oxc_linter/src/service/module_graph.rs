@@ -0,0 +1,131 @@
+use std::{path::Path, sync::Arc};
+
+use oxc_semantic::ModuleRecord;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Module records of every file visited during a cross-module lint run.
+///
+/// This includes both the files being linted and any dependencies that were
+/// resolved while linting them. Use [`ModuleGraph::is_linted`] to tell them
+/// apart.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: FxHashMap<Box<Path>, Arc<ModuleRecord>>,
+    linted: FxHashSet<Box<Path>>,
+    /// Edges from importers to the modules they import, without duplicates.
+    dependencies: FxHashMap<Box<Path>, Vec<Box<Path>>>,
+    /// The same edges, from imported modules to their importers.
+    dependents: FxHashMap<Box<Path>, Vec<Box<Path>>>,
+}
+
+impl ModuleGraph {
+    pub(crate) fn new(
+        modules: FxHashMap<Box<Path>, Arc<ModuleRecord>>,
+        linted: FxHashSet<Box<Path>>,
+    ) -> Self {
+        let mut dependencies = FxHashMap::<Box<Path>, Vec<Box<Path>>>::default();
+        let mut dependents = FxHashMap::<Box<Path>, Vec<Box<Path>>>::default();
+        for (importer, record) in &modules {
+            let mut imported = record
+                .loaded_modules
+                .iter()
+                .filter_map(|entry| {
+                    modules.get_key_value(entry.value().resolved_absolute_path.as_path())
+                })
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>();
+            imported.sort_unstable();
+            imported.dedup();
+            for path in &imported {
+                dependents.entry(path.clone()).or_default().push(importer.clone());
+            }
+            if !imported.is_empty() {
+                dependencies.insert(importer.clone(), imported);
+            }
+        }
+        Self { modules, linted, dependencies, dependents }
+    }
+
+    /// Get the module record of a file, if it was visited.
+    pub fn get(&self, path: &Path) -> Option<&ModuleRecord> {
+        self.modules.get(path).map(AsRef::as_ref)
+    }
+
+    /// Iterate over every module in the graph, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &ModuleRecord)> + '_ {
+        self.modules.iter().map(|(path, record)| (path.as_ref(), record.as_ref()))
+    }
+
+    /// Number of modules in the graph.
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Returns `true` if no modules were visited.
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Returns `true` if `path` is one of the files being linted, as opposed
+    /// to a dependency that was only visited to resolve imports.
+    pub fn is_linted(&self, path: &Path) -> bool {
+        self.linted.contains(path)
+    }
+
+    /// Paths of the modules `path` imports or re-exports from.
+    pub fn dependencies(&self, path: &Path) -> impl Iterator<Item = &Path> + '_ {
+        Self::edges(&self.dependencies, path)
+    }
+
+    /// Paths of the modules that import or re-export from `path`.
+    pub fn dependents(&self, path: &Path) -> impl Iterator<Item = &Path> + '_ {
+        Self::edges(&self.dependents, path)
+    }
+
+    fn edges<'g>(
+        edges: &'g FxHashMap<Box<Path>, Vec<Box<Path>>>,
+        path: &Path,
+    ) -> impl Iterator<Item = &'g Path> + 'g {
+        edges.get(path).into_iter().flatten().map(AsRef::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use oxc_semantic::ModuleRecord;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    use super::ModuleGraph;
+
+    #[test]
+    fn test_dependencies() {
+        let record = |path: &str| Arc::new(ModuleRecord::new(path.into()));
+        let (a, b, c) = (record("a.js"), record("b.js"), record("c.js"));
+        a.loaded_modules.insert("./b".into(), Arc::clone(&b));
+        a.loaded_modules.insert("./c".into(), Arc::clone(&c));
+        a.loaded_modules.insert("./c.js".into(), Arc::clone(&c));
+        b.loaded_modules.insert("./c".into(), Arc::clone(&c));
+
+        let modules = [a, b, c]
+            .into_iter()
+            .map(|record| (record.resolved_absolute_path.clone().into_boxed_path(), record))
+            .collect::<FxHashMap<_, _>>();
+        let linted = FxHashSet::from_iter([Path::new("a.js").into()]);
+        let graph = ModuleGraph::new(modules, linted);
+
+        let sorted = |iter: &mut dyn Iterator<Item = &Path>| {
+            let mut paths = iter.map(|path| path.to_str().unwrap()).collect::<Vec<_>>();
+            paths.sort_unstable();
+            paths.join(",")
+        };
+        assert_eq!(graph.len(), 3);
+        assert!(graph.is_linted(Path::new("a.js")));
+        assert!(!graph.is_linted(Path::new("b.js")));
+        assert_eq!(sorted(&mut graph.dependencies(Path::new("a.js"))), "b.js,c.js");
+        assert_eq!(sorted(&mut graph.dependencies(Path::new("c.js"))), "");
+        assert_eq!(sorted(&mut graph.dependents(Path::new("c.js"))), "a.js,b.js");
+        assert_eq!(sorted(&mut graph.dependents(Path::new("a.js"))), "");
+    }
+}
@@ -1,55 +1,99 @@
 use std::{
     borrow::Cow,
     ffi::OsStr,
-    fs,
+    fs, io,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::Arc,
+    sync::{mpsc, Arc, OnceLock},
 };
 
-use oxc_allocator::Allocator;
+use dashmap::DashMap;
+use oxc_allocator::{Allocator, AllocatorPool};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
+use oxc_resolver::{ResolveError, Resolver};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
     utils::read_to_string,
-    Fixer, Linter, Message,
+    Fixer, Linter, Message, ModuleKind,
 };
 
 use super::{
     module_cache::{ModuleCache, ModuleState},
-    LintServiceOptions,
+    LintServiceOptions, ModuleGraph,
 };
 
 pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
+    /// See [`LintServiceOptions::with_changed_files`].
+    changed_files: Option<FxHashSet<Box<Path>>>,
+    /// The paths that are actually linted: all of `paths`, unless only changed
+    /// files are linted. Then it is set once the module graph is known.
+    linted_paths: OnceLock<FxHashSet<Box<Path>>>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
     modules: ModuleCache,
+    report_fixes: bool,
+    /// Arenas are reused across files instead of allocating a new one for each file.
+    allocator_pool: AllocatorPool,
+    /// Source text that replaces the contents on disk, see [`LintServiceOptions::with_file_contents`].
+    file_contents: FxHashMap<Box<Path>, String>,
+    /// Whether the nearest `package.json` of a directory has `"type": "commonjs"`.
+    commonjs_dirs: DashMap<Box<Path>, bool>,
 }
 
 impl Runtime {
     pub(super) fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            Self::get_resolver(options.tsconfig.or_else(|| Self::find_tsconfig(&options.cwd)))
         });
+        let paths = options.paths.iter().cloned().collect::<FxHashSet<_>>();
+        let linted_paths = OnceLock::new();
+        match &options.changed_files {
+            // Dependents are only known after visiting all paths.
+            Some(_) if resolver.is_some() => {}
+            Some(changed_files) => {
+                let _ = linted_paths.set(paths.intersection(changed_files).cloned().collect());
+            }
+            None => {
+                let _ = linted_paths.set(paths.clone());
+            }
+        }
         Self {
             cwd: options.cwd,
-            paths: options.paths.iter().cloned().collect(),
+            paths,
+            changed_files: options.changed_files,
+            linted_paths,
             linter,
             resolver,
             modules: ModuleCache::default(),
+            report_fixes: options.report_fixes,
+            allocator_pool: AllocatorPool::new(rayon::current_num_threads()),
+            file_contents: options.file_contents,
+            commonjs_dirs: DashMap::default(),
         }
     }
 
+    /// Find the nearest `tsconfig.json`, or `jsconfig.json` for JavaScript
+    /// projects, in `cwd` or any of its ancestors.
+    fn find_tsconfig(cwd: &Path) -> Option<PathBuf> {
+        cwd.ancestors().find_map(|dir| {
+            ["tsconfig.json", "jsconfig.json"]
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|path| path.is_file())
+        })
+    }
+
+    /// `paths`, `baseUrl` and `extends` in the tsconfig are handled by the
+    /// resolver, which also caches resolutions across all files.
     fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
         use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
         let tsconfig = tsconfig.and_then(|path| {
@@ -63,12 +107,55 @@ impl Runtime {
         Resolver::new(ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
             condition_names: vec!["module".into(), "require".into()],
+            // Report Node.js builtins as such, instead of failing to find them in `node_modules`.
+            builtin_modules: true,
             tsconfig,
             ..ResolveOptions::default()
         })
     }
 
+    fn read_source_text(&self, path: &Path) -> io::Result<String> {
+        match self.file_contents.get(path) {
+            Some(source_text) => Ok(source_text.clone()),
+            None => read_to_string(path),
+        }
+    }
+
+    /// Whether a file is a CommonJS or an ES module.
+    ///
+    /// `.cjs` and `.cts` files are CommonJS, as are `.js` and `.jsx` files if the
+    /// nearest `package.json` has `"type": "commonjs"`, as in Node.js. Such `.js`
+    /// files are still parsed as modules, since bundlers allow `import` in them.
+    fn module_kind(&self, path: &Path, source_type: SourceType) -> ModuleKind {
+        let is_commonjs = source_type.is_script()
+            || (matches!(path.extension().and_then(OsStr::to_str), Some("js" | "jsx"))
+                && path.parent().is_some_and(|dir| self.is_commonjs_dir(dir)));
+        if is_commonjs {
+            ModuleKind::CommonJs
+        } else {
+            ModuleKind::Module
+        }
+    }
+
+    fn is_commonjs_dir(&self, dir: &Path) -> bool {
+        if let Some(is_commonjs) = self.commonjs_dirs.get(dir) {
+            return *is_commonjs;
+        }
+        let package_json = dir.join("package.json");
+        let is_commonjs = if package_json.is_file() {
+            read_to_string(&package_json)
+                .ok()
+                .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+                .is_some_and(|json| json["type"] == "commonjs")
+        } else {
+            dir.parent().is_some_and(|parent| self.is_commonjs_dir(parent))
+        };
+        self.commonjs_dirs.insert(dir.into(), is_commonjs);
+        is_commonjs
+    }
+
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
@@ -79,7 +166,7 @@ impl Runtime {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
-        let file_result = read_to_string(path).map_err(|e| {
+        let file_result = self.read_source_text(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {path:?} with error \"{e}\""
             )))
@@ -103,7 +190,7 @@ impl Runtime {
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -134,11 +221,8 @@ impl Runtime {
         // source code after each fix.
         let mut fix_offset: i32 = 0;
 
-        let mut allocator = Allocator::default();
-        for (i, source) in sources.into_iter().enumerate() {
-            if i >= 1 {
-                allocator.reset();
-            }
+        for source in sources {
+            let allocator = self.allocator_pool.get();
             let mut messages = self.process_source(
                 path,
                 &allocator,
@@ -148,7 +232,10 @@ impl Runtime {
                 tx_error,
             );
 
-            if self.linter.options().fix.is_some() {
+            if self.linter.options().fix.is_some()
+                && !self.report_fixes
+                && !self.file_contents.contains_key(path)
+            {
                 let fix_result = Fixer::new(source.source_text, messages).fix();
                 if fix_result.fixed {
                     // write to file, replacing only the changed part
@@ -165,10 +252,25 @@ impl Runtime {
 
             if !messages.is_empty() {
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(Into::into).collect();
+                // Report against the whole file so that embedded scripts, e.g. in
+                // `.vue` files, show the line numbers of the original file.
+                let errors = messages
+                    .into_iter()
+                    .map(|Message { error, fix, .. }| {
+                        let error = match fix
+                            .filter(|fix| !(fix.span.is_empty() && fix.content.is_empty()))
+                        {
+                            Some(fix) if self.report_fixes => error.with_fix(
+                                fix.span.start as usize..fix.span.end as usize,
+                                fix.content,
+                            ),
+                            _ => error,
+                        };
+                        error.with_label_offset(source.start as usize)
+                    })
+                    .collect();
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics =
-                    DiagnosticService::wrap_diagnostics(path, source.source_text, errors);
+                let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
         }
@@ -199,6 +301,10 @@ impl Runtime {
             .parse();
 
         if !ret.errors.is_empty() {
+            // Flow is not supported, skip Flow files instead of reporting their syntax.
+            if ret.has_flow_pragma {
+                return vec![];
+            }
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
@@ -208,6 +314,7 @@ impl Runtime {
             .with_cfg(true)
             .with_build_jsdoc(true)
             .with_check_syntax_error(check_syntax_errors)
+            .with_definition_files(true)
             .build_module_record(path, &ret.program);
         let module_record = semantic_builder.module_record();
 
@@ -221,7 +328,14 @@ impl Runtime {
                 .keys()
                 .par_bridge()
                 .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    match resolver.resolve(dir, specifier) {
+                        Ok(resolution) => Some((specifier, resolution)),
+                        Err(ResolveError::NotFound(_)) => {
+                            module_record.unresolved_modules.insert(specifier.clone());
+                            None
+                        }
+                        Err(_) => None,
+                    }
                 })
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
@@ -274,7 +388,7 @@ impl Runtime {
             }
 
             // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            if !self.is_linted(path) {
                 return vec![];
             }
         }
@@ -287,7 +401,72 @@ impl Runtime {
 
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
-        self.linter.run(path, Rc::new(semantic))
+        let module_kind = self.module_kind(path, source_type);
+        self.linter.run_with_module_kind(path, Rc::new(semantic), module_kind)
+    }
+
+    /// Run project rules once all paths have been processed. Does nothing
+    /// unless cross-module analysis is enabled.
+    pub(super) fn run_project_rules(&self, tx_error: &DiagnosticSender) {
+        if self.resolver.is_none() {
+            return;
+        }
+
+        let linted = self.iter_paths().cloned().collect();
+        let graph = ModuleGraph::new(self.modules.resolved_modules(), linted);
+        let mut diagnostics_by_path = FxHashMap::<Box<Path>, Vec<OxcDiagnostic>>::default();
+        for (path, diagnostic) in self.linter.run_on_project(&graph) {
+            // Dependencies are only visited to resolve imports, they are not linted.
+            if graph.is_linted(&path) {
+                diagnostics_by_path.entry(path).or_default().push(diagnostic);
+            }
+        }
+
+        for (path, errors) in diagnostics_by_path {
+            let Ok(source_text) = self.read_source_text(&path) else {
+                continue;
+            };
+            let path = path.strip_prefix(&self.cwd).unwrap_or(&path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// Find the paths that import changed files, directly or through other
+    /// modules, by visiting all paths without linting them. Does nothing unless
+    /// only changed files are linted with cross-module analysis enabled.
+    pub(super) fn resolve_changed_files(&self) {
+        let Some(changed_files) = &self.changed_files else {
+            return;
+        };
+        if self.linted_paths.get().is_some() {
+            return;
+        }
+
+        // Problems such as parse errors are reported when the paths are linted.
+        let (tx_error, _rx_error) = mpsc::channel();
+        self.paths
+            .iter()
+            .par_bridge()
+            .for_each_with(tx_error, |tx_error, path| self.process_path(path, tx_error));
+
+        let graph = ModuleGraph::new(self.modules.resolved_modules(), FxHashSet::default());
+        let mut linted = FxHashSet::default();
+        let mut stack = changed_files.iter().map(AsRef::as_ref).collect::<Vec<&Path>>();
+        while let Some(path) = stack.pop() {
+            if linted.insert(Box::from(path)) {
+                stack.extend(graph.dependents(path));
+            }
+        }
+        linted.retain(|path| self.paths.contains(path));
+
+        // The linted paths and their dependencies are visited again.
+        self.modules.clear();
+        let _ = self.linted_paths.set(linted);
+    }
+
+    fn is_linted(&self, path: &Path) -> bool {
+        self.linted_paths.get().is_some_and(|paths| paths.contains(path))
     }
 
     pub(super) fn init_cache_state(&self, path: &Path) -> bool {
@@ -303,10 +482,11 @@ impl Runtime {
     }
 
     pub(super) fn number_of_dependencies(&self) -> usize {
-        self.modules.len() - self.paths.len()
+        self.modules.len().saturating_sub(self.iter_paths().count())
     }
 
+    /// The paths that are linted, see [`Runtime::resolve_changed_files`].
     pub(super) fn iter_paths(&self) -> impl Iterator<Item = &Box<Path>> + '_ {
-        self.paths.iter()
+        self.linted_paths.get().into_iter().flatten()
     }
 }
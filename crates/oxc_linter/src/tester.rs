@@ -5,7 +5,11 @@ use std::{
 
 use cow_utils::CowUtils;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, GraphicalTheme, NamedSource};
+use oxc_diagnostics::{
+    DiagnosticService, GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -14,19 +18,97 @@ use crate::{
     LintServiceOptions, LinterBuilder, Oxlintrc, RuleEnum, RuleWithSeverity,
 };
 
-#[derive(Eq, PartialEq)]
 enum TestResult {
     Passed,
-    Failed,
+    Failed(Vec<ReportedError>),
     Fixed(String),
 }
 
+/// A diagnostic reported while running a test case, with its 1-based position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReportedError {
+    message: String,
+    line: usize,
+    column: usize,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TestCase {
     source: String,
     rule_config: Option<Value>,
     eslint_config: Option<Value>,
     path: Option<PathBuf>,
+    /// Number of diagnostics a failing case must report.
+    expected_error_count: Option<usize>,
+    /// Diagnostics a failing case must report, in source order.
+    expected_errors: Option<Vec<ExpectError>>,
+}
+
+impl TestCase {
+    /// Configure the rule for this case only.
+    #[must_use]
+    pub fn with_rule_config(mut self, rule_config: Value) -> Self {
+        self.rule_config = Some(rule_config);
+        self
+    }
+
+    /// Lint this case as if it were the file at `path`, relative to the tester's working
+    /// directory. The source type is inferred from the extension, e.g. `foo.cjs` or `foo.d.ts`.
+    #[must_use]
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Assert that this case reports exactly `count` diagnostics.
+    #[must_use]
+    pub fn expect_error_count(mut self, count: usize) -> Self {
+        self.expected_error_count = Some(count);
+        self
+    }
+
+    /// Assert that this case reports exactly these diagnostics, ordered by their position in
+    /// the source.
+    ///
+    /// ```ignore
+    /// let fail = vec![
+    ///     TestCase::from("debugger; debugger;")
+    ///         .expect_errors(vec![("not allowed", 1, 1), ("not allowed", 1, 11)]),
+    /// ];
+    /// ```
+    #[must_use]
+    pub fn expect_errors<E: Into<ExpectError>>(mut self, errors: Vec<E>) -> Self {
+        self.expected_errors = Some(errors.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// An expected diagnostic of a failing [`TestCase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectError {
+    /// A substring of the diagnostic message.
+    message: String,
+    /// The 1-based line and column of the diagnostic's primary label.
+    position: Option<(usize, usize)>,
+}
+
+impl From<&str> for ExpectError {
+    fn from(message: &str) -> Self {
+        Self { message: message.to_string(), position: None }
+    }
+}
+
+impl From<(&str, usize, usize)> for ExpectError {
+    fn from((message, line, column): (&str, usize, usize)) -> Self {
+        Self { message: message.to_string(), position: Some((line, column)) }
+    }
+}
+
+impl ExpectError {
+    fn matches(&self, error: &ReportedError) -> bool {
+        error.message.contains(&self.message)
+            && self.position.map_or(true, |position| position == (error.line, error.column))
+    }
 }
 
 impl From<&str> for TestCase {
@@ -62,7 +144,7 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
             Option<PathBuf>,
         ),
     ) -> Self {
-        Self { source: source.to_string(), rule_config, eslint_config, path }
+        Self { source: source.to_string(), rule_config, eslint_config, path, ..Self::default() }
     }
 }
 
@@ -332,6 +414,9 @@ impl Tester {
         self.test_pass();
         self.test_fail();
         self.test_fix();
+        if env::var_os("OXC_CHECK_FIXES").is_some() {
+            self.check_fixes();
+        }
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -355,10 +440,10 @@ impl Tester {
     }
 
     fn test_pass(&mut self) {
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_pass.clone() {
+        for TestCase { source, rule_config, eslint_config, path, .. } in self.expect_pass.clone() {
             let result =
                 self.run(&source, rule_config.clone(), &eslint_config, path, ExpectFixKind::None);
-            let passed = result == TestResult::Passed;
+            let passed = matches!(result, TestResult::Passed);
             let config = rule_config.map_or_else(
                 || "\n\n------------------------\n".to_string(),
                 |v| {
@@ -377,22 +462,49 @@ impl Tester {
     }
 
     fn test_fail(&mut self) {
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_fail.clone() {
-            let result =
-                self.run(&source, rule_config.clone(), &eslint_config, path, ExpectFixKind::None);
-            let failed = result == TestResult::Failed;
-            let config = rule_config.map_or_else(
+        for test_case in self.expect_fail.clone() {
+            let TestCase { source, rule_config, eslint_config, path, .. } = &test_case;
+            let result = self.run(
+                source,
+                rule_config.clone(),
+                eslint_config,
+                path.clone(),
+                ExpectFixKind::None,
+            );
+            let config = rule_config.as_ref().map_or_else(
                 || "\n\n------------------------".to_string(),
                 |v| {
                     format!(
                         "\n-------- rule config --------\n{}",
-                        serde_json::to_string_pretty(&v).unwrap()
+                        serde_json::to_string_pretty(v).unwrap()
                     )
                 },
             );
+            let TestResult::Failed(errors) = result else {
+                panic!(
+                    "expected test to fail, but it passed:\n\n-------- source --------\n\n{source}{config}\n",
+                );
+            };
+            Self::check_errors(&test_case, &errors);
+        }
+    }
+
+    fn check_errors(test_case: &TestCase, errors: &[ReportedError]) {
+        let source = &test_case.source;
+        if let Some(count) = test_case.expected_error_count {
+            assert_eq!(
+                errors.len(),
+                count,
+                "expected {count} diagnostics, but found {}:\n\n-------- source --------\n\n{source}\n\n-------- diagnostics --------\n{errors:#?}\n",
+                errors.len(),
+            );
+        }
+        if let Some(expected) = &test_case.expected_errors {
+            let matches = expected.len() == errors.len()
+                && expected.iter().zip(errors).all(|(expected, error)| expected.matches(error));
             assert!(
-                failed,
-                "expected test to fail, but it passed:\n\n-------- source --------\n\n{source}{config}\n",
+                matches,
+                "diagnostics do not match:\n\n-------- source --------\n\n{source}\n\n-------- expected --------\n{expected:#?}\n\n-------- found --------\n{errors:#?}\n",
             );
         }
     }
@@ -414,11 +526,129 @@ impl Tester {
                     r#"Expected "{source}" to be fixed into "{expected}""#
                 ),
                 TestResult::Passed => panic!("Expected a fix, but test passed: {source}"),
-                TestResult::Failed => panic!("Expected a fix, but test failed: {source}"),
+                TestResult::Failed(_) => panic!("Expected a fix, but test failed: {source}"),
             }
         }
     }
 
+    /// Sanity checks for every fix produced by the failing and fix test cases, enabled with
+    /// `OXC_CHECK_FIXES=1 cargo test -p oxc_linter`. Like `--fix`, fixes are applied over and
+    /// over until the code stops changing, e.g. to fix the inner object of
+    /// `{a:1, c:{y:1, x:1}, b:1}` after the outer one. This must
+    ///
+    /// 1. produce code that still parses after every pass,
+    /// 2. leave fewer diagnostics behind than there were before, and
+    /// 3. stop changing the code within [`MAX_FIX_PASSES`] passes.
+    ///
+    /// Rules whose fixes are known to break these rules are listed in [`UNCHECKED_FIX_RULES`].
+    fn check_fixes(&mut self) {
+        if UNCHECKED_FIX_RULES.contains(&self.rule_name) {
+            return;
+        }
+        let snapshot_len = self.snapshot.len();
+        let cases = self
+            .expect_fail
+            .iter()
+            .map(|case| {
+                let TestCase { source, rule_config, eslint_config, path, .. } = case.clone();
+                (source, rule_config, eslint_config, path)
+            })
+            .chain(
+                self.expect_fix
+                    .iter()
+                    .flatten()
+                    .map(|fix| (fix.source.clone(), fix.rule_config.clone(), None, None)),
+            )
+            .collect::<Vec<_>>();
+
+        let mut problems = vec![];
+        for (source, rule_config, eslint_config, path) in cases {
+            let config = (rule_config, eslint_config);
+            if let Err(problem) = self.check_fix(&source, &config, path.as_ref()) {
+                problems.push(format!("{problem}\n\n-------- source --------\n\n{source}\n"));
+            }
+        }
+
+        self.snapshot.truncate(snapshot_len);
+        assert!(problems.is_empty(), "{}", problems.join("\n========================\n\n"));
+    }
+
+    fn check_fix(
+        &mut self,
+        source: &str,
+        config: &(Option<Value>, Option<Value>),
+        path: Option<&PathBuf>,
+    ) -> Result<(), String> {
+        let source_type = self.source_type(path);
+        if !parses(source, source_type) {
+            return Ok(());
+        }
+
+        let mut fixed = source.to_string();
+        let mut passes = 0;
+        while let TestResult::Fixed(fixed_again) = self.run_with_fix(&fixed, config, path) {
+            if fixed_again == fixed {
+                break;
+            }
+            if !parses(&fixed_again, source_type) {
+                return Err(format!(
+                    "fixed code does not parse:\n\n-------- fixed --------\n\n{fixed_again}"
+                ));
+            }
+            passes += 1;
+            if passes > MAX_FIX_PASSES {
+                return Err(format!(
+                    "fixes keep changing the code after {MAX_FIX_PASSES} passes:\n\n-------- fixed --------\n\n{fixed_again}"
+                ));
+            }
+            fixed = fixed_again;
+        }
+        if fixed == source {
+            return Ok(());
+        }
+
+        let count_errors = |result: TestResult| match result {
+            TestResult::Failed(errors) => errors.len(),
+            _ => 0,
+        };
+        let before = count_errors(self.run_case(source, config, path));
+        let after = count_errors(self.run_case(&fixed, config, path));
+        if after >= before {
+            return Err(format!(
+                "fixing did not resolve any diagnostics ({before} before, {after} after):\n\n-------- fixed --------\n\n{fixed}"
+            ));
+        }
+        Ok(())
+    }
+
+    fn run_case(
+        &mut self,
+        source_text: &str,
+        (rule_config, eslint_config): &(Option<Value>, Option<Value>),
+        path: Option<&PathBuf>,
+    ) -> TestResult {
+        let fix = ExpectFixKind::None;
+        self.run(source_text, rule_config.clone(), eslint_config, path.cloned(), fix)
+    }
+
+    fn run_with_fix(
+        &mut self,
+        source_text: &str,
+        (rule_config, eslint_config): &(Option<Value>, Option<Value>),
+        path: Option<&PathBuf>,
+    ) -> TestResult {
+        let fix = ExpectFixKind::Specific(FixKind::All);
+        self.run(source_text, rule_config.clone(), eslint_config, path.cloned(), fix)
+    }
+
+    fn source_type(&self, path: Option<&PathBuf>) -> SourceType {
+        let path = match path {
+            Some(path) if !self.plugins.has_import() => path,
+            _ => &self.rule_path,
+        };
+        SourceType::from_path(path).unwrap_or_default()
+    }
+
     fn run(
         &mut self,
         source_text: &str,
@@ -475,6 +705,28 @@ impl Tester {
         }
         .to_string_lossy();
 
+        let mut errors = result
+            .iter()
+            .map(|diagnostic| {
+                let offset = diagnostic
+                    .error
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| {
+                        labels.iter().find(|label| label.primary()).or(labels.first())
+                    })
+                    .map_or(0, LabeledSpan::offset);
+                let before = &source_text[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                ReportedError {
+                    message: diagnostic.error.message.to_string(),
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                }
+            })
+            .collect::<Vec<_>>();
+        errors.sort_by_key(|error| (error.line, error.column));
+
         let handler = GraphicalReportHandler::new()
             .with_links(false)
             .with_theme(GraphicalTheme::unicode_nocolor());
@@ -485,7 +737,7 @@ impl Tester {
             ));
             handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
         }
-        TestResult::Failed
+        TestResult::Failed(errors)
     }
 
     fn find_rule(&self) -> &RuleEnum {
@@ -495,3 +747,19 @@ impl Tester {
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
+
+/// How often [`Tester::check_fixes`] applies fixes before giving up, as ESLint does.
+const MAX_FIX_PASSES: usize = 10;
+
+/// Rules skipped by [`Tester::check_fixes`].
+const UNCHECKED_FIX_RULES: &[&str] = &[
+    // `@ts-ignore` is replaced with `@ts-expect-error`, which is reported as
+    // well unless it is allowed or has a description.
+    "ban-ts-comment",
+];
+
+fn parses(source_text: &str, source_type: SourceType) -> bool {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    !ret.panicked && ret.errors.is_empty()
+}
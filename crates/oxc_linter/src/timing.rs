@@ -0,0 +1,149 @@
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    rules::{RuleEnum, RULES},
+    RuleWithSeverity,
+};
+
+/// Total time spent in a single rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub plugin_name: Cow<'static, str>,
+    /// `*` for external plugins, which run all their rules at once.
+    pub rule_name: Cow<'static, str>,
+    pub duration: Duration,
+    /// Number of files the rule ran on. Rules skip files they do not apply to, e.g. Jest rules
+    /// on non-test files.
+    pub files: usize,
+}
+
+/// Wall time spent in each rule of a [`Linter`], accumulated across all files it linted.
+///
+/// Timings are shared between threads, so a [`Linter`] used by a [`LintService`] reports the
+/// total for the whole run.
+///
+/// [`Linter`]: crate::Linter
+/// [`LintService`]: crate::LintService
+#[derive(Debug)]
+pub(crate) struct RuleTimings {
+    /// Whether each rule in [`RULES`], by [`id`](RuleEnum::id), is configured on the linter.
+    /// Other rules only show up in timings if they ran, e.g. because of an override.
+    configured: Box<[bool]>,
+    nanos: Box<[AtomicU64]>,
+    files: Box<[AtomicUsize]>,
+    /// Time and number of files of each external plugin, by name.
+    external: Mutex<FxHashMap<String, (Duration, usize)>>,
+}
+
+impl RuleTimings {
+    pub fn new(rules: &[RuleWithSeverity]) -> Self {
+        let mut configured = vec![false; RULES.len()].into_boxed_slice();
+        for rule in rules {
+            configured[rule.id()] = true;
+        }
+        Self {
+            configured,
+            nanos: RULES.iter().map(|_| AtomicU64::new(0)).collect(),
+            files: RULES.iter().map(|_| AtomicUsize::new(0)).collect(),
+            external: Mutex::default(),
+        }
+    }
+
+    /// Record that `rule` ran on a file for `duration`.
+    pub fn add(&self, rule: &RuleEnum, duration: Duration) {
+        self.add_duration(rule, duration);
+        self.files[rule.id()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Add `duration` to `rule`, without counting a file. Used for project rules.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn add_duration(&self, rule: &RuleEnum, duration: Duration) {
+        self.nanos[rule.id()].fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    /// Record that the external plugin `plugin_name` ran on a file for `duration`.
+    pub fn add_external(&self, plugin_name: &str, duration: Duration) {
+        let mut external = self.external.lock().unwrap();
+        let (total, files) = external.entry(plugin_name.to_string()).or_default();
+        *total += duration;
+        *files += 1;
+    }
+
+    /// Timings of every rule, slowest first.
+    pub fn get(&self) -> Vec<RuleTiming> {
+        let mut timings = RULES
+            .iter()
+            .zip(self.nanos.iter().zip(self.files.iter()))
+            .map(|(rule, (nanos, files))| RuleTiming {
+                plugin_name: rule.plugin_name().into(),
+                rule_name: rule.name().into(),
+                duration: Duration::from_nanos(nanos.load(Ordering::Relaxed)),
+                files: files.load(Ordering::Relaxed),
+            })
+            .zip(self.configured.iter())
+            .filter(|(timing, configured)| **configured || timing.files > 0)
+            .map(|(timing, _)| timing)
+            .collect::<Vec<_>>();
+        timings.extend(self.external.lock().unwrap().iter().map(|(name, (duration, files))| {
+            RuleTiming {
+                plugin_name: name.clone().into(),
+                rule_name: "*".into(),
+                duration: *duration,
+                files: *files,
+            }
+        }));
+        timings.sort_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| a.plugin_name.cmp(&b.plugin_name))
+                .then_with(|| a.rule_name.cmp(&b.rule_name))
+        });
+        timings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::RuleTimings;
+    use crate::{rules::RULES, AllowWarnDeny, RuleWithSeverity};
+
+    #[test]
+    fn test_rule_timings() {
+        let rules = RULES
+            .iter()
+            .take(2)
+            .map(|rule| RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn))
+            .collect::<Vec<_>>();
+        let timings = RuleTimings::new(&rules);
+        timings.add(&rules[0], Duration::from_millis(1));
+        timings.add(&rules[1], Duration::from_millis(2));
+        timings.add(&rules[0], Duration::from_millis(3));
+        timings.add_duration(&rules[1], Duration::from_millis(1));
+        // Not configured, but ran on a file.
+        timings.add(&RULES[2], Duration::from_millis(0));
+        timings.add_external("acme", Duration::from_millis(2));
+
+        let timings = timings.get();
+        assert_eq!(timings[0].rule_name, rules[0].name());
+        assert_eq!(timings[0].duration, Duration::from_millis(4));
+        assert_eq!(timings[0].files, 2);
+        assert_eq!(timings[1].duration, Duration::from_millis(3));
+        assert_eq!(timings[1].files, 1);
+        assert_eq!(timings[2].plugin_name, "acme");
+        assert_eq!(timings[2].rule_name, "*");
+        assert_eq!(timings[2].files, 1);
+        assert_eq!(timings.len(), 4);
+        assert_eq!(timings[3].rule_name, RULES[2].name());
+    }
+}
@@ -0,0 +1,122 @@
+//! Type information for type-aware rules.
+//!
+//! oxc does not type check code itself. Rules that benefit from types, such as
+//! `typescript/no-floating-promises`, ask a [`TypeInfoProvider`] when one is
+//! set with [`LinterBuilder::with_type_info_provider`], and fall back to
+//! syntactic checks when it is not set or knows nothing about an expression.
+//!
+//! [`LinterBuilder::with_type_info_provider`]: crate::LinterBuilder::with_type_info_provider
+
+use std::{fmt, path::Path};
+
+use bitflags::bitflags;
+use oxc_span::Span;
+
+/// A source of type information, e.g. a TypeScript language server.
+///
+/// Files are linted in parallel, so implementations must be safe to call
+/// from several threads at once.
+pub trait TypeInfoProvider: fmt::Debug + Send + Sync {
+    /// Type information for a single file, or [`None`] if it is not
+    /// available, e.g. because the file is not part of a TypeScript project.
+    ///
+    /// This is called at most once per linted file, the first time a rule
+    /// asks for type information.
+    fn file(&self, path: &Path, source_text: &str) -> Option<Box<dyn FileTypeInfo>>;
+}
+
+/// Type information about the expressions of a single file.
+pub trait FileTypeInfo {
+    /// Facts about the type of the expression covering exactly `span`, or
+    /// [`None`] if its type is not known.
+    fn expression_type(&self, span: Span) -> Option<TypeFacts>;
+}
+
+bitflags! {
+    /// Facts about the type of an expression that rules can rely on.
+    ///
+    /// Facts of union types are combined, e.g. `Promise<void> | any` is both
+    /// [`TypeFacts::THENABLE`] and [`TypeFacts::ANY`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TypeFacts: u8 {
+        /// The type is `any` or `unknown`, so nothing else is known about it.
+        const ANY = 1 << 0;
+        /// The value may be a promise or another object with a `then` method.
+        const THENABLE = 1 << 1;
+    }
+}
+
+impl TypeFacts {
+    /// Whether the value is known to be a thenable, e.g. a promise.
+    pub fn is_thenable(self) -> bool {
+        !self.contains(Self::ANY) && self.contains(Self::THENABLE)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+    use serde_json::json;
+
+    use super::{FileTypeInfo, TypeFacts, TypeInfoProvider};
+    use crate::{LinterBuilder, Oxlintrc};
+
+    /// Knows that calls of `fetchData()` return a promise.
+    #[derive(Debug)]
+    struct FetchDataProvider;
+
+    struct FetchDataTypes(Vec<Span>);
+
+    impl TypeInfoProvider for FetchDataProvider {
+        fn file(&self, _path: &Path, source_text: &str) -> Option<Box<dyn FileTypeInfo>> {
+            #[allow(clippy::cast_possible_truncation)]
+            let spans = source_text
+                .match_indices("fetchData()")
+                .map(|(start, text)| Span::new(start as u32, (start + text.len()) as u32))
+                .collect();
+            Some(Box::new(FetchDataTypes(spans)))
+        }
+    }
+
+    impl FileTypeInfo for FetchDataTypes {
+        fn expression_type(&self, span: Span) -> Option<TypeFacts> {
+            self.0.contains(&span).then_some(TypeFacts::THENABLE)
+        }
+    }
+
+    fn lint(path: &str, source_text: &str, with_provider: bool) -> usize {
+        let config = json!({ "rules": { "typescript/no-floating-promises": "error" } });
+        let oxlintrc: Oxlintrc = serde_json::from_value(config).unwrap();
+        let mut builder = LinterBuilder::from_oxlintrc(true, oxlintrc).unwrap();
+        if with_provider {
+            builder = builder.with_type_info_provider(Arc::new(FetchDataProvider));
+        }
+        let linter = builder.build();
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        linter.run(Path::new(path), Rc::new(semantic)).len()
+    }
+
+    #[test]
+    fn test_type_info_provider() {
+        let source_text = "import { fetchData } from './api';\nfetchData();\nawait fetchData();";
+        assert_eq!(lint("test.ts", source_text, true), 1);
+        // Rules fall back to syntactic checks without type information.
+        assert_eq!(lint("test.ts", source_text, false), 0);
+        assert_eq!(lint("test.mts", "fetchData().then(() => {}, () => {});", true), 0);
+    }
+
+    #[test]
+    fn test_type_facts() {
+        assert!(TypeFacts::THENABLE.is_thenable());
+        assert!(!(TypeFacts::THENABLE | TypeFacts::ANY).is_thenable());
+        assert!(!TypeFacts::default().is_thenable());
+    }
+}
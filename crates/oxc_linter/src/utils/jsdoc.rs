@@ -64,17 +64,7 @@ pub fn get_function_nearest_jsdoc_node<'a, 'b>(
 }
 
 pub fn should_ignore_as_internal(jsdoc: &JSDoc, settings: &JSDocPluginSettings) -> bool {
-    if settings.ignore_internal {
-        let resolved_internal_tag_name = settings.resolve_tag_name("internal");
-
-        for tag in jsdoc.tags() {
-            if tag.kind.parsed() == resolved_internal_tag_name {
-                return true;
-            }
-        }
-    }
-
-    false
+    settings.ignore_internal && jsdoc.has_tag(settings.resolve_tag_name("internal"))
 }
 
 pub fn should_ignore_as_private(jsdoc: &JSDoc, settings: &JSDocPluginSettings) -> bool {
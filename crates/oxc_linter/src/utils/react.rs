@@ -2,15 +2,18 @@ use std::borrow::Cow;
 
 use oxc_ast::{
     ast::{
-        CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXChild, JSXElement, JSXExpression, JSXOpeningElement, MemberExpression,
+        ArrayPattern, BindingPatternKind, CallExpression, Expression, Function, JSXAttributeItem,
+        JSXAttributeName, JSXAttributeValue, JSXChild, JSXElement, JSXExpression,
+        JSXOpeningElement, MemberExpression, VariableDeclarator,
     },
     match_member_expression, AstKind,
 };
 use oxc_ecmascript::ToBoolean;
-use oxc_semantic::AstNode;
+use oxc_semantic::{AstNode, AstNodes, NodeId, SymbolId};
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{LintContext, OxlintSettings};
+use crate::{FileAnalysis, LintContext, OxlintSettings};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     match &call_expr.callee {
@@ -144,23 +147,21 @@ pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningEle
     }
 }
 
-const PRAGMA: &str = "React";
-const CREATE_CLASS: &str = "createReactClass";
-
-pub fn is_es5_component(node: &AstNode) -> bool {
+pub fn is_es5_component(node: &AstNode, ctx: &LintContext) -> bool {
     let AstKind::CallExpression(call_expr) = node.kind() else {
         return false;
     };
+    let settings = &ctx.settings().react;
 
     if let Some(member_expr) = call_expr.callee.as_member_expression() {
         if let Expression::Identifier(ident) = member_expr.object() {
-            return ident.name == PRAGMA
-                && member_expr.static_property_name() == Some(CREATE_CLASS);
+            return ident.name == settings.pragma()
+                && member_expr.static_property_name() == Some(settings.create_class());
         }
     }
 
     if let Some(ident_reference) = call_expr.callee.get_identifier_reference() {
-        return ident_reference.name == CREATE_CLASS;
+        return ident_reference.name == settings.create_class();
     }
 
     false
@@ -169,14 +170,14 @@ pub fn is_es5_component(node: &AstNode) -> bool {
 const COMPONENT: &str = "Component";
 const PURE_COMPONENT: &str = "PureComponent";
 
-pub fn is_es6_component(node: &AstNode) -> bool {
+pub fn is_es6_component(node: &AstNode, ctx: &LintContext) -> bool {
     let AstKind::Class(class_expr) = node.kind() else {
         return false;
     };
     if let Some(super_class) = &class_expr.super_class {
         if let Some(member_expr) = super_class.as_member_expression() {
             if let Expression::Identifier(ident) = member_expr.object() {
-                return ident.name == PRAGMA
+                return ident.name == ctx.settings().react.pragma()
                     && member_expr
                         .static_property_name()
                         .is_some_and(|name| name == COMPONENT || name == PURE_COMPONENT);
@@ -197,7 +198,7 @@ pub fn get_parent_component<'a, 'b>(
 ) -> Option<&'b AstNode<'a>> {
     for node_id in ctx.nodes().ancestors(node.id()) {
         let node = ctx.nodes().get_node(node_id);
-        if is_es5_component(node) || is_es6_component(node) {
+        if is_es5_component(node, ctx) || is_es6_component(node, ctx) {
             return Some(node);
         }
     }
@@ -312,9 +313,264 @@ pub fn is_react_function_call(call: &CallExpression, expected_call: &str) -> boo
     if let Some(member) = call.callee.as_member_expression() {
         matches! {
             member.object().get_identifier_reference(),
-            Some(ident) if ident.name.as_str() == PRAGMA
+            Some(ident) if ident.name.as_str() == "React"
         }
     } else {
         true
     }
 }
+
+/// The bindings of a `useState` call, e.g. `const [count, setCount] = useState(0)`.
+#[derive(Debug, Clone, Copy)]
+pub struct StateBinding {
+    /// The state value, unless it is not bound, e.g. `const [, setCount] = useState(0)`.
+    pub state: Option<SymbolId>,
+}
+
+/// Get the pattern a `useState` call is destructured into, e.g. `[count, setCount]` in
+/// `const [count, setCount] = useState(0)`.
+pub fn get_use_state_pattern<'a, 'b>(
+    decl: &'b VariableDeclarator<'a>,
+) -> Option<&'b ArrayPattern<'a>> {
+    let BindingPatternKind::ArrayPattern(pattern) = &decl.id.kind else { return None };
+    let Some(Expression::CallExpression(call)) =
+        decl.init.as_ref().map(Expression::get_inner_expression)
+    else {
+        return None;
+    };
+    is_react_function_call(call, "useState").then_some(&**pattern)
+}
+
+/// Get the `useState` bindings if `symbol_id` is the setter of a `useState` call. Setters
+/// are stable across renders.
+pub fn get_state_binding(symbol_id: SymbolId, ctx: &LintContext) -> Option<StateBinding> {
+    let AstKind::VariableDeclarator(decl) = ctx.symbol_declaration(symbol_id).kind() else {
+        return None;
+    };
+    let pattern = get_use_state_pattern(decl)?;
+    let binding = |index: usize| {
+        pattern.elements.get(index)?.as_ref()?.get_binding_identifier()?.symbol_id.get()
+    };
+    if binding(1) != Some(symbol_id) {
+        return None;
+    }
+    Some(StateBinding { state: binding(0) })
+}
+
+/// Whether a function is a React component or a custom hook. See [`ReactFunctions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactFunctionKind {
+    /// A function component, e.g. `function Button() { return <button /> }`.
+    Component,
+    /// A custom hook, e.g. `function useCounter() {}`.
+    Hook,
+}
+
+/// Classifies every function in a file as a React component, a custom hook, or
+/// neither. Computed once per file, get it with [`LintContext::get_or_compute`].
+///
+/// A function is a hook if it is named like one (`useFoo`). It is a component if
+/// - it is named like one (`Foo`),
+/// - it is passed directly to `memo` or `forwardRef`, or
+/// - it is the default export and returns JSX.
+///
+/// Functions are named either by their own identifier, or by the variable,
+/// property or assignment target they are directly assigned to.
+#[derive(Debug, Default)]
+pub struct ReactFunctions {
+    kinds: FxHashMap<NodeId, ReactFunctionKind>,
+    returns_jsx: FxHashSet<NodeId>,
+}
+
+impl ReactFunctions {
+    /// Get the classification of the `Function` or `ArrowFunctionExpression`
+    /// node with the given id.
+    pub fn kind(&self, function_id: NodeId) -> Option<ReactFunctionKind> {
+        self.kinds.get(&function_id).copied()
+    }
+
+    /// Whether the function with the given id directly returns a JSX element or
+    /// fragment, e.g. `() => <div />`.
+    pub fn returns_jsx(&self, function_id: NodeId) -> bool {
+        self.returns_jsx.contains(&function_id)
+    }
+}
+
+impl FileAnalysis for ReactFunctions {
+    fn compute(ctx: &LintContext<'_>) -> Self {
+        let nodes = ctx.nodes();
+
+        let mut returns_jsx = FxHashSet::default();
+        for node in nodes {
+            let returned = match node.kind() {
+                AstKind::ReturnStatement(ret) => ret.argument.as_ref(),
+                AstKind::ArrowFunctionExpression(arrow) => arrow.get_expression(),
+                _ => continue,
+            };
+            if !returned.is_some_and(|expr| {
+                matches!(
+                    expr.get_inner_expression(),
+                    Expression::JSXElement(_) | Expression::JSXFragment(_)
+                )
+            }) {
+                continue;
+            }
+            // `iter_parents` starts at the node itself, which finds arrow functions with expression bodies.
+            if let Some(function) =
+                nodes.iter_parents(node.id()).find(|n| n.kind().is_function_like())
+            {
+                returns_jsx.insert(function.id());
+            }
+        }
+
+        let kinds = nodes
+            .iter()
+            .filter(|node| node.kind().is_function_like())
+            .filter_map(|node| {
+                let kind = classify_function(nodes, node.id(), returns_jsx.contains(&node.id()))?;
+                Some((node.id(), kind))
+            })
+            .collect();
+
+        Self { kinds, returns_jsx }
+    }
+}
+
+fn classify_function(
+    nodes: &AstNodes,
+    function_id: NodeId,
+    returns_jsx: bool,
+) -> Option<ReactFunctionKind> {
+    if let Some(name) = get_function_name(nodes, function_id) {
+        if is_react_hook_name(&name) {
+            return Some(ReactFunctionKind::Hook);
+        }
+        if is_react_component_name(&name) {
+            return Some(ReactFunctionKind::Component);
+        }
+    }
+
+    let parent =
+        nodes.iter_parents(function_id).skip(1).find(|node| !is_transparent(node.kind()))?;
+    let is_component = match parent.kind() {
+        // memo(() => {}), React.forwardRef(function () {})
+        AstKind::Argument(_) => nodes.parent_kind(parent.id()).is_some_and(|kind| {
+            matches!(kind, AstKind::CallExpression(call)
+                if is_react_function_call(call, "memo") || is_react_function_call(call, "forwardRef"))
+        }),
+        // export default () => <div />
+        AstKind::ExportDefaultDeclaration(_) => returns_jsx,
+        _ => false,
+    };
+    is_component.then_some(ReactFunctionKind::Component)
+}
+
+/// Get the name of a `Function` or `ArrowFunctionExpression`, either from its
+/// own identifier or from what it is directly assigned to.
+///
+/// ```js
+/// function foo() {}          // foo
+/// const foo = () => {};      // foo
+/// foo = function () {};      // foo
+/// const { foo = () => {} } = {};  // foo
+/// ({ foo: () => {} });       // foo
+/// ```
+pub fn get_function_name<'a>(nodes: &AstNodes<'a>, function_id: NodeId) -> Option<Cow<'a, str>> {
+    if let AstKind::Function(Function { id: Some(id), .. }) = nodes.kind(function_id) {
+        return Some(Cow::Borrowed(id.name.as_str()));
+    }
+    let parent =
+        nodes.iter_parents(function_id).skip(1).find(|node| !is_transparent(node.kind()))?;
+    match parent.kind() {
+        AstKind::VariableDeclarator(decl) => {
+            decl.id.get_identifier().map(|id| Cow::Borrowed(id.as_str()))
+        }
+        AstKind::AssignmentExpression(expr) if expr.operator == AssignmentOperator::Assign => {
+            expr.left.get_identifier().map(Cow::Borrowed)
+        }
+        AstKind::AssignmentPattern(pattern) => {
+            pattern.left.get_identifier().map(|id| Cow::Borrowed(id.as_str()))
+        }
+        AstKind::ObjectProperty(prop) => prop.key.name(),
+        _ => None,
+    }
+}
+
+/// Nodes that wrap an expression without changing its value.
+pub fn is_transparent(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            | AstKind::TSTypeAssertion(_)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{get_function_name, ReactFunctionKind, ReactFunctions};
+    use crate::{options::LintOptions, ContextHost};
+
+    #[test]
+    fn test_react_functions() {
+        let source_text = "
+            function Button() {}
+            function useCounter() {}
+            function helper() {}
+            const Card = () => {};
+            const useToggle = function () {};
+            const Memoized = memo(() => null);
+            const Forwarded = React.forwardRef(function (props, ref) {});
+            const render = () => <div />;
+            export default () => <></>;
+        ";
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&parser_ret.program).semantic;
+        let ctx = Rc::new(ContextHost::new(
+            "foo.jsx",
+            Rc::new(semantic),
+            LintOptions::default(),
+            Arc::default(),
+        ))
+        .spawn_for_test();
+
+        let react_functions = ctx.get_or_compute::<ReactFunctions>();
+        let kinds = ctx
+            .nodes()
+            .iter()
+            .filter(|node| node.kind().is_function_like())
+            .map(|node| {
+                let name = get_function_name(ctx.nodes(), node.id());
+                (
+                    name.as_deref().unwrap_or("<anonymous>").to_string(),
+                    react_functions.kind(node.id()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let component = Some(ReactFunctionKind::Component);
+        let hook = Some(ReactFunctionKind::Hook);
+        let expected = [
+            ("Button", component),
+            ("useCounter", hook),
+            ("helper", None),
+            ("Card", component),
+            ("useToggle", hook),
+            ("<anonymous>", component),
+            ("<anonymous>", component),
+            ("render", None),
+            ("<anonymous>", component),
+        ];
+        assert_eq!(kinds, expected.map(|(name, kind)| (name.to_string(), kind)).to_vec(),);
+    }
+}
@@ -106,7 +106,7 @@ where
             return;
         }
 
-        let declaration_node = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+        let declaration_node = ctx.symbol_declaration(symbol_id);
         if let Some((decl_span, init_span)) =
             self.check_for_violation_on_ast_kind(&declaration_node.kind(), symbol_id)
         {
@@ -0,0 +1,65 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_regular_expression::{ast::Pattern, ConstructorParser, Options};
+use oxc_semantic::AstNode;
+use oxc_span::Span;
+
+use crate::LintContext;
+
+/// Run `cb` on the pattern of the regular expression created by `node`, along with the span
+/// of the regex literal or pattern argument to report on.
+///
+/// Handles regex literals (`/a/u`), and `RegExp("a", "u")` and `new RegExp("a", "u")` called
+/// with a string literal pattern. The flags are only used when they are a string literal too.
+///
+/// Spans in a pattern from a string literal point into the source text of the string, e.g.
+/// `\x1f` in `RegExp("\\x1f")` is the 5 characters `\\x1f`.
+///
+/// Patterns that fail to parse are skipped, as they are reported by `eslint/no-invalid-regexp`.
+pub fn run_on_regex_node<'a, M>(node: &AstNode<'a>, ctx: &LintContext<'a>, cb: M)
+where
+    M: FnOnce(&Pattern<'_>, Span),
+{
+    match node.kind() {
+        AstKind::RegExpLiteral(reg) => {
+            if let Some(pattern) = reg.regex.pattern.as_pattern() {
+                cb(pattern, reg.span);
+            }
+        }
+        AstKind::NewExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+            run_on_arguments(&expr.arguments, ctx, cb);
+        }
+        AstKind::CallExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+            run_on_arguments(&expr.arguments, ctx, cb);
+        }
+        _ => {}
+    }
+}
+
+fn run_on_arguments<M>(arguments: &[Argument], ctx: &LintContext, cb: M)
+where
+    M: FnOnce(&Pattern<'_>, Span),
+{
+    // Missing or non-string patterns are runtime errors, or can't be known statically.
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return;
+    };
+    let flags_span = match arguments.get(1) {
+        Some(Argument::StringLiteral(flags)) => Some(flags.span),
+        _ => None,
+    };
+
+    let allocator = Allocator::default();
+    let parser = ConstructorParser::new(
+        &allocator,
+        pattern.span.source_text(ctx.source_text()),
+        flags_span.map(|span| span.source_text(ctx.source_text())),
+        Options {
+            pattern_span_offset: pattern.span.start,
+            flags_span_offset: flags_span.map_or(0, |span| span.start),
+        },
+    );
+    if let Ok(parsed) = parser.parse() {
+        cb(&parsed, pattern.span);
+    }
+}
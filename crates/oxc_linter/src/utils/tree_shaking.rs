@@ -10,7 +10,7 @@ use oxc_span::{CompactStr, GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
 use rustc_hash::FxHashSet;
 
-use crate::LintContext;
+use crate::{ast_util::const_eval::has_pure_annotation, LintContext};
 
 mod pure_functions;
 
@@ -223,12 +223,7 @@ fn flatten_member_expr_if_possible(function_name: &FunctionName) -> CompactStr {
 ///
 /// <https://rollupjs.org/configuration-options/#pure>
 pub fn has_pure_notation(span: Span, ctx: &LintContext) -> bool {
-    let Some(comment) = ctx.semantic().comments_range(..span.start).next_back() else {
-        return false;
-    };
-    let raw = comment.span.source_text(ctx.semantic().source_text());
-
-    raw.contains("@__PURE__") || raw.contains("#__PURE__")
+    has_pure_annotation(span, ctx.semantic())
 }
 
 const TREE_SHAKING_COMMENT_ID: &str = "tree-shaking";
@@ -62,7 +62,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
         #(pub use self::#use_stmts::#struct_names;)*
 
         use crate::{
-            context::{ContextHost, LintContext},
+            context::{ContextHost, LintContext, ProjectContext},
             rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta},
             utils::PossibleJestNode,
             AstNode
@@ -107,12 +107,38 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// AST node types this [`Rule`] runs on, or `None` for every node.
+            pub fn node_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::NODE_TYPES),*
+                }
+            }
+
             pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #plugin_names),*
                 }
             }
 
+            /// JSON schema for this [`Rule`]'s options, if it accepts any.
+            pub fn schema(&self, gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::schema(gen)),*
+                }
+            }
+
+            /// Check this [`Rule`]'s options against their type, see [`RuleMeta::check_options`].
+            pub fn check_options(&self, options: &serde_json::Value) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::check_options(options)),*
+                }
+            }
+
+            /// Link to this [`Rule`]'s documentation page.
+            pub fn docs_url(&self) -> String {
+                crate::rule::rule_docs_url(self.plugin_name(), self.name())
+            }
+
             pub fn read_json(&self, value: serde_json::Value) -> Self {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
@@ -121,6 +147,13 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// Problems in this [`Rule`]'s options, see [`Rule::validate_configuration`].
+            pub fn validate_json(&self, value: &serde_json::Value) -> Vec<(usize, String)> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
@@ -149,6 +182,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub(super) fn run_on_project(&self, ctx: &ProjectContext<'_>) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_project(ctx)),*
+                }
+            }
+
             pub(super) fn should_run(&self, ctx: &ContextHost) -> bool {
                 match self {
                     #(Self::#struct_names(rule) => rule.should_run(ctx)),*
@@ -4,14 +4,23 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
+    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token, Type,
 };
 
+mod kw {
+    syn::custom_keyword!(config);
+    syn::custom_keyword!(node_types);
+}
+
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
     /// Describes what auto-fixing capabilities the rule has
     fix: Option<Ident>,
+    /// Type of the rule's options, used to generate its JSON schema
+    config: Option<Type>,
+    /// AST node types the rule's `run` method handles
+    node_types: Option<Vec<Ident>>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -38,17 +47,46 @@ impl Parse for LintRuleMeta {
         // Parse FixMeta if it's specified. It will otherwise be excluded from
         // the RuleMeta impl, falling back on default set by RuleMeta itself.
         // Do not provide a default value here so that it can be set there instead.
-        let fix: Option<Ident> = if input.peek(Token!(,)) {
+        let mut fix: Option<Ident> = None;
+        let mut config: Option<Type> = None;
+        let mut node_types: Option<Vec<Ident>> = None;
+        while input.peek(Token!(,)) {
             input.parse::<Token!(,)>()?;
-            input.parse()?
-        } else {
-            None
-        };
+            if input.is_empty() {
+                break;
+            }
+            // `config = MyRuleConfig`
+            if input.peek(kw::config) && input.peek2(Token!(=)) {
+                input.parse::<kw::config>()?;
+                input.parse::<Token!(=)>()?;
+                config = Some(input.parse()?);
+            // `node_types = [DebuggerStatement, WithStatement]`
+            } else if input.peek(kw::node_types) && input.peek2(Token!(=)) {
+                input.parse::<kw::node_types>()?;
+                input.parse::<Token!(=)>()?;
+                let content;
+                syn::bracketed!(content in input);
+                let types = content.parse_terminated(Ident::parse, Token!(,))?;
+                node_types = Some(types.into_iter().collect());
+            } else if fix.is_none() && config.is_none() && node_types.is_none() {
+                fix = Some(input.parse()?);
+            } else {
+                return Err(input.error("expected `config = <type>` or `node_types = [..]`"));
+            }
+        }
 
         // Ignore the rest
         input.parse::<proc_macro2::TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, fix, documentation, used_in_test: false })
+        Ok(Self {
+            name: struct_name,
+            category,
+            fix,
+            config,
+            node_types,
+            documentation,
+            used_in_test: false,
+        })
     }
 }
 
@@ -57,7 +95,8 @@ pub(crate) fn rule_name_converter() -> Converter {
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, fix, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, config, node_types, documentation, used_in_test } =
+        metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
     let category = match category.to_string().as_str() {
@@ -77,6 +116,27 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let schema = config.map(|config| {
+        quote! {
+            fn schema(gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                Some(gen.subschema_for::<#config>())
+            }
+
+            fn check_options(options: &serde_json::Value) -> Result<(), String> {
+                <#config as serde::Deserialize>::deserialize(options)
+                    .map(drop)
+                    .map_err(|err| err.to_string())
+            }
+        }
+    });
+
+    let node_types = node_types.map(|types| {
+        quote! {
+            const NODE_TYPES: Option<&'static [oxc_ast::AstType]> =
+                Some(&[#(oxc_ast::AstType::#types),*]);
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
@@ -93,6 +153,10 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #node_types
+
+            #schema
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
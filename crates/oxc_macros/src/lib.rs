@@ -11,12 +11,13 @@ mod declare_oxc_secret;
 
 /// Macro used to declare an oxc lint rule
 ///
-/// Every lint declaration consists of 4 parts:
+/// Every lint declaration consists of 4 parts, plus an optional 5th:
 ///
 /// 1. The documentation
 /// 2. The lint's struct
 /// 3. The lint's category
 /// 4. What kind of auto-fixes the lint supports
+/// 5. The type of the lint's options, as `config = MyRuleConfig`
 ///
 /// ## Documentation
 /// Lint rule documentation added here will be used to build documentation pages
@@ -50,6 +51,15 @@ mod declare_oxc_secret;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// ## Options
+///
+/// Lints that accept options may point to a type describing them with
+/// `config = MyRuleConfig`. The type must implement [`JsonSchema`], which is
+/// used to generate a JSON schema for the lint's options.
+///
+/// [`JsonSchema`]: https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html
+///
 /// # Example
 ///
 /// ```
@@ -1,3 +1,5 @@
+use oxc_syntax::identifier::ZWNBSP;
+
 use super::{Kind, Lexer};
 use crate::diagnostics;
 
@@ -237,7 +239,9 @@ ascii_byte_handler!(HAS(lexer) {
     lexer.consume_char();
     // HashbangComment ::
     //     `#!` SingleLineCommentChars?
-    if lexer.token.start == 0 && lexer.next_ascii_byte_eq(b'!') {
+    // A byte order mark (3 bytes) is allowed before it, as it is not part of the source text.
+    let source_start = if lexer.source.whole().starts_with(ZWNBSP) { 3 } else { 0 };
+    if lexer.token.start == source_start && lexer.next_ascii_byte_eq(b'!') {
         lexer.read_hashbang_comment()
     } else {
         lexer.private_identifier()
@@ -92,6 +92,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
+use oxc_syntax::identifier::{is_line_terminator, ZWNBSP};
 
 use crate::{
     lexer::{Kind, Lexer, Token},
@@ -168,6 +169,15 @@ pub struct ParserReturn<'a> {
     /// [`program`]: ParserReturn::program
     /// [`errors`]: ParserReturn::errors
     pub panicked: bool,
+
+    /// Whether the first comment of a JavaScript file is a `@flow` pragma.
+    ///
+    /// Flow syntax is not supported. If such a file fails to parse, [`errors`] only contains
+    /// a single "Flow is not supported" error, so tools can skip Flow files instead of
+    /// reporting syntax errors for them.
+    ///
+    /// [`errors`]: ParserReturn::errors
+    pub has_flow_pragma: bool,
 }
 
 /// Parse options
@@ -413,21 +423,28 @@ impl<'a> ParserImpl<'a> {
                 (program, true)
             }
         };
-        let mut errors = vec![];
-        // only check for `@flow` if the file failed to parse.
-        if !self.lexer.errors.is_empty() || !self.errors.is_empty() {
-            if let Some(error) = self.flow_error() {
-                errors.push(error);
+        let flow_pragma = self.flow_pragma();
+        let errors = match flow_pragma {
+            // Syntax errors in Flow files are most likely Flow syntax.
+            Some(span) if !self.lexer.errors.is_empty() || !self.errors.is_empty() => {
+                vec![diagnostics::flow(span)]
             }
-        }
-        if errors.len() != 1 {
-            errors.reserve(self.lexer.errors.len() + self.errors.len());
-            errors.extend(self.lexer.errors);
-            errors.extend(self.errors);
-        }
+            _ => {
+                let mut errors = Vec::with_capacity(self.lexer.errors.len() + self.errors.len());
+                errors.extend(self.lexer.errors);
+                errors.extend(self.errors);
+                errors
+            }
+        };
         let irregular_whitespaces =
             self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
-        ParserReturn { program, errors, irregular_whitespaces, panicked }
+        ParserReturn {
+            program,
+            errors,
+            irregular_whitespaces,
+            panicked,
+            has_flow_pragma: flow_pragma.is_some(),
+        }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
@@ -477,19 +494,22 @@ impl<'a> ParserImpl<'a> {
         ctx
     }
 
-    /// Check for Flow declaration if the file cannot be parsed.
+    /// Find the span of the Flow declaration of a JavaScript file.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
-    fn flow_error(&mut self) -> Option<OxcDiagnostic> {
+    fn flow_pragma(&self) -> Option<Span> {
         if !self.source_type.is_javascript() {
             return None;
         };
-        let span = self.lexer.trivia_builder.comments.first()?.span;
-        if span.source_text(self.source_text).contains("@flow") {
-            self.errors.clear();
-            Some(diagnostics::flow(span))
-        } else {
-            None
-        }
+        let comment = self.lexer.trivia_builder.comments.first()?;
+        let span = comment.span;
+        let before =
+            self.source_text[..comment.real_span_start() as usize].trim_start_matches(ZWNBSP);
+        let before = match before.strip_prefix("#!") {
+            Some(hashbang) => hashbang.find(is_line_terminator).map_or("", |i| &hashbang[i..]),
+            None => before,
+        };
+        (before.trim().is_empty() && span.source_text(self.source_text).contains("@flow"))
+            .then_some(span)
     }
 
     /// Check if source length exceeds MAX_LEN, if the file cannot be parsed.
@@ -585,9 +605,20 @@ mod test {
         ];
         for source in sources {
             let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.has_flow_pragma);
             assert_eq!(ret.errors.len(), 1);
             assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
         }
+
+        // Flow files without Flow syntax parse like any other file.
+        let ret =
+            Parser::new(&allocator, "#!/usr/bin/env node\n// @flow\nfoo;", source_type).parse();
+        assert!(ret.has_flow_pragma);
+        assert!(ret.errors.is_empty());
+
+        let ret = Parser::new(&allocator, "foo; // @flow\nasdf asdf", source_type).parse();
+        assert!(!ret.has_flow_pragma);
+        assert_eq!(ret.errors.len(), 1);
     }
 
     #[test]
@@ -642,6 +673,12 @@ mod test {
         let source = "#!/usr/bin/node\n;";
         let ret = Parser::new(&allocator, source, source_type).parse();
         assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
+
+        // After a byte order mark
+        let source = "\u{feff}#!/usr/bin/env node\n;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/env node");
     }
 
     #[test]
@@ -2,20 +2,24 @@
 
 use std::{
     cell::{Cell, RefCell},
+    mem,
     path::Path,
     sync::Arc,
 };
 
 use rustc_hash::FxHashMap;
 
-use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_ast::{ast::*, visit::walk, AstKind, Visit};
 use oxc_cfg::{
     ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
     IterationInstructionKind, ReturnInstructionKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::{Atom, CompactStr, SourceType, Span};
-use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
+use oxc_span::{Atom, CompactStr, GetSpan, SourceType, Span};
+use oxc_syntax::{
+    module_record::{DynamicImport, ModuleRecord},
+    operator::AssignmentOperator,
+};
 
 use crate::{
     binder::Binder,
@@ -23,6 +27,7 @@ use crate::{
     class::ClassTableBuilder,
     diagnostics::redeclaration,
     jsdoc::JSDocBuilder,
+    jsx_pragma::JSXPragma,
     label::UnusedLabels,
     module_record::ModuleRecordBuilder,
     node::{AstNodes, NodeFlags, NodeId},
@@ -91,10 +96,12 @@ pub struct SemanticBuilder<'a> {
     unresolved_references: UnresolvedReferencesStack<'a>,
 
     pub(crate) module_record: Arc<ModuleRecord>,
+    dynamic_imports: Vec<DynamicImport>,
 
     unused_labels: UnusedLabels<'a>,
     build_jsdoc: bool,
     jsdoc: JSDocBuilder<'a>,
+    jsx_pragma: JSXPragma<'a>,
     stats: Option<Stats>,
     excess_capacity: f64,
 
@@ -103,6 +110,11 @@ pub struct SemanticBuilder<'a> {
     /// See: [`crate::checker::check`]
     check_syntax_error: bool,
 
+    /// Should TypeScript definition files be analyzed?
+    ///
+    /// See: [`SemanticBuilder::with_definition_files`]
+    build_definition_files: bool,
+
     pub(crate) cfg: Option<ControlFlowGraphBuilder<'a>>,
 
     pub(crate) class_table_builder: ClassTableBuilder,
@@ -144,12 +156,15 @@ impl<'a> SemanticBuilder<'a> {
             symbols: SymbolTable::default(),
             unresolved_references: UnresolvedReferencesStack::new(),
             module_record: Arc::new(ModuleRecord::default()),
+            dynamic_imports: vec![],
             unused_labels: UnusedLabels::default(),
             build_jsdoc: false,
             jsdoc: JSDocBuilder::default(),
+            jsx_pragma: JSXPragma::default(),
             stats: None,
             excess_capacity: 0.0,
             check_syntax_error: false,
+            build_definition_files: false,
             cfg: None,
             class_table_builder: ClassTableBuilder::new(),
             ast_node_records: Vec::new(),
@@ -169,6 +184,17 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Enable/disable analysis of TypeScript definition files (`.d.ts`).
+    ///
+    /// Definition files only declare types, so by default only a root scope is
+    /// created for them, without any nodes, symbols or references. Enable this
+    /// for tools that inspect them, such as linters.
+    #[must_use]
+    pub fn with_definition_files(mut self, yes: bool) -> Self {
+        self.build_definition_files = yes;
+        self
+    }
+
     /// Enable/disable JSDoc parsing.
     #[must_use]
     pub fn with_build_jsdoc(mut self, yes: bool) -> Self {
@@ -250,7 +276,10 @@ impl<'a> SemanticBuilder<'a> {
         if self.build_jsdoc {
             self.jsdoc = JSDocBuilder::new(self.source_text, &program.comments);
         }
-        if self.source_type.is_typescript_definition() {
+        if self.source_type.is_jsx() {
+            self.jsx_pragma = JSXPragma::from_comments(self.source_text, &program.comments);
+        }
+        if self.source_type.is_typescript_definition() && !self.build_definition_files {
             let scope_id = self.scope.add_scope(None, NodeId::DUMMY, ScopeFlags::Top);
             program.scope_id.set(Some(scope_id));
         } else {
@@ -280,6 +309,10 @@ impl<'a> SemanticBuilder<'a> {
             // Visit AST to generate scopes tree etc
             self.visit_program(program);
 
+            // The module record may already be shared with other threads, so `import()`
+            // expressions found by the visit are set on it once, after the fact.
+            let _ = self.module_record.dynamic_imports.set(mem::take(&mut self.dynamic_imports));
+
             // Check that estimated counts accurately (unless in release mode)
             #[cfg(debug_assertions)]
             if let Some(stats) = check_stats {
@@ -322,6 +355,7 @@ impl<'a> SemanticBuilder<'a> {
             classes: self.class_table_builder.build(),
             module_record: Arc::clone(&self.module_record),
             jsdoc,
+            jsx_pragma: self.jsx_pragma,
             unused_labels: self.unused_labels.labels,
             cfg: self.cfg.map(ControlFlowGraphBuilder::build),
         };
@@ -1740,6 +1774,12 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.leave_node(kind);
         self.leave_scope();
     }
+
+    fn visit_import_expression(&mut self, expr: &ImportExpression<'a>) {
+        self.dynamic_imports
+            .push(DynamicImport { span: expr.span, module_request: expr.source.span() });
+        walk::walk_import_expression(self, expr);
+    }
 }
 
 impl<'a> SemanticBuilder<'a> {
@@ -1955,6 +1995,13 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::YieldExpression(_) => {
                 self.set_function_node_flags(NodeFlags::HasYield);
             }
+            AstKind::JSXOpeningElement(_) => {
+                self.jsx_pragma.uses_factory = true;
+            }
+            AstKind::JSXFragment(_) => {
+                self.jsx_pragma.uses_factory = true;
+                self.jsx_pragma.uses_fragment = true;
+            }
             _ => {}
         }
     }
@@ -2039,7 +2086,10 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     fn reference_identifier(&mut self, ident: &IdentifierReference<'a>) {
-        let flags = self.resolve_reference_usages();
+        let mut flags = self.resolve_reference_usages();
+        if flags.is_read() && self.is_callee(ident) {
+            flags |= ReferenceFlags::Call;
+        }
         let reference = Reference::new(self.current_node_id, flags);
         let reference_id = self.declare_reference(ident.name.clone(), reference);
         ident.reference_id.set(Some(reference_id));
@@ -2054,6 +2104,25 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Returns `true` if `ident` is the callee of the closest enclosing call,
+    /// `new` or tagged template expression, ignoring parentheses.
+    fn is_callee(&self, ident: &IdentifierReference<'a>) -> bool {
+        for node in self.nodes.iter_parents(self.current_node_id).skip(1) {
+            let callee = match node.kind() {
+                AstKind::ParenthesizedExpression(_) => continue,
+                AstKind::CallExpression(call) => &call.callee,
+                AstKind::NewExpression(new) => &new.callee,
+                AstKind::TaggedTemplateExpression(tagged) => &tagged.tag,
+                _ => return false,
+            };
+            return matches!(
+                callee.without_parentheses(),
+                Expression::Identifier(callee) if callee.span == ident.span
+            );
+        }
+        false
+    }
+
     fn is_not_expression_statement_parent(&self) -> bool {
         for node in self.nodes.iter_parents(self.current_node_id).skip(1) {
             return match node.kind() {
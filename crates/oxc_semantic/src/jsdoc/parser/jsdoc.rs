@@ -29,6 +29,24 @@ impl<'a> JSDoc<'a> {
         &self.parse().1
     }
 
+    /// Iterate over tags of a given kind, without the leading `@`.
+    ///
+    /// ```js
+    /// /**
+    ///  * @param a      <- `tags_named("param")` yields this
+    ///  * @param b      <- and this
+    ///  * @returns
+    ///  */
+    /// ```
+    pub fn tags_named<'b>(&'b self, kind: &'b str) -> impl Iterator<Item = &'b JSDocTag<'a>> + 'b {
+        self.tags().iter().filter(move |tag| tag.kind.parsed() == kind)
+    }
+
+    /// Returns `true` if this JSDoc has at least one tag of the given kind, e.g. `deprecated`.
+    pub fn has_tag(&self, kind: &str) -> bool {
+        self.tags_named(kind).next().is_some()
+    }
+
     fn parse(&self) -> &ParsedJSDoc<'a> {
         self.cached.get_or_init(|| parse_jsdoc(self.raw, self.span.start))
     }
@@ -326,4 +344,27 @@ line2
         let tag = tags.next().unwrap();
         assert_eq!(tag.kind.parsed(), "example");
     }
+
+    #[test]
+    fn tags_named() {
+        let allocator = Allocator::default();
+        let semantic = build_semantic(
+            &allocator,
+            "
+            /**
+             * @deprecated use `bar` instead
+             * @param a
+             * @param b
+             */
+            function foo(a, b) {}
+            ",
+        );
+        let jsdoc = semantic.jsdoc().iter_all().next().unwrap();
+
+        assert!(jsdoc.has_tag("deprecated"));
+        assert!(!jsdoc.has_tag("returns"));
+        let params =
+            jsdoc.tags_named("param").map(|tag| tag.comment().parsed()).collect::<Vec<_>>();
+        assert_eq!(params, ["a", "b"]);
+    }
 }
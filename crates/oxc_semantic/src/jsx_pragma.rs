@@ -0,0 +1,132 @@
+use oxc_ast::Comment;
+
+/// Which JSX transform a file opted into with an `@jsxRuntime` pragma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JSXRuntime {
+    /// `<div />` compiles to `React.createElement("div")`, so the factory must be in scope.
+    Classic,
+    /// `<div />` compiles to an auto-imported `jsx("div")` call.
+    Automatic,
+}
+
+/// JSX pragmas declared in a file's comments, plus whether the file contains
+/// any JSX that implicitly uses them.
+///
+/// ```jsx
+/// /** @jsx h */
+/// /** @jsxFrag Fragment */
+/// import { h, Fragment } from 'preact';
+///
+/// // `h` and `Fragment` are used here, even though they never appear in the source.
+/// const App = () => <><div /></>;
+/// ```
+///
+/// Without any pragmas the runtime is unknown, since it is usually configured
+/// outside of the file (e.g. `"jsx": "react-jsx"` in `tsconfig.json`). In that
+/// case JSX is not considered to reference any binding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JSXPragma<'a> {
+    factory: Option<&'a str>,
+    fragment: Option<&'a str>,
+    runtime: Option<JSXRuntime>,
+    /// Set when a JSX element or fragment is found, both of which compile to a factory call.
+    pub(crate) uses_factory: bool,
+    /// Set when a JSX fragment (`<></>`) is found.
+    pub(crate) uses_fragment: bool,
+}
+
+impl<'a> JSXPragma<'a> {
+    const DEFAULT_FACTORY: &'static str = "React.createElement";
+    const DEFAULT_FRAGMENT: &'static str = "React.Fragment";
+
+    /// Scan `comments` for `@jsx`, `@jsxFrag` and `@jsxRuntime` pragmas. Later
+    /// pragmas override earlier ones.
+    pub(crate) fn from_comments(source_text: &'a str, comments: &[Comment]) -> Self {
+        let mut pragma = Self::default();
+        for comment in comments {
+            let text = comment.span.source_text(source_text);
+            if !text.contains("@jsx") {
+                continue;
+            }
+            for line in text.lines() {
+                let line = line.trim_start_matches(|c: char| c.is_whitespace() || c == '*');
+                let Some(rest) = line.strip_prefix("@jsx") else { continue };
+                let mut parts = rest.split_whitespace();
+                let (keyword, value) = if rest.starts_with(char::is_whitespace) {
+                    ("", parts.next())
+                } else {
+                    (parts.next().unwrap_or_default(), parts.next())
+                };
+                let Some(value) = value else { continue };
+                match keyword {
+                    "" => pragma.factory = Some(value),
+                    "Frag" => pragma.fragment = Some(value),
+                    "Runtime" => {
+                        pragma.runtime = match value {
+                            "classic" => Some(JSXRuntime::Classic),
+                            "automatic" => Some(JSXRuntime::Automatic),
+                            _ => pragma.runtime,
+                        };
+                    }
+                    _ => {}
+                }
+            }
+        }
+        pragma
+    }
+
+    /// The function JSX elements compile to, e.g. `React.createElement` or `h`.
+    pub fn factory(&self) -> &'a str {
+        self.factory.unwrap_or(Self::DEFAULT_FACTORY)
+    }
+
+    /// The component JSX fragments compile to, e.g. `React.Fragment`.
+    pub fn fragment(&self) -> &'a str {
+        self.fragment.unwrap_or(Self::DEFAULT_FRAGMENT)
+    }
+
+    /// The runtime requested by an `@jsxRuntime` pragma, if any.
+    pub fn runtime(&self) -> Option<JSXRuntime> {
+        self.runtime
+    }
+
+    /// Returns `true` if the file opted into the automatic runtime, in which
+    /// case JSX does not reference any in-scope binding.
+    pub fn is_automatic_runtime(&self) -> bool {
+        self.runtime == Some(JSXRuntime::Automatic)
+    }
+
+    /// Name of the binding the factory is accessed through, e.g. `React` for
+    /// `React.createElement`.
+    pub fn factory_binding(&self) -> &'a str {
+        root_identifier(self.factory())
+    }
+
+    /// Name of the binding the fragment is accessed through, e.g. `React` for
+    /// `React.Fragment`.
+    pub fn fragment_binding(&self) -> &'a str {
+        root_identifier(self.fragment())
+    }
+
+    /// Returns `true` if the file is known to use the classic runtime, either
+    /// via `@jsxRuntime classic` or by declaring a custom factory or fragment.
+    pub fn is_classic_runtime(&self) -> bool {
+        match self.runtime {
+            Some(runtime) => runtime == JSXRuntime::Classic,
+            None => self.factory.is_some() || self.fragment.is_some(),
+        }
+    }
+
+    /// Returns `true` if JSX in this file implicitly references a binding named `name`.
+    pub fn references_binding(&self, name: &str) -> bool {
+        if !self.is_classic_runtime() {
+            return false;
+        }
+        (self.uses_factory && self.factory_binding() == name)
+            || (self.uses_fragment && self.fragment_binding() == name)
+    }
+}
+
+fn root_identifier(member_chain: &str) -> &str {
+    member_chain.split('.').next().unwrap_or(member_chain)
+}
@@ -27,6 +27,7 @@ mod checker;
 mod class;
 mod diagnostics;
 mod jsdoc;
+mod jsx_pragma;
 mod label;
 mod module_record;
 mod node;
@@ -39,6 +40,7 @@ mod unresolved_stack;
 pub use crate::{
     builder::{SemanticBuilder, SemanticBuilderReturn},
     jsdoc::{JSDoc, JSDocFinder, JSDocTag},
+    jsx_pragma::{JSXPragma, JSXRuntime},
     node::{AstNode, AstNodes, NodeId},
     reference::{Reference, ReferenceFlags, ReferenceId},
     scope::ScopeTree,
@@ -87,6 +89,9 @@ pub struct Semantic<'a> {
     /// Parsed JSDoc comments.
     jsdoc: JSDocFinder<'a>,
 
+    /// JSX pragmas (`@jsx`, `@jsxFrag`, `@jsxRuntime`) and whether JSX uses them.
+    jsx_pragma: JSXPragma<'a>,
+
     unused_labels: Vec<NodeId>,
 
     /// Control flow graph. Only present if [`Semantic`] is built with cfg
@@ -162,6 +167,11 @@ impl<'a> Semantic<'a> {
         &self.jsdoc
     }
 
+    /// JSX pragmas declared in this file's comments.
+    pub fn jsx_pragma(&self) -> &JSXPragma<'a> {
+        &self.jsx_pragma
+    }
+
     /// ESM module record containing imports and exports.
     pub fn module_record(&self) -> &ModuleRecord {
         self.module_record.as_ref()
@@ -201,7 +211,7 @@ impl<'a> Semantic<'a> {
         let AstKind::IdentifierReference(id) = reference_node.kind() else {
             return false;
         };
-        self.scopes().root_unresolved_references().contains_key(id.name.as_str())
+        self.is_reference_to_global_variable(id)
     }
 
     /// Find which scope a symbol is declared in
@@ -214,12 +224,74 @@ impl<'a> Semantic<'a> {
         self.symbols.get_resolved_references(symbol_id)
     }
 
+    /// Get the [`AstNode`] that declares a symbol, e.g. a `VariableDeclarator`
+    /// or `Function`.
     pub fn symbol_declaration(&self, symbol_id: SymbolId) -> &AstNode<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
 
+    /// Iterate over every symbol in the program along with the node declaring it.
+    ///
+    /// Use [`Semantic::scope_declarations`] to only visit symbols bound in a
+    /// single scope.
+    pub fn declarations(&self) -> impl Iterator<Item = (SymbolId, &AstNode<'a>)> + '_ {
+        self.symbols.symbol_ids().map(|symbol_id| (symbol_id, self.symbol_declaration(symbol_id)))
+    }
+
+    /// Iterate over the symbols bound in `scope_id` along with the node
+    /// declaring each of them. Symbols in child scopes are not included.
+    pub fn scope_declarations(
+        &self,
+        scope_id: ScopeId,
+    ) -> impl Iterator<Item = (SymbolId, &AstNode<'a>)> + '_ {
+        self.scopes
+            .iter_bindings_in(scope_id)
+            .map(|symbol_id| (symbol_id, self.symbol_declaration(symbol_id)))
+    }
+
+    /// Returns `true` if `symbol_id` is implicitly referenced by JSX, e.g. the
+    /// `React` binding that `<div />` compiles to with the classic runtime.
+    ///
+    /// Only bindings in the root scope are considered.
+    pub fn is_referenced_by_jsx(&self, symbol_id: SymbolId) -> bool {
+        self.symbols.get_scope_id(symbol_id) == self.scopes.root_scope_id()
+            && self.jsx_pragma.references_binding(self.symbols.get_name(symbol_id))
+    }
+
+    /// Returns `true` if `ident` does not resolve to any binding, i.e. it refers to a global
+    /// variable.
+    ///
+    /// This only looks up the symbol the reference resolved to, so an `ident` is not
+    /// considered global just because another reference with the same name is.
     pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
-        self.scopes().root_unresolved_references().contains_key(ident.name.as_str())
+        ident.reference_id.get().map_or_else(
+            || self.scopes.root_unresolved_references().contains_key(ident.name.as_str()),
+            |reference_id| self.symbols.get_reference(reference_id).symbol_id().is_none(),
+        )
+    }
+
+    /// Iterate over every reference in the program that does not resolve to any binding,
+    /// along with its name and the node making it.
+    ///
+    /// Use [`Semantic::scope_unresolved_references`] to only visit references made in a
+    /// single scope.
+    pub fn unresolved_references(
+        &self,
+    ) -> impl Iterator<Item = (&str, &Reference, &AstNode<'a>)> + '_ {
+        self.scopes.iter_root_unresolved_references().map(|(name, reference_id)| {
+            let reference = self.symbols.get_reference(reference_id);
+            (name, reference, self.nodes.get_node(reference.node_id()))
+        })
+    }
+
+    /// Iterate over the references made in `scope_id` that do not resolve to any binding,
+    /// along with their names and the nodes making them. References in child scopes are
+    /// not included.
+    pub fn scope_unresolved_references(
+        &self,
+        scope_id: ScopeId,
+    ) -> impl Iterator<Item = (&str, &Reference, &AstNode<'a>)> + '_ {
+        self.unresolved_references().filter(move |(_, _, node)| node.scope_id() == scope_id)
     }
 
     pub fn reference_name(&self, reference: &Reference) -> &str {
@@ -239,7 +311,10 @@ impl<'a> Semantic<'a> {
 #[cfg(test)]
 mod tests {
     use oxc_allocator::Allocator;
-    use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+    use oxc_ast::{
+        ast::{CallExpression, Class, Function, IfStatement, VariableDeclarationKind},
+        AstKind,
+    };
     use oxc_span::{Atom, SourceType};
 
     use super::*;
@@ -283,6 +358,32 @@ mod tests {
         assert_eq!(references.count(), 1);
     }
 
+    #[test]
+    fn test_declarations() {
+        let source = "
+            let a;
+            function foo(b) {
+                class C {}
+            }";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+
+        let names = |iter: &mut dyn Iterator<Item = (SymbolId, &AstNode)>| {
+            let mut names = iter.map(|(id, _)| semantic.symbols().get_name(id)).collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        };
+        assert_eq!(names(&mut semantic.declarations()), ["C", "a", "b", "foo"]);
+        let root = semantic.scopes().root_scope_id();
+        assert_eq!(names(&mut semantic.scope_declarations(root)), ["a", "foo"]);
+
+        let (_, decl) = semantic
+            .scope_declarations(root)
+            .find(|&(id, _)| semantic.symbols().get_name(id) == "foo")
+            .unwrap();
+        assert!(matches!(decl.kind(), AstKind::Function(_)));
+    }
+
     #[test]
     fn test_top_level_symbols() {
         let source = "function Fn() {}";
@@ -297,6 +398,43 @@ mod tests {
         assert_eq!(semantic.symbols.get_scope_id(top_level_a.1), top_level_a.0);
     }
 
+    #[test]
+    fn test_jsx_pragma() {
+        let source = "
+            /**
+             * @jsx h
+             * @jsxFrag Fragment
+             */
+            import { h, Fragment } from 'preact';
+            const render = () => <><div /></>;
+        ";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::jsx());
+        let pragma = semantic.jsx_pragma();
+        assert_eq!(pragma.factory(), "h");
+        assert_eq!(pragma.fragment(), "Fragment");
+        assert!(pragma.is_classic_runtime());
+
+        let root_scope_id = semantic.scopes().root_scope_id();
+        for name in ["h", "Fragment"] {
+            let symbol_id = semantic.scopes().get_binding(root_scope_id, name).unwrap();
+            assert!(semantic.is_referenced_by_jsx(symbol_id), "{name} should be used by JSX");
+        }
+        let render = semantic.scopes().get_binding(root_scope_id, "render").unwrap();
+        assert!(!semantic.is_referenced_by_jsx(render));
+
+        // the runtime is unknown without pragmas
+        let source = "import React from 'react'; <div />;";
+        let semantic = get_semantic(&allocator, source, SourceType::jsx());
+        let react = semantic.scopes().get_binding(root_scope_id, "React").unwrap();
+        assert_eq!(semantic.jsx_pragma().factory_binding(), "React");
+        assert!(!semantic.is_referenced_by_jsx(react));
+
+        let source = "// @jsxRuntime automatic\nimport React from 'react'; <div />;";
+        let semantic = get_semantic(&allocator, source, SourceType::jsx());
+        assert!(semantic.jsx_pragma().is_automatic_runtime());
+    }
+
     #[test]
     fn test_is_global() {
         let source = "
@@ -316,6 +454,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ancestor_queries() {
+        let source = "
+            function foo() {
+                if (a) {
+                    bar(() => x);
+                }
+            }
+            class C { y = z; }
+        ";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+        let nodes = semantic.nodes();
+        let find_reference = |name: &str| {
+            nodes
+                .iter()
+                .find(|node| {
+                    matches!(node.kind(), AstKind::IdentifierReference(id) if id.name == name)
+                })
+                .unwrap()
+                .id()
+        };
+
+        let x = find_reference("x");
+        let call = nodes.ancestor_of_kind::<CallExpression>(x).unwrap();
+        assert_eq!(call.callee_name(), Some("bar"));
+        let (if_node, _) = nodes.ancestor_node_of_kind::<IfStatement>(x).unwrap();
+        let func = nodes.ancestor_of_kind::<Function>(if_node.id()).unwrap();
+        assert_eq!(func.name().unwrap(), "foo");
+        assert!(nodes.ancestor_of_kind::<Class>(x).is_none());
+
+        // the node itself is not an ancestor
+        let call_node = nodes.ancestor_node_of_kind::<CallExpression>(x).unwrap().0;
+        assert!(nodes.ancestor_of_kind::<CallExpression>(call_node.id()).is_none());
+
+        let statement = nodes.enclosing_statement(x).unwrap();
+        assert!(matches!(statement.kind(), AstKind::ExpressionStatement(_)));
+        assert_eq!(nodes.enclosing_statement(statement.id()).unwrap().id(), statement.id());
+        assert!(nodes.enclosing_statement(find_reference("z")).is_none());
+    }
+
     #[test]
     fn type_alias_gets_reference() {
         let source = "type A = 1; type B = A";
@@ -275,4 +275,18 @@ mod module_record_tests {
             }
         );
     }
+
+    #[test]
+    fn dynamic_imports() {
+        let module_record = build("import('a'); async function f() { await import(`./${b}`); }");
+        assert!(module_record.not_esm);
+        assert!(module_record.requested_modules.is_empty());
+        assert_eq!(
+            module_record.dynamic_imports(),
+            [
+                DynamicImport { span: Span::new(0, 11), module_request: Span::new(7, 10) },
+                DynamicImport { span: Span::new(40, 56), module_request: Span::new(47, 55) },
+            ]
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, FromAstKind};
 use oxc_cfg::BlockNodeId;
 use oxc_index::IndexVec;
 use oxc_span::GetSpan;
@@ -225,6 +225,50 @@ impl<'a> AstNodes<'a> {
         std::iter::successors(Some(node_id), |&node_id| parent_ids[node_id])
     }
 
+    /// Find the closest ancestor of the node pointed to by `node_id` that is
+    /// of type `T`. The node itself is not considered.
+    ///
+    /// ## Example
+    ///
+    /// ```ignore
+    /// use oxc_ast::ast::CallExpression;
+    ///
+    /// if let Some(call_expr) = nodes.ancestor_of_kind::<CallExpression>(node_id) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn ancestor_of_kind<T: FromAstKind<'a>>(&self, node_id: NodeId) -> Option<&'a T> {
+        self.ancestor_node_of_kind::<T>(node_id).map(|(_, it)| it)
+    }
+
+    /// Like [`AstNodes::ancestor_of_kind`], but also returns the ancestor's
+    /// [`AstNode`], e.g. for looking up its scope or parent.
+    pub fn ancestor_node_of_kind<T: FromAstKind<'a>>(
+        &self,
+        node_id: NodeId,
+    ) -> Option<(&AstNode<'a>, &'a T)> {
+        self.iter_parents(node_id)
+            .skip(1)
+            .find_map(|node| T::from_ast_kind(node.kind()).map(|it| (node, it)))
+    }
+
+    /// Find the statement or declaration containing the node pointed to by
+    /// `node_id`. If the node is itself a statement, it is returned.
+    ///
+    /// Returns [`None`] for nodes that are not inside a statement, such as
+    /// class members or the [`Program`] itself.
+    ///
+    /// [`Program`]: oxc_ast::ast::Program
+    pub fn enclosing_statement(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        self.iter_parents(node_id)
+            .take_while(|node| !matches!(node.kind(), AstKind::ClassBody(_) | AstKind::Program(_)))
+            .find(|node| {
+                let kind = node.kind();
+                kind.is_statement()
+                    || (kind.is_declaration() && !matches!(kind, AstKind::PropertyDefinition(_)))
+            })
+    }
+
     /// Create and add an [`AstNode`] to the [`AstNodes`] tree and get its [`NodeId`].
     /// Node must not be [`Program`]; if it is, use [`add_program_node`] instead.
     ///
@@ -121,4 +121,12 @@ impl Reference {
     pub fn is_type(&self) -> bool {
         self.flags.is_type()
     }
+
+    /// Returns `true` if the referenced value is invoked, e.g. `foo()` or `new Foo()`.
+    ///
+    /// Call references are always also [read](Reference::is_read) references.
+    #[inline]
+    pub fn is_call(&self) -> bool {
+        self.flags.is_call()
+    }
 }
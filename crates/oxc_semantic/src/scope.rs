@@ -76,6 +76,20 @@ impl ScopeTree {
         std::iter::successors(Some(scope_id), |&scope_id| self.parent_ids[scope_id])
     }
 
+    /// Iterate over the scopes that contain a scope, from its parent up to the root.
+    ///
+    /// Unlike [`ScopeTree::ancestors`], the scope itself is not included.
+    pub fn iter_ancestors(&self, scope_id: ScopeId) -> impl Iterator<Item = ScopeId> + '_ {
+        self.ancestors(scope_id).skip(1)
+    }
+
+    /// Returns `true` if `ancestor_id` contains `scope_id`, e.g. to check whether a
+    /// variable is declared in a function enclosing a callback. A scope is not a strict
+    /// ancestor of itself.
+    pub fn is_strict_ancestor_of(&self, ancestor_id: ScopeId, scope_id: ScopeId) -> bool {
+        self.iter_ancestors(scope_id).any(|id| id == ancestor_id)
+    }
+
     pub fn descendants_from_root(&self) -> impl Iterator<Item = ScopeId> + '_ {
         self.parent_ids.iter_enumerated().map(|(scope_id, _)| scope_id)
     }
@@ -101,6 +115,15 @@ impl ScopeTree {
         &self.root_unresolved_references
     }
 
+    /// Iterate over all unresolved references, along with their names.
+    pub fn iter_root_unresolved_references(
+        &self,
+    ) -> impl Iterator<Item = (&str, ReferenceId)> + '_ {
+        self.root_unresolved_references
+            .iter()
+            .flat_map(|(name, ids)| ids.iter().map(move |&id| (name.as_str(), id)))
+    }
+
     pub fn root_unresolved_references_ids(
         &self,
     ) -> impl Iterator<Item = impl Iterator<Item = ReferenceId> + '_> + '_ {
@@ -1,5 +1,6 @@
 use std::fs;
 
+use oxc_ast::AstKind;
 use oxc_span::SourceType;
 
 use crate::util::SemanticTester;
@@ -17,3 +18,37 @@ fn test_cfg_files() {
         });
     });
 }
+
+#[test]
+fn test_always_returns() {
+    let cases = [
+        ("function f() { return 1 }", true, true),
+        ("function f() { throw new Error() }", true, true),
+        ("function f() { if (a) { return 1 } else { return 2 } }", true, true),
+        ("function f() { if (a) { return 1 } }", false, false),
+        ("function f() { switch (a) { case 1: return 1; default: throw a } }", true, true),
+        ("function f() { while (a) { return 1 } }", false, false),
+        ("function f() { try { a() } finally { return 1 } }", true, true),
+        ("function f() { try { return a() } finally { b() } }", true, true),
+        ("function f() { try { a() } finally { b() } return 1 }", true, true),
+        ("function f() { try { if (a) return 1 } finally { b() } }", false, false),
+        ("function f() { return }", false, true),
+        ("function f() {}", false, false),
+    ];
+    for (source, expected, expected_allow_implicit) in cases {
+        let tester = SemanticTester::js(source).with_cfg(true);
+        let semantic = tester.build();
+        let cfg = semantic.cfg().unwrap();
+        let function = semantic
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::Function(_)))
+            .unwrap();
+        assert_eq!(cfg.always_returns(function.cfg_id(), false), expected, "{source}");
+        assert_eq!(
+            cfg.always_returns(function.cfg_id(), true),
+            expected_allow_implicit,
+            "{source} (allow implicit undefined)"
+        );
+    }
+}
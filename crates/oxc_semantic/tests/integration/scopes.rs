@@ -261,3 +261,73 @@ fn test_ts_conditional_types() {
         .has_number_of_references(0)
         .test();
 }
+
+#[test]
+fn unresolved_references() {
+    let tester = SemanticTester::js(
+        "
+            foo();
+            function bar() {
+                let foo = 1;
+                return foo + baz;
+            }
+        ",
+    );
+    let semantic = tester.build();
+
+    let mut names = semantic.unresolved_references().map(|(name, _, _)| name).collect::<Vec<_>>();
+    names.sort_unstable();
+    assert_eq!(names, ["baz", "foo"]);
+
+    let root = semantic.scopes().root_scope_id();
+    let root_names =
+        semantic.scope_unresolved_references(root).map(|(name, _, _)| name).collect::<Vec<_>>();
+    assert_eq!(root_names, ["foo"]);
+
+    // Only the `foo` in the root scope is a global, the one in `bar` is shadowed.
+    let globals = semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::IdentifierReference(ident) => Some(ident),
+            _ => None,
+        })
+        .map(|ident| (ident.name.as_str(), semantic.is_reference_to_global_variable(ident)))
+        .collect::<Vec<_>>();
+    assert_eq!(globals, [("foo", true), ("foo", false), ("baz", true)]);
+}
+
+#[test]
+fn scope_ancestry() {
+    let tester = SemanticTester::js(
+        "
+            function Component() {
+                const a = 1;
+                useEffect(() => {
+                    const b = 2;
+                });
+            }
+        ",
+    );
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let scope_of = |name: &str| {
+        let symbol_id = semantic
+            .symbols()
+            .symbol_ids()
+            .find(|&id| semantic.symbols().get_name(id) == name)
+            .unwrap();
+        semantic.symbols().get_scope_id(symbol_id)
+    };
+    let root = scopes.root_scope_id();
+    let component = scope_of("a");
+    let callback = scope_of("b");
+
+    assert_eq!(scopes.iter_ancestors(callback).collect::<Vec<_>>(), [component, root]);
+    assert_eq!(scopes.iter_ancestors(root).count(), 0);
+
+    assert!(scopes.is_strict_ancestor_of(component, callback));
+    assert!(scopes.is_strict_ancestor_of(root, callback));
+    assert!(!scopes.is_strict_ancestor_of(callback, component));
+    assert!(!scopes.is_strict_ancestor_of(component, component));
+}
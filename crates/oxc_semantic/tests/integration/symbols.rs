@@ -86,6 +86,27 @@ fn test_var_read_write() {
     .test();
 }
 
+#[test]
+fn test_var_call() {
+    SemanticTester::js("function foo() {} foo(); (foo)(); new foo(); foo``")
+        .has_root_symbol("foo")
+        .has_number_of_reads(4)
+        .has_number_of_references_where(4, Reference::is_call)
+        .test();
+
+    SemanticTester::js("function foo() {} bar(foo); foo.call(); let x = foo; x = foo;")
+        .has_root_symbol("foo")
+        .has_number_of_reads(4)
+        .has_number_of_references_where(0, Reference::is_call)
+        .test();
+
+    SemanticTester::js("let foo; foo = foo(); foo?.()")
+        .has_root_symbol("foo")
+        .has_number_of_writes(1)
+        .has_number_of_references_where(2, Reference::is_call)
+        .test();
+}
+
 #[test]
 fn test_types_simple() {
     let test = SemanticTester::ts(
@@ -1,9 +1,14 @@
 //! [ECMAScript Module Record](https://tc39.es/ecma262/#sec-abstract-module-records)
 #![allow(missing_docs)] // fixme
 
-use std::{fmt, hash::BuildHasherDefault, path::PathBuf, sync::Arc};
-
-use dashmap::DashMap;
+use std::{
+    fmt,
+    hash::BuildHasherDefault,
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+};
+
+use dashmap::{DashMap, DashSet};
 use oxc_span::{CompactStr, Span};
 use rustc_hash::{FxHashMap, FxHasher};
 
@@ -43,6 +48,13 @@ pub struct ModuleRecord {
     /// [`ModuleRecord`] is created. You must link the module records yourself.
     pub loaded_modules: DashMap<CompactStr, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// Specifiers from [`ModuleRecord::requested_modules`] that a resolver
+    /// failed to find a file for.
+    ///
+    /// Like [`ModuleRecord::loaded_modules`], this is empty until the module
+    /// records are linked.
+    pub unresolved_modules: DashSet<CompactStr, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
@@ -84,12 +96,29 @@ pub struct ModuleRecord {
 
     /// Duplicated span of `export default` for diagnostics
     pub export_default_duplicated: Vec<Span>,
+
+    /// `import()` expressions anywhere in this module, in source text occurrence order.
+    ///
+    /// Unlike [`ModuleRecord::requested_modules`], the module request may be any
+    /// expression, so it is not resolved to a specifier.
+    ///
+    /// `import()` may appear in any expression, so this is set by the full
+    /// semantic analysis pass rather than when the module record is created.
+    /// Use [`ModuleRecord::dynamic_imports`] to read it.
+    pub dynamic_imports: OnceLock<Vec<DynamicImport>>,
 }
 
 impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// `import()` expressions anywhere in this module, in source text occurrence order.
+    ///
+    /// Empty until the semantic model of this module is built.
+    pub fn dynamic_imports(&self) -> &[DynamicImport] {
+        self.dynamic_imports.get().map_or(&[], Vec::as_slice)
+    }
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -107,6 +136,7 @@ impl fmt::Debug for ModuleRecord {
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("unresolved_modules", &self.unresolved_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -116,6 +146,7 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
             .field("export_default_duplicated", &self.export_default_duplicated)
+            .field("dynamic_imports", &self.dynamic_imports())
             .finish()
     }
 }
@@ -353,6 +384,21 @@ impl ExportLocalName {
     }
 }
 
+/// A dynamic `import()` expression.
+///
+/// ```js
+/// import('./foo.js', { with: { type: 'json' } })
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ span
+///        ^^^^^^^^^^ module_request
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicImport {
+    /// Span of the entire `import(...)` expression
+    pub span: Span,
+    /// Span of the module request argument
+    pub module_request: Span,
+}
+
 pub struct FunctionMeta {
     pub deprecated: bool,
 }
@@ -41,6 +41,7 @@ export type ReferenceFlags = {
     Read: 0b1,
     Write: 0b10,
     Type: 0b100,
+    Call: 0b10000,
     Value: 0b11
 }
 "#;
@@ -63,6 +64,12 @@ bitflags! {
     /// 2. This ensures that during symbol resolution, 'a' should be a value symbol.
     /// 3. However, the final resolved reference's flags will be treated as a type.
     ///
+    /// ## Calls
+    /// References that are the callee of a call, `new` or tagged template
+    /// expression are additionally flagged with [`Call`], e.g. `foo` in
+    /// `foo()`. This lets rules distinguish invoking a binding from passing it
+    /// around without re-walking the AST.
+    ///
     /// ## Types
     /// Type references are indicated by [`Type`]. These are used primarily in
     /// type definitions and signatures. Types can never be re-assigned, so
@@ -72,6 +79,7 @@ bitflags! {
     /// [`Write`]: ReferenceFlags::Write
     /// [`Type`]: ReferenceFlags::Type
     /// [`ValueAsType`]: ReferenceFlags::ValueAsType
+    /// [`Call`]: ReferenceFlags::Call
     #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
     #[cfg_attr(feature = "serialize", derive(Serialize))]
     pub struct ReferenceFlags: u8 {
@@ -84,6 +92,11 @@ bitflags! {
         const Type = 1 << 2;
         /// A value symbol is used in a type context, such as in `typeof` expressions.
         const ValueAsType = 1 << 3;
+        /// The symbol's value is invoked, e.g. `foo()`, `new Foo()` or `` tag`` ``.
+        /// Always set together with [`Read`].
+        ///
+        /// [`Read`]: ReferenceFlags::Read
+        const Call = 1 << 4;
         /// The symbol being referenced is a value.
         ///
         /// Note that this does not necessarily indicate the reference is used
@@ -157,6 +170,12 @@ impl ReferenceFlags {
         matches!(self, Self::Type)
     }
 
+    /// The identifier is the callee of a call, `new` or tagged template expression.
+    #[inline]
+    pub const fn is_call(&self) -> bool {
+        self.contains(Self::Call)
+    }
+
     #[inline]
     pub const fn is_value(&self) -> bool {
         self.intersects(Self::Value)
@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use itertools::Itertools;
 use quote::{format_ident, quote};
-use syn::{parse_quote, Arm, ImplItemFn, Variant};
+use syn::{parse_quote, Arm, ImplItemFn, ItemImpl, Variant};
 
 use crate::{
     output::{output_path, Output},
@@ -101,6 +101,13 @@ impl Generator for AstKindGenerator {
         let kinds: Vec<Variant> =
             have_kinds.iter().map(|(ident, typ)| parse_quote!(#ident(&'a #typ))).collect_vec();
 
+        let type_count = proc_macro2::Literal::usize_unsuffixed(types.len());
+
+        let type_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| parse_quote!(Self :: #ident(_) => AstType :: #ident))
+            .collect_vec();
+
         let span_matches: Vec<Arm> = have_kinds
             .iter()
             .map(|(ident, _)| parse_quote!(Self :: #ident(it) => it.span()))
@@ -125,6 +132,23 @@ impl Generator for AstKindGenerator {
             })
             .collect_vec();
 
+        let from_ast_kind_impls: Vec<ItemImpl> = have_kinds
+            .iter()
+            .map(|(ident, typ)| {
+                let snake_case_name =
+                    format_ident!("as_{}", ident.to_string().to_case(Case::Snake));
+                parse_quote!(
+                    ///@@line_break
+                    impl<'a> FromAstKind<'a> for #typ {
+                        #[inline]
+                        fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+                            kind.#snake_case_name()
+                        }
+                    }
+                )
+            })
+            .collect_vec();
+
         Output::Rust {
             path: output_path(crate::AST_CRATE, "ast_kind.rs"),
             tokens: quote! {
@@ -137,11 +161,17 @@ impl Generator for AstKindGenerator {
                 use crate::ast::*;
 
                 ///@@line_break
-                #[derive(Debug, Clone, Copy)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
                 pub enum AstType {
                     #(#types),*,
                 }
 
+                ///@@line_break
+                impl AstType {
+                    /// Number of [`AstType`] variants. `ty as usize` is always less than this.
+                    pub const COUNT: usize = #type_count;
+                }
+
                 ///@@line_break
                 /// Untyped AST Node Kind
                 #[derive(Debug, Clone, Copy)]
@@ -161,8 +191,27 @@ impl Generator for AstKindGenerator {
 
                 ///@@line_break
                 impl<'a> AstKind<'a> {
+                    /// The type of this node, without the reference to it.
+                    #[inline]
+                    pub fn ty(&self) -> AstType {
+                        match self {
+                            #(#type_matches),*,
+                        }
+                    }
+
                     #(#as_ast_kind_impls)*
                 }
+
+                ///@@line_break
+                /// AST node types with a corresponding [`AstKind`] variant.
+                ///
+                /// Allows looking up nodes by their type, e.g.
+                /// `CallExpression::from_ast_kind(kind)` is equivalent to `kind.as_call_expression()`.
+                pub trait FromAstKind<'a>: Sized {
+                    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self>;
+                }
+
+                #(#from_ast_kind_impls)*
             },
         }
     }
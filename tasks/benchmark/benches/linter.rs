@@ -1,12 +1,12 @@
 use std::{env, path::Path, rc::Rc};
 
 use oxc_allocator::Allocator;
-use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use oxc_linter::{FixKind, LinterBuilder};
+use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use oxc_linter::{FixKind, Linter, LinterBuilder};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_tasks_common::TestFiles;
+use oxc_tasks_common::{TestFile, TestFiles};
 
 fn bench_linter(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("linter");
@@ -46,5 +46,59 @@ fn bench_linter(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(linter, bench_linter);
+/// Parse, analyze and lint the whole corpus with all rules enabled, reporting files per second.
+///
+/// Set `TIMING=1` to also print the slowest rules over the corpus.
+#[expect(clippy::print_stdout)]
+fn bench_linter_corpus(criterion: &mut Criterion) {
+    // Sharded CI runs only benchmark single files.
+    if env::var("FIXTURE").is_ok() {
+        return;
+    }
+
+    let test_files = TestFiles::complicated();
+    let files = test_files.files();
+
+    let mut group = criterion.benchmark_group("linter_corpus");
+    group.throughput(Throughput::Elements(files.len() as u64));
+    let linter = LinterBuilder::all().build();
+    group.bench_function("all_rules", |b| {
+        b.iter(|| {
+            for file in files {
+                lint_file(&linter, file);
+            }
+        });
+    });
+    group.finish();
+
+    if env::var("TIMING").is_ok() {
+        let linter = LinterBuilder::all().build().with_timing(true);
+        for file in files {
+            lint_file(&linter, file);
+        }
+        for timing in linter.rule_timings().unwrap_or_default().iter().take(20) {
+            println!(
+                "{:>10.3}ms  {}/{}",
+                timing.duration.as_secs_f64() * 1000.0,
+                timing.plugin_name,
+                timing.rule_name
+            );
+        }
+    }
+}
+
+fn lint_file(linter: &Linter, file: &TestFile) {
+    let source_type = SourceType::from_path(&file.file_name).unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &file.source_text, source_type).parse();
+    let path = Path::new(&file.file_name);
+    let semantic_ret = SemanticBuilder::new()
+        .with_build_jsdoc(true)
+        .with_cfg(true)
+        .build_module_record(path, &ret.program)
+        .build(&ret.program);
+    linter.run(path, Rc::new(semantic_ret.semantic));
+}
+
+criterion_group!(linter, bench_linter, bench_linter_corpus);
 criterion_main!(linter);
@@ -0,0 +1,51 @@
+/**
+ * @typedef {'implemented' | 'partial' | 'missing' | 'not-supported'} RuleStatus
+ */
+
+/**
+ * @param {import("./oxlint-rules.cjs").RuleEntry} entry
+ * @returns {RuleStatus}
+ */
+const getRuleStatus = (entry) => {
+  if (entry.isPartial) return 'partial';
+  if (entry.isImplemented) return 'implemented';
+  if (entry.isNotSupported) return 'not-supported';
+  return 'missing';
+};
+
+/**
+ * Render the implementation status of every rule of the target plugins as JSON.
+ *
+ * @param {Map<string, import("./eslint-rules.cjs").TargetPluginMeta>} targetPlugins
+ * @param {import("./oxlint-rules.cjs").RuleEntries} ruleEntries
+ */
+exports.renderJson = (targetPlugins, ruleEntries) => {
+  const plugins = Array.from(targetPlugins).map(([pluginName, { npm }]) => {
+    /** @type {Record<RuleStatus, number>} */
+    const counts = {
+      implemented: 0,
+      partial: 0,
+      missing: 0,
+      'not-supported': 0,
+    };
+    const rules = [];
+
+    for (const [name, entry] of ruleEntries) {
+      if (!name.startsWith(`${pluginName}/`)) continue;
+
+      const status = getRuleStatus(entry);
+      counts[status]++;
+      rules.push({
+        name,
+        status,
+        recommended: Boolean(entry.isRecommended),
+        deprecated: Boolean(entry.isDeprecated),
+        docsUrl: entry.docsUrl,
+      });
+    }
+
+    return { name: pluginName, npm, counts, rules };
+  });
+
+  return JSON.stringify({ plugins }, null, 2);
+};
@@ -12,15 +12,17 @@ const {
   syncVitestPluginStatusWithJestPluginStatus,
 } = require('./oxlint-rules.cjs');
 const { renderMarkdown } = require('./markdown-renderer.cjs');
+const { renderJson } = require('./json-renderer.cjs');
 const { updateGitHubIssue } = require('./result-reporter.cjs');
 
 const HELP = `
 Usage:
-  $ cmd [--target=<pluginName>]... [--update] [--help]
+  $ cmd [--target=<pluginName>]... [--update | --json] [--help]
 
 Options:
   --target, -t: Which plugin to target, multiple allowed
   --update: Update the issue instead of printing to stdout
+  --json: Print the status of each rule as JSON instead of markdown
   --help, -h: Print this help message
 
 Plugins: ${Array.from(ALL_TARGET_PLUGINS.keys()).join(', ')}
@@ -35,11 +37,17 @@ Plugins: ${Array.from(ALL_TARGET_PLUGINS.keys()).join(', ')}
       // Mainly for debugging
       target: { type: 'string', short: 't', multiple: true },
       update: { type: 'boolean' },
+      json: { type: 'boolean' },
       help: { type: 'boolean', short: 'h' },
     },
   });
 
   if (values.help) return console.log(HELP);
+  if (values.update && values.json) {
+    console.error('--update and --json cannot be used together');
+    process.exitCode = 1;
+    return;
+  }
 
   const targetPluginNames = new Set(values.target ?? ALL_TARGET_PLUGINS.keys());
   for (const pluginName of targetPluginNames) {
@@ -64,6 +72,13 @@ Plugins: ${Array.from(ALL_TARGET_PLUGINS.keys()).join(', ')}
   syncTypeScriptPluginStatusWithEslintPluginStatus(ruleEntries);
   await syncVitestPluginStatusWithJestPluginStatus(ruleEntries);
 
+  if (values.json) {
+    const targetPlugins = new Map(
+      Array.from(ALL_TARGET_PLUGINS).filter(([pluginName]) => targetPluginNames.has(pluginName)),
+    );
+    return console.log(renderJson(targetPlugins, ruleEntries));
+  }
+
   //
   // Render list and update if necessary
   //
@@ -1,14 +1,18 @@
 const { resolve } = require('node:path');
 const { readFile } = require('node:fs/promises');
 
+/**
+ * Read the names of all implemented rules, mapped to the path of their module,
+ * e.g. `eslint/no-debugger` => `crates/oxc_linter/src/rules/eslint/no_debugger`.
+ */
 const readAllImplementedRuleNames = async () => {
   const rulesFile = await readFile(
     resolve('crates/oxc_linter/src/rules.rs'),
     'utf8',
   );
 
-  /** @type {Set<string>} */
-  const rules = new Set();
+  /** @type {Map<string, string>} */
+  const rules = new Map();
 
   let found = false;
   for (let line of rulesFile.split('\n')) {
@@ -26,21 +30,52 @@ const readAllImplementedRuleNames = async () => {
     }
 
     if (found) {
-      const prefixedName = line
-        .replaceAll(',', '')
-        .replaceAll('::', '/')
-        .replaceAll('_', '-');
+      const modulePath = line.replaceAll(',', '').replaceAll('::', '/');
+      const prefixedName = modulePath.replaceAll('_', '-');
 
       // Ignore no reference rules
       if (prefixedName.startsWith('oxc/')) continue;
 
-      rules.add(prefixedName);
+      rules.set(prefixedName, `crates/oxc_linter/src/rules/${modulePath}`);
     }
   }
 
   throw new Error('Failed to find the end of the rules list');
 };
 
+/**
+ * Read the category a rule is declared with in `declare_oxc_lint!`,
+ * e.g. `correctness` or `nursery`.
+ *
+ * @param {string} modulePath
+ */
+const readRuleCategory = async (modulePath) => {
+  const ruleFile = await readFile(resolve(`${modulePath}.rs`), 'utf8').catch(
+    () => readFile(resolve(`${modulePath}/mod.rs`), 'utf8'),
+  );
+
+  // ```
+  // declare_oxc_lint!(
+  //     /// ### What it does
+  //     NoDebugger,
+  //     correctness,
+  //     fix
+  // );
+  // ```
+  const declaration = ruleFile.match(/^declare_oxc_lint!\s*[({]\n(.+?)\n[)}]/ms)?.[1];
+  const [, category] = (declaration ?? '')
+    .split('\n')
+    // Doc comments and comments
+    .map((line) => line.replace(/\/\/.*/, '').trim())
+    .join(' ')
+    .split(',')
+    .map((token) => token.trim());
+  if (!category) {
+    throw new Error(`Failed to find the category of the rule in ${modulePath}`);
+  }
+  return category;
+};
+
 const NOT_SUPPORTED_RULE_NAMES = new Set([
   'eslint/no-dupe-args', // superseded by strict mode
   'eslint/no-octal', // superseded by strict mode
@@ -55,6 +90,7 @@ const NOT_SUPPORTED_RULE_NAMES = new Set([
  *   isDeprecated: boolean,
  *   isRecommended: boolean,
  *   isImplemented: boolean,
+ *   isPartial: boolean,
  *   isNotSupported: boolean,
  * }} RuleEntry
  * @typedef {Map<string, RuleEntry>} RuleEntries
@@ -79,6 +115,7 @@ exports.createRuleEntries = (loadedAllRules) => {
       isRecommended,
       // Will be updated later
       isImplemented: false,
+      isPartial: false,
       isNotSupported: false,
     });
   }
@@ -90,10 +127,15 @@ exports.createRuleEntries = (loadedAllRules) => {
 exports.updateImplementedStatus = async (ruleEntries) => {
   const implementedRuleNames = await readAllImplementedRuleNames();
 
-  for (const name of implementedRuleNames) {
+  for (const [name, modulePath] of implementedRuleNames) {
     const rule = ruleEntries.get(name);
-    if (rule) rule.isImplemented = true;
-    else console.log(`👀 ${name} is implemented but not found in their rules`);
+    if (!rule) {
+      console.error(`👀 ${name} is implemented but not found in their rules`);
+      continue;
+    }
+    rule.isImplemented = true;
+    // Rules in the nursery are still under development
+    rule.isPartial = (await readRuleCategory(modulePath)) === 'nursery';
   }
 };
 
@@ -124,6 +166,7 @@ exports.overrideTypeScriptPluginStatusWithEslintPluginStatus = (
     if (!eslintRule) continue;
 
     rule.isImplemented = eslintRule.isImplemented;
+    rule.isPartial = eslintRule.isPartial;
     rule.isNotSupported = eslintRule.isNotSupported;
   }
 };
@@ -175,6 +218,7 @@ exports.syncVitestPluginStatusWithJestPluginStatus = async (ruleEntries) => {
       ruleEntries.set(`vitest/${rule}`, {
         ...vitestRuleEntry,
         isImplemented: jestRuleEntry.isImplemented,
+        isPartial: jestRuleEntry.isPartial,
       });
     }
   }